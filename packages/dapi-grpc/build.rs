@@ -10,6 +10,7 @@ fn main() {
 
     println!("cargo:rerun-if-changed=./protos");
     println!("cargo:rerun-if-env-changed=CARGO_FEATURE_SERDE");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_SERVER");
 }
 
 struct MappingConfig {
@@ -34,17 +35,19 @@ pub fn generate() -> Result<(), std::io::Error> {
     // Derive features for versioned messages
     //
     // "GetConsensusParamsRequest" is excluded as this message does not support proofs
-    const VERSIONED_REQUESTS: [&str; 15] = [
+    const VERSIONED_REQUESTS: [&str; 17] = [
         "GetDataContractHistoryRequest",
         "GetDataContractRequest",
         "GetDataContractsRequest",
         "GetDocumentsRequest",
+        "GetDocumentsCountRequest",
         "GetIdentitiesByPublicKeyHashesRequest",
         "GetIdentitiesRequest",
         "GetIdentityBalanceAndRevisionRequest",
         "GetIdentityBalanceRequest",
         "GetIdentityByPublicKeyHashRequest",
         "GetIdentityKeysRequest",
+        "GetIdentityNonceRequest",
         "GetIdentityRequest",
         "GetProofsRequest",
         "WaitForStateTransitionResultRequest",
@@ -53,17 +56,19 @@ pub fn generate() -> Result<(), std::io::Error> {
     ];
 
     //  "GetConsensusParamsResponse" is excluded as this message does not support proofs
-    const VERSIONED_RESPONSES: [&str; 16] = [
+    const VERSIONED_RESPONSES: [&str; 18] = [
         "GetDataContractHistoryResponse",
         "GetDataContractResponse",
         "GetDataContractsResponse",
         "GetDocumentsResponse",
+        "GetDocumentsCountResponse",
         "GetIdentitiesByPublicKeyHashesResponse",
         "GetIdentitiesResponse",
         "GetIdentityBalanceAndRevisionResponse",
         "GetIdentityBalanceResponse",
         "GetIdentityByPublicKeyHashResponse",
         "GetIdentityKeysResponse",
+        "GetIdentityNonceResponse",
         "GetIdentityResponse",
         "GetProofsResponse",
         "WaitForStateTransitionResultResponse",
@@ -132,6 +137,9 @@ pub fn generate() -> Result<(), std::io::Error> {
         .field_attribute("Proof.signature", r#"#[serde(with = "serde_bytes")]"#)
         .field_attribute("Proof.block_id_hash", r#"#[serde(with = "serde_bytes")]"#);
 
+    #[cfg(feature = "server")]
+    let platform = platform.build_server();
+
     platform.generate().unwrap();
 
     Ok(())
@@ -160,6 +168,13 @@ impl MappingConfig {
         }
     }
 
+    /// Generate the service trait to implement a server
+    #[allow(unused)]
+    fn build_server(mut self) -> Self {
+        self.builder = self.builder.build_server(true);
+        self
+    }
+
     #[allow(unused)]
     fn type_attribute(mut self, path: &str, attribute: &str) -> Self {
         self.builder = self.builder.type_attribute(path, attribute);
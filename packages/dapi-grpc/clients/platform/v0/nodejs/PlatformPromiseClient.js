@@ -26,14 +26,20 @@ const {
           v0: {
             BroadcastStateTransitionRequest: PBJSBroadcastStateTransitionRequest,
             BroadcastStateTransitionResponse: PBJSBroadcastStateTransitionResponse,
+            EstimateStateTransitionFeeRequest: PBJSEstimateStateTransitionFeeRequest,
+            EstimateStateTransitionFeeResponse: PBJSEstimateStateTransitionFeeResponse,
             GetIdentityRequest: PBJSGetIdentityRequest,
             GetIdentityResponse: PBJSGetIdentityResponse,
+            GetIdentityNonceRequest: PBJSGetIdentityNonceRequest,
+            GetIdentityNonceResponse: PBJSGetIdentityNonceResponse,
             GetDataContractRequest: PBJSGetDataContractRequest,
             GetDataContractResponse: PBJSGetDataContractResponse,
             GetDataContractHistoryRequest: PBJSGetDataContractHistoryRequest,
             GetDataContractHistoryResponse: PBJSGetDataContractHistoryResponse,
             GetDocumentsRequest: PBJSGetDocumentsRequest,
             GetDocumentsResponse: PBJSGetDocumentsResponse,
+            GetDocumentsCountRequest: PBJSGetDocumentsCountRequest,
+            GetDocumentsCountResponse: PBJSGetDocumentsCountResponse,
             GetIdentitiesByPublicKeyHashesRequest: PBJSGetIdentitiesByPublicKeyHashesRequest,
             GetIdentitiesByPublicKeyHashesResponse: PBJSGetIdentitiesByPublicKeyHashesResponse,
             WaitForStateTransitionResultRequest: PBJSWaitForStateTransitionResultRequest,
@@ -48,6 +54,8 @@ const {
               PBJSGetProtocolVersionUpgradeVoteStatusResponse,
             GetProtocolVersionUpgradeStateRequest: PBJSGetProtocolVersionUpgradeStateRequest,
             GetProtocolVersionUpgradeStateResponse: PBJSGetProtocolVersionUpgradeStateResponse,
+            SubscribePlatformEventsRequest: PBJSSubscribePlatformEventsRequest,
+            SubscribePlatformEventsResponse: PBJSSubscribePlatformEventsResponse,
           },
         },
       },
@@ -57,16 +65,20 @@ const {
 
 const {
   BroadcastStateTransitionResponse: ProtocBroadcastStateTransitionResponse,
+  EstimateStateTransitionFeeResponse: ProtocEstimateStateTransitionFeeResponse,
   GetIdentityResponse: ProtocGetIdentityResponse,
+  GetIdentityNonceResponse: ProtocGetIdentityNonceResponse,
   GetDataContractResponse: ProtocGetDataContractResponse,
   GetDataContractHistoryResponse: ProtocGetDataContractHistoryResponse,
   GetDocumentsResponse: ProtocGetDocumentsResponse,
+  GetDocumentsCountResponse: ProtocGetDocumentsCountResponse,
   GetIdentitiesByPublicKeyHashesResponse: ProtocGetIdentitiesByPublicKeyHashesResponse,
   WaitForStateTransitionResultResponse: ProtocWaitForStateTransitionResultResponse,
   GetConsensusParamsResponse: ProtocGetConsensusParamsResponse,
   GetEpochsInfoResponse: ProtocGetEpochsInfoResponse,
   GetProtocolVersionUpgradeVoteStatusResponse: ProtocGetProtocolVersionUpgradeVoteStatusResponse,
   GetProtocolVersionUpgradeStateResponse: ProtocGetProtocolVersionUpgradeStateResponse,
+  SubscribePlatformEventsResponse: ProtocSubscribePlatformEventsResponse,
 } = require('./platform_protoc');
 
 const getPlatformDefinition = require('../../../../lib/getPlatformDefinition');
@@ -97,10 +109,18 @@ class PlatformPromiseClient {
       this.client.broadcastStateTransition.bind(this.client),
     );
 
+    this.client.estimateStateTransitionFee = promisify(
+      this.client.estimateStateTransitionFee.bind(this.client),
+    );
+
     this.client.getIdentity = promisify(
       this.client.getIdentity.bind(this.client),
     );
 
+    this.client.getIdentityNonce = promisify(
+      this.client.getIdentityNonce.bind(this.client),
+    );
+
     this.client.getDataContract = promisify(
       this.client.getDataContract.bind(this.client),
     );
@@ -113,6 +133,10 @@ class PlatformPromiseClient {
       this.client.getDocuments.bind(this.client),
     );
 
+    this.client.getDocumentsCount = promisify(
+      this.client.getDocumentsCount.bind(this.client),
+    );
+
     this.client.getIdentitiesByPublicKeyHashes = promisify(
       this.client.getIdentitiesByPublicKeyHashes.bind(this.client),
     );
@@ -171,6 +195,37 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!EstimateStateTransitionFeeRequest} estimateStateTransitionFeeRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @return {Promise<!EstimateStateTransitionFeeResponse>}
+   */
+  estimateStateTransitionFee(estimateStateTransitionFeeRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.estimateStateTransitionFee(
+      estimateStateTransitionFeeRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocEstimateStateTransitionFeeResponse,
+              PBJSEstimateStateTransitionFeeResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSEstimateStateTransitionFeeRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    * @param {!GetIdentityRequest} getIdentityRequest
    * @param {?Object<string, string>} metadata
@@ -202,6 +257,37 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!GetIdentityNonceRequest} getIdentityNonceRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @return {Promise<!GetIdentityNonceResponse>}
+   */
+  getIdentityNonce(getIdentityNonceRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getIdentityNonce(
+      getIdentityNonceRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetIdentityNonceResponse,
+              PBJSGetIdentityNonceResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetIdentityNonceRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    *
    * @param {!GetDataContractRequest} getDataContractRequest
@@ -298,6 +384,37 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!GetDocumentsCountRequest} getDocumentsCountRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetDocumentsCountResponse>}
+   */
+  getDocumentsCount(getDocumentsCountRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getDocumentsCount(
+      getDocumentsCountRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetDocumentsCountResponse,
+              PBJSGetDocumentsCountResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetDocumentsCountRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    * @param {!GetIdentitiesByPublicKeyHashesRequest} getIdentitiesByPublicKeyHashesRequest
    * @param {?Object<string, string>} metadata
@@ -496,6 +613,38 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!SubscribePlatformEventsRequest} subscribePlatformEventsRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @return {!grpc.web.ClientReadableStream<!SubscribePlatformEventsResponse>|undefined}
+   *     The XHR Node Readable Stream
+   */
+  subscribePlatformEvents(subscribePlatformEventsRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.subscribePlatformEvents(
+      subscribePlatformEventsRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocSubscribePlatformEventsResponse,
+              PBJSSubscribePlatformEventsResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSSubscribePlatformEventsRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    * @param {string} protocolVersion
    */
@@ -27,6 +27,20 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!EstimateStateTransitionFeeRequest} estimateStateTransitionFeeRequest
+   * @param {?Object<string, string>} metadata
+   * @return {Promise<!EstimateStateTransitionFeeResponse>}
+   */
+  estimateStateTransitionFee(estimateStateTransitionFeeRequest, metadata = {}) {
+    return promisify(
+      this.client.estimateStateTransitionFee.bind(this.client),
+    )(
+      estimateStateTransitionFeeRequest,
+      metadata,
+    );
+  }
+
   /**
    * @param {!GetIdentityRequest} getIdentityRequest
    * @param {?Object<string, string>} metadata
@@ -41,6 +55,20 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!GetIdentityNonceRequest} getIdentityNonceRequest
+   * @param {?Object<string, string>} metadata
+   * @return {Promise<!GetIdentityNonceResponse>}
+   */
+  getIdentityNonce(getIdentityNonceRequest, metadata = {}) {
+    return promisify(
+      this.client.getIdentityNonce.bind(this.client),
+    )(
+      getIdentityNonceRequest,
+      metadata,
+    );
+  }
+
   /**
    *
    * @param {!GetDataContractRequest} getDataContractRequest
@@ -86,6 +114,20 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!GetDocumentsCountRequest} getDocumentsCountRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetDocumentsCountResponse>}
+   */
+  getDocumentsCount(getDocumentsCountRequest, metadata = {}) {
+    return promisify(
+      this.client.getDocumentsCount.bind(this.client),
+    )(
+      getDocumentsCountRequest,
+      metadata,
+    );
+  }
+
   /**
    * @param {!GetIdentitiesByPublicKeyHashesRequest} getIdentitiesByPublicKeyHashesRequest
    * @param {?Object<string, string>} metadata
@@ -162,6 +162,152 @@ export namespace BroadcastStateTransitionResponse {
   }
 }
 
+export class EstimateStateTransitionFeeRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): EstimateStateTransitionFeeRequest.EstimateStateTransitionFeeRequestV0 | undefined;
+  setV0(value?: EstimateStateTransitionFeeRequest.EstimateStateTransitionFeeRequestV0): void;
+
+  getVersionCase(): EstimateStateTransitionFeeRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): EstimateStateTransitionFeeRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: EstimateStateTransitionFeeRequest): EstimateStateTransitionFeeRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: EstimateStateTransitionFeeRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): EstimateStateTransitionFeeRequest;
+  static deserializeBinaryFromReader(message: EstimateStateTransitionFeeRequest, reader: jspb.BinaryReader): EstimateStateTransitionFeeRequest;
+}
+
+export namespace EstimateStateTransitionFeeRequest {
+  export type AsObject = {
+    v0?: EstimateStateTransitionFeeRequest.EstimateStateTransitionFeeRequestV0.AsObject,
+  }
+
+  export class EstimateStateTransitionFeeRequestV0 extends jspb.Message {
+    getStateTransition(): Uint8Array | string;
+    getStateTransition_asU8(): Uint8Array;
+    getStateTransition_asB64(): string;
+    setStateTransition(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): EstimateStateTransitionFeeRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: EstimateStateTransitionFeeRequestV0): EstimateStateTransitionFeeRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: EstimateStateTransitionFeeRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): EstimateStateTransitionFeeRequestV0;
+    static deserializeBinaryFromReader(message: EstimateStateTransitionFeeRequestV0, reader: jspb.BinaryReader): EstimateStateTransitionFeeRequestV0;
+  }
+
+  export namespace EstimateStateTransitionFeeRequestV0 {
+    export type AsObject = {
+      stateTransition: Uint8Array | string,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class EstimateStateTransitionFeeResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0 | undefined;
+  setV0(value?: EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0): void;
+
+  getVersionCase(): EstimateStateTransitionFeeResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): EstimateStateTransitionFeeResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: EstimateStateTransitionFeeResponse): EstimateStateTransitionFeeResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: EstimateStateTransitionFeeResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): EstimateStateTransitionFeeResponse;
+  static deserializeBinaryFromReader(message: EstimateStateTransitionFeeResponse, reader: jspb.BinaryReader): EstimateStateTransitionFeeResponse;
+}
+
+export namespace EstimateStateTransitionFeeResponse {
+  export type AsObject = {
+    v0?: EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0.AsObject,
+  }
+
+  export class EstimateStateTransitionFeeResponseV0 extends jspb.Message {
+    getStorageFee(): number;
+    setStorageFee(value: number): void;
+
+    getProcessingFee(): number;
+    setProcessingFee(value: number): void;
+
+    clearFeeRefundsList(): void;
+    getFeeRefundsList(): Array<EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0.FeeRefund>;
+    setFeeRefundsList(value: Array<EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0.FeeRefund>): void;
+    addFeeRefunds(value?: EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0.FeeRefund, index?: number): EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0.FeeRefund;
+
+    clearErrorsList(): void;
+    getErrorsList(): Array<StateTransitionBroadcastError>;
+    setErrorsList(value: Array<StateTransitionBroadcastError>): void;
+    addErrors(value?: StateTransitionBroadcastError, index?: number): StateTransitionBroadcastError;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): EstimateStateTransitionFeeResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: EstimateStateTransitionFeeResponseV0): EstimateStateTransitionFeeResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: EstimateStateTransitionFeeResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): EstimateStateTransitionFeeResponseV0;
+    static deserializeBinaryFromReader(message: EstimateStateTransitionFeeResponseV0, reader: jspb.BinaryReader): EstimateStateTransitionFeeResponseV0;
+  }
+
+  export namespace EstimateStateTransitionFeeResponseV0 {
+    export type AsObject = {
+      storageFee: number,
+      processingFee: number,
+      feeRefundsList: Array<EstimateStateTransitionFeeResponse.EstimateStateTransitionFeeResponseV0.FeeRefund.AsObject>,
+      errorsList: Array<StateTransitionBroadcastError.AsObject>,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class FeeRefund extends jspb.Message {
+      getIdentityId(): Uint8Array | string;
+      getIdentityId_asU8(): Uint8Array;
+      getIdentityId_asB64(): string;
+      setIdentityId(value: Uint8Array | string): void;
+
+      getCredits(): number;
+      setCredits(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): FeeRefund.AsObject;
+      static toObject(includeInstance: boolean, msg: FeeRefund): FeeRefund.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: FeeRefund, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): FeeRefund;
+      static deserializeBinaryFromReader(message: FeeRefund, reader: jspb.BinaryReader): FeeRefund;
+    }
+
+    export namespace FeeRefund {
+      export type AsObject = {
+        identityId: Uint8Array | string,
+        credits: number,
+      }
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class GetIdentityRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
@@ -193,6 +339,11 @@ export namespace GetIdentityRequest {
     getProve(): boolean;
     setProve(value: boolean): void;
 
+    hasHeight(): boolean;
+    clearHeight(): void;
+    getHeight(): number;
+    setHeight(value: number): void;
+
     serializeBinary(): Uint8Array;
     toObject(includeInstance?: boolean): GetIdentityRequestV0.AsObject;
     static toObject(includeInstance: boolean, msg: GetIdentityRequestV0): GetIdentityRequestV0.AsObject;
@@ -207,6 +358,7 @@ export namespace GetIdentityRequest {
     export type AsObject = {
       id: Uint8Array | string,
       prove: boolean,
+      height?: number,
     }
   }
 
@@ -324,6 +476,60 @@ export namespace GetIdentityBalanceAndRevisionRequest {
   }
 }
 
+export class GetIdentityNonceRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetIdentityNonceRequest.GetIdentityNonceRequestV0 | undefined;
+  setV0(value?: GetIdentityNonceRequest.GetIdentityNonceRequestV0): void;
+
+  getVersionCase(): GetIdentityNonceRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityNonceRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityNonceRequest): GetIdentityNonceRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityNonceRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityNonceRequest;
+  static deserializeBinaryFromReader(message: GetIdentityNonceRequest, reader: jspb.BinaryReader): GetIdentityNonceRequest;
+}
+
+export namespace GetIdentityNonceRequest {
+  export type AsObject = {
+    v0?: GetIdentityNonceRequest.GetIdentityNonceRequestV0.AsObject,
+  }
+
+  export class GetIdentityNonceRequestV0 extends jspb.Message {
+    getId(): Uint8Array | string;
+    getId_asU8(): Uint8Array;
+    getId_asB64(): string;
+    setId(value: Uint8Array | string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetIdentityNonceRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetIdentityNonceRequestV0): GetIdentityNonceRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetIdentityNonceRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetIdentityNonceRequestV0;
+    static deserializeBinaryFromReader(message: GetIdentityNonceRequestV0, reader: jspb.BinaryReader): GetIdentityNonceRequestV0;
+  }
+
+  export namespace GetIdentityNonceRequestV0 {
+    export type AsObject = {
+      id: Uint8Array | string,
+      prove: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class GetIdentityResponse extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
@@ -754,6 +960,75 @@ export namespace GetIdentityBalanceAndRevisionResponse {
   }
 }
 
+export class GetIdentityNonceResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetIdentityNonceResponse.GetIdentityNonceResponseV0 | undefined;
+  setV0(value?: GetIdentityNonceResponse.GetIdentityNonceResponseV0): void;
+
+  getVersionCase(): GetIdentityNonceResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityNonceResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityNonceResponse): GetIdentityNonceResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityNonceResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityNonceResponse;
+  static deserializeBinaryFromReader(message: GetIdentityNonceResponse, reader: jspb.BinaryReader): GetIdentityNonceResponse;
+}
+
+export namespace GetIdentityNonceResponse {
+  export type AsObject = {
+    v0?: GetIdentityNonceResponse.GetIdentityNonceResponseV0.AsObject,
+  }
+
+  export class GetIdentityNonceResponseV0 extends jspb.Message {
+    hasIdentityNonce(): boolean;
+    clearIdentityNonce(): void;
+    getIdentityNonce(): number;
+    setIdentityNonce(value: number): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetIdentityNonceResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetIdentityNonceResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetIdentityNonceResponseV0): GetIdentityNonceResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetIdentityNonceResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetIdentityNonceResponseV0;
+    static deserializeBinaryFromReader(message: GetIdentityNonceResponseV0, reader: jspb.BinaryReader): GetIdentityNonceResponseV0;
+  }
+
+  export namespace GetIdentityNonceResponseV0 {
+    export type AsObject = {
+      identityNonce: number,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      IDENTITY_NONCE = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class KeyRequestType extends jspb.Message {
   hasAllKeys(): boolean;
   clearAllKeys(): void;
@@ -1164,6 +1439,7 @@ export namespace GetProofsRequest {
         FULL_IDENTITY: 0;
         BALANCE: 1;
         KEYS: 2;
+        NONCE: 3;
       }
 
       export const Type: TypeMap;
@@ -1291,6 +1567,11 @@ export namespace GetDataContractRequest {
     getProve(): boolean;
     setProve(value: boolean): void;
 
+    hasHeight(): boolean;
+    clearHeight(): void;
+    getHeight(): number;
+    setHeight(value: number): void;
+
     serializeBinary(): Uint8Array;
     toObject(includeInstance?: boolean): GetDataContractRequestV0.AsObject;
     static toObject(includeInstance: boolean, msg: GetDataContractRequestV0): GetDataContractRequestV0.AsObject;
@@ -1305,6 +1586,7 @@ export namespace GetDataContractRequest {
     export type AsObject = {
       id: Uint8Array | string,
       prove: boolean,
+      height?: number,
     }
   }
 
@@ -1808,6 +2090,11 @@ export namespace GetDocumentsRequest {
     getProve(): boolean;
     setProve(value: boolean): void;
 
+    hasHeight(): boolean;
+    clearHeight(): void;
+    getHeight(): number;
+    setHeight(value: number): void;
+
     getStartCase(): GetDocumentsRequestV0.StartCase;
     serializeBinary(): Uint8Array;
     toObject(includeInstance?: boolean): GetDocumentsRequestV0.AsObject;
@@ -1829,6 +2116,7 @@ export namespace GetDocumentsRequest {
       startAfter: Uint8Array | string,
       startAt: Uint8Array | string,
       prove: boolean,
+      height?: number,
     }
 
     export enum StartCase {
@@ -1937,6 +2225,143 @@ export namespace GetDocumentsResponse {
   }
 }
 
+export class GetDocumentsCountRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetDocumentsCountRequest.GetDocumentsCountRequestV0 | undefined;
+  setV0(value?: GetDocumentsCountRequest.GetDocumentsCountRequestV0): void;
+
+  getVersionCase(): GetDocumentsCountRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDocumentsCountRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDocumentsCountRequest): GetDocumentsCountRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDocumentsCountRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDocumentsCountRequest;
+  static deserializeBinaryFromReader(message: GetDocumentsCountRequest, reader: jspb.BinaryReader): GetDocumentsCountRequest;
+}
+
+export namespace GetDocumentsCountRequest {
+  export type AsObject = {
+    v0?: GetDocumentsCountRequest.GetDocumentsCountRequestV0.AsObject,
+  }
+
+  export class GetDocumentsCountRequestV0 extends jspb.Message {
+    getDataContractId(): Uint8Array | string;
+    getDataContractId_asU8(): Uint8Array;
+    getDataContractId_asB64(): string;
+    setDataContractId(value: Uint8Array | string): void;
+
+    getDocumentType(): string;
+    setDocumentType(value: string): void;
+
+    getWhere(): Uint8Array | string;
+    getWhere_asU8(): Uint8Array;
+    getWhere_asB64(): string;
+    setWhere(value: Uint8Array | string): void;
+
+    getSumProperty(): string;
+    setSumProperty(value: string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetDocumentsCountRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetDocumentsCountRequestV0): GetDocumentsCountRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetDocumentsCountRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetDocumentsCountRequestV0;
+    static deserializeBinaryFromReader(message: GetDocumentsCountRequestV0, reader: jspb.BinaryReader): GetDocumentsCountRequestV0;
+  }
+
+  export namespace GetDocumentsCountRequestV0 {
+    export type AsObject = {
+      dataContractId: Uint8Array | string,
+      documentType: string,
+      where: Uint8Array | string,
+      sumProperty: string,
+      prove: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetDocumentsCountResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetDocumentsCountResponse.GetDocumentsCountResponseV0 | undefined;
+  setV0(value?: GetDocumentsCountResponse.GetDocumentsCountResponseV0): void;
+
+  getVersionCase(): GetDocumentsCountResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDocumentsCountResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDocumentsCountResponse): GetDocumentsCountResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDocumentsCountResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDocumentsCountResponse;
+  static deserializeBinaryFromReader(message: GetDocumentsCountResponse, reader: jspb.BinaryReader): GetDocumentsCountResponse;
+}
+
+export namespace GetDocumentsCountResponse {
+  export type AsObject = {
+    v0?: GetDocumentsCountResponse.GetDocumentsCountResponseV0.AsObject,
+  }
+
+  export class GetDocumentsCountResponseV0 extends jspb.Message {
+    hasValue(): boolean;
+    clearValue(): void;
+    getValue(): number;
+    setValue(value: number): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetDocumentsCountResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetDocumentsCountResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetDocumentsCountResponseV0): GetDocumentsCountResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetDocumentsCountResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetDocumentsCountResponseV0;
+    static deserializeBinaryFromReader(message: GetDocumentsCountResponseV0, reader: jspb.BinaryReader): GetDocumentsCountResponseV0;
+  }
+
+  export namespace GetDocumentsCountResponseV0 {
+    export type AsObject = {
+      value: number,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      VALUE = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class GetIdentitiesByPublicKeyHashesRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
@@ -3051,3 +3476,354 @@ export namespace GetEpochsInfoResponse {
   }
 }
 
+export class SubscribePlatformEventsRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): SubscribePlatformEventsRequest.SubscribePlatformEventsRequestV0 | undefined;
+  setV0(value?: SubscribePlatformEventsRequest.SubscribePlatformEventsRequestV0): void;
+
+  getVersionCase(): SubscribePlatformEventsRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SubscribePlatformEventsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: SubscribePlatformEventsRequest): SubscribePlatformEventsRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: SubscribePlatformEventsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SubscribePlatformEventsRequest;
+  static deserializeBinaryFromReader(message: SubscribePlatformEventsRequest, reader: jspb.BinaryReader): SubscribePlatformEventsRequest;
+}
+
+export namespace SubscribePlatformEventsRequest {
+  export type AsObject = {
+    v0?: SubscribePlatformEventsRequest.SubscribePlatformEventsRequestV0.AsObject,
+  }
+
+  export class SubscribePlatformEventsRequestV0 extends jspb.Message {
+    clearDataContractIdsList(): void;
+    getDataContractIdsList(): Array<Uint8Array | string>;
+    getDataContractIdsList_asU8(): Array<Uint8Array>;
+    getDataContractIdsList_asB64(): Array<string>;
+    setDataContractIdsList(value: Array<Uint8Array | string>): void;
+    addDataContractIds(value: Uint8Array | string, index?: number): Uint8Array | string;
+
+    clearDocumentTypesList(): void;
+    getDocumentTypesList(): Array<string>;
+    setDocumentTypesList(value: Array<string>): void;
+    addDocumentTypes(value: string, index?: number): string;
+
+    clearIdentityIdsList(): void;
+    getIdentityIdsList(): Array<Uint8Array | string>;
+    getIdentityIdsList_asU8(): Array<Uint8Array>;
+    getIdentityIdsList_asB64(): Array<string>;
+    setIdentityIdsList(value: Array<Uint8Array | string>): void;
+    addIdentityIds(value: Uint8Array | string, index?: number): Uint8Array | string;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): SubscribePlatformEventsRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: SubscribePlatformEventsRequestV0): SubscribePlatformEventsRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: SubscribePlatformEventsRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): SubscribePlatformEventsRequestV0;
+    static deserializeBinaryFromReader(message: SubscribePlatformEventsRequestV0, reader: jspb.BinaryReader): SubscribePlatformEventsRequestV0;
+  }
+
+  export namespace SubscribePlatformEventsRequestV0 {
+    export type AsObject = {
+      dataContractIdsList: Array<Uint8Array | string>,
+      documentTypesList: Array<string>,
+      identityIdsList: Array<Uint8Array | string>,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class PlatformEvent extends jspb.Message {
+  hasDocumentCreated(): boolean;
+  clearDocumentCreated(): void;
+  getDocumentCreated(): PlatformEvent.DocumentChanged | undefined;
+  setDocumentCreated(value?: PlatformEvent.DocumentChanged): void;
+
+  hasDocumentReplaced(): boolean;
+  clearDocumentReplaced(): void;
+  getDocumentReplaced(): PlatformEvent.DocumentChanged | undefined;
+  setDocumentReplaced(value?: PlatformEvent.DocumentChanged): void;
+
+  hasDocumentDeleted(): boolean;
+  clearDocumentDeleted(): void;
+  getDocumentDeleted(): PlatformEvent.DocumentDeleted | undefined;
+  setDocumentDeleted(value?: PlatformEvent.DocumentDeleted): void;
+
+  hasIdentityBalanceChanged(): boolean;
+  clearIdentityBalanceChanged(): void;
+  getIdentityBalanceChanged(): PlatformEvent.IdentityBalanceChanged | undefined;
+  setIdentityBalanceChanged(value?: PlatformEvent.IdentityBalanceChanged): void;
+
+  hasIdentityKeyAdded(): boolean;
+  clearIdentityKeyAdded(): void;
+  getIdentityKeyAdded(): PlatformEvent.IdentityKeyAdded | undefined;
+  setIdentityKeyAdded(value?: PlatformEvent.IdentityKeyAdded): void;
+
+  hasIdentityKeyDisabled(): boolean;
+  clearIdentityKeyDisabled(): void;
+  getIdentityKeyDisabled(): PlatformEvent.IdentityKeyDisabled | undefined;
+  setIdentityKeyDisabled(value?: PlatformEvent.IdentityKeyDisabled): void;
+
+  getEventCase(): PlatformEvent.EventCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): PlatformEvent.AsObject;
+  static toObject(includeInstance: boolean, msg: PlatformEvent): PlatformEvent.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: PlatformEvent, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): PlatformEvent;
+  static deserializeBinaryFromReader(message: PlatformEvent, reader: jspb.BinaryReader): PlatformEvent;
+}
+
+export namespace PlatformEvent {
+  export type AsObject = {
+    documentCreated?: PlatformEvent.DocumentChanged.AsObject,
+    documentReplaced?: PlatformEvent.DocumentChanged.AsObject,
+    documentDeleted?: PlatformEvent.DocumentDeleted.AsObject,
+    identityBalanceChanged?: PlatformEvent.IdentityBalanceChanged.AsObject,
+    identityKeyAdded?: PlatformEvent.IdentityKeyAdded.AsObject,
+    identityKeyDisabled?: PlatformEvent.IdentityKeyDisabled.AsObject,
+  }
+
+  export class DocumentChanged extends jspb.Message {
+    getDataContractId(): Uint8Array | string;
+    getDataContractId_asU8(): Uint8Array;
+    getDataContractId_asB64(): string;
+    setDataContractId(value: Uint8Array | string): void;
+
+    getDocumentType(): string;
+    setDocumentType(value: string): void;
+
+    getDocumentId(): Uint8Array | string;
+    getDocumentId_asU8(): Uint8Array;
+    getDocumentId_asB64(): string;
+    setDocumentId(value: Uint8Array | string): void;
+
+    getOwnerId(): Uint8Array | string;
+    getOwnerId_asU8(): Uint8Array;
+    getOwnerId_asB64(): string;
+    setOwnerId(value: Uint8Array | string): void;
+
+    getDocument(): Uint8Array | string;
+    getDocument_asU8(): Uint8Array;
+    getDocument_asB64(): string;
+    setDocument(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): DocumentChanged.AsObject;
+    static toObject(includeInstance: boolean, msg: DocumentChanged): DocumentChanged.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: DocumentChanged, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): DocumentChanged;
+    static deserializeBinaryFromReader(message: DocumentChanged, reader: jspb.BinaryReader): DocumentChanged;
+  }
+
+  export namespace DocumentChanged {
+    export type AsObject = {
+      dataContractId: Uint8Array | string,
+      documentType: string,
+      documentId: Uint8Array | string,
+      ownerId: Uint8Array | string,
+      document: Uint8Array | string,
+    }
+  }
+
+  export class DocumentDeleted extends jspb.Message {
+    getDataContractId(): Uint8Array | string;
+    getDataContractId_asU8(): Uint8Array;
+    getDataContractId_asB64(): string;
+    setDataContractId(value: Uint8Array | string): void;
+
+    getDocumentType(): string;
+    setDocumentType(value: string): void;
+
+    getDocumentId(): Uint8Array | string;
+    getDocumentId_asU8(): Uint8Array;
+    getDocumentId_asB64(): string;
+    setDocumentId(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): DocumentDeleted.AsObject;
+    static toObject(includeInstance: boolean, msg: DocumentDeleted): DocumentDeleted.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: DocumentDeleted, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): DocumentDeleted;
+    static deserializeBinaryFromReader(message: DocumentDeleted, reader: jspb.BinaryReader): DocumentDeleted;
+  }
+
+  export namespace DocumentDeleted {
+    export type AsObject = {
+      dataContractId: Uint8Array | string,
+      documentType: string,
+      documentId: Uint8Array | string,
+    }
+  }
+
+  export class IdentityBalanceChanged extends jspb.Message {
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    getBalance(): number;
+    setBalance(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): IdentityBalanceChanged.AsObject;
+    static toObject(includeInstance: boolean, msg: IdentityBalanceChanged): IdentityBalanceChanged.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: IdentityBalanceChanged, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): IdentityBalanceChanged;
+    static deserializeBinaryFromReader(message: IdentityBalanceChanged, reader: jspb.BinaryReader): IdentityBalanceChanged;
+  }
+
+  export namespace IdentityBalanceChanged {
+    export type AsObject = {
+      identityId: Uint8Array | string,
+      balance: number,
+    }
+  }
+
+  export class IdentityKeyAdded extends jspb.Message {
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    getKeyId(): number;
+    setKeyId(value: number): void;
+
+    getPublicKey(): Uint8Array | string;
+    getPublicKey_asU8(): Uint8Array;
+    getPublicKey_asB64(): string;
+    setPublicKey(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): IdentityKeyAdded.AsObject;
+    static toObject(includeInstance: boolean, msg: IdentityKeyAdded): IdentityKeyAdded.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: IdentityKeyAdded, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): IdentityKeyAdded;
+    static deserializeBinaryFromReader(message: IdentityKeyAdded, reader: jspb.BinaryReader): IdentityKeyAdded;
+  }
+
+  export namespace IdentityKeyAdded {
+    export type AsObject = {
+      identityId: Uint8Array | string,
+      keyId: number,
+      publicKey: Uint8Array | string,
+    }
+  }
+
+  export class IdentityKeyDisabled extends jspb.Message {
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    getKeyId(): number;
+    setKeyId(value: number): void;
+
+    getDisabledAt(): number;
+    setDisabledAt(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): IdentityKeyDisabled.AsObject;
+    static toObject(includeInstance: boolean, msg: IdentityKeyDisabled): IdentityKeyDisabled.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: IdentityKeyDisabled, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): IdentityKeyDisabled;
+    static deserializeBinaryFromReader(message: IdentityKeyDisabled, reader: jspb.BinaryReader): IdentityKeyDisabled;
+  }
+
+  export namespace IdentityKeyDisabled {
+    export type AsObject = {
+      identityId: Uint8Array | string,
+      keyId: number,
+      disabledAt: number,
+    }
+  }
+
+  export enum EventCase {
+    EVENT_NOT_SET = 0,
+    DOCUMENT_CREATED = 1,
+    DOCUMENT_REPLACED = 2,
+    DOCUMENT_DELETED = 3,
+    IDENTITY_BALANCE_CHANGED = 4,
+    IDENTITY_KEY_ADDED = 5,
+    IDENTITY_KEY_DISABLED = 6,
+  }
+}
+
+export class SubscribePlatformEventsResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): SubscribePlatformEventsResponse.SubscribePlatformEventsResponseV0 | undefined;
+  setV0(value?: SubscribePlatformEventsResponse.SubscribePlatformEventsResponseV0): void;
+
+  getVersionCase(): SubscribePlatformEventsResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SubscribePlatformEventsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: SubscribePlatformEventsResponse): SubscribePlatformEventsResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: SubscribePlatformEventsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SubscribePlatformEventsResponse;
+  static deserializeBinaryFromReader(message: SubscribePlatformEventsResponse, reader: jspb.BinaryReader): SubscribePlatformEventsResponse;
+}
+
+export namespace SubscribePlatformEventsResponse {
+  export type AsObject = {
+    v0?: SubscribePlatformEventsResponse.SubscribePlatformEventsResponseV0.AsObject,
+  }
+
+  export class SubscribePlatformEventsResponseV0 extends jspb.Message {
+    getHeight(): number;
+    setHeight(value: number): void;
+
+    getTimeMs(): number;
+    setTimeMs(value: number): void;
+
+    clearEventsList(): void;
+    getEventsList(): Array<PlatformEvent>;
+    setEventsList(value: Array<PlatformEvent>): void;
+    addEvents(value?: PlatformEvent, index?: number): PlatformEvent;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): SubscribePlatformEventsResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: SubscribePlatformEventsResponseV0): SubscribePlatformEventsResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: SubscribePlatformEventsResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): SubscribePlatformEventsResponseV0;
+    static deserializeBinaryFromReader(message: SubscribePlatformEventsResponseV0, reader: jspb.BinaryReader): SubscribePlatformEventsResponseV0;
+  }
+
+  export namespace SubscribePlatformEventsResponseV0 {
+    export type AsObject = {
+      height: number,
+      timeMs: number,
+      eventsList: Array<PlatformEvent.AsObject>,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
@@ -13,6 +13,15 @@ type PlatformbroadcastStateTransition = {
   readonly responseType: typeof platform_pb.BroadcastStateTransitionResponse;
 };
 
+type PlatformestimateStateTransitionFee = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.EstimateStateTransitionFeeRequest;
+  readonly responseType: typeof platform_pb.EstimateStateTransitionFeeResponse;
+};
+
 type PlatformgetIdentity = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -58,6 +67,15 @@ type PlatformgetIdentityBalanceAndRevision = {
   readonly responseType: typeof platform_pb.GetIdentityBalanceAndRevisionResponse;
 };
 
+type PlatformgetIdentityNonce = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetIdentityNonceRequest;
+  readonly responseType: typeof platform_pb.GetIdentityNonceResponse;
+};
+
 type PlatformgetProofs = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -103,6 +121,15 @@ type PlatformgetDocuments = {
   readonly responseType: typeof platform_pb.GetDocumentsResponse;
 };
 
+type PlatformgetDocumentsCount = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetDocumentsCountRequest;
+  readonly responseType: typeof platform_pb.GetDocumentsCountResponse;
+};
+
 type PlatformgetIdentitiesByPublicKeyHashes = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -166,19 +193,31 @@ type PlatformgetEpochsInfo = {
   readonly responseType: typeof platform_pb.GetEpochsInfoResponse;
 };
 
+type PlatformsubscribePlatformEvents = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: true;
+  readonly requestType: typeof platform_pb.SubscribePlatformEventsRequest;
+  readonly responseType: typeof platform_pb.SubscribePlatformEventsResponse;
+};
+
 export class Platform {
   static readonly serviceName: string;
   static readonly broadcastStateTransition: PlatformbroadcastStateTransition;
+  static readonly estimateStateTransitionFee: PlatformestimateStateTransitionFee;
   static readonly getIdentity: PlatformgetIdentity;
   static readonly getIdentities: PlatformgetIdentities;
   static readonly getIdentityKeys: PlatformgetIdentityKeys;
   static readonly getIdentityBalance: PlatformgetIdentityBalance;
   static readonly getIdentityBalanceAndRevision: PlatformgetIdentityBalanceAndRevision;
+  static readonly getIdentityNonce: PlatformgetIdentityNonce;
   static readonly getProofs: PlatformgetProofs;
   static readonly getDataContract: PlatformgetDataContract;
   static readonly getDataContractHistory: PlatformgetDataContractHistory;
   static readonly getDataContracts: PlatformgetDataContracts;
   static readonly getDocuments: PlatformgetDocuments;
+  static readonly getDocumentsCount: PlatformgetDocumentsCount;
   static readonly getIdentitiesByPublicKeyHashes: PlatformgetIdentitiesByPublicKeyHashes;
   static readonly getIdentityByPublicKeyHash: PlatformgetIdentityByPublicKeyHash;
   static readonly waitForStateTransitionResult: PlatformwaitForStateTransitionResult;
@@ -186,6 +225,7 @@ export class Platform {
   static readonly getProtocolVersionUpgradeState: PlatformgetProtocolVersionUpgradeState;
   static readonly getProtocolVersionUpgradeVoteStatus: PlatformgetProtocolVersionUpgradeVoteStatus;
   static readonly getEpochsInfo: PlatformgetEpochsInfo;
+  static readonly subscribePlatformEvents: PlatformsubscribePlatformEvents;
 }
 
 export type ServiceError = { message: string, code: number; metadata: grpc.Metadata }
@@ -229,6 +269,15 @@ export class PlatformClient {
     requestMessage: platform_pb.BroadcastStateTransitionRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.BroadcastStateTransitionResponse|null) => void
   ): UnaryResponse;
+  estimateStateTransitionFee(
+    requestMessage: platform_pb.EstimateStateTransitionFeeRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.EstimateStateTransitionFeeResponse|null) => void
+  ): UnaryResponse;
+  estimateStateTransitionFee(
+    requestMessage: platform_pb.EstimateStateTransitionFeeRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.EstimateStateTransitionFeeResponse|null) => void
+  ): UnaryResponse;
   getIdentity(
     requestMessage: platform_pb.GetIdentityRequest,
     metadata: grpc.Metadata,
@@ -274,6 +323,15 @@ export class PlatformClient {
     requestMessage: platform_pb.GetIdentityBalanceAndRevisionRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityBalanceAndRevisionResponse|null) => void
   ): UnaryResponse;
+  getIdentityNonce(
+    requestMessage: platform_pb.GetIdentityNonceRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityNonceResponse|null) => void
+  ): UnaryResponse;
+  getIdentityNonce(
+    requestMessage: platform_pb.GetIdentityNonceRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityNonceResponse|null) => void
+  ): UnaryResponse;
   getProofs(
     requestMessage: platform_pb.GetProofsRequest,
     metadata: grpc.Metadata,
@@ -319,6 +377,15 @@ export class PlatformClient {
     requestMessage: platform_pb.GetDocumentsRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsResponse|null) => void
   ): UnaryResponse;
+  getDocumentsCount(
+    requestMessage: platform_pb.GetDocumentsCountRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsCountResponse|null) => void
+  ): UnaryResponse;
+  getDocumentsCount(
+    requestMessage: platform_pb.GetDocumentsCountRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsCountResponse|null) => void
+  ): UnaryResponse;
   getIdentitiesByPublicKeyHashes(
     requestMessage: platform_pb.GetIdentitiesByPublicKeyHashesRequest,
     metadata: grpc.Metadata,
@@ -382,5 +449,6 @@ export class PlatformClient {
     requestMessage: platform_pb.GetEpochsInfoRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetEpochsInfoResponse|null) => void
   ): UnaryResponse;
+  subscribePlatformEvents(requestMessage: platform_pb.SubscribePlatformEventsRequest, metadata?: grpc.Metadata): ResponseStream<platform_pb.SubscribePlatformEventsResponse>;
 }
 
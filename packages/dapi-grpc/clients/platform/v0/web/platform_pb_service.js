@@ -19,6 +19,15 @@ Platform.broadcastStateTransition = {
   responseType: platform_pb.BroadcastStateTransitionResponse
 };
 
+Platform.estimateStateTransitionFee = {
+  methodName: "estimateStateTransitionFee",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.EstimateStateTransitionFeeRequest,
+  responseType: platform_pb.EstimateStateTransitionFeeResponse
+};
+
 Platform.getIdentity = {
   methodName: "getIdentity",
   service: Platform,
@@ -64,6 +73,15 @@ Platform.getIdentityBalanceAndRevision = {
   responseType: platform_pb.GetIdentityBalanceAndRevisionResponse
 };
 
+Platform.getIdentityNonce = {
+  methodName: "getIdentityNonce",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetIdentityNonceRequest,
+  responseType: platform_pb.GetIdentityNonceResponse
+};
+
 Platform.getProofs = {
   methodName: "getProofs",
   service: Platform,
@@ -109,6 +127,15 @@ Platform.getDocuments = {
   responseType: platform_pb.GetDocumentsResponse
 };
 
+Platform.getDocumentsCount = {
+  methodName: "getDocumentsCount",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetDocumentsCountRequest,
+  responseType: platform_pb.GetDocumentsCountResponse
+};
+
 Platform.getIdentitiesByPublicKeyHashes = {
   methodName: "getIdentitiesByPublicKeyHashes",
   service: Platform,
@@ -172,6 +199,15 @@ Platform.getEpochsInfo = {
   responseType: platform_pb.GetEpochsInfoResponse
 };
 
+Platform.subscribePlatformEvents = {
+  methodName: "subscribePlatformEvents",
+  service: Platform,
+  requestStream: false,
+  responseStream: true,
+  requestType: platform_pb.SubscribePlatformEventsRequest,
+  responseType: platform_pb.SubscribePlatformEventsResponse
+};
+
 exports.Platform = Platform;
 
 function PlatformClient(serviceHost, options) {
@@ -210,6 +246,37 @@ PlatformClient.prototype.broadcastStateTransition = function broadcastStateTrans
   };
 };
 
+PlatformClient.prototype.estimateStateTransitionFee = function estimateStateTransitionFee(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.estimateStateTransitionFee, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getIdentity = function getIdentity(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -365,6 +432,37 @@ PlatformClient.prototype.getIdentityBalanceAndRevision = function getIdentityBal
   };
 };
 
+PlatformClient.prototype.getIdentityNonce = function getIdentityNonce(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getIdentityNonce, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getProofs = function getProofs(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -520,6 +618,37 @@ PlatformClient.prototype.getDocuments = function getDocuments(requestMessage, me
   };
 };
 
+PlatformClient.prototype.getDocumentsCount = function getDocumentsCount(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getDocumentsCount, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getIdentitiesByPublicKeyHashes = function getIdentitiesByPublicKeyHashes(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -737,5 +866,44 @@ PlatformClient.prototype.getEpochsInfo = function getEpochsInfo(requestMessage,
   };
 };
 
+PlatformClient.prototype.subscribePlatformEvents = function subscribePlatformEvents(requestMessage, metadata) {
+  var listeners = {
+    data: [],
+    end: [],
+    status: []
+  };
+  var client = grpc.invoke(Platform.subscribePlatformEvents, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onMessage: function (responseMessage) {
+      listeners.data.forEach(function (handler) {
+        handler(responseMessage);
+      });
+    },
+    onEnd: function (status, statusMessage, trailers) {
+      listeners.status.forEach(function (handler) {
+        handler({ code: status, details: statusMessage, metadata: trailers });
+      });
+      listeners.end.forEach(function (handler) {
+        handler({ code: status, details: statusMessage, metadata: trailers });
+      });
+      listeners = null;
+    }
+  });
+  return {
+    on: function (type, handler) {
+      listeners[type].push(handler);
+      return this;
+    },
+    cancel: function () {
+      listeners = null;
+      client.close();
+    }
+  };
+};
+
 exports.PlatformClient = PlatformClient;
 
@@ -15,6 +15,7 @@ pub mod platform {
     #[cfg(feature = "tenderdash-proto")]
     pub use tenderdash_proto as proto;
 
+    mod conversions;
     mod versioning;
     pub use versioning::{VersionedGrpcMessage, VersionedGrpcResponse};
 }
@@ -0,0 +1,31 @@
+use super::v0::get_proofs_response::{get_proofs_response_v0, GetProofsResponseV0};
+use super::v0::wait_for_state_transition_result_response::{
+    wait_for_state_transition_result_response_v0, WaitForStateTransitionResultResponseV0,
+};
+use super::v0::{
+    get_proofs_response, wait_for_state_transition_result_response, GetProofsResponse,
+    WaitForStateTransitionResultResponse,
+};
+
+/// Proof returned by `waitForStateTransitionResult` is the proof `getProofs` returns for objects
+/// modified by the state transition, so both responses can be verified the same way.
+impl From<GetProofsResponse> for WaitForStateTransitionResultResponse {
+    fn from(value: GetProofsResponse) -> Self {
+        let version = value.version.map(|version| match version {
+            get_proofs_response::Version::V0(GetProofsResponseV0 { result, metadata }) => {
+                wait_for_state_transition_result_response::Version::V0(
+                    WaitForStateTransitionResultResponseV0 {
+                        result: result.map(|result| match result {
+                            get_proofs_response_v0::Result::Proof(proof) => {
+                                wait_for_state_transition_result_response_v0::Result::Proof(proof)
+                            }
+                        }),
+                        metadata,
+                    },
+                )
+            }
+        });
+
+        WaitForStateTransitionResultResponse { version }
+    }
+}
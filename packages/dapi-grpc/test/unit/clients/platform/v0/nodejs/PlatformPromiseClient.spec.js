@@ -12,9 +12,13 @@ describe('PlatformPromiseClient', () => {
     platformPromiseClient = new PlatformPromiseClient('https://localhost/');
     platformPromiseClient.client = {
       broadcastStateTransition: this.sinon.stub().resolves(response),
+      estimateStateTransitionFee: this.sinon.stub().resolves(response),
       getIdentity: this.sinon.stub().resolves(response),
+      getIdentityNonce: this.sinon.stub().resolves(response),
       getDataContract: this.sinon.stub().resolves(response),
       getDocuments: this.sinon.stub().resolves(response),
+      getDocumentsCount: this.sinon.stub().resolves(response),
+      subscribePlatformEvents: this.sinon.stub().returns(response),
       getEpochsInfo: this.sinon.stub().resolves(response),
       getProtocolVersionUpgradeVoteStatus: this.sinon.stub().resolves(response),
       getProtocolVersionUpgradeState: this.sinon.stub().resolves(response),
@@ -40,6 +44,26 @@ describe('PlatformPromiseClient', () => {
     });
   });
 
+  describe('#estimateStateTransitionFee', () => {
+    it('should estimate state transition fee', async () => {
+      const result = await platformPromiseClient.estimateStateTransitionFee(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.estimateStateTransitionFee)
+        .to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.estimateStateTransitionFee({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
   describe('#getIdentity', () => {
     it('should get identity', async () => {
       const result = await platformPromiseClient.getIdentity(request);
@@ -60,6 +84,26 @@ describe('PlatformPromiseClient', () => {
     });
   });
 
+  describe('#getIdentityNonce', () => {
+    it('should get identity nonce', async () => {
+      const result = await platformPromiseClient.getIdentityNonce(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.getIdentityNonce)
+        .to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.getIdentityNonce({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
   describe('#getDataContract', () => {
     it('should get data contract', async () => {
       const result = await platformPromiseClient.getDataContract(request);
@@ -88,6 +132,25 @@ describe('PlatformPromiseClient', () => {
     });
   });
 
+  describe('#getDocumentsCount', () => {
+    it('should get documents count', async () => {
+      const result = await platformPromiseClient.getDocumentsCount(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.getDocumentsCount).to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.getDocumentsCount({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
   describe('#getEpochsInfo', () => {
     it('should get epochs info', async () => {
       const result = await platformPromiseClient.getEpochsInfo(request);
@@ -116,4 +179,24 @@ describe('PlatformPromiseClient', () => {
         .to.be.calledOnceWith(request);
     });
   });
+
+  describe('#subscribePlatformEvents', () => {
+    it('should subscribe to platform events', () => {
+      const result = platformPromiseClient.subscribePlatformEvents(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.subscribePlatformEvents)
+        .to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', () => {
+      try {
+        platformPromiseClient.subscribePlatformEvents({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
 });
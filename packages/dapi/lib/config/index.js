@@ -15,6 +15,8 @@ const OPTIONS = {
   DASHCORE_P2P_NETWORK: 'DASHCORE_P2P_NETWORK',
   DRIVE_RPC_HOST: 'DRIVE_RPC_HOST',
   DRIVE_RPC_PORT: 'DRIVE_RPC_PORT',
+  DRIVE_GRPC_HOST: 'DRIVE_GRPC_HOST',
+  DRIVE_GRPC_PORT: 'DRIVE_GRPC_PORT',
   BLOCK_HEADERS_CACHE_SIZE: 'BLOCK_HEADERS_CACHE_SIZE',
   NETWORK: 'NETWORK',
   BLOOM_FILTER_PERSISTENCE_TIMEOUT: 'BLOOM_FILTER_PERSISTENCE_TIMEOUT',
@@ -40,6 +42,8 @@ DEFAULT_CONFIG[OPTIONS.DASHCORE_P2P_PORT] = 30001;
 DEFAULT_CONFIG[OPTIONS.DASHCORE_P2P_NETWORK] = 'testnet';
 DEFAULT_CONFIG[OPTIONS.DRIVE_RPC_HOST] = '127.0.0.1';
 DEFAULT_CONFIG[OPTIONS.DRIVE_RPC_PORT] = 6000;
+DEFAULT_CONFIG[OPTIONS.DRIVE_GRPC_HOST] = '127.0.0.1';
+DEFAULT_CONFIG[OPTIONS.DRIVE_GRPC_PORT] = 26670;
 DEFAULT_CONFIG[OPTIONS.BLOCK_HEADERS_CACHE_SIZE] = 500;
 DEFAULT_CONFIG[OPTIONS.NETWORK] = 'testnet';
 DEFAULT_CONFIG[OPTIONS.BLOOM_FILTER_PERSISTENCE_TIMEOUT] = 1000 * 60;
@@ -88,6 +92,12 @@ module.exports = {
   },
   network: config[OPTIONS.NETWORK].toLowerCase(),
   bloomFilterPersistenceTimeout: config[OPTIONS.BLOOM_FILTER_PERSISTENCE_TIMEOUT],
+  drive: {
+    grpc: {
+      host: config[OPTIONS.DRIVE_GRPC_HOST],
+      port: parseInt(config[OPTIONS.DRIVE_GRPC_PORT], 10),
+    },
+  },
   tendermintCore: {
     host: config[OPTIONS.TENDERMINT_RPC_HOST],
     port: parseInt(config[OPTIONS.TENDERMINT_RPC_PORT], 10),
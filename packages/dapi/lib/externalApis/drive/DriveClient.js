@@ -104,6 +104,34 @@ class DriveClient {
     );
   }
 
+  /**
+   * Fetch serialized documents count or sum
+   *
+   * @param {GetDocumentsCountRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchDocumentsCount(request) {
+    return this.request(
+      '/documents/count',
+      request.serializeBinary(),
+    );
+  }
+
+  /**
+   * Fetch serialized fee estimate of a state transition
+   *
+   * @param {EstimateStateTransitionFeeRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchStateTransitionFeeEstimate(request) {
+    return this.request(
+      '/stateTransition/estimate',
+      request.serializeBinary(),
+    );
+  }
+
   /**
    * Fetch serialized identity
    *
@@ -160,6 +188,20 @@ class DriveClient {
     );
   }
 
+  /**
+   * Fetch serialized identity nonce
+   *
+   * @param {GetIdentityNonceRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchIdentityNonce(request) {
+    return this.request(
+      '/identity/nonce',
+      request.serializeBinary(),
+    );
+  }
+
   /**
    * Fetch serialized identity keys
    *
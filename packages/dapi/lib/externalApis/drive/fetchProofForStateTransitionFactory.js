@@ -48,6 +48,15 @@ function fetchProofForStateTransitionFactory(driveClient) {
         return identityRequest;
       });
 
+      // The sender's nonce proves that the transition was executed and its balance was debited
+      if (stateTransition.getType() === StateTransitionTypes.IdentityCreditTransfer
+        || stateTransition.getType() === StateTransitionTypes.IdentityCreditWithdrawal) {
+        const nonceRequest = new IdentityRequest();
+        nonceRequest.setIdentityId(modifiedIds[0].toBuffer());
+        nonceRequest.setRequestType(IdentityRequest.Type.NONCE);
+        identitiesList.push(nonceRequest);
+      }
+
       getProofsRequest.setV0(new GetProofsRequestV0().setIdentitiesList(identitiesList));
     } if (stateTransition.isDataContractStateTransition()) {
       const { ContractRequest } = GetProofsRequestV0;
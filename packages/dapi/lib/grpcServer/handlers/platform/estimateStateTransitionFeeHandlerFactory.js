@@ -0,0 +1,46 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    EstimateStateTransitionFeeResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @return {estimateStateTransitionFeeHandler}
+ */
+function estimateStateTransitionFeeHandlerFactory(driveClient) {
+  /**
+   * @typedef estimateStateTransitionFeeHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<EstimateStateTransitionFeeResponse>}
+   */
+  async function estimateStateTransitionFeeHandler(call) {
+    const { request } = call;
+
+    const stByteArray = request.getV0().getStateTransition();
+
+    if (!stByteArray || stByteArray.length === 0) {
+      throw new InvalidArgumentGrpcError('State Transition is not specified');
+    }
+
+    const feeEstimationResponseBuffer = await driveClient
+      .fetchStateTransitionFeeEstimate(request);
+
+    return EstimateStateTransitionFeeResponse.deserializeBinary(feeEstimationResponseBuffer);
+  }
+
+  return estimateStateTransitionFeeHandler;
+}
+
+module.exports = estimateStateTransitionFeeHandlerFactory;
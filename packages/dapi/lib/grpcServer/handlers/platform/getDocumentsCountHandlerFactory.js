@@ -0,0 +1,50 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetDocumentsCountResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ *
+ * @param {DriveClient} driveClient
+ *
+ * @returns {getDocumentsCountHandler}
+ */
+function getDocumentsCountHandlerFactory(driveClient) {
+  /**
+   * @typedef getDocumentsCountHandler
+   *
+   * @param {Object} call
+   *
+   * @returns {Promise<GetDocumentsCountResponse>}
+   */
+  async function getDocumentsCountHandler(call) {
+    const { request } = call;
+
+    if (!request.getV0().getDataContractId()) {
+      throw new InvalidArgumentGrpcError('dataContractId is not specified');
+    }
+
+    if (!request.getV0().getDocumentType()) {
+      throw new InvalidArgumentGrpcError('documentType is not specified');
+    }
+
+    const documentsCountResponseBuffer = await driveClient.fetchDocumentsCount(
+      request,
+    );
+
+    return GetDocumentsCountResponse.deserializeBinary(documentsCountResponseBuffer);
+  }
+
+  return getDocumentsCountHandler;
+}
+
+module.exports = getDocumentsCountHandlerFactory;
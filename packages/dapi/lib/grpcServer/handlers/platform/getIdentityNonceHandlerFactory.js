@@ -0,0 +1,44 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetIdentityNonceResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @return {getIdentityNonceHandler}
+ */
+function getIdentityNonceHandlerFactory(driveClient) {
+  /**
+   * @typedef getIdentityNonceHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<GetIdentityNonceResponse>}
+   */
+  async function getIdentityNonceHandler(call) {
+    const { request } = call;
+
+    if (!request.getV0().getId()) {
+      throw new InvalidArgumentGrpcError('identity id is not specified');
+    }
+
+    const identityNonceResponseBuffer = await driveClient
+      .fetchIdentityNonce(request);
+
+    return GetIdentityNonceResponse.deserializeBinary(identityNonceResponseBuffer);
+  }
+
+  return getIdentityNonceHandler;
+}
+
+module.exports = getIdentityNonceHandlerFactory;
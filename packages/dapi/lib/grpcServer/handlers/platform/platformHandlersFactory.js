@@ -16,12 +16,15 @@ const {
 const {
   v0: {
     BroadcastStateTransitionRequest,
+    EstimateStateTransitionFeeRequest,
     GetIdentityRequest,
     GetIdentityKeysRequest,
+    GetIdentityNonceRequest,
     GetDataContractRequest,
     GetDataContractsRequest,
     GetDataContractHistoryRequest,
     GetDocumentsRequest,
+    GetDocumentsCountRequest,
     GetIdentitiesRequest,
     GetIdentitiesByPublicKeyHashesRequest,
     GetIdentityByPublicKeyHashRequest,
@@ -31,9 +34,12 @@ const {
     GetEpochsInfoRequest,
     GetProtocolVersionUpgradeVoteStatusRequest,
     GetProtocolVersionUpgradeStateRequest,
+    SubscribePlatformEventsRequest,
     pbjs: {
       BroadcastStateTransitionRequest: PBJSBroadcastStateTransitionRequest,
       BroadcastStateTransitionResponse: PBJSBroadcastStateTransitionResponse,
+      EstimateStateTransitionFeeRequest: PBJSEstimateStateTransitionFeeRequest,
+      EstimateStateTransitionFeeResponse: PBJSEstimateStateTransitionFeeResponse,
       GetIdentityRequest: PBJSGetIdentityRequest,
       GetIdentityResponse: PBJSGetIdentityResponse,
       GetIdentitiesRequest: PBJSGetIdentitiesRequest,
@@ -42,12 +48,16 @@ const {
       GetIdentityBalanceAndRevisionResponse: PBJSGetIdentityBalanceAndRevisionResponse,
       GetIdentityKeysRequest: PBJSGetIdentityKeysRequest,
       GetIdentityKeysResponse: PBJSGetIdentityKeysResponse,
+      GetIdentityNonceRequest: PBJSGetIdentityNonceRequest,
+      GetIdentityNonceResponse: PBJSGetIdentityNonceResponse,
       GetDataContractRequest: PBJSGetDataContractRequest,
       GetDataContractResponse: PBJSGetDataContractResponse,
       GetDataContractsRequest: PBJSGetDataContractsRequest,
       GetDataContractsResponse: PBJSGetDataContractsResponse,
       GetDocumentsRequest: PBJSGetDocumentsRequest,
       GetDocumentsResponse: PBJSGetDocumentsResponse,
+      GetDocumentsCountRequest: PBJSGetDocumentsCountRequest,
+      GetDocumentsCountResponse: PBJSGetDocumentsCountResponse,
       GetIdentitiesByPublicKeyHashesResponse: PBJSGetIdentitiesByPublicKeyHashesResponse,
       GetIdentitiesByPublicKeyHashesRequest: PBJSGetIdentitiesByPublicKeyHashesRequest,
       GetIdentityByPublicKeyHashResponse: PBJSGetIdentityByPublicKeyHashResponse,
@@ -66,6 +76,8 @@ const {
       GetProtocolVersionUpgradeVoteStatusResponse: PBJSGetProtocolVersionUpgradeVoteStatusResponse,
       GetProtocolVersionUpgradeStateRequest: PBJSGetProtocolVersionUpgradeStateRequest,
       GetProtocolVersionUpgradeStateResponse: PBJSGetProtocolVersionUpgradeStateResponse,
+      SubscribePlatformEventsRequest: PBJSSubscribePlatformEventsRequest,
+      SubscribePlatformEventsResponse: PBJSSubscribePlatformEventsResponse,
     },
   },
 } = require('@dashevo/dapi-grpc');
@@ -89,12 +101,21 @@ const getIdentityKeysHandlerFactory = require(
 const getIdentityBalanceAndRevisionHandlerFactory = require(
   './getIdentityBalanceAndRevisionHandlerFactory',
 );
+const getIdentityNonceHandlerFactory = require(
+  './getIdentityNonceHandlerFactory',
+);
 const broadcastStateTransitionHandlerFactory = require(
   './broadcastStateTransitionHandlerFactory',
 );
+const estimateStateTransitionFeeHandlerFactory = require(
+  './estimateStateTransitionFeeHandlerFactory',
+);
 const getDocumentsHandlerFactory = require(
   './getDocumentsHandlerFactory',
 );
+const getDocumentsCountHandlerFactory = require(
+  './getDocumentsCountHandlerFactory',
+);
 const getDataContractHandlerFactory = require(
   './getDataContractHandlerFactory',
 );
@@ -131,6 +152,10 @@ const getProtocolVersionUpgradeStateHandlerFactory = require(
   './getProtocolVersionUpgradeStateHandlerFactory',
 );
 
+const subscribePlatformEventsHandlerFactory = require(
+  './subscribePlatformEventsHandlerFactory',
+);
+
 const fetchProofForStateTransitionFactory = require('../../../externalApis/drive/fetchProofForStateTransitionFactory');
 const waitForTransactionToBeProvableFactory = require('../../../externalApis/tenderdash/waitForTransactionToBeProvable/waitForTransactionToBeProvableFactory');
 const waitForTransactionResult = require('../../../externalApis/tenderdash/waitForTransactionToBeProvable/waitForTransactionResult');
@@ -141,6 +166,7 @@ const getConsensusParamsFactory = require('../../../externalApis/tenderdash/getC
  * @param {jaysonClient} rpcClient
  * @param {BlockchainListener} blockchainListener
  * @param {DriveClient} driveClient
+ * @param {PlatformPromiseClient} driveGrpcClient
  * @param {DashPlatformProtocol} dpp
  * @param {boolean} isProductionEnvironment
  * @returns {Object<string, function>}
@@ -149,6 +175,7 @@ function platformHandlersFactory(
   rpcClient,
   blockchainListener,
   driveClient,
+  driveGrpcClient,
   dpp,
   isProductionEnvironment,
 ) {
@@ -171,6 +198,22 @@ function platformHandlersFactory(
     wrapInErrorHandler(broadcastStateTransitionHandler),
   );
 
+  // estimateStateTransitionFee
+  const estimateStateTransitionFeeHandler = estimateStateTransitionFeeHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedEstimateStateTransitionFee = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      EstimateStateTransitionFeeRequest,
+      PBJSEstimateStateTransitionFeeRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSEstimateStateTransitionFeeResponse,
+    ),
+    wrapInErrorHandler(estimateStateTransitionFeeHandler),
+  );
+
   // getIdentity
   const getIdentityHandler = getIdentityHandlerFactory(
     driveClient,
@@ -251,6 +294,22 @@ function platformHandlersFactory(
     wrapInErrorHandler(getIdentityKeysHandler),
   );
 
+  // getIdentityNonce
+  const getIdentityNonceHandler = getIdentityNonceHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedGetIdentityNonce = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      GetIdentityNonceRequest,
+      PBJSGetIdentityNonceRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSGetIdentityNonceResponse,
+    ),
+    wrapInErrorHandler(getIdentityNonceHandler),
+  );
+
   // getDocuments
   const getDocumentsHandler = getDocumentsHandlerFactory(
     driveClient,
@@ -267,6 +326,22 @@ function platformHandlersFactory(
     wrapInErrorHandler(getDocumentsHandler),
   );
 
+  // getDocumentsCount
+  const getDocumentsCountHandler = getDocumentsCountHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedGetDocumentsCount = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      GetDocumentsCountRequest,
+      PBJSGetDocumentsCountRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSGetDocumentsCountResponse,
+    ),
+    wrapInErrorHandler(getDocumentsCountHandler),
+  );
+
   // getDataContract
   const getDataContractHandler = getDataContractHandlerFactory(
     driveClient,
@@ -458,14 +533,33 @@ function platformHandlersFactory(
     wrapInErrorHandler(getProtocolVersionUpgradeStateHandler),
   );
 
+  // subscribePlatformEvents
+  const subscribePlatformEventsHandler = subscribePlatformEventsHandlerFactory(
+    driveGrpcClient,
+  );
+
+  const wrappedSubscribePlatformEvents = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      SubscribePlatformEventsRequest,
+      PBJSSubscribePlatformEventsRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSSubscribePlatformEventsResponse,
+    ),
+    wrapInErrorHandler(subscribePlatformEventsHandler),
+  );
+
   return {
     broadcastStateTransition: wrappedBroadcastStateTransition,
+    estimateStateTransitionFee: wrappedEstimateStateTransitionFee,
     getIdentity: wrappedGetIdentity,
     getIdentities: wrappedGetIdentities,
     getIdentityBalance: wrappedGetIdentityBalance,
     getIdentityBalanceAndRevision: wrappedGetIdentityBalanceAndRevision,
     getIdentityKeys: wrappedGetIdentityKeys,
+    getIdentityNonce: wrappedGetIdentityNonce,
     getDocuments: wrappedGetDocuments,
+    getDocumentsCount: wrappedGetDocumentsCount,
     getDataContract: wrappedGetDataContract,
     getDataContracts: wrappedGetDataContracts,
     getDataContractHistory: wrappedGetDataContractHistory,
@@ -477,6 +571,7 @@ function platformHandlersFactory(
     getEpochsInfo: wrappedGetEpochsInfo,
     getProtocolVersionUpgradeVoteStatus: wrappedGetProtocolVersionUpgradeVoteStatus,
     getProtocolVersionUpgradeState: wrappedGetProtocolVersionUpgradeState,
+    subscribePlatformEvents: wrappedSubscribePlatformEvents,
   };
 }
 
@@ -0,0 +1,79 @@
+const {
+  server: {
+    error: {
+      GrpcError,
+    },
+    stream: {
+      AcknowledgingWritable,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const GrpcErrorCodes = require('@dashevo/grpc-common/lib/server/error/GrpcErrorCodes');
+
+/**
+ * @param {PlatformPromiseClient} driveGrpcClient - gRPC client of the Drive query server
+ *
+ * @return {subscribePlatformEventsHandler}
+ */
+function subscribePlatformEventsHandlerFactory(driveGrpcClient) {
+  /**
+   * Relay the events of committed blocks streamed by Drive to the client
+   *
+   * @typedef subscribePlatformEventsHandler
+   *
+   * @param {grpc.ServerWriteableStream<SubscribePlatformEventsRequest>} call
+   */
+  async function subscribePlatformEventsHandler(call) {
+    const { request } = call;
+
+    const acknowledgingCall = new AcknowledgingWritable(call);
+
+    const eventsStream = driveGrpcClient.subscribePlatformEvents(request);
+
+    let isCancelled = false;
+
+    eventsStream.on('data', async (response) => {
+      // Do not read more events until the client received the previous ones
+      eventsStream.pause();
+
+      try {
+        await acknowledgingCall.write(response);
+      } catch (e) {
+        isCancelled = true;
+        eventsStream.cancel();
+
+        return;
+      }
+
+      eventsStream.resume();
+    });
+
+    eventsStream.on('error', (e) => {
+      if (isCancelled && e.code === GrpcErrorCodes.CANCELLED) {
+        return;
+      }
+
+      call.destroy(new GrpcError(
+        e.code,
+        e.details || e.message,
+        e.metadata ? e.metadata.getMap() : undefined,
+      ));
+    });
+
+    eventsStream.on('end', () => {
+      call.end();
+    });
+
+    call.on('cancelled', () => {
+      isCancelled = true;
+      eventsStream.cancel();
+
+      call.end();
+    });
+  }
+
+  return subscribePlatformEventsHandler;
+}
+
+module.exports = subscribePlatformEventsHandlerFactory;
@@ -12,6 +12,9 @@ const {
 const {
   getCoreDefinition,
   getPlatformDefinition,
+  v0: {
+    PlatformPromiseClient,
+  },
 } = require('@dashevo/dapi-grpc');
 
 const { default: loadWasmDpp, DashPlatformProtocol } = require('@dashevo/wasm-dpp');
@@ -59,6 +62,11 @@ async function main() {
     port: config.tendermintCore.port,
   });
 
+  const driveGrpcClient = new PlatformPromiseClient(
+    `http://${config.drive.grpc.host}:${config.drive.grpc.port}`,
+    undefined,
+  );
+
   const rpcClient = RpcClient.http({
     host: config.tendermintCore.host,
     port: config.tendermintCore.port,
@@ -110,6 +118,7 @@ async function main() {
     rpcClient,
     blockchainListener,
     driveClient,
+    driveGrpcClient,
     dpp,
     isProductionEnvironment,
   );
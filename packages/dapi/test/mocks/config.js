@@ -15,6 +15,12 @@ module.exports = {
           host: '123',
         },
       },
+      drive: {
+        grpc: {
+          host: '123',
+          port: '123',
+        },
+      },
       tendermintCore: {
         host: '123',
         port: '123',
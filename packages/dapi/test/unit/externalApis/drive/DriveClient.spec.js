@@ -12,16 +12,22 @@ const InvalidArgumentGrpcError = require('@dashevo/grpc-common/lib/server/error/
 const GrpcErrorCodes = require('@dashevo/grpc-common/lib/server/error/GrpcErrorCodes');
 const {
   v0: {
+    EstimateStateTransitionFeeRequest,
+    EstimateStateTransitionFeeResponse,
     GetIdentitiesByPublicKeyHashesRequest,
     GetIdentitiesByPublicKeyHashesResponse,
     GetDataContractRequest,
     GetDataContractResponse,
     GetDocumentsRequest,
     GetDocumentsResponse,
+    GetDocumentsCountRequest,
+    GetDocumentsCountResponse,
     GetEpochsInfoRequest,
     GetEpochsInfoResponse,
     GetIdentityRequest,
     GetIdentityResponse,
+    GetIdentityNonceRequest,
+    GetIdentityNonceResponse,
     GetProofsRequest,
     GetProofsResponse,
     GetProtocolVersionUpgradeStateRequest,
@@ -186,6 +192,88 @@ describe('DriveClient', () => {
     });
   });
 
+  describe('#fetchDocumentsCount', () => {
+    it('Should call \'fetchDocumentsCount\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const contractId = 'someId';
+      const type = 'object';
+      const options = {
+        where: 'id === someId',
+      };
+
+      const { GetDocumentsCountRequestV0 } = GetDocumentsCountRequest;
+      const request = new GetDocumentsCountRequest();
+      request.setV0(
+        new GetDocumentsCountRequestV0()
+          .setDataContractId(contractId)
+          .setDocumentType(type)
+          .setWhere(cbor.encode({ where: options.where })),
+      );
+
+      const { GetDocumentsCountResponseV0 } = GetDocumentsCountResponse;
+      const response = new GetDocumentsCountResponse();
+      response.setV0(
+        new GetDocumentsCountResponseV0()
+          .setValue(3),
+      );
+
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchDocumentsCount(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/documents/count',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
+  describe('#fetchStateTransitionFeeEstimate', () => {
+    it('Should call \'fetchStateTransitionFeeEstimate\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const { EstimateStateTransitionFeeRequestV0 } = EstimateStateTransitionFeeRequest;
+      const request = new EstimateStateTransitionFeeRequest();
+      request.setV0(
+        new EstimateStateTransitionFeeRequestV0()
+          .setStateTransition(Buffer.from('0300', 'hex')),
+      );
+
+      const { EstimateStateTransitionFeeResponseV0 } = EstimateStateTransitionFeeResponse;
+      const response = new EstimateStateTransitionFeeResponse();
+      response.setV0(
+        new EstimateStateTransitionFeeResponseV0()
+          .setStorageFee(1000)
+          .setProcessingFee(200),
+      );
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchStateTransitionFeeEstimate(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/stateTransition/estimate',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
   describe('#fetchIdentity', () => {
     it('Should call \'fetchIdentity\' RPC with the given parameters', async () => {
       const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
@@ -225,6 +313,44 @@ describe('DriveClient', () => {
     });
   });
 
+  describe('#fetchIdentityNonce', () => {
+    it('Should call \'fetchIdentityNonce\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const identityId = 'someId';
+
+      const { GetIdentityNonceRequestV0 } = GetIdentityNonceRequest;
+      const request = new GetIdentityNonceRequest();
+      request.setV0(
+        new GetIdentityNonceRequestV0()
+          .setId(identityId),
+      );
+
+      const { GetIdentityNonceResponseV0 } = GetIdentityNonceResponse;
+      const response = new GetIdentityNonceResponse();
+      response.setV0(
+        new GetIdentityNonceResponseV0()
+          .setIdentityNonce(5),
+      );
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchIdentityNonce(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/identity/nonce',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
   describe('#fetchIdentitiesByPublicKeyHashes', () => {
     it('Should call \'fetchIdentitiesByPublicKeyHashes\' RPC with the given parameters', async () => {
       const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
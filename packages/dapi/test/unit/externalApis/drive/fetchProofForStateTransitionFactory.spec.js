@@ -2,6 +2,7 @@ const { expect } = require('chai');
 
 const {
   v0: {
+    GetProofsRequest,
     GetProofsResponse,
     Proof,
   },
@@ -65,6 +66,24 @@ describe('fetchProofForStateTransition', () => {
       .equal(identitiesProofResponse.serializeBinary());
   });
 
+  it('should fetch sender nonce proof for credit transfer', async () => {
+    stateTransitionFixture.isIdentityStateTransition.returns(true);
+    stateTransitionFixture.getType.returns(StateTransitionTypes.IdentityCreditTransfer);
+    await fetchProofForStateTransition(stateTransitionFixture);
+
+    const { IdentityRequest } = GetProofsRequest.GetProofsRequestV0;
+    const [senderId] = stateTransitionFixture.getModifiedDataIds();
+    const identitiesList = driveClientMock.fetchProofs.getCall(0).args[0]
+      .getV0().getIdentitiesList();
+
+    expect(identitiesList.map((request) => request.getRequestType())).to.deep.equal([
+      IdentityRequest.Type.BALANCE,
+      IdentityRequest.Type.BALANCE,
+      IdentityRequest.Type.NONCE,
+    ]);
+    expect(Buffer.from(identitiesList[2].getIdentityId())).to.deep.equal(senderId.toBuffer());
+  });
+
   it('should fetch data contract proofs', async () => {
     stateTransitionFixture.isDataContractStateTransition.returns(true);
     const result = await fetchProofForStateTransition(stateTransitionFixture);
@@ -0,0 +1,110 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    EstimateStateTransitionFeeResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const {
+  EstimateStateTransitionFeeResponseV0,
+} = EstimateStateTransitionFeeResponse;
+
+/* eslint-disable import/no-extraneous-dependencies */
+const generateRandomIdentifierAsync = require('@dashevo/wasm-dpp/lib/test/utils/generateRandomIdentifierAsync');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const estimateStateTransitionFeeHandlerFactory = require('../../../../../lib/grpcServer/handlers/platform/estimateStateTransitionFeeHandlerFactory');
+
+describe('estimateStateTransitionFeeHandlerFactory', () => {
+  let call;
+  let estimateStateTransitionFeeHandler;
+  let request;
+  let stateTransitionBytes;
+  let fetchStateTransitionFeeEstimateMock;
+
+  beforeEach(async function beforeEach() {
+    stateTransitionBytes = Buffer.from('0300', 'hex');
+
+    request = {
+      getStateTransition: this.sinon.stub().returns(stateTransitionBytes),
+    };
+
+    call = new GrpcCallMock(this.sinon, {
+      getV0: () => request,
+    });
+
+    fetchStateTransitionFeeEstimateMock = this.sinon.stub();
+
+    estimateStateTransitionFeeHandler = estimateStateTransitionFeeHandlerFactory({
+      fetchStateTransitionFeeEstimate: fetchStateTransitionFeeEstimateMock,
+    });
+  });
+
+  it('should return fee estimate', async () => {
+    const identityId = await generateRandomIdentifierAsync();
+
+    const response = new EstimateStateTransitionFeeResponse()
+      .setV0(
+        new EstimateStateTransitionFeeResponseV0()
+          .setStorageFee(1000)
+          .setProcessingFee(200)
+          .setFeeRefundsList([
+            new EstimateStateTransitionFeeResponseV0.FeeRefund()
+              .setIdentityId(identityId.toBuffer())
+              .setCredits(50),
+          ]),
+      );
+
+    fetchStateTransitionFeeEstimateMock.resolves(response.serializeBinary());
+
+    const result = await estimateStateTransitionFeeHandler(call);
+
+    expect(result).to.be.an.instanceOf(EstimateStateTransitionFeeResponse);
+
+    const estimate = result.getV0();
+
+    expect(estimate.getStorageFee()).to.equal(1000);
+    expect(estimate.getProcessingFee()).to.equal(200);
+    expect(estimate.getFeeRefundsList()).to.have.lengthOf(1);
+    expect(estimate.getFeeRefundsList()[0].getCredits()).to.equal(50);
+    expect(estimate.getErrorsList()).to.be.empty();
+
+    expect(fetchStateTransitionFeeEstimateMock).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError if state transition is not specified', async () => {
+    request.getStateTransition.returns(null);
+
+    try {
+      await estimateStateTransitionFeeHandler(call);
+
+      expect.fail('should throw InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('State Transition is not specified');
+      expect(fetchStateTransitionFeeEstimateMock).to.be.not.called();
+    }
+  });
+
+  it('should throw error if fetchStateTransitionFeeEstimate throws an error', async () => {
+    const error = new Error('Some error');
+
+    fetchStateTransitionFeeEstimateMock.throws(error);
+
+    try {
+      await estimateStateTransitionFeeHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(error);
+    }
+  });
+});
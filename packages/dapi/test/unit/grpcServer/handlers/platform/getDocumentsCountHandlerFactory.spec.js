@@ -0,0 +1,162 @@
+const cbor = require('cbor');
+
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetDocumentsCountResponse,
+    Proof,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/* eslint-disable import/no-extraneous-dependencies */
+const generateRandomIdentifierAsync = require('@dashevo/wasm-dpp/lib/test/utils/generateRandomIdentifierAsync');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const getDocumentsCountHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/getDocumentsCountHandlerFactory',
+);
+
+describe('getDocumentsCountHandlerFactory', () => {
+  let call;
+  let getDocumentsCountHandler;
+  let driveStateRepositoryMock;
+  let request;
+  let dataContractId;
+  let documentType;
+  let where;
+  let proofFixture;
+  let response;
+  let proofMock;
+  let proofResponse;
+
+  beforeEach(async function beforeEach() {
+    dataContractId = await generateRandomIdentifierAsync();
+    documentType = 'document';
+    where = [['name', '==', 'John']];
+
+    request = {
+      getDataContractId: this.sinon.stub().returns(dataContractId),
+      getDocumentType: this.sinon.stub().returns(documentType),
+      getWhere_asU8: this.sinon.stub().returns(new Uint8Array(cbor.encode(where))),
+      getSumProperty: this.sinon.stub().returns(''),
+      getProve: this.sinon.stub().returns(false),
+    };
+
+    call = new GrpcCallMock(this.sinon, {
+      getV0: () => request,
+    });
+
+    proofFixture = {
+      merkleProof: Buffer.alloc(1, 1),
+    };
+
+    proofMock = new Proof();
+    proofMock.setGrovedbProof(proofFixture.merkleProof);
+
+    const { GetDocumentsCountResponseV0 } = GetDocumentsCountResponse;
+    response = new GetDocumentsCountResponse();
+    response.setV0(
+      new GetDocumentsCountResponseV0().setValue(42),
+    );
+
+    proofResponse = new GetDocumentsCountResponse();
+    proofResponse.setV0(
+      new GetDocumentsCountResponseV0().setProof(proofMock),
+    );
+
+    driveStateRepositoryMock = {
+      fetchDocumentsCount: this.sinon.stub().resolves(response.serializeBinary()),
+    };
+
+    getDocumentsCountHandler = getDocumentsCountHandlerFactory(
+      driveStateRepositoryMock,
+    );
+  });
+
+  it('should return valid result', async () => {
+    const result = await getDocumentsCountHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetDocumentsCountResponse);
+    expect(result.getV0().getValue()).to.equal(42);
+
+    expect(driveStateRepositoryMock.fetchDocumentsCount).to.be.calledOnceWith(
+      call.request,
+    );
+
+    const proof = result.getV0().getProof();
+
+    expect(proof).to.be.undefined();
+  });
+
+  it('should return proof', async () => {
+    request.getProve.returns(true);
+    driveStateRepositoryMock.fetchDocumentsCount.resolves(proofResponse.serializeBinary());
+
+    const result = await getDocumentsCountHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetDocumentsCountResponse);
+
+    expect(driveStateRepositoryMock.fetchDocumentsCount).to.be.calledOnceWith(
+      call.request,
+    );
+
+    const proof = result.getV0().getProof();
+
+    expect(proof).to.be.an.instanceOf(Proof);
+    const merkleProof = proof.getGrovedbProof();
+
+    expect(merkleProof).to.deep.equal(proofFixture.merkleProof);
+  });
+
+  it('should throw InvalidArgumentGrpcError if dataContractId is not specified', async () => {
+    dataContractId = null;
+    request.getDataContractId.returns(dataContractId);
+
+    try {
+      await getDocumentsCountHandler(call);
+
+      expect.fail('should throw InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('dataContractId is not specified');
+      expect(driveStateRepositoryMock.fetchDocumentsCount).to.be.not.called();
+    }
+  });
+
+  it('should throw InvalidArgumentGrpcError if documentType is not specified', async () => {
+    documentType = null;
+    request.getDocumentType.returns(documentType);
+
+    try {
+      await getDocumentsCountHandler(call);
+
+      expect.fail('should throw InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('documentType is not specified');
+      expect(driveStateRepositoryMock.fetchDocumentsCount).to.be.not.called();
+    }
+  });
+
+  it('should throw error if fetchDocumentsCount throws an error', async () => {
+    const error = new Error('Some error');
+
+    driveStateRepositoryMock.fetchDocumentsCount.throws(error);
+
+    try {
+      await getDocumentsCountHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(error);
+    }
+  });
+});
@@ -0,0 +1,122 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetIdentityNonceResponse,
+    Proof,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const {
+  GetIdentityNonceResponseV0,
+} = GetIdentityNonceResponse;
+
+/* eslint-disable import/no-extraneous-dependencies */
+const generateRandomIdentifierAsync = require('@dashevo/wasm-dpp/lib/test/utils/generateRandomIdentifierAsync');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const getIdentityNonceHandlerFactory = require('../../../../../lib/grpcServer/handlers/platform/getIdentityNonceHandlerFactory');
+
+describe('getIdentityNonceHandlerFactory', () => {
+  let call;
+  let getIdentityNonceHandler;
+  let request;
+  let id;
+  let fetchIdentityNonceMock;
+
+  beforeEach(async function beforeEach() {
+    id = await generateRandomIdentifierAsync();
+    request = {
+      getId: this.sinon.stub().returns(id),
+      getProve: this.sinon.stub().returns(true),
+    };
+
+    call = new GrpcCallMock(this.sinon, {
+      getV0: () => request,
+    });
+
+    fetchIdentityNonceMock = this.sinon.stub();
+
+    getIdentityNonceHandler = getIdentityNonceHandlerFactory({
+      fetchIdentityNonce: fetchIdentityNonceMock,
+    });
+  });
+
+  it('should return identity nonce', async () => {
+    const response = new GetIdentityNonceResponse()
+      .setV0(new GetIdentityNonceResponseV0().setIdentityNonce(15));
+
+    fetchIdentityNonceMock.resolves(response.serializeBinary());
+
+    const result = await getIdentityNonceHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetIdentityNonceResponse);
+
+    const identityNonce = result.getV0().getIdentityNonce();
+
+    expect(identityNonce).to.deep.equal(15);
+  });
+
+  it('should return proof', async () => {
+    const proofFixture = {
+      merkleProof: Buffer.alloc(1, 1),
+    };
+
+    const proofMock = new Proof();
+    proofMock.setGrovedbProof(proofFixture.merkleProof);
+
+    const response = new GetIdentityNonceResponse()
+      .setV0(new GetIdentityNonceResponseV0().setProof(proofMock));
+
+    fetchIdentityNonceMock.resolves(response.serializeBinary());
+
+    const result = await getIdentityNonceHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetIdentityNonceResponse);
+
+    const proof = result.getV0().getProof();
+
+    expect(proof).to.be.an.instanceOf(Proof);
+    const merkleProof = proof.getGrovedbProof();
+
+    expect(merkleProof).to.deep.equal(proofFixture.merkleProof);
+
+    expect(fetchIdentityNonceMock).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError error if ids are not specified', async () => {
+    request.getId.returns(null);
+
+    try {
+      await getIdentityNonceHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('identity id is not specified');
+      expect(fetchIdentityNonceMock).to.be.not.called();
+    }
+  });
+
+  it('should throw error if driveStateRepository throws an error', async () => {
+    const message = 'Some error';
+    const abciResponseError = new Error(message);
+
+    fetchIdentityNonceMock.throws(abciResponseError);
+
+    try {
+      await getIdentityNonceHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(abciResponseError);
+    }
+  });
+});
@@ -0,0 +1,119 @@
+const { EventEmitter } = require('events');
+
+const {
+  server: {
+    error: {
+      GrpcError,
+    },
+    stream: {
+      AcknowledgingWritable,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const GrpcErrorCodes = require('@dashevo/grpc-common/lib/server/error/GrpcErrorCodes');
+
+const {
+  v0: {
+    SubscribePlatformEventsRequest,
+    SubscribePlatformEventsResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const subscribePlatformEventsHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/subscribePlatformEventsHandlerFactory',
+);
+
+describe('subscribePlatformEventsHandlerFactory', () => {
+  let call;
+  let request;
+  let eventsStream;
+  let driveGrpcClientMock;
+  let subscribePlatformEventsHandler;
+
+  beforeEach(function beforeEach() {
+    const { SubscribePlatformEventsRequestV0 } = SubscribePlatformEventsRequest;
+    request = new SubscribePlatformEventsRequest();
+    request.setV0(new SubscribePlatformEventsRequestV0());
+
+    call = new GrpcCallMock(this.sinon, request);
+    call.destroy = this.sinon.stub();
+
+    eventsStream = new EventEmitter();
+    eventsStream.pause = this.sinon.stub();
+    eventsStream.resume = this.sinon.stub();
+    eventsStream.cancel = this.sinon.stub();
+
+    driveGrpcClientMock = {
+      subscribePlatformEvents: this.sinon.stub().returns(eventsStream),
+    };
+
+    subscribePlatformEventsHandler = subscribePlatformEventsHandlerFactory(
+      driveGrpcClientMock,
+    );
+  });
+
+  it('should relay events streamed by Drive', async function it() {
+    this.sinon.stub(AcknowledgingWritable.prototype, 'write').resolves(true);
+
+    await subscribePlatformEventsHandler(call);
+
+    expect(driveGrpcClientMock.subscribePlatformEvents).to.be.calledOnceWith(request);
+
+    const { SubscribePlatformEventsResponseV0 } = SubscribePlatformEventsResponse;
+    const response = new SubscribePlatformEventsResponse();
+    response.setV0(
+      new SubscribePlatformEventsResponseV0()
+        .setHeight(10)
+        .setTimeMs(1000),
+    );
+
+    eventsStream.emit('data', response);
+
+    await new Promise(setImmediate);
+
+    expect(AcknowledgingWritable.prototype.write).to.be.calledOnceWith(response);
+    expect(eventsStream.pause).to.be.calledOnce();
+    expect(eventsStream.resume).to.be.calledOnce();
+
+    eventsStream.emit('end');
+
+    expect(call.end).to.be.calledOnce();
+  });
+
+  it('should cancel Drive subscription when the client cancels the call', async () => {
+    await subscribePlatformEventsHandler(call);
+
+    call.emit('cancelled');
+
+    expect(eventsStream.cancel).to.be.calledOnce();
+    expect(call.end).to.be.calledOnce();
+
+    const error = new Error('Cancelled on client');
+    error.code = GrpcErrorCodes.CANCELLED;
+
+    eventsStream.emit('error', error);
+
+    expect(call.destroy).to.not.be.called();
+  });
+
+  it('should pass Drive errors to the client', async () => {
+    await subscribePlatformEventsHandler(call);
+
+    const error = new Error('invalid identifier');
+    error.code = GrpcErrorCodes.INVALID_ARGUMENT;
+    error.details = 'invalid identifier';
+
+    eventsStream.emit('error', error);
+
+    expect(call.destroy).to.be.calledOnce();
+
+    const [grpcError] = call.destroy.getCall(0).args;
+
+    expect(grpcError).to.be.an.instanceOf(GrpcError);
+    expect(grpcError.getCode()).to.equal(GrpcErrorCodes.INVALID_ARGUMENT);
+    expect(grpcError.getMessage()).to.equal('invalid identifier');
+  });
+});
@@ -3,16 +3,43 @@
 use std::time;
 
 use http::Uri;
-use rand::{rngs::SmallRng, seq::IteratorRandom, SeedableRng};
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
 
 const DEFAULT_BASE_BAN_PERIOD: time::Duration = time::Duration::from_secs(60);
 
+/// Latency assumed for addresses without successful requests, so they are tried as well.
+const UNKNOWN_LATENCY: time::Duration = time::Duration::from_millis(100);
+
+/// Lower bound of the latency used to score addresses, to keep scores finite.
+const MIN_LATENCY: time::Duration = time::Duration::from_millis(1);
+
+/// Weight of the latest request in the moving average of latencies.
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+/// Health statistics of an [Address], collected from requests executed on it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AddressHealth {
+    /// Number of successful requests.
+    pub successes: u64,
+    /// Number of failed requests, including responses that were rejected by the client.
+    pub failures: u64,
+    /// Moving average of the latency of successful requests, if any.
+    pub average_latency: Option<time::Duration>,
+    /// Number of bans since the last successful request.
+    pub ban_count: usize,
+    /// End of the current ban, if the address is banned.
+    pub banned_until: Option<time::Instant>,
+}
+
 /// Peer's address.
 #[derive(Debug)]
 pub struct Address {
     base_ban_period: time::Duration,
     ban_count: usize,
     banned_until: Option<time::Instant>,
+    successes: u64,
+    failures: u64,
+    average_latency: Option<time::Duration>,
     uri: Uri,
 }
 
@@ -33,6 +60,60 @@ impl Address {
         self.banned_until = None;
     }
 
+    /// Check if the [Address] is banned at the moment.
+    pub fn is_banned(&self) -> bool {
+        self.is_banned_at(time::Instant::now())
+    }
+
+    fn is_banned_at(&self, now: time::Instant) -> bool {
+        self.banned_until
+            .map(|banned_until| banned_until >= now)
+            .unwrap_or(false)
+    }
+
+    /// Record a successful request that took `latency`, which also clears the ban record.
+    pub fn record_success(&mut self, latency: time::Duration) {
+        self.successes += 1;
+        self.average_latency = Some(match self.average_latency {
+            Some(average) => {
+                average.mul_f64(1.0 - LATENCY_SMOOTHING) + latency.mul_f64(LATENCY_SMOOTHING)
+            }
+            None => latency,
+        });
+        self.clear_ban();
+    }
+
+    /// Record a failed request and ban the [Address].
+    pub fn record_failure(&mut self) {
+        self.failures += 1;
+        self.ban();
+    }
+
+    /// Get health statistics of the [Address].
+    pub fn health(&self) -> AddressHealth {
+        AddressHealth {
+            successes: self.successes,
+            failures: self.failures,
+            average_latency: self.average_latency,
+            ban_count: self.ban_count,
+            banned_until: self.banned_until,
+        }
+    }
+
+    /// Score used to prefer addresses, higher is better.
+    ///
+    /// Success rate is smoothed so addresses with few requests are neither preferred nor avoided.
+    fn score(&self) -> f64 {
+        let success_rate =
+            (self.successes + 1) as f64 / (self.successes + self.failures + 2) as f64;
+        let latency = self
+            .average_latency
+            .unwrap_or(UNKNOWN_LATENCY)
+            .max(MIN_LATENCY);
+
+        success_rate / latency.as_secs_f64()
+    }
+
     /// Get [Uri] of a peer.
     pub fn uri(&self) -> &Uri {
         &self.uri
@@ -71,27 +152,89 @@ impl AddressList {
     // however we need to support bulk loading (e.g. providing a network name)
     // and also fetch updated from SML.
     /// Manually add a peer to [AddressList].
-    pub fn add_uri(&mut self, uri: Uri) {
+    ///
+    /// Returns `false` if the [Uri] is already in the list.
+    pub fn add_uri(&mut self, uri: Uri) -> bool {
+        if self.get_address_mut(&uri).is_some() {
+            return false;
+        }
+
         self.addresses.push(Address {
             ban_count: 0,
             banned_until: None,
             base_ban_period: self.base_ban_period,
+            successes: 0,
+            failures: 0,
+            average_latency: None,
             uri,
         });
+
+        true
+    }
+
+    /// Get the [Address] with provided [Uri].
+    pub fn get_address_mut(&mut self, uri: &Uri) -> Option<&mut Address> {
+        self.addresses.iter_mut().find(|addr| addr.uri() == uri)
+    }
+
+    /// Record a failed request on the [Address] with provided [Uri] and ban it.
+    ///
+    /// Returns `false` if the [Uri] is not in the list.
+    pub fn ban_address(&mut self, uri: &Uri) -> bool {
+        match self.get_address_mut(uri) {
+            Some(address) => {
+                address.record_failure();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check if the list has no addresses.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    /// Iterate over all addresses, including banned ones.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> {
+        self.addresses.iter()
     }
 
     /// Randomly select a not banned address.
+    ///
+    /// Addresses with higher success rate and lower latency are more likely to be selected.
     pub fn get_live_address(&mut self) -> Option<&mut Address> {
         let now = time::Instant::now();
         let mut rng = SmallRng::from_entropy();
-        self.addresses
-            .iter_mut()
-            .filter(|addr| {
-                addr.banned_until
-                    .map(|banned_until| banned_until < now)
-                    .unwrap_or(true)
-            })
-            .choose(&mut rng)
+
+        let live: Vec<usize> = (0..self.addresses.len())
+            .filter(|index| !self.addresses[*index].is_banned_at(now))
+            .collect();
+
+        let index = *live
+            .choose_weighted(&mut rng, |index| self.addresses[*index].score())
+            .ok()?;
+
+        self.addresses.get_mut(index)
+    }
+
+    /// Randomly select up to `count` distinct not banned addresses.
+    ///
+    /// Like in [AddressList::get_live_address], addresses with higher success rate and lower latency
+    /// are more likely to be selected.
+    pub fn get_live_addresses(&self, count: usize) -> Vec<Uri> {
+        let now = time::Instant::now();
+        let mut rng = SmallRng::from_entropy();
+
+        let live: Vec<&Address> = self
+            .addresses
+            .iter()
+            .filter(|address| !address.is_banned_at(now))
+            .collect();
+
+        live.choose_multiple_weighted(&mut rng, count, |address| address.score())
+            .map(|selected| selected.map(|address| address.uri().clone()).collect())
+            .unwrap_or_default()
     }
 }
 
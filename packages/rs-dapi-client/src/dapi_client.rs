@@ -1,11 +1,28 @@
 //! [DapiClient] definition.
 
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time;
+
 use backon::{ExponentialBuilder, Retryable};
-use tonic::async_trait;
+use http::Uri;
+use tonic::{async_trait, IntoRequest};
 use tracing::Instrument;
 
+use dapi_grpc::core::v0::{
+    BlockHeadersWithChainLocksRequest, BlockHeadersWithChainLocksResponse, GetBlockRequest,
+    GetBlockResponse, GetStatusRequest, GetStatusResponse,
+};
+use dapi_grpc::platform::v0::{SubscribePlatformEventsRequest, SubscribePlatformEventsResponse};
+
 use crate::{
-    transport::{TransportClient, TransportRequest},
+    address_list::AddressHealth,
+    transport::{
+        AppliedRequestSettings, CoreGrpcClient, PlatformGrpcClient, TransportClient,
+        TransportRequest, TransportResponse,
+    },
     AddressList, CanRetry, RequestSettings,
 };
 
@@ -18,6 +35,14 @@ pub enum DapiClientError<TE> {
     /// There are no valid peer addresses to use.
     #[error("no available addresses to use")]
     NoAvailableAddresses,
+    /// The node responded with a state older than the one already seen on other nodes.
+    #[error("stale node: response at height {height}, but height {highest_seen_height} was already seen")]
+    StaleNode {
+        /// Height of the response
+        height: u64,
+        /// Highest height seen by the client
+        highest_seen_height: u64,
+    },
     #[cfg(feature = "mocks")]
     /// Expectation not found
     #[error("mock expectation not found for request: {0}")]
@@ -29,6 +54,7 @@ impl<TE: CanRetry> CanRetry for DapiClientError<TE> {
         use DapiClientError::*;
         match self {
             NoAvailableAddresses => false,
+            StaleNode { .. } => true,
             Transport(transport_error) => transport_error.can_retry(),
             #[cfg(feature = "mocks")]
             MockExpectationNotFound(_) => false,
@@ -36,6 +62,22 @@ impl<TE: CanRetry> CanRetry for DapiClientError<TE> {
     }
 }
 
+/// Response to a request executed with [Dapi::execute], with the address that served it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionResponse<R> {
+    /// The response
+    pub inner: R,
+    /// [Uri] of the address that served the response, `None` if it wasn't served by a DAPI node
+    pub address: Option<Uri>,
+}
+
+impl<R> ExecutionResponse<R> {
+    /// Unwrap the response, dropping the address it was served by.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
 #[async_trait]
 /// DAPI client trait.
 pub trait Dapi {
@@ -44,7 +86,10 @@ pub trait Dapi {
         &mut self,
         request: R,
         settings: RequestSettings,
-    ) -> Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>>
+    ) -> Result<
+        ExecutionResponse<R::Response>,
+        DapiClientError<<R::Client as TransportClient>::Error>,
+    >
     where
         R: TransportRequest;
 }
@@ -55,7 +100,10 @@ impl<D: Dapi + Send> Dapi for &mut D {
         &mut self,
         request: R,
         settings: RequestSettings,
-    ) -> Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>>
+    ) -> Result<
+        ExecutionResponse<R::Response>,
+        DapiClientError<<R::Client as TransportClient>::Error>,
+    >
     where
         R: TransportRequest,
     {
@@ -64,10 +112,16 @@ impl<D: Dapi + Send> Dapi for &mut D {
 }
 
 /// Access point to DAPI.
+///
+/// Requests are sent to addresses selected from the [AddressList] by their health. Addresses that
+/// fail with a retryable error or respond with a stale state are banned, and the request is
+/// retried on another address.
 #[derive(Debug)]
 pub struct DapiClient {
-    address_list: AddressList,
+    address_list: Arc<RwLock<AddressList>>,
     settings: RequestSettings,
+    /// Highest Platform height seen in responses, used to detect stale nodes.
+    highest_seen_height: Arc<AtomicU64>,
     #[cfg(feature = "dump")]
     pub(crate) dump_dir: Option<std::path::PathBuf>,
 }
@@ -76,12 +130,266 @@ impl DapiClient {
     /// Initialize new [DapiClient] and optionally override default settings.
     pub fn new(address_list: AddressList, settings: RequestSettings) -> Self {
         Self {
-            address_list,
+            address_list: Arc::new(RwLock::new(address_list)),
             settings,
+            highest_seen_height: Arc::new(AtomicU64::new(0)),
             #[cfg(feature = "dump")]
             dump_dir: None,
         }
     }
+
+    /// Add a peer to the address list.
+    ///
+    /// Returns `false` if the [Uri] is already in the list.
+    pub fn add_uri(&self, uri: Uri) -> bool {
+        self.address_list
+            .write()
+            .expect("address list lock poisoned")
+            .add_uri(uri)
+    }
+
+    /// Ban the address with provided [Uri], for example when its response failed verification.
+    ///
+    /// Returns `false` if the [Uri] is not in the address list.
+    pub fn ban_address(&self, uri: &Uri) -> bool {
+        self.address_list
+            .write()
+            .expect("address list lock poisoned")
+            .ban_address(uri)
+    }
+
+    /// Health statistics of all addresses in the address list.
+    pub fn address_health(&self) -> Vec<(Uri, AddressHealth)> {
+        self.address_list
+            .read()
+            .expect("address list lock poisoned")
+            .addresses()
+            .map(|address| (address.uri().clone(), address.health()))
+            .collect()
+    }
+
+    /// Highest Platform height seen in responses.
+    pub fn highest_seen_height(&self) -> u64 {
+        self.highest_seen_height.load(Ordering::Relaxed)
+    }
+
+    /// Execute the same request on up to `nodes` distinct live addresses concurrently.
+    ///
+    /// Unlike [Dapi::execute], failed requests are not retried on other addresses. Outcome of the
+    /// request is returned for each address it was sent to, so that responses of different nodes
+    /// can be compared.
+    pub async fn execute_on_nodes<R>(
+        &self,
+        request: R,
+        nodes: usize,
+        settings: RequestSettings,
+    ) -> Result<
+        Vec<(
+            Uri,
+            Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>>,
+        )>,
+        DapiClientError<<R::Client as TransportClient>::Error>,
+    >
+    where
+        R: TransportRequest,
+    {
+        let applied_settings = self
+            .settings
+            .override_by(R::SETTINGS_OVERRIDES)
+            .override_by(settings)
+            .finalize();
+
+        let uris = self
+            .address_list
+            .read()
+            .expect("address list lock poisoned")
+            .get_live_addresses(nodes);
+
+        if uris.is_empty() {
+            return Err(DapiClientError::NoAvailableAddresses);
+        }
+
+        let requests = uris.into_iter().map(|uri| {
+            let transport_request = request.clone();
+
+            async move {
+                let mut transport_client = R::Client::with_uri(uri.clone());
+                let started = time::Instant::now();
+
+                let result = transport_request
+                    .execute_transport(&mut transport_client, &applied_settings)
+                    .await
+                    .map_err(DapiClientError::Transport)
+                    .and_then(|response| {
+                        check_response_height(
+                            &response,
+                            &self.highest_seen_height,
+                            applied_settings.max_height_lag,
+                        )
+                        .map(|_| response)
+                    });
+
+                update_address_health(&self.address_list, &uri, &result, started.elapsed());
+
+                (uri, result)
+            }
+        });
+
+        Ok(futures::future::join_all(requests)
+            .instrument(tracing::info_span!("multiple nodes request routine"))
+            .await)
+    }
+
+    /// Subscribe to changes of platform state.
+    ///
+    /// Streams can't be retried transparently, so the retry policy only applies to opening
+    /// the subscription. Once the returned stream ends or fails the caller should subscribe again.
+    pub async fn subscribe_platform_events(
+        &mut self,
+        request: SubscribePlatformEventsRequest,
+        settings: RequestSettings,
+    ) -> Result<
+        tonic::Streaming<SubscribePlatformEventsResponse>,
+        DapiClientError<<PlatformGrpcClient as TransportClient>::Error>,
+    > {
+        self.execute_on_live_address(
+            settings,
+            tracing::info_span!("subscribe routine"),
+            move |mut client: PlatformGrpcClient, _| {
+                let request = request.clone();
+
+                async move {
+                    client
+                        .subscribe_platform_events(request)
+                        .await
+                        .map(|response| response.into_inner())
+                }
+            },
+        )
+        .await
+    }
+
+    /// Retrieve status of the Core node behind DAPI.
+    pub async fn get_core_status(
+        &mut self,
+        settings: RequestSettings,
+    ) -> Result<GetStatusResponse, DapiClientError<<CoreGrpcClient as TransportClient>::Error>>
+    {
+        self.execute_on_live_address(
+            settings,
+            tracing::info_span!("core status routine"),
+            |mut client: CoreGrpcClient, applied_settings| async move {
+                let mut grpc_request = GetStatusRequest {}.into_request();
+                grpc_request.set_timeout(applied_settings.timeout);
+
+                client
+                    .get_status(grpc_request)
+                    .await
+                    .map(|response| response.into_inner())
+            },
+        )
+        .await
+    }
+
+    /// Retrieve a serialized Core block.
+    pub async fn get_core_block(
+        &mut self,
+        request: GetBlockRequest,
+        settings: RequestSettings,
+    ) -> Result<GetBlockResponse, DapiClientError<<CoreGrpcClient as TransportClient>::Error>> {
+        self.execute_on_live_address(
+            settings,
+            tracing::info_span!("core block routine"),
+            move |mut client: CoreGrpcClient, applied_settings| {
+                let mut grpc_request = request.clone().into_request();
+                grpc_request.set_timeout(applied_settings.timeout);
+
+                async move {
+                    client
+                        .get_block(grpc_request)
+                        .await
+                        .map(|response| response.into_inner())
+                }
+            },
+        )
+        .await
+    }
+
+    /// Subscribe to Core block headers and chain locks.
+    ///
+    /// Like [DapiClient::subscribe_platform_events], the retry policy only applies to opening
+    /// the subscription.
+    pub async fn subscribe_core_block_headers(
+        &mut self,
+        request: BlockHeadersWithChainLocksRequest,
+        settings: RequestSettings,
+    ) -> Result<
+        tonic::Streaming<BlockHeadersWithChainLocksResponse>,
+        DapiClientError<<CoreGrpcClient as TransportClient>::Error>,
+    > {
+        self.execute_on_live_address(
+            settings,
+            tracing::info_span!("core block headers routine"),
+            move |mut client: CoreGrpcClient, _| {
+                let request = request.clone();
+
+                async move {
+                    client
+                        .subscribe_to_block_headers_with_chain_locks(request)
+                        .await
+                        .map(|response| response.into_inner())
+                }
+            },
+        )
+        .await
+    }
+
+    /// Run `operation` with a client connected to a live address, applying the retry policy.
+    ///
+    /// Used for requests that can't be executed as [TransportRequest]s, like streams.
+    async fn execute_on_live_address<C, T, F, Fut>(
+        &self,
+        settings: RequestSettings,
+        span: tracing::Span,
+        operation: F,
+    ) -> Result<T, DapiClientError<C::Error>>
+    where
+        C: TransportClient,
+        F: Fn(C, AppliedRequestSettings) -> Fut,
+        Fut: Future<Output = Result<T, C::Error>>,
+    {
+        let applied_settings = self.settings.override_by(settings).finalize();
+
+        let retry_settings = ExponentialBuilder::default().with_max_times(applied_settings.retries);
+
+        let address_list = Arc::clone(&self.address_list);
+        let operation = &operation;
+
+        let routine = move || {
+            let address = select_address::<C::Error>(&address_list);
+
+            let address_list = Arc::clone(&address_list);
+
+            async move {
+                let uri = address?;
+                let started = time::Instant::now();
+
+                let result = operation(C::with_uri(uri.clone()), applied_settings)
+                    .await
+                    .map_err(DapiClientError::Transport);
+
+                update_address_health(&address_list, &uri, &result, started.elapsed());
+
+                result
+            }
+        };
+
+        routine
+            .retry(&retry_settings)
+            .when(|e| e.can_retry())
+            .instrument(span)
+            .await
+    }
 }
 
 #[async_trait]
@@ -91,7 +399,10 @@ impl Dapi for DapiClient {
         &mut self,
         request: R,
         settings: RequestSettings,
-    ) -> Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>>
+    ) -> Result<
+        ExecutionResponse<R::Response>,
+        DapiClientError<<R::Client as TransportClient>::Error>,
+    >
     where
         R: TransportRequest,
     {
@@ -111,30 +422,50 @@ impl Dapi for DapiClient {
         #[cfg(feature = "dump")]
         let dump_request = request.clone();
 
+        let address_list = Arc::clone(&self.address_list);
+        let highest_seen_height = Arc::clone(&self.highest_seen_height);
+
         // Setup DAPI request execution routine future. It's a closure that will be called
         // more once to build new future on each retry.
         let routine = move || {
             // Try to get an address to initialize transport on:
-            let address = self.address_list.get_live_address().ok_or(
-                DapiClientError::<<R::Client as TransportClient>::Error>::NoAvailableAddresses,
-            );
-
-            // Get a transport client requried by the DAPI request from this DAPI client.
-            // It stays wrapped in `Result` since we want to return
-            // `impl Future<Output = Result<...>`, not a `Result` itself.
-            let transport_client = address.map(|addr| R::Client::with_uri(addr.uri().clone()));
+            let address = select_address::<<R::Client as TransportClient>::Error>(&address_list);
 
             let transport_request = request.clone();
 
+            let address_list = Arc::clone(&address_list);
+            let highest_seen_height = Arc::clone(&highest_seen_height);
+
             // Create a future using `async` block that will be returned from the closure on
-            // each retry. Could be just a request future, but need to unpack client first.
+            // each retry. Could be just a request future, but need to unpack address first.
             async move {
-                transport_request
-                    .execute_transport(&mut transport_client?, &applied_settings)
+                let uri = address?;
+
+                // Get a transport client requried by the DAPI request from this DAPI client.
+                let mut transport_client = R::Client::with_uri(uri.clone());
+                let started = time::Instant::now();
+
+                let result = transport_request
+                    .execute_transport(&mut transport_client, &applied_settings)
                     .await
                     .map_err(|e| {
                         DapiClientError::<<R::Client as TransportClient>::Error>::Transport(e)
                     })
+                    .and_then(|response| {
+                        check_response_height(
+                            &response,
+                            &highest_seen_height,
+                            applied_settings.max_height_lag,
+                        )
+                        .map(|_| response)
+                    });
+
+                update_address_health(&address_list, &uri, &result, started.elapsed());
+
+                result.map(|inner| ExecutionResponse {
+                    inner,
+                    address: Some(uri),
+                })
             }
         };
 
@@ -150,9 +481,66 @@ impl Dapi for DapiClient {
         // Dump request and response to disk if dump_dir is set:
         #[cfg(feature = "dump")]
         if let Ok(ref result) = &result {
-            Self::dump_request_response(dump_request, result.clone(), dump_dir);
+            Self::dump_request_response(dump_request, result.inner.clone(), dump_dir);
         }
 
         result
     }
 }
+
+/// Select a live address to send a request to.
+fn select_address<TE>(address_list: &RwLock<AddressList>) -> Result<Uri, DapiClientError<TE>> {
+    address_list
+        .write()
+        .expect("address list lock poisoned")
+        .get_live_address()
+        .map(|address| address.uri().clone())
+        .ok_or(DapiClientError::NoAvailableAddresses)
+}
+
+/// Record the outcome of a request in the health statistics of the address it was sent to.
+///
+/// Only retryable errors are recorded as failures, as other errors are caused by the request.
+fn update_address_health<T, TE: CanRetry + Debug>(
+    address_list: &RwLock<AddressList>,
+    uri: &Uri,
+    result: &Result<T, DapiClientError<TE>>,
+    latency: time::Duration,
+) {
+    let mut address_list = address_list.write().expect("address list lock poisoned");
+
+    let Some(address) = address_list.get_address_mut(uri) else {
+        return;
+    };
+
+    match result {
+        Ok(_) => address.record_success(latency),
+        Err(error) if error.can_retry() => {
+            tracing::debug!(%uri, ?error, "banning address after a failed request");
+            address.record_failure();
+        }
+        Err(_) => {}
+    }
+}
+
+/// Check that the response isn't older than the state already seen on other nodes.
+fn check_response_height<TE>(
+    response: &impl TransportResponse,
+    highest_seen_height: &AtomicU64,
+    max_height_lag: u64,
+) -> Result<(), DapiClientError<TE>> {
+    let Some(height) = response.metadata_height() else {
+        return Ok(());
+    };
+
+    let highest_seen_height = highest_seen_height.fetch_max(height, Ordering::Relaxed);
+
+    if height.saturating_add(max_height_lag) < highest_seen_height {
+        return Err(DapiClientError::StaleNode {
+            height,
+            highest_seen_height,
+        });
+    }
+
+    Ok(())
+}
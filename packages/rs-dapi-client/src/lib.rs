@@ -15,8 +15,8 @@ pub use dapi_client::Dapi;
 use futures::{future::BoxFuture, FutureExt};
 pub use http::Uri;
 
-pub use address_list::AddressList;
-pub use dapi_client::{DapiClient, DapiClientError};
+pub use address_list::{Address, AddressHealth, AddressList};
+pub use dapi_client::{DapiClient, DapiClientError, ExecutionResponse};
 #[cfg(feature = "dump")]
 pub use dump::DumpData;
 pub use request_settings::RequestSettings;
@@ -30,7 +30,7 @@ pub use request_settings::RequestSettings;
 ///
 /// # let _ = async {
 /// let mut client = MockDapiClient::new();
-/// let request: proto::GetIdentityRequest = proto::get_identity_request::GetIdentityRequestV0 { id: b"0".to_vec(), prove: true }.into();
+/// let request: proto::GetIdentityRequest = proto::get_identity_request::GetIdentityRequestV0 { id: b"0".to_vec(), prove: true, height: None }.into();
 /// let response = request.execute(&mut client, RequestSettings::default()).await?;
 /// # Ok::<(), DapiClientError<_>>(())
 /// # };
@@ -46,7 +46,10 @@ pub trait DapiRequest {
         self,
         dapi_client: &'c mut D,
         settings: RequestSettings,
-    ) -> BoxFuture<'c, Result<Self::Response, DapiClientError<Self::TransportError>>>
+    ) -> BoxFuture<
+        'c,
+        Result<ExecutionResponse<Self::Response>, DapiClientError<Self::TransportError>>,
+    >
     where
         Self: 'c;
 }
@@ -61,7 +64,10 @@ impl<T: transport::TransportRequest + Send> DapiRequest for T {
         self,
         dapi_client: &'c mut D,
         settings: RequestSettings,
-    ) -> BoxFuture<'c, Result<Self::Response, DapiClientError<Self::TransportError>>>
+    ) -> BoxFuture<
+        'c,
+        Result<ExecutionResponse<Self::Response>, DapiClientError<Self::TransportError>>,
+    >
     where
         Self: 'c,
     {
@@ -13,7 +13,7 @@
 
 use crate::{
     transport::{TransportClient, TransportRequest},
-    Dapi, DapiClientError, RequestSettings,
+    Dapi, DapiClientError, ExecutionResponse, RequestSettings,
 };
 use hex::ToHex;
 use sha2::Digest;
@@ -95,7 +95,10 @@ impl Dapi for MockDapiClient {
         &mut self,
         request: R,
         _settings: RequestSettings,
-    ) -> Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>> {
+    ) -> Result<
+        ExecutionResponse<R::Response>,
+        DapiClientError<<R::Client as TransportClient>::Error>,
+    > {
         let (key, response) = self.expectations.get(&request);
 
         tracing::trace!(
@@ -107,7 +110,10 @@ impl Dapi for MockDapiClient {
         );
 
         return if let Some(response) = response {
-            Ok(response)
+            Ok(ExecutionResponse {
+                inner: response,
+                address: None,
+            })
         } else {
             Err(DapiClientError::MockExpectationNotFound(format!(
                 "unexpected mock request with key {}, use MockDapiClient::expect(): {:?}",
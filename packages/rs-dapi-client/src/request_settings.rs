@@ -4,6 +4,7 @@ use std::time::Duration;
 
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 const DEFAULT_RETRIES: usize = 5;
+const DEFAULT_MAX_HEIGHT_LAG: u64 = 3;
 
 /// DAPI request settings.
 ///
@@ -18,6 +19,9 @@ pub struct RequestSettings {
     pub timeout: Option<Duration>,
     /// Number of retries until returning the last error.
     pub retries: Option<usize>,
+    /// Max number of blocks a response can lag behind the highest height seen by the client
+    /// before the node is considered stale.
+    pub max_height_lag: Option<u64>,
 }
 
 impl RequestSettings {
@@ -27,6 +31,7 @@ impl RequestSettings {
         RequestSettings {
             timeout: None,
             retries: None,
+            max_height_lag: None,
         }
     }
 
@@ -37,6 +42,7 @@ impl RequestSettings {
         RequestSettings {
             timeout: rhs.timeout.or(self.timeout),
             retries: rhs.retries.or(self.retries),
+            max_height_lag: rhs.max_height_lag.or(self.max_height_lag),
         }
     }
 
@@ -45,6 +51,7 @@ impl RequestSettings {
         AppliedRequestSettings {
             timeout: self.timeout.unwrap_or(DEFAULT_TIMEOUT),
             retries: self.retries.unwrap_or(DEFAULT_RETRIES),
+            max_height_lag: self.max_height_lag.unwrap_or(DEFAULT_MAX_HEIGHT_LAG),
         }
     }
 }
@@ -56,4 +63,7 @@ pub struct AppliedRequestSettings {
     pub timeout: Duration,
     /// Number of retries until returning the last error.
     pub retries: usize,
+    /// Max number of blocks a response can lag behind the highest height seen by the client
+    /// before the node is considered stale.
+    pub max_height_lag: u64,
 }
@@ -4,6 +4,7 @@ pub(crate) mod grpc;
 
 pub use crate::request_settings::AppliedRequestSettings;
 use crate::{CanRetry, RequestSettings};
+use dapi_grpc::platform::v0::ResponseMetadata;
 pub use futures::future::BoxFuture;
 pub use grpc::{CoreGrpcClient, PlatformGrpcClient};
 use http::Uri;
@@ -33,6 +34,17 @@ pub trait TransportRequest: Clone + Send + Sync + Debug + serde::Serialize {
 pub trait TransportResponse:
     Clone + Send + Sync + Debug + serde::Serialize + for<'de> serde::Deserialize<'de>
 {
+    /// Metadata of the Platform state the response was created from, if the response has any.
+    fn response_metadata(&self) -> Option<&ResponseMetadata> {
+        None
+    }
+
+    /// Height of the Platform state the response was created from, if the response has metadata.
+    ///
+    /// Used to detect nodes that lag behind the rest of the network.
+    fn metadata_height(&self) -> Option<u64> {
+        self.response_metadata().map(|metadata| metadata.height)
+    }
 }
 
 /// Generic way to create a transport client from provided [Uri].
@@ -4,6 +4,7 @@ use std::time::Duration;
 
 use dapi_grpc::core::v0::core_client::CoreClient;
 use dapi_grpc::platform::v0::{self as platform_proto, platform_client::PlatformClient};
+use dapi_grpc::platform::VersionedGrpcResponse;
 use futures::{future::BoxFuture, FutureExt, TryFutureExt};
 use http::Uri;
 use tonic::{transport::Channel, IntoRequest};
@@ -76,7 +77,26 @@ macro_rules! impl_transport_request_grpc {
                     .boxed()
             }
         }
-        impl TransportResponse for $response {}
+    };
+}
+
+/// Implements [TransportResponse] for responses without metadata.
+macro_rules! impl_transport_response {
+    ($($response:ty),+ $(,)?) => {
+        $(impl TransportResponse for $response {})+
+    };
+}
+
+/// Implements [TransportResponse] for versioned Platform responses, which carry metadata.
+macro_rules! impl_versioned_transport_response {
+    ($($response:ty),+ $(,)?) => {
+        $(
+            impl TransportResponse for $response {
+                fn response_metadata(&self) -> Option<&platform_proto::ResponseMetadata> {
+                    self.metadata().ok()
+                }
+            }
+        )+
     };
 }
 
@@ -98,6 +118,14 @@ impl_transport_request_grpc!(
     get_documents
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetDocumentsCountRequest,
+    platform_proto::GetDocumentsCountResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_documents_count
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetDataContractRequest,
     platform_proto::GetDataContractResponse,
@@ -130,6 +158,14 @@ impl_transport_request_grpc!(
     broadcast_state_transition
 );
 
+impl_transport_request_grpc!(
+    platform_proto::EstimateStateTransitionFeeRequest,
+    platform_proto::EstimateStateTransitionFeeResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    estimate_state_transition_fee
+);
+
 impl_transport_request_grpc!(
     platform_proto::WaitForStateTransitionResultRequest,
     platform_proto::WaitForStateTransitionResultResponse,
@@ -141,6 +177,14 @@ impl_transport_request_grpc!(
     wait_for_state_transition_result
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetProofsRequest,
+    platform_proto::GetProofsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_proofs
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetIdentitiesByPublicKeyHashesRequest,
     platform_proto::GetIdentitiesByPublicKeyHashesResponse,
@@ -173,6 +217,14 @@ impl_transport_request_grpc!(
     get_identity_balance_and_revision
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetIdentityNonceRequest,
+    platform_proto::GetIdentityNonceResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_identity_nonce
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetIdentityKeysRequest,
     platform_proto::GetIdentityKeysResponse,
@@ -213,6 +265,32 @@ impl_transport_request_grpc!(
     get_data_contracts
 );
 
+impl_transport_response!(
+    platform_proto::GetConsensusParamsResponse,
+    platform_proto::BroadcastStateTransitionResponse,
+    platform_proto::EstimateStateTransitionFeeResponse,
+);
+
+impl_versioned_transport_response!(
+    platform_proto::GetIdentityResponse,
+    platform_proto::GetDocumentsResponse,
+    platform_proto::GetDocumentsCountResponse,
+    platform_proto::GetDataContractResponse,
+    platform_proto::GetDataContractHistoryResponse,
+    platform_proto::WaitForStateTransitionResultResponse,
+    platform_proto::GetProofsResponse,
+    platform_proto::GetIdentitiesByPublicKeyHashesResponse,
+    platform_proto::GetIdentityByPublicKeyHashResponse,
+    platform_proto::GetIdentityBalanceResponse,
+    platform_proto::GetIdentityBalanceAndRevisionResponse,
+    platform_proto::GetIdentityNonceResponse,
+    platform_proto::GetIdentityKeysResponse,
+    platform_proto::GetEpochsInfoResponse,
+    platform_proto::GetProtocolVersionUpgradeStateResponse,
+    platform_proto::GetProtocolVersionUpgradeVoteStatusResponse,
+    platform_proto::GetDataContractsResponse,
+);
+
 // Link to each core gRPC request what client and method to use:
 /*
 TODO: Implement serde on Core gRPC requests and responses
@@ -240,4 +318,10 @@ impl_transport_request_grpc!(
     RequestSettings::default(),
     broadcast_transaction
 );
+
+impl_transport_response!(
+    core_proto::GetTransactionResponse,
+    core_proto::GetStatusResponse,
+    core_proto::BroadcastTransactionResponse,
+);
 */
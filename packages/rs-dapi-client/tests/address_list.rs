@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use rs_dapi_client::{AddressList, Uri};
+
+#[test]
+fn test_banned_address_is_not_selected() {
+    let first: Uri = "http://127.0.0.1:1".parse().unwrap();
+    let second: Uri = "http://127.0.0.1:2".parse().unwrap();
+
+    let mut address_list = AddressList::from_iter([first.clone(), second.clone()]);
+
+    assert!(!address_list.add_uri(first.clone()));
+    assert!(address_list.ban_address(&first));
+
+    for _ in 0..10 {
+        let address = address_list
+            .get_live_address()
+            .expect("second address should be live");
+
+        assert_eq!(address.uri(), &second);
+    }
+
+    assert!(address_list.ban_address(&second));
+    assert!(address_list.get_live_address().is_none());
+}
+
+#[test]
+fn test_successful_request_updates_health() {
+    let uri: Uri = "http://127.0.0.1:1".parse().unwrap();
+
+    let mut address_list = AddressList::from_iter([uri.clone()]);
+
+    address_list.ban_address(&uri);
+
+    let address = address_list.get_address_mut(&uri).unwrap();
+    assert!(address.is_banned());
+
+    address.record_success(Duration::from_millis(10));
+    address.record_success(Duration::from_millis(20));
+
+    let health = address.health();
+
+    assert!(!address.is_banned());
+    assert_eq!(health.successes, 2);
+    assert_eq!(health.failures, 1);
+    assert_eq!(health.ban_count, 0);
+
+    let average_latency = health.average_latency.expect("latency should be recorded");
+    assert!(average_latency > Duration::from_millis(12));
+    assert!(average_latency < Duration::from_millis(14));
+}
+
+#[test]
+fn test_live_addresses_are_distinct() {
+    let uris: Vec<Uri> = (1..=5)
+        .map(|port| format!("http://127.0.0.1:{}", port).parse().unwrap())
+        .collect();
+
+    let mut address_list = AddressList::from_iter(uris.clone());
+    address_list.ban_address(&uris[0]);
+
+    let mut selected = address_list.get_live_addresses(3);
+    assert_eq!(selected.len(), 3);
+    assert!(!selected.contains(&uris[0]));
+
+    selected.sort_by_key(|uri| uri.to_string());
+    selected.dedup();
+    assert_eq!(selected.len(), 3);
+
+    assert_eq!(address_list.get_live_addresses(10).len(), 4);
+}
@@ -21,6 +21,7 @@ mod tests {
                 platform_proto::get_identity_request::GetIdentityRequestV0 {
                     id: OWNER_ID_BYTES.to_vec(),
                     prove: false,
+                    height: None,
                 },
             )),
         };
@@ -39,6 +40,7 @@ mod tests {
             .execute(&mut client, RequestSettings::default())
             .await
             .expect("unable to perform dapi request")
+            .inner
         {
             assert!(!bytes.is_empty());
             assert_eq!(protocol_version, 1);
@@ -27,6 +27,7 @@ async fn test_mock_get_identity_dapi_client() {
 
     let result2 = request.execute(&mut dapi, settings).await.unwrap();
 
-    assert_eq!(result, response);
-    assert_eq!(result2, response);
+    assert_eq!(result.inner, response);
+    assert_eq!(result2.inner, response);
+    assert_eq!(result.address, None);
 }
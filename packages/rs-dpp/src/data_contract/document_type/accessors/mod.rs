@@ -7,8 +7,11 @@ use crate::data_contract::document_type::{DocumentType, DocumentTypeMutRef, Docu
 
 use platform_value::{Identifier, Value};
 
+use crate::data_contract::document_type::trade_mode::TradeMode;
+use crate::data_contract::document_type::transferable::Transferable;
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
 use crate::identity::SecurityLevel;
+use crate::prelude::TimestampMillis;
 use indexmap::IndexMap;
 use std::collections::BTreeSet;
 pub use v0::*;
@@ -86,6 +89,24 @@ impl DocumentTypeV0Getters for DocumentType {
         }
     }
 
+    fn documents_transferable(&self) -> Transferable {
+        match self {
+            DocumentType::V0(v0) => v0.documents_transferable(),
+        }
+    }
+
+    fn trade_mode(&self) -> TradeMode {
+        match self {
+            DocumentType::V0(v0) => v0.trade_mode(),
+        }
+    }
+
+    fn documents_expire_after(&self) -> Option<TimestampMillis> {
+        match self {
+            DocumentType::V0(v0) => v0.documents_expire_after(),
+        }
+    }
+
     fn data_contract_id(&self) -> Identifier {
         match self {
             DocumentType::V0(v0) => v0.data_contract_id(),
@@ -184,6 +205,24 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeRef<'a> {
         }
     }
 
+    fn documents_transferable(&self) -> Transferable {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.documents_transferable(),
+        }
+    }
+
+    fn trade_mode(&self) -> TradeMode {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.trade_mode(),
+        }
+    }
+
+    fn documents_expire_after(&self) -> Option<TimestampMillis> {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.documents_expire_after(),
+        }
+    }
+
     fn data_contract_id(&self) -> Identifier {
         match self {
             DocumentTypeRef::V0(v0) => v0.data_contract_id(),
@@ -282,6 +321,24 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeMutRef<'a> {
         }
     }
 
+    fn documents_transferable(&self) -> Transferable {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.documents_transferable(),
+        }
+    }
+
+    fn trade_mode(&self) -> TradeMode {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.trade_mode(),
+        }
+    }
+
+    fn documents_expire_after(&self) -> Option<TimestampMillis> {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.documents_expire_after(),
+        }
+    }
+
     fn data_contract_id(&self) -> Identifier {
         match self {
             DocumentTypeMutRef::V0(v0) => v0.data_contract_id(),
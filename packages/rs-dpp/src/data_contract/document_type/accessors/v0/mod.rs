@@ -4,8 +4,11 @@ use crate::data_contract::document_type::property::DocumentProperty;
 
 use platform_value::{Identifier, Value};
 
+use crate::data_contract::document_type::trade_mode::TradeMode;
+use crate::data_contract::document_type::transferable::Transferable;
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
 use crate::identity::SecurityLevel;
+use crate::prelude::TimestampMillis;
 use indexmap::IndexMap;
 use std::collections::BTreeSet;
 
@@ -44,6 +47,15 @@ pub trait DocumentTypeV0Getters {
     /// Returns the documents mutable flag of the document type.
     fn documents_mutable(&self) -> bool;
 
+    /// Returns whether documents of the document type can be transferred.
+    fn documents_transferable(&self) -> Transferable;
+
+    /// Returns how documents of the document type can be traded.
+    fn trade_mode(&self) -> TradeMode;
+
+    /// Returns how long after their creation documents of the document type expire.
+    fn documents_expire_after(&self) -> Option<TimestampMillis>;
+
     /// Returns the data contract id of the document type.
     fn data_contract_id(&self) -> Identifier;
 
@@ -24,6 +24,8 @@ use crate::data_contract::document_type::schema::{
 use crate::consensus::basic::document::MissingPositionsInDocumentTypePropertiesError;
 use crate::consensus::basic::BasicError;
 use crate::data_contract::document_type::schema::enrich_with_base_schema;
+use crate::data_contract::document_type::trade_mode::TradeMode;
+use crate::data_contract::document_type::transferable::Transferable;
 use crate::data_contract::document_type::{property_names, DocumentType};
 use crate::data_contract::errors::{DataContractError, StructureError};
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
@@ -339,6 +341,35 @@ impl DocumentTypeV0 {
                                     Ok(())
                                 }
                             })?;
+
+                            // Summed properties must be integer properties of the document
+                            index.summable.iter().try_for_each(|summable_property| {
+                                let property_definition = flattened_document_properties
+                                    .get(summable_property)
+                                    .ok_or_else(|| {
+                                        ProtocolError::ConsensusError(Box::new(
+                                            UndefinedIndexPropertyError::new(
+                                                name.to_owned(),
+                                                index.name.to_owned(),
+                                                summable_property.to_owned(),
+                                            )
+                                            .into(),
+                                        ))
+                                    })?;
+
+                                match property_definition.property_type {
+                                    DocumentPropertyType::Integer => Ok(()),
+                                    _ => Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexPropertyTypeError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            summable_property.to_owned(),
+                                            property_definition.property_type.name(),
+                                        )
+                                        .into(),
+                                    ))),
+                                }
+                            })?;
                         }
 
                         Ok(index)
@@ -376,6 +407,50 @@ impl DocumentTypeV0 {
             .map(StorageKeyRequirements::try_from)
             .transpose()?;
 
+        let documents_transferable = schema
+            .get_optional_integer::<u8>(property_names::TRANSFERABLE)?
+            .map(Transferable::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        let trade_mode = schema
+            .get_optional_integer::<u8>(property_names::TRADE_MODE)?
+            .map(TradeMode::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        // Documents expire a fixed time after their creation, so their expiry can always be
+        // recomputed from the document itself
+        let documents_expire_after =
+            schema.get_optional_integer::<u64>(property_names::DOCUMENTS_EXPIRE_AFTER)?;
+
+        if let Some(documents_expire_after) = documents_expire_after {
+            if documents_expire_after == 0 {
+                return Err(ProtocolError::DataContractError(
+                    DataContractError::InvalidContractStructure(
+                        "documentsExpireAfter must be greater than 0".to_string(),
+                    ),
+                ));
+            }
+
+            if documents_keep_history {
+                return Err(ProtocolError::DataContractError(
+                    DataContractError::InvalidContractStructure(
+                        "documents that keep history can't expire".to_string(),
+                    ),
+                ));
+            }
+
+            if !required_fields.contains(property_names::CREATED_AT) {
+                return Err(ProtocolError::DataContractError(
+                    DataContractError::InvalidContractStructure(
+                        "documents that expire must have $createdAt as a required field"
+                            .to_string(),
+                    ),
+                ));
+            }
+        }
+
         Ok(DocumentTypeV0 {
             name: String::from(name),
             schema,
@@ -388,6 +463,9 @@ impl DocumentTypeV0 {
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_transferable,
+            trade_mode,
+            documents_expire_after,
             data_contract_id,
             requires_identity_encryption_bounded_key,
             requires_identity_decryption_bounded_key,
@@ -25,6 +25,11 @@ pub struct Index {
     pub name: String,
     pub properties: Vec<IndexProperty>,
     pub unique: bool,
+    /// Keep a count of the documents at each value of the index, so they can be counted
+    /// without being fetched
+    pub countable: bool,
+    /// Integer properties whose sum is kept at each value of the index
+    pub summable: Vec<String>,
 }
 
 impl Index {
@@ -44,6 +49,11 @@ impl Index {
             value1 == value2
         })
     }
+    /// Returns true if the index keeps a count or sums of its documents
+    pub fn has_aggregates(&self) -> bool {
+        self.countable || !self.summable.is_empty()
+    }
+
     /// The field names of the index
     pub fn property_names(&self) -> Vec<String> {
         self.properties
@@ -184,6 +194,8 @@ impl TryFrom<&[(Value, Value)]> for Index {
         // For properties, we iterate each and move it to IndexProperty
 
         let mut unique = false;
+        let mut countable = false;
+        let mut summable: Vec<String> = Vec::new();
         let mut name = None;
         let mut index_properties: Vec<IndexProperty> = Vec::new();
 
@@ -210,6 +222,26 @@ impl TryFrom<&[(Value, Value)]> for Index {
                         unique = value_value.as_bool().expect("confirmed as bool");
                     }
                 }
+                "countable" => {
+                    if value_value.is_bool() {
+                        countable = value_value.as_bool().expect("confirmed as bool");
+                    }
+                }
+                "summable" => {
+                    let properties =
+                        value_value.as_array().ok_or(ProtocolError::StructureError(
+                            StructureError::ValueWrongType("summable value should be an array"),
+                        ))?;
+
+                    for property in properties {
+                        let property_name = property.as_text().ok_or(
+                            ProtocolError::StructureError(StructureError::ValueWrongType(
+                                "each summable property should be a string",
+                            )),
+                        )?;
+                        summable.push(property_name.to_owned());
+                    }
+                }
                 "properties" => {
                     let properties =
                         value_value.as_array().ok_or(ProtocolError::StructureError(
@@ -244,6 +276,8 @@ impl TryFrom<&[(Value, Value)]> for Index {
             name,
             properties: index_properties,
             unique,
+            countable,
+            summable,
         })
     }
 }
@@ -52,6 +52,8 @@ impl Index {
             name: index_name,
             properties,
             unique,
+            countable: false,
+            summable: vec![],
         })
     }
 }
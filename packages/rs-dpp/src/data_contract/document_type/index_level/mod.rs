@@ -12,6 +12,10 @@ pub struct IndexLevel {
     sub_index_levels: BTreeMap<String, IndexLevel>,
     /// did an index terminate at this level
     has_index_with_uniqueness: Option<bool>,
+    /// does the index terminating at this level keep a count of its documents
+    countable: bool,
+    /// integer properties whose sum is kept by the index terminating at this level
+    summable_properties: Vec<String>,
     /// unique level identifier
     level_identifier: u64,
 }
@@ -29,6 +33,21 @@ impl IndexLevel {
         self.has_index_with_uniqueness
     }
 
+    /// Returns true if the index terminating at this level keeps a count of its documents
+    pub fn is_countable(&self) -> bool {
+        self.countable
+    }
+
+    /// Integer properties whose sum is kept by the index terminating at this level
+    pub fn summable_properties(&self) -> &[String] {
+        &self.summable_properties
+    }
+
+    /// Returns true if the index terminating at this level keeps a count or sums
+    pub fn has_aggregates(&self) -> bool {
+        self.countable || !self.summable_properties.is_empty()
+    }
+
     /// Checks whether the given `rhs` IndexLevel is a subset of the current IndexLevel (`self`).
     ///
     /// A level is considered a subset if:
@@ -50,6 +69,7 @@ impl IndexLevel {
     ///
     /// A level is considered a subset if:
     /// - The `level_identifier` of both IndexLevels matches.
+    /// - The aggregates kept at both levels match, as they can not be rebuilt for existing documents.
     /// - Each sub_index_level in `rhs` is also a subset of the corresponding sub_index_level in `self`.
     ///
     /// # Parameters
@@ -65,6 +85,13 @@ impl IndexLevel {
             return Some("Invalid path".to_string());
         }
 
+        if rhs.has_index_with_uniqueness.is_some()
+            && (self.countable != rhs.countable
+                || self.summable_properties != rhs.summable_properties)
+        {
+            return Some("Changed aggregates".to_string());
+        }
+
         // Check if each sub_index_level in the rhs is a subset of self.
         for (key, rhs_sub_index) in &rhs.sub_index_levels {
             match self.sub_index_levels.get(key) {
@@ -112,6 +139,8 @@ impl IndexLevel {
         let mut index_level = IndexLevel {
             sub_index_levels: Default::default(),
             has_index_with_uniqueness: None,
+            countable: false,
+            summable_properties: vec![],
             level_identifier: 0,
         };
 
@@ -131,6 +160,8 @@ impl IndexLevel {
                             level_identifier: counter,
                             sub_index_levels: Default::default(),
                             has_index_with_uniqueness: None,
+                            countable: false,
+                            summable_properties: vec![],
                         }
                     });
 
@@ -152,6 +183,8 @@ impl IndexLevel {
                     }
 
                     current_level.has_index_with_uniqueness = Some(index.unique);
+                    current_level.countable = index.countable;
+                    current_level.summable_properties = index.summable.clone();
                 }
             }
         }
@@ -11,6 +11,8 @@ pub use index_level::IndexLevel;
 #[cfg(feature = "random-documents")]
 pub mod random_document;
 pub mod schema;
+pub mod trade_mode;
+pub mod transferable;
 pub mod v0;
 
 use crate::data_contract::document_type::methods::DocumentTypeV0Methods;
@@ -26,6 +28,9 @@ use std::collections::BTreeMap;
 mod property_names {
     pub const DOCUMENTS_KEEP_HISTORY: &str = "documentsKeepHistory";
     pub const DOCUMENTS_MUTABLE: &str = "documentsMutable";
+    pub const TRANSFERABLE: &str = "transferable";
+    pub const TRADE_MODE: &str = "tradeMode";
+    pub const DOCUMENTS_EXPIRE_AFTER: &str = "documentsExpireAfter";
     pub const SECURITY_LEVEL_REQUIREMENT: &str = "signatureSecurityLevelRequirement";
     pub const REQUIRES_IDENTITY_ENCRYPTION_BOUNDED_KEY: &str =
         "requiresIdentityEncryptionBoundedKey";
@@ -0,0 +1,44 @@
+use crate::data_contract::errors::DataContractError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use serde_repr::*;
+use std::convert::TryFrom;
+
+/// How documents of a document type can be traded between identities
+// @append_only
+#[repr(u8)]
+#[derive(
+    Serialize_repr, Deserialize_repr, Debug, PartialEq, Eq, Copy, Clone, Encode, Decode, Default,
+)]
+pub enum TradeMode {
+    /// Documents can not be sold
+    #[default]
+    None = 0,
+    /// The owner sets a price and anyone can buy the document at that price
+    DirectPurchase = 1,
+}
+
+impl TradeMode {
+    pub fn seller_sets_price(&self) -> bool {
+        match self {
+            TradeMode::None => false,
+            TradeMode::DirectPurchase => true,
+        }
+    }
+}
+
+impl TryFrom<u8> for TradeMode {
+    type Error = ProtocolError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::DirectPurchase),
+            value => Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(format!(
+                    "unrecognized trade mode: {}",
+                    value
+                )),
+            )),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::data_contract::errors::DataContractError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use serde_repr::*;
+use std::convert::TryFrom;
+
+/// Whether documents of a document type can be transferred to another identity
+// @append_only
+#[repr(u8)]
+#[derive(
+    Serialize_repr, Deserialize_repr, Debug, PartialEq, Eq, Copy, Clone, Encode, Decode, Default,
+)]
+pub enum Transferable {
+    #[default]
+    Never = 0,
+    Always = 1,
+}
+
+impl Transferable {
+    pub fn is_transferable(&self) -> bool {
+        matches!(self, Transferable::Always)
+    }
+}
+
+impl TryFrom<u8> for Transferable {
+    type Error = ProtocolError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Never),
+            1 => Ok(Self::Always),
+            value => Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(format!(
+                    "unrecognized transferable value: {}",
+                    value
+                )),
+            )),
+        }
+    }
+}
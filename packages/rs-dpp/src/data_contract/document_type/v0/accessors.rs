@@ -6,8 +6,11 @@ use crate::data_contract::document_type::v0::DocumentTypeV0;
 
 use platform_value::{Identifier, Value};
 
+use crate::data_contract::document_type::trade_mode::TradeMode;
+use crate::data_contract::document_type::transferable::Transferable;
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
 use crate::identity::SecurityLevel;
+use crate::prelude::TimestampMillis;
 use indexmap::IndexMap;
 use std::collections::BTreeSet;
 
@@ -60,6 +63,18 @@ impl DocumentTypeV0Getters for DocumentTypeV0 {
         self.documents_mutable
     }
 
+    fn documents_transferable(&self) -> Transferable {
+        self.documents_transferable
+    }
+
+    fn trade_mode(&self) -> TradeMode {
+        self.trade_mode
+    }
+
+    fn documents_expire_after(&self) -> Option<TimestampMillis> {
+        self.documents_expire_after
+    }
+
     fn data_contract_id(&self) -> Identifier {
         self.data_contract_id
     }
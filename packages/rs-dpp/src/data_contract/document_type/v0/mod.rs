@@ -4,12 +4,15 @@ use std::collections::BTreeSet;
 use crate::data_contract::document_type::index::Index;
 use crate::data_contract::document_type::index_level::IndexLevel;
 use crate::data_contract::document_type::property::DocumentProperty;
+use crate::data_contract::document_type::trade_mode::TradeMode;
+use crate::data_contract::document_type::transferable::Transferable;
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
 
 #[cfg(feature = "validation")]
 pub(in crate::data_contract) use validator::StatelessJsonSchemaLazyValidator;
 
 use crate::identity::SecurityLevel;
+use crate::prelude::TimestampMillis;
 use platform_value::{Identifier, Value};
 
 mod accessors;
@@ -53,6 +56,12 @@ pub struct DocumentTypeV0 {
     pub(in crate::data_contract) documents_keep_history: bool,
     /// Are documents mutable?
     pub(in crate::data_contract) documents_mutable: bool,
+    /// Can documents be transferred to another identity?
+    pub(in crate::data_contract) documents_transferable: Transferable,
+    /// How can documents be traded?
+    pub(in crate::data_contract) trade_mode: TradeMode,
+    /// How long after their creation documents expire, in milliseconds
+    pub(in crate::data_contract) documents_expire_after: Option<TimestampMillis>,
     pub(in crate::data_contract) data_contract_id: Identifier,
     /// Encryption key storage requirements
     pub(in crate::data_contract) requires_identity_encryption_bounded_key:
@@ -98,6 +98,8 @@ impl RandomDocumentTypeParameters {
 }
 
 use crate::data_contract::document_type::index_level::IndexLevel;
+use crate::data_contract::document_type::trade_mode::TradeMode;
+use crate::data_contract::document_type::transferable::Transferable;
 use crate::data_contract::document_type::v0::{DocumentTypeV0, StatelessJsonSchemaLazyValidator};
 use crate::data_contract::document_type::{
     DocumentProperty, DocumentPropertyType, DocumentType, Index,
@@ -254,6 +256,9 @@ impl DocumentTypeV0 {
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_transferable: Transferable::default(),
+            trade_mode: TradeMode::default(),
+            documents_expire_after: None,
             data_contract_id,
             requires_identity_encryption_bounded_key: None,
             requires_identity_decryption_bounded_key: None,
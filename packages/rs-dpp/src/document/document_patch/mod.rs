@@ -4,16 +4,16 @@ use platform_value::{Identifier, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-/// Documents contain the data that goes into data contracts.
+/// The changed properties of an existing document, sent with a document patch transition.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
 pub struct DocumentPatch {
     /// The unique document ID.
     #[serde(rename = "$id")]
     pub id: Identifier,
-    /// The document's properties (data).
+    /// The changed properties.
     #[serde(flatten)]
     pub properties: BTreeMap<String, Value>,
-    /// The document revision.
+    /// The document revision after the patch.
     #[serde(rename = "$revision")]
     pub revision: Option<Revision>,
     #[serde(rename = "$updatedAt")]
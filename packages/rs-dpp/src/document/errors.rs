@@ -4,6 +4,7 @@ use thiserror::Error;
 use crate::errors::consensus::ConsensusError;
 
 use crate::document::accessors::v0::DocumentV0Getters;
+use crate::document::document_patch::DocumentPatch;
 use crate::document::Document;
 #[cfg(feature = "state-transitions")]
 use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
@@ -55,4 +56,7 @@ pub enum DocumentError {
 
     #[error("No documents were supplied to state transition")]
     NoDocumentsSuppliedError,
+
+    #[error("Document patch has no revision")]
+    DocumentPatchNoRevisionError { document_patch: Box<DocumentPatch> },
 }
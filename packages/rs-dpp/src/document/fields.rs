@@ -7,6 +7,7 @@ pub mod property_names {
     pub const OWNER_ID: &str = "$ownerId";
     pub const CREATED_AT: &str = "$createdAt";
     pub const UPDATED_AT: &str = "$updatedAt";
+    pub const PRICE: &str = "$price";
 }
 
 pub const IDENTIFIER_FIELDS: [&str; 3] = [
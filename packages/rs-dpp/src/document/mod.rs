@@ -6,7 +6,7 @@ mod document_facade;
 #[cfg(feature = "factories")]
 pub mod document_factory;
 pub mod document_methods;
-mod document_patch;
+pub mod document_patch;
 pub mod errors;
 #[cfg(feature = "extended-document")]
 pub mod extended_document;
@@ -226,6 +226,13 @@ impl SpecializedDocumentFactoryV0 {
                         .collect(),
                     platform_version,
                 ),
+
+                DocumentTransitionActionType::Transfer
+                | DocumentTransitionActionType::UpdatePrice
+                | DocumentTransitionActionType::Purchase
+                | DocumentTransitionActionType::Patch => {
+                    Err(DocumentError::InvalidActionError(action as u8).into())
+                }
             })
             .collect::<Result<Vec<_>, ProtocolError>>()?
             .into_iter()
@@ -239,6 +246,7 @@ impl SpecializedDocumentFactoryV0 {
         Ok(DocumentsBatchTransitionV0 {
             owner_id,
             transitions,
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: Default::default(),
         }
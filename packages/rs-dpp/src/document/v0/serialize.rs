@@ -1,8 +1,9 @@
 use crate::data_contract::document_type::DocumentTypeRef;
 use crate::data_contract::errors::DataContractError;
 
-use crate::document::property_names::{CREATED_AT, UPDATED_AT};
+use crate::document::property_names::{CREATED_AT, PRICE, UPDATED_AT};
 
+use crate::fee::Credits;
 use crate::prelude::Revision;
 
 use crate::ProtocolError;
@@ -17,6 +18,7 @@ use crate::version::PlatformVersion;
 use byteorder::{BigEndian, ReadBytesExt};
 use integer_encoding::{VarInt, VarIntReader};
 
+use platform_value::btreemap_extensions::{BTreeValueMapHelper, BTreeValueRemoveFromMapHelper};
 use platform_value::{Identifier, Value};
 use platform_version::version::FeatureVersion;
 
@@ -84,6 +86,16 @@ impl DocumentPlatformSerializationMethodsV0 for DocumentV0 {
             buffer.push(0);
         }
 
+        // $price
+        if document_type.trade_mode().seller_sets_price() {
+            if let Some(price) = self.properties.get_optional_integer::<Credits>(PRICE)? {
+                buffer.push(1);
+                buffer.extend(price.to_be_bytes());
+            } else {
+                buffer.push(0);
+            }
+        }
+
         // User defined properties
         document_type
             .properties()
@@ -192,6 +204,16 @@ impl DocumentPlatformSerializationMethodsV0 for DocumentV0 {
             buffer.push(0);
         }
 
+        // $price
+        if document_type.trade_mode().seller_sets_price() {
+            if let Some(price) = self.properties.remove_optional_integer::<Credits>(PRICE)? {
+                buffer.push(1);
+                buffer.extend(price.to_be_bytes());
+            } else {
+                buffer.push(0);
+            }
+        }
+
         // User defined properties
         document_type
             .properties()
@@ -288,9 +310,16 @@ impl DocumentPlatformDeserializationMethodsV0 for DocumentV0 {
         let created_at = read_timestamp(&mut buf, document_type, CREATED_AT)?;
         let updated_at = read_timestamp(&mut buf, document_type, UPDATED_AT)?;
 
+        // $price
+        let price = if document_type.trade_mode().seller_sets_price() {
+            read_price(&mut buf)?
+        } else {
+            None
+        };
+
         let mut finished_buffer = false;
 
-        let properties = document_type
+        let mut properties = document_type
             .properties()
             .iter()
             .filter_map(|(key, property)| {
@@ -319,6 +348,10 @@ impl DocumentPlatformDeserializationMethodsV0 for DocumentV0 {
             })
             .collect::<Result<BTreeMap<String, Value>, ProtocolError>>()?;
 
+        if let Some(price) = price {
+            properties.insert(PRICE.to_string(), Value::U64(price));
+        }
+
         Ok(DocumentV0 {
             id: Identifier::new(id),
             properties,
@@ -356,6 +389,26 @@ fn read_timestamp(
     Ok(Some(timestamp))
 }
 
+fn read_price(buf: &mut BufReader<&[u8]>) -> Result<Option<Credits>, ProtocolError> {
+    let marker = buf.read_u8().map_err(|_| {
+        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+            "error reading price optional byte from serialized document",
+        ))
+    })?;
+
+    if marker == 0 {
+        return Ok(None);
+    }
+
+    let price = buf.read_u64::<BigEndian>().map_err(|_| {
+        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+            "error reading price from serialized document",
+        ))
+    })?;
+
+    Ok(Some(price))
+}
+
 impl DocumentPlatformConversionMethodsV0 for DocumentV0 {
     /// Serializes the document.
     ///
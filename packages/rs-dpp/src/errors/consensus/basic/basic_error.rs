@@ -46,7 +46,7 @@ use crate::consensus::basic::identity::{
 use crate::consensus::basic::invalid_identifier_error::InvalidIdentifierError;
 use crate::consensus::basic::state_transition::{
     InvalidStateTransitionTypeError, MissingStateTransitionTypeError,
-    StateTransitionMaxSizeExceededError,
+    StateTransitionMaxSizeExceededError, UserFeeIncreaseTooHighError,
 };
 use crate::consensus::basic::{IncompatibleProtocolVersionError, UnsupportedProtocolVersionError};
 use crate::consensus::ConsensusError;
@@ -301,6 +301,9 @@ pub enum BasicError {
 
     #[error(transparent)]
     DocumentTransitionsAreAbsentError(DocumentTransitionsAreAbsentError),
+
+    #[error(transparent)]
+    UserFeeIncreaseTooHighError(UserFeeIncreaseTooHighError),
 }
 
 impl From<BasicError> for ConsensusError {
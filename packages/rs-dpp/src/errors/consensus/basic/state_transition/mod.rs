@@ -1,7 +1,9 @@
 mod invalid_state_transition_type_error;
 mod missing_state_transition_type_error;
 mod state_transition_max_size_exceeded_error;
+mod user_fee_increase_too_high_error;
 
 pub use invalid_state_transition_type_error::*;
 pub use missing_state_transition_type_error::*;
 pub use state_transition_max_size_exceeded_error::*;
+pub use user_fee_increase_too_high_error::*;
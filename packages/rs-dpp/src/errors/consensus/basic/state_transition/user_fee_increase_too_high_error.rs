@@ -0,0 +1,44 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::UserFeeIncrease;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("User fee increase {user_fee_increase}% is more than maximum {max_user_fee_increase}%")]
+#[platform_serialize(unversioned)]
+pub struct UserFeeIncreaseTooHighError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    user_fee_increase: UserFeeIncrease,
+    max_user_fee_increase: UserFeeIncrease,
+}
+
+impl UserFeeIncreaseTooHighError {
+    pub fn new(user_fee_increase: UserFeeIncrease, max_user_fee_increase: UserFeeIncrease) -> Self {
+        Self {
+            user_fee_increase,
+            max_user_fee_increase,
+        }
+    }
+
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        self.user_fee_increase
+    }
+    pub fn max_user_fee_increase(&self) -> UserFeeIncrease {
+        self.max_user_fee_increase
+    }
+}
+
+impl From<UserFeeIncreaseTooHighError> for ConsensusError {
+    fn from(err: UserFeeIncreaseTooHighError) -> Self {
+        Self::BasicError(BasicError::UserFeeIncreaseTooHighError(err))
+    }
+}
@@ -113,6 +113,7 @@ impl ErrorWithCode for BasicError {
             Self::InvalidStateTransitionTypeError { .. } => 1043,
             Self::MissingStateTransitionTypeError { .. } => 1044,
             Self::StateTransitionMaxSizeExceededError { .. } => 1045,
+            Self::UserFeeIncreaseTooHighError(_) => 1068,
         }
     }
 }
@@ -152,6 +153,9 @@ impl ErrorWithCode for StateError {
             #[cfg(feature = "validation")]
             Self::DataTriggerError(ref e) => e.code(),
             Self::DataContractConfigUpdateError { .. } => 4027,
+            Self::DataContractIsNotDeletableError { .. } => 4028,
+            Self::DataContractOwnerIdMismatchError { .. } => 4029,
+            Self::DataContractIsTooLargeToDeleteError { .. } => 4035,
 
             // Document
             Self::DocumentAlreadyPresentError { .. } => 4004,
@@ -162,6 +166,12 @@ impl ErrorWithCode for StateError {
             Self::DuplicateUniqueIndexError { .. } => 4009,
             Self::InvalidDocumentRevisionError { .. } => 4010,
             Self::DocumentTimestampsAreEqualError(_) => 4025,
+            Self::DocumentNotForSaleError(_) => 4030,
+            Self::DocumentIncorrectPurchasePriceError(_) => 4031,
+            Self::DocumentAlreadyOwnedError(_) => 4036,
+            Self::DocumentTypeIndexBackfillInProgressError(_) => 4033,
+            Self::DocumentIndexBackfillConflictError(_) => 4034,
+            Self::DocumentTypeIndexBackfillFailedError(_) => 4038,
 
             // Identity
             Self::IdentityAlreadyExistsError(_) => 4011,
@@ -176,6 +186,10 @@ impl ErrorWithCode for StateError {
             Self::MissingIdentityPublicKeyIdsError { .. } => 4024,
             Self::IdentityInsufficientBalanceError(_) => 4026,
             Self::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError(_) => 4027,
+            Self::InvalidIdentityNonceError(_) => 4032,
+
+            // State transition
+            Self::PendingStateTransitionConflictError(_) => 4037,
         }
     }
 }
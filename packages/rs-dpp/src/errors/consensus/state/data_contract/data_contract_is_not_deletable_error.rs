@@ -0,0 +1,37 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Can't delete the Data Contract {data_contract_id}: Data Contract can not be deleted")]
+#[platform_serialize(unversioned)]
+pub struct DataContractIsNotDeletableError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+}
+
+impl DataContractIsNotDeletableError {
+    pub fn new(data_contract_id: Identifier) -> Self {
+        Self { data_contract_id }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+}
+
+impl From<DataContractIsNotDeletableError> for ConsensusError {
+    fn from(err: DataContractIsNotDeletableError) -> Self {
+        Self::StateError(StateError::DataContractIsNotDeletableError(err))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Can't delete the Data Contract {data_contract_id}: Data Contract has more than {max_deleted_elements} elements to delete")]
+#[platform_serialize(unversioned)]
+pub struct DataContractIsTooLargeToDeleteError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    max_deleted_elements: u16,
+}
+
+impl DataContractIsTooLargeToDeleteError {
+    pub fn new(data_contract_id: Identifier, max_deleted_elements: u16) -> Self {
+        Self {
+            data_contract_id,
+            max_deleted_elements,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn max_deleted_elements(&self) -> u16 {
+        self.max_deleted_elements
+    }
+}
+
+impl From<DataContractIsTooLargeToDeleteError> for ConsensusError {
+    fn from(err: DataContractIsTooLargeToDeleteError) -> Self {
+        Self::StateError(StateError::DataContractIsTooLargeToDeleteError(err))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} is not the owner of the Data Contract {data_contract_id}, the owner is {data_contract_owner_id}")]
+#[platform_serialize(unversioned)]
+pub struct DataContractOwnerIdMismatchError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    identity_id: Identifier,
+    data_contract_owner_id: Identifier,
+}
+
+impl DataContractOwnerIdMismatchError {
+    pub fn new(
+        data_contract_id: Identifier,
+        identity_id: Identifier,
+        data_contract_owner_id: Identifier,
+    ) -> Self {
+        Self {
+            data_contract_id,
+            identity_id,
+            data_contract_owner_id,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+
+    pub fn data_contract_owner_id(&self) -> &Identifier {
+        &self.data_contract_owner_id
+    }
+}
+
+impl From<DataContractOwnerIdMismatchError> for ConsensusError {
+    fn from(err: DataContractOwnerIdMismatchError) -> Self {
+        Self::StateError(StateError::DataContractOwnerIdMismatchError(err))
+    }
+}
@@ -1,3 +1,6 @@
 pub mod data_contract_already_present_error;
 pub mod data_contract_config_update_error;
+pub mod data_contract_is_not_deletable_error;
 pub mod data_contract_is_readonly_error;
+pub mod data_contract_is_too_large_to_delete_error;
+pub mod data_contract_owner_id_mismatch_error;
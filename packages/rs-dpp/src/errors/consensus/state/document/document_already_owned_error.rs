@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Document {document_id} is already owned by {owner_id}")]
+#[platform_serialize(unversioned)]
+pub struct DocumentAlreadyOwnedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_id: Identifier,
+    owner_id: Identifier,
+}
+
+impl DocumentAlreadyOwnedError {
+    pub fn new(document_id: Identifier, owner_id: Identifier) -> Self {
+        Self {
+            document_id,
+            owner_id,
+        }
+    }
+
+    pub fn document_id(&self) -> &Identifier {
+        &self.document_id
+    }
+
+    pub fn owner_id(&self) -> &Identifier {
+        &self.owner_id
+    }
+}
+
+impl From<DocumentAlreadyOwnedError> for ConsensusError {
+    fn from(err: DocumentAlreadyOwnedError) -> Self {
+        Self::StateError(StateError::DocumentAlreadyOwnedError(err))
+    }
+}
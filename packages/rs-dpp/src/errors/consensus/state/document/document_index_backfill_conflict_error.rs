@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Document {document_id} conflicted with unique index {index_name} during its backfill and can not be changed")]
+#[platform_serialize(unversioned)]
+pub struct DocumentIndexBackfillConflictError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_id: Identifier,
+    index_name: String,
+}
+
+impl DocumentIndexBackfillConflictError {
+    pub fn new(document_id: Identifier, index_name: String) -> Self {
+        Self {
+            document_id,
+            index_name,
+        }
+    }
+
+    pub fn document_id(&self) -> &Identifier {
+        &self.document_id
+    }
+
+    pub fn index_name(&self) -> &String {
+        &self.index_name
+    }
+}
+
+impl From<DocumentIndexBackfillConflictError> for ConsensusError {
+    fn from(err: DocumentIndexBackfillConflictError) -> Self {
+        Self::StateError(StateError::DocumentIndexBackfillConflictError(err))
+    }
+}
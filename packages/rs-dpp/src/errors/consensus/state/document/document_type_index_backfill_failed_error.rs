@@ -0,0 +1,55 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Backfill of indices {index_names:?} of document type {document_type_name} of data contract {data_contract_id} failed, they must be removed or changed by a contract update")]
+#[platform_serialize(unversioned)]
+pub struct DocumentTypeIndexBackfillFailedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    document_type_name: String,
+    index_names: Vec<String>,
+}
+
+impl DocumentTypeIndexBackfillFailedError {
+    pub fn new(
+        data_contract_id: Identifier,
+        document_type_name: String,
+        index_names: Vec<String>,
+    ) -> Self {
+        Self {
+            data_contract_id,
+            document_type_name,
+            index_names,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn document_type_name(&self) -> &String {
+        &self.document_type_name
+    }
+
+    pub fn index_names(&self) -> &Vec<String> {
+        &self.index_names
+    }
+}
+
+impl From<DocumentTypeIndexBackfillFailedError> for ConsensusError {
+    fn from(err: DocumentTypeIndexBackfillFailedError) -> Self {
+        Self::StateError(StateError::DocumentTypeIndexBackfillFailedError(err))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Indices of document type {document_type_name} of data contract {data_contract_id} are being backfilled")]
+#[platform_serialize(unversioned)]
+pub struct DocumentTypeIndexBackfillInProgressError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    document_type_name: String,
+}
+
+impl DocumentTypeIndexBackfillInProgressError {
+    pub fn new(data_contract_id: Identifier, document_type_name: String) -> Self {
+        Self {
+            data_contract_id,
+            document_type_name,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn document_type_name(&self) -> &String {
+        &self.document_type_name
+    }
+}
+
+impl From<DocumentTypeIndexBackfillInProgressError> for ConsensusError {
+    fn from(err: DocumentTypeIndexBackfillInProgressError) -> Self {
+        Self::StateError(StateError::DocumentTypeIndexBackfillInProgressError(err))
+    }
+}
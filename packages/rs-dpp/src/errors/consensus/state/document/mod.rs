@@ -1,8 +1,14 @@
+pub mod document_already_owned_error;
 pub mod document_already_present_error;
+pub mod document_incorrect_purchase_price_error;
+pub mod document_index_backfill_conflict_error;
+pub mod document_not_for_sale_error;
 pub mod document_not_found_error;
 pub mod document_owner_id_mismatch_error;
 pub mod document_timestamp_window_violation_error;
 pub mod document_timestamps_are_equal_error;
 pub mod document_timestamps_mismatch_error;
+pub mod document_type_index_backfill_failed_error;
+pub mod document_type_index_backfill_in_progress_error;
 pub mod duplicate_unique_index_error;
 pub mod invalid_document_revision_error;
@@ -0,0 +1,54 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::{Identifier, IdentityNonce};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} has invalid nonce {setting_nonce}. The current nonce is {current_nonce}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityNonceError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    current_nonce: IdentityNonce,
+    setting_nonce: IdentityNonce,
+}
+
+impl InvalidIdentityNonceError {
+    pub fn new(
+        identity_id: Identifier,
+        current_nonce: IdentityNonce,
+        setting_nonce: IdentityNonce,
+    ) -> Self {
+        Self {
+            identity_id,
+            current_nonce,
+            setting_nonce,
+        }
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+    pub fn current_nonce(&self) -> &IdentityNonce {
+        &self.current_nonce
+    }
+    pub fn setting_nonce(&self) -> &IdentityNonce {
+        &self.setting_nonce
+    }
+}
+impl From<InvalidIdentityNonceError> for ConsensusError {
+    fn from(err: InvalidIdentityNonceError) -> Self {
+        Self::StateError(StateError::InvalidIdentityNonceError(err))
+    }
+}
@@ -9,6 +9,7 @@ pub mod identity_public_key_already_exists_for_unique_contract_bounds_error;
 pub mod identity_public_key_disabled_at_window_violation_error;
 pub mod identity_public_key_is_disabled_error;
 pub mod identity_public_key_is_read_only_error;
+pub mod invalid_identity_nonce_error;
 pub mod invalid_identity_public_key_id_error;
 pub mod invalid_identity_revision_error;
 pub mod max_identity_public_key_limit_reached_error;
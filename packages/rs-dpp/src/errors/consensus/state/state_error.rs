@@ -5,14 +5,23 @@ use thiserror::Error;
 
 use crate::consensus::state::data_contract::data_contract_already_present_error::DataContractAlreadyPresentError;
 use crate::consensus::state::data_contract::data_contract_config_update_error::DataContractConfigUpdateError;
+use crate::consensus::state::data_contract::data_contract_is_not_deletable_error::DataContractIsNotDeletableError;
 use crate::consensus::state::data_contract::data_contract_is_readonly_error::DataContractIsReadonlyError;
+use crate::consensus::state::data_contract::data_contract_is_too_large_to_delete_error::DataContractIsTooLargeToDeleteError;
+use crate::consensus::state::data_contract::data_contract_owner_id_mismatch_error::DataContractOwnerIdMismatchError;
 #[cfg(feature = "state-transition-validation")]
 use crate::consensus::state::data_trigger::DataTriggerError;
+use crate::consensus::state::document::document_already_owned_error::DocumentAlreadyOwnedError;
 use crate::consensus::state::document::document_already_present_error::DocumentAlreadyPresentError;
+use crate::consensus::state::document::document_incorrect_purchase_price_error::DocumentIncorrectPurchasePriceError;
+use crate::consensus::state::document::document_index_backfill_conflict_error::DocumentIndexBackfillConflictError;
+use crate::consensus::state::document::document_not_for_sale_error::DocumentNotForSaleError;
 use crate::consensus::state::document::document_not_found_error::DocumentNotFoundError;
 use crate::consensus::state::document::document_owner_id_mismatch_error::DocumentOwnerIdMismatchError;
 use crate::consensus::state::document::document_timestamp_window_violation_error::DocumentTimestampWindowViolationError;
 use crate::consensus::state::document::document_timestamps_mismatch_error::DocumentTimestampsMismatchError;
+use crate::consensus::state::document::document_type_index_backfill_failed_error::DocumentTypeIndexBackfillFailedError;
+use crate::consensus::state::document::document_type_index_backfill_in_progress_error::DocumentTypeIndexBackfillInProgressError;
 use crate::consensus::state::document::duplicate_unique_index_error::DuplicateUniqueIndexError;
 use crate::consensus::state::document::invalid_document_revision_error::InvalidDocumentRevisionError;
 use crate::consensus::state::identity::duplicated_identity_public_key_id_state_error::DuplicatedIdentityPublicKeyIdStateError;
@@ -21,12 +30,14 @@ use crate::consensus::state::identity::identity_public_key_disabled_at_window_vi
 use crate::consensus::state::identity::identity_public_key_is_disabled_error::IdentityPublicKeyIsDisabledError;
 use crate::consensus::state::identity::identity_public_key_is_read_only_error::IdentityPublicKeyIsReadOnlyError;
 use crate::consensus::state::identity::invalid_identity_public_key_id_error::InvalidIdentityPublicKeyIdError;
+use crate::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
 use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use crate::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 use crate::consensus::state::identity::missing_identity_public_key_ids_error::MissingIdentityPublicKeyIdsError;
 use crate::consensus::state::identity::{
     IdentityAlreadyExistsError, IdentityInsufficientBalanceError,
 };
+use crate::consensus::state::state_transition::pending_state_transition_conflict_error::PendingStateTransitionConflictError;
 use crate::consensus::ConsensusError;
 use crate::consensus::state::identity::identity_public_key_already_exists_for_unique_contract_bounds_error::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError;
 
@@ -117,6 +128,39 @@ pub enum StateError {
 
     #[error(transparent)]
     DataContractConfigUpdateError(DataContractConfigUpdateError),
+
+    #[error(transparent)]
+    DataContractIsNotDeletableError(DataContractIsNotDeletableError),
+
+    #[error(transparent)]
+    DataContractIsTooLargeToDeleteError(DataContractIsTooLargeToDeleteError),
+
+    #[error(transparent)]
+    DataContractOwnerIdMismatchError(DataContractOwnerIdMismatchError),
+
+    #[error(transparent)]
+    DocumentNotForSaleError(DocumentNotForSaleError),
+
+    #[error(transparent)]
+    DocumentIncorrectPurchasePriceError(DocumentIncorrectPurchasePriceError),
+
+    #[error(transparent)]
+    DocumentAlreadyOwnedError(DocumentAlreadyOwnedError),
+
+    #[error(transparent)]
+    InvalidIdentityNonceError(InvalidIdentityNonceError),
+
+    #[error(transparent)]
+    DocumentTypeIndexBackfillInProgressError(DocumentTypeIndexBackfillInProgressError),
+
+    #[error(transparent)]
+    DocumentIndexBackfillConflictError(DocumentIndexBackfillConflictError),
+
+    #[error(transparent)]
+    PendingStateTransitionConflictError(PendingStateTransitionConflictError),
+
+    #[error(transparent)]
+    DocumentTypeIndexBackfillFailedError(DocumentTypeIndexBackfillFailedError),
 }
 
 impl From<StateError> for ConsensusError {
@@ -0,0 +1 @@
+pub mod pending_state_transition_conflict_error;
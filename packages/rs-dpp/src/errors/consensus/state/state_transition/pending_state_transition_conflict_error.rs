@@ -0,0 +1,46 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[platform_serialize(unversioned)]
+pub struct PendingStateTransitionConflictError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pending_state_transition_hash: [u8; 32],
+}
+
+impl Display for PendingStateTransitionConflictError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let hex = hex::encode(self.pending_state_transition_hash);
+        let message = format!("State transition conflicts with pending state transition {hex}");
+        f.write_str(&message)
+    }
+}
+
+impl PendingStateTransitionConflictError {
+    pub fn new(pending_state_transition_hash: [u8; 32]) -> Self {
+        Self {
+            pending_state_transition_hash,
+        }
+    }
+
+    pub fn pending_state_transition_hash(&self) -> &[u8; 32] {
+        &self.pending_state_transition_hash
+    }
+}
+
+impl From<PendingStateTransitionConflictError> for ConsensusError {
+    fn from(err: PendingStateTransitionConflictError) -> Self {
+        Self::StateError(StateError::PendingStateTransitionConflictError(err))
+    }
+}
@@ -199,6 +199,9 @@ pub enum ProtocolError {
     #[error("Public key generation error {0}")]
     PublicKeyGenerationError(String),
 
+    #[error("desired key with type, purpose and security level missing: {0}")]
+    DesiredKeyWithTypePurposeSecurityLevelMissing(String),
+
     #[error("corrupted code execution: {0}")]
     CorruptedCodeExecution(String),
 
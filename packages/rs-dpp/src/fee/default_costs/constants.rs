@@ -1,8 +1,11 @@
 use crate::fee::Credits;
+use crate::prelude::UserFeeIncrease;
 
 pub const BASE_ST_PROCESSING_FEE: Credits = 10000; // 84000
 pub const FEE_MULTIPLIER: Credits = 2;
 pub const DEFAULT_USER_TIP: Credits = 0;
+/// The maximum percentage a state transition can increase its processing fee by
+pub const MAX_USER_FEE_INCREASE: UserFeeIncrease = 1000;
 pub const STORAGE_CREDIT_PER_BYTE: Credits = 5000;
 pub const PROCESSING_CREDIT_PER_BYTE: Credits = 12;
 pub const DELETE_BASE_PROCESSING_COST: Credits = 2000; // 20000
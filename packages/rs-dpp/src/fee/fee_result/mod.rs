@@ -41,6 +41,7 @@ use crate::consensus::fee::fee_error::FeeError;
 use crate::fee::fee_result::refunds::FeeRefunds;
 use crate::fee::fee_result::BalanceChange::{AddToBalance, NoBalanceChange, RemoveFromBalance};
 use crate::fee::Credits;
+use crate::prelude::UserFeeIncrease;
 use crate::ProtocolError;
 use platform_value::Identifier;
 use std::cmp::Ordering;
@@ -249,6 +250,20 @@ impl FeeResult {
         }
     }
 
+    /// Increases the processing fee by the user fee increase percentage and returns
+    /// the increase, which is paid to the block proposer
+    pub fn apply_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) -> Credits {
+        let additional_processing_fee = (self.processing_fee as u128)
+            .saturating_mul(user_fee_increase as u128)
+            .saturating_div(100)
+            .min((u64::MAX - self.processing_fee) as u128)
+            as Credits;
+
+        self.processing_fee += additional_processing_fee;
+
+        additional_processing_fee
+    }
+
     /// Adds and self assigns result between two Fee Results
     pub fn checked_add_assign(&mut self, rhs: Self) -> Result<(), ProtocolError> {
         self.storage_fee = self
@@ -269,3 +284,32 @@ impl FeeResult {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod apply_user_fee_increase {
+        use super::*;
+
+        #[test]
+        fn should_increase_only_processing_fee_by_percentage() {
+            let mut fee_result = FeeResult::default_with_fees(1000, 2000);
+
+            let additional_processing_fee = fee_result.apply_user_fee_increase(50);
+
+            assert_eq!(additional_processing_fee, 1000);
+            assert_eq!(fee_result.storage_fee, 1000);
+            assert_eq!(fee_result.processing_fee, 3000);
+        }
+
+        #[test]
+        fn should_saturate_on_overflow() {
+            let mut fee_result = FeeResult::default_with_fees(0, u64::MAX / 2);
+
+            fee_result.apply_user_fee_increase(u16::MAX);
+
+            assert_eq!(fee_result.processing_fee, u64::MAX);
+        }
+    }
+}
@@ -64,6 +64,17 @@ lazy_static! {
 }
 
 impl KeyType {
+    /// All key types known to the protocol
+    pub fn all_key_types() -> [KeyType; 5] {
+        [
+            Self::ECDSA_SECP256K1,
+            Self::BLS12_381,
+            Self::ECDSA_HASH160,
+            Self::BIP13_SCRIPT_HASH,
+            Self::EDDSA_25519_HASH160,
+        ]
+    }
+
     /// Gets the default size of the public key
     pub fn default_size(&self) -> usize {
         KEY_TYPE_SIZES[self]
@@ -61,6 +61,10 @@ pub mod prelude {
     pub use crate::validation::ConsensusValidationResult;
     pub type TimestampMillis = u64;
     pub type Revision = u64;
+    /// A monotonically increasing counter of nonce-protected state transitions of an identity
+    pub type IdentityNonce = u64;
+    /// The percentage the processing fee of a state transition is increased by, paid to proposers
+    pub type UserFeeIncrease = u16;
 }
 
 pub use bincode;
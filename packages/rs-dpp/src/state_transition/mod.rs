@@ -18,7 +18,8 @@ mod state_transition_types;
 pub mod state_transition_factory;
 
 pub mod errors;
-use crate::util::hash::{hash_to_vec, ripemd160_sha256};
+pub mod proof_result;
+use crate::util::hash::{hash_single, hash_to_vec, ripemd160_sha256};
 
 mod serialization;
 pub mod state_transitions;
@@ -37,12 +38,16 @@ use crate::consensus::ConsensusError;
 use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use crate::identity::signer::Signer;
 use crate::identity::{IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
+use crate::prelude::UserFeeIncrease;
 pub use state_transitions::*;
 
 use crate::serialization::Signable;
 use crate::state_transition::data_contract_create_transition::{
     DataContractCreateTransition, DataContractCreateTransitionSignable,
 };
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteTransition, DataContractDeleteTransitionSignable,
+};
 use crate::state_transition::data_contract_update_transition::{
     DataContractUpdateTransition, DataContractUpdateTransitionSignable,
 };
@@ -84,6 +89,7 @@ macro_rules! call_method {
         match $state_transition {
             StateTransition::DataContractCreate(st) => st.$method($args),
             StateTransition::DataContractUpdate(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
             StateTransition::DocumentsBatch(st) => st.$method($args),
             StateTransition::IdentityCreate(st) => st.$method($args),
             StateTransition::IdentityTopUp(st) => st.$method($args),
@@ -96,6 +102,7 @@ macro_rules! call_method {
         match $state_transition {
             StateTransition::DataContractCreate(st) => st.$method(),
             StateTransition::DataContractUpdate(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
             StateTransition::DocumentsBatch(st) => st.$method(),
             StateTransition::IdentityCreate(st) => st.$method(),
             StateTransition::IdentityTopUp(st) => st.$method(),
@@ -111,6 +118,7 @@ macro_rules! call_getter_method_identity_signed {
         match $state_transition {
             StateTransition::DataContractCreate(st) => Some(st.$method($args)),
             StateTransition::DataContractUpdate(st) => Some(st.$method($args)),
+            StateTransition::DataContractDelete(st) => Some(st.$method($args)),
             StateTransition::DocumentsBatch(st) => Some(st.$method($args)),
             StateTransition::IdentityCreate(st) => None,
             StateTransition::IdentityTopUp(st) => None,
@@ -123,6 +131,7 @@ macro_rules! call_getter_method_identity_signed {
         match $state_transition {
             StateTransition::DataContractCreate(st) => Some(st.$method()),
             StateTransition::DataContractUpdate(st) => Some(st.$method()),
+            StateTransition::DataContractDelete(st) => Some(st.$method()),
             StateTransition::DocumentsBatch(st) => Some(st.$method()),
             StateTransition::IdentityCreate(_st) => None,
             StateTransition::IdentityTopUp(_st) => None,
@@ -138,6 +147,7 @@ macro_rules! call_method_identity_signed {
         match $state_transition {
             StateTransition::DataContractCreate(st) => st.$method($args),
             StateTransition::DataContractUpdate(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
             StateTransition::DocumentsBatch(st) => st.$method($args),
             StateTransition::IdentityCreate(_st) => {}
             StateTransition::IdentityTopUp(_st) => {}
@@ -150,6 +160,7 @@ macro_rules! call_method_identity_signed {
         match $state_transition {
             StateTransition::DataContractCreate(st) => st.$method(),
             StateTransition::DataContractUpdate(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
             StateTransition::DocumentsBatch(st) => st.$method(),
             StateTransition::IdentityCreate(st) => {}
             StateTransition::IdentityTopUp(st) => {}
@@ -165,6 +176,7 @@ macro_rules! call_errorable_method_identity_signed {
         match $state_transition {
             StateTransition::DataContractCreate(st) => st.$method($args),
             StateTransition::DataContractUpdate(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
             StateTransition::DocumentsBatch(st) => st.$method($args),
             StateTransition::IdentityCreate(_st) => Err(ProtocolError::CorruptedCodeExecution(
                 "identity create can not be called for identity signing".to_string(),
@@ -181,6 +193,7 @@ macro_rules! call_errorable_method_identity_signed {
         match $state_transition {
             StateTransition::DataContractCreate(st) => st.$method(),
             StateTransition::DataContractUpdate(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
             StateTransition::DocumentsBatch(st) => st.$method(),
             StateTransition::IdentityCreate(st) => Err(ProtocolError::CorruptedCodeExecution(
                 "identity create can not be called for identity signing".to_string(),
@@ -200,6 +213,7 @@ macro_rules! call_static_method {
         match $state_transition {
             StateTransition::DataContractCreate(_) => DataContractCreateTransition::$method(),
             StateTransition::DataContractUpdate(_) => DataContractUpdateTransition::$method(),
+            StateTransition::DataContractDelete(_) => DataContractDeleteTransition::$method(),
             StateTransition::DocumentsBatch(_) => DocumentsBatchTransition::$method(),
             StateTransition::IdentityCreate(_) => IdentityCreateTransition::$method(),
             StateTransition::IdentityTopUp(_) => IdentityTopUpTransition::$method(),
@@ -241,6 +255,7 @@ pub enum StateTransition {
     IdentityCreditWithdrawal(IdentityCreditWithdrawalTransition),
     IdentityUpdate(IdentityUpdateTransition),
     IdentityCreditTransfer(IdentityCreditTransferTransition),
+    DataContractDelete(DataContractDeleteTransition),
 }
 
 impl StateTransition {
@@ -270,6 +285,16 @@ impl StateTransition {
         }
     }
 
+    /// Returns the transaction id of the state transition.
+    ///
+    /// This is the sha256 hash of the serialized state transition, the same hash Tenderdash
+    /// uses to identify the transaction it is broadcast in.
+    pub fn transaction_id(&self) -> Result<[u8; 32], ProtocolError> {
+        Ok(hash_single(
+            crate::serialization::PlatformSerializable::serialize_to_bytes(self)?,
+        ))
+    }
+
     /// Returns state transition name
     pub fn name(&self) -> String {
         match self {
@@ -281,6 +306,7 @@ impl StateTransition {
             Self::IdentityCreditWithdrawal(_) => "IdentityCreditWithdrawal",
             Self::IdentityUpdate(_) => "IdentityUpdate",
             Self::IdentityCreditTransfer(_) => "IdentityCreditTransfer",
+            Self::DataContractDelete(_) => "DataContractDelete",
         }
         .to_string()
     }
@@ -305,6 +331,16 @@ impl StateTransition {
         call_method!(self, owner_id)
     }
 
+    /// returns the percentage the processing fee is increased by
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        call_method!(self, user_fee_increase)
+    }
+
+    /// set the percentage the processing fee is increased by
+    pub fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        call_method!(self, set_user_fee_increase, user_fee_increase)
+    }
+
     /// set a new signature
     pub fn set_signature(&mut self, signature: BinaryData) {
         call_method!(self, set_signature, signature)
@@ -333,6 +369,10 @@ impl StateTransition {
                 st.verify_public_key_level_and_purpose(identity_public_key)?;
                 st.verify_public_key_is_enabled(identity_public_key)?;
             }
+            StateTransition::DataContractDelete(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
             StateTransition::DocumentsBatch(st) => {
                 if identity_public_key.purpose() != Purpose::AUTHENTICATION {
                     return Err(ProtocolError::WrongPublicKeyPurposeError(
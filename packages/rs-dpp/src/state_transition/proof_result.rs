@@ -0,0 +1,25 @@
+use crate::data_contract::DataContract;
+use crate::document::Document;
+use crate::identity::{Identity, PartialIdentity};
+use platform_value::Identifier;
+use std::collections::BTreeMap;
+
+/// State of the Platform, proven to be the result of a state transition execution.
+///
+/// Returned when a proof is verified against the state transition that was expected to produce it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateTransitionProofResult {
+    /// Data contract stored by a data contract create or update transition
+    VerifiedDataContract(DataContract),
+    /// Identity registered by an identity create transition, or modified by a top up, withdrawal
+    /// or update transition
+    VerifiedIdentity(Identity),
+    /// Part of an identity proven after a state transition, when the full identity is not needed
+    VerifiedPartialIdentity(PartialIdentity),
+    /// Balances of the sender and the recipient after a credit transfer, in this order
+    VerifiedBalanceTransfer(PartialIdentity, PartialIdentity),
+    /// Documents after a documents batch transition; deleted documents are `None`
+    VerifiedDocuments(BTreeMap<Identifier, Option<Document>>),
+    /// Id of the data contract proven to be absent after a data contract delete transition
+    VerifiedDataContractDeleted(Identifier),
+}
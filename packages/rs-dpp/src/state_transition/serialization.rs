@@ -88,6 +88,7 @@ mod tests {
         let identity_topup_transition = IdentityTopUpTransitionV0 {
             asset_lock_proof: AssetLockProof::Instant(asset_lock_proof),
             identity_id: identity.id(),
+            user_fee_increase: 0,
             signature: [1u8; 65].to_vec().into(),
         };
         let state_transition: StateTransition = identity_topup_transition.into();
@@ -117,6 +118,7 @@ mod tests {
             .map(|public_key| public_key.into())
             .collect();
         let mut identity_update_transition = IdentityUpdateTransitionV0 {
+            user_fee_increase: 0,
             signature: Default::default(),
             signature_public_key_id: 0,
             identity_id: identity.id(),
@@ -183,6 +185,7 @@ mod tests {
             .map(|public_key| public_key.into())
             .collect();
         let mut identity_update_transition = IdentityUpdateTransitionV0 {
+            user_fee_increase: 0,
             signature: Default::default(),
             signature_public_key_id: 0,
             identity_id: identity.id(),
@@ -244,6 +247,8 @@ mod tests {
             pooling: Pooling::Standard,
             output_script: CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
             revision: 1,
+            nonce: 1,
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: [1u8; 65].to_vec().into(),
         };
@@ -293,6 +298,7 @@ mod tests {
                     .data_contract_owned()
                     .try_into_platform_versioned(platform_version)
                     .expect("expected a data contract"),
+                user_fee_increase: 0,
                 signature_public_key_id: 0,
                 signature: [1u8; 65].to_vec().into(),
             });
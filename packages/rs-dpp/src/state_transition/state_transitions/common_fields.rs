@@ -5,4 +5,5 @@ pub mod property_names {
     pub const SIGNATURE: &str = "signature";
     pub const TRANSITION_TYPE: &str = "type";
     pub const REVISION: &str = "revision";
+    pub const USER_FEE_INCREASE: &str = "userFeeIncrease";
 }
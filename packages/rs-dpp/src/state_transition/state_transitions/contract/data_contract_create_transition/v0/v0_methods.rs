@@ -36,7 +36,8 @@ impl DataContractCreateTransitionMethodsV0 for DataContractCreateTransitionV0 {
         data_contract.set_owner_id(identity.id);
         let transition = DataContractCreateTransition::V0(DataContractCreateTransitionV0 {
             data_contract: data_contract.try_into_platform_versioned(platform_version)?,
-            entropy: Default::default(),
+            entropy,
+            user_fee_increase: 0,
             signature_public_key_id: key_id,
             signature: Default::default(),
         });
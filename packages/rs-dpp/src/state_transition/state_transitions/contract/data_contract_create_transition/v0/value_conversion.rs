@@ -19,6 +19,10 @@ impl<'a> StateTransitionValueConvert<'a> for DataContractCreateTransitionV0 {
         platform_version: &PlatformVersion,
     ) -> Result<Self, ProtocolError> {
         Ok(DataContractCreateTransitionV0 {
+            user_fee_increase: raw_object
+                .remove_optional_integer(USER_FEE_INCREASE)
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or_default(),
             signature: raw_object
                 .remove_optional_binary_data(SIGNATURE)
                 .map_err(ProtocolError::ValueError)?
@@ -49,6 +53,10 @@ impl<'a> StateTransitionValueConvert<'a> for DataContractCreateTransitionV0 {
         platform_version: &PlatformVersion,
     ) -> Result<Self, ProtocolError> {
         Ok(DataContractCreateTransitionV0 {
+            user_fee_increase: raw_value_map
+                .remove_optional_integer(USER_FEE_INCREASE)
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or_default(),
             signature: raw_value_map
                 .remove_optional_binary_data(SIGNATURE)
                 .map_err(ProtocolError::ValueError)?
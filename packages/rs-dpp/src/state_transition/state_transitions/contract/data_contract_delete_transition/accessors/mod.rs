@@ -0,0 +1,50 @@
+mod v0;
+
+use crate::prelude::IdentityNonce;
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use platform_value::Identifier;
+pub use v0::*;
+
+impl DataContractDeleteTransitionAccessorsV0 for DataContractDeleteTransition {
+    fn data_contract_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.data_contract_id,
+        }
+    }
+
+    fn set_data_contract_id(&mut self, data_contract_id: Identifier) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.data_contract_id = data_contract_id;
+            }
+        }
+    }
+
+    fn owner_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.owner_id,
+        }
+    }
+
+    fn set_owner_id(&mut self, owner_id: Identifier) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.owner_id = owner_id;
+            }
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.nonce,
+        }
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.nonce = nonce;
+            }
+        }
+    }
+}
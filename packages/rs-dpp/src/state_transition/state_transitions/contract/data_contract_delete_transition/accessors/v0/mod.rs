@@ -0,0 +1,11 @@
+use crate::prelude::IdentityNonce;
+use platform_value::Identifier;
+
+pub trait DataContractDeleteTransitionAccessorsV0 {
+    fn data_contract_id(&self) -> Identifier;
+    fn set_data_contract_id(&mut self, data_contract_id: Identifier);
+    fn owner_id(&self) -> Identifier;
+    fn set_owner_id(&mut self, owner_id: Identifier);
+    fn nonce(&self) -> IdentityNonce;
+    fn set_nonce(&mut self, nonce: IdentityNonce);
+}
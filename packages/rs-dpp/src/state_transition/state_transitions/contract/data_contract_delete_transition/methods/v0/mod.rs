@@ -0,0 +1,38 @@
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::state_transition::{StateTransition, StateTransitionType};
+use crate::version::FeatureVersion;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+pub trait DataContractDeleteTransitionMethodsV0 {
+    /// Creates a signed transition deleting the data contract with the given identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_contract_id` - The identifier of the data contract to delete.
+    /// * `identity` - A reference to the `PartialIdentity` of the contract owner containing the public keys.
+    /// * `key_id` - The `KeyID` (public key identifier) to be used for signing the transition.
+    /// * `nonce` - The next identity nonce of the owner, one more than the nonce stored for the identity.
+    /// * `signer` - A reference to the `Signer` object that will sign the transition.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, ProtocolError>` - If successful, returns the signed transition.
+    ///   In case of any error, a relevant `ProtocolError` is returned.
+    fn new_from_data_contract_id<S: Signer>(
+        data_contract_id: Identifier,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Get State Transition Type
+    fn get_type() -> StateTransitionType {
+        StateTransitionType::DataContractDelete
+    }
+}
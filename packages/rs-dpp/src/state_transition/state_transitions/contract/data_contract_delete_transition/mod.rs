@@ -0,0 +1,89 @@
+pub mod accessors;
+pub mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod state_transition_like;
+pub mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::state_transition::data_contract_delete_transition::fields::property_names::{
+    DATA_CONTRACT_ID, OWNER_ID,
+};
+use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0Signable;
+use crate::state_transition::StateTransitionFieldTypes;
+
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use fields::*;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+use serde::{Deserialize, Serialize};
+
+pub type DataContractDeleteTransitionLatest = DataContractDeleteTransitionV0;
+
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_version_path_bounds(
+    "dpp.state_transition_serialization_versions.contract_delete_state_transition"
+)]
+pub enum DataContractDeleteTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(DataContractDeleteTransitionV0),
+}
+
+impl DataContractDeleteTransition {
+    pub fn default_versioned(platform_version: &PlatformVersion) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .state_transition_serialization_versions
+            .contract_delete_state_transition
+            .default_current_version
+        {
+            0 => Ok(DataContractDeleteTransition::V0(
+                DataContractDeleteTransitionV0::default(),
+            )),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DataContractDeleteTransition::default_versioned".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
+
+impl StateTransitionFieldTypes for DataContractDeleteTransition {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![DATA_CONTRACT_ID, OWNER_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
@@ -0,0 +1,89 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::identity::KeyID;
+
+use crate::prelude::{Identifier, IdentityNonce};
+
+use crate::prelude::UserFeeIncrease;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_value::BinaryData;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    PlatformSignable,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+#[derive(Default)]
+pub struct DataContractDeleteTransitionV0 {
+    // Own ST fields
+    pub data_contract_id: Identifier,
+    pub owner_id: Identifier,
+    pub nonce: IdentityNonce,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+    use platform_value::Identifier;
+    use rand::Rng;
+    use std::fmt::Debug;
+
+    fn test_data_contract_delete_transition<
+        T: PlatformSerializable + PlatformDeserializable + Debug + PartialEq,
+    >(
+        transition: T,
+    ) where
+        <T as PlatformSerializable>::Error: std::fmt::Debug,
+    {
+        let serialized = T::serialize_to_bytes(&transition).expect("expected to serialize");
+        let deserialized =
+            T::deserialize_from_bytes(serialized.as_slice()).expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+
+    #[test]
+    fn test_data_contract_delete_transition1() {
+        let mut rng = rand::thread_rng();
+        let transition = DataContractDeleteTransitionV0 {
+            data_contract_id: Identifier::random(),
+            owner_id: Identifier::random(),
+            nonce: rng.gen(),
+            user_fee_increase: 0,
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_data_contract_delete_transition(transition);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::state_transition::data_contract_delete_transition::fields::property_names::*;
+use crate::state_transition::data_contract_delete_transition::fields::*;
+use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for DataContractDeleteTransitionV0 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![DATA_CONTRACT_ID, OWNER_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
@@ -0,0 +1,45 @@
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::serialization::Signable;
+
+use crate::state_transition::data_contract_delete_transition::methods::DataContractDeleteTransitionMethodsV0;
+use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::{NonConsensusError, ProtocolError};
+use platform_version::version::PlatformVersion;
+
+impl DataContractDeleteTransitionMethodsV0 for DataContractDeleteTransitionV0 {
+    fn new_from_data_contract_id<S: Signer>(
+        data_contract_id: Identifier,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        nonce: IdentityNonce,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut state_transition: StateTransition = DataContractDeleteTransitionV0 {
+            data_contract_id,
+            owner_id: identity.id,
+            nonce,
+            user_fee_increase: 0,
+            signature_public_key_id: key_id,
+            signature: Default::default(),
+        }
+        .into();
+        let value = state_transition.signable_bytes()?;
+        let public_key =
+            identity
+                .loaded_public_keys
+                .get(&key_id)
+                .ok_or(ProtocolError::NonConsensusError(
+                    NonConsensusError::StateTransitionCreationError(
+                        "public key did not exist".to_string(),
+                    ),
+                ))?;
+        state_transition.set_signature(signer.sign(public_key, &value)?);
+        Ok(state_transition)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for DataContractDeleteTransitionV0 {
+    fn feature_version(&self) -> FeatureVersion {
+        0
+    }
+}
@@ -11,6 +11,7 @@ mod version;
 use platform_value::BinaryData;
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::UserFeeIncrease;
 use bincode::{Decode, Encode};
 use platform_serialization_derive::PlatformSignable;
 use platform_version::version::PlatformVersion;
@@ -30,6 +31,8 @@ use crate::{data_contract::DataContract, identity::KeyID, ProtocolError};
 
 pub struct DataContractUpdateTransitionV0 {
     pub data_contract: DataContractInSerializationFormat,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
     #[platform_signable(exclude_from_sig_hash)]
     pub signature_public_key_id: KeyID,
     #[platform_signable(exclude_from_sig_hash)]
@@ -45,6 +48,7 @@ impl TryFromPlatformVersioned<DataContract> for DataContractUpdateTransitionV0 {
     ) -> Result<Self, Self::Error> {
         Ok(DataContractUpdateTransitionV0 {
             data_contract: value.try_into_platform_versioned(platform_version)?,
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: Default::default(),
         })
@@ -1,3 +1,4 @@
+use crate::prelude::UserFeeIncrease;
 use platform_value::BinaryData;
 
 use crate::{
@@ -40,4 +41,12 @@ impl StateTransitionLike for DataContractUpdateTransitionV0 {
     fn owner_id(&self) -> Identifier {
         self.data_contract.owner_id()
     }
+
+    fn user_fee_increase(&self) -> UserFeeIncrease {
+        self.user_fee_increase
+    }
+
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        self.user_fee_increase = user_fee_increase
+    }
 }
@@ -24,6 +24,7 @@ impl DataContractUpdateTransitionMethodsV0 for DataContractUpdateTransitionV0 {
     ) -> Result<StateTransition, ProtocolError> {
         let transition = DataContractUpdateTransition::V0(DataContractUpdateTransitionV0 {
             data_contract: data_contract.try_into_platform_versioned(platform_version)?,
+            user_fee_increase: 0,
             signature_public_key_id: key_id,
             signature: Default::default(),
         });
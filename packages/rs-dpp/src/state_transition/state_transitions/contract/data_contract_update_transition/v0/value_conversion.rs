@@ -49,6 +49,10 @@ impl<'a> StateTransitionValueConvert<'a> for DataContractUpdateTransitionV0 {
         platform_version: &PlatformVersion,
     ) -> Result<Self, ProtocolError> {
         Ok(DataContractUpdateTransitionV0 {
+            user_fee_increase: raw_object
+                .remove_optional_integer(USER_FEE_INCREASE)
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or_default(),
             signature: raw_object
                 .remove_optional_binary_data(SIGNATURE)
                 .map_err(ProtocolError::ValueError)?
@@ -75,6 +79,10 @@ impl<'a> StateTransitionValueConvert<'a> for DataContractUpdateTransitionV0 {
         platform_version: &PlatformVersion,
     ) -> Result<Self, ProtocolError> {
         Ok(DataContractUpdateTransitionV0 {
+            user_fee_increase: raw_value_map
+                .remove_optional_integer(USER_FEE_INCREASE)
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or_default(),
             signature: raw_value_map
                 .remove_optional_binary_data(SIGNATURE)
                 .map_err(ProtocolError::ValueError)?
@@ -7,6 +7,10 @@ pub enum DocumentTransitionActionType {
     Create, //the entropy used
     Replace,
     Delete,
+    Transfer,
+    UpdatePrice,
+    Purchase,
+    Patch,
 }
 
 pub trait TransitionActionTypeGetter {
@@ -19,6 +23,10 @@ impl TransitionActionTypeGetter for DocumentTransition {
             DocumentTransition::Create(_) => DocumentTransitionActionType::Create,
             DocumentTransition::Delete(_) => DocumentTransitionActionType::Delete,
             DocumentTransition::Replace(_) => DocumentTransitionActionType::Replace,
+            DocumentTransition::Transfer(_) => DocumentTransitionActionType::Transfer,
+            DocumentTransition::UpdatePrice(_) => DocumentTransitionActionType::UpdatePrice,
+            DocumentTransition::Purchase(_) => DocumentTransitionActionType::Purchase,
+            DocumentTransition::Patch(_) => DocumentTransitionActionType::Patch,
         }
     }
 }
@@ -31,6 +39,10 @@ impl TryFrom<&str> for DocumentTransitionActionType {
             "create" => Ok(DocumentTransitionActionType::Create),
             "replace" => Ok(DocumentTransitionActionType::Replace),
             "delete" => Ok(DocumentTransitionActionType::Delete),
+            "transfer" => Ok(DocumentTransitionActionType::Transfer),
+            "updatePrice" => Ok(DocumentTransitionActionType::UpdatePrice),
+            "purchase" => Ok(DocumentTransitionActionType::Purchase),
+            "patch" => Ok(DocumentTransitionActionType::Patch),
             action_type => Err(ProtocolError::Generic(format!(
                 "unknown action type {action_type}"
             ))),
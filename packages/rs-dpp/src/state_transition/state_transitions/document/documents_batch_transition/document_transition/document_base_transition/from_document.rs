@@ -1,4 +1,5 @@
 use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::document_patch::DocumentPatch;
 use crate::document::Document;
 use crate::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
 use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
@@ -27,4 +28,28 @@ impl DocumentBaseTransition {
             }),
         }
     }
+
+    pub fn from_document_patch(
+        document_patch: &DocumentPatch,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .document_base_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(
+                DocumentBaseTransitionV0::from_document_patch(document_patch, document_type).into(),
+            ),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentBaseTransition::from_document_patch".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::document_patch::DocumentPatch;
 use crate::document::{Document, DocumentV0Getters};
 use crate::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
 
@@ -14,4 +15,15 @@ impl DocumentBaseTransitionV0 {
             data_contract_id: document_type.data_contract_id(),
         }
     }
+
+    pub(in crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_base_transition) fn from_document_patch(
+        document_patch: &DocumentPatch,
+        document_type: DocumentTypeRef,
+    ) -> Self {
+        DocumentBaseTransitionV0 {
+            id: document_patch.id,
+            document_type_name: document_type.name().to_string(),
+            data_contract_id: document_type.data_contract_id(),
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use platform_version::version::{FeatureVersion, PlatformVersion};
+use crate::data_contract::document_type::{DocumentTypeRef};
+use crate::document::document_patch::DocumentPatch;
+use crate::ProtocolError;
+use crate::state_transition::documents_batch_transition::document_transition::{DocumentPatchTransition};
+use crate::state_transition::documents_batch_transition::document_transition::document_patch_transition::DocumentPatchTransitionV0;
+
+impl DocumentPatchTransition {
+    pub fn from_document_patch(
+        document_patch: DocumentPatch,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .document_patch_state_transition
+                .bounds
+                .default_current_version,
+        ) {
+            0 => Ok(DocumentPatchTransitionV0::from_document_patch(
+                document_patch,
+                document_type,
+                platform_version,
+                base_feature_version,
+            )?
+            .into()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentPatchTransition::from_document_patch".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use platform_version::version::{FeatureVersion, PlatformVersion};
+use crate::data_contract::document_type::{DocumentTypeRef};
+use crate::document::document_patch::DocumentPatch;
+use crate::document::errors::DocumentError;
+use crate::ProtocolError;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_patch_transition::DocumentPatchTransitionV0;
+
+impl DocumentPatchTransitionV0 {
+    pub(crate) fn from_document_patch(
+        document_patch: DocumentPatch,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        let base = DocumentBaseTransition::from_document_patch(
+            &document_patch,
+            document_type,
+            platform_version,
+            base_feature_version,
+        )?;
+
+        let Some(revision) = document_patch.revision else {
+            return Err(ProtocolError::Document(Box::new(
+                DocumentError::DocumentPatchNoRevisionError {
+                    document_patch: Box::new(document_patch),
+                },
+            )));
+        };
+
+        Ok(DocumentPatchTransitionV0 {
+            base,
+            revision,
+            updated_at: document_patch.updated_at,
+            data: document_patch.properties,
+        })
+    }
+}
@@ -0,0 +1,52 @@
+mod from_document;
+pub mod v0_methods;
+
+use crate::identity::TimestampMillis;
+use crate::prelude::Revision;
+use bincode::{Decode, Encode};
+use derive_more::Display;
+
+use platform_value::Value;
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+
+/// Updates only some properties of an existing document.
+///
+/// The properties are merged into the stored document, properties not present in `data`
+/// keep their stored value. The merged document must be valid against the document type
+/// schema, like the one of a replace transition.
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq, Display)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[display(
+    fmt = "Base: {}, Revision: {}, Updated At: {:?}, Data: {:?}",
+    "base",
+    "revision",
+    "updated_at",
+    "data"
+)]
+pub struct DocumentPatchTransitionV0 {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: DocumentBaseTransition,
+    /// The revision of the patched document, it must follow the stored one
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename = "$revision")
+    )]
+    pub revision: Revision,
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(skip_serializing_if = "Option::is_none", rename = "$updatedAt")
+    )]
+    pub updated_at: Option<TimestampMillis>,
+    /// The changed properties
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub data: BTreeMap<String, Value>,
+}
@@ -11,7 +11,11 @@ pub mod action_type;
 pub mod document_base_transition;
 pub mod document_create_transition;
 pub mod document_delete_transition;
+pub mod document_patch_transition;
+pub mod document_purchase_transition;
 pub mod document_replace_transition;
+pub mod document_transfer_transition;
+pub mod document_update_price_transition;
 
 use crate::identity::TimestampMillis;
 use crate::prelude::Revision;
@@ -19,12 +23,20 @@ use crate::state_transition::documents_batch_transition::document_base_transitio
 use derive_more::Display;
 pub use document_create_transition::DocumentCreateTransition;
 pub use document_delete_transition::DocumentDeleteTransition;
+pub use document_patch_transition::DocumentPatchTransition;
+pub use document_purchase_transition::DocumentPurchaseTransition;
 pub use document_replace_transition::DocumentReplaceTransition;
+pub use document_transfer_transition::DocumentTransferTransition;
+pub use document_update_price_transition::DocumentUpdatePriceTransition;
 use platform_value::Value;
 
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_delete_transition::v0::v0_methods::DocumentDeleteTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_patch_transition::v0::v0_methods::DocumentPatchTransitionV0Methods;
 
 pub const PROPERTY_ACTION: &str = "$action";
 
@@ -77,6 +89,18 @@ pub enum DocumentTransition {
 
     #[display(fmt = "DeleteDocumentTransition({})", "_0")]
     Delete(DocumentDeleteTransition),
+
+    #[display(fmt = "TransferDocumentTransition({})", "_0")]
+    Transfer(DocumentTransferTransition),
+
+    #[display(fmt = "UpdatePriceDocumentTransition({})", "_0")]
+    UpdatePrice(DocumentUpdatePriceTransition),
+
+    #[display(fmt = "PurchaseDocumentTransition({})", "_0")]
+    Purchase(DocumentPurchaseTransition),
+
+    #[display(fmt = "PatchDocumentTransition({})", "_0")]
+    Patch(DocumentPatchTransition),
 }
 
 //
@@ -210,6 +234,38 @@ impl DocumentTransition {
             None
         }
     }
+
+    pub fn as_transition_transfer(&self) -> Option<&DocumentTransferTransition> {
+        if let Self::Transfer(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_transition_update_price(&self) -> Option<&DocumentUpdatePriceTransition> {
+        if let Self::UpdatePrice(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_transition_purchase(&self) -> Option<&DocumentPurchaseTransition> {
+        if let Self::Purchase(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_transition_patch(&self) -> Option<&DocumentPatchTransition> {
+        if let Self::Patch(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
 }
 
 impl DocumentTransitionV0Methods for DocumentTransition {
@@ -218,6 +274,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.base(),
             DocumentTransition::Replace(t) => t.base(),
             DocumentTransition::Delete(t) => t.base(),
+            DocumentTransition::Transfer(t) => t.base(),
+            DocumentTransition::UpdatePrice(t) => t.base(),
+            DocumentTransition::Purchase(t) => t.base(),
+            DocumentTransition::Patch(t) => t.base(),
         }
     }
 
@@ -226,6 +286,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.created_at(),
             DocumentTransition::Replace(_) => None,
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
+            DocumentTransition::Patch(_) => None,
         }
     }
 
@@ -234,6 +298,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.updated_at(),
             DocumentTransition::Replace(t) => t.updated_at(),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
+            DocumentTransition::Patch(t) => t.updated_at(),
         }
     }
 
@@ -243,6 +311,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(ref mut t) => t.set_created_at(timestamp_millis),
             DocumentTransition::Replace(_) => {}
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
+            DocumentTransition::Patch(_) => {}
         }
     }
 
@@ -251,6 +323,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(ref mut t) => t.set_updated_at(timestamp_millis),
             DocumentTransition::Replace(ref mut t) => t.set_updated_at(timestamp_millis),
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
+            DocumentTransition::Patch(ref mut t) => t.set_updated_at(timestamp_millis),
         }
     }
 
@@ -259,6 +335,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(_) => {}
             DocumentTransition::Replace(ref mut t) => t.set_revision(revision),
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(ref mut t) => t.set_revision(revision),
+            DocumentTransition::UpdatePrice(ref mut t) => t.set_revision(revision),
+            DocumentTransition::Purchase(ref mut t) => t.set_revision(revision),
+            DocumentTransition::Patch(ref mut t) => t.set_revision(revision),
         }
     }
 
@@ -267,6 +347,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.data().get(path),
             DocumentTransition::Replace(t) => t.data().get(path),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
+            DocumentTransition::Patch(t) => t.data().get(path),
         }
     }
 
@@ -287,6 +371,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => Some(t.data()),
             DocumentTransition::Replace(t) => Some(t.data()),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
+            DocumentTransition::Patch(t) => Some(t.data()),
         }
     }
 
@@ -295,6 +383,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(_) => Some(1),
             DocumentTransition::Replace(t) => Some(t.revision()),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(t) => Some(t.revision()),
+            DocumentTransition::UpdatePrice(t) => Some(t.revision()),
+            DocumentTransition::Purchase(t) => Some(t.revision()),
+            DocumentTransition::Patch(t) => Some(t.revision()),
         }
     }
 
@@ -312,6 +404,14 @@ impl DocumentTransitionV0Methods for DocumentTransition {
                     .insert(property_name, value);
             }
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
+            DocumentTransition::Patch(document_patch_transition) => {
+                document_patch_transition
+                    .data_mut()
+                    .insert(property_name, value);
+            }
         }
     }
 
@@ -324,6 +424,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.base_mut(),
             DocumentTransition::Replace(t) => t.base_mut(),
             DocumentTransition::Delete(t) => t.base_mut(),
+            DocumentTransition::Transfer(t) => t.base_mut(),
+            DocumentTransition::UpdatePrice(t) => t.base_mut(),
+            DocumentTransition::Purchase(t) => t.base_mut(),
+            DocumentTransition::Patch(t) => t.base_mut(),
         }
     }
 
@@ -332,6 +436,10 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => Some(t.data_mut()),
             DocumentTransition::Replace(t) => Some(t.data_mut()),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
+            DocumentTransition::Patch(t) => Some(t.data_mut()),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::prelude::UserFeeIncrease;
 use crate::state_transition::documents_batch_transition::DocumentsBatchTransition;
 use crate::state_transition::{StateTransitionLike, StateTransitionType};
 use crate::version::FeatureVersion;
@@ -46,4 +47,18 @@ impl StateTransitionLike for DocumentsBatchTransition {
             DocumentsBatchTransition::V0(transition) => transition.owner_id(),
         }
     }
+
+    fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            DocumentsBatchTransition::V0(transition) => transition.user_fee_increase(),
+        }
+    }
+
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        match self {
+            DocumentsBatchTransition::V0(transition) => {
+                transition.set_user_fee_increase(user_fee_increase)
+            }
+        }
+    }
 }
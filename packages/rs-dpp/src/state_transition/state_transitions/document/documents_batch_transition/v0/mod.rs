@@ -10,6 +10,7 @@ mod version;
 
 use crate::identity::KeyID;
 
+use crate::prelude::UserFeeIncrease;
 use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
 use crate::ProtocolError;
 use bincode::{Decode, Encode};
@@ -27,6 +28,8 @@ use serde::{Deserialize, Serialize};
 pub struct DocumentsBatchTransitionV0 {
     pub owner_id: Identifier,
     pub transitions: Vec<DocumentTransition>,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
     #[platform_signable(exclude_from_sig_hash)]
     pub signature_public_key_id: KeyID,
     #[platform_signable(exclude_from_sig_hash)]
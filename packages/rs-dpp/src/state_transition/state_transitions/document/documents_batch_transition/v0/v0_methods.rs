@@ -51,6 +51,7 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransitionV0 {
         let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
             owner_id,
             transitions: vec![create_transition.into()],
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: Default::default(),
         }
@@ -86,6 +87,7 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransitionV0 {
         let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
             owner_id,
             transitions: vec![replace_transition.into()],
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: Default::default(),
         }
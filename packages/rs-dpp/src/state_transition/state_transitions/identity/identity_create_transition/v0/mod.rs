@@ -10,6 +10,7 @@ mod version;
 
 use std::convert::TryFrom;
 
+use crate::prelude::UserFeeIncrease;
 use bincode::{Decode, Encode};
 use platform_serialization_derive::PlatformSignable;
 
@@ -45,6 +46,8 @@ pub struct IdentityCreateTransitionV0 {
     #[platform_signable(into = "Vec<IdentityPublicKeyInCreationSignable>")]
     pub public_keys: Vec<IdentityPublicKeyInCreation>,
     pub asset_lock_proof: AssetLockProof,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
     #[platform_signable(exclude_from_sig_hash)]
     pub signature: BinaryData,
     #[cfg_attr(feature = "state-transition-serde-conversion", serde(skip))]
@@ -63,6 +66,8 @@ struct IdentityCreateTransitionV0Inner {
     asset_lock_proof: AssetLockProof,
     // Generic identity ST fields
     // protocol_version: u32,
+    #[serde(default)]
+    user_fee_increase: UserFeeIncrease,
     signature: BinaryData,
 }
 
@@ -73,12 +78,14 @@ impl TryFrom<IdentityCreateTransitionV0Inner> for IdentityCreateTransitionV0 {
         let IdentityCreateTransitionV0Inner {
             public_keys,
             asset_lock_proof,
+            user_fee_increase,
             signature,
         } = value;
         let identity_id = asset_lock_proof.create_identifier()?;
         Ok(Self {
             public_keys,
             asset_lock_proof,
+            user_fee_increase,
             signature,
             identity_id,
         })
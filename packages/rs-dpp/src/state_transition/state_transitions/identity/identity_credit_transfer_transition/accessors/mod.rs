@@ -1,5 +1,6 @@
 mod v0;
 
+use crate::prelude::IdentityNonce;
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use platform_value::Identifier;
 pub use v0::*;
@@ -46,4 +47,18 @@ impl IdentityCreditTransferTransitionAccessorsV0 for IdentityCreditTransferTrans
             }
         }
     }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityCreditTransferTransition::V0(transition) => transition.nonce,
+        }
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            IdentityCreditTransferTransition::V0(transition) => {
+                transition.nonce = nonce;
+            }
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use crate::prelude::IdentityNonce;
 use platform_value::Identifier;
 
 pub trait IdentityCreditTransferTransitionAccessorsV0 {
@@ -7,4 +8,6 @@ pub trait IdentityCreditTransferTransitionAccessorsV0 {
     fn set_identity_id(&mut self, identity_id: Identifier);
     fn recipient_id(&self) -> Identifier;
     fn set_recipient_id(&mut self, recipient_id: Identifier);
+    fn nonce(&self) -> IdentityNonce;
+    fn set_nonce(&mut self, nonce: IdentityNonce);
 }
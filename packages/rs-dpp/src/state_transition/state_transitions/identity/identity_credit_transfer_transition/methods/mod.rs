@@ -2,6 +2,52 @@ mod v0;
 
 pub use v0::*;
 
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{Identifier, IdentityNonce};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
 
-impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransition {}
+impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        to_identity_with_identifier: Identifier,
+        amount: u64,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .identity_credit_transfer_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(IdentityCreditTransferTransitionV0::try_from_identity(
+                identity,
+                to_identity_with_identifier,
+                amount,
+                nonce,
+                signer,
+                platform_version,
+                version,
+            )?),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityCreditTransferTransition version for try_from_identity {v}"
+            ))),
+        }
+    }
+}
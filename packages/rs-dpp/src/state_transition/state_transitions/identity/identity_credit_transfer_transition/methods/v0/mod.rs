@@ -1,6 +1,34 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{Identifier, IdentityNonce};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
 use crate::state_transition::StateTransitionType;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
 
 pub trait IdentityCreditTransferTransitionMethodsV0 {
+    /// Creates a signed credit transfer from `identity` to the identity with the given identifier.
+    ///
+    /// `nonce` must be the next identity nonce, one more than the nonce stored for the identity.
+    ///
+    /// The transition is signed with the first critical authentication key of the identity.
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        to_identity_with_identifier: Identifier,
+        amount: u64,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
     /// Get State Transition Type
     fn get_type() -> StateTransitionType {
         StateTransitionType::IdentityCreditTransfer
@@ -1,3 +1,4 @@
+use crate::prelude::UserFeeIncrease;
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use crate::state_transition::{StateTransitionLike, StateTransitionType};
 use crate::version::FeatureVersion;
@@ -48,4 +49,18 @@ impl StateTransitionLike for IdentityCreditTransferTransition {
             IdentityCreditTransferTransition::V0(transition) => transition.owner_id(),
         }
     }
+
+    fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            IdentityCreditTransferTransition::V0(transition) => transition.user_fee_increase(),
+        }
+    }
+
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        match self {
+            IdentityCreditTransferTransition::V0(transition) => {
+                transition.set_user_fee_increase(user_fee_increase)
+            }
+        }
+    }
 }
@@ -10,8 +10,9 @@ mod version;
 
 use crate::identity::KeyID;
 
-use crate::prelude::Identifier;
+use crate::prelude::{Identifier, IdentityNonce};
 
+use crate::prelude::UserFeeIncrease;
 use crate::ProtocolError;
 use bincode::{Decode, Encode};
 use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
@@ -40,6 +41,9 @@ pub struct IdentityCreditTransferTransitionV0 {
     pub identity_id: Identifier,
     pub recipient_id: Identifier,
     pub amount: u64,
+    pub nonce: IdentityNonce,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
     #[platform_signable(exclude_from_sig_hash)]
     pub signature_public_key_id: KeyID,
     #[platform_signable(exclude_from_sig_hash)]
@@ -76,6 +80,8 @@ mod test {
             identity_id: Identifier::random(),
             recipient_id: Identifier::random(),
             amount: rng.gen(),
+            nonce: rng.gen(),
+            user_fee_increase: 0,
             signature_public_key_id: rng.gen(),
             signature: [0; 65].to_vec().into(),
         };
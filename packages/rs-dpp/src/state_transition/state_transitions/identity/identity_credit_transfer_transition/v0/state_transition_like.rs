@@ -1,3 +1,4 @@
+use crate::prelude::UserFeeIncrease;
 use platform_value::BinaryData;
 
 use crate::{
@@ -49,4 +50,12 @@ impl StateTransitionLike for IdentityCreditTransferTransitionV0 {
     fn owner_id(&self) -> Identifier {
         self.identity_id
     }
+
+    fn user_fee_increase(&self) -> UserFeeIncrease {
+        self.user_fee_increase
+    }
+
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        self.user_fee_increase = user_fee_increase
+    }
 }
@@ -1,4 +1,60 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::accessors::IdentityGettersV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, KeyType, Purpose, SecurityLevel};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{Identifier, IdentityNonce};
 use crate::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
 use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::{GetDataContractSecurityLevelRequirementFn, StateTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
 
-impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransitionV0 {}
+impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        to_identity_with_identifier: Identifier,
+        amount: u64,
+        nonce: IdentityNonce,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut transition: StateTransition = IdentityCreditTransferTransitionV0 {
+            identity_id: identity.id(),
+            recipient_id: to_identity_with_identifier,
+            amount,
+            nonce,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let identity_public_key = identity
+            .get_first_public_key_matching(
+                Purpose::AUTHENTICATION,
+                [SecurityLevel::CRITICAL].into(),
+                KeyType::all_key_types().into(),
+            )
+            .ok_or(
+                ProtocolError::DesiredKeyWithTypePurposeSecurityLevelMissing(
+                    "no critical authentication public key to sign the credit transfer".to_string(),
+                ),
+            )?;
+
+        transition.sign_external(
+            identity_public_key,
+            signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+
+        Ok(transition)
+    }
+}
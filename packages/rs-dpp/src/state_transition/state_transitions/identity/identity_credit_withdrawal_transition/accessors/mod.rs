@@ -4,7 +4,7 @@ use crate::identity::core_script::CoreScript;
 use platform_value::Identifier;
 pub use v0::*;
 
-use crate::prelude::Revision;
+use crate::prelude::{IdentityNonce, Revision};
 use crate::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
 
 use crate::withdrawal::Pooling;
@@ -34,6 +34,18 @@ impl IdentityCreditWithdrawalTransitionAccessorsV0 for IdentityCreditWithdrawalT
         }
     }
 
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            IdentityCreditWithdrawalTransition::V0(transition) => transition.nonce = nonce,
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityCreditWithdrawalTransition::V0(transition) => transition.nonce,
+        }
+    }
+
     fn pooling(&self) -> Pooling {
         match self {
             IdentityCreditWithdrawalTransition::V0(transition) => transition.pooling,
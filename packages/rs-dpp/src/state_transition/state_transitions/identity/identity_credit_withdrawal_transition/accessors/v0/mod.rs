@@ -1,5 +1,5 @@
 use crate::identity::core_script::CoreScript;
-use crate::prelude::Revision;
+use crate::prelude::{IdentityNonce, Revision};
 use crate::withdrawal::Pooling;
 use platform_value::Identifier;
 
@@ -8,6 +8,8 @@ pub trait IdentityCreditWithdrawalTransitionAccessorsV0 {
     fn amount(&self) -> u64;
     fn set_revision(&mut self, revision: Revision);
     fn revision(&self) -> Revision;
+    fn set_nonce(&mut self, nonce: IdentityNonce);
+    fn nonce(&self) -> IdentityNonce;
     fn pooling(&self) -> Pooling;
     fn core_fee_per_byte(&self) -> u32;
     fn output_script(&self) -> CoreScript;
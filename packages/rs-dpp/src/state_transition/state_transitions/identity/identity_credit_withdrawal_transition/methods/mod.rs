@@ -1,5 +1,59 @@
 mod v0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::core_script::CoreScript;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::IdentityNonce;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
 use crate::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::withdrawal::Pooling;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
 pub use v0::*;
 
-impl IdentityCreditWithdrawalTransitionMethodsV0 for IdentityCreditWithdrawalTransition {}
+impl IdentityCreditWithdrawalTransitionMethodsV0 for IdentityCreditWithdrawalTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        output_script: CoreScript,
+        amount: u64,
+        pooling: Pooling,
+        core_fee_per_byte: u32,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .identity_credit_withdrawal_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(IdentityCreditWithdrawalTransitionV0::try_from_identity(
+                identity,
+                output_script,
+                amount,
+                pooling,
+                core_fee_per_byte,
+                nonce,
+                signer,
+                platform_version,
+                version,
+            )?),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityCreditWithdrawalTransition version for try_from_identity {v}"
+            ))),
+        }
+    }
+}
@@ -1,6 +1,40 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::core_script::CoreScript;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::IdentityNonce;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
 use crate::state_transition::StateTransitionType;
+#[cfg(feature = "state-transition-signing")]
+use crate::withdrawal::Pooling;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
 
 pub trait IdentityCreditWithdrawalTransitionMethodsV0 {
+    /// Creates a signed withdrawal of `amount` credits from `identity` to `output_script`.
+    ///
+    /// The transition bumps the identity revision and is signed with the first critical
+    /// authentication key of the identity. `nonce` must be the next identity nonce, one more
+    /// than the nonce stored for the identity.
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        output_script: CoreScript,
+        amount: u64,
+        pooling: Pooling,
+        core_fee_per_byte: u32,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
     /// Get State Transition Type
     fn get_type() -> StateTransitionType {
         StateTransitionType::IdentityCreditWithdrawal
@@ -3,10 +3,12 @@ mod identity_signed;
 mod json_conversion;
 mod state_transition_like;
 mod types;
+pub(super) mod v0_methods;
 #[cfg(feature = "state-transition-value-conversion")]
 mod value_conversion;
 mod version;
 
+use crate::prelude::UserFeeIncrease;
 use bincode::{Decode, Encode};
 use platform_serialization_derive::PlatformSignable;
 use platform_value::BinaryData;
@@ -14,7 +16,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     identity::{core_script::CoreScript, KeyID},
-    prelude::{Identifier, Revision},
+    prelude::{Identifier, IdentityNonce, Revision},
     ProtocolError,
 };
 
@@ -34,6 +36,9 @@ pub struct IdentityCreditWithdrawalTransitionV0 {
     pub pooling: Pooling,
     pub output_script: CoreScript,
     pub revision: Revision,
+    pub nonce: IdentityNonce,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
     #[platform_signable(exclude_from_sig_hash)]
     pub signature_public_key_id: KeyID,
     #[platform_signable(exclude_from_sig_hash)]
@@ -0,0 +1,70 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::accessors::IdentityGettersV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::core_script::CoreScript;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, KeyType, Purpose, SecurityLevel};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::IdentityNonce;
+use crate::state_transition::identity_credit_withdrawal_transition::methods::IdentityCreditWithdrawalTransitionMethodsV0;
+use crate::state_transition::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::{GetDataContractSecurityLevelRequirementFn, StateTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::withdrawal::Pooling;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+impl IdentityCreditWithdrawalTransitionMethodsV0 for IdentityCreditWithdrawalTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        output_script: CoreScript,
+        amount: u64,
+        pooling: Pooling,
+        core_fee_per_byte: u32,
+        nonce: IdentityNonce,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut transition: StateTransition = IdentityCreditWithdrawalTransitionV0 {
+            identity_id: identity.id(),
+            amount,
+            core_fee_per_byte,
+            pooling,
+            output_script,
+            revision: identity.revision() + 1,
+            nonce,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let identity_public_key = identity
+            .get_first_public_key_matching(
+                Purpose::AUTHENTICATION,
+                [SecurityLevel::CRITICAL].into(),
+                KeyType::all_key_types().into(),
+            )
+            .ok_or(
+                ProtocolError::DesiredKeyWithTypePurposeSecurityLevelMissing(
+                    "no critical authentication public key to sign the credit withdrawal"
+                        .to_string(),
+                ),
+            )?;
+
+        transition.sign_external(
+            identity_public_key,
+            signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+
+        Ok(transition)
+    }
+}
@@ -33,7 +33,13 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityTopUpTransitionV0 {
             .map_err(ProtocolError::ValueError)?;
         let asset_lock_proof = AssetLockProof::try_from(raw_asset_lock_proof)?;
 
+        let user_fee_increase = raw_object
+            .get_optional_integer(USER_FEE_INCREASE)
+            .map_err(ProtocolError::ValueError)?
+            .unwrap_or_default();
+
         Ok(IdentityTopUpTransitionV0 {
+            user_fee_increase,
             signature,
             identity_id,
             asset_lock_proof,
@@ -5,7 +5,7 @@ use crate::state_transition::identity_update_transition::fields::property_names:
 };
 pub use state_transitions::common_fields::property_names::{
     ENTROPY, REVISION, SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, STATE_TRANSITION_PROTOCOL_VERSION,
-    TRANSITION_TYPE,
+    TRANSITION_TYPE, USER_FEE_INCREASE,
 };
 pub use state_transitions::identity::common_fields::property_names::IDENTITY_ID;
 
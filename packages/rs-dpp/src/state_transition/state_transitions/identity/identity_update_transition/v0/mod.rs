@@ -8,6 +8,7 @@ pub(super) mod v0_methods;
 mod value_conversion;
 mod version;
 
+use crate::prelude::UserFeeIncrease;
 use bincode::{Decode, Encode};
 use platform_serialization_derive::PlatformSignable;
 use platform_value::{BinaryData, Value};
@@ -55,6 +56,10 @@ pub struct IdentityUpdateTransitionV0 {
     /// Timestamp when keys were disabled
     pub public_keys_disabled_at: Option<TimestampMillis>,
 
+    /// The percentage the processing fee is increased by
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub user_fee_increase: UserFeeIncrease,
+
     /// The ID of the public key used to sing the State Transition
     #[platform_signable(exclude_from_sig_hash)]
     pub signature_public_key_id: KeyID,
@@ -42,6 +42,7 @@ impl IdentityUpdateTransitionMethodsV0 for IdentityUpdateTransitionV0 {
             .collect();
 
         let mut identity_update_transition = IdentityUpdateTransitionV0 {
+            user_fee_increase: 0,
             signature: Default::default(),
             signature_public_key_id: 0,
             identity_id: identity.id(),
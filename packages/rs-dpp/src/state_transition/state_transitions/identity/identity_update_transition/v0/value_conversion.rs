@@ -41,8 +41,13 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransitionV0 {
         let public_keys_disabled_at = raw_object
             .remove_optional_integer(property_names::PUBLIC_KEYS_DISABLED_AT)
             .map_err(ProtocolError::ValueError)?;
+        let user_fee_increase = raw_object
+            .get_optional_integer(USER_FEE_INCREASE)
+            .map_err(ProtocolError::ValueError)?
+            .unwrap_or_default();
 
         Ok(IdentityUpdateTransitionV0 {
+            user_fee_increase,
             signature,
             signature_public_key_id,
             identity_id,
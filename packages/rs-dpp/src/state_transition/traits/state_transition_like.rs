@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use platform_value::BinaryData;
 
-use crate::prelude::Identifier;
+use crate::prelude::{Identifier, UserFeeIncrease};
 use crate::version::FeatureVersion;
 
 use crate::state_transition::StateTransitionType;
@@ -18,9 +18,10 @@ pub const IDENTITY_TRANSITION_TYPE: [StateTransitionType; 4] = [
     StateTransitionType::IdentityCreditTransfer,
 ];
 
-pub const DATA_CONTRACT_TRANSITION_TYPES: [StateTransitionType; 2] = [
+pub const DATA_CONTRACT_TRANSITION_TYPES: [StateTransitionType; 3] = [
     StateTransitionType::DataContractCreate,
     StateTransitionType::DataContractUpdate,
+    StateTransitionType::DataContractDelete,
 ];
 
 /// The StateTransitionLike represents set of methods that are shared for all types of State Transition.
@@ -56,4 +57,9 @@ pub trait StateTransitionLike:
 
     /// Get owner ID
     fn owner_id(&self) -> Identifier;
+
+    /// returns the percentage the processing fee is increased by
+    fn user_fee_increase(&self) -> UserFeeIncrease;
+    /// set the percentage the processing fee is increased by
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease);
 }
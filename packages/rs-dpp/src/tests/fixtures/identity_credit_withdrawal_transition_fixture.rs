@@ -1,6 +1,6 @@
 use crate::identity::core_script::CoreScript;
 use crate::prelude::Identifier;
-use crate::prelude::Revision;
+use crate::prelude::{IdentityNonce, Revision};
 use dashcore::{PubkeyHash, ScriptBuf};
 use platform_value::string_encoding::{encode, Encoding};
 use platform_value::BinaryData;
@@ -20,6 +20,7 @@ pub fn identity_credit_withdrawal_transition_fixture_raw_object() -> Value {
         "pooling": Pooling::Never as u8,
         "outputScript": CoreScript::new(ScriptBuf::new_p2pkh(&PubkeyHash::from_hex("0000000000000000000000000000000000000000").unwrap())),
         "revision": 1 as Revision,
+        "nonce": 1 as IdentityNonce,
         "signaturePublicKeyId": 0u32,
         "signature": BinaryData::new(vec![0_u8; 65]),
     })
@@ -37,5 +38,6 @@ pub fn identity_credit_withdrawal_transition_fixture_json() -> JsonValue {
         "signature": encode(&[0_u8; 65], Encoding::Base64),
         "signaturePublicKeyId": 0,
         "revision": 1,
+        "nonce": 1,
     })
 }
@@ -9,6 +9,10 @@ pub fn hash(payload: impl AsRef<[u8]>) -> [u8; 32] {
     Sha256::digest(Sha256::digest(payload)).into()
 }
 
+pub fn hash_single(payload: impl AsRef<[u8]>) -> [u8; 32] {
+    Sha256::digest(payload).into()
+}
+
 pub fn hash_to_hex_string(payload: impl AsRef<[u8]>) -> String {
     hex::encode(hash(payload))
 }
@@ -17,7 +17,7 @@ impl TryIntoPlatformVersioned<ExecTxResult> for StateTransitionExecutionResult {
         platform_version: &PlatformVersion,
     ) -> Result<ExecTxResult, Self::Error> {
         let response = match self {
-            Self::SuccessfulPaidExecution(dry_run_fee_result, fee_result) => ExecTxResult {
+            Self::SuccessfulPaidExecution(dry_run_fee_result, fee_result, _) => ExecTxResult {
                 code: 0,
                 data: vec![],
                 log: "".to_string(),
@@ -37,6 +37,7 @@
 
 mod error;
 mod execution_result;
+mod tx_ordering;
 
 use crate::abci::server::AbciApplication;
 use crate::error::execution::ExecutionError;
@@ -67,6 +68,7 @@ use crate::platform_types::block_execution_outcome;
 use crate::platform_types::block_proposal::v0::BlockProposal;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::snapshot::{SnapshotRestore, SNAPSHOT_FORMAT};
 use crate::platform_types::withdrawal::withdrawal_txs;
 use dpp::dashcore::hashes::Hash;
 use dpp::fee::SignedCredits;
@@ -172,126 +174,8 @@ where
         &self,
         request: RequestPrepareProposal,
     ) -> Result<ResponsePrepareProposal, proto::ResponseException> {
-        let _timer = crate::metrics::abci_request_duration("prepare_proposal");
-
-        // We should get the latest CoreChainLock from core
-        // It is possible that we will not get a chain lock from core, in this case, just don't
-        // propose one
-        // This is done before all else
-
-        let core_chain_lock_update = match self.platform.core_rpc.get_best_chain_lock() {
-            Ok(latest_chain_lock) => {
-                if request.core_chain_locked_height < latest_chain_lock.core_block_height {
-                    Some(latest_chain_lock)
-                } else {
-                    None
-                }
-            }
-            Err(_) => None,
-        };
-
-        let mut block_proposal: BlockProposal = (&request).try_into()?;
-
-        if let Some(core_chain_lock_update) = core_chain_lock_update.as_ref() {
-            // We can't add this, as it slows down CI way too much
-            // todo: find a way to re-enable this without destroying CI
-            tracing::debug!(
-                "propose chain lock update to height {} at block {}",
-                core_chain_lock_update.core_block_height,
-                request.height
-            );
-            block_proposal.core_chain_locked_height = core_chain_lock_update.core_block_height;
-        }
-
-        // Prepare transaction
-        let transaction_guard = if request.height == self.platform.config.abci.genesis_height as i64
-        {
-            // special logic on init chain
-            let transaction = self.transaction.read().unwrap();
-            if transaction.is_none() {
-                return Err(Error::Abci(AbciError::BadRequest("received a prepare proposal request for the genesis height before an init chain request".to_string())))?;
-            }
-            if request.round > 0 {
-                transaction.as_ref().map(|tx| tx.rollback_to_savepoint());
-            }
-            transaction
-        } else {
-            self.start_transaction();
-            self.transaction.read().unwrap()
-        };
-
-        let transaction = transaction_guard.as_ref().unwrap();
-
-        // Running the proposal executes all the state transitions for the block
-        let run_result = self
-            .platform
-            .run_block_proposal(block_proposal, transaction)?;
-
-        if !run_result.is_valid() {
-            // This is a system error, because we are proposing
-            return Err(run_result.errors.first().unwrap().to_string().into());
-        }
-
-        let block_execution_outcome::v0::BlockExecutionOutcome {
-            app_hash,
-            state_transition_results,
-            validator_set_update,
-            protocol_version,
-        } = run_result.into_data().map_err(Error::Protocol)?;
-
-        let platform_version = PlatformVersion::get(protocol_version)
-            .expect("must be set in run block proposal from existing protocol version");
-
-        // We need to let Tenderdash know about the transactions we should remove from execution
-        let mut tx_results = Vec::new();
-        let mut tx_records = Vec::new();
-        let mut valid_txs_count = 0;
-        let mut invalid_tx_count = 0;
-
-        for (tx, state_transition_execution_result) in state_transition_results {
-            let tx_result: ExecTxResult =
-                state_transition_execution_result.try_into_platform_versioned(platform_version)?;
-
-            let action = if tx_result.code > 0 {
-                invalid_tx_count += 1;
-                TxAction::Removed
-            } else {
-                valid_txs_count += 1;
-                TxAction::Unmodified
-            } as i32;
-
-            tx_results.push(tx_result);
-            tx_records.push(TxRecord { action, tx });
-        }
-
-        // TODO: implement all fields, including tx processing; for now, just leaving bare minimum
-        let response = ResponsePrepareProposal {
-            tx_results,
-            app_hash: app_hash.to_vec(),
-            tx_records,
-            core_chain_lock_update,
-            validator_set_update,
-            ..Default::default()
-        };
-
-        let mut block_execution_context_guard =
-            self.platform.block_execution_context.write().unwrap();
-
-        let block_execution_context = block_execution_context_guard
-            .as_mut()
-            .expect("expected that a block execution context was set");
-        block_execution_context.set_proposer_results(Some(response.clone()));
-
-        tracing::info!(
-            invalid_tx_count,
-            valid_txs_count,
-            "Prepared proposal with {} transitions for height: {}, round: {}",
-            valid_txs_count,
-            request.height,
-            request.round,
-        );
-
-        Ok(response)
+        self.prepare_proposal_with_removed_tx_results(request)
+            .map(|(response, _)| response)
     }
 
     fn process_proposal(
@@ -446,6 +330,7 @@ where
                 app_hash,
                 state_transition_results,
                 validator_set_update,
+                consensus_param_updates,
                 protocol_version,
             } = run_result.into_data().map_err(Error::Protocol)?;
 
@@ -477,6 +362,7 @@ where
                 tx_results,
                 status: proto::response_process_proposal::ProposalStatus::Accept.into(),
                 validator_set_update,
+                consensus_param_updates,
                 ..Default::default()
             };
 
@@ -630,6 +516,9 @@ where
     ) -> Result<ResponseFinalizeBlock, proto::ResponseException> {
         let _timer = crate::metrics::abci_request_duration("finalize_block");
 
+        // The platform state is updated before the transaction is committed
+        let _commit_guard = self.platform.commit_sequence.begin_commit();
+
         let transaction_guard = self.transaction.read().unwrap();
 
         let transaction = transaction_guard.as_ref().ok_or(Error::Execution(
@@ -784,6 +673,356 @@ where
 
         Ok(response)
     }
+
+    fn list_snapshots(
+        &self,
+        _request: proto::RequestListSnapshots,
+    ) -> Result<proto::ResponseListSnapshots, proto::ResponseException> {
+        let _timer = crate::metrics::abci_request_duration("list_snapshots");
+
+        let snapshots = self
+            .platform
+            .snapshots
+            .read()
+            .unwrap()
+            .values()
+            .rev()
+            .map(|snapshot| snapshot.to_abci_snapshot())
+            .collect();
+
+        Ok(proto::ResponseListSnapshots { snapshots })
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        request: proto::RequestLoadSnapshotChunk,
+    ) -> Result<proto::ResponseLoadSnapshotChunk, proto::ResponseException> {
+        let _timer = crate::metrics::abci_request_duration("load_snapshot_chunk");
+
+        let snapshot = self
+            .platform
+            .snapshots
+            .read()
+            .unwrap()
+            .get(&request.height)
+            .cloned();
+
+        // An empty chunk tells the peer we don't have it
+        let chunk = match snapshot {
+            Some(snapshot) if request.format == SNAPSHOT_FORMAT => {
+                match snapshot.load_chunk(request.chunk) {
+                    Ok(chunk) => chunk,
+                    Err(error) => {
+                        tracing::warn!(
+                            ?error,
+                            height = request.height,
+                            chunk = request.chunk,
+                            "can't load snapshot chunk"
+                        );
+                        vec![]
+                    }
+                }
+            }
+            _ => vec![],
+        };
+
+        Ok(proto::ResponseLoadSnapshotChunk { chunk })
+    }
+
+    fn offer_snapshot(
+        &self,
+        request: proto::RequestOfferSnapshot,
+    ) -> Result<proto::ResponseOfferSnapshot, proto::ResponseException> {
+        let _timer = crate::metrics::abci_request_duration("offer_snapshot");
+
+        let Some(snapshot) = request.snapshot else {
+            return Ok(proto::ResponseOfferSnapshot {
+                result: proto::response_offer_snapshot::Result::Reject as i32,
+            });
+        };
+
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return Ok(proto::ResponseOfferSnapshot {
+                result: proto::response_offer_snapshot::Result::RejectFormat as i32,
+            });
+        }
+
+        let last_block_height = self.platform.state.read().unwrap().last_block_height();
+
+        if last_block_height > 0 {
+            tracing::warn!(
+                last_block_height,
+                "can't restore a snapshot over an existing state"
+            );
+
+            return Ok(proto::ResponseOfferSnapshot {
+                result: proto::response_offer_snapshot::Result::Abort as i32,
+            });
+        }
+
+        let restore = match SnapshotRestore::new(&snapshot, &request.app_hash) {
+            Ok(restore) => restore,
+            Err(error) => {
+                tracing::warn!(?error, height = snapshot.height, "rejected snapshot");
+
+                return Ok(proto::ResponseOfferSnapshot {
+                    result: proto::response_offer_snapshot::Result::Reject as i32,
+                });
+            }
+        };
+
+        tracing::info!(
+            height = restore.height,
+            chunks = restore.chunks,
+            "restoring state sync snapshot"
+        );
+
+        // Starting a new transaction discards whatever a previously offered snapshot restored
+        self.start_transaction();
+        self.snapshot_restore.write().unwrap().replace(restore);
+
+        Ok(proto::ResponseOfferSnapshot {
+            result: proto::response_offer_snapshot::Result::Accept as i32,
+        })
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        request: proto::RequestApplySnapshotChunk,
+    ) -> Result<proto::ResponseApplySnapshotChunk, proto::ResponseException> {
+        let _timer = crate::metrics::abci_request_duration("apply_snapshot_chunk");
+
+        let mut restore_guard = self.snapshot_restore.write().unwrap();
+
+        let Some(restore) = restore_guard.as_mut() else {
+            return Err(proto::ResponseException::from(
+                "received a snapshot chunk, but no snapshot is being restored".to_string(),
+            ));
+        };
+
+        let transaction_guard = self.transaction.read().unwrap();
+
+        let transaction = transaction_guard.as_ref().ok_or(Error::Execution(
+            ExecutionError::NotInTransaction(
+                "trying to apply a snapshot chunk without a current transaction",
+            ),
+        ))?;
+
+        let result = restore
+            .apply_chunk(
+                &self.platform.drive,
+                request.index,
+                &request.chunk,
+                transaction,
+            )
+            .and_then(|_| {
+                if restore.is_complete() {
+                    restore.finish(&self.platform.drive, transaction)?;
+
+                    self.platform
+                        .rebuild_restored_platform_state(&mut restore.platform_state, transaction)
+                } else {
+                    Ok(())
+                }
+            });
+
+        drop(transaction_guard);
+
+        if let Err(error) = result {
+            tracing::warn!(
+                ?error,
+                index = request.index,
+                sender = request.sender,
+                "rejected snapshot chunk"
+            );
+
+            // Dropping the transaction discards the chunks applied so far
+            restore_guard.take();
+            self.transaction.write().unwrap().take();
+
+            return Ok(proto::ResponseApplySnapshotChunk {
+                result: proto::response_apply_snapshot_chunk::Result::RejectSnapshot as i32,
+                refetch_chunks: vec![],
+                reject_senders: vec![request.sender],
+            });
+        }
+
+        if restore.is_complete() {
+            let restore = restore_guard
+                .take()
+                .expect("snapshot restore is checked above");
+
+            let height = restore.height;
+
+            self.commit_snapshot_restore(restore)?;
+
+            tracing::info!(height, "state sync snapshot restored");
+        }
+
+        Ok(proto::ResponseApplySnapshotChunk {
+            result: proto::response_apply_snapshot_chunk::Result::Accept as i32,
+            refetch_chunks: vec![],
+            reject_senders: vec![],
+        })
+    }
+}
+
+impl<'a, C> AbciApplication<'a, C>
+where
+    C: CoreRPCLike,
+{
+    /// Prepares a block proposal.
+    ///
+    /// State transitions failing in the proposal are removed from the block, so their results
+    /// are not part of the response. They are returned together with the removed transactions.
+    pub(crate) fn prepare_proposal_with_removed_tx_results(
+        &self,
+        mut request: RequestPrepareProposal,
+    ) -> Result<(ResponsePrepareProposal, Vec<(Vec<u8>, ExecTxResult)>), proto::ResponseException>
+    {
+        let _timer = crate::metrics::abci_request_duration("prepare_proposal");
+
+        // We should get the latest CoreChainLock from core
+        // It is possible that we will not get a chain lock from core, in this case, just don't
+        // propose one
+        // This is done before all else
+
+        let core_chain_lock_update = match self.platform.core_rpc.get_best_chain_lock() {
+            Ok(latest_chain_lock) => {
+                if request.core_chain_locked_height < latest_chain_lock.core_block_height {
+                    Some(latest_chain_lock)
+                } else {
+                    None
+                }
+            }
+            Err(_) => None,
+        };
+
+        // Transactions with a higher user fee increase are proposed first, the ones that
+        // don't fit into the block are delayed
+        let (txs, delayed_txs) = tx_ordering::order_txs_by_user_fee_increase(
+            std::mem::take(&mut request.txs),
+            request.max_tx_bytes,
+        );
+        request.txs = txs;
+
+        let mut block_proposal: BlockProposal = (&request).try_into()?;
+
+        if let Some(core_chain_lock_update) = core_chain_lock_update.as_ref() {
+            // We can't add this, as it slows down CI way too much
+            // todo: find a way to re-enable this without destroying CI
+            tracing::debug!(
+                "propose chain lock update to height {} at block {}",
+                core_chain_lock_update.core_block_height,
+                request.height
+            );
+            block_proposal.core_chain_locked_height = core_chain_lock_update.core_block_height;
+        }
+
+        // Prepare transaction
+        let transaction_guard = if request.height == self.platform.config.abci.genesis_height as i64
+        {
+            // special logic on init chain
+            let transaction = self.transaction.read().unwrap();
+            if transaction.is_none() {
+                return Err(Error::Abci(AbciError::BadRequest("received a prepare proposal request for the genesis height before an init chain request".to_string())))?;
+            }
+            if request.round > 0 {
+                transaction.as_ref().map(|tx| tx.rollback_to_savepoint());
+            }
+            transaction
+        } else {
+            self.start_transaction();
+            self.transaction.read().unwrap()
+        };
+
+        let transaction = transaction_guard.as_ref().unwrap();
+
+        // Running the proposal executes all the state transitions for the block
+        let run_result = self
+            .platform
+            .run_block_proposal(block_proposal, transaction)?;
+
+        if !run_result.is_valid() {
+            // This is a system error, because we are proposing
+            return Err(run_result.errors.first().unwrap().to_string().into());
+        }
+
+        let block_execution_outcome::v0::BlockExecutionOutcome {
+            app_hash,
+            state_transition_results,
+            validator_set_update,
+            consensus_param_updates,
+            protocol_version,
+        } = run_result.into_data().map_err(Error::Protocol)?;
+
+        let platform_version = PlatformVersion::get(protocol_version)
+            .expect("must be set in run block proposal from existing protocol version");
+
+        // We need to let Tenderdash know about the transactions we should remove from execution
+        let mut tx_results = Vec::new();
+        let mut removed_tx_results = Vec::new();
+        let mut tx_records = Vec::new();
+        let mut valid_txs_count = 0;
+        let mut invalid_tx_count = 0;
+
+        for (tx, state_transition_execution_result) in state_transition_results {
+            let tx_result: ExecTxResult =
+                state_transition_execution_result.try_into_platform_versioned(platform_version)?;
+
+            // Failed state transitions don't change the state, so they are left out of
+            // the block and the app hash stays the same without them
+            let action = if tx_result.code > 0 {
+                invalid_tx_count += 1;
+                removed_tx_results.push((tx.clone(), tx_result));
+                TxAction::Removed
+            } else {
+                valid_txs_count += 1;
+                tx_results.push(tx_result);
+                TxAction::Unmodified
+            } as i32;
+
+            tx_records.push(TxRecord { action, tx });
+        }
+
+        let delayed_tx_count = delayed_txs.len();
+
+        tx_records.extend(delayed_txs.into_iter().map(|tx| TxRecord {
+            action: TxAction::Delayed as i32,
+            tx,
+        }));
+
+        // TODO: implement all fields, including tx processing; for now, just leaving bare minimum
+        let response = ResponsePrepareProposal {
+            tx_results,
+            app_hash: app_hash.to_vec(),
+            tx_records,
+            core_chain_lock_update,
+            validator_set_update,
+            consensus_param_updates,
+            ..Default::default()
+        };
+
+        let mut block_execution_context_guard =
+            self.platform.block_execution_context.write().unwrap();
+
+        let block_execution_context = block_execution_context_guard
+            .as_mut()
+            .expect("expected that a block execution context was set");
+        block_execution_context.set_proposer_results(Some(response.clone()));
+
+        tracing::info!(
+            invalid_tx_count,
+            valid_txs_count,
+            delayed_tx_count,
+            "Prepared proposal with {} transitions for height: {}, round: {}",
+            valid_txs_count,
+            request.height,
+            request.round,
+        );
+
+        Ok((response, removed_tx_results))
+    }
 }
 //
 // #[cfg(test)]
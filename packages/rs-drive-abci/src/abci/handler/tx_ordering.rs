@@ -0,0 +1,210 @@
+//! Ordering of the transactions proposed in a block.
+//!
+//! Proposers are paid the user fee increase of the state transitions they include, so
+//! transactions are ordered by it (highest first) and those that no longer fit into the
+//! block are delayed to a later one. Transactions of the same identity keep their order,
+//! as their nonces must be executed in sequence.
+
+use dpp::fee::default_costs::constants::MAX_USER_FEE_INCREASE;
+use dpp::prelude::{Identifier, UserFeeIncrease};
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/// A transaction waiting to be ordered, with its position in the original order
+struct PendingTx {
+    user_fee_increase: UserFeeIncrease,
+    position: usize,
+    tx: Vec<u8>,
+}
+
+/// Orders raw transactions by their user fee increase, keeping the original order for
+/// equal increases and for transactions of the same identity, and splits them into the ones
+/// that fit in `max_tx_bytes` and the ones that should be delayed.
+///
+/// Once a transaction is delayed, the following transactions of its identity are delayed too.
+/// Transactions that can't be deserialized or have a user fee increase over the maximum are
+/// treated as having no fee increase, they will be removed during the execution of the
+/// proposal anyway.
+/// A `max_tx_bytes` of zero or less means there is no limit.
+pub(super) fn order_txs_by_user_fee_increase(
+    txs: Vec<Vec<u8>>,
+    max_tx_bytes: i64,
+) -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+    let tx_count = txs.len();
+
+    // Transactions queued per identity, undecodable ones are queued on their own
+    let mut queues: Vec<VecDeque<PendingTx>> = Vec::new();
+    let mut queue_by_identity: HashMap<Identifier, usize> = HashMap::new();
+
+    for (position, tx) in txs.into_iter().enumerate() {
+        let state_transition = StateTransition::deserialize_from_bytes(&tx).ok();
+
+        let user_fee_increase = state_transition
+            .as_ref()
+            .map(|state_transition| state_transition.user_fee_increase())
+            .filter(|user_fee_increase| *user_fee_increase <= MAX_USER_FEE_INCREASE)
+            .unwrap_or_default();
+
+        let pending_tx = PendingTx {
+            user_fee_increase,
+            position,
+            tx,
+        };
+
+        let queue_index = match state_transition {
+            Some(state_transition) => *queue_by_identity
+                .entry(state_transition.owner_id())
+                .or_insert_with(|| {
+                    queues.push(VecDeque::new());
+                    queues.len() - 1
+                }),
+            None => {
+                queues.push(VecDeque::new());
+                queues.len() - 1
+            }
+        };
+
+        queues[queue_index].push_back(pending_tx);
+    }
+
+    // The next transaction of each identity, highest fee increase first, then earliest
+    let mut next_txs: BinaryHeap<(UserFeeIncrease, Reverse<usize>, usize)> = queues
+        .iter()
+        .enumerate()
+        .filter_map(|(queue_index, queue)| {
+            queue.front().map(|pending_tx| {
+                (
+                    pending_tx.user_fee_increase,
+                    Reverse(pending_tx.position),
+                    queue_index,
+                )
+            })
+        })
+        .collect();
+
+    let mut selected_txs = Vec::with_capacity(tx_count);
+    let mut delayed_txs = Vec::new();
+    let mut total_bytes: u64 = 0;
+
+    while let Some((_, _, queue_index)) = next_txs.pop() {
+        let queue = &mut queues[queue_index];
+
+        let Some(pending_tx) = queue.pop_front() else {
+            continue;
+        };
+
+        let tx_bytes = pending_tx.tx.len() as u64;
+
+        if max_tx_bytes > 0 && total_bytes + tx_bytes > max_tx_bytes as u64 {
+            delayed_txs.push(pending_tx.tx);
+            delayed_txs.extend(queue.drain(..).map(|pending_tx| pending_tx.tx));
+            continue;
+        }
+
+        total_bytes += tx_bytes;
+        selected_txs.push(pending_tx.tx);
+
+        if let Some(next_tx) = queue.front() {
+            next_txs.push((
+                next_tx.user_fee_increase,
+                Reverse(next_tx.position),
+                queue_index,
+            ));
+        }
+    }
+
+    (selected_txs, delayed_txs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+
+    fn credit_transfer_tx(
+        identity_id: [u8; 32],
+        nonce: u64,
+        user_fee_increase: UserFeeIncrease,
+    ) -> Vec<u8> {
+        StateTransition::from(IdentityCreditTransferTransition::from(
+            IdentityCreditTransferTransitionV0 {
+                identity_id: Identifier::new(identity_id),
+                recipient_id: Identifier::new([9; 32]),
+                amount: 1000,
+                nonce,
+                user_fee_increase,
+                ..Default::default()
+            },
+        ))
+        .serialize_to_bytes()
+        .expect("expected to serialize state transition")
+    }
+
+    #[test]
+    fn should_order_txs_by_fee_increase_and_keep_order_of_identity() {
+        let first_of_a = credit_transfer_tx([1; 32], 1, 0);
+        let second_of_a = credit_transfer_tx([1; 32], 2, 50);
+        let first_of_b = credit_transfer_tx([2; 32], 1, 10);
+
+        let (selected_txs, delayed_txs) = order_txs_by_user_fee_increase(
+            vec![first_of_a.clone(), second_of_a.clone(), first_of_b.clone()],
+            0,
+        );
+
+        assert_eq!(selected_txs, vec![first_of_b, first_of_a, second_of_a]);
+        assert!(delayed_txs.is_empty());
+    }
+
+    #[test]
+    fn should_delay_following_txs_of_identity_with_delayed_tx() {
+        let first_of_a = credit_transfer_tx([1; 32], 1, 10);
+        let second_of_a = credit_transfer_tx([1; 32], 2, 10);
+        let first_of_b = credit_transfer_tx([2; 32], 1, 0);
+
+        // Only two transfers fit
+        let max_tx_bytes = (first_of_a.len() + first_of_b.len()) as i64;
+
+        let (selected_txs, delayed_txs) = order_txs_by_user_fee_increase(
+            vec![first_of_b.clone(), first_of_a.clone(), second_of_a.clone()],
+            max_tx_bytes,
+        );
+
+        assert_eq!(selected_txs, vec![first_of_a, first_of_b]);
+        assert_eq!(delayed_txs, vec![second_of_a]);
+    }
+
+    #[test]
+    fn should_not_prioritize_txs_with_fee_increase_over_maximum() {
+        let over_maximum = credit_transfer_tx([1; 32], 1, MAX_USER_FEE_INCREASE + 1);
+        let at_maximum = credit_transfer_tx([2; 32], 1, MAX_USER_FEE_INCREASE);
+
+        let (selected_txs, _) =
+            order_txs_by_user_fee_increase(vec![over_maximum.clone(), at_maximum.clone()], 0);
+
+        assert_eq!(selected_txs, vec![at_maximum, over_maximum]);
+    }
+
+    #[test]
+    fn should_keep_order_of_undecodable_txs_and_delay_what_does_not_fit() {
+        let txs = vec![vec![1; 10], vec![2; 10], vec![3; 10]];
+
+        let (selected_txs, delayed_txs) = order_txs_by_user_fee_increase(txs.clone(), 25);
+
+        assert_eq!(selected_txs, txs[..2].to_vec());
+        assert_eq!(delayed_txs, txs[2..].to_vec());
+    }
+
+    #[test]
+    fn should_select_all_txs_without_limit() {
+        let txs = vec![vec![1; 10], vec![2; 10], vec![3; 10]];
+
+        let (selected_txs, delayed_txs) = order_txs_by_user_fee_increase(txs.clone(), 0);
+
+        assert_eq!(selected_txs, txs);
+        assert!(delayed_txs.is_empty());
+    }
+}
@@ -1,13 +1,19 @@
 //! This module implements ABCI application server.
 //!
 use crate::error::execution::ExecutionError;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::snapshot::SnapshotRestore;
+use crate::query::start_grpc_server;
 use crate::{
     config::PlatformConfig, error::Error, platform_types::platform::Platform,
     rpc::core::CoreRPCLike,
 };
+use dpp::serialization::PlatformSerializable;
+use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
+use drive::error::Error::GroveDB;
 use drive::grovedb::Transaction;
 use std::fmt::Debug;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use tokio_util::sync::CancellationToken;
 
 /// AbciApp is an implementation of ABCI Application, as defined by Tenderdash.
@@ -19,20 +25,27 @@ pub struct AbciApplication<'a, C> {
     pub platform: &'a Platform<C>,
     /// The current transaction
     pub transaction: RwLock<Option<Transaction<'a>>>,
+    /// Snapshot being restored by state sync
+    pub snapshot_restore: RwLock<Option<SnapshotRestore>>,
 }
 
 /// Start ABCI server and process incoming connections.
 ///
 /// Should never return.
-pub fn start<C: CoreRPCLike>(
+pub fn start<C: CoreRPCLike + Send + Sync + 'static>(
     config: &PlatformConfig,
     core_rpc: C,
     cancel: CancellationToken,
 ) -> Result<(), Error> {
     let bind_address = config.abci.bind_address.clone();
 
-    let platform: Platform<C> =
-        Platform::open_with_client(&config.db_path, Some(config.clone()), core_rpc)?;
+    let platform: Arc<Platform<C>> = Arc::new(Platform::open_with_client(
+        &config.db_path,
+        Some(config.clone()),
+        core_rpc,
+    )?);
+
+    let grpc_server = start_grpc_server(Arc::clone(&platform), &config.grpc, cancel.clone())?;
 
     let abci = AbciApplication::new(&platform)?;
 
@@ -49,6 +62,12 @@ pub fn start<C: CoreRPCLike>(
         }
     }
 
+    if let Some(grpc_server) = grpc_server {
+        if grpc_server.join().is_err() {
+            tracing::error!("gRPC query server thread panicked");
+        }
+    }
+
     Ok(())
 }
 
@@ -58,6 +77,7 @@ impl<'a, C> AbciApplication<'a, C> {
         let app = AbciApplication {
             platform,
             transaction: RwLock::new(None),
+            snapshot_restore: RwLock::new(None),
         };
 
         Ok(app)
@@ -84,7 +104,78 @@ impl<'a, C> AbciApplication<'a, C> {
         self.platform
             .drive
             .commit_transaction(transaction, &platform_version.drive)
-            .map_err(Error::Drive)
+            .map_err(Error::Drive)?;
+
+        // Historical queries are a convenience, failing to retain a state must not halt the chain
+        if let Err(error) = self
+            .platform
+            .store_historical_state(&platform_state, platform_version)
+        {
+            tracing::error!(?error, "can't store historical state");
+        }
+
+        // Snapshots only speed up syncing of new nodes, failing to take one must not halt the chain
+        if let Err(error) = self
+            .platform
+            .store_snapshot(&platform_state, platform_version)
+        {
+            tracing::error!(?error, "can't store state sync snapshot");
+        }
+
+        self.platform.change_feed.publish_staged(
+            platform_state.last_block_height(),
+            platform_state.last_block_round(),
+        );
+
+        // Remaining state transitions are re-checked against the new state and tracked again
+        self.platform.mempool.clear();
+
+        Ok(())
+    }
+
+    /// Commit the state restored from a snapshot and load it as the current platform state
+    pub fn commit_snapshot_restore(&self, restore: SnapshotRestore) -> Result<(), Error> {
+        let _commit_guard = self.platform.commit_sequence.begin_commit();
+
+        let transaction = self
+            .transaction
+            .write()
+            .unwrap()
+            .take()
+            .ok_or(Error::Execution(ExecutionError::NotInTransaction(
+                "trying to commit a restored snapshot, but we are not in one",
+            )))?;
+
+        let platform_state = restore.platform_state;
+        let platform_version =
+            PlatformVersion::get(platform_state.current_protocol_version_in_consensus())?;
+
+        // The platform state is kept in aux storage, so it's not covered by the snapshot chunks
+        self.platform
+            .drive
+            .grove
+            .put_aux(
+                b"saved_state",
+                &platform_state.serialize_to_bytes()?,
+                None,
+                Some(&transaction),
+            )
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+        self.platform
+            .drive
+            .commit_transaction(transaction, &platform_version.drive)
+            .map_err(Error::Drive)?;
+
+        // Genesis time, contracts and protocol version votes are reloaded from the restored state
+        self.platform.drive.drop_cache(&platform_version.drive)?;
+
+        PlatformVersion::set_current(platform_version);
+
+        *self.platform.state.write().unwrap() = platform_state;
+
+        Ok(())
     }
 }
 
@@ -142,6 +142,72 @@ pub struct ExecutionConfig {
     pub epoch_time_length_s: u64,
 }
 
+/// Configuration of the gRPC server answering Platform queries.
+///
+/// The server runs on its own threads, so queries don't compete with block processing
+/// for the Tenderdash connection.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+// NOTE: in renames, we use lower_snake_case, because uppercase does not work; see
+// https://github.com/softprops/envy/issues/61 and https://github.com/softprops/envy/pull/69
+pub struct GrpcConfig {
+    /// Address to listen for gRPC connections, for example `0.0.0.0:26670`.
+    ///
+    /// The server is not started if not set.
+    #[serde(default, rename = "grpc_bind_address")]
+    pub bind_address: Option<String>,
+
+    /// Number of threads accepting connections and streaming responses
+    #[serde(
+        default = "GrpcConfig::default_threads",
+        rename = "grpc_threads",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub threads: usize,
+
+    /// Maximum number of queries executed at the same time. Queries above this limit wait
+    /// for a free slot until their deadline.
+    #[serde(
+        default = "GrpcConfig::default_max_concurrent_queries",
+        rename = "grpc_max_concurrent_queries",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub max_concurrent_queries: usize,
+
+    /// Deadline of a query in milliseconds. Clients can set a shorter one with the
+    /// `grpc-timeout` header.
+    #[serde(
+        default = "GrpcConfig::default_request_timeout_ms",
+        rename = "grpc_request_timeout_ms",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub request_timeout_ms: u64,
+}
+
+impl GrpcConfig {
+    fn default_threads() -> usize {
+        4
+    }
+
+    fn default_max_concurrent_queries() -> usize {
+        32
+    }
+
+    fn default_request_timeout_ms() -> u64 {
+        10000
+    }
+}
+
+impl Default for GrpcConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: None,
+            threads: GrpcConfig::default_threads(),
+            max_concurrent_queries: GrpcConfig::default_max_concurrent_queries(),
+            request_timeout_ms: GrpcConfig::default_request_timeout_ms(),
+        }
+    }
+}
+
 fn from_str_or_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -189,6 +255,10 @@ pub struct PlatformConfig {
     #[serde(flatten)]
     pub execution: ExecutionConfig,
 
+    /// gRPC query server config
+    #[serde(flatten)]
+    pub grpc: GrpcConfig,
+
     /// The default quorum type
     pub quorum_type: String,
 
@@ -206,6 +276,29 @@ pub struct PlatformConfig {
     /// Path to data storage
     pub db_path: PathBuf,
 
+    /// How many recently committed blocks should be kept queryable through the `height`
+    /// parameter of queries. A GroveDB checkpoint is retained for each of these blocks.
+    /// `0` disables historical queries.
+    #[serde(default)]
+    pub historical_state_retention_blocks: u64,
+
+    /// Path to the directory where historical state checkpoints are stored
+    #[serde(default = "PlatformConfig::default_historical_state_checkpoints_path")]
+    pub historical_state_checkpoints_path: PathBuf,
+
+    /// Height interval of state sync snapshots. A snapshot is taken when a block at a multiple
+    /// of this height is committed. `0` disables snapshots.
+    #[serde(default)]
+    pub snapshot_interval: u64,
+
+    /// How many of the most recent snapshots are kept and offered to syncing nodes
+    #[serde(default = "PlatformConfig::default_snapshots_retained")]
+    pub snapshots_retained: usize,
+
+    /// Path to the directory where snapshot checkpoints are stored
+    #[serde(default = "PlatformConfig::default_snapshots_path")]
+    pub snapshots_path: PathBuf,
+
     // todo: put this in tests like #[cfg(test)]
     /// This should be None, except in the case of Testing platform
     #[serde(skip)]
@@ -236,6 +329,18 @@ impl PlatformConfig {
         1
     }
 
+    fn default_historical_state_checkpoints_path() -> PathBuf {
+        PathBuf::from("/var/lib/dash-platform/checkpoints")
+    }
+
+    fn default_snapshots_retained() -> usize {
+        2
+    }
+
+    fn default_snapshots_path() -> PathBuf {
+        PathBuf::from("/var/lib/dash-platform/snapshots")
+    }
+
     /// Return type of quorum
     pub fn quorum_type(&self) -> QuorumType {
         let found = if let Ok(t) = self.quorum_type.trim().parse::<u32>() {
@@ -296,7 +401,14 @@ impl Default for PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             execution: Default::default(),
+            grpc: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
+            historical_state_retention_blocks: 0,
+            historical_state_checkpoints_path:
+                PlatformConfig::default_historical_state_checkpoints_path(),
+            snapshot_interval: 0,
+            snapshots_retained: PlatformConfig::default_snapshots_retained(),
+            snapshots_path: PlatformConfig::default_snapshots_path(),
             testing_configs: PlatformTestConfig::default(),
             initial_protocol_version: 1,
         }
@@ -121,4 +121,12 @@ pub enum ExecutionError {
     /// General Bls Error
     #[error("bls error: {0}")]
     BlsErrorGeneral(#[from] BlsError),
+
+    /// Historical state checkpoint could not be stored or removed.
+    #[error("historical state storage error: {0}")]
+    HistoricalStateStorageError(String),
+
+    /// State sync snapshot could not be created, served or restored.
+    #[error("snapshot error: {0}")]
+    SnapshotError(String),
 }
@@ -48,6 +48,12 @@ pub enum Error {
     /// Error from metrics subsystem
     #[error("metrics: {0}")]
     Metrics(#[from] crate::metrics::Error),
+    /// gRPC query server error
+    #[error("query server: {0}")]
+    QueryServer(String),
+    /// Offline tools error
+    #[error("tools: {0}")]
+    Tools(String),
 }
 
 impl From<PlatformVersionError> for Error {
@@ -1,5 +1,8 @@
 use crate::error::Error;
+use crate::execution::types::execution_event::ExecutionEvent;
 use crate::execution::validation::state_transition::processor::process_state_transition;
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::platform_types::mempool::{PendingClaim, PendingStateTransition};
 use crate::platform_types::platform::{Platform, PlatformRef};
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 #[cfg(test)]
@@ -10,14 +13,26 @@ use crate::rpc::core::CoreRPCLike;
 
 use dpp::consensus::basic::decode::SerializedObjectParsingError;
 use dpp::consensus::basic::BasicError;
+use dpp::consensus::signature::IdentityNotFoundError;
+use dpp::consensus::state::state_error::StateError;
 use dpp::consensus::ConsensusError;
 
+use dpp::dashcore;
+use dpp::dashcore::hashes::Hash;
+use dpp::document::DocumentV0Getters;
 use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::prelude::{Identifier, IdentityNonce};
 use dpp::serialization::PlatformDeserializable;
 use dpp::state_transition::StateTransition;
 #[cfg(test)]
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::{
+    DocumentOperationType, DriveOperation, IdentityOperationType, SystemOperationType,
+};
+use drive::drive::object_size_info::DocumentInfoV0Methods;
 #[cfg(test)]
 use drive::grovedb::Transaction;
 
@@ -49,7 +64,13 @@ where
         if state_transition_execution_event.is_valid() {
             let platform_version = platform_ref.state.current_platform_version()?;
             let execution_event = state_transition_execution_event.into_data()?;
-            self.execute_event(execution_event, block_info, transaction, platform_version)
+            self.execute_event(
+                execution_event,
+                block_info,
+                transaction,
+                None,
+                platform_version,
+            )
         } else {
             Ok(ConsensusExecutionError(
                 SimpleConsensusValidationResult::new_with_errors(
@@ -101,30 +122,246 @@ where
             block_info,
         };
 
-        let execution_event = process_state_transition(&platform_ref, state_transition, None)?;
+        let execution_event =
+            process_state_transition(&platform_ref, state_transition.clone(), None)?;
+
+        let tx_hash = dashcore::hashes::sha256::Hash::hash(raw_tx).to_byte_array();
 
         let platform_version = platform_ref.state.current_platform_version()?;
 
+        // The state transition uses the nonce after the one of a pending state transition.
+        // Its state can't be validated against the committed state, so it's fully validated
+        // by the re-check once the pending one is committed. Until then it still reserves
+        // what it spends, so the pending state transitions of the identity can't overspend.
+        let execution_event = match nonce_ahead_of_committed_state(&execution_event.errors) {
+            Some((identity_id, nonce))
+                if self
+                    .mempool
+                    .follows_pending(&PendingClaim::IdentityNonce(identity_id), nonce) =>
+            {
+                self.execution_event_ahead_of_committed_state(
+                    &platform_ref,
+                    &state_transition,
+                    identity_id,
+                    platform_version,
+                )?
+            }
+            _ => execution_event,
+        };
+
+        let pending_state_transition = execution_event.data.as_ref().map(pending_state_transition);
+
         // We should run the execution event in dry run to see if we would have enough fees for the transition
-        execution_event.and_then_borrowed_validation(|execution_event| {
-            self.validate_fees_of_event(execution_event, block_info, None, platform_version)
-        })
+        let mut validation_result =
+            execution_event.and_then_borrowed_validation(|execution_event| {
+                self.validate_fees_of_event(execution_event, block_info, None, platform_version)
+            })?;
+
+        // The state transition is valid on its own, make sure it doesn't conflict
+        // with other state transitions waiting in the mempool
+        if let (true, Some(fee_result), Some((mut pending_state_transition, committed_balance))) = (
+            validation_result.is_valid(),
+            validation_result.data.as_ref(),
+            pending_state_transition,
+        ) {
+            if pending_state_transition.identity_id.is_some() {
+                pending_state_transition.reserved_balance = pending_state_transition
+                    .reserved_balance
+                    .saturating_add(fee_result.total_base_fee());
+            }
+
+            let mempool_result =
+                self.mempool
+                    .add(tx_hash, pending_state_transition, committed_balance);
+
+            validation_result.add_errors(mempool_result.errors);
+        }
+
+        Ok(validation_result)
+    }
+
+    /// The execution event of a state transition whose nonce follows the one of a pending
+    /// state transition, built without validating its state.
+    ///
+    /// Only what it spends and its fees are taken from it: the mempool checks them against
+    /// the balance left by the pending state transitions of the identity.
+    fn execution_event_ahead_of_committed_state<'a>(
+        &self,
+        platform_ref: &'a PlatformRef<C>,
+        state_transition: &StateTransition,
+        identity_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<ValidationResult<ExecutionEvent<'a>, ConsensusError>, Error> {
+        let action_result = state_transition.transform_into_action(platform_ref, false, None)?;
+
+        if !action_result.is_valid_with_data() {
+            return Ok(ValidationResult::new_with_errors(action_result.errors));
+        }
+
+        let Some(identity) = self.drive.fetch_identity_with_balance(
+            identity_id.to_buffer(),
+            None,
+            platform_version,
+        )?
+        else {
+            return Ok(ValidationResult::new_with_error(
+                IdentityNotFoundError::new(identity_id).into(),
+            ));
+        };
+
+        let execution_event = ExecutionEvent::create_from_state_transition_action(
+            action_result.into_data()?,
+            Some(identity),
+            state_transition.user_fee_increase(),
+            platform_ref.state.epoch_ref(),
+            platform_version,
+        )?;
+
+        Ok(ValidationResult::new_with_data(execution_event))
     }
 }
 
+/// The identity and nonce of a state transition which only failed because its nonce is ahead
+/// of the committed one
+fn nonce_ahead_of_committed_state(
+    errors: &[ConsensusError],
+) -> Option<(Identifier, IdentityNonce)> {
+    let [ConsensusError::StateError(StateError::InvalidIdentityNonceError(error))] = errors else {
+        return None;
+    };
+
+    (*error.setting_nonce() > error.current_nonce().saturating_add(1))
+        .then_some((*error.identity_id(), *error.setting_nonce()))
+}
+
+/// What the execution event is going to change, with the committed balance of the paying identity
+fn pending_state_transition(
+    execution_event: &ExecutionEvent,
+) -> (PendingStateTransition, Option<Credits>) {
+    let (payer, operations) = match execution_event {
+        ExecutionEvent::PaidDriveEvent {
+            identity,
+            operations,
+            ..
+        } => (Some(identity), operations),
+        // Paid from an asset lock, so there is no balance to reserve
+        ExecutionEvent::PaidFromAssetLockDriveEvent { operations, .. }
+        | ExecutionEvent::FreeDriveEvent { operations } => (None, operations),
+    };
+
+    let mut pending_state_transition = PendingStateTransition {
+        identity_id: payer.map(|identity| identity.id),
+        ..Default::default()
+    };
+
+    for operation in operations {
+        match operation {
+            DriveOperation::DocumentOperation(
+                DocumentOperationType::AddDocument {
+                    owned_document_info,
+                    contract_id,
+                    document_type_name,
+                    ..
+                }
+                | DocumentOperationType::UpdateDocument {
+                    owned_document_info,
+                    contract_id,
+                    document_type_name,
+                },
+            ) => {
+                if let Some(document) = owned_document_info.document_info.get_borrowed_document() {
+                    pending_state_transition.claims.insert(
+                        PendingClaim::Document {
+                            data_contract_id: *contract_id,
+                            document_type_name: document_type_name.to_string(),
+                            document_id: document.id(),
+                        },
+                        document.revision(),
+                    );
+                }
+            }
+            DriveOperation::DocumentOperation(
+                DocumentOperationType::DeleteDocumentOfNamedTypeForContractId {
+                    document_id,
+                    contract_id,
+                    document_type_name,
+                },
+            ) => {
+                pending_state_transition.claims.insert(
+                    PendingClaim::Document {
+                        data_contract_id: Identifier::new(*contract_id),
+                        document_type_name: document_type_name.to_string(),
+                        document_id: Identifier::new(*document_id),
+                    },
+                    None,
+                );
+            }
+            DriveOperation::IdentityOperation(IdentityOperationType::UpdateIdentityRevision {
+                identity_id,
+                revision,
+            }) => {
+                pending_state_transition.claims.insert(
+                    PendingClaim::IdentityRevision(Identifier::new(*identity_id)),
+                    Some(*revision),
+                );
+            }
+            DriveOperation::IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
+                identity_id,
+                nonce,
+            }) => {
+                pending_state_transition.claims.insert(
+                    PendingClaim::IdentityNonce(Identifier::new(*identity_id)),
+                    Some(*nonce),
+                );
+            }
+            DriveOperation::IdentityOperation(
+                IdentityOperationType::RemoveFromIdentityBalance {
+                    identity_id,
+                    balance_to_remove,
+                },
+            ) => {
+                // Credits transferred or withdrawn by the payer can't be spent by other transitions
+                if pending_state_transition.identity_id == Some(Identifier::new(*identity_id)) {
+                    pending_state_transition.reserved_balance = pending_state_transition
+                        .reserved_balance
+                        .saturating_add(*balance_to_remove);
+                }
+            }
+            DriveOperation::SystemOperation(SystemOperationType::AddUsedAssetLock {
+                asset_lock_outpoint,
+            }) => {
+                pending_state_transition
+                    .claims
+                    .insert(PendingClaim::AssetLock(*asset_lock_outpoint), None);
+            }
+            _ => {}
+        }
+    }
+
+    (
+        pending_state_transition,
+        payer.and_then(|identity| identity.balance),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::config::PlatformConfig;
     use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
     use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult::SuccessfulPaidExecution;
     use crate::platform_types::system_identity_public_keys::v0::SystemIdentityPublicKeysV0;
-    use crate::test::helpers::setup::TestPlatformBuilder;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
     use dpp::block::block_info::BlockInfo;
     use dpp::consensus::basic::BasicError;
     use dpp::consensus::signature::SignatureError;
     use simple_signer::signer::SimpleSigner;
 
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::state::state_transition::pending_state_transition_conflict_error::PendingStateTransitionConflictError;
     use dpp::consensus::ConsensusError;
+    use dpp::dashcore;
+    use dpp::dashcore::hashes::Hash;
     use dpp::dashcore::secp256k1::Secp256k1;
     use dpp::dashcore::{key::KeyPair, signer, Network, PrivateKey};
 
@@ -146,6 +383,8 @@ mod tests {
     use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
     use dpp::state_transition::identity_create_transition::methods::IdentityCreateTransitionMethodsV0;
     use dpp::state_transition::identity_create_transition::IdentityCreateTransition;
+    use dpp::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
     use dpp::state_transition::identity_topup_transition::methods::IdentityTopUpTransitionMethodsV0;
     use dpp::state_transition::identity_topup_transition::IdentityTopUpTransition;
     use dpp::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
@@ -1126,6 +1365,7 @@ mod tests {
             add_public_keys: vec![IdentityPublicKeyInCreation::V0(new_key)],
             disable_public_keys: vec![],
             public_keys_disabled_at: None,
+            user_fee_increase: 0,
             signature_public_key_id: 1,
             signature: Default::default(),
         }
@@ -1232,6 +1472,7 @@ mod tests {
             add_public_keys: vec![IdentityPublicKeyInCreation::V0(new_key.clone())],
             disable_public_keys: vec![],
             public_keys_disabled_at: None,
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: Default::default(),
         }
@@ -1255,6 +1496,7 @@ mod tests {
             add_public_keys: vec![IdentityPublicKeyInCreation::V0(new_key)],
             disable_public_keys: vec![],
             public_keys_disabled_at: None,
+            user_fee_increase: 0,
             signature_public_key_id: 0,
             signature: Default::default(),
         }
@@ -1279,4 +1521,271 @@ mod tests {
 
         validation_result.errors.first().expect("expected an error");
     }
+
+    fn create_identity(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        seed: u64,
+        platform_version: &PlatformVersion,
+    ) -> (Identity, SimpleSigner) {
+        let mut signer = SimpleSigner::default();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let (key, private_key) =
+            IdentityPublicKey::random_ecdsa_critical_level_authentication_key_with_rng(
+                1,
+                &mut rng,
+                platform_version,
+            )
+            .expect("expected to get key pair");
+
+        signer.add_key(key.clone(), private_key.clone());
+
+        let (_, pk) = ECDSA_SECP256K1
+            .random_public_and_private_key_data(&mut rng, platform_version)
+            .unwrap();
+
+        let asset_lock_proof = instant_asset_lock_proof_fixture(Some(
+            PrivateKey::from_slice(pk.as_slice(), Network::Testnet).unwrap(),
+        ));
+
+        let identity: Identity = IdentityV0 {
+            id: asset_lock_proof
+                .create_identifier()
+                .expect("expected an identifier"),
+            public_keys: BTreeMap::from([(1, key)]),
+            balance: 1000000000,
+            revision: 0,
+        }
+        .into();
+
+        let identity_create_transition: StateTransition =
+            IdentityCreateTransition::try_from_identity_with_signer(
+                identity.clone(),
+                asset_lock_proof,
+                pk.as_slice(),
+                &signer,
+                &NativeBlsModule,
+                platform_version,
+            )
+            .expect("expected an identity create transition");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let execution_result = platform
+            .execute_tx(
+                identity_create_transition
+                    .serialize_to_bytes()
+                    .expect("serialized state transition"),
+                &BlockInfo::default(),
+                &transaction,
+            )
+            .expect("expected to execute identity create tx");
+        assert!(matches!(execution_result, SuccessfulPaidExecution(..)));
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        (identity, signer)
+    }
+
+    #[test]
+    fn credit_transfers_with_consecutive_nonces_check_tx() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        platform
+            .core_rpc
+            .expect_verify_instant_lock()
+            .returning(|_, _| Ok(true));
+
+        let platform_version = PlatformVersion::latest();
+
+        let (identity, signer) = create_identity(&platform, 567, platform_version);
+        let (recipient, _) = create_identity(&platform, 568, platform_version);
+
+        let credit_transfer = |amount: u64, nonce: u64| {
+            IdentityCreditTransferTransition::try_from_identity(
+                &identity,
+                recipient.id(),
+                amount,
+                nonce,
+                &signer,
+                platform_version,
+                None,
+            )
+            .expect("expected a credit transfer transition")
+            .serialize_to_bytes()
+            .expect("expected to serialize")
+        };
+
+        let validation_result = platform
+            .check_tx(credit_transfer(10000, 1).as_slice())
+            .expect("expected to check tx");
+        assert!(validation_result.is_valid());
+
+        // The next nonce is accepted while the first transfer is pending
+        let validation_result = platform
+            .check_tx(credit_transfer(10000, 2).as_slice())
+            .expect("expected to check tx");
+        assert!(validation_result.is_valid());
+
+        assert_eq!(platform.mempool.len(), 2);
+
+        // A nonce that doesn't follow a pending one is still rejected
+        let validation_result = platform
+            .check_tx(credit_transfer(10000, 4).as_slice())
+            .expect("expected to check tx");
+        assert!(matches!(
+            validation_result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::InvalidIdentityNonceError(_)
+            )]
+        ));
+
+        // Without the pending transfer the nonce is ahead of the committed one
+        platform.mempool.clear();
+
+        let validation_result = platform
+            .check_tx(credit_transfer(10000, 2).as_slice())
+            .expect("expected to check tx");
+        assert!(!validation_result.is_valid());
+    }
+
+    #[test]
+    fn credit_transfer_ahead_of_committed_nonce_reserves_balance_check_tx() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        platform
+            .core_rpc
+            .expect_verify_instant_lock()
+            .returning(|_, _| Ok(true));
+
+        let platform_version = PlatformVersion::latest();
+
+        let (identity, signer) = create_identity(&platform, 567, platform_version);
+        let (recipient, _) = create_identity(&platform, 568, platform_version);
+
+        let balance = platform
+            .drive
+            .fetch_identity_balance(identity.id().to_buffer(), None, platform_version)
+            .expect("expected to fetch balance")
+            .expect("expected the identity to exist");
+
+        let credit_transfer = |amount: u64, nonce: u64| {
+            IdentityCreditTransferTransition::try_from_identity(
+                &identity,
+                recipient.id(),
+                amount,
+                nonce,
+                &signer,
+                platform_version,
+                None,
+            )
+            .expect("expected a credit transfer transition")
+            .serialize_to_bytes()
+            .expect("expected to serialize")
+        };
+
+        let validation_result = platform
+            .check_tx(credit_transfer(balance / 2, 1).as_slice())
+            .expect("expected to check tx");
+        assert!(validation_result.is_valid());
+
+        // The committed balance covers the second transfer, but not once the first one is paid
+        let validation_result = platform
+            .check_tx(credit_transfer(balance / 2, 2).as_slice())
+            .expect("expected to check tx");
+        assert!(matches!(
+            validation_result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::IdentityInsufficientBalanceError(_)
+            )]
+        ));
+        assert_eq!(platform.mempool.len(), 1);
+
+        // A transfer the pending chain can pay for is accepted with its fees
+        let validation_result = platform
+            .check_tx(credit_transfer(10000, 2).as_slice())
+            .expect("expected to check tx");
+        assert!(validation_result.is_valid());
+
+        let fee_result = validation_result.data.expect("expected a fee result");
+        assert!(fee_result.total_base_fee() > 0);
+
+        assert_eq!(platform.mempool.len(), 2);
+    }
+
+    #[test]
+    fn credit_transfer_conflicting_with_pending_one_check_tx() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        platform
+            .core_rpc
+            .expect_verify_instant_lock()
+            .returning(|_, _| Ok(true));
+
+        let platform_version = PlatformVersion::latest();
+
+        let (identity, signer) = create_identity(&platform, 567, platform_version);
+        let (recipient, _) = create_identity(&platform, 568, platform_version);
+
+        let credit_transfer = |amount: u64| {
+            IdentityCreditTransferTransition::try_from_identity(
+                &identity,
+                recipient.id(),
+                amount,
+                1,
+                &signer,
+                platform_version,
+                None,
+            )
+            .expect("expected a credit transfer transition")
+            .serialize_to_bytes()
+            .expect("expected to serialize")
+        };
+
+        let pending_transfer = credit_transfer(10000);
+
+        let validation_result = platform
+            .check_tx(pending_transfer.as_slice())
+            .expect("expected to check tx");
+        assert!(validation_result.is_valid());
+
+        // Checking the same transition again is fine
+        let validation_result = platform
+            .check_tx(pending_transfer.as_slice())
+            .expect("expected to check tx");
+        assert!(validation_result.is_valid());
+
+        // Another transfer using the same nonce is rejected
+        let validation_result = platform
+            .check_tx(credit_transfer(20000).as_slice())
+            .expect("expected to check tx");
+
+        let pending_transfer_hash =
+            dashcore::hashes::sha256::Hash::hash(&pending_transfer).to_byte_array();
+
+        assert_eq!(
+            validation_result.errors,
+            vec![ConsensusError::StateError(
+                StateError::PendingStateTransitionConflictError(
+                    PendingStateTransitionConflictError::new(pending_transfer_hash)
+                )
+            )]
+        );
+        assert_eq!(platform.mempool.len(), 1);
+    }
 }
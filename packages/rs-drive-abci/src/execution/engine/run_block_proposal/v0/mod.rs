@@ -2,6 +2,7 @@ use dashcore_rpc::dashcore::hashes::Hash;
 use dashcore_rpc::dashcore::Txid;
 
 use dpp::block::epoch::Epoch;
+use dpp::fee::Credits;
 
 use dpp::validation::ValidationResult;
 use drive::error::Error::GroveDB;
@@ -27,10 +28,12 @@ use crate::execution::types::{block_execution_context, block_state_info};
 
 use crate::platform_types::block_execution_outcome;
 use crate::platform_types::block_proposal;
+use crate::platform_types::change_feed::BlockChangeFeed;
 use crate::platform_types::epoch_info::v0::{EpochInfoV0Getters, EpochInfoV0Methods};
 use crate::platform_types::epoch_info::EpochInfo;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult;
 use crate::rpc::core::CoreRPCLike;
 
 impl<C> Platform<C>
@@ -114,6 +117,7 @@ where
             proposer_pro_tx_hash,
             validator_set_quorum_hash,
             raw_state_transitions,
+            round,
             ..
         } = block_proposal;
 
@@ -270,10 +274,42 @@ where
                 .collect(),
         );
 
-        let (block_fees, state_transition_results) = self.process_raw_state_transitions(
-            raw_state_transitions,
-            block_execution_context.block_platform_state(),
+        let (mut block_fees, state_transition_results, change_feed_events) = self
+            .process_raw_state_transitions(
+                raw_state_transitions,
+                block_execution_context.block_platform_state(),
+                &block_info,
+                transaction,
+                platform_version,
+            )?;
+
+        // Subscribers are notified only once the block is committed
+        self.change_feed.stage(BlockChangeFeed {
+            height: block_info.height,
+            round,
+            time_ms: block_info.time_ms,
+            events: change_feed_events,
+        });
+
+        // Indices added by contract updates are backfilled once the block's state transitions
+        // are executed, so a backfill registered in this block starts right away
+        let index_backfill_fees =
+            self.process_index_backfills(&block_info, transaction, platform_version)?;
+
+        block_fees.checked_add_assign(index_backfill_fees)?;
+
+        // Expired documents are deleted once the block's state transitions are executed, and
+        // their owners are refunded from the epoch pools with the block fees
+        let expired_documents_refunds =
+            self.remove_expired_documents(&block_info, transaction, platform_version)?;
+
+        block_fees.checked_add_assign(expired_documents_refunds)?;
+
+        // Feature flags are read once the block's state transitions are executed, so a feature
+        // flag created in the block it is enabled at is applied too
+        let consensus_param_updates = self.update_consensus_params(
             &block_info,
+            block_execution_context.block_platform_state_mut(),
             transaction,
             platform_version,
         )?;
@@ -288,7 +324,22 @@ where
 
         // while we have the state transitions executed, we now need to process the block fees
 
-        let block_fees_v0: BlockFeesV0 = block_fees.into();
+        let mut block_fees_v0: BlockFeesV0 = block_fees.into();
+
+        // User fee increases go to the proposer, so they are taken out of the processing fee
+        // distributed into the epoch pools
+        let proposer_tips = state_transition_results
+            .iter()
+            .filter_map(|(_, execution_result)| match execution_result {
+                StateTransitionExecutionResult::SuccessfulPaidExecution(_, _, proposer_tip) => {
+                    Some(*proposer_tip)
+                }
+                _ => None,
+            })
+            .fold(0, Credits::saturating_add);
+
+        block_fees_v0.processing_fee = block_fees_v0.processing_fee.saturating_sub(proposer_tips);
+        block_fees_v0.proposer_tips = proposer_tips;
 
         // Process fees
         let processed_block_fees = self.process_block_fees(
@@ -336,6 +387,7 @@ where
                 app_hash: root_hash,
                 state_transition_results,
                 validator_set_update,
+                consensus_param_updates,
                 protocol_version: platform_version.protocol_version,
             },
         ))
@@ -0,0 +1,57 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::consensus::ConsensusError;
+use dpp::fee::fee_result::FeeResult;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Estimates the fees of a state transition without executing it.
+    ///
+    /// The state transition goes through the same transformation and validation as in check tx,
+    /// against the last committed state, and its drive operations are then applied in a
+    /// transaction that is rolled back. Nothing is written to state and the mempool is left
+    /// untouched. The signature of an unsigned state transition is not verified, so fees can be
+    /// shown before asking the user to sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_state_transition` - A serialized, signed or unsigned, state transition.
+    /// * `state` - The last committed platform state.
+    /// * `platform_version` - The platform version of the last committed state.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ValidationResult<FeeResult, ConsensusError>, Error>` - The estimated fees, along
+    ///   with the consensus errors the state transition would fail with if it was broadcast.
+    pub fn estimate_state_transition_fee(
+        &self,
+        raw_state_transition: &[u8],
+        state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<ValidationResult<FeeResult, ConsensusError>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .engine
+            .estimate_state_transition_fee
+        {
+            0 => {
+                self.estimate_state_transition_fee_v0(raw_state_transition, state, platform_version)
+            }
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "estimate_state_transition_fee".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
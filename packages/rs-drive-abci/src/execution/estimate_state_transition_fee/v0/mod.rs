@@ -0,0 +1,458 @@
+use crate::error::Error;
+use crate::execution::types::execution_event::ExecutionEvent;
+use crate::execution::validation::state_transition::processor::process_state_transition_in_dry_run;
+use crate::platform_types::platform::{Platform, PlatformRef};
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::basic::decode::SerializedObjectParsingError;
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::ConsensusError;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::DriveOperation;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Estimates the fees of a state transition without executing it.
+    ///
+    /// The drive operations of the state transition are applied in a transaction that is rolled
+    /// back, so the fees take into account the refunds for removed data. Data contract operations
+    /// are only estimated, as applying them would put the uncommitted contract into the data
+    /// contract cache shared with the block being executed.
+    pub(super) fn estimate_state_transition_fee_v0(
+        &self,
+        raw_state_transition: &[u8],
+        state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<ValidationResult<FeeResult, ConsensusError>, Error> {
+        let state_transition = match StateTransition::deserialize_from_bytes(raw_state_transition) {
+            Ok(state_transition) => state_transition,
+            Err(err) => {
+                return Ok(ValidationResult::new_with_error(
+                    ConsensusError::BasicError(BasicError::SerializedObjectParsingError(
+                        SerializedObjectParsingError::new(err.to_string()),
+                    )),
+                ))
+            }
+        };
+
+        // Latest committed or genesis block info
+        let block_info = state.any_block_info();
+
+        let platform_ref = PlatformRef {
+            drive: &self.drive,
+            state,
+            config: &self.config,
+            core_rpc: &self.core_rpc,
+            block_info,
+        };
+
+        let execution_event =
+            process_state_transition_in_dry_run(&platform_ref, state_transition, None)?;
+
+        let transaction = self.drive.grove.start_transaction();
+
+        let estimation_result = execution_event.and_then_borrowed_validation(|execution_event| {
+            self.estimate_fees_of_event_in_transaction(
+                execution_event,
+                block_info,
+                &transaction,
+                platform_version,
+            )
+        });
+
+        self.drive
+            .rollback_transaction(&transaction, &platform_version.drive)?;
+
+        estimation_result
+    }
+
+    /// Validates the fees of the event and, if the identity can pay them, applies its operations
+    /// in the given transaction to get the actual fees.
+    fn estimate_fees_of_event_in_transaction(
+        &self,
+        execution_event: &ExecutionEvent,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<FeeResult>, Error> {
+        let fee_validation_result = self.validate_fees_of_event(
+            execution_event,
+            block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        if !fee_validation_result.is_valid() {
+            return Ok(fee_validation_result);
+        }
+
+        let (operations, user_fee_increase) = match execution_event {
+            ExecutionEvent::PaidDriveEvent {
+                operations,
+                user_fee_increase,
+                ..
+            }
+            | ExecutionEvent::PaidFromAssetLockDriveEvent {
+                operations,
+                user_fee_increase,
+                ..
+            } => (operations, *user_fee_increase),
+            ExecutionEvent::FreeDriveEvent { .. } => return Ok(fee_validation_result),
+        };
+
+        if operations
+            .iter()
+            .any(|operation| matches!(operation, DriveOperation::DataContractOperation(_)))
+        {
+            return Ok(fee_validation_result);
+        }
+
+        let mut fee_result = self
+            .drive
+            .apply_drive_operations(
+                operations.clone(),
+                true,
+                block_info,
+                Some(transaction),
+                platform_version,
+            )
+            .map_err(Error::Drive)?;
+
+        fee_result.apply_user_fee_increase(user_fee_increase);
+
+        Ok(ConsensusValidationResult::new_with_data(fee_result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult::SuccessfulPaidExecution;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dashcore::{Network, PrivateKey};
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::{
+        CreateRandomDocument, DocumentFieldFillSize, DocumentFieldFillType,
+    };
+    use dpp::document::document_methods::DocumentMethodsV0;
+    use dpp::document::DocumentV0Setters;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::KeyType::ECDSA_SECP256K1;
+    use dpp::identity::{Identity, IdentityV0};
+    use dpp::platform_value::Bytes32;
+    use dpp::prelude::IdentityPublicKey;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+    use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+    use dpp::state_transition::identity_create_transition::methods::IdentityCreateTransitionMethodsV0;
+    use dpp::state_transition::identity_create_transition::IdentityCreateTransition;
+    use dpp::state_transition::StateTransition;
+    use dpp::tests::fixtures::{get_dashpay_contract_fixture, instant_asset_lock_proof_fixture};
+    use dpp::version::PlatformVersion;
+    use dpp::NativeBlsModule;
+    use platform_version::TryIntoPlatformVersioned;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use simple_signer::signer::SimpleSigner;
+    use std::collections::BTreeMap;
+
+    fn identity_create_transition(
+        seed: u64,
+        platform_version: &PlatformVersion,
+    ) -> (
+        Identity,
+        IdentityPublicKey,
+        Vec<u8>,
+        SimpleSigner,
+        StateTransition,
+    ) {
+        let mut signer = SimpleSigner::default();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let (key, private_key) =
+            IdentityPublicKey::random_ecdsa_critical_level_authentication_key_with_rng(
+                1,
+                &mut rng,
+                platform_version,
+            )
+            .expect("expected to get key pair");
+
+        signer.add_key(key.clone(), private_key.clone());
+
+        let (_, pk) = ECDSA_SECP256K1
+            .random_public_and_private_key_data(&mut rng, platform_version)
+            .unwrap();
+
+        let asset_lock_proof = instant_asset_lock_proof_fixture(Some(
+            PrivateKey::from_slice(pk.as_slice(), Network::Testnet).unwrap(),
+        ));
+
+        let identity: Identity = IdentityV0 {
+            id: asset_lock_proof
+                .create_identifier()
+                .expect("expected an identifier"),
+            public_keys: BTreeMap::from([(1, key.clone())]),
+            balance: 1000000000,
+            revision: 0,
+        }
+        .into();
+
+        let identity_create_transition = IdentityCreateTransition::try_from_identity_with_signer(
+            identity.clone(),
+            asset_lock_proof,
+            pk.as_slice(),
+            &signer,
+            &NativeBlsModule,
+            platform_version,
+        )
+        .expect("expected an identity create transition");
+
+        (
+            identity,
+            key,
+            private_key,
+            signer,
+            identity_create_transition,
+        )
+    }
+
+    fn execute_and_commit(platform: &TempPlatform<MockCoreRPCLike>, raw_tx: Vec<u8>) {
+        let transaction = platform.drive.grove.start_transaction();
+
+        let execution_result = platform
+            .execute_tx(raw_tx, &BlockInfo::default(), &transaction)
+            .expect("expected to execute tx");
+
+        assert!(
+            matches!(execution_result, SuccessfulPaidExecution(..)),
+            "{:?}",
+            execution_result
+        );
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+    }
+
+    #[test]
+    fn should_return_parsing_error_for_invalid_state_transition() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let state = platform.state.read().unwrap();
+        let platform_version = state.current_platform_version().unwrap();
+
+        let result = platform
+            .estimate_state_transition_fee(&[1, 2, 3], &state, platform_version)
+            .expect("expected to estimate fee");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::SerializedObjectParsingError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_estimate_identity_create_without_creating_identity() {
+        let mut platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        platform
+            .core_rpc
+            .expect_verify_instant_lock()
+            .returning(|_, _| Ok(true));
+
+        let platform_version = PlatformVersion::latest();
+
+        let (identity, _, _, _, identity_create_transition) =
+            identity_create_transition(45, platform_version);
+
+        let root_hash_before = platform.drive.grove.root_hash(None).unwrap().unwrap();
+
+        let state = platform.state.read().unwrap();
+
+        let result = platform
+            .estimate_state_transition_fee(
+                &identity_create_transition
+                    .serialize_to_bytes()
+                    .expect("expected to serialize state transition"),
+                &state,
+                platform_version,
+            )
+            .expect("expected to estimate fee");
+
+        assert!(result.is_valid(), "{:?}", result.errors);
+
+        let fee_result = result.into_data().expect("expected fee result");
+
+        assert_ne!(fee_result.storage_fee, 0);
+        assert_ne!(fee_result.processing_fee, 0);
+
+        assert_eq!(
+            platform.drive.grove.root_hash(None).unwrap().unwrap(),
+            root_hash_before
+        );
+
+        let balance = platform
+            .drive
+            .fetch_identity_balance(identity.id().to_buffer(), None, platform_version)
+            .expect("expected to fetch balance");
+
+        assert_eq!(balance, None);
+    }
+
+    #[test]
+    fn should_estimate_refunds_of_document_update() {
+        let mut platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        platform
+            .core_rpc
+            .expect_verify_instant_lock()
+            .returning(|_, _| Ok(true));
+
+        let platform_version = PlatformVersion::latest();
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let (identity, key, private_key, signer, identity_create_transition) =
+            identity_create_transition(46, platform_version);
+
+        execute_and_commit(
+            &platform,
+            identity_create_transition
+                .serialize_to_bytes()
+                .expect("expected to serialize state transition"),
+        );
+
+        let dashpay =
+            get_dashpay_contract_fixture(Some(identity.id()), platform_version.protocol_version);
+        let dashpay_contract = dashpay.data_contract().clone();
+        let mut create_contract_state_transition: StateTransition = dashpay
+            .try_into_platform_versioned(platform_version)
+            .expect("expected a state transition");
+        create_contract_state_transition
+            .sign(&key, private_key.as_slice(), &NativeBlsModule)
+            .expect("expected to sign transition");
+
+        execute_and_commit(
+            &platform,
+            create_contract_state_transition
+                .serialize_to_bytes()
+                .expect("expected to serialize state transition"),
+        );
+
+        let profile = dashpay_contract
+            .document_type_for_name("profile")
+            .expect("expected a profile document type");
+
+        let entropy = Bytes32::random_with_rng(&mut rng);
+
+        let mut document = profile
+            .random_document_with_identifier_and_entropy(
+                &mut rng,
+                identity.id(),
+                entropy,
+                DocumentFieldFillType::FillIfNotRequired,
+                DocumentFieldFillSize::AnyDocumentFillSize,
+                platform_version,
+            )
+            .expect("expected a random document");
+
+        document.set("displayName", "Bartholomew the Third".into());
+        document.set("avatarUrl", "http://test.com/bartholomew.jpg".into());
+
+        // The updated document is smaller, so part of its storage is refunded
+        let mut altered_document = document.clone();
+
+        altered_document.increment_revision().unwrap();
+        altered_document.set("displayName", "Bart".into());
+        altered_document.set("avatarUrl", "http://test.com/b.jpg".into());
+
+        let documents_batch_create_transition =
+            DocumentsBatchTransition::new_document_creation_transition_from_document(
+                document,
+                profile,
+                entropy.0,
+                &key,
+                &signer,
+                platform_version,
+                None,
+                None,
+                None,
+            )
+            .expect("expect to create documents batch transition");
+
+        execute_and_commit(
+            &platform,
+            documents_batch_create_transition
+                .serialize_to_bytes()
+                .expect("expected to serialize state transition"),
+        );
+
+        let documents_batch_update_transition =
+            DocumentsBatchTransition::new_document_replacement_transition_from_document(
+                altered_document,
+                profile,
+                &key,
+                &signer,
+                platform_version,
+                None,
+                None,
+                None,
+            )
+            .expect("expect to create documents batch transition");
+
+        let root_hash_before = platform.drive.grove.root_hash(None).unwrap().unwrap();
+
+        let state = platform.state.read().unwrap();
+
+        let result = platform
+            .estimate_state_transition_fee(
+                &documents_batch_update_transition
+                    .serialize_to_bytes()
+                    .expect("expected to serialize state transition"),
+                &state,
+                platform_version,
+            )
+            .expect("expected to estimate fee");
+
+        assert!(result.is_valid(), "{:?}", result.errors);
+
+        let fee_result = result.into_data().expect("expected fee result");
+
+        assert!(fee_result
+            .fee_refunds
+            .calculate_refunds_amount_for_identity(identity.id())
+            .is_some());
+
+        assert_eq!(
+            platform.drive.grove.root_hash(None).unwrap().unwrap(),
+            root_hash_before
+        );
+    }
+}
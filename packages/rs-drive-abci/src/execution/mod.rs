@@ -2,6 +2,8 @@
 mod check_tx;
 /// Engine module
 pub mod engine;
+/// Fee estimation module
+mod estimate_state_transition_fee;
 /// platform execution events
 pub(in crate::execution) mod platform_events;
 /// Types needed in execution
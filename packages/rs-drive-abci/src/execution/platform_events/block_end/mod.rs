@@ -7,3 +7,12 @@ pub(in crate::execution) mod validator_set_update;
 
 /// Updating the drive cache happens as the final part of block finalization
 pub(in crate::execution) mod update_drive_cache;
+
+/// Retaining committed state for historical queries
+pub(in crate::execution) mod store_historical_state;
+
+/// Taking state sync snapshots
+pub(in crate::execution) mod store_snapshot;
+
+/// Applying consensus params set by feature flags
+pub(in crate::execution) mod update_consensus_params;
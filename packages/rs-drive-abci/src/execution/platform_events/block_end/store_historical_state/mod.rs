@@ -0,0 +1,228 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C> {
+    /// Retains the state of platform after a block was committed, so it can be queried
+    /// at that height later on.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the store_historical_state function.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform_state` - The `PlatformState` right after the block was committed.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns an empty `Result` if the state is successfully retained, otherwise returns an `Error`.
+    ///
+    pub fn store_historical_state(
+        &self,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .store_historical_state
+        {
+            0 => self.store_historical_state_v0(platform_state, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "store_historical_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::PlatformConfig;
+    use crate::platform_types::platform::Platform;
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dapi_grpc::platform::v0::get_identity_balance_response::get_identity_balance_response_v0;
+    use dapi_grpc::platform::v0::get_identity_request::GetIdentityRequestV0;
+    use dapi_grpc::platform::v0::{
+        get_identity_balance_response, get_identity_request, GetIdentityBalanceResponse,
+        GetIdentityRequest,
+    };
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0;
+    use dpp::fee::Credits;
+    use dpp::identifier::Identifier;
+    use dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+    use dpp::identity::Identity;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::version::PlatformVersion;
+    use prost::Message;
+
+    /// Commits a block at `height` as far as historical states are concerned
+    fn commit_block(platform: &Platform<MockCoreRPCLike>, height: u64) {
+        let platform_version = PlatformVersion::latest();
+
+        let mut state = platform.state.read().unwrap().clone();
+
+        state.set_last_committed_block_info(Some(
+            ExtendedBlockInfoV0 {
+                basic_info: BlockInfo {
+                    time_ms: height * 1000,
+                    height,
+                    core_height: 42,
+                    epoch: Default::default(),
+                },
+                app_hash: platform.drive.grove.root_hash(None).unwrap().unwrap(),
+                quorum_hash: [0u8; 32],
+                block_id_hash: [0u8; 32],
+                signature: [0u8; 96],
+                round: 0,
+            }
+            .into(),
+        ));
+
+        platform
+            .drive
+            .grove
+            .put_aux(
+                b"saved_state",
+                &state.serialize_to_bytes().unwrap(),
+                None,
+                None,
+            )
+            .unwrap()
+            .expect("expected to save platform state");
+
+        *platform.state.write().unwrap() = state.clone();
+
+        platform
+            .store_historical_state(&state, platform_version)
+            .expect("expected to store historical state");
+    }
+
+    fn query_balance(
+        platform: &Platform<MockCoreRPCLike>,
+        identity_id: Identifier,
+        height: Option<u64>,
+    ) -> Credits {
+        let request = GetIdentityRequest {
+            version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
+                id: identity_id.to_vec(),
+                prove: false,
+                height,
+            })),
+        }
+        .encode_to_vec();
+
+        let validation_result = platform
+            .query("/identity/balance", &request, PlatformVersion::latest())
+            .expect("expected query to succeed");
+
+        assert!(
+            validation_result.is_valid(),
+            "{:?}",
+            validation_result.errors
+        );
+
+        let response =
+            GetIdentityBalanceResponse::decode(validation_result.data.unwrap().as_slice())
+                .expect("expected to decode response");
+
+        let Some(get_identity_balance_response::Version::V0(response)) = response.version else {
+            panic!("expected a v0 response");
+        };
+
+        let Some(get_identity_balance_response_v0::Result::Balance(balance)) = response.result
+        else {
+            panic!("expected a balance");
+        };
+
+        balance
+    }
+
+    #[test]
+    fn should_query_old_data_at_retained_height_after_restart() {
+        let platform_version = PlatformVersion::latest();
+        let checkpoints_dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let config = PlatformConfig {
+            historical_state_retention_blocks: 2,
+            historical_state_checkpoints_path: checkpoints_dir.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut identity = Identity::random_identity(2, Some(1), platform_version)
+            .expect("expected a random identity");
+        identity.set_balance(100);
+
+        platform
+            .drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        commit_block(&platform, 1);
+
+        platform
+            .drive
+            .add_to_identity_balance(
+                identity.id().to_buffer(),
+                50,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add to balance");
+
+        commit_block(&platform, 2);
+
+        assert_eq!(query_balance(&platform, identity.id(), Some(1)), 100);
+        assert_eq!(query_balance(&platform, identity.id(), Some(2)), 150);
+        assert_eq!(query_balance(&platform, identity.id(), None), 150);
+
+        let TempPlatform { platform, tempdir } = platform;
+        drop(platform);
+
+        let platform = TempPlatform::open_with_tempdir(tempdir, config);
+
+        assert_eq!(
+            platform
+                .historical_states
+                .read()
+                .unwrap()
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(query_balance(&platform, identity.id(), Some(1)), 100);
+
+        // Height 1 falls out of the retention window and its checkpoint is removed
+        commit_block(&platform, 3);
+
+        assert!(!checkpoints_dir.path().join("1").exists());
+        assert!(platform.historical_states.read().unwrap().get(&1).is_none());
+    }
+}
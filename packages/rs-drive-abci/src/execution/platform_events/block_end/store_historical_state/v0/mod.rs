@@ -0,0 +1,94 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::checkpoints::{create_checkpoint, remove_checkpoints};
+use crate::platform_types::historical_state::HistoricalPlatformState;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+
+use dpp::version::PlatformVersion;
+use drive::drive::Drive;
+use std::sync::Arc;
+
+impl<C> Platform<C> {
+    /// Creates a GroveDB checkpoint of the committed block and keeps it, together with the
+    /// platform state, for `historical_state_retention_blocks` blocks.
+    ///
+    /// The platform state is stored in aux storage with the block, so it's part of the checkpoint
+    /// and retained heights are reopened with [`Platform::load_historical_states`] after a restart.
+    ///
+    /// Checkpoints that fell out of the retention window are removed, including the ones
+    /// left over from a previous run.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform_state` - The `PlatformState` right after the block was committed.
+    /// * `platform_version` - A `PlatformVersion` reference used to open the checkpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns an empty `Result` if the state is successfully retained, otherwise returns an `Error`.
+    ///
+    pub(super) fn store_historical_state_v0(
+        &self,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let retention_blocks = self.config.historical_state_retention_blocks;
+
+        if retention_blocks == 0 {
+            return Ok(());
+        }
+
+        let height = platform_state.last_block_height();
+        let checkpoints_path = &self.config.historical_state_checkpoints_path;
+
+        // A state at the same height can only be left over from a previous run,
+        // its checkpoint must be closed before it's replaced
+        self.historical_states.write().unwrap().remove(&height);
+
+        let checkpoint_path = create_checkpoint(
+            &self.drive,
+            checkpoints_path,
+            height,
+            ExecutionError::HistoricalStateStorageError,
+        )?;
+
+        let drive = Drive::open(
+            &checkpoint_path,
+            Some(self.config.drive.clone()),
+            platform_version,
+        )?;
+
+        let first_retained_height = height.saturating_sub(retention_blocks - 1);
+
+        let expired_states = {
+            let mut historical_states = self.historical_states.write().unwrap();
+
+            historical_states.insert(
+                height,
+                Arc::new(HistoricalPlatformState {
+                    drive,
+                    state: platform_state.clone(),
+                }),
+            );
+
+            let retained_states = historical_states.split_off(&first_retained_height);
+
+            std::mem::replace(&mut *historical_states, retained_states)
+        };
+
+        // Close expired checkpoints before removing them from disk
+        drop(expired_states);
+
+        remove_checkpoints(
+            checkpoints_path,
+            |checkpoint_height| {
+                checkpoint_height >= first_retained_height && checkpoint_height <= height
+            },
+            ExecutionError::HistoricalStateStorageError,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C> {
+    /// Takes a state sync snapshot of the committed block if its height is a multiple of
+    /// the configured snapshot interval.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the store_snapshot function.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform_state` - The `PlatformState` right after the block was committed.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns an empty `Result` if the snapshot is successfully taken or not due, otherwise returns an `Error`.
+    ///
+    pub fn store_snapshot(
+        &self,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version.drive_abci.methods.block_end.store_snapshot {
+            0 => self.store_snapshot_v0(platform_state, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "store_snapshot".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::checkpoints::{create_checkpoint, remove_checkpoints};
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::snapshot::PlatformSnapshot;
+
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use drive::drive::config::DriveConfig;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+impl<C> Platform<C> {
+    /// Creates a GroveDB checkpoint of the committed block and keeps it, together with
+    /// the platform state, to be offered to syncing nodes.
+    ///
+    /// Splitting the checkpoint into chunks walks the whole state, so it's done on a background
+    /// thread and the snapshot is offered once it's built. Builds run one at a time, a commit
+    /// only waits for the previous build if it takes longer than the snapshot interval.
+    ///
+    /// Only the `snapshots_retained` most recent snapshots are kept. Older checkpoints are
+    /// removed, including the ones left over from a previous run.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform_state` - The `PlatformState` right after the block was committed.
+    /// * `platform_version` - A `PlatformVersion` reference used to open the checkpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns an empty `Result` if the snapshot build is started or not due, otherwise returns an `Error`.
+    ///
+    pub(super) fn store_snapshot_v0(
+        &self,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let interval = self.config.snapshot_interval;
+        let height = platform_state.last_block_height();
+
+        if interval == 0 || height % interval != 0 {
+            return Ok(());
+        }
+
+        self.wait_for_snapshot_build();
+
+        let snapshots_path = self.config.snapshots_path.clone();
+
+        // A snapshot at the same height can only be left over from a previous run,
+        // its checkpoint must be closed before it's replaced
+        self.snapshots.write().unwrap().remove(&height);
+
+        let checkpoint_path = create_checkpoint(
+            &self.drive,
+            &snapshots_path,
+            height,
+            ExecutionError::SnapshotError,
+        )?;
+
+        let serialized_platform_state = platform_state.serialize_to_bytes()?;
+        let platform_version = PlatformVersion::get(platform_version.protocol_version)?;
+        let drive_config = self.config.drive.clone();
+        let snapshots_retained = self.config.snapshots_retained;
+        let snapshots = Arc::clone(&self.snapshots);
+
+        let snapshot_build = std::thread::Builder::new()
+            .name("snapshot-build".to_string())
+            .spawn(move || {
+                let result = build_snapshot(
+                    height,
+                    &checkpoint_path,
+                    serialized_platform_state,
+                    drive_config,
+                    platform_version,
+                )
+                .and_then(|snapshot| {
+                    keep_snapshot(&snapshots, snapshot, &snapshots_path, snapshots_retained)
+                });
+
+                // Snapshots only speed up syncing of new nodes, failing to take one must not halt the chain
+                if let Err(error) = result {
+                    tracing::error!(?error, height, "can't build state sync snapshot");
+                }
+            })
+            .map_err(|e| {
+                Error::Execution(ExecutionError::SnapshotError(format!(
+                    "can't start snapshot build: {}",
+                    e
+                )))
+            })?;
+
+        self.snapshot_build.lock().unwrap().replace(snapshot_build);
+
+        Ok(())
+    }
+}
+
+/// Open the checkpoint of a block and split it into chunks
+fn build_snapshot(
+    height: u64,
+    checkpoint_path: &Path,
+    serialized_platform_state: Vec<u8>,
+    drive_config: DriveConfig,
+    platform_version: &PlatformVersion,
+) -> Result<PlatformSnapshot, Error> {
+    let drive = Drive::open(checkpoint_path, Some(drive_config), platform_version)?;
+
+    let app_hash = drive
+        .grove
+        .root_hash(None)
+        .unwrap()
+        .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+    let snapshot = PlatformSnapshot::new(height, app_hash, serialized_platform_state, drive)?;
+
+    tracing::info!(
+        height,
+        chunks = snapshot.chunks.len(),
+        "state sync snapshot taken"
+    );
+
+    Ok(snapshot)
+}
+
+/// Offer a built snapshot and remove the expired ones
+fn keep_snapshot(
+    snapshots: &RwLock<BTreeMap<u64, Arc<PlatformSnapshot>>>,
+    snapshot: PlatformSnapshot,
+    snapshots_path: &Path,
+    snapshots_retained: usize,
+) -> Result<(), Error> {
+    let expired_snapshots = {
+        let mut snapshots = snapshots.write().unwrap();
+
+        snapshots.insert(snapshot.height, Arc::new(snapshot));
+
+        let expired_count = snapshots.len().saturating_sub(snapshots_retained);
+
+        let expired_heights: Vec<u64> = snapshots.keys().take(expired_count).copied().collect();
+
+        expired_heights
+            .into_iter()
+            .filter_map(|expired_height| snapshots.remove(&expired_height))
+            .collect::<Vec<_>>()
+    };
+
+    // Close expired checkpoints before removing them from disk
+    drop(expired_snapshots);
+
+    let retained_heights: Vec<u64> = snapshots.read().unwrap().keys().copied().collect();
+
+    remove_checkpoints(
+        snapshots_path,
+        |snapshot_height| retained_heights.contains(&snapshot_height),
+        ExecutionError::SnapshotError,
+    )
+}
@@ -0,0 +1,64 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+use tenderdash_abci::proto::types::ConsensusParams;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Applies the `updateConsensusParams` feature flags enabled at the height of the block.
+    ///
+    /// The active consensus params are kept in the platform state of the block, so a feature
+    /// flag only has to set the params it changes.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the update_consensus_params function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block being processed.
+    /// * `block_platform_state` - The platform state of the block, updated with the new params.
+    /// * `transaction` - The transaction of the block.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<ConsensusParams>, Error>` - The consensus param updates to send to
+    ///   Tenderdash, or `None` if no feature flag is enabled at this height.
+    ///
+    pub(in crate::execution) fn update_consensus_params(
+        &self,
+        block_info: &BlockInfo,
+        block_platform_state: &mut PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ConsensusParams>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .update_consensus_params
+        {
+            0 => self.update_consensus_params_v0(
+                block_info,
+                block_platform_state,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "update_consensus_params".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::platform_value::Value;
+use dpp::system_data_contracts::feature_flags_contract;
+use dpp::system_data_contracts::feature_flags_contract::document_types::update_consensus_params;
+use dpp::system_data_contracts::feature_flags_contract::document_types::update_consensus_params::properties::PROPERTY_ENABLE_AT_HEIGHT;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::grovedb::Transaction;
+use drive::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use std::collections::BTreeMap;
+use tenderdash_abci::proto::types::ConsensusParams;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn update_consensus_params_v0(
+        &self,
+        block_info: &BlockInfo,
+        block_platform_state: &mut PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ConsensusParams>, Error> {
+        // Feature flags can only be created once the contract is registered
+        let Some(contract_fetch_info) = self
+            .drive
+            .fetch_contract(
+                feature_flags_contract::ID_BYTES,
+                None,
+                None,
+                Some(transaction),
+                platform_version,
+            )
+            .unwrap()?
+        else {
+            return Ok(None);
+        };
+
+        let contract = &contract_fetch_info.contract;
+        let document_type = contract.document_type_for_name(update_consensus_params::NAME)?;
+
+        // `enableAtHeight` is a unique index, so there is at most one feature flag per height
+        let drive_query = DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: None,
+                in_clause: None,
+                range_clause: None,
+                equal_clauses: BTreeMap::from([(
+                    PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+                    WhereClause {
+                        field: PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::U64(block_info.height),
+                    },
+                )]),
+                other_in_clauses: vec![],
+                or_clauses: vec![],
+            },
+            offset: None,
+            limit: Some(1),
+            order_by: Default::default(),
+            start_at: None,
+            start_at_included: false,
+            block_time_ms: None,
+        };
+
+        let Some(feature_flag) = self
+            .drive
+            .query_documents(
+                drive_query,
+                None,
+                false,
+                Some(transaction),
+                Some(platform_version.protocol_version),
+            )?
+            .documents_owned()
+            .into_iter()
+            .next()
+        else {
+            return Ok(None);
+        };
+
+        let update = block_platform_state
+            .consensus_params_mut()
+            .apply_feature_flag(&feature_flag)?;
+
+        tracing::info!(
+            height = block_info.height,
+            consensus_params = ?update,
+            "consensus params are updated by a feature flag"
+        );
+
+        Ok(Some(update.into()))
+    }
+}
@@ -116,6 +116,27 @@ impl<CoreRPCLike> Platform<CoreRPCLike> {
             )?,
         ));
 
+        // User fee increases are kept in the epoch pool for the proposer
+        // and paid out together with the epoch fees
+        if block_fees.proposer_tips() > 0 {
+            let cached_previous_tips = if epoch_info.is_epoch_change() {
+                Some(0)
+            } else {
+                None
+            };
+
+            batch.push(DriveOperation::GroveDBOperation(
+                current_epoch.increment_proposer_tips_operation(
+                    &self.drive,
+                    &block_info.proposer_pro_tx_hash(),
+                    block_fees.proposer_tips(),
+                    cached_previous_tips,
+                    Some(transaction),
+                    platform_version,
+                )?,
+            ));
+        }
+
         // Distribute fees from unpaid epoch pool to proposers
 
         // Since start_block_height for current epoch is batched and not committed yet
@@ -271,6 +292,7 @@ mod tests {
                 storage_fee: 100000,
                 processing_fee: 10000,
                 refunds_per_epoch: CreditsPerEpoch::from_iter([(epoch_index, 100)]),
+                proposer_tips: 0,
             }
             .into();
 
@@ -503,4 +525,186 @@ mod tests {
             &transaction,
         );
     }
+
+    #[test]
+    fn test_process_block_fees_pays_proposer_tips_with_epoch_payout() {
+        use crate::execution::types::block_fees::v0::BlockFeesV0;
+        use crate::execution::types::block_state_info::v0::BlockStateInfoV0;
+        use crate::platform_types::epoch_info::v0::EpochInfoV0;
+        use crate::rpc::core::MockCoreRPCLike;
+
+        let platform_version = PlatformVersion::latest();
+        // We are not adding to the overall platform credits so we can't verify
+        // the sum trees
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                execution: ExecutionConfig {
+                    verify_sum_trees: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        platform.create_mn_shares_contract(Some(&transaction), platform_version);
+
+        let proposers = create_test_masternode_identities(
+            &platform.drive,
+            1,
+            Some(57),
+            Some(&transaction),
+            platform_version,
+        );
+
+        let proposer = proposers[0];
+
+        let balance_before = platform
+            .drive
+            .fetch_identity_balance(proposer, Some(&transaction), platform_version)
+            .expect("should fetch balance")
+            .expect("proposer identity should exist");
+
+        let genesis_time_ms = Utc::now()
+            .timestamp_millis()
+            .to_u64()
+            .expect("block time can not be before 1970");
+
+        let process_block = |height: u64,
+                             block_time_ms: u64,
+                             previous_block_time_ms: Option<u64>,
+                             proposer_tips: u64| {
+            let block_info = BlockStateInfoV0 {
+                height,
+                round: 0,
+                block_time_ms,
+                previous_block_time_ms,
+                proposer_pro_tx_hash: proposer,
+                core_chain_locked_height: 1,
+                block_hash: None,
+                app_hash: None,
+            };
+
+            let epoch_info: EpochInfo = EpochInfoV0::from_genesis_time_and_block_info(
+                genesis_time_ms,
+                &block_info,
+                platform.config.execution.epoch_time_length_s,
+            )
+            .expect("should calculate epoch info")
+            .into();
+
+            let block_fees: BlockFees = BlockFeesV0 {
+                storage_fee: 100000,
+                processing_fee: 10000,
+                proposer_tips,
+                ..Default::default()
+            }
+            .into();
+
+            platform
+                .process_block_fees_v0(
+                    &block_info.into(),
+                    &epoch_info,
+                    block_fees,
+                    &transaction,
+                    platform_version,
+                )
+                .expect("should process block fees")
+        };
+
+        /*
+        Process first block of epoch 0 (genesis epoch)
+
+        Should keep tips in the epoch pool
+         */
+
+        process_block(1, genesis_time_ms, None, 500);
+
+        let balance = platform
+            .drive
+            .fetch_identity_balance(proposer, Some(&transaction), platform_version)
+            .expect("should fetch balance")
+            .expect("proposer identity should exist");
+
+        assert_eq!(balance, balance_before);
+
+        let genesis_epoch = Epoch::new(GENESIS_EPOCH_INDEX).unwrap();
+
+        let genesis_epoch_tips = platform
+            .drive
+            .get_epochs_proposer_tips(
+                &genesis_epoch,
+                &proposer,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("should get proposer tips");
+
+        assert_eq!(genesis_epoch_tips, 500);
+
+        let genesis_epoch_credits = platform
+            .drive
+            .get_epoch_total_credits_for_distribution(
+                &genesis_epoch,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("should get epoch credits");
+
+        /*
+        Process first block of epoch 1
+
+        Should pay genesis epoch credits and tips to the same proposer
+        which earns tips in this block
+         */
+
+        let outcome = process_block(
+            2,
+            genesis_time_ms + platform.config.execution.epoch_time_length_s * 1000 + 2,
+            Some(genesis_time_ms),
+            300,
+        );
+
+        assert!(outcome.payouts.is_some());
+
+        let core_block_rewards =
+            Platform::<MockCoreRPCLike>::epoch_core_reward_credits_for_distribution(
+                1,
+                1,
+                platform_version,
+            )
+            .expect("should calculate core block rewards");
+
+        let balance_after = platform
+            .drive
+            .fetch_identity_balance(proposer, Some(&transaction), platform_version)
+            .expect("should fetch balance")
+            .expect("proposer identity should exist");
+
+        assert_eq!(
+            balance_after,
+            balance_before + genesis_epoch_credits + core_block_rewards + 500
+        );
+
+        let genesis_epoch_tips = platform
+            .drive
+            .get_epoch_proposers_tips(&genesis_epoch, Some(&transaction), platform_version)
+            .expect("should get proposers tips");
+
+        assert!(genesis_epoch_tips.is_empty());
+
+        let current_epoch_tips = platform
+            .drive
+            .get_epochs_proposer_tips(
+                &Epoch::new(GENESIS_EPOCH_INDEX + 1).unwrap(),
+                &proposer,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("should get proposer tips");
+
+        assert_eq!(current_epoch_tips, 300);
+    }
 }
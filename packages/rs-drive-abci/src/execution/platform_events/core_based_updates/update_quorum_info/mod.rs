@@ -24,7 +24,7 @@ where
     ///
     /// * `Result<SimpleConsensusValidationResult, ExecutionError>` - A `SimpleConsensusValidationResult`
     ///   on success, or an `Error` on failure.
-    pub(in crate::execution::platform_events::core_based_updates) fn update_quorum_info(
+    pub(crate) fn update_quorum_info(
         &self,
         block_platform_state: &mut PlatformState,
         core_block_height: u32,
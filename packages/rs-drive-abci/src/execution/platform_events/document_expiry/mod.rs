@@ -0,0 +1,2 @@
+/// Deletion of the documents that expired
+pub(in crate::execution) mod remove_expired_documents;
@@ -0,0 +1,57 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Deletes the documents that expired by the time of the block.
+    ///
+    /// At most a bounded number of documents is deleted in each block, the remaining ones are
+    /// deleted in the following blocks. The deletions are not paid by anyone, but the storage
+    /// of the deleted documents is refunded to their owners.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the remove_expired_documents function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block being processed.
+    /// * `transaction` - The transaction of the block.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - The storage refunds given to the owners of the documents,
+    ///   which are removed from the epoch pools with the block fees.
+    ///
+    pub(in crate::execution) fn remove_expired_documents(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .document_expiry
+            .remove_expired_documents
+        {
+            0 => self.remove_expired_documents_v0(block_info, transaction, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "remove_expired_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
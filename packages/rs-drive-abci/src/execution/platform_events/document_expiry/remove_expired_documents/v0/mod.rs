@@ -0,0 +1,63 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+/// The maximum amount of expired documents deleted in a block
+const EXPIRED_DOCUMENTS_PER_BLOCK: u16 = 100;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn remove_expired_documents_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let outcome = self.drive.delete_expired_documents(
+            EXPIRED_DOCUMENTS_PER_BLOCK,
+            block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        if outcome.documents_deleted > 0 {
+            tracing::debug!(
+                documents_deleted = outcome.documents_deleted,
+                "expired documents are deleted"
+            );
+        }
+
+        // Only the refunds are kept, the processing of the deletions is done by the platform
+        let fee_refunds = outcome.fee_result.fee_refunds;
+
+        for (identity_id, _) in fee_refunds.iter() {
+            let Some(credits) =
+                fee_refunds.calculate_refunds_amount_for_identity(Identifier::new(*identity_id))
+            else {
+                continue;
+            };
+
+            self.drive.add_to_identity_balance(
+                *identity_id,
+                credits,
+                block_info,
+                true,
+                Some(transaction),
+                platform_version,
+            )?;
+        }
+
+        Ok(FeeResult {
+            fee_refunds,
+            removed_bytes_from_system: outcome.fee_result.removed_bytes_from_system,
+            ..Default::default()
+        })
+    }
+}
@@ -11,9 +11,11 @@ use dpp::fee::Credits;
 use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
 use dpp::version::PlatformVersion;
 use dpp::ProtocolError;
-use drive::drive::batch::DriveOperation;
+use drive::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
 use drive::drive::batch::DriveOperation::IdentityOperation;
 use drive::drive::batch::IdentityOperationType::AddToIdentityBalance;
+use drive::drive::batch::{DriveOperation, GroveDbOpBatch};
+use drive::fee_pools::epochs::operations_factory::EpochOperations;
 
 use drive::grovedb::Transaction;
 
@@ -65,6 +67,19 @@ impl<C> Platform<C> {
 
         let proposers_len = proposers.len() as u16;
 
+        // Tips are paid to proposers on top of their share of the epoch pool
+        let mut proposers_tips = self
+            .drive
+            .get_epoch_proposers_tips(&unpaid_epoch_tree, Some(transaction), platform_version)
+            .map_err(Error::Drive)?;
+
+        let mut tips_batch = GroveDbOpBatch::new();
+
+        unpaid_epoch_tree.add_delete_proposers_tips_operations(
+            proposers_tips.keys().cloned().collect(),
+            &mut tips_batch,
+        );
+
         for (i, (proposer_tx_hash, proposed_block_count)) in proposers.into_iter().enumerate() {
             let i = i as u16;
 
@@ -135,6 +150,12 @@ impl<C> Platform<C> {
                 masternode_payout_leftover
             };
 
+            let proposer_payout = proposer_payout
+                .checked_add(proposers_tips.remove(&proposer_tx_hash).unwrap_or_default())
+                .ok_or(Error::Execution(ExecutionError::Overflow(
+                    "overflow when adding proposer tips",
+                )))?;
+
             let proposer = proposer_tx_hash.as_slice().try_into().map_err(|_| {
                 Error::Execution(ExecutionError::DriveIncoherence(
                     "proposer_tx_hash is not 32 bytes long",
@@ -147,6 +168,21 @@ impl<C> Platform<C> {
             }));
         }
 
+        // Proposers are always in the proposers tree of the epoch they earned tips in,
+        // but we still don't want to burn credits if it wasn't the case
+        for (proposer_tx_hash, tips) in proposers_tips {
+            let proposer = proposer_tx_hash.as_slice().try_into().map_err(|_| {
+                Error::Execution(ExecutionError::DriveIncoherence(
+                    "proposer_tx_hash is not 32 bytes long",
+                ))
+            })?;
+
+            drive_operations.push(IdentityOperation(AddToIdentityBalance {
+                identity_id: proposer,
+                added_balance: tips,
+            }));
+        }
+
         let operations = self.drive.convert_drive_operations_to_grove_operations(
             drive_operations,
             &BlockInfo::default(),
@@ -156,6 +192,10 @@ impl<C> Platform<C> {
 
         batch.push(DriveOperation::GroveDBOpBatch(operations));
 
+        if !tips_batch.is_empty() {
+            batch.push(DriveOperation::GroveDBOpBatch(tips_batch));
+        }
+
         Ok(proposers_len)
     }
 }
@@ -175,9 +215,6 @@ mod tests {
         use dpp::identity::accessors::IdentityGettersV0;
         use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
         use drive::common::identities::create_test_masternode_identities_and_add_them_as_epoch_block_proposers;
-        use drive::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
-        use drive::drive::batch::GroveDbOpBatch;
-        use drive::fee_pools::epochs::operations_factory::EpochOperations;
         use rust_decimal::Decimal;
         use rust_decimal_macros::dec;
 
@@ -53,6 +53,8 @@ impl<C> Platform<C> {
                         value: Value::Bytes(masternode_owner_id.to_vec()),
                     },
                 )]),
+                other_in_clauses: vec![],
+                or_clauses: vec![],
             },
             offset: None,
             limit: Some(1),
@@ -351,6 +351,7 @@ mod tests {
                 full_masternode_list: Default::default(),
                 hpmn_masternode_list: Default::default(),
                 genesis_block_info: None,
+                consensus_params: Default::default(),
             }
             .into(),
             proposer_results: None,
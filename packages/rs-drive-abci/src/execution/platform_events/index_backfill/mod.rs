@@ -0,0 +1,2 @@
+/// Processing of the index backfills of document types registered by contract updates
+pub(in crate::execution) mod process_index_backfills;
@@ -0,0 +1,57 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Backfills the indices added to document types holding documents by contract updates.
+    ///
+    /// At most a bounded number of documents is backfilled in each block. The fees of the new
+    /// index entries are paid by the owner of the contract; backfills of contracts whose owner
+    /// can't pay for the next batch are paused until the owner's balance is topped up.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the process_index_backfills function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block being processed.
+    /// * `transaction` - The transaction of the block.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - The fees paid by contract owners for the backfilled entries,
+    ///   which are added to the block fees.
+    ///
+    pub(in crate::execution) fn process_index_backfills(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .index_backfill
+            .process_index_backfills
+        {
+            0 => self.process_index_backfills_v0(block_info, transaction, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "process_index_backfills".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
+use drive::grovedb::Transaction;
+
+/// The maximum amount of documents backfilled in a block, over all document types
+const INDEX_BACKFILL_DOCUMENTS_PER_BLOCK: u16 = 100;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn process_index_backfills_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let index_backfills = self
+            .drive
+            .fetch_pending_index_backfills(Some(transaction), platform_version)?;
+
+        let mut aggregate_fee_result = FeeResult::default();
+        let mut remaining_documents = INDEX_BACKFILL_DOCUMENTS_PER_BLOCK;
+
+        for index_backfill in index_backfills {
+            if remaining_documents == 0 {
+                break;
+            }
+
+            let Some(contract_fetch_info) = self
+                .drive
+                .get_contract_with_fetch_info_and_fee(
+                    index_backfill.contract_id.to_buffer(),
+                    None,
+                    false,
+                    Some(transaction),
+                    platform_version,
+                )?
+                .1
+            else {
+                // Backfills are deleted with their contract, an orphaned one can only be
+                // left over and must not halt the chain
+                tracing::warn!(
+                    contract_id = %index_backfill.contract_id,
+                    document_type_name = index_backfill.document_type_name.as_str(),
+                    "index backfill of a missing contract is dropped"
+                );
+
+                let mut batch_operations = vec![];
+
+                self.drive.delete_index_backfill_operations(
+                    index_backfill.contract_id.as_bytes(),
+                    index_backfill.document_type_name.as_str(),
+                    &mut None,
+                    Some(transaction),
+                    &mut batch_operations,
+                    platform_version,
+                )?;
+
+                self.drive.apply_batch_low_level_drive_operations(
+                    None,
+                    Some(transaction),
+                    batch_operations,
+                    &mut vec![],
+                    &platform_version.drive,
+                )?;
+
+                continue;
+            };
+
+            let contract = &contract_fetch_info.contract;
+            let owner_id = contract.owner_id();
+
+            let estimated_outcome = self.drive.backfill_document_type_indices(
+                contract,
+                &index_backfill,
+                remaining_documents,
+                block_info,
+                false,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            let owner_balance = self
+                .drive
+                .fetch_identity_balance(owner_id.to_buffer(), Some(transaction), platform_version)?
+                .unwrap_or_default();
+
+            if owner_balance < estimated_outcome.fee_result.total_base_fee() {
+                tracing::debug!(
+                    contract_id = %index_backfill.contract_id,
+                    document_type_name = index_backfill.document_type_name.as_str(),
+                    owner_balance,
+                    "index backfill is paused as the contract owner can't pay for it"
+                );
+                continue;
+            }
+
+            let outcome = self.drive.backfill_document_type_indices(
+                contract,
+                &index_backfill,
+                remaining_documents,
+                block_info,
+                true,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            for conflict in &outcome.conflicts {
+                tracing::warn!(
+                    contract_id = %index_backfill.contract_id,
+                    document_type_name = index_backfill.document_type_name.as_str(),
+                    index_name = conflict.index_name.as_str(),
+                    document_id = %conflict.document_id,
+                    "document is left out of a backfilled unique index as its values are already taken"
+                );
+            }
+
+            if outcome.failed {
+                tracing::warn!(
+                    contract_id = %index_backfill.contract_id,
+                    document_type_name = index_backfill.document_type_name.as_str(),
+                    "index backfill failed as too many documents conflict with a new unique index"
+                );
+            }
+
+            if outcome.rolled_back {
+                tracing::info!(
+                    contract_id = %index_backfill.contract_id,
+                    document_type_name = index_backfill.document_type_name.as_str(),
+                    index_names = ?index_backfill.index_names,
+                    "entries of the failed index backfill are removed"
+                );
+            }
+
+            if outcome.completed {
+                tracing::info!(
+                    contract_id = %index_backfill.contract_id,
+                    document_type_name = index_backfill.document_type_name.as_str(),
+                    "index backfill is completed"
+                );
+            }
+
+            remaining_documents = remaining_documents.saturating_sub(outcome.documents_processed);
+
+            let balance_change = outcome.fee_result.into_balance_change(owner_id);
+
+            let balance_change_outcome = self.drive.apply_balance_change_from_fee_to_identity(
+                balance_change,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            aggregate_fee_result
+                .checked_add_assign(balance_change_outcome.actual_fee_paid_owned())?;
+        }
+
+        Ok(aggregate_fee_result)
+    }
+}
@@ -6,6 +6,8 @@ pub(in crate::execution) mod block_fee_processing;
 pub(in crate::execution) mod core_based_updates;
 /// Core subsidy
 pub(in crate::execution) mod core_subsidy;
+/// Expiry of documents
+pub(in crate::execution) mod document_expiry;
 /// Epoch based methods
 pub(in crate::execution) mod epoch;
 /// Fee pools module
@@ -14,6 +16,8 @@ pub(in crate::execution) mod fee_pool_inwards_distribution;
 pub(in crate::execution) mod fee_pool_outwards_distribution;
 /// Functions related to IdentityCreditWithdrawalTransaction
 pub(in crate::execution) mod identity_credit_withdrawal;
+/// Backfill of document indices added by contract updates
+pub(in crate::execution) mod index_backfill;
 /// Initialization
 pub(in crate::execution) mod initialization;
 /// Protocol upgrade events
@@ -0,0 +1,133 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::prelude::Identifier;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::DriveOperation;
+use drive::grovedb::Transaction;
+use std::collections::BTreeSet;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Collects the change feed events described by the drive operations of a state transition.
+    ///
+    /// Balance changes can't be read from the operations, so instead of events the ids of
+    /// identities whose balance is touched are returned, and their balances should be
+    /// fetched once the operations are applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `operations` - The drive operations of a valid state transition.
+    /// * `transaction` - The transaction of the block being processed.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(Vec<ChangeFeedEvent>, BTreeSet<Identifier>), Error>` - The events and the
+    ///   identities with changed balances.
+    pub(in crate::execution) fn collect_change_feed_events(
+        &self,
+        operations: &[DriveOperation],
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<ChangeFeedEvent>, BTreeSet<Identifier>), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .state_transition_processing
+            .collect_change_feed_events
+        {
+            0 => self.collect_change_feed_events_v0(operations, transaction, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "collect_change_feed_events".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::platform_types::change_feed::event::{ChangeFeedEvent, DocumentChange};
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+    use dpp::document::DocumentV0Getters;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use dpp::version::PlatformVersion;
+    use drive::drive::batch::{DocumentOperationType, DriveOperation};
+    use drive::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use drive::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+
+    #[test]
+    fn should_collect_events_of_document_operations_for_contract() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let data_contract = get_data_contract_fixture(None, platform_version.protocol_version)
+            .data_contract_owned();
+        let document_type = data_contract
+            .document_type_for_name("niceDocument")
+            .expect("expected a document type");
+        let document = document_type
+            .random_document(Some(1), platform_version)
+            .expect("expected a random document");
+
+        let operations = vec![
+            DriveOperation::DocumentOperation(DocumentOperationType::AddDocumentForContract {
+                document_and_contract_info: DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&document, None)),
+                        owner_id: None,
+                    },
+                    contract: &data_contract,
+                    document_type,
+                },
+                override_document: false,
+            }),
+            DriveOperation::DocumentOperation(DocumentOperationType::DeleteDocumentForContract {
+                document_id: document.id().to_buffer(),
+                contract: &data_contract,
+                document_type,
+            }),
+        ];
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let (events, changed_balances) = platform
+            .collect_change_feed_events(&operations, &transaction, platform_version)
+            .expect("expected to collect events");
+
+        assert!(changed_balances.is_empty());
+        assert_eq!(
+            events,
+            vec![
+                ChangeFeedEvent::DocumentCreated(DocumentChange {
+                    data_contract_id: data_contract.id(),
+                    document_type_name: "niceDocument".to_string(),
+                    document_id: document.id(),
+                    owner_id: document.owner_id(),
+                    serialized_document: document
+                        .serialize(document_type, platform_version)
+                        .expect("expected to serialize document"),
+                }),
+                ChangeFeedEvent::DocumentDeleted {
+                    data_contract_id: data_contract.id(),
+                    document_type_name: "niceDocument".to_string(),
+                    document_id: document.id(),
+                },
+            ]
+        );
+    }
+}
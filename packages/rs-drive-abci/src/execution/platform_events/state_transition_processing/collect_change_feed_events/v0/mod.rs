@@ -0,0 +1,351 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::change_feed::event::{ChangeFeedEvent, DocumentChange};
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::drive_op_batch::{DocumentOperation, UpdateOperationInfo};
+use drive::drive::batch::{DocumentOperationType, DriveOperation, IdentityOperationType};
+use drive::drive::contract::DataContractFetchInfo;
+use drive::drive::object_size_info::{DocumentInfoV0Methods, OwnedDocumentInfo};
+use drive::grovedb::Transaction;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn collect_change_feed_events_v0(
+        &self,
+        operations: &[DriveOperation],
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<ChangeFeedEvent>, BTreeSet<Identifier>), Error> {
+        let mut events = vec![];
+        let mut identities_with_changed_balance = BTreeSet::new();
+
+        for operation in operations {
+            match operation {
+                DriveOperation::DocumentOperation(DocumentOperationType::AddDocument {
+                    owned_document_info,
+                    contract_id,
+                    document_type_name,
+                    ..
+                }) => {
+                    let fetch_info =
+                        self.fetch_contract(*contract_id, transaction, platform_version)?;
+                    if let Some(change) = self.document_change(
+                        &fetch_info.contract,
+                        document_type_name,
+                        owned_document_info,
+                        platform_version,
+                    )? {
+                        events.push(ChangeFeedEvent::DocumentCreated(change));
+                    }
+                }
+                DriveOperation::DocumentOperation(DocumentOperationType::UpdateDocument {
+                    owned_document_info,
+                    contract_id,
+                    document_type_name,
+                }) => {
+                    let fetch_info =
+                        self.fetch_contract(*contract_id, transaction, platform_version)?;
+                    if let Some(change) = self.document_change(
+                        &fetch_info.contract,
+                        document_type_name,
+                        owned_document_info,
+                        platform_version,
+                    )? {
+                        events.push(ChangeFeedEvent::DocumentReplaced(change));
+                    }
+                }
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::AddWithdrawalDocument {
+                        owned_document_info,
+                    },
+                ) => {
+                    if let Some(change) = self.document_change(
+                        &self.drive.system_contracts.withdrawal_contract,
+                        withdrawal::NAME,
+                        owned_document_info,
+                        platform_version,
+                    )? {
+                        events.push(ChangeFeedEvent::DocumentCreated(change));
+                    }
+                }
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::AddDocumentForContract {
+                        document_and_contract_info,
+                        ..
+                    },
+                ) => {
+                    if let Some(change) = self.document_change(
+                        document_and_contract_info.contract,
+                        document_and_contract_info.document_type.name(),
+                        &document_and_contract_info.owned_document_info,
+                        platform_version,
+                    )? {
+                        events.push(ChangeFeedEvent::DocumentCreated(change));
+                    }
+                }
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::AddSerializedDocumentForContract {
+                        serialized_document,
+                        contract,
+                        document_type_name,
+                        owner_id,
+                        ..
+                    },
+                ) => events.push(ChangeFeedEvent::DocumentCreated(
+                    self.serialized_document_change(
+                        contract,
+                        document_type_name,
+                        serialized_document,
+                        *owner_id,
+                        platform_version,
+                    )?,
+                )),
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::UpdateDocumentForContract {
+                        document,
+                        serialized_document,
+                        contract,
+                        document_type_name,
+                        owner_id,
+                        ..
+                    },
+                ) => events.push(ChangeFeedEvent::DocumentReplaced(DocumentChange {
+                    data_contract_id: contract.id(),
+                    document_type_name: document_type_name.to_string(),
+                    document_id: document.id(),
+                    owner_id: owner_id.map(Identifier::new).unwrap_or(document.owner_id()),
+                    serialized_document: serialized_document.to_vec(),
+                })),
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::UpdateSerializedDocumentForContract {
+                        serialized_document,
+                        contract,
+                        document_type_name,
+                        owner_id,
+                        ..
+                    },
+                ) => events.push(ChangeFeedEvent::DocumentReplaced(
+                    self.serialized_document_change(
+                        contract,
+                        document_type_name,
+                        serialized_document,
+                        *owner_id,
+                        platform_version,
+                    )?,
+                )),
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::MultipleDocumentOperationsForSameContractDocumentType {
+                        document_operations,
+                    },
+                ) => {
+                    let contract = document_operations.contract;
+                    let document_type_name = document_operations.document_type.name();
+
+                    for document_operation in &document_operations.operations {
+                        match document_operation {
+                            DocumentOperation::AddOperation {
+                                owned_document_info,
+                                ..
+                            } => {
+                                if let Some(change) = self.document_change(
+                                    contract,
+                                    document_type_name,
+                                    owned_document_info,
+                                    platform_version,
+                                )? {
+                                    events.push(ChangeFeedEvent::DocumentCreated(change));
+                                }
+                            }
+                            DocumentOperation::UpdateOperation(UpdateOperationInfo {
+                                document,
+                                owner_id,
+                                ..
+                            }) => {
+                                events.push(ChangeFeedEvent::DocumentReplaced(DocumentChange {
+                                    data_contract_id: contract.id(),
+                                    document_type_name: document_type_name.to_string(),
+                                    document_id: document.id(),
+                                    owner_id: owner_id
+                                        .map(Identifier::new)
+                                        .unwrap_or(document.owner_id()),
+                                    serialized_document: document.serialize(
+                                        document_operations.document_type,
+                                        platform_version,
+                                    )?,
+                                }));
+                            }
+                        }
+                    }
+                }
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::DeleteDocumentOfNamedTypeForContractId {
+                        document_id,
+                        contract_id,
+                        document_type_name,
+                    },
+                ) => events.push(ChangeFeedEvent::DocumentDeleted {
+                    data_contract_id: Identifier::new(*contract_id),
+                    document_type_name: document_type_name.to_string(),
+                    document_id: Identifier::new(*document_id),
+                }),
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::DeleteDocumentOfNamedTypeForContract {
+                        document_id,
+                        contract,
+                        document_type_name,
+                    },
+                ) => events.push(ChangeFeedEvent::DocumentDeleted {
+                    data_contract_id: contract.id(),
+                    document_type_name: document_type_name.to_string(),
+                    document_id: Identifier::new(*document_id),
+                }),
+                DriveOperation::DocumentOperation(
+                    DocumentOperationType::DeleteDocumentForContract {
+                        document_id,
+                        contract,
+                        document_type,
+                    },
+                ) => events.push(ChangeFeedEvent::DocumentDeleted {
+                    data_contract_id: contract.id(),
+                    document_type_name: document_type.name().to_string(),
+                    document_id: Identifier::new(*document_id),
+                }),
+                DriveOperation::IdentityOperation(IdentityOperationType::AddNewIdentity {
+                    identity,
+                    ..
+                }) => {
+                    for (key_id, public_key) in identity.public_keys() {
+                        events.push(ChangeFeedEvent::IdentityKeyAdded {
+                            identity_id: identity.id(),
+                            key_id: *key_id,
+                            serialized_public_key: public_key.serialize_to_bytes()?,
+                        });
+                    }
+                    identities_with_changed_balance.insert(identity.id());
+                }
+                DriveOperation::IdentityOperation(
+                    IdentityOperationType::AddToIdentityBalance { identity_id, .. }
+                    | IdentityOperationType::RemoveFromIdentityBalance { identity_id, .. },
+                ) => {
+                    identities_with_changed_balance.insert(Identifier::new(*identity_id));
+                }
+                DriveOperation::IdentityOperation(
+                    IdentityOperationType::AddNewKeysToIdentity {
+                        identity_id,
+                        unique_keys_to_add,
+                        non_unique_keys_to_add,
+                    },
+                ) => {
+                    for public_key in unique_keys_to_add.iter().chain(non_unique_keys_to_add) {
+                        events.push(ChangeFeedEvent::IdentityKeyAdded {
+                            identity_id: Identifier::new(*identity_id),
+                            key_id: public_key.id(),
+                            serialized_public_key: public_key.serialize_to_bytes()?,
+                        });
+                    }
+                }
+                DriveOperation::IdentityOperation(IdentityOperationType::DisableIdentityKeys {
+                    identity_id,
+                    keys_ids,
+                    disable_at,
+                }) => {
+                    for key_id in keys_ids {
+                        events.push(ChangeFeedEvent::IdentityKeyDisabled {
+                            identity_id: Identifier::new(*identity_id),
+                            key_id: *key_id,
+                            disabled_at: *disable_at,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok((events, identities_with_changed_balance))
+    }
+
+    fn fetch_contract(
+        &self,
+        contract_id: Identifier,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Arc<DataContractFetchInfo>, Error> {
+        self.drive
+            .get_contract_with_fetch_info(
+                contract_id.to_buffer(),
+                false,
+                Some(transaction),
+                platform_version,
+            )?
+            .ok_or_else(|| {
+                Error::Execution(ExecutionError::DriveMissingData(format!(
+                    "data contract {} of a changed document not found",
+                    contract_id
+                )))
+            })
+    }
+
+    fn document_change(
+        &self,
+        contract: &DataContract,
+        document_type_name: &str,
+        owned_document_info: &OwnedDocumentInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<DocumentChange>, Error> {
+        let Some(document) = owned_document_info.document_info.get_borrowed_document() else {
+            return Ok(None);
+        };
+
+        let document_type = contract.document_type_for_name(document_type_name)?;
+
+        let owner_id = owned_document_info
+            .owner_id
+            .map(Identifier::new)
+            .unwrap_or(document.owner_id());
+
+        Ok(Some(DocumentChange {
+            data_contract_id: contract.id(),
+            document_type_name: document_type_name.to_string(),
+            document_id: document.id(),
+            owner_id,
+            serialized_document: document.serialize(document_type, platform_version)?,
+        }))
+    }
+
+    fn serialized_document_change(
+        &self,
+        contract: &DataContract,
+        document_type_name: &str,
+        serialized_document: &[u8],
+        owner_id: Option<[u8; 32]>,
+        platform_version: &PlatformVersion,
+    ) -> Result<DocumentChange, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+
+        let document = Document::from_bytes(serialized_document, document_type, platform_version)?;
+
+        Ok(DocumentChange {
+            data_contract_id: contract.id(),
+            document_type_name: document_type_name.to_string(),
+            document_id: document.id(),
+            owner_id: owner_id.map(Identifier::new).unwrap_or(document.owner_id()),
+            serialized_document: serialized_document.to_vec(),
+        })
+    }
+}
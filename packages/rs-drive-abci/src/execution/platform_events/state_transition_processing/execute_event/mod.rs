@@ -3,6 +3,7 @@ mod v0;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::execution_event::ExecutionEvent;
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult;
 use crate::rpc::core::CoreRPCLike;
@@ -21,6 +22,7 @@ where
     /// * `event` - The execution event to be processed.
     /// * `block_info` - Information about the current block being processed.
     /// * `transaction` - The transaction associated with the execution event.
+    /// * `change_feed_events` - If given, the changes made by a successful execution are appended to it.
     /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
     ///   the method to call.
     ///
@@ -39,6 +41,7 @@ where
         event: ExecutionEvent,
         block_info: &BlockInfo,
         transaction: &Transaction,
+        change_feed_events: Option<&mut Vec<ChangeFeedEvent>>,
         platform_version: &PlatformVersion,
     ) -> Result<StateTransitionExecutionResult, Error> {
         match platform_version
@@ -47,7 +50,13 @@ where
             .state_transition_processing
             .execute_event
         {
-            0 => self.execute_event_v0(event, block_info, transaction, platform_version),
+            0 => self.execute_event_v0(
+                event,
+                block_info,
+                transaction,
+                change_feed_events,
+                platform_version,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "execute_event".to_string(),
                 known_versions: vec![0],
@@ -1,5 +1,6 @@
 use crate::error::Error;
 use crate::execution::types::execution_event::ExecutionEvent;
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult;
 use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult::{
@@ -7,10 +8,13 @@ use crate::platform_types::state_transition_execution_result::StateTransitionExe
 };
 use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
+use dpp::prelude::Identifier;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::version::PlatformVersion;
+use drive::drive::batch::DriveOperation;
 use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
 use drive::grovedb::Transaction;
+use std::collections::BTreeSet;
 
 impl<C> Platform<C>
 where
@@ -27,6 +31,7 @@ where
     /// * `event` - The execution event to be processed.
     /// * `block_info` - Information about the current block being processed.
     /// * `transaction` - The transaction associated with the execution event.
+    /// * `change_feed_events` - If given, the changes made by a successful execution are appended to it.
     ///
     /// # Returns
     ///
@@ -43,6 +48,7 @@ where
         event: ExecutionEvent,
         block_info: &BlockInfo,
         transaction: &Transaction,
+        change_feed_events: Option<&mut Vec<ChangeFeedEvent>>,
         platform_version: &PlatformVersion,
     ) -> Result<StateTransitionExecutionResult, Error> {
         //todo: we need to split out errors
@@ -54,15 +60,25 @@ where
             ExecutionEvent::PaidFromAssetLockDriveEvent {
                 identity,
                 operations,
+                user_fee_increase,
                 ..
             }
             | ExecutionEvent::PaidDriveEvent {
                 identity,
                 operations,
+                user_fee_increase,
             } => {
                 if validation_result.is_valid_with_data() {
+                    let collected_changes = change_feed_events.is_some().then(|| {
+                        self.try_collect_change_feed_events(
+                            &operations,
+                            transaction,
+                            platform_version,
+                        )
+                    });
+
                     //todo: make this into an atomic event with partial batches
-                    let individual_fee_result = self
+                    let mut individual_fee_result = self
                         .drive
                         .apply_drive_operations(
                             operations,
@@ -73,6 +89,9 @@ where
                         )
                         .map_err(Error::Drive)?;
 
+                    let user_fee_increase_credits =
+                        individual_fee_result.apply_user_fee_increase(user_fee_increase);
+
                     let balance_change = individual_fee_result.into_balance_change(identity.id);
 
                     let outcome = self.drive.apply_balance_change_from_fee_to_identity(
@@ -81,9 +100,29 @@ where
                         platform_version,
                     )?;
 
+                    if let (Some(change_feed_events), Some(Some((events, mut changed_balances)))) =
+                        (change_feed_events, collected_changes)
+                    {
+                        changed_balances.insert(identity.id);
+                        self.push_change_feed_events(
+                            change_feed_events,
+                            events,
+                            changed_balances,
+                            transaction,
+                            platform_version,
+                        );
+                    }
+
+                    let actual_fee_paid = outcome.actual_fee_paid_owned();
+
+                    // The identity might not have been able to pay the whole processing fee
+                    let proposer_tip =
+                        user_fee_increase_credits.min(actual_fee_paid.processing_fee);
+
                     Ok(SuccessfulPaidExecution(
                         validation_result.into_data()?,
-                        outcome.actual_fee_paid_owned(),
+                        actual_fee_paid,
+                        proposer_tip,
                     ))
                 } else {
                     Ok(ConsensusExecutionError(
@@ -92,6 +131,10 @@ where
                 }
             }
             ExecutionEvent::FreeDriveEvent { operations } => {
+                let collected_changes = change_feed_events.is_some().then(|| {
+                    self.try_collect_change_feed_events(&operations, transaction, platform_version)
+                });
+
                 self.drive
                     .apply_drive_operations(
                         operations,
@@ -101,8 +144,74 @@ where
                         platform_version,
                     )
                     .map_err(Error::Drive)?;
+
+                if let (Some(change_feed_events), Some(Some((events, changed_balances)))) =
+                    (change_feed_events, collected_changes)
+                {
+                    self.push_change_feed_events(
+                        change_feed_events,
+                        events,
+                        changed_balances,
+                        transaction,
+                        platform_version,
+                    );
+                }
+
                 Ok(SuccessfulFreeExecution)
             }
         }
     }
+
+    /// Collects the change feed events of the operations.
+    ///
+    /// Events are a convenience for subscribers, so failing to collect them is logged
+    /// instead of failing the execution.
+    fn try_collect_change_feed_events(
+        &self,
+        operations: &[DriveOperation],
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Option<(Vec<ChangeFeedEvent>, BTreeSet<Identifier>)> {
+        self.collect_change_feed_events(operations, transaction, platform_version)
+            .map_err(|error| tracing::error!(?error, "can't collect change feed events"))
+            .ok()
+    }
+
+    /// Appends the collected events, followed by the balances of the touched identities
+    /// as they are after the execution.
+    ///
+    /// Balances that can't be fetched are logged and skipped.
+    fn push_change_feed_events(
+        &self,
+        change_feed_events: &mut Vec<ChangeFeedEvent>,
+        events: Vec<ChangeFeedEvent>,
+        changed_balances: BTreeSet<Identifier>,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) {
+        change_feed_events.extend(events);
+
+        for identity_id in changed_balances {
+            match self.drive.fetch_identity_balance(
+                identity_id.to_buffer(),
+                Some(transaction),
+                platform_version,
+            ) {
+                Ok(Some(balance)) => {
+                    change_feed_events.push(ChangeFeedEvent::IdentityBalanceChanged {
+                        identity_id,
+                        balance,
+                    });
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    tracing::error!(
+                        ?error,
+                        %identity_id,
+                        "can't fetch changed balance for change feed"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -1,3 +1,4 @@
+mod collect_change_feed_events;
 mod execute_event;
 mod process_raw_state_transitions;
 mod validate_fees_of_event;
@@ -2,6 +2,7 @@ mod v0;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult;
@@ -28,9 +29,9 @@ where
     ///
     /// # Returns
     ///
-    /// * `Result<(FeeResult, Vec<ExecTxResult>), Error>` - If the processing is successful, it returns
-    ///   a tuple consisting of a `FeeResult` and a vector of `ExecTxResult`. If the processing fails,
-    ///   it returns an `Error`.
+    /// * `Result<(FeeResult, Vec<ExecTxResult>, Vec<ChangeFeedEvent>), Error>` - If the processing is
+    ///   successful, it returns a tuple consisting of a `FeeResult`, a vector of `ExecTxResult` and the
+    ///   changes made by the successful state transitions. If the processing fails, it returns an `Error`.
     ///
     /// # Errors
     ///
@@ -43,7 +44,14 @@ where
         block_info: &BlockInfo,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
-    ) -> Result<(FeeResult, Vec<(Vec<u8>, StateTransitionExecutionResult)>), Error> {
+    ) -> Result<
+        (
+            FeeResult,
+            Vec<(Vec<u8>, StateTransitionExecutionResult)>,
+            Vec<ChangeFeedEvent>,
+        ),
+        Error,
+    > {
         match platform_version
             .drive_abci
             .methods
@@ -1,5 +1,6 @@
 use crate::error::Error;
 use crate::execution::validation::state_transition::processor::process_state_transition;
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
 use crate::platform_types::platform::{Platform, PlatformRef};
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult::{
@@ -35,9 +36,9 @@ where
     ///
     /// # Returns
     ///
-    /// * `Result<(FeeResult, Vec<ExecTxResult>), Error>` - If the processing is successful, it returns
-    ///   a tuple consisting of a `FeeResult` and a vector of `ExecTxResult`. If the processing fails,
-    ///   it returns an `Error`.
+    /// * `Result<(FeeResult, Vec<ExecTxResult>, Vec<ChangeFeedEvent>), Error>` - If the processing is
+    ///   successful, it returns a tuple consisting of a `FeeResult`, a vector of `ExecTxResult` and the
+    ///   changes made by the successful state transitions. If the processing fails, it returns an `Error`.
     ///
     /// # Errors
     ///
@@ -51,9 +52,19 @@ where
         block_info: &BlockInfo,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
-    ) -> Result<(FeeResult, Vec<(Vec<u8>, StateTransitionExecutionResult)>), Error> {
+    ) -> Result<
+        (
+            FeeResult,
+            Vec<(Vec<u8>, StateTransitionExecutionResult)>,
+            Vec<ChangeFeedEvent>,
+        ),
+        Error,
+    > {
         let state_transitions = StateTransition::deserialize_many(raw_state_transitions)?;
         let mut aggregate_fee_result = FeeResult::default();
+        let mut change_feed_events = vec![];
+        // Collecting events costs extra reads, so it's skipped when nobody listens
+        let collect_change_feed_events = self.change_feed.has_subscribers();
         let platform_ref = PlatformRef {
             drive: &self.drive,
             state: block_platform_state,
@@ -86,6 +97,7 @@ where
                         execution_event,
                         block_info,
                         transaction,
+                        collect_change_feed_events.then_some(&mut change_feed_events),
                         platform_version,
                     )?;
 
@@ -120,7 +132,7 @@ where
                         state_transition_execution_event.errors,
                     ))
                 };
-                if let SuccessfulPaidExecution(_, fee_result) = &execution_result {
+                if let SuccessfulPaidExecution(_, fee_result, _) = &execution_result {
                     aggregate_fee_result.checked_add_assign(fee_result.clone())?;
                 }
 
@@ -128,6 +140,6 @@ where
             })
             .collect::<Result<Vec<(Vec<u8>, StateTransitionExecutionResult)>, Error>>()?;
 
-        Ok((aggregate_fee_result, exec_tx_results))
+        Ok((aggregate_fee_result, exec_tx_results, change_feed_events))
     }
 }
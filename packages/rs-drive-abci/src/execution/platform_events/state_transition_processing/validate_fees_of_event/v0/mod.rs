@@ -44,12 +44,13 @@ where
                 identity,
                 added_balance,
                 operations,
+                user_fee_increase,
             } => {
                 let previous_balance = identity.balance.ok_or(Error::Execution(
                     ExecutionError::CorruptedCodeExecution("partial identity info with no balance"),
                 ))?;
                 let previous_balance_with_top_up = previous_balance + added_balance;
-                let estimated_fee_result = self
+                let mut estimated_fee_result = self
                     .drive
                     .apply_drive_operations(
                         operations.clone(),
@@ -60,6 +61,8 @@ where
                     )
                     .map_err(Error::Drive)?;
 
+                estimated_fee_result.apply_user_fee_increase(*user_fee_increase);
+
                 // TODO: Should take into account refunds as well
                 let total_fee = estimated_fee_result.total_base_fee();
                 if previous_balance_with_top_up >= total_fee {
@@ -83,11 +86,12 @@ where
             ExecutionEvent::PaidDriveEvent {
                 identity,
                 operations,
+                user_fee_increase,
             } => {
                 let balance = identity.balance.ok_or(Error::Execution(
                     ExecutionError::CorruptedCodeExecution("partial identity info with no balance"),
                 ))?;
-                let estimated_fee_result = self
+                let mut estimated_fee_result = self
                     .drive
                     .apply_drive_operations(
                         operations.clone(),
@@ -98,6 +102,8 @@ where
                     )
                     .map_err(Error::Drive)?;
 
+                estimated_fee_result.apply_user_fee_increase(*user_fee_increase);
+
                 // TODO: Should take into account refunds as well
                 let required_balance = estimated_fee_result.total_base_fee();
                 if balance >= required_balance {
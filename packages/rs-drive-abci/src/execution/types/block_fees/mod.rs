@@ -45,6 +45,12 @@ impl BlockFeesV0Getters for BlockFees {
             BlockFees::V0(v0) => v0.refunds_per_epoch_mut(),
         }
     }
+
+    fn proposer_tips(&self) -> u64 {
+        match self {
+            BlockFees::V0(v0) => v0.proposer_tips(),
+        }
+    }
 }
 
 impl BlockFeesV0Setters for BlockFees {
@@ -65,6 +71,12 @@ impl BlockFeesV0Setters for BlockFees {
             BlockFees::V0(v0) => v0.set_refunds_per_epoch(refunds),
         }
     }
+
+    fn set_proposer_tips(&mut self, tips: u64) {
+        match self {
+            BlockFees::V0(v0) => v0.set_proposer_tips(tips),
+        }
+    }
 }
 
 impl BlockFeesV0Methods for BlockFees {
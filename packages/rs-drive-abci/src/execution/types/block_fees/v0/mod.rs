@@ -12,6 +12,9 @@ pub struct BlockFeesV0 {
     pub storage_fee: u64,
     /// Fee refunds per epoch
     pub refunds_per_epoch: CreditsPerEpoch,
+    /// User fee increases paid to the block proposer, not part of the processing fee
+    #[serde(default)]
+    pub proposer_tips: u64,
 }
 
 pub trait BlockFeesV0Methods {
@@ -45,6 +48,9 @@ pub trait BlockFeesV0Getters {
 
     /// Returns the fee refunds per epoch.
     fn refunds_per_epoch_mut(&mut self) -> &mut CreditsPerEpoch;
+
+    /// Returns the user fee increases paid to the block proposer.
+    fn proposer_tips(&self) -> u64;
 }
 
 /// `BlockFeesV0Setters` trait provides setter methods for `BlockFeesV0`.
@@ -57,6 +63,9 @@ pub trait BlockFeesV0Setters {
 
     /// Sets the fee refunds per epoch.
     fn set_refunds_per_epoch(&mut self, refunds: CreditsPerEpoch);
+
+    /// Sets the user fee increases paid to the block proposer.
+    fn set_proposer_tips(&mut self, tips: u64);
 }
 
 impl BlockFeesV0Getters for BlockFeesV0 {
@@ -79,6 +88,10 @@ impl BlockFeesV0Getters for BlockFeesV0 {
     fn refunds_per_epoch_mut(&mut self) -> &mut CreditsPerEpoch {
         &mut self.refunds_per_epoch
     }
+
+    fn proposer_tips(&self) -> u64 {
+        self.proposer_tips
+    }
 }
 
 impl BlockFeesV0Setters for BlockFeesV0 {
@@ -93,6 +106,10 @@ impl BlockFeesV0Setters for BlockFeesV0 {
     fn set_refunds_per_epoch(&mut self, refunds: CreditsPerEpoch) {
         self.refunds_per_epoch = refunds;
     }
+
+    fn set_proposer_tips(&mut self, tips: u64) {
+        self.proposer_tips = tips;
+    }
 }
 
 impl From<FeeResult> for BlockFeesV0 {
@@ -101,6 +118,7 @@ impl From<FeeResult> for BlockFeesV0 {
             storage_fee: value.storage_fee,
             processing_fee: value.processing_fee,
             refunds_per_epoch: value.fee_refunds.sum_per_epoch(),
+            proposer_tips: 0,
         }
     }
 }
@@ -9,6 +9,7 @@ use dpp::block::epoch::Epoch;
 use dpp::fee::Credits;
 
 use dpp::identity::PartialIdentity;
+use dpp::prelude::UserFeeIncrease;
 
 use dpp::version::PlatformVersion;
 use drive::state_transition_action::StateTransitionAction;
@@ -25,6 +26,8 @@ pub(in crate::execution) enum ExecutionEvent<'a> {
         identity: PartialIdentity,
         /// the operations that the identity is requesting to perform
         operations: Vec<DriveOperation<'a>>,
+        /// the percentage the processing fee is increased by
+        user_fee_increase: UserFeeIncrease,
     },
     /// A drive event that is paid from an asset lock
     PaidFromAssetLockDriveEvent {
@@ -34,6 +37,8 @@ pub(in crate::execution) enum ExecutionEvent<'a> {
         added_balance: Credits,
         /// the operations that should be performed
         operations: Vec<DriveOperation<'a>>,
+        /// the percentage the processing fee is increased by
+        user_fee_increase: UserFeeIncrease,
     },
     /// A drive event that is free
     FreeDriveEvent {
@@ -51,6 +56,7 @@ impl<'a> ExecutionEvent<'a> {
         Self::PaidDriveEvent {
             identity,
             operations: vec![operation],
+            user_fee_increase: 0,
         }
     }
     /// Creates a new identity Insertion Event
@@ -61,6 +67,7 @@ impl<'a> ExecutionEvent<'a> {
         Self::PaidDriveEvent {
             identity,
             operations: vec![operation],
+            user_fee_increase: 0,
         }
     }
     /// Creates a new identity Insertion Event
@@ -71,6 +78,7 @@ impl<'a> ExecutionEvent<'a> {
         Self::PaidDriveEvent {
             identity,
             operations,
+            user_fee_increase: 0,
         }
     }
 }
@@ -79,6 +87,7 @@ impl<'a> ExecutionEvent<'a> {
     pub(crate) fn create_from_state_transition_action(
         action: StateTransitionAction,
         identity: Option<PartialIdentity>,
+        user_fee_increase: UserFeeIncrease,
         epoch: &Epoch,
         platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
@@ -91,6 +100,7 @@ impl<'a> ExecutionEvent<'a> {
                     identity,
                     added_balance: 0,
                     operations,
+                    user_fee_increase,
                 })
             }
             StateTransitionAction::IdentityTopUpAction(identity_top_up_action) => {
@@ -102,6 +112,7 @@ impl<'a> ExecutionEvent<'a> {
                         identity,
                         added_balance,
                         operations,
+                        user_fee_increase,
                     })
                 } else {
                     Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
@@ -116,6 +127,7 @@ impl<'a> ExecutionEvent<'a> {
                     Ok(PaidDriveEvent {
                         identity,
                         operations,
+                        user_fee_increase,
                     })
                 } else {
                     Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
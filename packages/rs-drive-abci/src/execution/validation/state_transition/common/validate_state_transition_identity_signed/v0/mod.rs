@@ -166,9 +166,11 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
         let operation = SignatureVerificationOperation::new(public_key.key_type());
         execution_context.add_operation(ExecutionOperation::SignatureVerification(operation));
 
-        // if execution_context.is_dry_run() {
-        //     return Ok(validation_result);
-        // }
+        // Fees can be estimated in a dry run before the state transition is signed
+        if execution_context.in_dry_run() && self.signature().is_empty() {
+            validation_result.set_data(partial_identity);
+            return Ok(validation_result);
+        }
 
         let signature_is_valid = self.verify_signature(public_key, &NativeBlsModule);
 
@@ -37,6 +37,7 @@ pub(in crate::execution) fn process_state_transition<'a, C: CoreRPCLike>(
         0 => v0::process_state_transition_v0(
             platform,
             state_transition,
+            false,
             transaction,
             platform_version,
         ),
@@ -47,3 +48,33 @@ pub(in crate::execution) fn process_state_transition<'a, C: CoreRPCLike>(
         })),
     }
 }
+
+/// Processes a state transition the same way as [process_state_transition], but in a dry run.
+///
+/// A dry run is used to estimate the fees of a state transition, which is often done before
+/// it is signed, so the signature of an unsigned state transition is not verified.
+pub(in crate::execution) fn process_state_transition_in_dry_run<'a, C: CoreRPCLike>(
+    platform: &'a PlatformRef<C>,
+    state_transition: StateTransition,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
+    let platform_version = platform.state.current_platform_version()?;
+    match platform_version
+        .drive_abci
+        .validation_and_processing
+        .process_state_transition
+    {
+        0 => v0::process_state_transition_v0(
+            platform,
+            state_transition,
+            true,
+            transaction,
+            platform_version,
+        ),
+        version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+            method: "process_state_transition_in_dry_run".to_string(),
+            known_versions: vec![0],
+            received: version,
+        })),
+    }
+}
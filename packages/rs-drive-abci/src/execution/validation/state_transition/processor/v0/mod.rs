@@ -4,6 +4,8 @@ use crate::execution::validation::state_transition::transformer::StateTransition
 use crate::platform_types::platform::{PlatformRef, PlatformStateRef};
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::rpc::core::CoreRPCLike;
+use dpp::consensus::basic::state_transition::UserFeeIncreaseTooHighError;
+use dpp::fee::default_costs::constants::MAX_USER_FEE_INCREASE;
 use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
 
@@ -15,7 +17,7 @@ use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 use crate::error::execution::ExecutionError;
-use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::{ValidateStateTransitionIdentitySignature};
 use crate::execution::validation::state_transition::state_transitions::identity_update::identity_and_signatures::v0::IdentityUpdateStateTransitionIdentityAndSignaturesValidationV0;
 use crate::execution::validation::state_transition::state_transitions::identity_create::identity_and_signatures::v0::IdentityCreateStateTransitionIdentityAndSignaturesValidationV0;
@@ -24,12 +26,25 @@ use crate::execution::validation::state_transition::state_transitions::identity_
 pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
     state_transition: StateTransition,
+    dry_run: bool,
     transaction: TransactionArg,
     platform_version: &PlatformVersion,
 ) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
     let mut state_transition_execution_context =
         StateTransitionExecutionContext::default_for_platform_version(platform_version)?;
 
+    if dry_run {
+        state_transition_execution_context.enable_dry_run();
+    }
+
+    // The fee increase is paid on top of every fee of the transition, so it's bounded
+    let user_fee_increase = state_transition.user_fee_increase();
+    if user_fee_increase > MAX_USER_FEE_INCREASE {
+        return Ok(ConsensusValidationResult::<ExecutionEvent>::new_with_error(
+            UserFeeIncreaseTooHighError::new(user_fee_increase, MAX_USER_FEE_INCREASE).into(),
+        ));
+    }
+
     let action = if state_transition.requires_state_to_validate_structure() {
         let state_transition_action_result =
             state_transition.transform_into_action(platform, true, transaction)?;
@@ -95,6 +110,7 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
         ExecutionEvent::create_from_state_transition_action(
             action,
             maybe_identity,
+            user_fee_increase,
             platform.state.epoch_ref(),
             platform_version,
         )
@@ -206,6 +222,9 @@ impl StateTransitionStructureValidationV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.validate_structure(platform, action, protocol_version)
             }
+            StateTransition::DataContractDelete(st) => {
+                st.validate_structure(platform, action, protocol_version)
+            }
         }
     }
 }
@@ -224,7 +243,8 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
             | StateTransition::DataContractUpdate(_)
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityCreditTransfer(_)
-            | StateTransition::DocumentsBatch(_) => {
+            | StateTransition::DocumentsBatch(_)
+            | StateTransition::DataContractDelete(_) => {
                 //Basic signature verification
                 Ok(self
                     .validate_state_transition_identity_signed(
@@ -256,7 +276,9 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                                 execution_context,
                                 platform_version,
                             )?;
-                        if !validation_result.is_valid() {
+                        if !validation_result.is_valid()
+                            || (execution_context.in_dry_run() && self.signature().is_empty())
+                        {
                             Ok(validation_result.map(Some))
                         } else {
                             let partial_identity = validation_result.data_as_borrowed()?;
@@ -296,6 +318,11 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                         let mut validation_result =
                             ConsensusValidationResult::<Option<PartialIdentity>>::default();
 
+                        if execution_context.in_dry_run() && self.signature().is_empty() {
+                            validation_result.set_data(None);
+                            return Ok(validation_result);
+                        }
+
                         let signable_bytes: Vec<u8> = self.signable_bytes()?;
 
                         let result = st.validate_identity_create_state_transition_signatures_v0(
@@ -371,6 +398,7 @@ impl StateTransitionStateValidationV0 for StateTransition {
             }
             StateTransition::DocumentsBatch(st) => st.validate_state(action, platform, tx),
             StateTransition::IdentityCreditTransfer(st) => st.validate_state(action, platform, tx),
+            StateTransition::DataContractDelete(st) => st.validate_state(action, platform, tx),
         }
     }
 }
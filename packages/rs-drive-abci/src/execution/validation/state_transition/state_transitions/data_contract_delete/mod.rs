@@ -0,0 +1,332 @@
+mod state;
+mod structure;
+
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
+use dpp::version::PlatformVersion;
+use drive::state_transition_action::StateTransitionAction;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::{PlatformRef, PlatformStateRef};
+use crate::rpc::core::CoreRPCLike;
+
+use crate::execution::validation::state_transition::data_contract_delete::state::v0::DataContractDeleteStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::data_contract_delete::structure::v0::DataContractDeleteStateTransitionStructureValidationV0;
+use crate::execution::validation::state_transition::processor::v0::{
+    StateTransitionStateValidationV0, StateTransitionStructureValidationV0,
+};
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+
+impl StateTransitionActionTransformerV0 for DataContractDeleteTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        _validate: bool,
+        _tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version =
+            PlatformVersion::get(platform.state.current_protocol_version_in_consensus())?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .transform_into_action
+        {
+            0 => self.transform_into_action_v0(),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "data contract delete transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+impl StateTransitionStructureValidationV0 for DataContractDeleteTransition {
+    fn validate_structure(
+        &self,
+        _platform: &PlatformStateRef,
+        _action: Option<&StateTransitionAction>,
+        protocol_version: u32,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let platform_version = PlatformVersion::get(protocol_version)?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .structure
+        {
+            0 => self.validate_base_structure_v0(),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "data contract delete transition: validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+impl StateTransitionStateValidationV0 for DataContractDeleteTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        _action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version =
+            PlatformVersion::get(platform.state.current_protocol_version_in_consensus())?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .state
+        {
+            0 => self.validate_state_v0(platform, tx, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "data contract delete transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{ExecutionConfig, PlatformConfig, PlatformTestConfig};
+    use crate::execution::validation::state_transition::processor::v0::StateTransitionStateValidationV0;
+    use crate::platform_types::platform::PlatformRef;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::assert_state_consensus_errors;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::config::v0::DataContractConfigSettersV0;
+    use dpp::data_contract::DataContract;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::Identity;
+    use dpp::platform_value::{BinaryData, Identifier};
+    use dpp::prelude::IdentityNonce;
+    use dpp::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+    use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use dpp::version::PlatformVersion;
+    use drive::drive::batch::transitions::DriveHighLevelOperationConverter;
+
+    struct TestData<T> {
+        data_contract: DataContract,
+        platform: TempPlatform<T>,
+    }
+
+    fn setup_test(can_be_deleted: bool) -> TestData<MockCoreRPCLike> {
+        let platform_version = PlatformVersion::latest();
+
+        let config = PlatformConfig {
+            quorum_size: 10,
+            execution: ExecutionConfig {
+                verify_sum_trees: true,
+                validator_set_quorum_rotation_block_count: 25,
+                ..Default::default()
+            },
+            block_spacing_ms: 300,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            ..Default::default()
+        };
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let owner = Identity::random_identity(2, Some(3), platform_version)
+            .expect("expected a random identity");
+
+        platform
+            .drive
+            .add_new_identity(
+                owner.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        let mut data_contract =
+            get_data_contract_fixture(Some(owner.id()), platform_version.protocol_version)
+                .data_contract_owned();
+        data_contract
+            .config_mut()
+            .set_can_be_deleted(can_be_deleted);
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        TestData {
+            data_contract,
+            platform,
+        }
+    }
+
+    fn delete_transition(
+        data_contract: &DataContract,
+        owner_id: Identifier,
+        nonce: IdentityNonce,
+    ) -> DataContractDeleteTransition {
+        DataContractDeleteTransition::V0(DataContractDeleteTransitionV0 {
+            data_contract_id: data_contract.id(),
+            owner_id,
+            nonce,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: BinaryData::new(vec![0; 65]),
+        })
+    }
+
+    #[test]
+    fn should_return_error_if_owner_id_does_not_match() {
+        let TestData {
+            data_contract,
+            platform,
+        } = setup_test(true);
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &platform.state.read().unwrap(),
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+            block_info: &BlockInfo::default(),
+        };
+
+        let result = delete_transition(&data_contract, Identifier::random(), 1)
+            .validate_state(None, &platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert!(!result.is_valid());
+        assert_state_consensus_errors!(result, StateError::DataContractOwnerIdMismatchError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_contract_can_not_be_deleted() {
+        let TestData {
+            data_contract,
+            platform,
+        } = setup_test(false);
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &platform.state.read().unwrap(),
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+            block_info: &BlockInfo::default(),
+        };
+
+        let result = delete_transition(&data_contract, data_contract.owner_id(), 1)
+            .validate_state(None, &platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert!(!result.is_valid());
+        assert_state_consensus_errors!(result, StateError::DataContractIsNotDeletableError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_nonce_is_not_the_next_one() {
+        let TestData {
+            data_contract,
+            platform,
+        } = setup_test(true);
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &platform.state.read().unwrap(),
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+            block_info: &BlockInfo::default(),
+        };
+
+        let result = delete_transition(&data_contract, data_contract.owner_id(), 2)
+            .validate_state(None, &platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert!(!result.is_valid());
+        assert_state_consensus_errors!(result, StateError::InvalidIdentityNonceError, 1);
+    }
+
+    #[test]
+    fn should_delete_contract_and_update_owner_nonce() {
+        let TestData {
+            data_contract,
+            platform,
+        } = setup_test(true);
+
+        let platform_version = PlatformVersion::latest();
+        let block_info = BlockInfo::default();
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &platform.state.read().unwrap(),
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+            block_info: &block_info,
+        };
+
+        let result = delete_transition(&data_contract, data_contract.owner_id(), 1)
+            .validate_state(None, &platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert!(result.is_valid());
+
+        let operations = result
+            .into_data()
+            .expect("expected an action")
+            .into_high_level_drive_operations(&block_info.epoch, platform_version)
+            .expect("expected to get drive operations");
+
+        let fee_result = platform
+            .drive
+            .apply_drive_operations(operations, true, &block_info, None, platform_version)
+            .expect("expected to apply drive operations");
+
+        assert!(fee_result.processing_fee > 0);
+
+        let fetched_contract = platform
+            .drive
+            .get_contract_with_fetch_info(
+                data_contract.id().to_buffer(),
+                false,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch contract");
+        assert!(fetched_contract.is_none());
+
+        let owner_nonce = platform
+            .drive
+            .fetch_identity_nonce(
+                data_contract.owner_id().to_buffer(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch nonce");
+        assert_eq!(owner_nonce, Some(1));
+    }
+}
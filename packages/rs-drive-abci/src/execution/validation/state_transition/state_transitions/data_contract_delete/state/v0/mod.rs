@@ -0,0 +1,131 @@
+use crate::error::Error;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::consensus::basic::document::DataContractNotPresentError;
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::state::data_contract::data_contract_is_not_deletable_error::DataContractIsNotDeletableError;
+use dpp::consensus::state::data_contract::data_contract_is_too_large_to_delete_error::DataContractIsTooLargeToDeleteError;
+use dpp::consensus::state::data_contract::data_contract_owner_id_mismatch_error::DataContractOwnerIdMismatchError;
+use dpp::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use drive::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+
+use dpp::version::PlatformVersion;
+use drive::drive::defaults::MAX_DELETED_CONTRACT_ELEMENTS;
+use drive::error::drive::DriveError;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(in crate::execution::validation::state_transition::state_transitions::data_contract_delete) trait DataContractDeleteStateTransitionStateValidationV0 {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl DataContractDeleteStateTransitionStateValidationV0 for DataContractDeleteTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let data_contract_id = self.data_contract_id();
+
+        // Data contract should exist
+        let Some(contract_fetch_info) = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                data_contract_id.to_buffer(),
+                None,
+                false,
+                tx,
+                platform_version,
+            )?
+            .1
+        else {
+            return Ok(ConsensusValidationResult::new_with_error(
+                BasicError::DataContractNotPresentError(DataContractNotPresentError::new(
+                    data_contract_id,
+                ))
+                .into(),
+            ));
+        };
+
+        let existing_data_contract = &contract_fetch_info.contract;
+
+        // Only the owner can delete the data contract
+        if existing_data_contract.owner_id() != self.owner_id() {
+            return Ok(ConsensusValidationResult::new_with_error(
+                DataContractOwnerIdMismatchError::new(
+                    data_contract_id,
+                    self.owner_id(),
+                    existing_data_contract.owner_id(),
+                )
+                .into(),
+            ));
+        }
+
+        if !existing_data_contract.config().can_be_deleted() {
+            return Ok(ConsensusValidationResult::new_with_error(
+                DataContractIsNotDeletableError::new(data_contract_id).into(),
+            ));
+        }
+
+        // Check nonce, identities without a stored nonce start from 0
+        let current_nonce = platform
+            .drive
+            .fetch_identity_nonce(self.owner_id().to_buffer(), true, tx, platform_version)?
+            .unwrap_or_default();
+
+        if current_nonce.checked_add(1) != Some(self.nonce()) {
+            return Ok(ConsensusValidationResult::new_with_error(
+                InvalidIdentityNonceError::new(self.owner_id(), current_nonce, self.nonce()).into(),
+            ));
+        }
+
+        // The contract must be small enough to be deleted in a single block, getting
+        // the deletion operations walks the contract and fails if it's too large
+        match platform.drive.delete_contract_operations(
+            data_contract_id.to_buffer(),
+            platform.block_info,
+            &mut None,
+            tx,
+            platform_version,
+        ) {
+            Ok(_) => {}
+            Err(drive::error::Error::Drive(DriveError::DeletingTooLargeContract(_))) => {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    DataContractIsTooLargeToDeleteError::new(
+                        data_contract_id,
+                        MAX_DELETED_CONTRACT_ELEMENTS,
+                    )
+                    .into(),
+                ));
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        self.transform_into_action_v0()
+    }
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        Ok(ConsensusValidationResult::new_with_data(
+            DataContractDeleteTransitionAction::from(self).into(),
+        ))
+    }
+}
@@ -0,0 +1,15 @@
+use crate::error::Error;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::validation::SimpleConsensusValidationResult;
+
+pub(in crate::execution::validation::state_transition::state_transitions::data_contract_delete) trait DataContractDeleteStateTransitionStructureValidationV0 {
+    fn validate_base_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl DataContractDeleteStateTransitionStructureValidationV0 for DataContractDeleteTransition {
+    fn validate_base_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error> {
+        // The transition only references the contract to delete, everything else
+        // (existence, ownership and deletability) depends on the state
+        Ok(SimpleConsensusValidationResult::default())
+    }
+}
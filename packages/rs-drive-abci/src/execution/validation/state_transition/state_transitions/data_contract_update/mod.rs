@@ -229,6 +229,7 @@ mod tests {
                     platform_version,
                 )
                 .expect("to be able to convert data contract to serialization format"),
+                user_fee_increase: 0,
                 signature: BinaryData::new(vec![0; 65]),
                 signature_public_key_id: 0,
             };
@@ -303,6 +304,7 @@ mod tests {
                     platform_version,
                 )
                 .expect("to be able to convert data contract to serialization format"),
+                user_fee_increase: 0,
                 signature: BinaryData::new(vec![0; 65]),
                 signature_public_key_id: 0,
             };
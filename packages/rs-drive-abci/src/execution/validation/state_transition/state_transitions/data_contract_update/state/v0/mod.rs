@@ -9,6 +9,7 @@ use dpp::consensus::basic::data_contract::{
 use dpp::consensus::basic::document::DataContractNotPresentError;
 use dpp::consensus::basic::BasicError;
 use dpp::consensus::state::data_contract::data_contract_is_readonly_error::DataContractIsReadonlyError;
+use dpp::consensus::state::document::document_type_index_backfill_in_progress_error::DocumentTypeIndexBackfillInProgressError;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 
 use dpp::data_contract::config::v0::DataContractConfigGettersV0;
@@ -17,6 +18,7 @@ use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::schema::{
     get_operation_and_property_name_json, validate_schema_compatibility,
 };
+use dpp::data_contract::document_type::IndexLevel;
 use dpp::data_contract::schema::DataContractSchemaMethodsV0;
 use dpp::data_contract::JsonValue;
 use dpp::platform_value::converter::serde_json::BTreeValueJsonConverter;
@@ -29,9 +31,11 @@ use dpp::ProtocolError;
 use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
 use dpp::version::{PlatformVersion, TryIntoPlatformVersioned};
 
+use drive::drive::document::index_backfill::added_index_names;
 use drive::grovedb::TransactionArg;
 use drive::state_transition_action::contract::data_contract_update::DataContractUpdateTransitionAction;
 use drive::state_transition_action::StateTransitionAction;
+use std::borrow::Cow;
 
 pub(in crate::execution::validation::state_transition::state_transitions::data_contract_update) trait DataContractUpdateStateTransitionStateValidationV0 {
     fn validate_state_v0<C: CoreRPCLike>(
@@ -119,11 +123,41 @@ impl DataContractUpdateStateTransitionStateValidationV0 for DataContractUpdateTr
                 // then new indices on it are fine
                 continue;
             };
+            let index_backfill = drive.fetch_index_backfill(
+                new_data_contract.id_ref().as_bytes(),
+                new_contract_document_type_name,
+                tx,
+                platform_version,
+            )?;
+
+            // Indices whose backfill failed can be removed, as no document is in them
+            let failed_index_names = index_backfill
+                .as_ref()
+                .map(|index_backfill| index_backfill.failed_index_names.as_slice())
+                .unwrap_or_default();
+
+            let old_index_structure = if failed_index_names.is_empty() {
+                Cow::Borrowed(old_contract_document_type.index_structure())
+            } else {
+                let kept_indices = old_contract_document_type
+                    .indices()
+                    .iter()
+                    .filter(|index| !failed_index_names.contains(&index.name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                Cow::Owned(IndexLevel::try_from_indices(
+                    kept_indices.as_slice(),
+                    new_contract_document_type_name,
+                    platform_version,
+                )?)
+            };
+
             // If the new contract document type doesn't contain all previous indexes then
             // there is a problem
             if let Some(non_subset_path) = new_contract_document_type
                 .index_structure()
-                .contains_subset_first_non_subset_path(old_contract_document_type.index_structure())
+                .contains_subset_first_non_subset_path(old_index_structure.as_ref())
             {
                 validation_result.add_error(
                     BasicError::DataContractInvalidIndexDefinitionUpdateError(
@@ -134,6 +168,43 @@ impl DataContractUpdateStateTransitionStateValidationV0 for DataContractUpdateTr
                     ),
                 )
             }
+
+            // Indices can't be added while the previously added ones are backfilled
+            if !added_index_names(
+                new_contract_document_type.as_ref(),
+                old_contract_document_type,
+            )
+            .is_empty()
+            {
+                if index_backfill.is_some_and(|index_backfill| index_backfill.is_pending()) {
+                    validation_result.add_error(DocumentTypeIndexBackfillInProgressError::new(
+                        new_data_contract.id(),
+                        new_contract_document_type_name.clone(),
+                    ));
+                }
+            }
+
+            // Existing documents were indexed by their expiry when they were created, so the
+            // expiry of a document type can't change
+            if new_contract_document_type.documents_expire_after()
+                != old_contract_document_type.documents_expire_after()
+            {
+                validation_result.add_error(BasicError::IncompatibleDataContractSchemaError(
+                    IncompatibleDataContractSchemaError::new(
+                        new_data_contract.id(),
+                        "replace".to_string(),
+                        format!("/{}/documentsExpireAfter", new_contract_document_type_name),
+                        old_contract_document_type
+                            .documents_expire_after()
+                            .map(Value::U64)
+                            .into(),
+                        new_contract_document_type
+                            .documents_expire_after()
+                            .map(Value::U64)
+                            .into(),
+                    ),
+                ));
+            }
         }
 
         if !validation_result.is_valid() {
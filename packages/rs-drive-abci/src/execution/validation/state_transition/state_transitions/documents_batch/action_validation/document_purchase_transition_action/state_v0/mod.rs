@@ -0,0 +1,62 @@
+use dpp::consensus::basic::document::InvalidDocumentTypeError;
+use dpp::document::DocumentV0Getters;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::identifier::Identifier;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::{DocumentPurchaseTransitionAction, DocumentPurchaseTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::error::Error;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(super) trait DocumentPurchaseTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentPurchaseTransitionActionStateValidationV0 for DocumentPurchaseTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        _owner_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+
+        let contract = &contract_fetch_info.contract;
+
+        let document_type_name = self.base().document_type_name();
+
+        let Some(document_type) = contract.document_type_optional_for_name(document_type_name)
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), contract.id()).into(),
+            ));
+        };
+
+        // The rest of state validation is happening in documents batch transition transformer,
+        // the purchaser balance is checked against all purchases of the batch together
+
+        // The document changes owner, which matters for unique indexes on $ownerId
+        let document = self.document();
+
+        platform
+            .drive
+            .validate_document_uniqueness(
+                contract,
+                document_type,
+                document,
+                document.owner_id(),
+                true,
+                transaction,
+                platform_version,
+            )
+            .map_err(Error::Drive)
+    }
+}
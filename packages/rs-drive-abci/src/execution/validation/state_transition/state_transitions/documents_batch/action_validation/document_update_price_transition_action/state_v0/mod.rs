@@ -0,0 +1,48 @@
+use dpp::consensus::basic::document::InvalidDocumentTypeError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::identifier::Identifier;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionAction;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::error::Error;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(super) trait DocumentUpdatePriceTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentUpdatePriceTransitionActionStateValidationV0 for DocumentUpdatePriceTransitionAction {
+    fn validate_state_v0(
+        &self,
+        _platform: &PlatformStateRef,
+        _owner_id: Identifier,
+        _transaction: TransactionArg,
+        _platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+
+        let contract = &contract_fetch_info.contract;
+
+        let document_type_name = self.base().document_type_name();
+
+        // The rest of state validation is actually happening in documents batch transition transformer
+        // Only the price changes, so unique indexes can't be violated
+        if contract
+            .document_type_optional_for_name(document_type_name)
+            .is_none()
+        {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), contract.id()).into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
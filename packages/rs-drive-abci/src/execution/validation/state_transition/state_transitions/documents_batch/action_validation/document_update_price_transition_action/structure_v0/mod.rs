@@ -0,0 +1,35 @@
+use dpp::consensus::basic::document::InvalidDocumentTypeError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::{DocumentUpdatePriceTransitionAction, DocumentUpdatePriceTransitionActionAccessorsV0};
+
+use crate::error::Error;
+
+pub(super) trait DocumentUpdatePriceTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentUpdatePriceTransitionActionStructureValidationV0
+    for DocumentUpdatePriceTransitionAction
+{
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+        let data_contract = &contract_fetch_info.contract;
+        let document_type_name = self.base().document_type_name();
+
+        // Make sure that the document type is defined in the contract
+        if data_contract
+            .document_type_optional_for_name(document_type_name)
+            .is_none()
+        {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), data_contract.id())
+                    .into(),
+            ));
+        }
+
+        // Whether the document type allows this action was already checked while
+        // building the action, as it requires the original document
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
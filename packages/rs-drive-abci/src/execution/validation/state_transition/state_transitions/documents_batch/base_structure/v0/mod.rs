@@ -17,6 +17,9 @@ use drive::state_transition_action::document::documents_batch::DocumentsBatchTra
 use crate::execution::validation::state_transition::state_transitions::documents_batch::action_validation::document_replace_transition_action::DocumentReplaceTransitionActionValidation;
 use crate::execution::validation::state_transition::state_transitions::documents_batch::action_validation::document_delete_transition_action::DocumentDeleteTransitionActionValidation;
 use crate::execution::validation::state_transition::state_transitions::documents_batch::action_validation::document_create_transition_action::DocumentCreateTransitionActionValidation;
+use crate::execution::validation::state_transition::state_transitions::documents_batch::action_validation::document_transfer_transition_action::DocumentTransferTransitionActionValidation;
+use crate::execution::validation::state_transition::state_transitions::documents_batch::action_validation::document_update_price_transition_action::DocumentUpdatePriceTransitionActionValidation;
+use crate::execution::validation::state_transition::state_transitions::documents_batch::action_validation::document_purchase_transition_action::DocumentPurchaseTransitionActionValidation;
 use dpp::state_transition::documents_batch_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
 use crate::platform_types::platform::PlatformStateRef;
 
@@ -87,6 +90,24 @@ impl DocumentsBatchStateTransitionStructureValidationV0 for DocumentsBatchTransi
                         return Ok(result);
                     }
                 }
+                DocumentTransitionAction::TransferAction(transfer_action) => {
+                    let result = transfer_action.validate_structure(platform_version)?;
+                    if !result.is_valid() {
+                        return Ok(result);
+                    }
+                }
+                DocumentTransitionAction::UpdatePriceAction(update_price_action) => {
+                    let result = update_price_action.validate_structure(platform_version)?;
+                    if !result.is_valid() {
+                        return Ok(result);
+                    }
+                }
+                DocumentTransitionAction::PurchaseAction(purchase_action) => {
+                    let result = purchase_action.validate_structure(platform_version)?;
+                    if !result.is_valid() {
+                        return Ok(result);
+                    }
+                }
             }
         }
         Ok(SimpleConsensusValidationResult::new())
@@ -232,6 +232,8 @@ pub fn create_domain_data_trigger_v0(
                         },
                     ),
                 ]),
+                other_in_clauses: vec![],
+                or_clauses: vec![],
             },
             offset: None,
             limit: None,
@@ -322,6 +324,8 @@ pub fn create_domain_data_trigger_v0(
                     value: Value::Bytes32(salted_domain_hash),
                 },
             )]),
+            other_in_clauses: vec![],
+            or_clauses: vec![],
         },
         offset: None,
         limit: None,
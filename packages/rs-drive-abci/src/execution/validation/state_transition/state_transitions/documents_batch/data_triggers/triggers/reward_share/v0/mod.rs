@@ -129,6 +129,8 @@ pub fn create_masternode_reward_shares_data_trigger_v0(
                     value: Value::Identifier(context.owner_id.to_buffer()),
                 },
             )]),
+            other_in_clauses: vec![],
+            or_clauses: vec![],
         },
         offset: None,
         limit: Some((MAX_DOCUMENTS + 1) as u16),
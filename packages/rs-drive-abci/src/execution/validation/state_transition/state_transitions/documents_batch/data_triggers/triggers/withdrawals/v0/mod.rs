@@ -66,6 +66,8 @@ pub fn delete_withdrawal_data_trigger_v0(
             in_clause: None,
             range_clause: None,
             equal_clauses: BTreeMap::default(),
+            other_in_clauses: vec![],
+            or_clauses: vec![],
         },
         offset: None,
         limit: Some(100),
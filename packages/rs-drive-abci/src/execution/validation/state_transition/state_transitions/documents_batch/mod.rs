@@ -139,3 +139,772 @@ impl StateTransitionStateValidationV0 for DocumentsBatchTransition {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{ExecutionConfig, PlatformConfig, PlatformTestConfig};
+    use crate::execution::validation::state_transition::processor::v0::StateTransitionStateValidationV0;
+    use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+    use crate::platform_types::platform::PlatformRef;
+    use crate::platform_types::state_transition_execution_result::StateTransitionExecutionResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::assert_state_consensus_errors;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::signature::SignatureError;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+    use dpp::data_contract::DataContract;
+    use dpp::document::document_methods::DocumentMethodsV0;
+    use dpp::document::document_patch::DocumentPatch;
+    use dpp::document::property_names::PRICE;
+    use dpp::document::{Document, DocumentV0, DocumentV0Getters, DocumentV0Setters};
+    use dpp::fee::Credits;
+    use dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+    use dpp::identity::{Identity, IdentityPublicKey, IdentityV0, SecurityLevel};
+    use dpp::platform_value::{platform_value, BinaryData, Identifier, Value};
+    use dpp::prelude::Revision;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::documents_batch_transition::document_transition::{
+        DocumentPatchTransition, DocumentPurchaseTransition, DocumentTransferTransition,
+        DocumentTransition, DocumentUpdatePriceTransition,
+    };
+    use dpp::state_transition::documents_batch_transition::{
+        DocumentsBatchTransition, DocumentsBatchTransitionV0,
+    };
+    use dpp::state_transition::StateTransition;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use dpp::version::PlatformVersion;
+    use drive::drive::batch::transitions::DriveHighLevelOperationConverter;
+    use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+    use drive::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use drive::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use drive::grovedb::Transaction;
+    use drive::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+    use drive::state_transition_action::StateTransitionAction;
+    use simple_signer::signer::SimpleSigner;
+    use std::collections::BTreeMap;
+
+    const CARD_PRICE: Credits = 1000;
+
+    struct TestData {
+        platform: TempPlatform<MockCoreRPCLike>,
+        data_contract: DataContract,
+        seller: Identity,
+        buyer: Identity,
+        /// Cards owned by the seller
+        cards: Vec<Document>,
+    }
+
+    fn add_identity(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        seed: u64,
+        balance: Credits,
+    ) -> Identity {
+        let platform_version = PlatformVersion::latest();
+
+        let mut identity = Identity::random_identity(2, Some(seed), platform_version)
+            .expect("expected a random identity");
+        identity.set_balance(balance);
+
+        platform
+            .drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        identity
+    }
+
+    /// Sets up a contract with a tradeable card document type and two cards of the seller
+    /// listed at `listed_price`
+    fn setup_test(buyer_balance: Credits, listed_price: Option<Credits>) -> TestData {
+        let platform_version = PlatformVersion::latest();
+
+        let config = PlatformConfig {
+            quorum_size: 10,
+            execution: ExecutionConfig {
+                verify_sum_trees: true,
+                validator_set_quorum_rotation_block_count: 25,
+                ..Default::default()
+            },
+            block_spacing_ms: 300,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            ..Default::default()
+        };
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let seller = add_identity(&platform, 1, 0);
+        let buyer = add_identity(&platform, 2, buyer_balance);
+
+        let mut data_contract =
+            get_data_contract_fixture(Some(seller.id()), platform_version.protocol_version)
+                .data_contract_owned();
+
+        let card_schema = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 63,
+                    "position": 0
+                }
+            },
+            "additionalProperties": false,
+            "transferable": 1,
+            "tradeMode": 1,
+        });
+
+        data_contract
+            .set_document_schema("card", card_schema, true, platform_version)
+            .expect("expected to set a document schema");
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        let document_type = data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let cards = (0..2)
+            .map(|seed| {
+                let mut card = document_type
+                    .random_document(Some(seed), platform_version)
+                    .expect("expected a random document");
+                card.set_owner_id(seller.id());
+                if let Some(price) = listed_price {
+                    card.set(PRICE, Value::U64(price));
+                }
+
+                platform
+                    .drive
+                    .add_document_for_contract(
+                        DocumentAndContractInfo {
+                            owned_document_info: OwnedDocumentInfo {
+                                document_info: DocumentRefInfo((&card, None)),
+                                owner_id: Some(seller.id().to_buffer()),
+                            },
+                            contract: &data_contract,
+                            document_type,
+                        },
+                        false,
+                        BlockInfo::default(),
+                        true,
+                        None,
+                        platform_version,
+                    )
+                    .expect("expected to add a document");
+
+                card
+            })
+            .collect();
+
+        TestData {
+            platform,
+            data_contract,
+            seller,
+            buyer,
+            cards,
+        }
+    }
+
+    fn batch(
+        owner_id: Identifier,
+        transitions: Vec<DocumentTransition>,
+    ) -> DocumentsBatchTransition {
+        DocumentsBatchTransition::V0(DocumentsBatchTransitionV0 {
+            owner_id,
+            transitions,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: BinaryData::new(vec![0; 65]),
+        })
+    }
+
+    fn purchase(data: &TestData, card: &Document, price: Credits) -> DocumentTransition {
+        let platform_version = PlatformVersion::latest();
+        let document_type = data
+            .data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let mut card = card.clone();
+        card.increment_revision()
+            .expect("expected to bump revision");
+
+        DocumentTransition::Purchase(
+            DocumentPurchaseTransition::from_document(
+                card,
+                document_type,
+                price,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected a purchase transition"),
+        )
+    }
+
+    /// Transforms the batch into an action and validates its state
+    fn validate(
+        data: &TestData,
+        transition: &DocumentsBatchTransition,
+    ) -> dpp::validation::ConsensusValidationResult<StateTransitionAction> {
+        let platform_ref = PlatformRef {
+            drive: &data.platform.drive,
+            state: &data.platform.state.read().unwrap(),
+            config: &data.platform.config,
+            core_rpc: &data.platform.core_rpc,
+            block_info: &BlockInfo::default(),
+        };
+
+        let result = transition
+            .transform_into_action(&platform_ref, true, None)
+            .expect("expected to transform into action");
+
+        if !result.is_valid() {
+            return result;
+        }
+
+        transition
+            .validate_state(Some(result.into_data().unwrap()), &platform_ref, None)
+            .expect("expected to validate state")
+    }
+
+    fn balance(data: &TestData, identity: &Identity) -> Credits {
+        data.platform
+            .drive
+            .fetch_identity_balance(identity.id().to_buffer(), None, PlatformVersion::latest())
+            .expect("expected to fetch balance")
+            .expect("expected identity to exist")
+    }
+
+    #[test]
+    fn should_purchase_listed_documents_and_pay_seller_once_per_batch() {
+        let data = setup_test(5 * CARD_PRICE, Some(CARD_PRICE));
+        let platform_version = PlatformVersion::latest();
+        let block_info = BlockInfo::default();
+
+        let transition = batch(
+            data.buyer.id(),
+            data.cards
+                .iter()
+                .map(|card| purchase(&data, card, CARD_PRICE))
+                .collect(),
+        );
+
+        let result = validate(&data, &transition);
+        assert!(result.is_valid(), "{:?}", result.errors);
+
+        let operations = result
+            .into_data()
+            .expect("expected an action")
+            .into_high_level_drive_operations(&block_info.epoch, platform_version)
+            .expect("expected drive operations");
+
+        data.platform
+            .drive
+            .apply_drive_operations(operations, true, &block_info, None, platform_version)
+            .expect("expected to apply drive operations");
+
+        assert_eq!(balance(&data, &data.buyer), 3 * CARD_PRICE);
+        assert_eq!(balance(&data, &data.seller), 2 * CARD_PRICE);
+    }
+
+    #[test]
+    fn should_return_error_if_purchase_price_is_not_the_listed_price() {
+        let data = setup_test(5 * CARD_PRICE, Some(CARD_PRICE));
+
+        let transition = batch(
+            data.buyer.id(),
+            vec![purchase(&data, &data.cards[0], CARD_PRICE - 1)],
+        );
+
+        let result = validate(&data, &transition);
+        assert_state_consensus_errors!(result, StateError::DocumentIncorrectPurchasePriceError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_document_is_not_for_sale() {
+        let data = setup_test(5 * CARD_PRICE, None);
+
+        let transition = batch(
+            data.buyer.id(),
+            vec![purchase(&data, &data.cards[0], CARD_PRICE)],
+        );
+
+        let result = validate(&data, &transition);
+        assert_state_consensus_errors!(result, StateError::DocumentNotForSaleError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_purchaser_already_owns_document() {
+        let data = setup_test(5 * CARD_PRICE, Some(CARD_PRICE));
+
+        let transition = batch(
+            data.seller.id(),
+            vec![purchase(&data, &data.cards[0], CARD_PRICE)],
+        );
+
+        let result = validate(&data, &transition);
+        assert_state_consensus_errors!(result, StateError::DocumentAlreadyOwnedError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_purchaser_can_not_pay_for_all_documents_of_batch() {
+        // enough for one card, but not for both
+        let data = setup_test(CARD_PRICE + CARD_PRICE / 2, Some(CARD_PRICE));
+
+        let transition = batch(
+            data.buyer.id(),
+            data.cards
+                .iter()
+                .map(|card| purchase(&data, card, CARD_PRICE))
+                .collect(),
+        );
+
+        let result = validate(&data, &transition);
+        assert_state_consensus_errors!(result, StateError::IdentityInsufficientBalanceError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_price_is_updated_by_someone_else_than_owner() {
+        let data = setup_test(5 * CARD_PRICE, Some(CARD_PRICE));
+        let platform_version = PlatformVersion::latest();
+        let document_type = data
+            .data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let mut card = data.cards[0].clone();
+        card.increment_revision()
+            .expect("expected to bump revision");
+
+        let transition = batch(
+            data.buyer.id(),
+            vec![DocumentTransition::UpdatePrice(
+                DocumentUpdatePriceTransition::from_document(
+                    card,
+                    document_type,
+                    1,
+                    platform_version,
+                    None,
+                    None,
+                )
+                .expect("expected an update price transition"),
+            )],
+        );
+
+        let result = validate(&data, &transition);
+        assert_state_consensus_errors!(result, StateError::DocumentOwnerIdMismatchError, 1);
+    }
+
+    #[test]
+    fn should_return_error_if_transfer_recipient_does_not_exist() {
+        let data = setup_test(5 * CARD_PRICE, Some(CARD_PRICE));
+        let platform_version = PlatformVersion::latest();
+        let document_type = data
+            .data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let mut card = data.cards[0].clone();
+        card.increment_revision()
+            .expect("expected to bump revision");
+
+        let transition = batch(
+            data.seller.id(),
+            vec![DocumentTransition::Transfer(
+                DocumentTransferTransition::from_document(
+                    card,
+                    document_type,
+                    Identifier::random(),
+                    platform_version,
+                    None,
+                    None,
+                )
+                .expect("expected a transfer transition"),
+            )],
+        );
+
+        let result = validate(&data, &transition);
+        assert!(!result.is_valid());
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::IdentityNotFoundError(_)
+            )]
+        ));
+    }
+
+    struct PatchTestData {
+        platform: TempPlatform<MockCoreRPCLike>,
+        data_contract: DataContract,
+        key: IdentityPublicKey,
+        signer: SimpleSigner,
+        /// Card of the identity, indexed by name and by color
+        card: Document,
+    }
+
+    /// Sets up an identity able to sign batches and a contract with a card document type
+    /// indexed by name and by color, with a single card of the identity
+    fn setup_patch_test() -> PatchTestData {
+        let platform_version = PlatformVersion::latest();
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (key, private_key) = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+            1,
+            Some(19),
+            platform_version,
+        )
+        .expect("expected to get key pair");
+
+        let mut signer = SimpleSigner::default();
+        signer.add_key(key.clone(), private_key);
+
+        let identity: Identity = IdentityV0 {
+            id: Identifier::new([5; 32]),
+            public_keys: BTreeMap::from([(1, key.clone())]),
+            balance: 1000000000,
+            revision: 0,
+        }
+        .into();
+
+        platform
+            .drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        let mut data_contract =
+            get_data_contract_fixture(Some(identity.id()), platform_version.protocol_version)
+                .data_contract_owned();
+
+        let card_schema = platform_value!({
+            "type": "object",
+            "indices": [
+                {
+                    "name": "name",
+                    "properties": [{ "name": "asc" }]
+                },
+                {
+                    "name": "color",
+                    "properties": [{ "color": "asc" }]
+                }
+            ],
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 63,
+                    "position": 0
+                },
+                "color": {
+                    "type": "string",
+                    "maxLength": 63,
+                    "position": 1
+                }
+            },
+            "required": ["name", "color"],
+            "additionalProperties": false,
+        });
+
+        data_contract
+            .set_document_schema("card", card_schema, true, platform_version)
+            .expect("expected to set a document schema");
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        let document_type = data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let card: Document = DocumentV0 {
+            id: Identifier::new([6; 32]),
+            owner_id: identity.id(),
+            properties: BTreeMap::from([
+                ("name".to_string(), Value::Text("Ace".to_string())),
+                ("color".to_string(), Value::Text("red".to_string())),
+            ]),
+            revision: Some(1),
+            created_at: None,
+            updated_at: None,
+        }
+        .into();
+
+        platform
+            .drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&card, None)),
+                        owner_id: Some(identity.id().to_buffer()),
+                    },
+                    contract: &data_contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add a document");
+
+        PatchTestData {
+            platform,
+            data_contract,
+            key,
+            signer,
+            card,
+        }
+    }
+
+    /// Signs a batch patching the card with `properties`
+    fn signed_patch(
+        data: &PatchTestData,
+        properties: BTreeMap<String, Value>,
+        revision: Revision,
+    ) -> Vec<u8> {
+        let platform_version = PlatformVersion::latest();
+        let document_type = data
+            .data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let patch_transition = DocumentPatchTransition::from_document_patch(
+            DocumentPatch {
+                id: data.card.id(),
+                properties,
+                revision: Some(revision),
+                updated_at: None,
+            },
+            document_type,
+            platform_version,
+            None,
+            None,
+        )
+        .expect("expected a patch transition");
+
+        let mut state_transition: StateTransition = batch(
+            data.card.owner_id(),
+            vec![DocumentTransition::Patch(patch_transition)],
+        )
+        .into();
+
+        state_transition
+            .sign_external(
+                &data.key,
+                &data.signer,
+                Some(|_, _| Ok(SecurityLevel::HIGH)),
+            )
+            .expect("expected to sign the batch");
+
+        state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize the batch")
+    }
+
+    /// Processes the batch as a block would and returns its execution result
+    fn process(
+        data: &PatchTestData,
+        serialized_batch: Vec<u8>,
+        transaction: &Transaction,
+    ) -> StateTransitionExecutionResult {
+        let platform_version = PlatformVersion::latest();
+        let platform_state = data.platform.state.read().unwrap().clone();
+
+        let (_, mut results, _) = data
+            .platform
+            .process_raw_state_transitions(
+                &vec![serialized_batch],
+                &platform_state,
+                &BlockInfo::default(),
+                transaction,
+                platform_version,
+            )
+            .expect("expected to process the batch");
+
+        results.remove(0).1
+    }
+
+    /// Queries the cards with `value` in the index of `field`
+    fn cards_by(
+        data: &PatchTestData,
+        field: &str,
+        value: &str,
+        transaction: &Transaction,
+    ) -> Vec<Document> {
+        let platform_version = PlatformVersion::latest();
+        let document_type = data
+            .data_contract
+            .document_type_for_name("card")
+            .expect("expected a card document type");
+
+        let drive_query = DriveQuery {
+            contract: &data.data_contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: None,
+                in_clause: None,
+                range_clause: None,
+                equal_clauses: BTreeMap::from([(
+                    field.to_string(),
+                    WhereClause {
+                        field: field.to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::Text(value.to_string()),
+                    },
+                )]),
+                other_in_clauses: vec![],
+                or_clauses: vec![],
+            },
+            offset: None,
+            limit: None,
+            order_by: Default::default(),
+            start_at: None,
+            start_at_included: false,
+            block_time_ms: None,
+        };
+
+        data.platform
+            .drive
+            .query_documents(
+                drive_query,
+                None,
+                false,
+                Some(transaction),
+                Some(platform_version.protocol_version),
+            )
+            .expect("expected to query cards")
+            .documents_owned()
+    }
+
+    #[test]
+    fn should_patch_document_and_only_move_changed_index_entries() {
+        let data = setup_patch_test();
+        let transaction = data.platform.drive.grove.start_transaction();
+
+        let serialized_batch = signed_patch(
+            &data,
+            BTreeMap::from([("color".to_string(), Value::Text("blue".to_string()))]),
+            2,
+        );
+
+        let result = process(&data, serialized_batch, &transaction);
+        assert!(
+            matches!(
+                result,
+                StateTransitionExecutionResult::SuccessfulPaidExecution(..)
+            ),
+            "{:?}",
+            result
+        );
+
+        // The name index entry is kept and resolves to the patched card
+        let cards = cards_by(&data, "name", "Ace", &transaction);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].id(), data.card.id());
+        assert_eq!(cards[0].revision(), Some(2));
+        assert_eq!(
+            cards[0].properties().get("color"),
+            Some(&Value::Text("blue".to_string()))
+        );
+
+        // The color index entry is moved to the patched value
+        assert!(cards_by(&data, "color", "red", &transaction).is_empty());
+        assert_eq!(cards_by(&data, "color", "blue", &transaction).len(), 1);
+    }
+
+    #[test]
+    fn should_reject_patch_making_document_invalid() {
+        let data = setup_patch_test();
+        let transaction = data.platform.drive.grove.start_transaction();
+
+        // The patch is only validated as part of the merged document
+        let serialized_batch = signed_patch(
+            &data,
+            BTreeMap::from([("color".to_string(), Value::Text("x".repeat(64)))]),
+            2,
+        );
+
+        let result = process(&data, serialized_batch, &transaction);
+        assert!(
+            matches!(
+                result,
+                StateTransitionExecutionResult::ConsensusExecutionError(..)
+            ),
+            "{:?}",
+            result
+        );
+
+        assert_eq!(cards_by(&data, "color", "red", &transaction).len(), 1);
+    }
+
+    #[test]
+    fn should_reject_patch_with_wrong_revision() {
+        let data = setup_patch_test();
+        let transaction = data.platform.drive.grove.start_transaction();
+
+        let serialized_batch = signed_patch(
+            &data,
+            BTreeMap::from([("color".to_string(), Value::Text("blue".to_string()))]),
+            3,
+        );
+
+        let StateTransitionExecutionResult::ConsensusExecutionError(result) =
+            process(&data, serialized_batch, &transaction)
+        else {
+            panic!("expected patch to be rejected");
+        };
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::InvalidDocumentRevisionError(_)
+            )]
+        ));
+
+        assert_eq!(cards_by(&data, "color", "red", &transaction).len(), 1);
+    }
+}
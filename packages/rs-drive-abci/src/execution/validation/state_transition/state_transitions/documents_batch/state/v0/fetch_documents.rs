@@ -147,6 +147,8 @@ pub(crate) fn fetch_documents_for_transitions_knowing_contract_and_document_type
             in_clause: None,
             range_clause: None,
             equal_clauses: Default::default(),
+            other_in_clauses: vec![],
+            or_clauses: vec![],
         },
         offset: None,
         limit: Some(transitions.len() as u16),
@@ -191,6 +193,8 @@ pub(crate) fn fetch_document_with_id(
             in_clause: None,
             range_clause: None,
             equal_clauses: Default::default(),
+            other_in_clauses: vec![],
+            or_clauses: vec![],
         },
         offset: None,
         limit: Some(1),
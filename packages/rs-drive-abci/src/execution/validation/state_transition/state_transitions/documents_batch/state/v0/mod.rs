@@ -1,3 +1,5 @@
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+use dpp::fee::Credits;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
@@ -5,6 +7,7 @@ use dpp::state_transition::StateTransitionLike;
 use drive::state_transition_action::StateTransitionAction;
 use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
 use drive::grovedb::TransactionArg;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionActionAccessorsV0;
 use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
 use drive::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use crate::error::Error;
@@ -12,6 +15,9 @@ use crate::execution::types::state_transition_execution_context::StateTransition
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_create_transition_action::DocumentCreateTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_delete_transition_action::DocumentDeleteTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_replace_transition_action::DocumentReplaceTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_transfer_transition_action::DocumentTransferTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_update_price_transition_action::DocumentUpdatePriceTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_purchase_transition_action::DocumentPurchaseTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::data_triggers::DataTriggerExecutionContext;
 use crate::execution::validation::state_transition::documents_batch::state::v0::data_triggers::execute_data_triggers;
 use crate::platform_types::platform::{PlatformStateRef};
@@ -62,6 +68,18 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
                     .validate_state(platform, owner_id, transaction, platform_version)?,
                 DocumentTransitionAction::DeleteAction(delete_action) => delete_action
                     .validate_state(platform, owner_id, transaction, platform_version)?,
+                DocumentTransitionAction::TransferAction(transfer_action) => transfer_action
+                    .validate_state(platform, owner_id, transaction, platform_version)?,
+                DocumentTransitionAction::UpdatePriceAction(update_price_action) => {
+                    update_price_action.validate_state(
+                        platform,
+                        owner_id,
+                        transaction,
+                        platform_version,
+                    )?
+                }
+                DocumentTransitionAction::PurchaseAction(purchase_action) => purchase_action
+                    .validate_state(platform, owner_id, transaction, platform_version)?,
             };
 
             if !transition_validation_result.is_valid() {
@@ -71,6 +89,35 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
             }
         }
 
+        // The purchaser must be able to pay for all documents bought in the batch
+        let total_purchase_price = state_transition_action
+            .transitions()
+            .iter()
+            .filter_map(|transition| match transition {
+                DocumentTransitionAction::PurchaseAction(purchase_action) => {
+                    Some(purchase_action.price())
+                }
+                _ => None,
+            })
+            .fold(0 as Credits, |total, price| total.saturating_add(price));
+
+        if total_purchase_price > 0 {
+            let balance = platform
+                .drive
+                .fetch_identity_balance(owner_id.to_buffer(), transaction, platform_version)?
+                .unwrap_or_default();
+
+            if balance < total_purchase_price {
+                validation_result.add_error(IdentityInsufficientBalanceError::new(
+                    owner_id,
+                    balance,
+                    total_purchase_price,
+                ));
+
+                return Ok(validation_result);
+            }
+        }
+
         let data_trigger_execution_context = DataTriggerExecutionContext {
             platform,
             transaction,
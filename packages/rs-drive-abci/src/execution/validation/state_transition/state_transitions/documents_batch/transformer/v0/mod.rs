@@ -4,28 +4,47 @@ use std::sync::Arc;
 
 use crate::error::Error;
 use crate::platform_types::platform::PlatformStateRef;
-use dpp::consensus::basic::document::{DataContractNotPresentError, InvalidDocumentTypeError};
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use dpp::consensus::basic::document::{
+    DataContractNotPresentError, InvalidDocumentTransitionActionError, InvalidDocumentTypeError,
+};
 use dpp::consensus::basic::BasicError;
 
+use dpp::consensus::state::document::document_already_owned_error::DocumentAlreadyOwnedError;
+use dpp::consensus::state::document::document_incorrect_purchase_price_error::DocumentIncorrectPurchasePriceError;
+use dpp::consensus::state::document::document_index_backfill_conflict_error::DocumentIndexBackfillConflictError;
+use dpp::consensus::state::document::document_not_for_sale_error::DocumentNotForSaleError;
 use dpp::consensus::state::document::document_not_found_error::DocumentNotFoundError;
 use dpp::consensus::state::document::document_owner_id_mismatch_error::DocumentOwnerIdMismatchError;
+use dpp::consensus::state::document::document_type_index_backfill_failed_error::DocumentTypeIndexBackfillFailedError;
+use dpp::consensus::state::document::document_type_index_backfill_in_progress_error::DocumentTypeIndexBackfillInProgressError;
 
 use dpp::consensus::state::document::invalid_document_revision_error::InvalidDocumentRevisionError;
 use dpp::consensus::state::state_error::StateError;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentTypeRef;
 
+use dpp::document::property_names::PRICE;
 use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::identity::TimestampMillis;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::prelude::Revision;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::{consensus::ConsensusError, prelude::Identifier, validation::ConsensusValidationResult};
 
 use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
 use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
-use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentReplaceTransition, DocumentTransitionV0Methods};
+use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentTransitionV0Methods};
 use dpp::state_transition::StateTransitionLike;
 use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::DocumentDeleteTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentReplaceTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
 use drive::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use drive::state_transition_action::document::documents_batch::v0::DocumentsBatchTransitionActionV0;
@@ -34,7 +53,11 @@ use crate::execution::validation::state_transition::documents_batch::state::v0::
 use dpp::version::PlatformVersion;
 use drive::grovedb::TransactionArg;
 
+use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_patch_transition::v0::v0_methods::DocumentPatchTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
 use drive::drive::contract::DataContractFetchInfo;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 
@@ -75,21 +98,24 @@ trait DocumentsBatchTransitionInternalTransformerV0 {
     fn transform_transition_v0(
         validate: bool,
         data_contract_fetch_info: Arc<DataContractFetchInfo>,
+        document_type: DocumentTypeRef,
         transition: &DocumentTransition,
         replaced_documents: &[Document],
         owner_id: Identifier,
+        block_time_ms: TimestampMillis,
     ) -> Result<ConsensusValidationResult<DocumentTransitionAction>, Error>;
     fn find_replaced_document_v0<'a>(
         document_transition: &'a DocumentTransition,
         fetched_documents: &'a [Document],
     ) -> ConsensusValidationResult<&'a Document>;
     fn check_ownership_of_old_replaced_document_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_id: Identifier,
         fetched_document: &Document,
         owner_id: &Identifier,
     ) -> SimpleConsensusValidationResult;
     fn check_revision_is_bumped_by_one_during_replace_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_id: Identifier,
+        transition_revision: Revision,
         original_document: &Document,
     ) -> SimpleConsensusValidationResult;
 }
@@ -249,15 +275,85 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
             ));
         };
 
+        if validate {
+            // Documents are frozen while new indices of their type are backfilled
+            if let Some(index_backfill) = platform.drive.fetch_index_backfill(
+                data_contract.id_ref().as_bytes(),
+                document_type_name,
+                transaction,
+                platform_version,
+            )? {
+                if index_backfill.is_pending() {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(
+                            StateError::DocumentTypeIndexBackfillInProgressError(
+                                DocumentTypeIndexBackfillInProgressError::new(
+                                    data_contract.id(),
+                                    document_type_name.to_owned(),
+                                ),
+                            ),
+                        ),
+                    ));
+                }
+
+                // Existing documents aren't in failed indices, so documents can't be changed
+                // until the contract owner removes or changes these indices
+                if index_backfill.is_document_type_frozen() {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(
+                            StateError::DocumentTypeIndexBackfillFailedError(
+                                DocumentTypeIndexBackfillFailedError::new(
+                                    data_contract.id(),
+                                    document_type_name.to_owned(),
+                                    index_backfill.failed_index_names.clone(),
+                                ),
+                            ),
+                        ),
+                    ));
+                }
+
+                // Documents left out of a unique index can't be changed,
+                // as they would remove the entry of the document holding it
+                for transition in document_transitions {
+                    if matches!(transition, DocumentTransition::Create(_)) {
+                        continue;
+                    }
+                    if let Some(conflict) =
+                        index_backfill.conflict_for_document(&transition.base().id())
+                    {
+                        return Ok(ConsensusValidationResult::new_with_error(
+                            ConsensusError::StateError(
+                                StateError::DocumentIndexBackfillConflictError(
+                                    DocumentIndexBackfillConflictError::new(
+                                        conflict.document_id,
+                                        conflict.index_name.clone(),
+                                    ),
+                                ),
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
         let replace_transitions = document_transitions
             .iter()
-            .filter(|transition| matches!(transition, DocumentTransition::Replace(_)))
+            .filter(|transition| {
+                matches!(
+                    transition,
+                    DocumentTransition::Replace(_)
+                        | DocumentTransition::Transfer(_)
+                        | DocumentTransition::UpdatePrice(_)
+                        | DocumentTransition::Purchase(_)
+                        | DocumentTransition::Patch(_)
+                )
+            })
             .copied()
             .collect::<Vec<_>>();
 
-        // We fetch documents only for replace transitions
+        // We fetch documents only for transitions that modify an existing document
         // since we need them to create transition actions
-        // Below we also perform state validation for replace transitions only
+        // Below we also perform state validation for these transitions only
         // other transitions are validated in their validate_state functions
         // TODO: Think more about this architecture
         let fetched_documents_validation_result =
@@ -278,6 +374,9 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
 
         let replaced_documents = fetched_documents_validation_result.into_data()?;
 
+        // Transfers, price updates and purchases stamp the document with the last block time
+        let block_time_ms = platform.state.last_block_time_ms().unwrap_or_default();
+
         let document_transition_actions_result = if !dry_run {
             let document_transition_actions_validation_result = document_transitions
                 .iter()
@@ -286,9 +385,11 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                     Self::transform_transition_v0(
                         validate,
                         data_contract_fetch_info.clone(),
+                        document_type,
                         transition,
                         &replaced_documents,
                         owner_id,
+                        block_time_ms,
                     )
                 })
                 .collect::<Result<Vec<ConsensusValidationResult<DocumentTransitionAction>>, Error>>(
@@ -321,9 +422,11 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
     fn transform_transition_v0<'a>(
         validate: bool,
         data_contract_fetch_info: Arc<DataContractFetchInfo>,
+        document_type: DocumentTypeRef,
         transition: &DocumentTransition,
         replaced_documents: &[Document],
         owner_id: Identifier,
+        block_time_ms: TimestampMillis,
     ) -> Result<ConsensusValidationResult<DocumentTransitionAction>, Error> {
         match transition {
             DocumentTransition::Create(document_create_transition) => {
@@ -357,7 +460,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                 let original_document_created_at = original_document.created_at();
 
                 let validation_result = Self::check_ownership_of_old_replaced_document_v0(
-                    document_replace_transition,
+                    document_replace_transition.base().id(),
                     original_document,
                     &owner_id,
                 );
@@ -372,7 +475,8 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                     // for example when we already applied the state transition action
                     // and we are just validating it happened
                     let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
-                        document_replace_transition,
+                        document_replace_transition.base().id(),
+                        document_replace_transition.revision(),
                         original_document,
                     );
 
@@ -401,6 +505,235 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
             })?;
                 Ok(DocumentTransitionAction::DeleteAction(action).into())
             }
+            DocumentTransition::Transfer(document_transfer_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                if !document_type.documents_transferable().is_transferable() {
+                    result.add_error(InvalidDocumentTransitionActionError::new(
+                        "transfer".to_string(),
+                    ));
+                    return Ok(result);
+                }
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                let validation_result = Self::check_ownership_of_old_replaced_document_v0(
+                    document_transfer_transition.base().id(),
+                    original_document,
+                    &owner_id,
+                );
+
+                if !validation_result.is_valid() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        document_transfer_transition.base().id(),
+                        document_transfer_transition.revision(),
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let document_transfer_action =
+                    DocumentTransferTransitionAction::try_from_borrowed_document_transfer_transition(
+                        document_transfer_transition,
+                        original_document.clone(),
+                        block_time_ms,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(DocumentTransitionAction::TransferAction(document_transfer_action).into())
+            }
+            DocumentTransition::UpdatePrice(document_update_price_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                if !document_type.trade_mode().seller_sets_price() {
+                    result.add_error(InvalidDocumentTransitionActionError::new(
+                        "updatePrice".to_string(),
+                    ));
+                    return Ok(result);
+                }
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                let validation_result = Self::check_ownership_of_old_replaced_document_v0(
+                    document_update_price_transition.base().id(),
+                    original_document,
+                    &owner_id,
+                );
+
+                if !validation_result.is_valid() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        document_update_price_transition.base().id(),
+                        document_update_price_transition.revision(),
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let document_update_price_action =
+                    DocumentUpdatePriceTransitionAction::try_from_borrowed_document_update_price_transition(
+                        document_update_price_transition,
+                        original_document.clone(),
+                        block_time_ms,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(
+                    DocumentTransitionAction::UpdatePriceAction(document_update_price_action)
+                        .into(),
+                )
+            }
+            DocumentTransition::Purchase(document_purchase_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                if !document_type.trade_mode().seller_sets_price() {
+                    result.add_error(InvalidDocumentTransitionActionError::new(
+                        "purchase".to_string(),
+                    ));
+                    return Ok(result);
+                }
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                let document_id = document_purchase_transition.base().id();
+
+                // Buying a document from yourself would only move credits in a circle
+                if original_document.owner_id() == owner_id {
+                    result.add_error(DocumentAlreadyOwnedError::new(document_id, owner_id));
+                    return Ok(result);
+                }
+
+                let Some(listed_price) = original_document
+                    .properties()
+                    .get_optional_integer::<Credits>(PRICE)?
+                else {
+                    result.add_error(DocumentNotForSaleError::new(document_id));
+                    return Ok(result);
+                };
+
+                if document_purchase_transition.price() != listed_price {
+                    result.add_error(DocumentIncorrectPurchasePriceError::new(
+                        document_id,
+                        document_purchase_transition.price(),
+                        listed_price,
+                    ));
+                    return Ok(result);
+                }
+
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        document_id,
+                        document_purchase_transition.revision(),
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let document_purchase_action =
+                    DocumentPurchaseTransitionAction::try_from_borrowed_document_purchase_transition(
+                        document_purchase_transition,
+                        original_document.clone(),
+                        owner_id,
+                        block_time_ms,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(DocumentTransitionAction::PurchaseAction(document_purchase_action).into())
+            }
+            DocumentTransition::Patch(document_patch_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                let validation_result = Self::check_ownership_of_old_replaced_document_v0(
+                    document_patch_transition.base().id(),
+                    original_document,
+                    &owner_id,
+                );
+
+                if !validation_result.is_valid() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        document_patch_transition.base().id(),
+                        document_patch_transition.revision(),
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                // The patch is applied as a replace of the merged document, so the merged
+                // document is validated against the schema and only the changed indices are
+                // updated in Drive
+                let document_replace_action =
+                    DocumentReplaceTransitionAction::try_from_borrowed_document_patch_transition(
+                        document_patch_transition,
+                        original_document,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(DocumentTransitionAction::ReplaceAction(document_replace_action).into())
+            }
         }
     }
 
@@ -424,7 +757,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
     }
 
     fn check_ownership_of_old_replaced_document_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_id: Identifier,
         fetched_document: &Document,
         owner_id: &Identifier,
     ) -> SimpleConsensusValidationResult {
@@ -432,7 +765,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
         if fetched_document.owner_id() != owner_id {
             result.add_error(ConsensusError::StateError(
                 StateError::DocumentOwnerIdMismatchError(DocumentOwnerIdMismatchError::new(
-                    document_transition.base().id(),
+                    document_id,
                     owner_id.to_owned(),
                     fetched_document.owner_id(),
                 )),
@@ -441,19 +774,20 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
         result
     }
     fn check_revision_is_bumped_by_one_during_replace_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_id: Identifier,
+        transition_revision: Revision,
         original_document: &Document,
     ) -> SimpleConsensusValidationResult {
         let mut result = SimpleConsensusValidationResult::default();
 
-        let revision = document_transition.revision();
+        let revision = transition_revision;
 
         // If there was no previous revision this means that the document_type is not update-able
         // However this should have been caught earlier
         let Some(previous_revision) = original_document.revision() else {
             result.add_error(ConsensusError::StateError(
                 StateError::InvalidDocumentRevisionError(InvalidDocumentRevisionError::new(
-                    document_transition.base().id(),
+                    document_id,
                     None,
                     revision,
                 )),
@@ -465,7 +799,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
         if revision != expected_revision {
             result.add_error(ConsensusError::StateError(
                 StateError::InvalidDocumentRevisionError(InvalidDocumentRevisionError::new(
-                    document_transition.base().id(),
+                    document_id,
                     Some(previous_revision),
                     revision,
                 )),
@@ -4,6 +4,7 @@ use crate::rpc::core::CoreRPCLike;
 
 use dpp::consensus::signature::IdentityNotFoundError;
 
+use dpp::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
 use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 
 use dpp::prelude::ConsensusValidationResult;
@@ -58,6 +59,19 @@ impl IdentityCreditTransferStateTransitionStateValidationV0 for IdentityCreditTr
             ));
         }
 
+        // Check nonce, identities without a stored nonce start from 0
+        let current_nonce = platform
+            .drive
+            .fetch_identity_nonce(self.identity_id().to_buffer(), true, tx, platform_version)?
+            .unwrap_or_default();
+
+        if current_nonce.checked_add(1) != Some(self.nonce()) {
+            return Ok(ConsensusValidationResult::new_with_error(
+                InvalidIdentityNonceError::new(self.identity_id(), current_nonce, self.nonce())
+                    .into(),
+            ));
+        }
+
         let maybe_existing_recipient = platform.drive.fetch_identity_balance(
             self.recipient_id().to_buffer(),
             tx,
@@ -5,6 +5,7 @@ use crate::rpc::core::CoreRPCLike;
 
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use dpp::consensus::signature::IdentityNotFoundError;
+use dpp::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
 use dpp::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 use dpp::consensus::state::state_error::StateError;
@@ -84,6 +85,19 @@ impl IdentityCreditWithdrawalStateTransitionStateValidationV0
             ));
         }
 
+        // Check nonce, identities without a stored nonce start from 0
+        let current_nonce = platform
+            .drive
+            .fetch_identity_nonce(self.identity_id().to_buffer(), true, tx, platform_version)?
+            .unwrap_or_default();
+
+        if current_nonce.checked_add(1) != Some(self.nonce()) {
+            return Ok(ConsensusValidationResult::new_with_error(
+                InvalidIdentityNonceError::new(self.identity_id(), current_nonce, self.nonce())
+                    .into(),
+            ));
+        }
+
         self.transform_into_action_v0(platform)
     }
 
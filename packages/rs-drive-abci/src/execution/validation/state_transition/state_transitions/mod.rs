@@ -21,3 +21,6 @@ pub mod data_contract_create;
 
 /// Module for updating an existing data contract entity.
 pub mod data_contract_update;
+
+/// Module for deleting an existing data contract entity.
+pub mod data_contract_delete;
@@ -56,6 +56,9 @@ impl StateTransitionActionTransformerV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.transform_into_action(platform, validate, tx)
             }
+            StateTransition::DataContractDelete(st) => {
+                st.transform_into_action(platform, validate, tx)
+            }
         }
     }
 }
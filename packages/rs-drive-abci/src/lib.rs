@@ -43,3 +43,6 @@ pub mod mimic;
 pub mod platform_types;
 /// Querying
 pub mod query;
+/// Offline tools to inspect, export, import and replay the state
+#[cfg(any(feature = "server", test))]
+pub mod tools;
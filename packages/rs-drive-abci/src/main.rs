@@ -3,15 +3,21 @@
 //! RS-Drive-ABCI server starts a single-threaded server and listens to connections from Tenderdash.
 
 use clap::{Parser, Subcommand};
+use dpp::platform_value::string_encoding::Encoding;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
 use drive_abci::config::{FromEnv, PlatformConfig};
 use drive_abci::core::wait_for_core_to_sync::v0::wait_for_core_to_sync_v0;
 use drive_abci::logging;
 use drive_abci::logging::{LogBuilder, LogConfig, LogDestination, Loggers};
 use drive_abci::metrics::{Prometheus, DEFAULT_PROMETHEUS_PORT};
+use drive_abci::platform_types::platform::Platform;
 use drive_abci::rpc::core::DefaultCoreRPC;
+use drive_abci::tools;
 use itertools::Itertools;
-use std::fs::remove_file;
-use std::path::PathBuf;
+use std::fs::{remove_file, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use tokio::runtime::Builder;
 use tokio::signal::unix::{signal, SignalKind};
@@ -46,6 +52,77 @@ enum Commands {
     /// by creating `.fsck` file in database directory (`DB_PATH`).
     #[command()]
     Verify,
+
+    /// Dump state as JSON.
+    ///
+    /// The database at `DB_PATH` is not modified, as a copy of it is opened. The node must be
+    /// stopped for the copy to be consistent.
+    #[command(subcommand)]
+    Inspect(InspectCommand),
+
+    /// Export the state of the last committed block to a snapshot file.
+    ///
+    /// The database at `DB_PATH` is not modified, as a copy of it is opened. The node must be
+    /// stopped for the copy to be consistent.
+    #[command()]
+    Export {
+        /// Path of the snapshot file to create.
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        path: PathBuf,
+    },
+
+    /// Restore a snapshot file into an empty database at `DB_PATH`.
+    ///
+    /// Chunks are verified against the app hash of the snapshot, like during state sync.
+    #[command()]
+    Import {
+        /// Path of the snapshot file.
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        path: PathBuf,
+        /// Hex encoded app hash the snapshot is required to have.
+        #[arg(long)]
+        app_hash: Option<String>,
+    },
+
+    /// Re-execute recorded blocks against a copy of the state.
+    ///
+    /// Reads length-delimited `Request` messages recorded from Tenderdash and reports the
+    /// first block whose app hash differs from the recorded one. The database at `DB_PATH`
+    /// is not modified. Dash Core must be reachable, as block execution reads from it.
+    #[command()]
+    Replay {
+        /// Path of the recorded requests.
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        path: PathBuf,
+        /// Directory to copy the state to, must not exist. Defaults to a temporary directory.
+        #[arg(long, value_hint = clap::ValueHint::DirPath)]
+        workdir: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum InspectCommand {
+    /// Dump an identity.
+    Identity {
+        /// Base58 encoded identity id.
+        id: String,
+    },
+    /// Dump a data contract.
+    Contract {
+        /// Base58 encoded data contract id.
+        id: String,
+    },
+    /// Dump a document.
+    Document {
+        /// Base58 encoded data contract id.
+        contract_id: String,
+        /// Name of the document type.
+        document_type: String,
+        /// Base58 encoded document id.
+        id: String,
+    },
+    /// Dump the platform state of the last committed block.
+    State,
 }
 
 /// Server that accepts connections from Tenderdash, and
@@ -107,6 +184,10 @@ impl Cli {
             Commands::Config => dump_config(&config)?,
             Commands::Status => check_status(&config)?,
             Commands::Verify => verify_grovedb(&config.db_path, true)?,
+            Commands::Inspect(command) => inspect(&config, command)?,
+            Commands::Export { path } => export_snapshot(&config, &path)?,
+            Commands::Import { path, app_hash } => import_snapshot(&config, &path, app_hash)?,
+            Commands::Replay { path, workdir } => replay(&config, &path, workdir)?,
         };
 
         Ok(())
@@ -295,6 +376,156 @@ fn verify_grovedb(db_path: &PathBuf, force: bool) -> Result<(), String> {
     }
 }
 
+fn parse_identifier(value: &str) -> Result<Identifier, String> {
+    Identifier::from_string(value, Encoding::Base58)
+        .map_err(|e| format!("invalid identifier {}: {}", value, e))
+}
+
+/// Print state of the database at `DB_PATH` as JSON.
+fn inspect(config: &PlatformConfig, command: InspectCommand) -> Result<(), String> {
+    let drive = tools::open_drive(&config.db_path, &config.drive).map_err(|e| e.to_string())?;
+    let platform_version = PlatformVersion::latest();
+
+    let value = match command {
+        InspectCommand::Identity { id } => {
+            tools::inspect::inspect_identity(&drive, parse_identifier(&id)?, platform_version)
+        }
+        InspectCommand::Contract { id } => {
+            tools::inspect::inspect_data_contract(&drive, parse_identifier(&id)?, platform_version)
+        }
+        InspectCommand::Document {
+            contract_id,
+            document_type,
+            id,
+        } => tools::inspect::inspect_document(
+            &drive,
+            parse_identifier(&contract_id)?,
+            &document_type,
+            parse_identifier(&id)?,
+            platform_version,
+        ),
+        InspectCommand::State => tools::load_platform_state(&drive)
+            .map(|state| state.map(|state| tools::inspect::inspect_platform_state(&state))),
+    }
+    .map_err(|e| e.to_string())?
+    .ok_or("not found".to_string())?;
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
+
+/// Export the last committed block of the database at `DB_PATH` to a snapshot file.
+fn export_snapshot(config: &PlatformConfig, path: &Path) -> Result<(), String> {
+    let drive = tools::open_drive(&config.db_path, &config.drive).map_err(|e| e.to_string())?;
+
+    let mut file = BufWriter::new(File::create(path).map_err(|e| e.to_string())?);
+
+    let header = tools::snapshot_file::export_snapshot(&drive, &config.drive, &mut file)
+        .map_err(|e| e.to_string())?;
+
+    file.flush().map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        height = header.height,
+        app_hash = hex::encode(header.app_hash),
+        chunks = header.chunks,
+        "snapshot exported to {}",
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Restore a snapshot file into the database at `DB_PATH`.
+fn import_snapshot(
+    config: &PlatformConfig,
+    path: &Path,
+    app_hash: Option<String>,
+) -> Result<(), String> {
+    let expected_app_hash = app_hash
+        .map(|app_hash| {
+            hex::decode(&app_hash)
+                .ok()
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .ok_or(format!("invalid app hash {}", app_hash))
+        })
+        .transpose()?;
+
+    let platform = Platform::<DefaultCoreRPC>::open(&config.db_path, Some(config.clone()))
+        .map_err(|e| e.to_string())?;
+
+    let mut file = BufReader::new(File::open(path).map_err(|e| e.to_string())?);
+
+    let header = tools::snapshot_file::import_snapshot(&platform, &mut file, expected_app_hash)
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        height = header.height,
+        app_hash = hex::encode(header.app_hash),
+        "snapshot imported from {}",
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Replay recorded blocks against a copy of the database at `DB_PATH`.
+fn replay(config: &PlatformConfig, path: &Path, workdir: Option<PathBuf>) -> Result<(), String> {
+    let requests =
+        tools::replay::read_recorded_requests(&std::fs::read(path).map_err(|e| e.to_string())?)
+            .map_err(|e| e.to_string())?;
+
+    let tempdir = match workdir {
+        Some(_) => None,
+        None => Some(tools::tempdir_next_to(&config.db_path).map_err(|e| e.to_string())?),
+    };
+
+    let replay_db_path = match (&workdir, &tempdir) {
+        (Some(workdir), _) => workdir.clone(),
+        (None, Some(tempdir)) => tempdir.path().join("db"),
+        (None, None) => unreachable!("a temporary directory is created without a workdir"),
+    };
+
+    tools::copy_database(&config.db_path, &replay_db_path).map_err(|e| e.to_string())?;
+
+    // Replayed blocks must not take snapshots or historical states of the original node
+    let replay_config = PlatformConfig {
+        db_path: replay_db_path,
+        snapshot_interval: 0,
+        historical_state_retention_blocks: 0,
+        ..config.clone()
+    };
+
+    let platform =
+        Platform::<DefaultCoreRPC>::open(&replay_config.db_path, Some(replay_config.clone()))
+            .map_err(|e| e.to_string())?;
+
+    let outcome = tools::replay::replay_blocks(&platform, requests).map_err(|e| e.to_string())?;
+
+    match outcome.divergence {
+        Some(divergence) => Err(format!(
+            "app hash diverged at height {} round {} after {} blocks: expected {}, got {}",
+            divergence.height,
+            divergence.round,
+            outcome.blocks_replayed,
+            hex::encode(divergence.expected_app_hash),
+            hex::encode(divergence.app_hash)
+        )),
+        None => {
+            tracing::info!(
+                blocks = outcome.blocks_replayed,
+                "replay finished without divergence"
+            );
+
+            Ok(())
+        }
+    }
+}
+
 fn load_config(path: &Option<PathBuf>) -> PlatformConfig {
     if let Some(path) = path {
         if let Err(e) = dotenvy::from_path(path) {
@@ -20,6 +20,7 @@ use dpp::serialization::PlatformSerializable;
 use dpp::state_transition::StateTransition;
 use dpp::util::deserializer::ProtocolVersion;
 use tenderdash_abci::proto::abci::response_verify_vote_extension::VerifyStatus;
+use tenderdash_abci::proto::abci::tx_record::TxAction;
 use tenderdash_abci::proto::abci::{CommitInfo, ExecTxResult, RequestExtendVote, RequestFinalizeBlock, RequestPrepareProposal, RequestProcessProposal, RequestVerifyVoteExtension, ResponsePrepareProposal, ValidatorSetUpdate};
 use tenderdash_abci::proto::google::protobuf::Timestamp;
 use tenderdash_abci::proto::serializers::timestamp::ToMilis;
@@ -122,8 +123,8 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
             quorum_hash: current_quorum.quorum_hash.to_byte_array().to_vec(),
         };
 
-        let response_prepare_proposal = self
-            .prepare_proposal(request_prepare_proposal)
+        let (response_prepare_proposal, removed_tx_results) = self
+            .prepare_proposal_with_removed_tx_results(request_prepare_proposal)
             .unwrap_or_else(|e| {
                 panic!(
                     "should prepare and process block #{} at time #{} : {:?}",
@@ -143,7 +144,16 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
             core_height = core_chain_lock_update.core_block_height;
         }
 
-        tx_results.iter().try_for_each(|tx_result| {
+        // Results of the transactions kept in the block, followed by the removed ones
+        let mut tx_results: Vec<(Vec<u8>, ExecTxResult)> = tx_records
+            .iter()
+            .filter(|record| record.action == TxAction::Unmodified as i32)
+            .map(|record| record.tx.clone())
+            .zip(tx_results)
+            .chain(removed_tx_results)
+            .collect();
+
+        tx_results.iter().try_for_each(|(_, tx_result)| {
             if tx_result.code > 0 && !expect_validation_errors.contains(&tx_result.code) {
                 Err(Error::Abci(AbciError::GenericWithCode(tx_result.code)))
             } else {
@@ -151,7 +161,19 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
             }
         })?;
 
-        let state_transaction_results = state_transitions.into_iter().zip(tx_results).collect();
+        // Results in the order the state transitions were submitted
+        let state_transaction_results = state_transitions
+            .into_iter()
+            .zip(serialized_state_transitions.iter())
+            .filter_map(|(state_transition, serialized_state_transition)| {
+                let position = tx_results
+                    .iter()
+                    .position(|(tx, _)| tx == serialized_state_transition)?;
+                let (_, tx_result) = tx_results.remove(position);
+
+                Some((state_transition, tx_result))
+            })
+            .collect();
 
         // PROCESS
 
@@ -211,7 +233,12 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
                 )
             });
 
-        let tx_order_for_finalize_block = tx_records.into_iter().map(|record| record.tx).collect();
+        // Removed and delayed transactions are not part of the block
+        let tx_order_for_finalize_block = tx_records
+            .into_iter()
+            .filter(|record| record.action == TxAction::Unmodified as i32)
+            .map(|record| record.tx)
+            .collect();
 
         let request_extend_vote = RequestExtendVote {
             hash: block_header_hash.to_vec(),
@@ -3,6 +3,7 @@ use crate::platform_types::state_transition_execution_result::StateTransitionExe
 use dpp::util::deserializer::ProtocolVersion;
 use dpp::validation::SimpleValidationResult;
 use tenderdash_abci::proto::abci::ValidatorSetUpdate;
+use tenderdash_abci::proto::types::ConsensusParams;
 
 /// The outcome of the block execution, either by prepare proposal, or process proposal
 #[derive(Clone)]
@@ -15,6 +16,8 @@ pub struct BlockExecutionOutcome {
     /// The changes to the validator set
     // TODO We should use another DTO, only abci module should deal with Tenderdash proto structures
     pub validator_set_update: Option<ValidatorSetUpdate>,
+    /// The changes to the consensus params made by feature flags
+    pub consensus_param_updates: Option<ConsensusParams>,
     /// Current block protocol version
     pub protocol_version: ProtocolVersion,
 }
@@ -0,0 +1,155 @@
+use dapi_grpc::platform::v0::platform_event::{
+    self, DocumentChanged, DocumentDeleted, IdentityBalanceChanged, IdentityKeyAdded,
+    IdentityKeyDisabled,
+};
+use dapi_grpc::platform::v0::PlatformEvent;
+use dpp::fee::Credits;
+use dpp::identity::{KeyID, TimestampMillis};
+use dpp::prelude::Identifier;
+
+/// A document that was created or replaced
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentChange {
+    /// The data contract of the document
+    pub data_contract_id: Identifier,
+    /// The document type of the document
+    pub document_type_name: String,
+    /// The document id
+    pub document_id: Identifier,
+    /// The owner of the document
+    pub owner_id: Identifier,
+    /// The document serialized for its document type
+    pub serialized_document: Vec<u8>,
+}
+
+/// A change of platform state made by a state transition
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangeFeedEvent {
+    /// A document was created
+    DocumentCreated(DocumentChange),
+    /// A document was replaced by a new revision
+    DocumentReplaced(DocumentChange),
+    /// A document was deleted
+    DocumentDeleted {
+        /// The data contract of the document
+        data_contract_id: Identifier,
+        /// The document type of the document
+        document_type_name: String,
+        /// The document id
+        document_id: Identifier,
+    },
+    /// The balance of an identity changed
+    IdentityBalanceChanged {
+        /// The identity id
+        identity_id: Identifier,
+        /// The balance after the change
+        balance: Credits,
+    },
+    /// A key was added to an identity
+    IdentityKeyAdded {
+        /// The identity id
+        identity_id: Identifier,
+        /// The key id
+        key_id: KeyID,
+        /// The serialized public key
+        serialized_public_key: Vec<u8>,
+    },
+    /// A key of an identity was disabled
+    IdentityKeyDisabled {
+        /// The identity id
+        identity_id: Identifier,
+        /// The key id
+        key_id: KeyID,
+        /// When the key was disabled
+        disabled_at: TimestampMillis,
+    },
+}
+
+impl ChangeFeedEvent {
+    /// The data contract and the document type name if this is a document event
+    pub fn document_type(&self) -> Option<(&Identifier, &str)> {
+        match self {
+            ChangeFeedEvent::DocumentCreated(change)
+            | ChangeFeedEvent::DocumentReplaced(change) => {
+                Some((&change.data_contract_id, change.document_type_name.as_str()))
+            }
+            ChangeFeedEvent::DocumentDeleted {
+                data_contract_id,
+                document_type_name,
+                ..
+            } => Some((data_contract_id, document_type_name.as_str())),
+            _ => None,
+        }
+    }
+
+    /// The identity if this is an identity event
+    pub fn identity_id(&self) -> Option<&Identifier> {
+        match self {
+            ChangeFeedEvent::IdentityBalanceChanged { identity_id, .. }
+            | ChangeFeedEvent::IdentityKeyAdded { identity_id, .. }
+            | ChangeFeedEvent::IdentityKeyDisabled { identity_id, .. } => Some(identity_id),
+            _ => None,
+        }
+    }
+}
+
+impl From<DocumentChange> for DocumentChanged {
+    fn from(value: DocumentChange) -> Self {
+        DocumentChanged {
+            data_contract_id: value.data_contract_id.to_vec(),
+            document_type: value.document_type_name,
+            document_id: value.document_id.to_vec(),
+            owner_id: value.owner_id.to_vec(),
+            document: value.serialized_document,
+        }
+    }
+}
+
+impl From<ChangeFeedEvent> for PlatformEvent {
+    fn from(value: ChangeFeedEvent) -> Self {
+        let event = match value {
+            ChangeFeedEvent::DocumentCreated(change) => {
+                platform_event::Event::DocumentCreated(change.into())
+            }
+            ChangeFeedEvent::DocumentReplaced(change) => {
+                platform_event::Event::DocumentReplaced(change.into())
+            }
+            ChangeFeedEvent::DocumentDeleted {
+                data_contract_id,
+                document_type_name,
+                document_id,
+            } => platform_event::Event::DocumentDeleted(DocumentDeleted {
+                data_contract_id: data_contract_id.to_vec(),
+                document_type: document_type_name,
+                document_id: document_id.to_vec(),
+            }),
+            ChangeFeedEvent::IdentityBalanceChanged {
+                identity_id,
+                balance,
+            } => platform_event::Event::IdentityBalanceChanged(IdentityBalanceChanged {
+                identity_id: identity_id.to_vec(),
+                balance,
+            }),
+            ChangeFeedEvent::IdentityKeyAdded {
+                identity_id,
+                key_id,
+                serialized_public_key,
+            } => platform_event::Event::IdentityKeyAdded(IdentityKeyAdded {
+                identity_id: identity_id.to_vec(),
+                key_id,
+                public_key: serialized_public_key,
+            }),
+            ChangeFeedEvent::IdentityKeyDisabled {
+                identity_id,
+                key_id,
+                disabled_at,
+            } => platform_event::Event::IdentityKeyDisabled(IdentityKeyDisabled {
+                identity_id: identity_id.to_vec(),
+                key_id,
+                disabled_at,
+            }),
+        };
+
+        PlatformEvent { event: Some(event) }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error::query::QueryError;
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
+use dapi_grpc::platform::v0::subscribe_platform_events_request::SubscribePlatformEventsRequestV0;
+use dpp::prelude::Identifier;
+
+/// Selects the change feed events a subscriber is interested in.
+///
+/// An empty filter selects all events. Otherwise document events are selected by the data
+/// contract and document type filters, and identity events by the identity filter.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangeFeedFilter {
+    /// Data contracts of the document events
+    pub data_contract_ids: Vec<Identifier>,
+    /// Document types of the document events
+    pub document_type_names: Vec<String>,
+    /// Identities of the identity events
+    pub identity_ids: Vec<Identifier>,
+}
+
+impl ChangeFeedFilter {
+    fn filters_documents(&self) -> bool {
+        !self.data_contract_ids.is_empty() || !self.document_type_names.is_empty()
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.filters_documents() && self.identity_ids.is_empty()
+    }
+
+    /// Is the event selected by this filter
+    pub fn matches(&self, event: &ChangeFeedEvent) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+
+        if let Some((data_contract_id, document_type_name)) = event.document_type() {
+            return self.filters_documents()
+                && (self.data_contract_ids.is_empty()
+                    || self.data_contract_ids.contains(data_contract_id))
+                && (self.document_type_names.is_empty()
+                    || self
+                        .document_type_names
+                        .iter()
+                        .any(|name| name == document_type_name));
+        }
+
+        event
+            .identity_id()
+            .is_some_and(|identity_id| self.identity_ids.contains(identity_id))
+    }
+}
+
+impl TryFrom<SubscribePlatformEventsRequestV0> for ChangeFeedFilter {
+    type Error = QueryError;
+
+    fn try_from(value: SubscribePlatformEventsRequestV0) -> Result<Self, Self::Error> {
+        let SubscribePlatformEventsRequestV0 {
+            data_contract_ids,
+            document_types,
+            identity_ids,
+        } = value;
+
+        let to_identifiers = |ids: Vec<Vec<u8>>, name: &str| {
+            ids.into_iter()
+                .map(|id| {
+                    Identifier::try_from(id).map_err(|_| {
+                        QueryError::InvalidArgument(format!(
+                            "{} must be valid identifiers (32 bytes long)",
+                            name
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<Identifier>, QueryError>>()
+        };
+
+        Ok(ChangeFeedFilter {
+            data_contract_ids: to_identifiers(data_contract_ids, "data contract ids")?,
+            document_type_names: document_types,
+            identity_ids: to_identifiers(identity_ids, "identity ids")?,
+        })
+    }
+}
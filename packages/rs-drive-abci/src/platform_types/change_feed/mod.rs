@@ -0,0 +1,220 @@
+/// Change feed events
+pub mod event;
+/// Change feed subscription filters
+pub mod filter;
+
+use crate::platform_types::change_feed::event::ChangeFeedEvent;
+use crate::platform_types::change_feed::filter::ChangeFeedFilter;
+use dapi_grpc::platform::v0::subscribe_platform_events_response::{
+    self, SubscribePlatformEventsResponseV0,
+};
+use dapi_grpc::platform::v0::SubscribePlatformEventsResponse;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+/// How many blocks a subscriber can fall behind before it starts missing events
+pub const CHANGE_FEED_CAPACITY: usize = 100;
+
+/// The change feed events of a single block
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockChangeFeed {
+    /// Block height
+    pub height: u64,
+    /// Consensus round of the executed block proposal
+    pub round: u32,
+    /// Block time
+    pub time_ms: u64,
+    /// Events in the order state transitions were executed
+    pub events: Vec<ChangeFeedEvent>,
+}
+
+impl BlockChangeFeed {
+    /// The subscription response with the events selected by `filter`, `None` if no event
+    /// of the block is selected
+    pub fn to_subscription_response(
+        &self,
+        filter: &ChangeFeedFilter,
+    ) -> Option<SubscribePlatformEventsResponse> {
+        let events: Vec<_> = self
+            .events
+            .iter()
+            .filter(|event| filter.matches(event))
+            .cloned()
+            .map(Into::into)
+            .collect();
+
+        if events.is_empty() {
+            return None;
+        }
+
+        Some(SubscribePlatformEventsResponse {
+            version: Some(subscribe_platform_events_response::Version::V0(
+                SubscribePlatformEventsResponseV0 {
+                    height: self.height,
+                    time_ms: self.time_ms,
+                    events,
+                },
+            )),
+        })
+    }
+}
+
+/// Publishes the changes made by state transitions once their block is committed
+pub struct ChangeFeed {
+    sender: broadcast::Sender<Arc<BlockChangeFeed>>,
+    /// Events of the block proposal we executed last, published if it gets committed
+    staged: Mutex<Option<BlockChangeFeed>>,
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(CHANGE_FEED_CAPACITY);
+
+        Self {
+            sender,
+            staged: Mutex::new(None),
+        }
+    }
+}
+
+impl ChangeFeed {
+    /// Receive the events of every block committed from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<BlockChangeFeed>> {
+        self.sender.subscribe()
+    }
+
+    /// Whether anybody receives the published events
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    /// Keep the events of an executed block proposal until the block is committed.
+    ///
+    /// Events of a previously executed proposal are discarded, as only the last executed
+    /// proposal can be finalized.
+    pub(crate) fn stage(&self, block_change_feed: BlockChangeFeed) {
+        self.staged.lock().unwrap().replace(block_change_feed);
+    }
+
+    /// Publish the staged events if they belong to the block committed at `committed_height`
+    /// and `committed_round`.
+    ///
+    /// Proposals of several rounds can be executed at the same height, so the round tells
+    /// whether the staged events were made by the committed proposal.
+    pub(crate) fn publish_staged(&self, committed_height: u64, committed_round: u32) {
+        let Some(block_change_feed) = self.staged.lock().unwrap().take() else {
+            return;
+        };
+
+        if block_change_feed.height != committed_height
+            || block_change_feed.round != committed_round
+        {
+            tracing::warn!(
+                staged_height = block_change_feed.height,
+                staged_round = block_change_feed.round,
+                committed_height,
+                committed_round,
+                "discarding change feed events of a block that was not committed"
+            );
+            return;
+        }
+
+        // An error only means there are no subscribers at the moment
+        let _ = self.sender.send(Arc::new(block_change_feed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::prelude::Identifier;
+
+    fn block(height: u64, round: u32) -> BlockChangeFeed {
+        BlockChangeFeed {
+            height,
+            round,
+            time_ms: 1000 * height,
+            events: vec![
+                ChangeFeedEvent::DocumentDeleted {
+                    data_contract_id: Identifier::new([1; 32]),
+                    document_type_name: "note".to_string(),
+                    document_id: Identifier::new([2; 32]),
+                },
+                ChangeFeedEvent::IdentityBalanceChanged {
+                    identity_id: Identifier::new([3; 32]),
+                    balance: 10,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn should_publish_only_committed_block() {
+        let change_feed = ChangeFeed::default();
+        let mut receiver = change_feed.subscribe();
+
+        change_feed.stage(block(1, 0));
+        change_feed.publish_staged(2, 0);
+
+        assert!(receiver.try_recv().is_err());
+
+        // The proposal of another round was committed at the same height
+        change_feed.stage(block(2, 0));
+        change_feed.publish_staged(2, 1);
+
+        assert!(receiver.try_recv().is_err());
+
+        change_feed.stage(block(2, 1));
+        change_feed.publish_staged(2, 1);
+
+        let published = receiver.try_recv().expect("expected published block");
+        assert_eq!(published.as_ref(), &block(2, 1));
+    }
+
+    #[test]
+    fn should_select_events_by_filter() {
+        let block = block(1, 0);
+
+        let all = block
+            .to_subscription_response(&ChangeFeedFilter::default())
+            .expect("expected events");
+        let Some(subscribe_platform_events_response::Version::V0(all)) = all.version else {
+            panic!("expected v0 response");
+        };
+        assert_eq!(all.events.len(), 2);
+
+        let by_identity = ChangeFeedFilter {
+            identity_ids: vec![Identifier::new([3; 32])],
+            ..Default::default()
+        };
+        let selected = block
+            .to_subscription_response(&by_identity)
+            .expect("expected events");
+        let Some(subscribe_platform_events_response::Version::V0(selected)) = selected.version
+        else {
+            panic!("expected v0 response");
+        };
+        assert_eq!(selected.events, vec![block.events[1].clone().into()]);
+
+        let by_other_document_type = ChangeFeedFilter {
+            data_contract_ids: vec![Identifier::new([1; 32])],
+            document_type_names: vec!["profile".to_string()],
+            ..Default::default()
+        };
+        assert!(block
+            .to_subscription_response(&by_other_document_type)
+            .is_none());
+    }
+
+    #[test]
+    fn should_have_subscribers_only_while_receivers_are_alive() {
+        let change_feed = ChangeFeed::default();
+        assert!(!change_feed.has_subscribers());
+
+        let receiver = change_feed.subscribe();
+        assert!(change_feed.has_subscribers());
+
+        drop(receiver);
+        assert!(!change_feed.has_subscribers());
+    }
+}
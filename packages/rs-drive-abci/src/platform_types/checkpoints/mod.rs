@@ -0,0 +1,108 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates a GroveDB checkpoint of the committed state in a `height` named directory
+/// of `checkpoints_path`.
+///
+/// A checkpoint at the same height can only be left over from a previous run, so it's replaced.
+/// Its drive must be closed before calling this function.
+///
+/// Errors are reported with the `error` variant of the caller.
+pub(crate) fn create_checkpoint(
+    drive: &Drive,
+    checkpoints_path: &Path,
+    height: u64,
+    error: fn(String) -> ExecutionError,
+) -> Result<PathBuf, Error> {
+    let checkpoint_path = checkpoints_path.join(height.to_string());
+
+    fs::create_dir_all(checkpoints_path).map_err(|e| {
+        Error::Execution(error(format!(
+            "can't create checkpoints directory {}: {}",
+            checkpoints_path.display(),
+            e
+        )))
+    })?;
+
+    if checkpoint_path.exists() {
+        fs::remove_dir_all(&checkpoint_path).map_err(|e| {
+            Error::Execution(error(format!(
+                "can't remove stale checkpoint {}: {}",
+                checkpoint_path.display(),
+                e
+            )))
+        })?;
+    }
+
+    drive
+        .grove
+        .create_checkpoint(&checkpoint_path)
+        .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+    Ok(checkpoint_path)
+}
+
+/// Heights and paths of the checkpoints found in `checkpoints_path`, in ascending order of height.
+///
+/// Entries not named after a height are ignored.
+pub(crate) fn list_checkpoints(
+    checkpoints_path: &Path,
+    error: fn(String) -> ExecutionError,
+) -> Result<Vec<(u64, PathBuf)>, Error> {
+    if !checkpoints_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(checkpoints_path).map_err(|e| {
+        Error::Execution(error(format!(
+            "can't read checkpoints directory {}: {}",
+            checkpoints_path.display(),
+            e
+        )))
+    })?;
+
+    let mut checkpoints: Vec<(u64, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let height = entry.file_name().to_str()?.parse::<u64>().ok()?;
+
+            Some((height, entry.path()))
+        })
+        .collect();
+
+    checkpoints.sort_by_key(|(height, _)| *height);
+
+    Ok(checkpoints)
+}
+
+/// Removes the checkpoints of `checkpoints_path` which heights are not retained.
+///
+/// Drives opened on removed checkpoints must be closed before calling this function.
+pub(crate) fn remove_checkpoints<F>(
+    checkpoints_path: &Path,
+    is_retained: F,
+    error: fn(String) -> ExecutionError,
+) -> Result<(), Error>
+where
+    F: Fn(u64) -> bool,
+{
+    for (height, path) in list_checkpoints(checkpoints_path, error)? {
+        if is_retained(height) {
+            continue;
+        }
+
+        fs::remove_dir_all(&path).map_err(|e| {
+            Error::Execution(error(format!(
+                "can't remove expired checkpoint {}: {}",
+                path.display(),
+                e
+            )))
+        })?;
+    }
+
+    Ok(())
+}
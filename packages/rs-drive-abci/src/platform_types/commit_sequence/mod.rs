@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// How long a reader waits before checking again whether a block commit is over
+const COMMIT_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Lets readers outside of block processing detect that the committed state changed while
+/// they were reading it, without ever blocking block processing.
+///
+/// The platform state is updated before the drive transaction is committed, so between the two
+/// a reader could see metadata of one block and data of another. The sequence is odd while
+/// a block is being finalized and committed, and even otherwise.
+#[derive(Debug, Default)]
+pub struct CommitSequence(AtomicU64);
+
+/// Marks a block commit in progress until dropped
+pub struct CommitGuard<'a>(&'a CommitSequence);
+
+impl Drop for CommitGuard<'_> {
+    fn drop(&mut self) {
+        self.0 .0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl CommitSequence {
+    /// Mark a block commit in progress, until the returned guard is dropped
+    pub fn begin_commit(&self) -> CommitGuard<'_> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        CommitGuard(self)
+    }
+
+    /// Run `read` until it isn't interleaved with a block commit.
+    ///
+    /// Returns `None` if no consistent read could be made before the deadline.
+    pub fn read_consistent<T>(&self, deadline: Instant, mut read: impl FnMut() -> T) -> Option<T> {
+        while Instant::now() < deadline {
+            let before = self.0.load(Ordering::SeqCst);
+
+            if before % 2 == 1 {
+                std::thread::sleep(COMMIT_POLL_INTERVAL);
+                continue;
+            }
+
+            let result = read();
+
+            if self.0.load(Ordering::SeqCst) == before {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_retry_read_interleaved_with_commit() {
+        let sequence = CommitSequence::default();
+        let deadline = Instant::now() + Duration::from_secs(1);
+
+        let mut attempts = 0;
+        let result = sequence.read_consistent(deadline, || {
+            attempts += 1;
+            if attempts == 1 {
+                // A block is committed while reading
+                drop(sequence.begin_commit());
+            }
+            attempts
+        });
+
+        assert_eq!(result, Some(2));
+    }
+
+    #[test]
+    fn should_give_up_while_commit_is_in_progress() {
+        let sequence = CommitSequence::default();
+        let deadline = Instant::now() + Duration::from_millis(10);
+
+        let _guard = sequence.begin_commit();
+
+        assert_eq!(sequence.read_consistent(deadline, || ()), None);
+    }
+}
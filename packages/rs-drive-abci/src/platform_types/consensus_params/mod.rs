@@ -0,0 +1,283 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use dpp::bincode::{Decode, Encode};
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::Value;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tenderdash_abci::proto::google::protobuf::Duration as ProtoDuration;
+use tenderdash_abci::proto::types;
+
+/// Tenderdash default max size of a block, in bytes
+pub const DEFAULT_BLOCK_MAX_BYTES: i64 = 22020096;
+
+/// Tenderdash default max gas of a block, -1 means unlimited
+pub const DEFAULT_BLOCK_MAX_GAS: i64 = -1;
+
+/// Tenderdash default max age of evidence, in blocks
+pub const DEFAULT_EVIDENCE_MAX_AGE_NUM_BLOCKS: i64 = 100000;
+
+/// Tenderdash default max age of evidence, in time
+pub const DEFAULT_EVIDENCE_MAX_AGE_DURATION: Duration = Duration::from_secs(48 * 60 * 60);
+
+/// Tenderdash default max size of the evidence of a block, in bytes
+pub const DEFAULT_EVIDENCE_MAX_BYTES: i64 = 1048576;
+
+/// Block size limits
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockParams {
+    /// Max size of a block, in bytes
+    pub max_bytes: i64,
+    /// Max sum of gas wanted in a proposed block
+    pub max_gas: i64,
+}
+
+impl Default for BlockParams {
+    fn default() -> Self {
+        BlockParams {
+            max_bytes: DEFAULT_BLOCK_MAX_BYTES,
+            max_gas: DEFAULT_BLOCK_MAX_GAS,
+        }
+    }
+}
+
+/// Limits of the validity of evidence of byzantine behaviour
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvidenceParams {
+    /// Max age of evidence, in blocks
+    pub max_age_num_blocks: i64,
+    /// Max age of evidence, in time
+    pub max_age_duration: Duration,
+    /// Max size of the evidence of a block, in bytes
+    pub max_bytes: i64,
+}
+
+impl Default for EvidenceParams {
+    fn default() -> Self {
+        EvidenceParams {
+            max_age_num_blocks: DEFAULT_EVIDENCE_MAX_AGE_NUM_BLOCKS,
+            max_age_duration: DEFAULT_EVIDENCE_MAX_AGE_DURATION,
+            max_bytes: DEFAULT_EVIDENCE_MAX_BYTES,
+        }
+    }
+}
+
+/// Version of the ABCI application
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionParams {
+    /// The ABCI application version
+    pub app_version: u64,
+}
+
+/// Tenderdash consensus params set by `updateConsensusParams` feature flag documents.
+///
+/// Tenderdash replaces a group of params as a whole, so groups are always complete. Fields a
+/// feature flag doesn't set keep their active value, or the Tenderdash default if the group was
+/// never set by a feature flag. A group is `None` until a feature flag sets it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsensusParams {
+    /// Block size limits
+    pub block: Option<BlockParams>,
+    /// Evidence limits
+    pub evidence: Option<EvidenceParams>,
+    /// Application version
+    pub version: Option<VersionParams>,
+}
+
+impl ConsensusParams {
+    /// Applies an `updateConsensusParams` document to the active params.
+    ///
+    /// Returns the groups changed by the document, which are the updates sent to Tenderdash.
+    pub fn apply_feature_flag(&mut self, document: &Document) -> Result<ConsensusParams, Error> {
+        let mut update = ConsensusParams::default();
+
+        if document.get("block").is_some() {
+            let mut block = self.block.clone().unwrap_or_default();
+
+            if let Some(max_bytes) = feature_flag_integer(document, "block.maxBytes")? {
+                block.max_bytes = max_bytes;
+            }
+            if let Some(max_gas) = feature_flag_integer(document, "block.maxGas")? {
+                block.max_gas = max_gas;
+            }
+
+            update.block = Some(block);
+        }
+
+        if document.get("evidence").is_some() {
+            let mut evidence = self.evidence.clone().unwrap_or_default();
+
+            if let Some(max_age_num_blocks) =
+                feature_flag_integer(document, "evidence.maxAgeNumBlocks")?
+            {
+                evidence.max_age_num_blocks = max_age_num_blocks;
+            }
+            if let Some(seconds) =
+                feature_flag_integer(document, "evidence.maxAgeDuration.seconds")?
+            {
+                let nanos = feature_flag_integer(document, "evidence.maxAgeDuration.nanos")?
+                    .unwrap_or_default();
+
+                // Both are validated to be positive by the document schema
+                evidence.max_age_duration = Duration::new(seconds as u64, nanos as u32);
+            }
+            if let Some(max_bytes) = feature_flag_integer(document, "evidence.maxBytes")? {
+                evidence.max_bytes = max_bytes;
+            }
+
+            update.evidence = Some(evidence);
+        }
+
+        if let Some(app_version) = feature_flag_integer(document, "version.appVersion")? {
+            update.version = Some(VersionParams {
+                app_version: app_version as u64,
+            });
+        }
+
+        if update.block.is_some() {
+            self.block = update.block.clone();
+        }
+        if update.evidence.is_some() {
+            self.evidence = update.evidence.clone();
+        }
+        if update.version.is_some() {
+            self.version = update.version.clone();
+        }
+
+        Ok(update)
+    }
+}
+
+fn feature_flag_integer(document: &Document, path: &str) -> Result<Option<i64>, Error> {
+    document
+        .get(path)
+        .map(Value::to_integer::<i64>)
+        .transpose()
+        .map_err(|e| {
+            Error::Execution(ExecutionError::CorruptedDriveResponse(format!(
+                "feature flag {} has an invalid {}: {}",
+                document.id(),
+                path,
+                e
+            )))
+        })
+}
+
+impl From<ConsensusParams> for types::ConsensusParams {
+    fn from(value: ConsensusParams) -> Self {
+        types::ConsensusParams {
+            block: value.block.map(|block| types::BlockParams {
+                max_bytes: block.max_bytes,
+                max_gas: block.max_gas,
+            }),
+            evidence: value.evidence.map(|evidence| types::EvidenceParams {
+                max_age_num_blocks: evidence.max_age_num_blocks,
+                max_age_duration: Some(ProtoDuration {
+                    seconds: evidence.max_age_duration.as_secs() as i64,
+                    nanos: evidence.max_age_duration.subsec_nanos() as i32,
+                }),
+                max_bytes: evidence.max_bytes,
+            }),
+            version: value.version.map(|version| types::VersionParams {
+                app_version: version.app_version,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::document::DocumentV0;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::Identifier;
+
+    fn feature_flag(properties: Value) -> Document {
+        DocumentV0 {
+            id: Identifier::new([1; 32]),
+            owner_id: Identifier::new([2; 32]),
+            properties: properties
+                .into_btree_string_map()
+                .expect("expected a map of properties"),
+            revision: None,
+            created_at: None,
+            updated_at: None,
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_keep_active_values_not_set_by_feature_flag() {
+        let mut consensus_params = ConsensusParams::default();
+
+        let update = consensus_params
+            .apply_feature_flag(&feature_flag(platform_value!({
+                "enableAtHeight": 10u64,
+                "block": { "maxBytes": 1000u64 },
+            })))
+            .expect("expected to apply feature flag");
+
+        assert_eq!(
+            update,
+            ConsensusParams {
+                block: Some(BlockParams {
+                    max_bytes: 1000,
+                    max_gas: DEFAULT_BLOCK_MAX_GAS,
+                }),
+                evidence: None,
+                version: None,
+            }
+        );
+
+        let update = consensus_params
+            .apply_feature_flag(&feature_flag(platform_value!({
+                "enableAtHeight": 20u64,
+                "block": { "maxGas": 50u64 },
+                "version": { "appVersion": 2u64 },
+            })))
+            .expect("expected to apply feature flag");
+
+        assert_eq!(
+            update.block,
+            Some(BlockParams {
+                max_bytes: 1000,
+                max_gas: 50,
+            })
+        );
+        assert_eq!(update.version, Some(VersionParams { app_version: 2 }));
+        assert_eq!(consensus_params, update);
+    }
+
+    #[test]
+    fn should_convert_evidence_duration() {
+        let mut consensus_params = ConsensusParams::default();
+
+        let update = consensus_params
+            .apply_feature_flag(&feature_flag(platform_value!({
+                "enableAtHeight": 10u64,
+                "evidence": { "maxAgeDuration": { "seconds": 60u64, "nanos": 5u64 } },
+            })))
+            .expect("expected to apply feature flag");
+
+        let evidence = types::ConsensusParams::from(update)
+            .evidence
+            .expect("expected evidence params");
+
+        assert_eq!(
+            evidence.max_age_num_blocks,
+            DEFAULT_EVIDENCE_MAX_AGE_NUM_BLOCKS
+        );
+        assert_eq!(
+            evidence.max_age_duration,
+            Some(ProtoDuration {
+                seconds: 60,
+                nanos: 5,
+            })
+        );
+    }
+}
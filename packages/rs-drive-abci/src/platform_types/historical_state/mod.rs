@@ -0,0 +1,104 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::checkpoints::list_checkpoints;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use drive::drive::config::DriveConfig;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::sync::Arc;
+
+/// The state of platform as it was right after a block was committed.
+///
+/// The drive is opened on a GroveDB checkpoint, so queries and proofs made against it
+/// match the app hash that was signed for that block. GroveDB can only be opened for writing,
+/// the checkpoint is kept unchanged by using this drive for queries only.
+pub struct HistoricalPlatformState {
+    /// Drive opened on the checkpoint of the block
+    pub drive: Drive,
+    /// Platform state after the block was committed
+    pub state: PlatformState,
+}
+
+impl HistoricalPlatformState {
+    /// Opens the checkpoint of a block together with the platform state saved in it
+    pub(crate) fn open_checkpoint<P: AsRef<Path>>(
+        checkpoint_path: P,
+        drive_config: DriveConfig,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, Error> {
+        let drive = Drive::open(checkpoint_path, Some(drive_config), platform_version)?;
+
+        let serialized_platform_state = drive
+            .grove
+            .get_aux(b"saved_state", None)
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?
+            .ok_or(Error::Execution(
+                ExecutionError::HistoricalStateStorageError(
+                    "checkpoint doesn't contain platform state".to_string(),
+                ),
+            ))?;
+
+        let state = PlatformState::deserialize_from_bytes_no_limit(&serialized_platform_state)?;
+
+        Ok(Self { drive, state })
+    }
+}
+
+impl Debug for HistoricalPlatformState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HistoricalPlatformState")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<C> Platform<C> {
+    /// Reopens checkpoints retained by a previous run, so heights committed before a restart
+    /// stay queryable.
+    ///
+    /// Checkpoints outside of the retention window of the current block are skipped,
+    /// they are removed when the next block is committed.
+    pub(crate) fn load_historical_states(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let retention_blocks = self.config.historical_state_retention_blocks;
+
+        if retention_blocks == 0 {
+            return Ok(());
+        }
+
+        let height = self.state.read().unwrap().last_block_height();
+        let first_retained_height = height.saturating_sub(retention_blocks - 1);
+
+        let checkpoints = list_checkpoints(
+            &self.config.historical_state_checkpoints_path,
+            ExecutionError::HistoricalStateStorageError,
+        )?;
+
+        let mut historical_states = self.historical_states.write().unwrap();
+
+        for (checkpoint_height, checkpoint_path) in checkpoints {
+            if checkpoint_height < first_retained_height || checkpoint_height > height {
+                continue;
+            }
+
+            let historical_state = HistoricalPlatformState::open_checkpoint(
+                &checkpoint_path,
+                self.config.drive.clone(),
+                platform_version,
+            )?;
+
+            historical_states.insert(checkpoint_height, Arc::new(historical_state));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+use dpp::consensus::state::state_transition::pending_state_transition_conflict_error::PendingStateTransitionConflictError;
+use dpp::fee::Credits;
+use dpp::platform_value::Bytes36;
+use dpp::prelude::{Identifier, Revision};
+use dpp::validation::SimpleConsensusValidationResult;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Part of platform state a pending state transition is going to change.
+///
+/// Two pending state transitions can't change the same part, as both were validated against
+/// the same committed state and the second one would fail once the first one is executed.
+/// Revisions and nonces are the exception: a state transition setting the next one after
+/// the latest pending one is executed right after it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PendingClaim {
+    /// The revision of an identity, changed by identity updates
+    IdentityRevision(Identifier),
+    /// A document, changed by document transitions
+    Document {
+        /// The data contract of the document
+        data_contract_id: Identifier,
+        /// The document type of the document
+        document_type_name: String,
+        /// The document id
+        document_id: Identifier,
+    },
+    /// An asset lock, spent by identity create and top up transitions
+    AssetLock(Bytes36),
+    /// The nonce of an identity, changed by credit transfers and withdrawals
+    IdentityNonce(Identifier),
+}
+
+/// What an unconfirmed state transition is going to change once executed
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PendingStateTransition {
+    /// The identity paying for the state transition
+    pub identity_id: Option<Identifier>,
+    /// Credits the paying identity needs to keep for the state transition
+    pub reserved_balance: Credits,
+    /// Claimed parts of the state, with the revision or nonce the state transition sets if any
+    pub claims: BTreeMap<PendingClaim, Option<Revision>>,
+}
+
+#[derive(Debug, Default)]
+struct MempoolState {
+    transitions: BTreeMap<[u8; 32], PendingStateTransition>,
+    reserved_balances: BTreeMap<Identifier, Credits>,
+    /// Pending state transitions of each claim with the revision or nonce they set,
+    /// in the order they were accepted
+    claims: BTreeMap<PendingClaim, Vec<([u8; 32], Option<Revision>)>>,
+}
+
+impl MempoolState {
+    /// The pending state transition a claim conflicts with, if any
+    fn conflicting_transition(
+        &self,
+        claim: &PendingClaim,
+        revision: Option<Revision>,
+    ) -> Option<[u8; 32]> {
+        let (pending_tx_hash, pending_revision) = self.claims.get(claim)?.last()?;
+
+        match (pending_revision, revision) {
+            (Some(pending_revision), Some(revision))
+                if pending_revision.checked_add(1) == Some(revision) =>
+            {
+                None
+            }
+            _ => Some(*pending_tx_hash),
+        }
+    }
+}
+
+/// Tracks state transitions accepted by `check_tx` that are not committed yet, so that
+/// transitions conflicting with them are rejected before they get into a block.
+///
+/// Tenderdash re-checks all remaining transitions after a block is committed, so the tracked
+/// transitions are cleared on commit and tracked again by the re-check.
+#[derive(Debug, Default)]
+pub struct Mempool {
+    state: Mutex<MempoolState>,
+}
+
+impl Mempool {
+    /// Track a state transition unless it conflicts with already tracked ones.
+    ///
+    /// A state transition setting the revision or nonce following the latest pending one
+    /// doesn't conflict with it.
+    ///
+    /// `committed_balance` is the balance of the paying identity in the committed state.
+    pub fn add(
+        &self,
+        tx_hash: [u8; 32],
+        transition: PendingStateTransition,
+        committed_balance: Option<Credits>,
+    ) -> SimpleConsensusValidationResult {
+        let mut state = self.state.lock().unwrap();
+
+        // The same state transition is checked again
+        if state.transitions.contains_key(&tx_hash) {
+            return SimpleConsensusValidationResult::default();
+        }
+
+        if let Some(identity_id) = transition.identity_id {
+            let reserved = state
+                .reserved_balances
+                .get(&identity_id)
+                .copied()
+                .unwrap_or_default();
+
+            if let Some(committed_balance) = committed_balance {
+                let available_balance = committed_balance.saturating_sub(reserved);
+
+                if available_balance < transition.reserved_balance {
+                    return SimpleConsensusValidationResult::new_with_error(
+                        IdentityInsufficientBalanceError::new(
+                            identity_id,
+                            available_balance,
+                            transition.reserved_balance,
+                        )
+                        .into(),
+                    );
+                }
+            }
+        }
+
+        for (claim, revision) in &transition.claims {
+            if let Some(pending_tx_hash) = state.conflicting_transition(claim, *revision) {
+                return SimpleConsensusValidationResult::new_with_error(
+                    PendingStateTransitionConflictError::new(pending_tx_hash).into(),
+                );
+            }
+        }
+
+        if let Some(identity_id) = transition.identity_id {
+            *state.reserved_balances.entry(identity_id).or_default() += transition.reserved_balance;
+        }
+
+        for (claim, revision) in &transition.claims {
+            state
+                .claims
+                .entry(claim.clone())
+                .or_default()
+                .push((tx_hash, *revision));
+        }
+
+        state.transitions.insert(tx_hash, transition);
+
+        SimpleConsensusValidationResult::default()
+    }
+
+    /// Does `revision` follow the latest revision or nonce set by pending state transitions
+    /// for the claim
+    pub fn follows_pending(&self, claim: &PendingClaim, revision: Revision) -> bool {
+        self.state
+            .lock()
+            .unwrap()
+            .claims
+            .get(claim)
+            .and_then(|pending| pending.last())
+            .and_then(|(_, pending_revision)| *pending_revision)
+            .is_some_and(|pending_revision| pending_revision.checked_add(1) == Some(revision))
+    }
+
+    /// Forget all tracked state transitions
+    pub fn clear(&self) {
+        *self.state.lock().unwrap() = MempoolState::default();
+    }
+
+    /// The number of tracked state transitions
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().transitions.len()
+    }
+
+    /// Are there no tracked state transitions
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::consensus::ConsensusError;
+
+    fn top_up_identity(
+        identity_id: Identifier,
+        reserved_balance: Credits,
+    ) -> PendingStateTransition {
+        PendingStateTransition {
+            identity_id: Some(identity_id),
+            reserved_balance,
+            claims: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn should_reserve_balance_across_pending_transitions() {
+        let mempool = Mempool::default();
+        let identity_id = Identifier::new([1; 32]);
+
+        assert!(mempool
+            .add([1; 32], top_up_identity(identity_id, 600), Some(1000))
+            .is_valid());
+
+        let result = mempool.add([2; 32], top_up_identity(identity_id, 600), Some(1000));
+        assert!(matches!(
+            result.errors.first(),
+            Some(ConsensusError::StateError(
+                dpp::consensus::state::state_error::StateError::IdentityInsufficientBalanceError(_)
+            ))
+        ));
+
+        // Checking the same transition again doesn't reserve twice
+        assert!(mempool
+            .add([1; 32], top_up_identity(identity_id, 600), Some(1000))
+            .is_valid());
+        assert_eq!(mempool.len(), 1);
+
+        mempool.clear();
+
+        assert!(mempool
+            .add([2; 32], top_up_identity(identity_id, 600), Some(1000))
+            .is_valid());
+    }
+
+    #[test]
+    fn should_reject_conflicting_document_transitions() {
+        let mempool = Mempool::default();
+        let claim = PendingClaim::Document {
+            data_contract_id: Identifier::new([1; 32]),
+            document_type_name: "note".to_string(),
+            document_id: Identifier::new([2; 32]),
+        };
+
+        let replace = PendingStateTransition {
+            claims: BTreeMap::from([(claim.clone(), Some(2))]),
+            ..Default::default()
+        };
+
+        assert!(mempool.add([1; 32], replace.clone(), None).is_valid());
+        assert_eq!(
+            mempool.add([2; 32], replace, None).errors,
+            vec![PendingStateTransitionConflictError::new([1; 32]).into()]
+        );
+    }
+
+    #[test]
+    fn should_accept_next_nonce_of_pending_transition() {
+        let mempool = Mempool::default();
+        let claim = PendingClaim::IdentityNonce(Identifier::new([1; 32]));
+
+        let transfer = |nonce| PendingStateTransition {
+            claims: BTreeMap::from([(claim.clone(), Some(nonce))]),
+            ..Default::default()
+        };
+
+        assert!(mempool.add([1; 32], transfer(1), None).is_valid());
+        assert!(mempool.follows_pending(&claim, 2));
+        assert!(mempool.add([2; 32], transfer(2), None).is_valid());
+
+        // The nonce is already used by a pending transition
+        let result = mempool.add([3; 32], transfer(2), None);
+        assert_eq!(
+            result.errors,
+            vec![PendingStateTransitionConflictError::new([2; 32]).into()]
+        );
+
+        // The nonce doesn't follow the latest pending one
+        assert!(!mempool.follows_pending(&claim, 4));
+        assert!(!mempool.add([4; 32], transfer(4), None).is_valid());
+
+        assert!(mempool.add([5; 32], transfer(3), None).is_valid());
+        assert_eq!(mempool.len(), 3);
+    }
+}
@@ -31,20 +31,34 @@
 pub mod block_execution_outcome;
 /// The block proposal
 pub mod block_proposal;
+/// Changes made by state transitions, published to subscribers
+pub mod change_feed;
+/// GroveDB checkpoints of committed blocks, kept by height
+pub(crate) mod checkpoints;
 /// A clean version of the the requst to finalize a block
 pub mod cleaned_abci_messages;
 /// The commit
 pub mod commit;
+/// Detection of block commits interleaved with reads
+pub mod commit_sequence;
+/// Tenderdash consensus params set by feature flags
+pub mod consensus_params;
 /// Epoch
 pub mod epoch_info;
+/// Retained platform state at past block heights, used by historical queries
+pub mod historical_state;
 /// Masternode
 pub mod masternode;
+/// Unconfirmed state transitions accepted by check tx
+pub mod mempool;
 /// Main platform structs, not versioned
 pub mod platform;
 /// Platform state
 pub mod platform_state;
 /// Required identity public key set for system identities
 pub mod required_identity_public_key_set;
+/// State sync snapshots served to and restored from other nodes
+pub mod snapshot;
 /// The state transition execution result as part of the block execution outcome
 pub mod state_transition_execution_result;
 /// System identity public keys
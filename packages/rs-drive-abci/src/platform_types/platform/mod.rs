@@ -8,15 +8,22 @@ use std::fmt::{Debug, Formatter};
 #[cfg(any(feature = "mocks", test))]
 use crate::rpc::core::MockCoreRPCLike;
 use drive::drive::defaults::PROTOCOL_VERSION;
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::RwLock;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::JoinHandle;
 
 use dashcore_rpc::dashcore::BlockHash;
 
 use crate::execution::types::block_execution_context::BlockExecutionContext;
+use crate::platform_types::change_feed::ChangeFeed;
+use crate::platform_types::commit_sequence::CommitSequence;
+use crate::platform_types::historical_state::HistoricalPlatformState;
+use crate::platform_types::mempool::Mempool;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::snapshot::PlatformSnapshot;
 use dpp::block::block_info::BlockInfo;
 use dpp::serialization::PlatformDeserializable;
 use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
@@ -39,6 +46,19 @@ pub struct Platform<C> {
     pub block_execution_context: RwLock<Option<BlockExecutionContext>>,
     /// Core RPC Client
     pub core_rpc: C,
+    /// Platform states retained at recent block heights, see
+    /// [`PlatformConfig::historical_state_retention_blocks`]
+    pub historical_states: RwLock<BTreeMap<u64, Arc<HistoricalPlatformState>>>,
+    /// Changes made by committed blocks, published to subscribers
+    pub change_feed: ChangeFeed,
+    /// State transitions accepted by check tx and not committed yet
+    pub mempool: Mempool,
+    /// State sync snapshots offered to other nodes, see [`PlatformConfig::snapshot_interval`]
+    pub snapshots: Arc<RwLock<BTreeMap<u64, Arc<PlatformSnapshot>>>>,
+    /// Block commits, so queries served outside of ABCI can read a consistent committed state
+    pub commit_sequence: CommitSequence,
+    /// The background build of the latest state sync snapshot
+    pub snapshot_build: Mutex<Option<JoinHandle<()>>>,
 }
 
 // @append_only
@@ -225,9 +245,10 @@ impl<C> Platform<C> {
         let platform_state =
             PlatformState::deserialize_from_bytes_no_limit(&serialized_platform_state)?;
 
-        PlatformVersion::set_current(PlatformVersion::get(
-            platform_state.current_protocol_version_in_consensus(),
-        )?);
+        let platform_version =
+            PlatformVersion::get(platform_state.current_protocol_version_in_consensus())?;
+
+        PlatformVersion::set_current(platform_version);
 
         let platform: Platform<C> = Platform {
             drive,
@@ -235,8 +256,20 @@ impl<C> Platform<C> {
             config,
             block_execution_context: RwLock::new(None),
             core_rpc,
+            historical_states: RwLock::new(BTreeMap::new()),
+            change_feed: ChangeFeed::default(),
+            mempool: Mempool::default(),
+            snapshots: Arc::new(RwLock::new(BTreeMap::new())),
+            commit_sequence: CommitSequence::default(),
+            snapshot_build: Mutex::new(None),
         };
 
+        // Historical queries are a convenience, failing to reopen retained states must not
+        // prevent the node from starting
+        if let Err(error) = platform.load_historical_states(platform_version) {
+            tracing::error!(?error, "can't load retained historical states");
+        }
+
         Ok(platform)
     }
 
@@ -264,6 +297,12 @@ impl<C> Platform<C> {
             config,
             block_execution_context: RwLock::new(None),
             core_rpc,
+            historical_states: RwLock::new(BTreeMap::new()),
+            change_feed: ChangeFeed::default(),
+            mempool: Mempool::default(),
+            snapshots: Arc::new(RwLock::new(BTreeMap::new())),
+            commit_sequence: CommitSequence::default(),
+            snapshot_build: Mutex::new(None),
         })
     }
 }
@@ -3,9 +3,10 @@ pub mod v0;
 
 use crate::error::Error;
 use crate::platform_types::platform_state::v0::{
-    PlatformStateForSavingV0, PlatformStateV0, PlatformStateV0Methods,
+    PlatformStateForSavingV0, PlatformStateForSavingV1, PlatformStateV0, PlatformStateV0Methods,
 };
 
+use crate::platform_types::consensus_params::ConsensusParams;
 use crate::platform_types::validator_set::ValidatorSet;
 use dashcore_rpc::dashcore_rpc_json::MasternodeListItem;
 use derive_more::From;
@@ -40,12 +41,15 @@ pub enum PlatformState {
 enum PlatformStateForSaving {
     /// Version 0
     V0(PlatformStateForSavingV0),
+    /// Version 1
+    V1(PlatformStateForSavingV1),
 }
 
 impl PlatformStateForSaving {
     pub fn current_protocol_version_in_consensus(&self) -> ProtocolVersion {
         match self {
             PlatformStateForSaving::V0(v0) => v0.current_protocol_version_in_consensus,
+            PlatformStateForSaving::V1(v1) => v1.current_protocol_version_in_consensus,
         }
     }
 }
@@ -169,11 +173,15 @@ impl TryFromPlatformVersioned<PlatformState> for PlatformStateForSaving {
                         let saving_v0: PlatformStateForSavingV0 = v0.try_into()?;
                         Ok(saving_v0.into())
                     }
+                    1 => {
+                        let saving_v1: PlatformStateForSavingV1 = v0.try_into()?;
+                        Ok(saving_v1.into())
+                    }
                     version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                         method:
                             "PlatformStateForSaving::try_from_platform_versioned(PlatformState)"
                                 .to_string(),
-                        known_versions: vec![0],
+                        known_versions: vec![0, 1],
                         received: version,
                     })),
                 }
@@ -205,6 +213,21 @@ impl TryFromPlatformVersioned<PlatformStateForSaving> for PlatformState {
                     }),
                 }
             }
+            PlatformStateForSaving::V1(v1) => {
+                match platform_version.drive_abci.structs.platform_state_structure {
+                    0 => {
+                        let platform_state_v0: PlatformStateV0 = v1.into();
+                        Ok(platform_state_v0.into())
+                    }
+                    version => Err(ProtocolError::UnknownVersionMismatch {
+                        method:
+                            "PlatformState::try_from_platform_versioned(PlatformStateForSaving)"
+                                .to_string(),
+                        known_versions: vec![0],
+                        received: version,
+                    }),
+                }
+            }
         }
     }
 }
@@ -348,6 +371,12 @@ impl PlatformStateV0Methods for PlatformState {
         }
     }
 
+    fn consensus_params(&self) -> &ConsensusParams {
+        match self {
+            PlatformState::V0(v0) => &v0.consensus_params,
+        }
+    }
+
     fn set_last_committed_block_info(&mut self, info: Option<ExtendedBlockInfo>) {
         match self {
             PlatformState::V0(v0) => v0.set_last_committed_block_info(info),
@@ -450,6 +479,12 @@ impl PlatformStateV0Methods for PlatformState {
         }
     }
 
+    fn consensus_params_mut(&mut self) -> &mut ConsensusParams {
+        match self {
+            PlatformState::V0(v0) => v0.consensus_params_mut(),
+        }
+    }
+
     fn take_next_validator_set_quorum_hash(&mut self) -> Option<QuorumHash> {
         match self {
             PlatformState::V0(v0) => v0.take_next_validator_set_quorum_hash(),
@@ -468,3 +503,64 @@ impl PlatformStateV0Methods for PlatformState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_types::consensus_params::{BlockParams, VersionParams};
+
+    #[test]
+    fn should_save_consensus_params() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform_state = PlatformState::default_with_protocol_versions(
+            platform_version.protocol_version,
+            platform_version.protocol_version,
+        );
+
+        let consensus_params = ConsensusParams {
+            block: Some(BlockParams {
+                max_bytes: 1024,
+                max_gas: 1000,
+            }),
+            evidence: None,
+            version: Some(VersionParams { app_version: 1 }),
+        };
+        *platform_state.consensus_params_mut() = consensus_params.clone();
+
+        let bytes = platform_state
+            .serialize_to_bytes()
+            .expect("expected to serialize platform state");
+        let restored_platform_state = PlatformState::deserialize_from_bytes_no_limit(&bytes)
+            .expect("expected to deserialize platform state");
+
+        assert_eq!(
+            restored_platform_state.consensus_params(),
+            &consensus_params
+        );
+    }
+
+    #[test]
+    fn should_load_platform_state_saved_without_consensus_params() {
+        let platform_version = PlatformVersion::latest();
+        let platform_state = PlatformStateV0::default_with_protocol_versions(
+            platform_version.protocol_version,
+            platform_version.protocol_version,
+        );
+
+        let platform_state_for_saving: PlatformStateForSaving =
+            PlatformStateForSavingV0::try_from(platform_state)
+                .expect("expected to convert platform state")
+                .into();
+        let config = config::standard().with_big_endian().with_no_limit();
+        let bytes = bincode::encode_to_vec(platform_state_for_saving, config)
+            .expect("expected to encode platform state");
+
+        let restored_platform_state = PlatformState::deserialize_from_bytes_no_limit(&bytes)
+            .expect("expected to deserialize platform state");
+
+        assert_eq!(
+            restored_platform_state.consensus_params(),
+            &ConsensusParams::default()
+        );
+    }
+}
@@ -13,6 +13,7 @@ use dpp::platform_value::Bytes32;
 use drive::dpp::util::deserializer::ProtocolVersion;
 use indexmap::IndexMap;
 
+use crate::platform_types::consensus_params::ConsensusParams;
 use crate::platform_types::masternode::Masternode;
 use crate::platform_types::validator_set::ValidatorSet;
 use dpp::block::block_info::{BlockInfo, DEFAULT_BLOCK_INFO};
@@ -46,6 +47,9 @@ pub struct PlatformStateV0 {
 
     /// current HPMN masternode list
     pub hpmn_masternode_list: BTreeMap<ProTxHash, MasternodeListItem>,
+
+    /// Consensus params set by feature flags
+    pub consensus_params: ConsensusParams,
 }
 
 impl Debug for PlatformStateV0 {
@@ -78,6 +82,7 @@ impl Debug for PlatformStateV0 {
             .field("full_masternode_list", &self.full_masternode_list)
             .field("hpmn_masternode_list", &self.hpmn_masternode_list)
             .field("initialization_information", &self.genesis_block_info)
+            .field("consensus_params", &self.consensus_params)
             .finish()
     }
 }
@@ -118,6 +123,37 @@ pub(super) struct PlatformStateForSavingV0 {
     pub hpmn_masternode_list: BTreeMap<Bytes32, Masternode>,
 }
 
+/// Platform state, saved with the consensus params set by feature flags
+#[derive(Clone, Debug, Encode, Decode)]
+pub(super) struct PlatformStateForSavingV1 {
+    /// Information about the genesis block
+    pub genesis_block_info: Option<BlockInfo>,
+    /// Information about the last block
+    pub last_committed_block_info: Option<ExtendedBlockInfo>,
+    /// Current Version
+    pub current_protocol_version_in_consensus: ProtocolVersion,
+    /// upcoming protocol version
+    pub next_epoch_protocol_version: ProtocolVersion,
+    /// current quorum
+    pub current_validator_set_quorum_hash: Bytes32,
+    /// next quorum
+    pub next_validator_set_quorum_hash: Option<Bytes32>,
+    /// current validator set quorums
+    /// The validator set quorums are a subset of the quorums, but they also contain the list of
+    /// all members
+    #[bincode(with_serde)]
+    pub validator_sets: Vec<(Bytes32, ValidatorSet)>,
+
+    /// current full masternode list
+    pub full_masternode_list: BTreeMap<Bytes32, Masternode>,
+
+    /// current HPMN masternode list
+    pub hpmn_masternode_list: BTreeMap<Bytes32, Masternode>,
+
+    /// Consensus params set by feature flags
+    pub consensus_params: ConsensusParams,
+}
+
 impl TryFrom<PlatformStateV0> for PlatformStateForSavingV0 {
     type Error = Error;
 
@@ -164,6 +200,37 @@ impl TryFrom<PlatformStateV0> for PlatformStateForSavingV0 {
     }
 }
 
+impl TryFrom<PlatformStateV0> for PlatformStateForSavingV1 {
+    type Error = Error;
+
+    fn try_from(value: PlatformStateV0) -> Result<Self, Self::Error> {
+        let consensus_params = value.consensus_params.clone();
+        let PlatformStateForSavingV0 {
+            genesis_block_info,
+            last_committed_block_info,
+            current_protocol_version_in_consensus,
+            next_epoch_protocol_version,
+            current_validator_set_quorum_hash,
+            next_validator_set_quorum_hash,
+            validator_sets,
+            full_masternode_list,
+            hpmn_masternode_list,
+        } = value.try_into()?;
+        Ok(PlatformStateForSavingV1 {
+            genesis_block_info,
+            last_committed_block_info,
+            current_protocol_version_in_consensus,
+            next_epoch_protocol_version,
+            current_validator_set_quorum_hash,
+            next_validator_set_quorum_hash,
+            validator_sets,
+            full_masternode_list,
+            hpmn_masternode_list,
+            consensus_params,
+        })
+    }
+}
+
 impl From<PlatformStateForSavingV0> for PlatformStateV0 {
     fn from(value: PlatformStateForSavingV0) -> Self {
         PlatformStateV0 {
@@ -192,6 +259,41 @@ impl From<PlatformStateForSavingV0> for PlatformStateV0 {
                 .into_iter()
                 .map(|(k, v)| (ProTxHash::from_byte_array(k.to_buffer()), v.into()))
                 .collect(),
+            // Consensus params were not saved before, Tenderdash was running with its defaults
+            consensus_params: Default::default(),
+        }
+    }
+}
+
+impl From<PlatformStateForSavingV1> for PlatformStateV0 {
+    fn from(value: PlatformStateForSavingV1) -> Self {
+        let PlatformStateForSavingV1 {
+            genesis_block_info,
+            last_committed_block_info,
+            current_protocol_version_in_consensus,
+            next_epoch_protocol_version,
+            current_validator_set_quorum_hash,
+            next_validator_set_quorum_hash,
+            validator_sets,
+            full_masternode_list,
+            hpmn_masternode_list,
+            consensus_params,
+        } = value;
+        let platform_state: PlatformStateV0 = PlatformStateForSavingV0 {
+            genesis_block_info,
+            last_committed_block_info,
+            current_protocol_version_in_consensus,
+            next_epoch_protocol_version,
+            current_validator_set_quorum_hash,
+            next_validator_set_quorum_hash,
+            validator_sets,
+            full_masternode_list,
+            hpmn_masternode_list,
+        }
+        .into();
+        PlatformStateV0 {
+            consensus_params,
+            ..platform_state
         }
     }
 }
@@ -212,6 +314,7 @@ impl PlatformStateV0 {
             full_masternode_list: Default::default(),
             hpmn_masternode_list: Default::default(),
             genesis_block_info: None,
+            consensus_params: Default::default(),
         }
     }
 }
@@ -273,6 +376,9 @@ pub trait PlatformStateV0Methods {
     /// Returns information about the platform initialization state, if it exists.
     fn genesis_block_info(&self) -> Option<&BlockInfo>;
 
+    /// Returns the consensus params set by feature flags.
+    fn consensus_params(&self) -> &ConsensusParams;
+
     /// Returns the last committed block info if present or the genesis block info if not or default one
     fn any_block_info(&self) -> &BlockInfo;
 
@@ -326,6 +432,9 @@ pub trait PlatformStateV0Methods {
     /// Returns a mutable reference to the list of high performance masternodes.
     fn hpmn_masternode_list_mut(&mut self) -> &mut BTreeMap<ProTxHash, MasternodeListItem>;
 
+    /// Returns a mutable reference to the consensus params set by feature flags.
+    fn consensus_params_mut(&mut self) -> &mut ConsensusParams;
+
     /// The epoch ref
     fn epoch_ref(&self) -> &Epoch;
     /// The last block id hash
@@ -498,6 +607,11 @@ impl PlatformStateV0Methods for PlatformStateV0 {
         self.genesis_block_info.as_ref()
     }
 
+    /// Returns the consensus params set by feature flags.
+    fn consensus_params(&self) -> &ConsensusParams {
+        &self.consensus_params
+    }
+
     /// Returns the quorum hash of the current validator set.
     fn current_validator_set_quorum_hash(&self) -> QuorumHash {
         self.current_validator_set_quorum_hash
@@ -585,6 +699,10 @@ impl PlatformStateV0Methods for PlatformStateV0 {
         &mut self.hpmn_masternode_list
     }
 
+    fn consensus_params_mut(&mut self) -> &mut ConsensusParams {
+        &mut self.consensus_params
+    }
+
     fn any_block_info(&self) -> &BlockInfo {
         self.last_committed_block_info
             .as_ref()
@@ -0,0 +1,917 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::consensus_params::ConsensusParams;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use bincode::{config, Decode, Encode};
+use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0Getters;
+use dpp::block::extended_epoch_info::v0::ExtendedEpochInfoV0Getters;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::DocumentV0Getters;
+use dpp::platform_value::Value;
+use dpp::serialization::PlatformDeserializable;
+use dpp::system_data_contracts::feature_flags_contract;
+use dpp::system_data_contracts::feature_flags_contract::document_types::update_consensus_params;
+use dpp::system_data_contracts::feature_flags_contract::document_types::update_consensus_params::properties::PROPERTY_ENABLE_AT_HEIGHT;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use drive::grovedb::query_result_type::QueryResultType;
+use drive::grovedb::{Element, GroveDb, PathQuery, Query, SizedQuery, Transaction};
+use drive::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use indexmap::IndexMap;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{Debug, Formatter};
+use tenderdash_abci::proto::abci as proto;
+
+/// Format of the chunks produced by this version of drive-abci
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+/// Maximum number of elements proved by a single page
+pub const SNAPSHOT_PAGE_MAX_ELEMENTS: u16 = 1000;
+
+/// Pages are packed into a chunk until it proves this many elements, so small subtrees
+/// don't end up in a chunk each
+pub const SNAPSHOT_CHUNK_MAX_ELEMENTS: usize = 1000;
+
+/// Number of feature flags fetched at once when the consensus params of a restored snapshot
+/// are replayed
+const FEATURE_FLAGS_PAGE_LIMIT: u16 = 100;
+
+/// A page of the elements stored directly in one GroveDB subtree
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct SnapshotPage {
+    /// Path of the subtree
+    pub path: Vec<Vec<u8>>,
+    /// The page starts right after this key, or at the first key of the subtree if `None`
+    pub start_after: Option<Vec<u8>>,
+}
+
+impl SnapshotPage {
+    /// The query proved by the page
+    pub fn path_query(&self) -> PathQuery {
+        let mut query = Query::new();
+
+        match &self.start_after {
+            Some(key) => query.insert_range_after(key.clone()..),
+            None => query.insert_all(),
+        }
+
+        PathQuery::new(
+            self.path.clone(),
+            SizedQuery::new(query, Some(SNAPSHOT_PAGE_MAX_ELEMENTS), None),
+        )
+    }
+}
+
+/// A page of a chunk with the GroveDB proof of its elements
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct SnapshotPageProof {
+    /// Location of the page
+    pub page: SnapshotPage,
+    /// GroveDB proof of the elements of the page
+    pub proof: Vec<u8>,
+}
+
+/// A chunk as it is sent to syncing nodes.
+///
+/// Every page is proved by a GroveDB proof of its query, so every chunk can be verified against
+/// the app hash of the snapshot on its own.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct SnapshotChunk {
+    /// Pages of the chunk, in the order they are restored
+    pub pages: Vec<SnapshotPageProof>,
+}
+
+impl SnapshotChunk {
+    /// Serialize the chunk
+    pub fn serialize_to_bytes(&self) -> Result<Vec<u8>, Error> {
+        bincode::encode_to_vec(self, config::standard().with_big_endian()).map_err(|e| {
+            Error::Execution(ExecutionError::SnapshotError(format!(
+                "unable to serialize snapshot chunk: {}",
+                e
+            )))
+        })
+    }
+
+    /// Deserialize a chunk received from a peer
+    pub fn deserialize_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        bincode::decode_from_slice(bytes, config::standard().with_big_endian())
+            .map(|(chunk, _)| chunk)
+            .map_err(|e| {
+                Error::Execution(ExecutionError::SnapshotError(format!(
+                    "unable to deserialize snapshot chunk: {}",
+                    e
+                )))
+            })
+    }
+}
+
+/// A snapshot of platform taken right after a block was committed.
+///
+/// The drive is opened on a GroveDB checkpoint of the block, so chunks can be proved
+/// against the app hash of that block while the chain moves on.
+pub struct PlatformSnapshot {
+    /// Height of the block
+    pub height: u64,
+    /// App hash of the block
+    pub app_hash: [u8; 32],
+    /// Serialized platform state after the block was committed
+    pub serialized_platform_state: Vec<u8>,
+    /// Drive opened on the checkpoint of the block
+    pub drive: Drive,
+    /// Pages of each chunk of the snapshot, parents always come before their subtrees
+    pub chunks: Vec<Vec<SnapshotPage>>,
+}
+
+impl PlatformSnapshot {
+    /// Split the checkpoint into chunks.
+    ///
+    /// Subtrees are walked breadth first, so a tree element is always restored before
+    /// the elements it contains.
+    pub fn new(
+        height: u64,
+        app_hash: [u8; 32],
+        serialized_platform_state: Vec<u8>,
+        drive: Drive,
+    ) -> Result<Self, Error> {
+        let mut chunks = Vec::new();
+        let mut chunk_pages = Vec::new();
+        let mut chunk_elements = 0;
+        let mut subtree_paths = VecDeque::from([Vec::<Vec<u8>>::new()]);
+
+        while let Some(path) = subtree_paths.pop_front() {
+            let mut start_after = None;
+
+            loop {
+                let page = SnapshotPage {
+                    path: path.clone(),
+                    start_after,
+                };
+
+                let elements = drive
+                    .grove
+                    .query_raw(
+                        &page.path_query(),
+                        false,
+                        true,
+                        QueryResultType::QueryKeyElementPairResultType,
+                        None,
+                    )
+                    .unwrap()
+                    .map_err(|e| Error::Drive(GroveDB(e)))?
+                    .0
+                    .to_key_elements();
+
+                // Empty subtrees still need a proof, so they count as one element
+                let page_elements = elements.len().max(1);
+
+                if !chunk_pages.is_empty()
+                    && chunk_elements + page_elements > SNAPSHOT_CHUNK_MAX_ELEMENTS
+                {
+                    chunks.push(std::mem::take(&mut chunk_pages));
+                    chunk_elements = 0;
+                }
+
+                chunk_pages.push(page);
+                chunk_elements += page_elements;
+
+                for (key, element) in elements.iter() {
+                    if matches!(element, Element::Tree(..) | Element::SumTree(..)) {
+                        let mut subtree_path = path.clone();
+                        subtree_path.push(key.clone());
+                        subtree_paths.push_back(subtree_path);
+                    }
+                }
+
+                if elements.len() < SNAPSHOT_PAGE_MAX_ELEMENTS as usize {
+                    break;
+                }
+
+                start_after = elements.last().map(|(key, _)| key.clone());
+            }
+        }
+
+        if !chunk_pages.is_empty() {
+            chunks.push(chunk_pages);
+        }
+
+        Ok(PlatformSnapshot {
+            height,
+            app_hash,
+            serialized_platform_state,
+            drive,
+            chunks,
+        })
+    }
+
+    /// The snapshot as it is listed to Tenderdash
+    pub fn to_abci_snapshot(&self) -> proto::Snapshot {
+        proto::Snapshot {
+            height: self.height,
+            format: SNAPSHOT_FORMAT,
+            chunks: self.chunks.len() as u32,
+            hash: self.app_hash.to_vec(),
+            metadata: self.serialized_platform_state.clone(),
+        }
+    }
+
+    /// Prove a chunk of the snapshot
+    pub fn load_chunk(&self, index: u32) -> Result<Vec<u8>, Error> {
+        let pages = self.chunks.get(index as usize).ok_or_else(|| {
+            Error::Execution(ExecutionError::SnapshotError(format!(
+                "snapshot at height {} has no chunk {}",
+                self.height, index
+            )))
+        })?;
+
+        let pages = pages
+            .iter()
+            .map(|page| {
+                let proof = self
+                    .drive
+                    .grove
+                    .get_proved_path_query(&page.path_query(), false, None)
+                    .unwrap()
+                    .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+                Ok(SnapshotPageProof {
+                    page: page.clone(),
+                    proof,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        SnapshotChunk { pages }.serialize_to_bytes()
+    }
+}
+
+impl Debug for PlatformSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlatformSnapshot")
+            .field("height", &self.height)
+            .field("app_hash", &hex::encode(self.app_hash))
+            .field("chunks", &self.chunks.len())
+            .finish()
+    }
+}
+
+/// Restoration of a snapshot accepted from a peer.
+///
+/// Chunks are applied in order within a single transaction. Trees are created empty and filled
+/// by the chunks of their subtrees, and references are inserted once everything they may point
+/// to exists. The transaction can only be committed once the restored root hash matches the
+/// app hash.
+pub struct SnapshotRestore {
+    /// Height of the snapshot
+    pub height: u64,
+    /// Trusted app hash of the snapshot block
+    pub app_hash: [u8; 32],
+    /// Number of chunks of the snapshot
+    pub chunks: u32,
+    /// Index of the next chunk to apply
+    pub next_chunk: u32,
+    /// Platform state of the snapshot block
+    pub platform_state: PlatformState,
+    pending_references: Vec<(Vec<Vec<u8>>, Vec<u8>, Element)>,
+}
+
+impl SnapshotRestore {
+    /// Start restoring a snapshot offered by Tenderdash.
+    ///
+    /// The platform state carried in the snapshot metadata must describe the block of the
+    /// trusted app hash.
+    pub fn new(snapshot: &proto::Snapshot, app_hash: &[u8]) -> Result<Self, Error> {
+        let app_hash: [u8; 32] = app_hash.try_into().map_err(|_| {
+            Error::Execution(ExecutionError::SnapshotError(
+                "app hash must be 32 bytes".to_string(),
+            ))
+        })?;
+
+        let platform_state = PlatformState::deserialize_from_bytes_no_limit(&snapshot.metadata)?;
+
+        if platform_state.last_block_height() != snapshot.height
+            || platform_state.last_block_app_hash() != Some(app_hash)
+        {
+            return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                "platform state of snapshot at height {} doesn't match the trusted app hash",
+                snapshot.height
+            ))));
+        }
+
+        Ok(SnapshotRestore {
+            height: snapshot.height,
+            app_hash,
+            chunks: snapshot.chunks,
+            next_chunk: 0,
+            platform_state,
+            pending_references: Vec::new(),
+        })
+    }
+
+    /// All chunks were applied
+    pub fn is_complete(&self) -> bool {
+        self.next_chunk >= self.chunks
+    }
+
+    /// Verify a chunk against the app hash and insert its elements
+    pub fn apply_chunk(
+        &mut self,
+        drive: &Drive,
+        index: u32,
+        chunk: &[u8],
+        transaction: &Transaction,
+    ) -> Result<(), Error> {
+        if index != self.next_chunk {
+            return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                "expected chunk {}, got {}",
+                self.next_chunk, index
+            ))));
+        }
+
+        let chunk = SnapshotChunk::deserialize_from_bytes(chunk)?;
+
+        // All pages are verified before anything of the chunk is inserted
+        let mut elements = Vec::new();
+
+        for page_proof in &chunk.pages {
+            let (root_hash, page_elements) =
+                GroveDb::verify_query(&page_proof.proof, &page_proof.page.path_query())
+                    .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+            if root_hash != self.app_hash {
+                return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                    "chunk {} is not proved by the app hash",
+                    index
+                ))));
+            }
+
+            elements.extend(page_elements);
+        }
+
+        for (path, key, element) in elements {
+            let Some(element) = element else {
+                continue;
+            };
+
+            let element = match element {
+                Element::Tree(_, flags) => Element::empty_tree_with_flags(flags),
+                Element::SumTree(_, _, flags) => Element::empty_sum_tree_with_flags(flags),
+                reference @ Element::Reference(..) => {
+                    self.pending_references.push((path, key, reference));
+                    continue;
+                }
+                element => element,
+            };
+
+            drive
+                .grove
+                .insert(path.as_slice(), &key, element, None, Some(transaction))
+                .unwrap()
+                .map_err(|e| Error::Drive(GroveDB(e)))?;
+        }
+
+        self.next_chunk += 1;
+
+        Ok(())
+    }
+
+    /// Insert the references and check that the restored state matches the app hash
+    pub fn finish(&mut self, drive: &Drive, transaction: &Transaction) -> Result<(), Error> {
+        // References may point to other references, so insert them until no more can be
+        while !self.pending_references.is_empty() {
+            let pending_references = std::mem::take(&mut self.pending_references);
+            let pending_count = pending_references.len();
+
+            for (path, key, reference) in pending_references {
+                let result = drive
+                    .grove
+                    .insert(
+                        path.as_slice(),
+                        &key,
+                        reference.clone(),
+                        None,
+                        Some(transaction),
+                    )
+                    .unwrap();
+
+                if result.is_err() {
+                    self.pending_references.push((path, key, reference));
+                }
+            }
+
+            if self.pending_references.len() == pending_count {
+                return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                    "{} references of the snapshot can't be resolved",
+                    pending_count
+                ))));
+            }
+        }
+
+        let root_hash = drive
+            .grove
+            .root_hash(Some(transaction))
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+        if root_hash != self.app_hash {
+            return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                "restored root hash {} doesn't match app hash {}",
+                hex::encode(root_hash),
+                hex::encode(self.app_hash)
+            ))));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Platform<C> {
+    /// Wait for the background build of the latest snapshot to finish
+    pub fn wait_for_snapshot_build(&self) {
+        if let Some(snapshot_build) = self.snapshot_build.lock().unwrap().take() {
+            if snapshot_build.join().is_err() {
+                tracing::error!("state sync snapshot build panicked");
+            }
+        }
+    }
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Verify and rebuild the parts of a restored platform state which are not proved by the
+    /// app hash.
+    ///
+    /// The protocol version, the last block info and the consensus params must match the
+    /// restored state, and masternode lists and validator sets are fetched from Core at the core
+    /// height of the snapshot block instead of trusting the peer.
+    pub(crate) fn rebuild_restored_platform_state(
+        &self,
+        platform_state: &mut PlatformState,
+        transaction: &Transaction,
+    ) -> Result<(), Error> {
+        let protocol_version = platform_state.current_protocol_version_in_consensus();
+        let platform_version = PlatformVersion::get(protocol_version)?;
+
+        // The protocol version is only stored once the chain upgraded
+        let stored_protocol_version = self
+            .drive
+            .fetch_current_protocol_version(Some(transaction), &platform_version.drive)?
+            .unwrap_or(self.config.initial_protocol_version);
+
+        if stored_protocol_version != protocol_version {
+            return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                "platform state of snapshot has protocol version {}, but the restored state has {}",
+                protocol_version, stored_protocol_version
+            ))));
+        }
+
+        self.verify_restored_block_info(platform_state, transaction, platform_version)?;
+        self.verify_restored_consensus_params(platform_state, transaction, platform_version)?;
+
+        let core_height = platform_state.core_height();
+
+        platform_state.validator_sets_mut().clear();
+
+        self.update_state_masternode_list_v0(platform_state, core_height, true)?;
+        self.update_quorum_info(platform_state, core_height, true, platform_version)?;
+
+        let current_quorum_hash = platform_state.current_validator_set_quorum_hash();
+        let next_quorum_hash = *platform_state.next_validator_set_quorum_hash();
+
+        for quorum_hash in std::iter::once(current_quorum_hash).chain(next_quorum_hash) {
+            if !platform_state.validator_sets().contains_key(&quorum_hash) {
+                return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                    "validator set {} of snapshot is not a quorum at core height {}",
+                    quorum_hash, core_height
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the last block info of a restored platform state against the epochs of the
+    /// restored state and the chain locks of Core.
+    ///
+    /// Tenderdash only passes the trusted app hash along with a snapshot, so the block can't be
+    /// compared to its light block. Instead it must belong to the last started epoch, can't
+    /// precede the start of that epoch, and its core height must be chain locked by Core.
+    fn verify_restored_block_info(
+        &self,
+        platform_state: &PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let Some(block_info) = platform_state.last_committed_block_info() else {
+            return Err(Error::Execution(ExecutionError::SnapshotError(
+                "platform state of snapshot has no last block info".to_string(),
+            )));
+        };
+
+        let block_info = block_info.basic_info();
+        let epoch_index = block_info.epoch.index;
+
+        let epoch_infos = self.drive.get_epochs_infos(
+            epoch_index,
+            2,
+            true,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        let mut epoch_infos = epoch_infos.iter();
+
+        match epoch_infos.next() {
+            Some(epoch_info) if epoch_info.index() == epoch_index => {
+                if block_info.height < epoch_info.first_block_height()
+                    || block_info.time_ms < epoch_info.first_block_time()
+                    || block_info.core_height < epoch_info.first_core_block_height()
+                {
+                    return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                        "last block of snapshot at height {} precedes the start of epoch {}",
+                        block_info.height, epoch_index
+                    ))));
+                }
+
+                if epoch_infos.next().is_some() {
+                    return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                        "epoch {} of snapshot at height {} already ended in the restored state",
+                        epoch_index, block_info.height
+                    ))));
+                }
+            }
+            // The start of the genesis epoch is only stored with its first block
+            None if epoch_index == 0 => {}
+            _ => {
+                return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                    "epoch {} of snapshot at height {} didn't start in the restored state",
+                    epoch_index, block_info.height
+                ))));
+            }
+        }
+
+        let chain_locked_height = self.core_rpc.get_best_chain_lock()?.core_block_height;
+
+        if block_info.core_height > chain_locked_height {
+            return Err(Error::Execution(ExecutionError::SnapshotError(format!(
+                "core height {} of snapshot is above the chain locked core height {}",
+                block_info.core_height, chain_locked_height
+            ))));
+        }
+
+        Ok(())
+    }
+
+    /// Replay the `updateConsensusParams` feature flags enabled up to the snapshot block and
+    /// check they result in the consensus params of the restored platform state.
+    fn verify_restored_consensus_params(
+        &self,
+        platform_state: &PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut consensus_params = ConsensusParams::default();
+
+        // Feature flags can only be created once the contract is registered
+        if let Some(contract_fetch_info) = self
+            .drive
+            .fetch_contract(
+                feature_flags_contract::ID_BYTES,
+                None,
+                None,
+                Some(transaction),
+                platform_version,
+            )
+            .unwrap()?
+        {
+            let contract = &contract_fetch_info.contract;
+            let document_type = contract.document_type_for_name(update_consensus_params::NAME)?;
+
+            let mut start_at = None;
+
+            loop {
+                let drive_query = DriveQuery {
+                    contract,
+                    document_type,
+                    internal_clauses: InternalClauses {
+                        primary_key_in_clause: None,
+                        primary_key_equal_clause: None,
+                        in_clause: None,
+                        range_clause: Some(WhereClause {
+                            field: PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+                            operator: WhereOperator::LessThanOrEquals,
+                            value: Value::U64(platform_state.last_block_height()),
+                        }),
+                        equal_clauses: BTreeMap::new(),
+                        other_in_clauses: vec![],
+                        or_clauses: vec![],
+                    },
+                    offset: None,
+                    limit: Some(FEATURE_FLAGS_PAGE_LIMIT),
+                    order_by: IndexMap::from([(
+                        PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+                        OrderClause {
+                            field: PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+                            ascending: true,
+                        },
+                    )]),
+                    start_at,
+                    start_at_included: false,
+                    block_time_ms: None,
+                };
+
+                let feature_flags = self
+                    .drive
+                    .query_documents(
+                        drive_query,
+                        None,
+                        false,
+                        Some(transaction),
+                        Some(platform_version.protocol_version),
+                    )?
+                    .documents_owned();
+
+                // Feature flags are applied in the order of the blocks enabling them
+                for feature_flag in &feature_flags {
+                    consensus_params.apply_feature_flag(feature_flag)?;
+                }
+
+                if feature_flags.len() < FEATURE_FLAGS_PAGE_LIMIT as usize {
+                    break;
+                }
+
+                start_at = feature_flags
+                    .last()
+                    .map(|feature_flag| feature_flag.id().to_buffer());
+            }
+        }
+
+        if &consensus_params != platform_state.consensus_params() {
+            return Err(Error::Execution(ExecutionError::SnapshotError(
+                "consensus params of snapshot don't match the feature flags of the restored state"
+                    .to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for SnapshotRestore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnapshotRestore")
+            .field("height", &self.height)
+            .field("app_hash", &hex::encode(self.app_hash))
+            .field("chunks", &self.chunks)
+            .field("next_chunk", &self.next_chunk)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PlatformConfig;
+    use crate::platform_types::consensus_params::BlockParams;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0;
+    use dpp::dashcore::hashes::Hash;
+    use dpp::dashcore::QuorumHash;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::version::PlatformVersion;
+    use std::sync::Arc;
+
+    fn take_snapshot(height: u64) -> PlatformSnapshot {
+        let snapshots_dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                snapshot_interval: height,
+                snapshots_path: snapshots_dir.into_path(),
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let platform_version = PlatformVersion::latest();
+
+        let mut state = platform.state.read().unwrap().clone();
+
+        state.set_last_committed_block_info(Some(
+            ExtendedBlockInfoV0 {
+                basic_info: BlockInfo {
+                    time_ms: 500000,
+                    height,
+                    core_height: 42,
+                    epoch: Default::default(),
+                },
+                app_hash: platform.drive.grove.root_hash(None).unwrap().unwrap(),
+                quorum_hash: [0u8; 32],
+                block_id_hash: [0u8; 32],
+                signature: [0u8; 96],
+                round: 0,
+            }
+            .into(),
+        ));
+
+        platform
+            .store_snapshot(&state, platform_version)
+            .expect("expected to store snapshot");
+
+        platform.wait_for_snapshot_build();
+
+        let snapshot = platform
+            .snapshots
+            .write()
+            .unwrap()
+            .remove(&height)
+            .expect("expected a snapshot at height");
+
+        Arc::try_unwrap(snapshot).expect("expected a single reference to the snapshot")
+    }
+
+    #[test]
+    fn should_restore_snapshot_matching_app_hash() {
+        let snapshot = take_snapshot(10);
+        let abci_snapshot = snapshot.to_abci_snapshot();
+
+        let platform = TestPlatformBuilder::new().build_with_mock_rpc();
+
+        let mut restore = SnapshotRestore::new(&abci_snapshot, &snapshot.app_hash)
+            .expect("expected to accept the snapshot");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        for index in 0..abci_snapshot.chunks {
+            let chunk = snapshot.load_chunk(index).expect("expected to load chunk");
+
+            restore
+                .apply_chunk(&platform.drive, index, &chunk, &transaction)
+                .expect("expected to apply chunk");
+        }
+
+        assert!(restore.is_complete());
+
+        restore
+            .finish(&platform.drive, &transaction)
+            .expect("expected restored state to match the app hash");
+
+        assert_eq!(restore.platform_state.last_block_height(), 10);
+    }
+
+    fn restore_and_rebuild(
+        snapshot: &PlatformSnapshot,
+        platform: &Platform<MockCoreRPCLike>,
+    ) -> Result<PlatformState, Error> {
+        let abci_snapshot = snapshot.to_abci_snapshot();
+
+        let mut restore = SnapshotRestore::new(&abci_snapshot, &snapshot.app_hash)
+            .expect("expected to accept the snapshot");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        for index in 0..abci_snapshot.chunks {
+            let chunk = snapshot.load_chunk(index).expect("expected to load chunk");
+
+            restore
+                .apply_chunk(&platform.drive, index, &chunk, &transaction)
+                .expect("expected to apply chunk");
+        }
+
+        restore
+            .finish(&platform.drive, &transaction)
+            .expect("expected restored state to match the app hash");
+
+        platform.rebuild_restored_platform_state(&mut restore.platform_state, &transaction)?;
+
+        Ok(restore.platform_state)
+    }
+
+    #[test]
+    fn should_rebuild_validator_sets_of_restored_state_from_core() {
+        let snapshot = take_snapshot(10);
+
+        let quorum_hash = snapshot_quorum_hash(&snapshot);
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .with_core_validator_set(quorum_hash)
+            .with_core_chain_locked_height(42);
+
+        let platform_state = restore_and_rebuild(&snapshot, &platform)
+            .expect("expected to rebuild restored platform state");
+
+        assert_eq!(platform_state.validator_sets().len(), 1);
+        assert!(platform_state.validator_sets().contains_key(&quorum_hash));
+    }
+
+    #[test]
+    fn should_reject_restored_state_with_validator_set_unknown_to_core() {
+        let snapshot = take_snapshot(10);
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .with_core_validator_set(QuorumHash::from_byte_array([7u8; 32]))
+            .with_core_chain_locked_height(42);
+
+        restore_and_rebuild(&snapshot, &platform)
+            .expect_err("expected restored platform state to be rejected");
+    }
+
+    fn snapshot_quorum_hash(snapshot: &PlatformSnapshot) -> QuorumHash {
+        PlatformState::deserialize_from_bytes_no_limit(&snapshot.serialized_platform_state)
+            .expect("expected to deserialize platform state")
+            .current_validator_set_quorum_hash()
+    }
+
+    #[test]
+    fn should_reject_restored_state_with_core_height_not_chain_locked() {
+        let snapshot = take_snapshot(10);
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .with_core_validator_set(snapshot_quorum_hash(&snapshot))
+            .with_core_chain_locked_height(41);
+
+        restore_and_rebuild(&snapshot, &platform)
+            .expect_err("expected restored platform state to be rejected");
+    }
+
+    #[test]
+    fn should_reject_restored_state_with_consensus_params_not_set_by_feature_flags() {
+        let mut snapshot = take_snapshot(10);
+
+        let mut platform_state =
+            PlatformState::deserialize_from_bytes_no_limit(&snapshot.serialized_platform_state)
+                .expect("expected to deserialize platform state");
+
+        platform_state.consensus_params_mut().block = Some(BlockParams {
+            max_bytes: 1024,
+            max_gas: -1,
+        });
+
+        // The platform state isn't proved by the app hash, so the snapshot is still accepted
+        snapshot.serialized_platform_state = platform_state
+            .serialize_to_bytes()
+            .expect("expected to serialize platform state");
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .with_core_validator_set(snapshot_quorum_hash(&snapshot))
+            .with_core_chain_locked_height(42);
+
+        restore_and_rebuild(&snapshot, &platform)
+            .expect_err("expected restored platform state to be rejected");
+    }
+
+    #[test]
+    fn should_reject_chunk_not_matching_its_proof() {
+        let snapshot = take_snapshot(10);
+        let abci_snapshot = snapshot.to_abci_snapshot();
+
+        let platform = TestPlatformBuilder::new().build_with_mock_rpc();
+
+        let mut restore = SnapshotRestore::new(&abci_snapshot, &snapshot.app_hash)
+            .expect("expected to accept the snapshot");
+
+        let mut chunk = SnapshotChunk::deserialize_from_bytes(
+            &snapshot.load_chunk(0).expect("expected to load chunk"),
+        )
+        .expect("expected to deserialize chunk");
+
+        // The proof doesn't prove the query the page claims anymore
+        chunk.pages[0].page.start_after = Some(vec![0]);
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        restore
+            .apply_chunk(
+                &platform.drive,
+                0,
+                &chunk
+                    .serialize_to_bytes()
+                    .expect("expected to serialize chunk"),
+                &transaction,
+            )
+            .expect_err("expected chunk to be rejected");
+
+        assert_eq!(restore.next_chunk, 0);
+    }
+
+    #[test]
+    fn should_reject_snapshot_with_state_of_another_block() {
+        let snapshot = take_snapshot(10);
+        let abci_snapshot = snapshot.to_abci_snapshot();
+
+        SnapshotRestore::new(&abci_snapshot, &[1u8; 32])
+            .expect_err("expected snapshot to be rejected");
+    }
+}
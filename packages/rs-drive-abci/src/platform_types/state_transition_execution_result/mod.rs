@@ -1,6 +1,7 @@
 use dpp::validation::SimpleConsensusValidationResult;
 
 use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
 
 /// The Fee Result for a Dry Run (without state)
 pub type DryRunFeeResult = FeeResult;
@@ -8,8 +9,9 @@ pub type DryRunFeeResult = FeeResult;
 /// An execution result
 #[derive(Debug, Clone)]
 pub enum StateTransitionExecutionResult {
-    /// Successfully executed a paid event
-    SuccessfulPaidExecution(DryRunFeeResult, FeeResult),
+    /// Successfully executed a paid event, with the part of the paid processing fee
+    /// that is the user fee increase
+    SuccessfulPaidExecution(DryRunFeeResult, FeeResult, Credits),
     /// Successfully executed a free event
     SuccessfulFreeExecution,
     /// There were consensus errors when trying to execute an event
@@ -21,9 +21,16 @@ impl<C> Platform<C> {
         request: GetDataContractRequestV0,
         platform_version: &PlatformVersion,
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
-        let metadata = self.response_metadata_v0(state);
         let quorum_type = self.config.quorum_type() as u32;
-        let GetDataContractRequestV0 { id, prove } = request;
+        let GetDataContractRequestV0 { id, prove, height } = request;
+        let historical_state = check_validation_result_with_data!(height
+            .map(|height| self.historical_state(height))
+            .transpose());
+        let (drive, state) = match &historical_state {
+            Some(historical_state) => (&historical_state.drive, &historical_state.state),
+            None => (&self.drive, state),
+        };
+        let metadata = self.response_metadata_v0(state);
         let contract_id: Identifier =
             check_validation_result_with_data!(id.try_into().map_err(|_| {
                 QueryError::InvalidArgument(
@@ -31,9 +38,7 @@ impl<C> Platform<C> {
                 )
             }));
         let response_data = if prove {
-            let proof =
-                self.drive
-                    .prove_contract(contract_id.into_buffer(), None, platform_version)?;
+            let proof = drive.prove_contract(contract_id.into_buffer(), None, platform_version)?;
 
             GetDataContractResponse {
                 version: Some(get_data_contract_response::Version::V0(GetDataContractResponseV0 {
@@ -50,8 +55,7 @@ impl<C> Platform<C> {
             }
                 .encode_to_vec()
         } else {
-            let maybe_data_contract_fetch_info = self
-                .drive
+            let maybe_data_contract_fetch_info = drive
                 .fetch_contract(
                     contract_id.into_buffer(),
                     None,
@@ -0,0 +1,144 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::{validate_query_index_is_backfilled, QueryValidationResult};
+use dapi_grpc::platform::v0::get_documents_count_request::GetDocumentsCountRequestV0;
+use dapi_grpc::platform::v0::get_documents_count_response::GetDocumentsCountResponseV0;
+use dapi_grpc::platform::v0::{get_documents_count_response, GetDocumentsCountResponse, Proof};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::{DocumentAggregate, DocumentAggregateDriveQuery, DriveQuery};
+use prost::Message;
+
+impl<C> Platform<C> {
+    pub(super) fn query_documents_count_v0(
+        &self,
+        state: &PlatformState,
+        request: GetDocumentsCountRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
+        let metadata = self.response_metadata_v0(state);
+        let quorum_type = self.config.quorum_type() as u32;
+        let GetDocumentsCountRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            r#where,
+            sum_property,
+            prove,
+        } = request;
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+        let contract_ref = &contract.contract;
+        let document_type = check_validation_result_with_data!(contract_ref
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        let where_clause = if r#where.is_empty() {
+            Value::Null
+        } else {
+            check_validation_result_with_data!(ciborium::de::from_reader(r#where.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'where' query from cbor".to_string(),
+                    ))
+                }))
+        };
+
+        let drive_query = check_validation_result_with_data!(DriveQuery::from_decomposed_values(
+            where_clause,
+            None,
+            None,
+            None,
+            true,
+            None,
+            contract_ref,
+            document_type,
+            &self.config.drive,
+        ));
+
+        if let Some(query_error) =
+            validate_query_index_is_backfilled(&self.drive, &drive_query, platform_version)?
+        {
+            return Ok(QueryValidationResult::new_with_error(query_error));
+        }
+
+        let aggregate_query = DocumentAggregateDriveQuery {
+            query: drive_query,
+            aggregate: if sum_property.is_empty() {
+                DocumentAggregate::Count
+            } else {
+                DocumentAggregate::Sum(sum_property)
+            },
+        };
+
+        let result = if prove {
+            let proof =
+                match aggregate_query.execute_with_proof(&self.drive, None, None, platform_version)
+                {
+                    Ok(result) => result.0,
+                    Err(drive::error::Error::Query(query_error)) => {
+                        return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                            query_error,
+                        )));
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+            get_documents_count_response::get_documents_count_response_v0::Result::Proof(Proof {
+                grovedb_proof: proof,
+                quorum_hash: state.last_quorum_hash().to_vec(),
+                quorum_type,
+                block_id_hash: state.last_block_id_hash().to_vec(),
+                signature: state.last_block_signature().to_vec(),
+                round: state.last_block_round(),
+            })
+        } else {
+            let value =
+                match aggregate_query.execute_no_proof(&self.drive, None, None, platform_version) {
+                    Ok(result) => result.0,
+                    Err(drive::error::Error::Query(query_error)) => {
+                        return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                            query_error,
+                        )));
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+            get_documents_count_response::get_documents_count_response_v0::Result::Value(value)
+        };
+
+        let response_data = GetDocumentsCountResponse {
+            version: Some(get_documents_count_response::Version::V0(
+                GetDocumentsCountResponseV0 {
+                    result: Some(result),
+                    metadata: Some(metadata),
+                },
+            )),
+        }
+        .encode_to_vec();
+        Ok(QueryValidationResult::new_with_data(response_data))
+    }
+}
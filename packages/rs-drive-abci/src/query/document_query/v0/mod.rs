@@ -3,17 +3,21 @@ use crate::error::Error;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
-use crate::query::QueryValidationResult;
+use crate::query::{validate_query_index_is_backfilled, QueryValidationResult};
 use dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
 use dapi_grpc::platform::v0::get_documents_request::GetDocumentsRequestV0;
 use dapi_grpc::platform::v0::get_documents_response::GetDocumentsResponseV0;
 use dapi_grpc::platform::v0::{get_documents_response, GetDocumentsResponse, Proof};
 use dpp::check_validation_result_with_data;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
 use dpp::identifier::Identifier;
 use dpp::platform_value::Value;
 use dpp::validation::ValidationResult;
 use dpp::version::PlatformVersion;
+use drive::drive::document::expiry::document_is_expired;
 use drive::error::query::QuerySyntaxError;
 use drive::query::DriveQuery;
 use prost::Message;
@@ -25,7 +29,6 @@ impl<C> Platform<C> {
         request: GetDocumentsRequestV0,
         platform_version: &PlatformVersion,
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
-        let metadata = self.response_metadata_v0(state);
         let quorum_type = self.config.quorum_type() as u32;
         let GetDocumentsRequestV0 {
             data_contract_id,
@@ -35,13 +38,22 @@ impl<C> Platform<C> {
             limit,
             prove,
             start,
+            height,
         } = request;
+        let historical_state = check_validation_result_with_data!(height
+            .map(|height| self.historical_state(height))
+            .transpose());
+        let (drive, state) = match &historical_state {
+            Some(historical_state) => (&historical_state.drive, &historical_state.state),
+            None => (&self.drive, state),
+        };
+        let metadata = self.response_metadata_v0(state);
         let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
             .try_into()
             .map_err(|_| QueryError::InvalidArgument(
                 "id must be a valid identifier (32 bytes long)".to_string()
             )));
-        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+        let (_, contract) = drive.get_contract_with_fetch_info_and_fee(
             contract_id.to_buffer(),
             None,
             true,
@@ -129,17 +141,21 @@ impl<C> Platform<C> {
             document_type,
             &self.config.drive,
         ));
+        if let Some(query_error) =
+            validate_query_index_is_backfilled(drive, &drive_query, platform_version)?
+        {
+            return Ok(QueryValidationResult::new_with_error(query_error));
+        }
         let response_data = if prove {
-            let proof =
-                match drive_query.execute_with_proof(&self.drive, None, None, platform_version) {
-                    Ok(result) => result.0,
-                    Err(drive::error::Error::Query(query_error)) => {
-                        return Ok(QueryValidationResult::new_with_error(QueryError::Query(
-                            query_error,
-                        )));
-                    }
-                    Err(e) => return Err(e.into()),
-                };
+            let proof = match drive_query.execute_with_proof(drive, None, None, platform_version) {
+                Ok(result) => result.0,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
             GetDocumentsResponse {
                 version: Some(get_documents_response::Version::V0(
                     GetDocumentsResponseV0 {
@@ -161,19 +177,39 @@ impl<C> Platform<C> {
             }
             .encode_to_vec()
         } else {
-            let results = match drive_query.execute_raw_results_no_proof(
-                &self.drive,
-                None,
-                None,
-                platform_version,
-            ) {
-                Ok(result) => result.0,
-                Err(drive::error::Error::Query(query_error)) => {
-                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
-                        query_error,
-                    )));
+            let results =
+                match drive_query.execute_raw_results_no_proof(drive, None, None, platform_version)
+                {
+                    Ok(result) => result.0,
+                    Err(drive::error::Error::Query(query_error)) => {
+                        return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                            query_error,
+                        )));
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+
+            // Expired documents are only deleted at the end of blocks, until then they are
+            // left out of the results, which can therefore hold less documents than the limit
+            let results = match state
+                .last_block_time_ms()
+                .filter(|_| document_type.documents_expire_after().is_some())
+            {
+                Some(block_time_ms) => {
+                    let mut unexpired_results = Vec::with_capacity(results.len());
+                    for serialized_document in results {
+                        let document = Document::from_bytes(
+                            serialized_document.as_slice(),
+                            document_type,
+                            platform_version,
+                        )?;
+                        if !document_is_expired(document_type, &document, block_time_ms) {
+                            unexpired_results.push(serialized_document);
+                        }
+                    }
+                    unexpired_results
                 }
-                Err(e) => return Err(e.into()),
+                None => results,
             };
 
             GetDocumentsResponse {
@@ -0,0 +1,32 @@
+use crate::error::query::QueryError;
+use crate::platform_types::historical_state::HistoricalPlatformState;
+use crate::platform_types::platform::Platform;
+use std::sync::Arc;
+
+impl<C> Platform<C> {
+    /// The platform state retained at a past block height
+    pub(in crate::query) fn historical_state(
+        &self,
+        height: u64,
+    ) -> Result<Arc<HistoricalPlatformState>, QueryError> {
+        let retention_blocks = self.config.historical_state_retention_blocks;
+
+        if retention_blocks == 0 {
+            return Err(QueryError::InvalidArgument(
+                "historical queries are disabled on this node".to_string(),
+            ));
+        }
+
+        self.historical_states
+            .read()
+            .unwrap()
+            .get(&height)
+            .cloned()
+            .ok_or_else(|| {
+                QueryError::NotFound(format!(
+                    "state at height {} is not retained, only the last {} blocks are available",
+                    height, retention_blocks
+                ))
+            })
+    }
+}
@@ -20,9 +20,16 @@ impl<C> Platform<C> {
         get_identity_request: GetIdentityRequestV0,
         platform_version: &PlatformVersion,
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
-        let metadata = self.response_metadata_v0(state);
         let quorum_type = self.config.quorum_type() as u32;
-        let GetIdentityRequestV0 { id, prove } = get_identity_request;
+        let GetIdentityRequestV0 { id, prove, height } = get_identity_request;
+        let historical_state = check_validation_result_with_data!(height
+            .map(|height| self.historical_state(height))
+            .transpose());
+        let (drive, state) = match &historical_state {
+            Some(historical_state) => (&historical_state.drive, &historical_state.state),
+            None => (&self.drive, state),
+        };
+        let metadata = self.response_metadata_v0(state);
         let identity_id: Identifier =
             check_validation_result_with_data!(id.try_into().map_err(|_| {
                 QueryError::InvalidArgument(
@@ -30,7 +37,7 @@ impl<C> Platform<C> {
                 )
             }));
         let response_data = if prove {
-            let proof = check_validation_result_with_data!(self.drive.prove_identity_balance(
+            let proof = check_validation_result_with_data!(drive.prove_identity_balance(
                 identity_id.into_buffer(),
                 None,
                 &platform_version.drive
@@ -51,11 +58,8 @@ impl<C> Platform<C> {
                 }
                     .encode_to_vec()
         } else {
-            let maybe_balance = self.drive.fetch_identity_balance(
-                identity_id.into_buffer(),
-                None,
-                platform_version,
-            )?;
+            let maybe_balance =
+                drive.fetch_identity_balance(identity_id.into_buffer(), None, platform_version)?;
 
             let Some(balance) = maybe_balance else {
                 return Ok(ValidationResult::new_with_error(QueryError::NotFound(
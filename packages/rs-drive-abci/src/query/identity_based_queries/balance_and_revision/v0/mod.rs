@@ -24,9 +24,16 @@ impl<C> Platform<C> {
         get_identity_request: GetIdentityRequestV0,
         platform_version: &PlatformVersion,
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
-        let metadata = self.response_metadata_v0(state);
         let quorum_type = self.config.quorum_type() as u32;
-        let GetIdentityRequestV0 { id, prove } = get_identity_request;
+        let GetIdentityRequestV0 { id, prove, height } = get_identity_request;
+        let historical_state = check_validation_result_with_data!(height
+            .map(|height| self.historical_state(height))
+            .transpose());
+        let (drive, state) = match &historical_state {
+            Some(historical_state) => (&historical_state.drive, &historical_state.state),
+            None => (&self.drive, state),
+        };
+        let metadata = self.response_metadata_v0(state);
         let identity_id: Identifier =
             check_validation_result_with_data!(id.try_into().map_err(|_| {
                 QueryError::InvalidArgument(
@@ -34,7 +41,7 @@ impl<C> Platform<C> {
                 )
             }));
         let response_data = if prove {
-            let proof = self.drive.prove_identity_balance_and_revision(
+            let proof = drive.prove_identity_balance_and_revision(
                 identity_id.into_buffer(),
                 None,
                 &platform_version.drive,
@@ -55,11 +62,8 @@ impl<C> Platform<C> {
             }
                 .encode_to_vec()
         } else {
-            let maybe_balance = self.drive.fetch_identity_balance(
-                identity_id.into_buffer(),
-                None,
-                platform_version,
-            )?;
+            let maybe_balance =
+                drive.fetch_identity_balance(identity_id.into_buffer(), None, platform_version)?;
 
             let Some(balance) = maybe_balance else {
                 return Ok(ValidationResult::new_with_error(QueryError::NotFound(
@@ -67,7 +71,7 @@ impl<C> Platform<C> {
                 )));
             };
 
-            let maybe_revision = self.drive.fetch_identity_revision(
+            let maybe_revision = drive.fetch_identity_revision(
                 identity_id.into_buffer(),
                 true,
                 None,
@@ -21,9 +21,16 @@ impl<C> Platform<C> {
         get_identity_request: GetIdentityRequestV0,
         platform_version: &PlatformVersion,
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
-        let metadata = self.response_metadata_v0(state);
         let quorum_type = self.config.quorum_type() as u32;
-        let GetIdentityRequestV0 { id, prove } = get_identity_request;
+        let GetIdentityRequestV0 { id, prove, height } = get_identity_request;
+        let historical_state = check_validation_result_with_data!(height
+            .map(|height| self.historical_state(height))
+            .transpose());
+        let (drive, state) = match &historical_state {
+            Some(historical_state) => (&historical_state.drive, &historical_state.state),
+            None => (&self.drive, state),
+        };
+        let metadata = self.response_metadata_v0(state);
         let identity_id: Identifier =
             check_validation_result_with_data!(id.try_into().map_err(|_| {
                 QueryError::InvalidArgument(
@@ -31,7 +38,7 @@ impl<C> Platform<C> {
                 )
             }));
         let response_data = if prove {
-            let proof = self.drive.prove_full_identity(
+            let proof = drive.prove_full_identity(
                 identity_id.into_buffer(),
                 None,
                 &platform_version.drive,
@@ -54,11 +61,8 @@ impl<C> Platform<C> {
             }
             .encode_to_vec()
         } else {
-            let maybe_identity = self.drive.fetch_full_identity(
-                identity_id.into_buffer(),
-                None,
-                platform_version,
-            )?;
+            let maybe_identity =
+                drive.fetch_full_identity(identity_id.into_buffer(), None, platform_version)?;
 
             let identity = check_validation_result_with_data!(maybe_identity.ok_or_else(|| {
                 QueryError::NotFound(format!("identity {} not found", identity_id))
@@ -5,3 +5,4 @@ mod identities_by_public_key_hashes;
 mod identity;
 mod identity_by_public_key_hash;
 mod keys;
+mod nonce;
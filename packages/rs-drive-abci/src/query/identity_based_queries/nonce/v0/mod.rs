@@ -0,0 +1,88 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_identity_nonce_request::GetIdentityNonceRequestV0;
+use dapi_grpc::platform::v0::get_identity_nonce_response::{
+    get_identity_nonce_response_v0, GetIdentityNonceResponseV0,
+};
+use dapi_grpc::platform::v0::{get_identity_nonce_response, GetIdentityNonceResponse, Proof};
+use dpp::check_validation_result_with_data;
+use dpp::identifier::Identifier;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use prost::Message;
+
+impl<C> Platform<C> {
+    pub(super) fn query_identity_nonce_v0(
+        &self,
+        state: &PlatformState,
+        get_identity_nonce_request: GetIdentityNonceRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
+        let metadata = self.response_metadata_v0(state);
+        let quorum_type = self.config.quorum_type() as u32;
+        let GetIdentityNonceRequestV0 { id, prove } = get_identity_nonce_request;
+        let identity_id: Identifier =
+            check_validation_result_with_data!(id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+        let response_data = if prove {
+            let proof = check_validation_result_with_data!(self.drive.prove_identity_nonce(
+                identity_id.into_buffer(),
+                None,
+                &platform_version.drive
+            ));
+
+            GetIdentityNonceResponse {
+                version: Some(get_identity_nonce_response::Version::V0(
+                    GetIdentityNonceResponseV0 {
+                        result: Some(get_identity_nonce_response_v0::Result::Proof(Proof {
+                            grovedb_proof: proof,
+                            quorum_hash: state.last_quorum_hash().to_vec(),
+                            quorum_type,
+                            block_id_hash: state.last_block_id_hash().to_vec(),
+                            signature: state.last_block_signature().to_vec(),
+                            round: state.last_block_round(),
+                        })),
+                        metadata: Some(metadata),
+                    },
+                )),
+            }
+            .encode_to_vec()
+        } else {
+            let maybe_balance = self.drive.fetch_identity_balance(
+                identity_id.into_buffer(),
+                None,
+                platform_version,
+            )?;
+
+            if maybe_balance.is_none() {
+                return Ok(ValidationResult::new_with_error(QueryError::NotFound(
+                    "No Identity found".to_string(),
+                )));
+            }
+
+            // Identities start without a stored nonce
+            let nonce = self
+                .drive
+                .fetch_identity_nonce(identity_id.into_buffer(), true, None, platform_version)?
+                .unwrap_or_default();
+
+            GetIdentityNonceResponse {
+                version: Some(get_identity_nonce_response::Version::V0(
+                    GetIdentityNonceResponseV0 {
+                        result: Some(get_identity_nonce_response_v0::Result::IdentityNonce(nonce)),
+                        metadata: Some(metadata),
+                    },
+                )),
+            }
+            .encode_to_vec()
+        };
+        Ok(QueryValidationResult::new_with_data(response_data))
+    }
+}
@@ -1,23 +1,79 @@
 mod data_contract_based_queries;
+mod document_count_query;
 mod document_query;
+mod historical_state;
 mod identity_based_queries;
 mod proofs;
 mod response_metadata;
+mod service;
+mod state_transition_fee_estimation;
 mod system;
 mod v0;
 
+pub use service::{start_grpc_server, QueryService};
+
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 
 use crate::error::execution::ExecutionError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::validation::ValidationResult;
 use dpp::version::PlatformVersion;
+use drive::drive::Drive;
+use drive::query::DriveQuery;
 
 /// A query validation result
 pub type QueryValidationResult<TData> = ValidationResult<TData, QueryError>;
 
-impl<C> Platform<C> {
+/// Returns an error if the query would use an index that is still being backfilled or whose
+/// backfill failed, as it would only return part of the matching documents.
+fn validate_query_index_is_backfilled(
+    drive: &Drive,
+    drive_query: &DriveQuery,
+    platform_version: &PlatformVersion,
+) -> Result<Option<QueryError>, Error> {
+    if drive_query.is_for_primary_key() {
+        return Ok(None);
+    }
+
+    // queries without a usable index are refused when they are executed
+    let Ok(index) = drive_query.find_best_index(platform_version) else {
+        return Ok(None);
+    };
+
+    let index_backfill = drive.fetch_index_backfill(
+        drive_query.contract.id_ref().as_bytes(),
+        drive_query.document_type.name(),
+        None,
+        platform_version,
+    )?;
+
+    Ok(index_backfill.and_then(|index_backfill| {
+        if index_backfill.is_index_pending(&index.name) {
+            Some(QueryError::InvalidArgument(format!(
+                "index {} of document type {} is being backfilled",
+                index.name,
+                drive_query.document_type.name()
+            )))
+        } else if index_backfill.is_index_failed(&index.name) {
+            Some(QueryError::InvalidArgument(format!(
+                "backfill of index {} of document type {} failed",
+                index.name,
+                drive_query.document_type.name()
+            )))
+        } else {
+            None
+        }
+    }))
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
     /// Querying
     pub fn query(
         &self,
@@ -155,14 +211,17 @@ mod tests {
             "/identity/balance",
             "/identity/balanceAndRevision",
             "/identity/keys",
+            "/identity/nonce",
             "/dataContract",
             "/dataContracts",
             "/dataContractHistory",
             "/documents",
             "/dataContract/documents",
+            "/documents/count",
             "/identity/by-public-key-hash",
             "/identities/by-public-key-hash",
             "/proofs",
+            "/stateTransition/estimate",
         ];
 
         paths.iter().for_each(|path| {
@@ -179,13 +238,16 @@ mod tests {
     }
 
     mod identity {
+        use crate::config::PlatformConfig;
         use crate::error::query::QueryError;
         use crate::query::tests::assert_invalid_identifier;
+        use crate::test::helpers::setup::TestPlatformBuilder;
         use bs58::encode;
         use dapi_grpc::platform::v0::get_identity_request::GetIdentityRequestV0;
         use dapi_grpc::platform::v0::{
             get_identity_request, get_identity_response, GetIdentityRequest, GetIdentityResponse,
         };
+        use platform_version::version::PlatformVersion;
         use prost::Message;
 
         const QUERY_PATH: &str = "/identity";
@@ -198,6 +260,7 @@ mod tests {
                 version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
                     id: vec![0; 8],
                     prove: false,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -216,6 +279,7 @@ mod tests {
                 version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
                     id: id.clone(),
                     prove: false,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -233,6 +297,61 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn test_identity_at_height_when_historical_queries_disabled() {
+            let (platform, version) = super::setup_platform();
+
+            let request = GetIdentityRequest {
+                version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
+                    id: vec![0; 32],
+                    prove: false,
+                    height: Some(1),
+                })),
+            }
+            .encode_to_vec();
+
+            let result = platform.query(QUERY_PATH, &request, version);
+            assert!(result.is_ok());
+            let validation_result = result.unwrap();
+            let validation_error = validation_result.first_error().unwrap();
+
+            assert!(matches!(
+                validation_error,
+                QueryError::InvalidArgument(msg) if msg.contains("historical queries are disabled")
+            ));
+        }
+
+        #[test]
+        fn test_identity_at_height_not_retained() {
+            let platform = TestPlatformBuilder::new()
+                .with_config(PlatformConfig {
+                    historical_state_retention_blocks: 10,
+                    ..Default::default()
+                })
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+            let version = PlatformVersion::latest();
+
+            let request = GetIdentityRequest {
+                version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
+                    id: vec![0; 32],
+                    prove: true,
+                    height: Some(100),
+                })),
+            }
+            .encode_to_vec();
+
+            let result = platform.query(QUERY_PATH, &request, version);
+            assert!(result.is_ok());
+            let validation_result = result.unwrap();
+            let validation_error = validation_result.first_error().unwrap();
+
+            assert!(matches!(
+                validation_error,
+                QueryError::NotFound(msg) if msg.contains("state at height 100 is not retained")
+            ));
+        }
+
         #[test]
         fn test_identity_absence_proof() {
             let (platform, version) = super::setup_platform();
@@ -242,6 +361,7 @@ mod tests {
                 version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
                     id: id.clone(),
                     prove: true,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -543,6 +663,91 @@ mod tests {
         }
     }
 
+    mod identity_nonce {
+        use crate::error::query::QueryError;
+        use dapi_grpc::platform::v0::get_identity_nonce_request::{
+            GetIdentityNonceRequestV0, Version,
+        };
+        use dapi_grpc::platform::v0::{
+            get_identity_nonce_response, GetIdentityNonceRequest, GetIdentityNonceResponse,
+        };
+        use prost::Message;
+
+        const QUERY_PATH: &str = "/identity/nonce";
+
+        #[test]
+        fn test_invalid_identity_id() {
+            let (platform, version) = super::setup_platform();
+
+            let request = GetIdentityNonceRequest {
+                version: Some(Version::V0(GetIdentityNonceRequestV0 {
+                    id: vec![0; 8],
+                    prove: false,
+                })),
+            }
+            .encode_to_vec();
+
+            let result = platform.query(QUERY_PATH, &request, version);
+            assert!(result.is_ok());
+            super::assert_invalid_identifier(result.unwrap());
+        }
+
+        #[test]
+        fn test_identity_not_found() {
+            let (platform, version) = super::setup_platform();
+
+            let request = GetIdentityNonceRequest {
+                version: Some(Version::V0(GetIdentityNonceRequestV0 {
+                    id: vec![0; 32],
+                    prove: false,
+                })),
+            }
+            .encode_to_vec();
+
+            let validation_result = platform
+                .query(QUERY_PATH, &request, version)
+                .expect("expected query to succeed");
+            let validation_error = validation_result.first_error().unwrap();
+
+            assert_eq!(
+                validation_error.to_string(),
+                "not found error: No Identity found".to_string()
+            );
+
+            assert!(matches!(validation_error, QueryError::NotFound(_)));
+        }
+
+        #[test]
+        fn test_identity_nonce_absence_proof() {
+            let (platform, version) = super::setup_platform();
+
+            let request = GetIdentityNonceRequest {
+                version: Some(Version::V0(GetIdentityNonceRequestV0 {
+                    id: vec![0; 32],
+                    prove: true,
+                })),
+            }
+            .encode_to_vec();
+
+            let result = platform.query(QUERY_PATH, &request, version);
+            let validation_result = result.unwrap();
+            let response =
+                GetIdentityNonceResponse::decode(validation_result.data.unwrap().as_slice())
+                    .unwrap();
+
+            assert!(matches!(
+                extract_single_variant_or_panic!(
+                    response.version.expect("expected a versioned response"),
+                    get_identity_nonce_response::Version::V0(inner),
+                    inner
+                )
+                .result
+                .unwrap(),
+                get_identity_nonce_response::get_identity_nonce_response_v0::Result::Proof(_)
+            ));
+        }
+    }
+
     mod identity_keys {
         use crate::error::query::QueryError;
         use dapi_grpc::platform::v0::get_identity_keys_request::{
@@ -846,6 +1051,7 @@ mod tests {
                 version: Some(Version::V0(GetDataContractRequestV0 {
                     id: vec![0; 8],
                     prove: false,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -864,6 +1070,7 @@ mod tests {
                 version: Some(Version::V0(GetDataContractRequestV0 {
                     id: id.clone(),
                     prove: false,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -890,6 +1097,7 @@ mod tests {
                 version: Some(Version::V0(GetDataContractRequestV0 {
                     id: id.clone(),
                     prove: true,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1191,6 +1399,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1215,6 +1424,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1249,6 +1459,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1286,6 +1497,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1318,6 +1530,7 @@ mod tests {
                     order_by: vec![0x9F], // Incomplete CBOR array
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1350,6 +1563,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: Some(Start::StartAt(vec![0; 8])),
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1382,6 +1596,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: Some(Start::StartAfter(vec![0; 8])),
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1415,6 +1630,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1447,6 +1663,7 @@ mod tests {
                     order_by: vec![],
                     prove: false,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1498,6 +1715,7 @@ mod tests {
                     order_by: vec![],
                     prove: true,
                     start: None,
+                    height: None,
                 })),
             }
             .encode_to_vec();
@@ -1524,6 +1742,215 @@ mod tests {
         }
     }
 
+    mod documents_count {
+        use crate::query::tests::store_data_contract;
+        use dapi_grpc::platform::v0::get_documents_count_request::{
+            GetDocumentsCountRequestV0, Version,
+        };
+        use dapi_grpc::platform::v0::{
+            get_documents_count_response, GetDocumentsCountRequest, GetDocumentsCountResponse,
+        };
+        use dpp::block::block_info::BlockInfo;
+        use dpp::data_contract::accessors::v0::DataContractV0Getters;
+        use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+        use dpp::document::{Document, DocumentV0};
+        use dpp::platform_value::{platform_value, Identifier, Value};
+        use dpp::tests::fixtures::get_data_contract_fixture;
+        use drive::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+        use drive::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+        use drive::drive::Drive;
+        use drive::query::{DocumentAggregate, DocumentAggregateDriveQuery, DriveQuery};
+        use prost::Message;
+        use std::collections::BTreeMap;
+
+        const QUERY_PATH: &str = "/documents/count";
+
+        #[test]
+        fn test_documents_count_and_sum_with_proof() {
+            let (platform, version) = super::setup_platform();
+
+            let mut data_contract =
+                get_data_contract_fixture(None, version.protocol_version).data_contract_owned();
+
+            let person_schema = platform_value!({
+                "type": "object",
+                "indices": [
+                    {
+                        "name": "lastNameFirstName",
+                        "properties": [{ "lastName": "asc" }, { "firstName": "asc" }],
+                        "countable": true,
+                        "summable": ["age"]
+                    }
+                ],
+                "properties": {
+                    "firstName": {
+                        "type": "string",
+                        "maxLength": 50,
+                        "position": 0
+                    },
+                    "lastName": {
+                        "type": "string",
+                        "maxLength": 50,
+                        "position": 1
+                    },
+                    "age": {
+                        "type": "integer",
+                        "position": 2
+                    }
+                },
+                "required": ["firstName", "lastName", "age"],
+                "additionalProperties": false,
+            });
+
+            data_contract
+                .set_document_schema("person", person_schema, true, version)
+                .expect("expected to set a document schema");
+
+            store_data_contract(&platform, &data_contract, version);
+
+            let document_type = data_contract
+                .document_type_for_name("person")
+                .expect("expected a person document type");
+
+            let people = [
+                ("Alice", "Doe", 30u64),
+                ("Bob", "Doe", 45),
+                ("Carol", "Smith", 27),
+            ];
+
+            for (i, (first_name, last_name, age)) in people.iter().enumerate() {
+                let document: Document = DocumentV0 {
+                    id: Identifier::new([i as u8 + 1; 32]),
+                    owner_id: Identifier::new([0; 32]),
+                    properties: BTreeMap::from([
+                        ("firstName".to_string(), Value::Text(first_name.to_string())),
+                        ("lastName".to_string(), Value::Text(last_name.to_string())),
+                        ("age".to_string(), Value::U64(*age)),
+                    ]),
+                    revision: None,
+                    created_at: None,
+                    updated_at: None,
+                }
+                .into();
+
+                platform
+                    .drive
+                    .add_document_for_contract(
+                        DocumentAndContractInfo {
+                            owned_document_info: OwnedDocumentInfo {
+                                document_info: DocumentRefInfo((&document, None)),
+                                owner_id: None,
+                            },
+                            contract: &data_contract,
+                            document_type,
+                        },
+                        false,
+                        BlockInfo::default(),
+                        true,
+                        None,
+                        version,
+                    )
+                    .expect("expected to add a document");
+            }
+
+            let where_clause = platform_value!([["lastName", "==", "Doe"]]);
+            let mut where_cbor = vec![];
+            ciborium::ser::into_writer(&where_clause, &mut where_cbor)
+                .expect("expected to serialize the where clause");
+
+            let query = |sum_property: &str, prove: bool| {
+                let request = GetDocumentsCountRequest {
+                    version: Some(Version::V0(GetDocumentsCountRequestV0 {
+                        data_contract_id: data_contract.id().to_vec(),
+                        document_type: "person".to_string(),
+                        r#where: where_cbor.clone(),
+                        sum_property: sum_property.to_string(),
+                        prove,
+                    })),
+                }
+                .encode_to_vec();
+
+                let validation_result = platform
+                    .query(QUERY_PATH, &request, version)
+                    .expect("expected query to succeed");
+                assert!(validation_result.is_valid());
+
+                let response = GetDocumentsCountResponse::decode(
+                    validation_result.data.expect("data must exist").as_slice(),
+                )
+                .expect("response decoded");
+
+                extract_single_variant_or_panic!(
+                    response.version.expect("expected a versioned response"),
+                    get_documents_count_response::Version::V0(inner),
+                    inner
+                )
+                .result
+                .expect("expected a result")
+            };
+
+            let verify = |aggregate: DocumentAggregate| {
+                let sum_property = match &aggregate {
+                    DocumentAggregate::Count => "",
+                    DocumentAggregate::Sum(property_name) => property_name.as_str(),
+                };
+
+                let get_documents_count_response::get_documents_count_response_v0::Result::Value(
+                    value,
+                ) = query(sum_property, false)
+                else {
+                    panic!("expected a value");
+                };
+
+                let get_documents_count_response::get_documents_count_response_v0::Result::Proof(
+                    proof,
+                ) = query(sum_property, true)
+                else {
+                    panic!("expected a proof");
+                };
+
+                let drive_query = DriveQuery::from_decomposed_values(
+                    where_clause.clone(),
+                    None,
+                    None,
+                    None,
+                    true,
+                    None,
+                    &data_contract,
+                    document_type,
+                    &platform.drive.config,
+                )
+                .expect("expected to build the query");
+                let aggregate_query = DocumentAggregateDriveQuery {
+                    query: drive_query,
+                    aggregate,
+                };
+
+                let (proof_root_hash, proved_value) = Drive::verify_documents_aggregate(
+                    proof.grovedb_proof.as_slice(),
+                    &aggregate_query,
+                    version,
+                )
+                .expect("expected to verify the proof");
+
+                let root_hash = platform
+                    .drive
+                    .grove
+                    .root_hash(None)
+                    .unwrap()
+                    .expect("expected a root hash");
+
+                assert_eq!(proof_root_hash, root_hash);
+                assert_eq!(proved_value, value);
+
+                value
+            };
+
+            assert_eq!(verify(DocumentAggregate::Count), 2);
+            assert_eq!(verify(DocumentAggregate::Sum("age".to_string())), 75);
+        }
+    }
+
     mod identity_by_public_key_hash {
         use crate::query::QueryError;
         use dapi_grpc::platform::v0::get_identity_by_public_key_hash_request::{
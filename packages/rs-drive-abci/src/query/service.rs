@@ -0,0 +1,467 @@
+use crate::config::GrpcConfig;
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::change_feed::filter::ChangeFeedFilter;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::platform_server::{self, PlatformServer};
+use dapi_grpc::platform::v0::{
+    subscribe_platform_events_request, BroadcastStateTransitionRequest,
+    BroadcastStateTransitionResponse, EstimateStateTransitionFeeRequest,
+    EstimateStateTransitionFeeResponse, GetConsensusParamsRequest, GetConsensusParamsResponse,
+    GetDataContractHistoryRequest, GetDataContractHistoryResponse, GetDataContractRequest,
+    GetDataContractResponse, GetDataContractsRequest, GetDataContractsResponse,
+    GetDocumentsCountRequest, GetDocumentsCountResponse, GetDocumentsRequest, GetDocumentsResponse,
+    GetEpochsInfoRequest, GetEpochsInfoResponse, GetIdentitiesByPublicKeyHashesRequest,
+    GetIdentitiesByPublicKeyHashesResponse, GetIdentitiesRequest, GetIdentitiesResponse,
+    GetIdentityBalanceAndRevisionRequest, GetIdentityBalanceAndRevisionResponse,
+    GetIdentityBalanceRequest, GetIdentityBalanceResponse, GetIdentityByPublicKeyHashRequest,
+    GetIdentityByPublicKeyHashResponse, GetIdentityKeysRequest, GetIdentityKeysResponse,
+    GetIdentityNonceRequest, GetIdentityNonceResponse, GetIdentityRequest, GetIdentityResponse,
+    GetProofsRequest, GetProofsResponse, GetProtocolVersionUpgradeStateRequest,
+    GetProtocolVersionUpgradeStateResponse, GetProtocolVersionUpgradeVoteStatusRequest,
+    GetProtocolVersionUpgradeVoteStatusResponse, SubscribePlatformEventsRequest,
+    SubscribePlatformEventsResponse, WaitForStateTransitionResultRequest,
+    WaitForStateTransitionResultResponse,
+};
+use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
+use prost::Message;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::Instant;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tokio_util::sync::CancellationToken;
+use tonic::{Request, Response, Status};
+
+/// Serves the Platform gRPC service straight from the platform, next to the ABCI connection.
+///
+/// Queries run on blocking threads, limited by [GrpcConfig::max_concurrent_queries], and give
+/// up at their deadline. They read the committed state only, retrying when a block is
+/// committed in the middle of a query, so the response data and metadata always belong to
+/// the same block.
+pub struct QueryService<C> {
+    platform: Arc<Platform<C>>,
+    query_slots: Arc<Semaphore>,
+    request_timeout: Duration,
+}
+
+impl<C> QueryService<C>
+where
+    C: CoreRPCLike + Send + Sync + 'static,
+{
+    /// Create a query service of the platform
+    pub fn new(platform: Arc<Platform<C>>, config: &GrpcConfig) -> Self {
+        Self {
+            platform,
+            query_slots: Arc::new(Semaphore::new(config.max_concurrent_queries)),
+            request_timeout: Duration::from_millis(config.request_timeout_ms),
+        }
+    }
+
+    async fn handle_query<RQ, RS>(
+        &self,
+        request: Request<RQ>,
+        query_path: &'static str,
+    ) -> Result<Response<RS>, Status>
+    where
+        RQ: Message,
+        RS: Message + Default,
+    {
+        let _timer = crate::metrics::abci_request_duration(query_path);
+
+        let deadline = Instant::now() + self.request_timeout;
+
+        let query_slot =
+            tokio::time::timeout_at(deadline, Arc::clone(&self.query_slots).acquire_owned())
+                .await
+                .map_err(|_| Status::resource_exhausted("too many queries in progress"))?
+                .map_err(|_| Status::unavailable("query service is shutting down"))?;
+
+        let platform = Arc::clone(&self.platform);
+        let query_data = request.into_inner().encode_to_vec();
+        let read_deadline = deadline.into_std();
+
+        // Blocking reads can't be interrupted, the slot is released once the read is over
+        let query = tokio::task::spawn_blocking(move || {
+            let _query_slot = query_slot;
+
+            let Some(platform_version) = PlatformVersion::get_maybe_current() else {
+                return Ok(None);
+            };
+
+            platform
+                .commit_sequence
+                .read_consistent(read_deadline, || {
+                    platform.query(query_path, &query_data, platform_version)
+                })
+                .transpose()
+        });
+
+        let result = tokio::time::timeout_at(deadline, query)
+            .await
+            .map_err(|_| Status::deadline_exceeded("query deadline exceeded"))?
+            .map_err(|e| Status::internal(format!("query failed: {}", e)))?
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| {
+                Status::unavailable("platform is not initialized or state is being committed")
+            })?;
+
+        if let Some(error) = result.errors.first() {
+            return Err(query_error_to_status(error));
+        }
+
+        let response = RS::decode(result.data.unwrap_or_default().as_slice())
+            .map_err(|e| Status::internal(format!("invalid query response: {}", e)))?;
+
+        Ok(Response::new(response))
+    }
+}
+
+fn query_error_to_status(error: &QueryError) -> Status {
+    match error {
+        QueryError::NotFound(message) => Status::not_found(message),
+        QueryError::InvalidArgument(message) => Status::invalid_argument(message),
+        QueryError::Query(error) => Status::invalid_argument(error.to_string()),
+        QueryError::ProtobufDecode(error) => Status::invalid_argument(error.to_string()),
+        QueryError::UnsupportedQueryVersion(..) => Status::invalid_argument(error.to_string()),
+        _ => Status::unknown(error.to_string()),
+    }
+}
+
+/// Stream of committed block events selected by a subscription
+pub type PlatformEventsStream =
+    Pin<Box<dyn Stream<Item = Result<SubscribePlatformEventsResponse, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl<C> platform_server::Platform for QueryService<C>
+where
+    C: CoreRPCLike + Send + Sync + 'static,
+{
+    async fn broadcast_state_transition(
+        &self,
+        _request: Request<BroadcastStateTransitionRequest>,
+    ) -> Result<Response<BroadcastStateTransitionResponse>, Status> {
+        Err(Status::unimplemented(
+            "state transitions are broadcast through Tenderdash",
+        ))
+    }
+
+    async fn estimate_state_transition_fee(
+        &self,
+        request: Request<EstimateStateTransitionFeeRequest>,
+    ) -> Result<Response<EstimateStateTransitionFeeResponse>, Status> {
+        self.handle_query(request, "/stateTransition/estimate")
+            .await
+    }
+
+    async fn get_identity(
+        &self,
+        request: Request<GetIdentityRequest>,
+    ) -> Result<Response<GetIdentityResponse>, Status> {
+        self.handle_query(request, "/identity").await
+    }
+
+    async fn get_identities(
+        &self,
+        request: Request<GetIdentitiesRequest>,
+    ) -> Result<Response<GetIdentitiesResponse>, Status> {
+        self.handle_query(request, "/identities").await
+    }
+
+    async fn get_identity_keys(
+        &self,
+        request: Request<GetIdentityKeysRequest>,
+    ) -> Result<Response<GetIdentityKeysResponse>, Status> {
+        self.handle_query(request, "/identity/keys").await
+    }
+
+    async fn get_identity_balance(
+        &self,
+        request: Request<GetIdentityBalanceRequest>,
+    ) -> Result<Response<GetIdentityBalanceResponse>, Status> {
+        self.handle_query(request, "/identity/balance").await
+    }
+
+    async fn get_identity_balance_and_revision(
+        &self,
+        request: Request<GetIdentityBalanceAndRevisionRequest>,
+    ) -> Result<Response<GetIdentityBalanceAndRevisionResponse>, Status> {
+        self.handle_query(request, "/identity/balanceAndRevision")
+            .await
+    }
+
+    async fn get_identity_nonce(
+        &self,
+        request: Request<GetIdentityNonceRequest>,
+    ) -> Result<Response<GetIdentityNonceResponse>, Status> {
+        self.handle_query(request, "/identity/nonce").await
+    }
+
+    async fn get_proofs(
+        &self,
+        request: Request<GetProofsRequest>,
+    ) -> Result<Response<GetProofsResponse>, Status> {
+        self.handle_query(request, "/proofs").await
+    }
+
+    async fn get_data_contract(
+        &self,
+        request: Request<GetDataContractRequest>,
+    ) -> Result<Response<GetDataContractResponse>, Status> {
+        self.handle_query(request, "/dataContract").await
+    }
+
+    async fn get_data_contract_history(
+        &self,
+        request: Request<GetDataContractHistoryRequest>,
+    ) -> Result<Response<GetDataContractHistoryResponse>, Status> {
+        self.handle_query(request, "/dataContractHistory").await
+    }
+
+    async fn get_data_contracts(
+        &self,
+        request: Request<GetDataContractsRequest>,
+    ) -> Result<Response<GetDataContractsResponse>, Status> {
+        self.handle_query(request, "/dataContracts").await
+    }
+
+    async fn get_documents(
+        &self,
+        request: Request<GetDocumentsRequest>,
+    ) -> Result<Response<GetDocumentsResponse>, Status> {
+        self.handle_query(request, "/documents").await
+    }
+
+    async fn get_documents_count(
+        &self,
+        request: Request<GetDocumentsCountRequest>,
+    ) -> Result<Response<GetDocumentsCountResponse>, Status> {
+        self.handle_query(request, "/documents/count").await
+    }
+
+    async fn get_identities_by_public_key_hashes(
+        &self,
+        request: Request<GetIdentitiesByPublicKeyHashesRequest>,
+    ) -> Result<Response<GetIdentitiesByPublicKeyHashesResponse>, Status> {
+        self.handle_query(request, "/identities/by-public-key-hash")
+            .await
+    }
+
+    async fn get_identity_by_public_key_hash(
+        &self,
+        request: Request<GetIdentityByPublicKeyHashRequest>,
+    ) -> Result<Response<GetIdentityByPublicKeyHashResponse>, Status> {
+        self.handle_query(request, "/identity/by-public-key-hash")
+            .await
+    }
+
+    async fn wait_for_state_transition_result(
+        &self,
+        _request: Request<WaitForStateTransitionResultRequest>,
+    ) -> Result<Response<WaitForStateTransitionResultResponse>, Status> {
+        Err(Status::unimplemented(
+            "state transition results are tracked through Tenderdash",
+        ))
+    }
+
+    async fn get_consensus_params(
+        &self,
+        _request: Request<GetConsensusParamsRequest>,
+    ) -> Result<Response<GetConsensusParamsResponse>, Status> {
+        Err(Status::unimplemented(
+            "consensus params are provided by Tenderdash",
+        ))
+    }
+
+    async fn get_protocol_version_upgrade_state(
+        &self,
+        request: Request<GetProtocolVersionUpgradeStateRequest>,
+    ) -> Result<Response<GetProtocolVersionUpgradeStateResponse>, Status> {
+        self.handle_query(request, "/versionUpgrade/state").await
+    }
+
+    async fn get_protocol_version_upgrade_vote_status(
+        &self,
+        request: Request<GetProtocolVersionUpgradeVoteStatusRequest>,
+    ) -> Result<Response<GetProtocolVersionUpgradeVoteStatusResponse>, Status> {
+        self.handle_query(request, "/versionUpgrade/voteStatus")
+            .await
+    }
+
+    async fn get_epochs_info(
+        &self,
+        request: Request<GetEpochsInfoRequest>,
+    ) -> Result<Response<GetEpochsInfoResponse>, Status> {
+        self.handle_query(request, "/epochInfos").await
+    }
+
+    #[allow(non_camel_case_types)]
+    type subscribePlatformEventsStream = PlatformEventsStream;
+
+    async fn subscribe_platform_events(
+        &self,
+        request: Request<SubscribePlatformEventsRequest>,
+    ) -> Result<Response<Self::subscribePlatformEventsStream>, Status> {
+        let Some(subscribe_platform_events_request::Version::V0(request_v0)) =
+            request.into_inner().version
+        else {
+            return Err(Status::invalid_argument(
+                "subscription request version is missing",
+            ));
+        };
+
+        let filter =
+            ChangeFeedFilter::try_from(request_v0).map_err(|e| query_error_to_status(&e))?;
+
+        let events = BroadcastStream::new(self.platform.change_feed.subscribe()).filter_map(
+            move |block_change_feed| match block_change_feed {
+                Ok(block_change_feed) => {
+                    block_change_feed.to_subscription_response(&filter).map(Ok)
+                }
+                Err(BroadcastStreamRecvError::Lagged(skipped_blocks)) => {
+                    Some(Err(Status::data_loss(format!(
+                        "subscriber fell behind, events of {} blocks were skipped",
+                        skipped_blocks
+                    ))))
+                }
+            },
+        );
+
+        Ok(Response::new(Box::pin(events)))
+    }
+}
+
+/// Start serving Platform queries over gRPC on the configured address.
+///
+/// The server runs on its own runtime in a new thread, until `cancel` is triggered.
+/// Returns `None` if no bind address is configured.
+pub fn start_grpc_server<C>(
+    platform: Arc<Platform<C>>,
+    config: &GrpcConfig,
+    cancel: CancellationToken,
+) -> Result<Option<JoinHandle<()>>, Error>
+where
+    C: CoreRPCLike + Send + Sync + 'static,
+{
+    let Some(bind_address) = &config.bind_address else {
+        return Ok(None);
+    };
+
+    let address: SocketAddr = bind_address
+        .parse()
+        .map_err(|e| Error::QueryServer(format!("invalid bind address {}: {}", bind_address, e)))?;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(config.threads)
+        .max_blocking_threads(config.max_concurrent_queries)
+        .thread_name("grpc-query")
+        // Some recursions in GroveDB can be pretty deep
+        .thread_stack_size(8 * 1024 * 1024)
+        .enable_all()
+        .build()
+        .map_err(|e| Error::QueryServer(format!("can't create runtime: {}", e)))?;
+
+    let service = QueryService::new(platform, config);
+    let request_timeout = service.request_timeout;
+
+    let handle = std::thread::Builder::new()
+        .name("grpc-query-server".to_string())
+        .spawn(move || {
+            tracing::info!(%address, "starting gRPC query server");
+
+            let result = runtime.block_on(
+                tonic::transport::Server::builder()
+                    .timeout(request_timeout)
+                    .add_service(PlatformServer::new(service))
+                    .serve_with_shutdown(address, cancel.cancelled()),
+            );
+
+            if let Err(error) = result {
+                tracing::error!(?error, "gRPC query server failed");
+            }
+        })
+        .map_err(|e| Error::QueryServer(format!("can't start server thread: {}", e)))?;
+
+    Ok(Some(handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_types::change_feed::event::ChangeFeedEvent;
+    use crate::platform_types::change_feed::BlockChangeFeed;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dapi_grpc::platform::v0::platform_server::Platform as _;
+    use dapi_grpc::platform::v0::subscribe_platform_events_request::SubscribePlatformEventsRequestV0;
+    use dapi_grpc::platform::v0::subscribe_platform_events_response;
+    use dpp::prelude::Identifier;
+
+    #[tokio::test]
+    async fn should_stream_selected_events_of_committed_blocks() {
+        let TempPlatform {
+            platform,
+            tempdir: _tempdir,
+        } = TestPlatformBuilder::new().build_with_mock_rpc();
+        let platform = Arc::new(platform);
+
+        let service = QueryService::new(Arc::clone(&platform), &GrpcConfig::default());
+
+        let identity_id = Identifier::new([3; 32]);
+
+        let mut stream = service
+            .subscribe_platform_events(Request::new(SubscribePlatformEventsRequest {
+                version: Some(subscribe_platform_events_request::Version::V0(
+                    SubscribePlatformEventsRequestV0 {
+                        data_contract_ids: vec![],
+                        document_types: vec![],
+                        identity_ids: vec![identity_id.to_vec()],
+                    },
+                )),
+            }))
+            .await
+            .expect("expected to subscribe")
+            .into_inner();
+
+        assert!(platform.change_feed.has_subscribers());
+
+        let balance_changed = ChangeFeedEvent::IdentityBalanceChanged {
+            identity_id,
+            balance: 10,
+        };
+
+        platform.change_feed.stage(BlockChangeFeed {
+            height: 1,
+            round: 0,
+            time_ms: 1000,
+            events: vec![
+                ChangeFeedEvent::DocumentDeleted {
+                    data_contract_id: Identifier::new([1; 32]),
+                    document_type_name: "note".to_string(),
+                    document_id: Identifier::new([2; 32]),
+                },
+                balance_changed.clone(),
+            ],
+        });
+        platform.change_feed.publish_staged(1, 0);
+
+        let response = stream
+            .next()
+            .await
+            .expect("expected a streamed block")
+            .expect("expected a response");
+
+        let Some(subscribe_platform_events_response::Version::V0(response)) = response.version
+        else {
+            panic!("expected a v0 response");
+        };
+
+        assert_eq!(response.height, 1);
+        assert_eq!(response.events, vec![balance_changed.into()]);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_request::Version;
+use dapi_grpc::platform::v0::EstimateStateTransitionFeeRequest;
+use dpp::check_validation_result_with_data;
+use dpp::validation::ValidationResult;
+
+use dpp::version::PlatformVersion;
+use prost::Message;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Querying of the estimated fees of a state transition
+    pub(in crate::query) fn query_state_transition_fee_estimation(
+        &self,
+        state: &PlatformState,
+        query_data: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
+        let EstimateStateTransitionFeeRequest { version } = check_validation_result_with_data!(
+            EstimateStateTransitionFeeRequest::decode(query_data).map_err(|e| {
+                QueryError::InvalidArgument(format!("invalid query proto message: {}", e))
+            })
+        );
+
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError(
+                    "could not decode state transition fee estimation query".to_string(),
+                ),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version
+            .drive_abci
+            .query
+            .state_transition_fee_estimation_query;
+
+        let feature_version = match &version {
+            Version::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "state_transition_fee_estimation".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            Version::V0(request) => {
+                self.query_state_transition_fee_estimation_v0(state, request, platform_version)
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_request::EstimateStateTransitionFeeRequestV0;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_response::estimate_state_transition_fee_response_v0::FeeRefund;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_response::EstimateStateTransitionFeeResponseV0;
+use dapi_grpc::platform::v0::{
+    estimate_state_transition_fee_response, EstimateStateTransitionFeeResponse,
+    StateTransitionBroadcastError,
+};
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::serialization::PlatformSerializableWithPlatformVersion;
+use dpp::version::PlatformVersion;
+use prost::Message;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn query_state_transition_fee_estimation_v0(
+        &self,
+        state: &PlatformState,
+        request: EstimateStateTransitionFeeRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
+        let metadata = self.response_metadata_v0(state);
+        let EstimateStateTransitionFeeRequestV0 { state_transition } = request;
+
+        let estimation_result =
+            self.estimate_state_transition_fee(&state_transition, state, platform_version)?;
+
+        let errors = estimation_result
+            .errors
+            .iter()
+            .map(|consensus_error| {
+                Ok(StateTransitionBroadcastError {
+                    code: consensus_error.code(),
+                    message: consensus_error.to_string(),
+                    data: consensus_error
+                        .serialize_to_bytes_with_platform_version(platform_version)
+                        .map_err(Error::Protocol)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let fee_result = estimation_result.data.unwrap_or_default();
+
+        let fee_refunds = fee_result
+            .fee_refunds
+            .iter()
+            .map(|(identity_id, credits_per_epoch)| FeeRefund {
+                identity_id: identity_id.to_vec(),
+                credits: credits_per_epoch.values().sum(),
+            })
+            .collect();
+
+        let response_data = EstimateStateTransitionFeeResponse {
+            version: Some(estimate_state_transition_fee_response::Version::V0(
+                EstimateStateTransitionFeeResponseV0 {
+                    storage_fee: fee_result.storage_fee,
+                    processing_fee: fee_result.processing_fee,
+                    fee_refunds,
+                    errors,
+                    metadata: Some(metadata),
+                },
+            )),
+        }
+        .encode_to_vec();
+
+        Ok(QueryValidationResult::new_with_data(response_data))
+    }
+}
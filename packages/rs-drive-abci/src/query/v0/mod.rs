@@ -1,12 +1,16 @@
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 
 use crate::query::QueryValidationResult;
 
 use dpp::version::PlatformVersion;
 
-impl<C> Platform<C> {
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
     /// Querying
     pub(super) fn query_v0(
         &self,
@@ -23,6 +27,7 @@ impl<C> Platform<C> {
                 self.query_balance_and_revision(&state, query_data, platform_version)
             }
             "/identity/keys" => self.query_keys(&state, query_data, platform_version),
+            "/identity/nonce" => self.query_identity_nonce(&state, query_data, platform_version),
             "/identity/by-public-key-hash" => {
                 self.query_identity_by_public_key_hash(&state, query_data, platform_version)
             }
@@ -37,7 +42,11 @@ impl<C> Platform<C> {
             "/documents" | "/dataContract/documents" => {
                 self.query_documents(&state, query_data, platform_version)
             }
+            "/documents/count" => self.query_documents_count(&state, query_data, platform_version),
             "/proofs" => self.query_proofs(&state, query_data, platform_version),
+            "/stateTransition/estimate" => {
+                self.query_state_transition_fee_estimation(&state, query_data, platform_version)
+            }
             "/versionUpgrade/state" => {
                 self.query_version_upgrade_state(&state, query_data, platform_version)
             }
@@ -32,15 +32,25 @@
 //! This module defines helper functions related to setting up Platform.
 //!
 
+use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 
+use crate::mimic::test_quorum::TestQuorumInfo;
 use crate::platform_types::platform::Platform;
 #[cfg(any(feature = "mocks", test))]
 use crate::rpc::core::MockCoreRPCLike;
 use crate::test::fixture::abci::static_system_identity_public_keys_v0;
 use crate::{config::PlatformConfig, rpc::core::DefaultCoreRPC};
+use dashcore_rpc::dashcore::hashes::Hash;
+use dashcore_rpc::dashcore::{BlockHash, ProTxHash, QuorumHash};
+use dashcore_rpc::dashcore_rpc_json::{
+    ExtendedQuorumDetails, ExtendedQuorumListResult, MasternodeListDiff, QuorumInfoResult,
+};
 use dpp::version::PlatformVersion;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use tempfile::TempDir;
+use tenderdash_abci::proto::types::CoreChainLock;
 
 /// A test platform builder.
 pub struct TestPlatformBuilder {
@@ -134,6 +144,83 @@ impl TempPlatform<MockCoreRPCLike> {
         self
     }
 
+    /// Mocks Core to report an empty masternode list and a single validator quorum
+    /// at any core height
+    pub fn with_core_validator_set(mut self, quorum_hash: QuorumHash) -> Self {
+        let quorum_type = self.platform.config.quorum_type();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let pro_tx_hashes = (0..3)
+            .map(|_| ProTxHash::from_byte_array(rng.gen()))
+            .collect();
+        let quorum_info: QuorumInfoResult = (&TestQuorumInfo::from_quorum_hash_and_pro_tx_hashes(
+            0,
+            quorum_hash,
+            pro_tx_hashes,
+            &mut rng,
+        ))
+            .into();
+
+        self.platform
+            .core_rpc
+            .expect_get_protx_diff_with_masternodes()
+            .returning(|base_block, block| {
+                Ok(MasternodeListDiff {
+                    base_height: base_block.unwrap_or_default(),
+                    block_height: block,
+                    added_mns: vec![],
+                    removed_mns: vec![],
+                    updated_mns: vec![],
+                })
+            });
+
+        self.platform
+            .core_rpc
+            .expect_get_quorum_listextended()
+            .returning(move |_| {
+                Ok(ExtendedQuorumListResult {
+                    quorums_by_type: HashMap::from([(
+                        quorum_type,
+                        vec![(
+                            quorum_hash,
+                            ExtendedQuorumDetails {
+                                creation_height: 0,
+                                quorum_index: None,
+                                mined_block_hash: BlockHash::all_zeros(),
+                                num_valid_members: 0,
+                                health_ratio: 0.0,
+                            },
+                        )]
+                        .into_iter()
+                        .collect(),
+                    )]),
+                })
+            });
+
+        self.platform
+            .core_rpc
+            .expect_get_quorum_info()
+            .returning(move |_, _, _| Ok(quorum_info.clone()));
+
+        self
+    }
+
+    /// Mocks Core to report a best chain lock at `core_height`
+    pub fn with_core_chain_locked_height(mut self, core_height: u32) -> Self {
+        self.platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: core_height,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+
+        self
+    }
+
     /// Rebuilds Platform from the tempdir as if it was destroyed and restarted
     pub fn open_with_tempdir(tempdir: TempDir, config: PlatformConfig) -> Self {
         let platform = Platform::<MockCoreRPCLike>::open(tempdir.path(), Some(config))
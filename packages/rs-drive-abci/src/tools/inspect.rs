@@ -0,0 +1,135 @@
+use crate::error::Error;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::conversion::json::DataContractJsonConversionMethodsV0;
+use dpp::platform_value::{Identifier, Value};
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::drive::Drive;
+use drive::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use serde_json::{json, Value as JsonValue};
+
+/// Returns the identity as JSON, or `None` if it doesn't exist
+pub fn inspect_identity(
+    drive: &Drive,
+    identity_id: Identifier,
+    platform_version: &PlatformVersion,
+) -> Result<Option<JsonValue>, Error> {
+    drive
+        .fetch_full_identity(identity_id.to_buffer(), None, platform_version)?
+        .map(|identity| {
+            serde_json::to_value(identity)
+                .map_err(|e| Error::Tools(format!("unable to convert identity to json: {}", e)))
+        })
+        .transpose()
+}
+
+/// Returns the data contract as JSON, or `None` if it doesn't exist
+pub fn inspect_data_contract(
+    drive: &Drive,
+    contract_id: Identifier,
+    platform_version: &PlatformVersion,
+) -> Result<Option<JsonValue>, Error> {
+    drive
+        .fetch_contract(contract_id.to_buffer(), None, None, None, platform_version)
+        .unwrap()?
+        .map(|contract_fetch_info| {
+            contract_fetch_info
+                .contract
+                .to_json(platform_version)
+                .map_err(Error::Protocol)
+        })
+        .transpose()
+}
+
+/// Returns the document as JSON, or `None` if it doesn't exist
+pub fn inspect_document(
+    drive: &Drive,
+    contract_id: Identifier,
+    document_type_name: &str,
+    document_id: Identifier,
+    platform_version: &PlatformVersion,
+) -> Result<Option<JsonValue>, Error> {
+    let Some(contract_fetch_info) = drive
+        .fetch_contract(contract_id.to_buffer(), None, None, None, platform_version)
+        .unwrap()?
+    else {
+        return Err(Error::Tools(format!(
+            "data contract {} doesn't exist",
+            contract_id
+        )));
+    };
+
+    let contract = &contract_fetch_info.contract;
+    let document_type = contract.document_type_for_name(document_type_name)?;
+
+    let drive_query = DriveQuery {
+        contract,
+        document_type,
+        internal_clauses: InternalClauses {
+            primary_key_in_clause: None,
+            primary_key_equal_clause: Some(WhereClause {
+                field: "$id".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(document_id.to_buffer()),
+            }),
+            in_clause: None,
+            range_clause: None,
+            equal_clauses: Default::default(),
+            other_in_clauses: vec![],
+            or_clauses: vec![],
+        },
+        offset: None,
+        limit: Some(1),
+        order_by: Default::default(),
+        start_at: None,
+        start_at_included: false,
+        block_time_ms: None,
+    };
+
+    drive
+        .query_documents(
+            drive_query,
+            None,
+            false,
+            None,
+            Some(platform_version.protocol_version),
+        )?
+        .documents_owned()
+        .into_iter()
+        .next()
+        .map(|document| {
+            serde_json::to_value(document)
+                .map_err(|e| Error::Tools(format!("unable to convert document to json: {}", e)))
+        })
+        .transpose()
+}
+
+/// Returns a summary of the platform state as JSON
+pub fn inspect_platform_state(platform_state: &PlatformState) -> JsonValue {
+    json!({
+        "lastBlockHeight": platform_state.last_block_height(),
+        "lastBlockRound": platform_state.last_block_round(),
+        "lastBlockTimeMs": platform_state.last_block_time_ms(),
+        "lastBlockAppHash": platform_state.last_block_app_hash().map(hex::encode),
+        "coreHeight": platform_state.core_height(),
+        "epoch": platform_state.epoch().index,
+        "currentProtocolVersionInConsensus": platform_state.current_protocol_version_in_consensus(),
+        "nextEpochProtocolVersion": platform_state.next_epoch_protocol_version(),
+        "currentValidatorSetQuorumHash": platform_state.current_validator_set_quorum_hash().to_string(),
+        "nextValidatorSetQuorumHash": platform_state
+            .next_validator_set_quorum_hash()
+            .as_ref()
+            .map(|quorum_hash| quorum_hash.to_string()),
+        "validatorSetQuorumHashes": platform_state
+            .validator_sets()
+            .keys()
+            .map(|quorum_hash| quorum_hash.to_string())
+            .collect::<Vec<_>>(),
+        "fullMasternodeListLength": platform_state.full_masternode_list().len(),
+        "hpmnMasternodeListLength": platform_state.hpmn_list_len(),
+        "genesisBlockInfo": platform_state.genesis_block_info(),
+        "consensusParams": platform_state.consensus_params(),
+    })
+}
@@ -0,0 +1,186 @@
+//! Offline tools working on the state of a stopped node.
+//!
+//! The database at `DB_PATH` is never opened: state is inspected, exported and replayed from a
+//! copy of it, so recovering or compacting the opened database doesn't touch the node's files.
+//! The copy is only consistent if the node is stopped first.
+//!
+
+/// Dump identities, data contracts, documents and the platform state as JSON
+pub mod inspect;
+/// Re-execute recorded blocks and find the first diverging app hash
+pub mod replay;
+/// Export and import the state as portable snapshot files
+pub mod snapshot_file;
+
+use crate::error::Error;
+use crate::platform_types::platform_state::PlatformState;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use drive::drive::config::DriveConfig;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use std::fs;
+use std::ops::Deref;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// A drive opened on a temporary copy of the database of a stopped node.
+///
+/// The copy is removed once dropped.
+pub struct DriveCopy {
+    // Declared first, so the drive is closed before its directory is removed
+    drive: Drive,
+    _dir: TempDir,
+}
+
+impl Deref for DriveCopy {
+    type Target = Drive;
+
+    fn deref(&self) -> &Self::Target {
+        &self.drive
+    }
+}
+
+/// Opens the drive of a stopped node on a temporary copy of its database.
+///
+/// Fails if there is no database at the path, instead of creating an empty one.
+pub fn open_drive(db_path: &Path, config: &DriveConfig) -> Result<DriveCopy, Error> {
+    let dir = tempdir_next_to(db_path)?;
+
+    let copy_path = dir.path().join("db");
+
+    copy_database(db_path, &copy_path)?;
+
+    let drive = Drive::open(&copy_path, Some(config.clone()), PlatformVersion::latest())
+        .map_err(Error::Drive)?;
+
+    Ok(DriveCopy { drive, _dir: dir })
+}
+
+/// Creates a temporary directory in the directory containing the database, so copies of the
+/// database are on the same filesystem and its table files can be hard linked.
+pub fn tempdir_next_to(db_path: &Path) -> Result<TempDir, Error> {
+    let parent_path = db_path
+        .parent()
+        .filter(|path| !path.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    tempfile::tempdir_in(parent_path)
+        .map_err(|e| Error::Tools(format!("can't create temporary directory: {}", e)))
+}
+
+/// Copies the database of a stopped node to a path that must not exist yet, like a GroveDB
+/// checkpoint but without opening the database.
+///
+/// Table files are never modified once written, so they are hard linked when possible.
+pub fn copy_database(db_path: &Path, copy_path: &Path) -> Result<(), Error> {
+    if !db_path.join("CURRENT").exists() {
+        return Err(Error::Tools(format!(
+            "no database found at {}",
+            db_path.display()
+        )));
+    }
+
+    if copy_path.exists() {
+        return Err(Error::Tools(format!(
+            "{} already exists",
+            copy_path.display()
+        )));
+    }
+
+    let tools_error = |path: &Path, e: std::io::Error| {
+        Error::Tools(format!("can't copy {}: {}", path.display(), e))
+    };
+
+    fs::create_dir_all(copy_path).map_err(|e| tools_error(copy_path, e))?;
+
+    for entry in fs::read_dir(db_path).map_err(|e| tools_error(db_path, e))? {
+        let entry = entry.map_err(|e| tools_error(db_path, e))?;
+        let path = entry.path();
+
+        if !path.is_file() || entry.file_name() == "LOCK" {
+            continue;
+        }
+
+        let target = copy_path.join(entry.file_name());
+
+        let is_table_file = path.extension().is_some_and(|extension| extension == "sst");
+
+        if !is_table_file || fs::hard_link(&path, &target).is_err() {
+            fs::copy(&path, &target).map_err(|e| tools_error(&path, e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the platform state of the last committed block, if any block was committed
+pub fn load_platform_state(drive: &Drive) -> Result<Option<PlatformState>, Error> {
+    let Some(serialized_platform_state) = drive
+        .grove
+        .get_aux(b"saved_state", None)
+        .unwrap()
+        .map_err(|e| Error::Drive(GroveDB(e)))?
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(PlatformState::deserialize_from_bytes_no_limit(
+        &serialized_platform_state,
+    )?))
+}
+
+/// Creates a GroveDB checkpoint of the drive at a path that must not exist yet
+pub fn create_checkpoint(drive: &Drive, checkpoint_path: &Path) -> Result<(), Error> {
+    drive
+        .grove
+        .create_checkpoint(checkpoint_path)
+        .map_err(|e| Error::Drive(GroveDB(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn should_open_copy_of_database() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let root_hash = platform.drive.grove.root_hash(None).unwrap().unwrap();
+
+        let drive = open_drive(platform.tempdir.path(), &platform.config.drive)
+            .expect("expected to open drive");
+
+        assert_eq!(drive.grove.root_hash(None).unwrap().unwrap(), root_hash);
+
+        // The copy is on the filesystem of the database, so table files can be hard linked
+        assert_eq!(drive._dir.path().parent(), platform.tempdir.path().parent());
+
+        drive
+            .grove
+            .put_aux(b"tools_test", b"value", None, None)
+            .unwrap()
+            .expect("expected to write to the copy");
+
+        let original_value = platform
+            .drive
+            .grove
+            .get_aux(b"tools_test", None)
+            .unwrap()
+            .expect("expected to read the original database");
+
+        assert_eq!(original_value, None);
+    }
+
+    #[test]
+    fn should_not_open_missing_database() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let result = open_drive(&dir.path().join("db"), &DriveConfig::default());
+
+        assert!(matches!(result, Err(Error::Tools(_))));
+    }
+}
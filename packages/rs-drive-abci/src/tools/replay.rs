@@ -0,0 +1,282 @@
+use crate::abci::AbciApplication;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+use prost::Message;
+use std::collections::BTreeMap;
+use tenderdash_abci::proto::abci as proto;
+use tenderdash_abci::proto::abci::request::Value;
+use tenderdash_abci::Application;
+
+/// The first block whose app hash differs from the recorded one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayDivergence {
+    /// Height of the block
+    pub height: u64,
+    /// Round in which the block was committed
+    pub round: i32,
+    /// App hash in the header of the recorded block
+    pub expected_app_hash: Vec<u8>,
+    /// App hash produced by the replay
+    pub app_hash: Vec<u8>,
+}
+
+/// Outcome of a replay
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReplayOutcome {
+    /// Number of blocks that were replayed and committed
+    pub blocks_replayed: u64,
+    /// The first diverging block, if any
+    pub divergence: Option<ReplayDivergence>,
+}
+
+/// Reads length-delimited protobuf encoded ABCI requests, as they are written by Tenderdash
+pub fn read_recorded_requests(mut bytes: &[u8]) -> Result<Vec<proto::Request>, Error> {
+    let mut requests = Vec::new();
+
+    while !bytes.is_empty() {
+        let request = proto::Request::decode_length_delimited(&mut bytes).map_err(|e| {
+            Error::Tools(format!(
+                "unable to decode recorded request {}: {}",
+                requests.len(),
+                e
+            ))
+        })?;
+
+        requests.push(request);
+    }
+
+    Ok(requests)
+}
+
+/// Re-executes recorded blocks and stops at the first block whose app hash differs from the
+/// one in its recorded header.
+///
+/// Blocks are executed the way a validator that isn't the proposer executes them, so a
+/// recorded `RequestPrepareProposal` is turned into a `RequestProcessProposal` using the
+/// finalized block. That also avoids asking Core for chain locks. Blocks at or below the last
+/// committed height are skipped, and requests other than `InitChain`, proposals and
+/// `FinalizeBlock` are ignored.
+///
+/// The platform must be opened on a copy of the state, as replayed blocks are committed.
+pub fn replay_blocks<C: CoreRPCLike>(
+    platform: &Platform<C>,
+    requests: Vec<proto::Request>,
+) -> Result<ReplayOutcome, Error> {
+    let abci = AbciApplication::new(platform)?;
+
+    let mut outcome = ReplayOutcome::default();
+    let mut proposals: BTreeMap<(i64, i32), Value> = BTreeMap::new();
+
+    for request in requests.into_iter().filter_map(|request| request.value) {
+        match request {
+            Value::InitChain(request) => {
+                if platform.state.read().unwrap().last_block_height() > 0 {
+                    continue;
+                }
+
+                abci.init_chain(request)
+                    .map_err(|e| Error::Tools(format!("init chain failed: {:?}", e)))?;
+            }
+            Value::PrepareProposal(prepare) => {
+                proposals.insert(
+                    (prepare.height, prepare.round),
+                    Value::PrepareProposal(prepare),
+                );
+            }
+            Value::ProcessProposal(process) => {
+                proposals.insert(
+                    (process.height, process.round),
+                    Value::ProcessProposal(process),
+                );
+            }
+            Value::FinalizeBlock(finalize) => {
+                let last_block_height = platform.state.read().unwrap().last_block_height();
+
+                if finalize.height as u64 <= last_block_height {
+                    continue;
+                }
+
+                let proposal = proposals
+                    .remove(&(finalize.height, finalize.round))
+                    .ok_or_else(|| {
+                        Error::Tools(format!(
+                            "no proposal recorded for height {} round {}",
+                            finalize.height, finalize.round
+                        ))
+                    })?;
+
+                // Proposals of earlier rounds of this height were not committed
+                proposals.retain(|(height, _), _| *height > finalize.height);
+
+                let process = process_proposal_request(proposal, &finalize)?;
+
+                let response = abci.process_proposal(process).map_err(|e| {
+                    Error::Tools(format!(
+                        "processing block {} failed: {:?}",
+                        finalize.height, e
+                    ))
+                })?;
+
+                if response.status
+                    != proto::response_process_proposal::ProposalStatus::Accept as i32
+                {
+                    return Err(Error::Tools(format!(
+                        "block {} was rejected",
+                        finalize.height
+                    )));
+                }
+
+                let expected_app_hash = finalize
+                    .block
+                    .as_ref()
+                    .and_then(|block| block.header.as_ref())
+                    .map(|header| header.app_hash.clone())
+                    .ok_or_else(|| {
+                        Error::Tools(format!("recorded block {} has no header", finalize.height))
+                    })?;
+
+                if response.app_hash != expected_app_hash {
+                    outcome.divergence = Some(ReplayDivergence {
+                        height: finalize.height as u64,
+                        round: finalize.round,
+                        expected_app_hash,
+                        app_hash: response.app_hash,
+                    });
+
+                    return Ok(outcome);
+                }
+
+                abci.finalize_block(finalize)
+                    .map_err(|e| Error::Tools(format!("finalize block failed: {:?}", e)))?;
+
+                outcome.blocks_replayed += 1;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(outcome)
+}
+
+/// The request a validator that isn't the proposer receives for the finalized block
+fn process_proposal_request(
+    proposal: Value,
+    finalize: &proto::RequestFinalizeBlock,
+) -> Result<proto::RequestProcessProposal, Error> {
+    let prepare = match proposal {
+        Value::ProcessProposal(process) => return Ok(process),
+        Value::PrepareProposal(prepare) => prepare,
+        _ => {
+            return Err(Error::Tools(
+                "recorded proposal is not a proposal request".to_string(),
+            ))
+        }
+    };
+
+    let block = finalize.block.as_ref().ok_or_else(|| {
+        Error::Tools(format!(
+            "recorded finalize block request {} has no block",
+            finalize.height
+        ))
+    })?;
+
+    Ok(proto::RequestProcessProposal {
+        txs: block
+            .data
+            .as_ref()
+            .map(|data| data.txs.clone())
+            .unwrap_or_default(),
+        proposed_last_commit: None,
+        misbehavior: prepare.misbehavior,
+        hash: finalize.hash.clone(),
+        height: prepare.height,
+        round: prepare.round,
+        time: prepare.time,
+        next_validators_hash: prepare.next_validators_hash,
+        core_chain_locked_height: block
+            .header
+            .as_ref()
+            .map(|header| header.core_chain_locked_height)
+            .unwrap_or(prepare.core_chain_locked_height),
+        core_chain_lock_update: block.core_chain_lock.clone(),
+        proposer_pro_tx_hash: prepare.proposer_pro_tx_hash,
+        proposed_app_version: prepare.proposed_app_version,
+        version: prepare.version,
+        quorum_hash: prepare.quorum_hash,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tenderdash_abci::proto::types::{Block, Data, Header};
+
+    #[test]
+    fn should_read_length_delimited_requests() {
+        let requests = vec![
+            proto::Request {
+                value: Some(Value::PrepareProposal(proto::RequestPrepareProposal {
+                    height: 2,
+                    round: 1,
+                    ..Default::default()
+                })),
+            },
+            proto::Request {
+                value: Some(Value::FinalizeBlock(proto::RequestFinalizeBlock {
+                    height: 2,
+                    round: 1,
+                    ..Default::default()
+                })),
+            },
+        ];
+
+        let mut bytes = Vec::new();
+
+        for request in requests.iter() {
+            request
+                .encode_length_delimited(&mut bytes)
+                .expect("expected to encode request");
+        }
+
+        assert_eq!(
+            read_recorded_requests(&bytes).expect("expected to read requests"),
+            requests
+        );
+    }
+
+    #[test]
+    fn should_use_finalized_block_for_prepared_proposal() {
+        let prepare = proto::RequestPrepareProposal {
+            height: 2,
+            round: 1,
+            core_chain_locked_height: 10,
+            ..Default::default()
+        };
+
+        let finalize = proto::RequestFinalizeBlock {
+            height: 2,
+            round: 1,
+            hash: vec![7; 32],
+            block: Some(Block {
+                header: Some(Header {
+                    core_chain_locked_height: 12,
+                    ..Default::default()
+                }),
+                data: Some(Data {
+                    txs: vec![vec![1, 2, 3]],
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let process = process_proposal_request(Value::PrepareProposal(prepare), &finalize)
+            .expect("expected a process proposal request");
+
+        assert_eq!(process.hash, vec![7; 32]);
+        assert_eq!(process.txs, vec![vec![1, 2, 3]]);
+        assert_eq!(process.core_chain_locked_height, 12);
+    }
+}
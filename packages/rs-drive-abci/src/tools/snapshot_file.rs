@@ -0,0 +1,289 @@
+use crate::abci::AbciApplication;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::snapshot::{PlatformSnapshot, SNAPSHOT_FORMAT};
+use crate::rpc::core::CoreRPCLike;
+use crate::tools::{create_checkpoint, load_platform_state};
+use bincode::{config, Decode, Encode};
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use drive::drive::config::DriveConfig;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use std::io::{Read, Write};
+use tenderdash_abci::proto::abci as proto;
+use tenderdash_abci::Application;
+
+/// Version of the snapshot file layout
+pub const SNAPSHOT_FILE_FORMAT: u16 = 1;
+
+/// Header of a snapshot file, followed by the chunks of the snapshot.
+///
+/// Chunks are the same proved chunks that are sent to syncing nodes, so an imported snapshot
+/// is verified against its app hash like one received through state sync.
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct SnapshotFileHeader {
+    /// Version of the file layout
+    pub file_format: u16,
+    /// Format of the chunks
+    pub snapshot_format: u32,
+    /// Height of the snapshot block
+    pub height: u64,
+    /// App hash of the snapshot block
+    pub app_hash: [u8; 32],
+    /// Serialized platform state after the snapshot block was committed
+    pub serialized_platform_state: Vec<u8>,
+    /// Number of chunks following the header
+    pub chunks: u32,
+}
+
+/// Writes a snapshot of the last committed block.
+///
+/// The snapshot is taken from a temporary checkpoint, the drive itself is only read.
+pub fn export_snapshot<W: Write>(
+    drive: &Drive,
+    drive_config: &DriveConfig,
+    writer: &mut W,
+) -> Result<SnapshotFileHeader, Error> {
+    let platform_state = load_platform_state(drive)?
+        .ok_or_else(|| Error::Tools("no block was committed yet".to_string()))?;
+
+    let app_hash = drive
+        .grove
+        .root_hash(None)
+        .unwrap()
+        .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+    if platform_state.last_block_app_hash() != Some(app_hash) {
+        return Err(Error::Tools(format!(
+            "root hash {} doesn't match the app hash of the last committed block",
+            hex::encode(app_hash)
+        )));
+    }
+
+    let checkpoint_dir = tempfile::tempdir()
+        .map_err(|e| Error::Tools(format!("can't create a temporary directory: {}", e)))?;
+    let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+
+    create_checkpoint(drive, &checkpoint_path)?;
+
+    let checkpoint_drive = Drive::open(
+        &checkpoint_path,
+        Some(drive_config.clone()),
+        PlatformVersion::latest(),
+    )?;
+
+    let snapshot = PlatformSnapshot::new(
+        platform_state.last_block_height(),
+        app_hash,
+        platform_state.serialize_to_bytes()?,
+        checkpoint_drive,
+    )?;
+
+    let header = SnapshotFileHeader {
+        file_format: SNAPSHOT_FILE_FORMAT,
+        snapshot_format: SNAPSHOT_FORMAT,
+        height: snapshot.height,
+        app_hash,
+        serialized_platform_state: snapshot.serialized_platform_state.clone(),
+        chunks: snapshot.chunks.len() as u32,
+    };
+
+    write_entry(writer, &header)?;
+
+    for index in 0..header.chunks {
+        write_entry(writer, &snapshot.load_chunk(index)?)?;
+    }
+
+    Ok(header)
+}
+
+/// Restores a snapshot file into an empty platform.
+///
+/// The chunks go through the state sync handlers, so the restored state is verified against
+/// the app hash of the snapshot, which must match `expected_app_hash` if it's given.
+pub fn import_snapshot<C: CoreRPCLike, R: Read>(
+    platform: &Platform<C>,
+    reader: &mut R,
+    expected_app_hash: Option<[u8; 32]>,
+) -> Result<SnapshotFileHeader, Error> {
+    let header: SnapshotFileHeader = read_entry(reader)?;
+
+    if header.file_format != SNAPSHOT_FILE_FORMAT {
+        return Err(Error::Tools(format!(
+            "unsupported snapshot file format {}",
+            header.file_format
+        )));
+    }
+
+    if let Some(expected_app_hash) = expected_app_hash {
+        if header.app_hash != expected_app_hash {
+            return Err(Error::Tools(format!(
+                "snapshot app hash {} doesn't match the expected app hash {}",
+                hex::encode(header.app_hash),
+                hex::encode(expected_app_hash)
+            )));
+        }
+    }
+
+    let abci = AbciApplication::new(platform)?;
+
+    let offer = abci
+        .offer_snapshot(proto::RequestOfferSnapshot {
+            snapshot: Some(proto::Snapshot {
+                height: header.height,
+                format: header.snapshot_format,
+                chunks: header.chunks,
+                hash: header.app_hash.to_vec(),
+                metadata: header.serialized_platform_state.clone(),
+            }),
+            app_hash: header.app_hash.to_vec(),
+        })
+        .map_err(|e| Error::Tools(format!("snapshot offer failed: {:?}", e)))?;
+
+    if offer.result != proto::response_offer_snapshot::Result::Accept as i32 {
+        return Err(Error::Tools(format!(
+            "snapshot at height {} was not accepted: {:?}",
+            header.height,
+            proto::response_offer_snapshot::Result::from_i32(offer.result)
+        )));
+    }
+
+    for index in 0..header.chunks {
+        let chunk: Vec<u8> = read_entry(reader)?;
+
+        let applied = abci
+            .apply_snapshot_chunk(proto::RequestApplySnapshotChunk {
+                index,
+                chunk,
+                sender: "import".to_string(),
+            })
+            .map_err(|e| Error::Tools(format!("applying chunk {} failed: {:?}", index, e)))?;
+
+        if applied.result != proto::response_apply_snapshot_chunk::Result::Accept as i32 {
+            return Err(Error::Tools(format!(
+                "chunk {} was not accepted: {:?}",
+                index,
+                proto::response_apply_snapshot_chunk::Result::from_i32(applied.result)
+            )));
+        }
+    }
+
+    Ok(header)
+}
+
+fn write_entry<W: Write, T: Encode>(writer: &mut W, entry: &T) -> Result<(), Error> {
+    bincode::encode_into_std_write(entry, writer, config::standard().with_big_endian())
+        .map(|_| ())
+        .map_err(|e| Error::Tools(format!("unable to write snapshot file: {}", e)))
+}
+
+fn read_entry<R: Read, T: Decode>(reader: &mut R) -> Result<T, Error> {
+    bincode::decode_from_std_read(reader, config::standard().with_big_endian())
+        .map_err(|e| Error::Tools(format!("unable to read snapshot file: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_types::platform_state::PlatformState;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0;
+
+    #[test]
+    fn should_import_exported_snapshot() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut state: PlatformState = platform.state.read().unwrap().clone();
+
+        state.set_last_committed_block_info(Some(
+            ExtendedBlockInfoV0 {
+                basic_info: BlockInfo {
+                    time_ms: 500000,
+                    height: 10,
+                    core_height: 42,
+                    epoch: Default::default(),
+                },
+                app_hash: platform.drive.grove.root_hash(None).unwrap().unwrap(),
+                quorum_hash: [0u8; 32],
+                block_id_hash: [0u8; 32],
+                signature: [0u8; 96],
+                round: 0,
+            }
+            .into(),
+        ));
+
+        platform
+            .drive
+            .grove
+            .put_aux(
+                b"saved_state",
+                &state
+                    .serialize_to_bytes()
+                    .expect("expected to serialize state"),
+                None,
+                None,
+            )
+            .unwrap()
+            .expect("expected to store state");
+
+        let mut file = Vec::new();
+
+        let exported = export_snapshot(&platform.drive, &platform.config.drive, &mut file)
+            .expect("expected to export snapshot");
+
+        let restored_platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .with_core_validator_set(state.current_validator_set_quorum_hash())
+            .with_core_chain_locked_height(42);
+
+        let imported = import_snapshot(
+            &restored_platform.platform,
+            &mut file.as_slice(),
+            Some(exported.app_hash),
+        )
+        .expect("expected to import snapshot");
+
+        assert_eq!(imported, exported);
+        assert_eq!(
+            restored_platform.state.read().unwrap().last_block_height(),
+            10
+        );
+        assert_eq!(
+            restored_platform
+                .drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .unwrap(),
+            exported.app_hash
+        );
+    }
+
+    #[test]
+    fn should_reject_snapshot_with_unexpected_app_hash() {
+        let platform = TestPlatformBuilder::new().build_with_mock_rpc();
+
+        let mut file = Vec::new();
+
+        write_entry(
+            &mut file,
+            &SnapshotFileHeader {
+                file_format: SNAPSHOT_FILE_FORMAT,
+                snapshot_format: SNAPSHOT_FORMAT,
+                height: 10,
+                app_hash: [1u8; 32],
+                serialized_platform_state: vec![],
+                chunks: 0,
+            },
+        )
+        .expect("expected to write header");
+
+        import_snapshot(&platform.platform, &mut file.as_slice(), Some([2u8; 32]))
+            .expect_err("expected snapshot to be rejected");
+    }
+}
@@ -443,6 +443,7 @@ impl NetworkStrategy {
                                     DocumentsBatchTransitionV0 {
                                         owner_id: identity.id(),
                                         transitions: vec![document_create_transition.into()],
+                                        user_fee_increase: 0,
                                         signature_public_key_id: 0,
                                         signature: BinaryData::default(),
                                     }
@@ -550,6 +551,7 @@ impl NetworkStrategy {
                                     DocumentsBatchTransitionV0 {
                                         owner_id: identity.id(),
                                         transitions: vec![document_create_transition.into()],
+                                        user_fee_increase: 0,
                                         signature_public_key_id: 0,
                                         signature: BinaryData::default(),
                                     }
@@ -641,6 +643,7 @@ impl NetworkStrategy {
                                 DocumentsBatchTransitionV0 {
                                     owner_id: identity.id,
                                     transitions: vec![document_delete_transition.into()],
+                                    user_fee_increase: 0,
                                     signature_public_key_id: 0,
                                     signature: BinaryData::default(),
                                 }
@@ -734,6 +737,7 @@ impl NetworkStrategy {
                                 DocumentsBatchTransitionV0 {
                                     owner_id: identity.id,
                                     transitions: vec![document_replace_transition.into()],
+                                    user_fee_increase: 0,
                                     signature_public_key_id: 0,
                                     signature: BinaryData::default(),
                                 }
@@ -822,11 +826,22 @@ impl NetworkStrategy {
                             (0..current_identities.len()).choose_multiple(rng, count as usize);
                         for index in indices {
                             let random_identity = current_identities.get_mut(index).unwrap();
+                            let nonce = platform
+                                .drive
+                                .fetch_identity_nonce(
+                                    random_identity.id().to_buffer(),
+                                    true,
+                                    None,
+                                    platform_version,
+                                )
+                                .expect("expected to be able to get identity nonce")
+                                .unwrap_or_default();
                             let state_transition =
                                 strategy_tests::transitions::create_identity_withdrawal_transition(
                                     random_identity,
                                     signer,
                                     rng,
+                                    nonce + 1,
                                 );
                             operations.push(state_transition);
                         }
@@ -845,12 +860,24 @@ impl NetworkStrategy {
                             .expect("expected to be able to get identity")
                             .expect("expected to get an identity");
 
+                        let fetched_owner_nonce = platform
+                            .drive
+                            .fetch_identity_nonce(
+                                owner.id().to_buffer(),
+                                true,
+                                None,
+                                platform_version,
+                            )
+                            .expect("expected to be able to get identity nonce")
+                            .unwrap_or_default();
+
                         let state_transition =
                             strategy_tests::transitions::create_identity_credit_transfer_transition(
                                 owner,
                                 recipient,
                                 signer,
                                 fetched_owner_balance - 100,
+                                fetched_owner_nonce + 1,
                             );
                         operations.push(state_transition);
                     }
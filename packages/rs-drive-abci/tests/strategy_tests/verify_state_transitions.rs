@@ -26,6 +26,9 @@ use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatch
 use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
 use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentFromCreateTransition;
 use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentFromReplaceTransition;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionActionAccessorsV0;
 
 pub(crate) fn verify_state_transitions_were_or_were_not_executed(
     abci_app: &AbciApplication<MockCoreRPCLike>,
@@ -362,6 +365,27 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                                 // we expect no document
                                 assert!(document.is_none());
                             }
+                            DocumentTransitionAction::TransferAction(transfer_action) => {
+                                if *was_executed {
+                                    if let Some(document) = document {
+                                        assert_eq!(&document, transfer_action.document());
+                                    }
+                                }
+                            }
+                            DocumentTransitionAction::UpdatePriceAction(update_price_action) => {
+                                if *was_executed {
+                                    if let Some(document) = document {
+                                        assert_eq!(&document, update_price_action.document());
+                                    }
+                                }
+                            }
+                            DocumentTransitionAction::PurchaseAction(purchase_action) => {
+                                if *was_executed {
+                                    if let Some(document) = document {
+                                        assert_eq!(&document, purchase_action.document());
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -681,6 +705,52 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                         );
                     }
                 }
+                StateTransitionAction::DataContractDeleteAction(data_contract_delete) => {
+                    proofs_request
+                        .contracts
+                        .push(get_proofs_request_v0::ContractRequest {
+                            contract_id: data_contract_delete.data_contract_id().to_vec(),
+                        });
+                    let versioned_request = GetProofsRequest {
+                        version: Some(get_proofs_request::Version::V0(proofs_request)),
+                    };
+                    let result = abci_app
+                        .platform
+                        .query(
+                            "/proofs",
+                            &versioned_request.encode_to_vec(),
+                            platform_version,
+                        )
+                        .expect("expected to query proofs");
+                    let serialized_get_proofs_response =
+                        result.into_data().expect("expected queries to be valid");
+
+                    let response =
+                        GetProofsResponse::decode(serialized_get_proofs_response.as_slice())
+                            .expect("expected to decode proof response");
+
+                    let response_proof = response.proof_owned().expect("expected to get proof");
+
+                    // we expect the contract to be absent once the deletion was executed
+                    let (root_hash, contract) = Drive::verify_contract(
+                        &response_proof.grovedb_proof,
+                        None,
+                        false,
+                        true,
+                        data_contract_delete.data_contract_id().into_buffer(),
+                        platform_version,
+                    )
+                    .expect("expected to verify contract absence");
+                    assert_eq!(
+                        &root_hash,
+                        expected_root_hash,
+                        "state last block info {:?}",
+                        platform.state.last_committed_block_info()
+                    );
+                    if *was_executed {
+                        assert!(contract.is_none());
+                    }
+                }
             }
         } else {
             // if we don't have an action this means there was a problem in the validation of the state transition
@@ -7,9 +7,13 @@ mod error;
 mod proof;
 mod provider;
 pub mod types;
+/// Parsing of responses received without proofs
+mod unproved;
 mod verify;
 pub use error::Error;
 pub use proof::FromProof;
 #[cfg(feature = "mocks")]
 pub use provider::MockQuorumInfoProvider;
 pub use provider::QuorumInfoProvider;
+pub use unproved::FromUnproved;
+pub use verify::verify_tenderdash_proof;
@@ -11,9 +11,9 @@ use dapi_grpc::platform::v0::{
     get_data_contract_history_request, get_data_contract_request, get_data_contracts_request,
     get_epochs_info_request, get_identity_balance_and_revision_request,
     get_identity_balance_request, get_identity_by_public_key_hash_request,
-    get_identity_keys_request, get_identity_request, GetProtocolVersionUpgradeStateRequest,
-    GetProtocolVersionUpgradeStateResponse, GetProtocolVersionUpgradeVoteStatusRequest,
-    GetProtocolVersionUpgradeVoteStatusResponse,
+    get_identity_keys_request, get_identity_nonce_request, get_identity_request,
+    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeStateResponse,
+    GetProtocolVersionUpgradeVoteStatusRequest, GetProtocolVersionUpgradeVoteStatusResponse,
 };
 use dapi_grpc::platform::{
     v0::{self as platform, key_request_type, KeyRequestType as GrpcKeyType},
@@ -23,6 +23,7 @@ use dpp::block::epoch::EpochIndex;
 use dpp::block::extended_epoch_info::ExtendedEpochInfo;
 use dpp::dashcore::hashes::Hash;
 use dpp::dashcore::ProTxHash;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::document::{Document, DocumentV0Getters};
 use dpp::prelude::{DataContract, Identifier, Identity};
 use dpp::version::PlatformVersion;
@@ -466,6 +467,53 @@ impl FromProof<platform::GetIdentityBalanceAndRevisionRequest> for IdentityBalan
     }
 }
 
+impl FromProof<platform::GetIdentityNonceRequest> for IdentityNonceFetcher {
+    type Request = platform::GetIdentityNonceRequest;
+    type Response = platform::GetIdentityNonceResponse;
+
+    fn maybe_from_proof<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+
+        provider: &'a dyn QuorumInfoProvider,
+    ) -> Result<Option<Self>, Error>
+    where
+        IdentityNonceFetcher: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let id = match request.version.ok_or(Error::EmptyVersion)? {
+            get_identity_nonce_request::Version::V0(v0) => {
+                Identifier::from_bytes(&v0.id).map_err(|e| Error::ProtocolError {
+                    error: e.to_string(),
+                })
+            }
+        }?;
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, maybe_nonce) = Drive::verify_identity_nonce_for_identity_id(
+            &proof.grovedb_proof,
+            id.into_buffer(),
+            false,
+            platform_version,
+        )
+        .map_err(|e| Error::DriveError {
+            error: e.to_string(),
+        })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok(maybe_nonce.map(IdentityNonceFetcher))
+    }
+}
+
 impl FromProof<platform::GetDataContractRequest> for DataContract {
     type Request = platform::GetDataContractRequest;
     type Response = platform::GetDataContractResponse;
@@ -849,6 +897,75 @@ impl FromProof<GetProtocolVersionUpgradeVoteStatusRequest> for MasternodeProtoco
     }
 }
 
+impl FromProof<StateTransitionQuery> for StateTransitionProofResult {
+    type Request = StateTransitionQuery;
+    type Response = platform::WaitForStateTransitionResultResponse;
+
+    fn maybe_from_proof<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn QuorumInfoProvider,
+    ) -> Result<Option<Self>, Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let known_contracts_provider_fn = |id: &Identifier| {
+            Ok::<_, drive::error::Error>(
+                request
+                    .data_contracts
+                    .iter()
+                    .find(|data_contract| data_contract.id() == *id)
+                    .cloned(),
+            )
+        };
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &request.state_transition,
+            &proof.grovedb_proof,
+            &known_contracts_provider_fn,
+            platform_version,
+        )?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok(Some(result))
+    }
+}
+
+/// Verify the result of a state transition executed earlier, using the proof returned for
+/// [StateTransitionQuery::get_proofs_request()].
+impl FromProof<platform::GetProofsRequest> for StateTransitionProofResult {
+    type Request = StateTransitionQuery;
+    type Response = platform::GetProofsResponse;
+
+    fn maybe_from_proof<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn QuorumInfoProvider,
+    ) -> Result<Option<Self>, Error>
+    where
+        Self: Sized + 'a,
+    {
+        let response: Self::Response = response.into();
+
+        <Self as FromProof<StateTransitionQuery>>::maybe_from_proof(
+            request,
+            platform::WaitForStateTransitionResultResponse::from(response),
+            platform_version,
+            provider,
+        )
+    }
+}
+
 // #[cfg_attr(feature = "mocks", mockall::automock)]
 impl<'dq, Q> FromProof<Q> for Documents
 where
@@ -982,4 +1099,5 @@ define_length!(Document);
 define_length!(Identity);
 define_length!(IdentityBalance);
 define_length!(IdentityBalanceAndRevision);
+define_length!(IdentityNonceFetcher);
 // define_length!(IdentityPublicKeys, |d: &IdentityPublicKeys| d.count_some());
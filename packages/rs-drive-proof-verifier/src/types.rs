@@ -6,13 +6,35 @@
 //! defined in this module.
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
+use dapi_grpc::platform::v0::get_proofs_request::{
+    self,
+    get_proofs_request_v0::{
+        identity_request::Type as IdentityRequestType, ContractRequest, DocumentRequest,
+        IdentityRequest,
+    },
+    GetProofsRequestV0,
+};
+use dapi_grpc::platform::v0::GetProofsRequest;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::document_transition::DocumentTransitionV0Methods;
+use dpp::state_transition::identity_create_transition::accessors::IdentityCreateTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dpp::state_transition::identity_topup_transition::accessors::IdentityTopUpTransitionAccessorsV0;
+use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+pub use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::StateTransitionLike;
 use dpp::{
     block::{epoch::EpochIndex, extended_epoch_info::ExtendedEpochInfo},
     dashcore::ProTxHash,
     document::Document,
     identity::KeyID,
-    prelude::{DataContract, Identifier, IdentityPublicKey, Revision},
+    prelude::{DataContract, Identifier, IdentityNonce, IdentityPublicKey, Revision},
+    state_transition::StateTransition,
     util::deserializer::ProtocolVersion,
 };
 
@@ -47,6 +69,13 @@ pub type IdentityBalance = u64;
 /// Identity balance and revision of the identity.
 pub type IdentityBalanceAndRevision = (u64, Revision);
 
+/// Nonce of the last nonce-protected state transition of an identity.
+///
+/// Wraps [IdentityNonce] so that it can be fetched independently of [IdentityBalance],
+/// as both are plain `u64` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentityNonceFetcher(pub IdentityNonce);
+
 /// Public keys belonging to some identity.
 ///
 /// Map of [key IDs](KeyID) to the [public key](IdentityPublicKey).
@@ -77,7 +106,7 @@ pub type ProtocolVersionUpgrades = RetrievedObjects<ProtocolVersion, ProtocolVer
 
 /// Vote of a masternode for a protocol version.
 #[cfg_attr(feature = "mocks", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MasternodeProtocolVote {
     /// ProTxHash of the masternode
     pub pro_tx_hash: ProTxHash,
@@ -90,3 +119,167 @@ pub struct MasternodeProtocolVote {
 /// Information about protocol version voted by each node, returned by [ProtocolVersion::fetch_many()].
 /// Indexed by [ProTxHash] of nodes.
 pub type MasternodeProtocolVotes = RetrievedObjects<ProTxHash, MasternodeProtocolVote>;
+
+/// State transition which result should be verified, together with data contracts needed to verify it.
+///
+/// [WaitForStateTransitionResultRequest](dapi_grpc::platform::v0::WaitForStateTransitionResultRequest) only contains
+/// hash of the state transition, so the state transition itself is needed to know what the proof should contain.
+/// Documents batch transitions also need data contracts of all documents in the batch.
+///
+/// Used to retrieve [StateTransitionProofResult] from the proof.
+#[derive(Debug, Clone)]
+pub struct StateTransitionQuery {
+    /// The executed state transition
+    pub state_transition: StateTransition,
+    /// Data contracts of documents created, replaced or deleted by the state transition
+    pub data_contracts: Vec<Arc<DataContract>>,
+}
+
+impl StateTransitionQuery {
+    /// Create a new query for the result of `state_transition`.
+    pub fn new(state_transition: StateTransition) -> Self {
+        Self {
+            state_transition,
+            data_contracts: Vec::new(),
+        }
+    }
+
+    /// Add a data contract of documents in the state transition.
+    pub fn with_data_contract(mut self, data_contract: Arc<DataContract>) -> Self {
+        self.data_contracts.push(data_contract);
+        self
+    }
+
+    /// Build a [GetProofsRequest] for the state modified by the state transition.
+    ///
+    /// The request asks for the same proofs DAPI returns for `waitForStateTransitionResult`, so the result of a
+    /// state transition executed earlier can be verified with [FromProof<GetProofsRequest>](crate::FromProof).
+    pub fn get_proofs_request(&self) -> GetProofsRequest {
+        let mut request = GetProofsRequestV0 {
+            identities: vec![],
+            contracts: vec![],
+            documents: vec![],
+        };
+
+        let identity_request =
+            |id: Identifier, request_type: IdentityRequestType| IdentityRequest {
+                identity_id: id.to_vec(),
+                request_type: request_type as i32,
+            };
+
+        match &self.state_transition {
+            StateTransition::DataContractCreate(transition) => {
+                request.contracts = Self::contract_requests(transition.modified_data_ids())
+            }
+            StateTransition::DataContractUpdate(transition) => {
+                request.contracts = Self::contract_requests(transition.modified_data_ids())
+            }
+            StateTransition::DataContractDelete(transition) => {
+                request.contracts = Self::contract_requests(transition.modified_data_ids())
+            }
+            StateTransition::DocumentsBatch(transition) => {
+                request.documents = transition
+                    .transitions()
+                    .iter()
+                    .map(|document_transition| {
+                        let document_type_keeps_history = self
+                            .data_contracts
+                            .iter()
+                            .find(|data_contract| {
+                                data_contract.id() == document_transition.data_contract_id()
+                            })
+                            .and_then(|data_contract| {
+                                data_contract
+                                    .document_type_for_name(
+                                        document_transition.document_type_name(),
+                                    )
+                                    .ok()
+                            })
+                            .is_some_and(|document_type| document_type.documents_keep_history());
+
+                        DocumentRequest {
+                            contract_id: document_transition.data_contract_id().to_vec(),
+                            document_type: document_transition.document_type_name().clone(),
+                            document_type_keeps_history,
+                            document_id: document_transition.get_id().to_vec(),
+                        }
+                    })
+                    .collect()
+            }
+            StateTransition::IdentityCreditTransfer(transition) => {
+                request.identities = vec![
+                    identity_request(transition.identity_id(), IdentityRequestType::Balance),
+                    identity_request(transition.recipient_id(), IdentityRequestType::Balance),
+                    identity_request(transition.identity_id(), IdentityRequestType::Nonce),
+                ]
+            }
+            StateTransition::IdentityCreditWithdrawal(transition) => {
+                request.identities = vec![
+                    identity_request(transition.identity_id(), IdentityRequestType::FullIdentity),
+                    identity_request(transition.identity_id(), IdentityRequestType::Nonce),
+                ]
+            }
+            StateTransition::IdentityCreate(transition) => {
+                request.identities = vec![identity_request(
+                    transition.identity_id(),
+                    IdentityRequestType::FullIdentity,
+                )]
+            }
+            StateTransition::IdentityTopUp(transition) => {
+                request.identities = vec![identity_request(
+                    transition.identity_id(),
+                    IdentityRequestType::FullIdentity,
+                )]
+            }
+            StateTransition::IdentityUpdate(transition) => {
+                request.identities = vec![identity_request(
+                    transition.identity_id(),
+                    IdentityRequestType::FullIdentity,
+                )]
+            }
+        }
+
+        GetProofsRequest {
+            version: Some(get_proofs_request::Version::V0(request)),
+        }
+    }
+
+    fn contract_requests(ids: Vec<Identifier>) -> Vec<ContractRequest> {
+        ids.into_iter()
+            .map(|id| ContractRequest {
+                contract_id: id.to_vec(),
+            })
+            .collect()
+    }
+}
+
+// State transitions don't always implement serde, so the query is identified by the serialized
+// state transition and IDs of the data contracts.
+#[cfg(feature = "mocks")]
+impl serde::Serialize for StateTransitionQuery {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use dpp::serialization::PlatformSerializable;
+        use serde::ser::{Error, SerializeTuple};
+
+        let state_transition = self
+            .state_transition
+            .serialize_to_bytes()
+            .map_err(S::Error::custom)?;
+        let data_contract_ids: Vec<[u8; 32]> = self
+            .data_contracts
+            .iter()
+            .map(|data_contract| data_contract.id().to_buffer())
+            .collect();
+
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&state_transition[..])?;
+        tuple.serialize_element(&data_contract_ids[..])?;
+        tuple.end()
+    }
+}
+
+impl From<StateTransition> for StateTransitionQuery {
+    fn from(state_transition: StateTransition) -> Self {
+        Self::new(state_transition)
+    }
+}
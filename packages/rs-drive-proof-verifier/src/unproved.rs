@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+
+use crate::{types::*, Error};
+use dapi_grpc::platform::v0::{
+    self as platform, get_data_contract_response, get_data_contracts_response,
+    get_epochs_info_response, get_identity_balance_and_revision_response,
+    get_identity_balance_response, get_identity_by_public_key_hash_response,
+    get_identity_keys_response, get_identity_nonce_response, get_identity_response,
+    get_protocol_version_upgrade_state_response, get_protocol_version_upgrade_vote_status_response,
+    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeStateResponse,
+    GetProtocolVersionUpgradeVoteStatusRequest, GetProtocolVersionUpgradeVoteStatusResponse,
+};
+use dpp::block::extended_epoch_info::v0::ExtendedEpochInfoV0;
+use dpp::block::extended_epoch_info::ExtendedEpochInfo;
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore::ProTxHash;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::prelude::{DataContract, Identifier, Identity, IdentityPublicKey};
+use dpp::serialization::{
+    PlatformDeserializable, PlatformDeserializableWithPotentialValidationFromVersionedStructure,
+};
+use dpp::version::PlatformVersion;
+
+/// Retrieve the requested object from a response received without a proof.
+///
+/// gRPC responses, received from the Dash Platform in response to requests containing `prove: false`, contain
+/// requested objects serialized by the node that served the request. Nothing in the response can be verified,
+/// so the caller is responsible for establishing trust in the returned data, for example by comparing
+/// responses of multiple nodes.
+///
+/// This trait is implemented by the same objects as [FromProof](crate::FromProof), so that one object can be
+/// fetched with or without proofs.
+pub trait FromUnproved<Req> {
+    /// Request type for which this trait is implemented.
+    type Request;
+    /// Response type for which this trait is implemented.
+    type Response;
+
+    /// Parse the received response and retrieve the requested object, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `request`: The request sent to the server.
+    /// * `response`: The response received from the server.
+    /// * `platform_version`: Platform version used to deserialize objects.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(object))` when the requested object was found.
+    /// * `Ok(None)` when the requested object was not found.
+    /// For collections, returns Ok(None) if none of the requested objects were found.
+    /// * `Err(Error)` when the response is invalid or contains a proof.
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error>
+    where
+        Self: Sized;
+}
+
+/// Error returned when the response contains a proof instead of the requested data.
+fn unexpected_proof() -> Error {
+    Error::ResponseDecodeError {
+        error: "expected data, got proof; send the request with `prove: false`".to_string(),
+    }
+}
+
+/// Error returned when the response contains neither data nor a proof.
+fn empty_result() -> Error {
+    Error::ResponseDecodeError {
+        error: "empty result".to_string(),
+    }
+}
+
+impl FromUnproved<platform::GetIdentityRequest> for Identity {
+    type Request = platform::GetIdentityRequest;
+    type Response = platform::GetIdentityResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_identity_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_identity_response::get_identity_response_v0::Result as ResponseResult;
+        match result.ok_or_else(empty_result)? {
+            ResponseResult::Identity(bytes) => Ok(Some(Identity::deserialize_from_bytes(&bytes)?)),
+            ResponseResult::Proof(_) => Err(unexpected_proof()),
+        }
+    }
+}
+
+impl FromUnproved<platform::GetIdentityByPublicKeyHashRequest> for Identity {
+    type Request = platform::GetIdentityByPublicKeyHashRequest;
+    type Response = platform::GetIdentityByPublicKeyHashResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_identity_by_public_key_hash_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_identity_by_public_key_hash_response::get_identity_by_public_key_hash_response_v0::Result as ResponseResult;
+        match result.ok_or_else(empty_result)? {
+            ResponseResult::Identity(bytes) => Ok(Some(Identity::deserialize_from_bytes(&bytes)?)),
+            ResponseResult::Proof(_) => Err(unexpected_proof()),
+        }
+    }
+}
+
+impl FromUnproved<platform::GetIdentityKeysRequest> for IdentityPublicKeys {
+    type Request = platform::GetIdentityKeysRequest;
+    type Response = platform::GetIdentityKeysResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_identity_keys_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_identity_keys_response::get_identity_keys_response_v0::Result as ResponseResult;
+        let keys = match result.ok_or_else(empty_result)? {
+            ResponseResult::Keys(keys) => keys.keys_bytes,
+            ResponseResult::Proof(_) => return Err(unexpected_proof()),
+        };
+
+        if keys.is_empty() {
+            return Ok(None);
+        }
+
+        let keys = keys
+            .iter()
+            .map(|bytes| {
+                IdentityPublicKey::deserialize_from_bytes(bytes).map(|key| (key.id(), Some(key)))
+            })
+            .collect::<Result<IdentityPublicKeys, _>>()?;
+
+        Ok(Some(keys))
+    }
+}
+
+impl FromUnproved<platform::GetIdentityBalanceRequest> for IdentityBalance {
+    type Request = platform::GetIdentityBalanceRequest;
+    type Response = platform::GetIdentityBalanceResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_identity_balance_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_identity_balance_response::get_identity_balance_response_v0::Result as ResponseResult;
+        match result.ok_or_else(empty_result)? {
+            ResponseResult::Balance(balance) => Ok(Some(balance)),
+            ResponseResult::Proof(_) => Err(unexpected_proof()),
+        }
+    }
+}
+
+impl FromUnproved<platform::GetIdentityBalanceAndRevisionRequest> for IdentityBalanceAndRevision {
+    type Request = platform::GetIdentityBalanceAndRevisionRequest;
+    type Response = platform::GetIdentityBalanceAndRevisionResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_identity_balance_and_revision_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_identity_balance_and_revision_response::get_identity_balance_and_revision_response_v0::Result as ResponseResult;
+        match result.ok_or_else(empty_result)? {
+            ResponseResult::BalanceAndRevision(v) => Ok(Some((v.balance, v.revision))),
+            ResponseResult::Proof(_) => Err(unexpected_proof()),
+        }
+    }
+}
+
+impl FromUnproved<platform::GetIdentityNonceRequest> for IdentityNonceFetcher {
+    type Request = platform::GetIdentityNonceRequest;
+    type Response = platform::GetIdentityNonceResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_identity_nonce_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_identity_nonce_response::get_identity_nonce_response_v0::Result as ResponseResult;
+        match result.ok_or_else(empty_result)? {
+            ResponseResult::IdentityNonce(nonce) => Ok(Some(IdentityNonceFetcher(nonce))),
+            ResponseResult::Proof(_) => Err(unexpected_proof()),
+        }
+    }
+}
+
+impl FromUnproved<platform::GetDataContractRequest> for DataContract {
+    type Request = platform::GetDataContractRequest;
+    type Response = platform::GetDataContractResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_data_contract_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_data_contract_response::get_data_contract_response_v0::Result as ResponseResult;
+        match result.ok_or_else(empty_result)? {
+            ResponseResult::DataContract(bytes) => Ok(Some(DataContract::versioned_deserialize(
+                &bytes,
+                false,
+                platform_version,
+            )?)),
+            ResponseResult::Proof(_) => Err(unexpected_proof()),
+        }
+    }
+}
+
+impl FromUnproved<platform::GetDataContractsRequest> for DataContracts {
+    type Request = platform::GetDataContractsRequest;
+    type Response = platform::GetDataContractsResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_data_contracts_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_data_contracts_response::get_data_contracts_response_v0::Result as ResponseResult;
+        let entries = match result.ok_or_else(empty_result)? {
+            ResponseResult::DataContracts(contracts) => contracts.data_contract_entries,
+            ResponseResult::Proof(_) => return Err(unexpected_proof()),
+        };
+
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        let contracts = entries
+            .into_iter()
+            .map(|entry| {
+                let id = Identifier::from_bytes(&entry.identifier).map_err(|e| {
+                    Error::ResponseDecodeError {
+                        error: e.to_string(),
+                    }
+                })?;
+                let contract = entry
+                    .data_contract
+                    .map(|bytes| {
+                        DataContract::versioned_deserialize(&bytes, false, platform_version)
+                    })
+                    .transpose()?;
+
+                Ok((id, contract))
+            })
+            .collect::<Result<DataContracts, Error>>()?;
+
+        Ok(Some(contracts))
+    }
+}
+
+impl FromUnproved<platform::GetEpochsInfoRequest> for ExtendedEpochInfo {
+    type Request = platform::GetEpochsInfoRequest;
+    type Response = platform::GetEpochsInfoResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let epochs = ExtendedEpochInfos::maybe_from_unproved(request, response, platform_version)?;
+
+        if let Some(mut e) = epochs {
+            if e.len() != 1 {
+                return Err(Error::RequestDecodeError {
+                    error: format!("expected 1 epoch, got {}", e.len()),
+                });
+            }
+            Ok(e.pop_first().and_then(|v| v.1))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl FromUnproved<platform::GetEpochsInfoRequest> for ExtendedEpochInfos {
+    type Request = platform::GetEpochsInfoRequest;
+    type Response = platform::GetEpochsInfoResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_epochs_info_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_epochs_info_response::get_epochs_info_response_v0::Result as ResponseResult;
+        let epochs = match result.ok_or_else(empty_result)? {
+            ResponseResult::Epochs(epochs) => epochs.epoch_infos,
+            ResponseResult::Proof(_) => return Err(unexpected_proof()),
+        };
+
+        if epochs.is_empty() {
+            return Ok(None);
+        }
+
+        let epochs = epochs
+            .into_iter()
+            .map(|epoch| {
+                let index = epoch
+                    .number
+                    .try_into()
+                    .map_err(|_| Error::ResponseDecodeError {
+                        error: format!("epoch index {} out of range", epoch.number),
+                    })?;
+
+                let info = ExtendedEpochInfoV0 {
+                    index,
+                    first_block_time: epoch.start_time,
+                    first_block_height: epoch.first_block_height,
+                    first_core_block_height: epoch.first_core_block_height,
+                    fee_multiplier: epoch.fee_multiplier,
+                };
+
+                Ok((index, Some(info.into())))
+            })
+            .collect::<Result<ExtendedEpochInfos, Error>>()?;
+
+        Ok(Some(epochs))
+    }
+}
+
+impl FromUnproved<GetProtocolVersionUpgradeStateRequest> for ProtocolVersionUpgrades {
+    type Request = GetProtocolVersionUpgradeStateRequest;
+    type Response = GetProtocolVersionUpgradeStateResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_protocol_version_upgrade_state_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_protocol_version_upgrade_state_response::get_protocol_version_upgrade_state_response_v0::Result as ResponseResult;
+        let versions = match result.ok_or_else(empty_result)? {
+            ResponseResult::Versions(versions) => versions.versions,
+            ResponseResult::Proof(_) => return Err(unexpected_proof()),
+        };
+
+        if versions.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            versions
+                .into_iter()
+                .map(|v| (v.version_number, Some(v.vote_count as u64)))
+                .collect::<BTreeMap<_, _>>(),
+        ))
+    }
+}
+
+impl FromUnproved<GetProtocolVersionUpgradeVoteStatusRequest> for MasternodeProtocolVotes {
+    type Request = GetProtocolVersionUpgradeVoteStatusRequest;
+    type Response = GetProtocolVersionUpgradeVoteStatusResponse;
+
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Option<Self>, Error> {
+        let response: Self::Response = response.into();
+
+        let result = match response.version.ok_or(Error::EmptyVersion)? {
+            get_protocol_version_upgrade_vote_status_response::Version::V0(v0) => v0.result,
+        };
+
+        use get_protocol_version_upgrade_vote_status_response::get_protocol_version_upgrade_vote_status_response_v0::Result as ResponseResult;
+        let signals = match result.ok_or_else(empty_result)? {
+            ResponseResult::Versions(versions) => versions.version_signals,
+            ResponseResult::Proof(_) => return Err(unexpected_proof()),
+        };
+
+        if signals.is_empty() {
+            return Ok(None);
+        }
+
+        let votes = signals
+            .into_iter()
+            .map(|signal| {
+                ProTxHash::from_slice(&signal.pro_tx_hash)
+                    .map(|pro_tx_hash| {
+                        (
+                            pro_tx_hash,
+                            Some(MasternodeProtocolVote {
+                                pro_tx_hash,
+                                voted_version: signal.version,
+                            }),
+                        )
+                    })
+                    .map_err(|e| Error::ResultEncodingError {
+                        error: e.to_string(),
+                    })
+            })
+            .collect::<Result<MasternodeProtocolVotes, Error>>()?;
+
+        Ok(Some(votes))
+    }
+}
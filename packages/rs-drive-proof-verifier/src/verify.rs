@@ -28,7 +28,7 @@ use crate::QuorumInfoProvider;
 /// * `Ok(())` when the proof is valid
 /// * `Err(Error)` when the proof is invalid
 ///
-pub(crate) fn verify_tenderdash_proof(
+pub fn verify_tenderdash_proof(
     proof: &Proof,
     mtd: &ResponseMetadata,
     root_hash: &[u8],
@@ -5,6 +5,7 @@ use crate::error::Error;
 use crate::fee::op::LowLevelDriveOperation;
 use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::DataContract;
+use dpp::platform_value::Identifier;
 
 use crate::drive::batch::drive_op_batch::finalize_task::{
     DriveOperationFinalizationTasks, DriveOperationFinalizeTask,
@@ -43,6 +44,11 @@ pub enum DataContractOperationType<'a> {
         /// Storage flags for the contract
         storage_flags: Option<Cow<'a, StorageFlags>>,
     },
+    /// Deletes a contract with all its documents, the storage of removed data is refunded.
+    DeleteContract {
+        /// The contract id
+        contract_id: Identifier,
+    },
 }
 
 impl DriveLowLevelOperationConverter for DataContractOperationType<'_> {
@@ -81,6 +87,14 @@ impl DriveLowLevelOperationConverter for DataContractOperationType<'_> {
                 transaction,
                 platform_version,
             ),
+            DataContractOperationType::DeleteContract { contract_id } => drive
+                .delete_contract_operations(
+                    contract_id.to_buffer(),
+                    block_info,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
         }
     }
 }
@@ -117,6 +131,11 @@ impl DataContractOperationType<'_> {
                     contract_id: contract.id(),
                 }]
             }
+            Self::DeleteContract { contract_id } => {
+                vec![DriveOperationFinalizeTask::RemoveDataContractFromCache {
+                    contract_id: *contract_id,
+                }]
+            }
         };
 
         Ok(Some(tasks))
@@ -4,7 +4,7 @@ use crate::error::Error;
 use crate::fee::op::LowLevelDriveOperation;
 use dpp::block::block_info::BlockInfo;
 use dpp::identity::{Identity, IdentityPublicKey, KeyID, TimestampMillis};
-use dpp::prelude::Revision;
+use dpp::prelude::{IdentityNonce, Revision};
 
 use dpp::version::PlatformVersion;
 use grovedb::batch::KeyInfoPath;
@@ -75,6 +75,14 @@ pub enum IdentityOperationType {
         /// The revision we are updating to
         revision: Revision,
     },
+
+    /// Updates the nonce of an identity.
+    UpdateIdentityNonce {
+        /// The identity id
+        identity_id: [u8; 32],
+        /// The nonce we are updating to
+        nonce: IdentityNonce,
+    },
 }
 
 impl DriveLowLevelOperationConverter for IdentityOperationType {
@@ -167,6 +175,13 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 estimated_costs_only_with_layer_info,
                 platform_version,
             )?]),
+            IdentityOperationType::UpdateIdentityNonce { identity_id, nonce } => Ok(vec![drive
+                .update_identity_nonce_operation(
+                    identity_id,
+                    nonce,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?]),
         }
     }
 }
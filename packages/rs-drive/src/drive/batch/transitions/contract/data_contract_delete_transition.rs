@@ -0,0 +1,28 @@
+use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
+use crate::drive::batch::DriveOperation::{DataContractOperation, IdentityOperation};
+use crate::drive::batch::{DataContractOperationType, DriveOperation, IdentityOperationType};
+use crate::error::Error;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for DataContractDeleteTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        // We must delete the contract and bump the nonce of its owner
+        let drive_operations = vec![
+            DataContractOperation(DataContractOperationType::DeleteContract {
+                contract_id: self.data_contract_id(),
+            }),
+            IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
+                identity_id: self.owner_id().to_buffer(),
+                nonce: self.nonce(),
+            }),
+        ];
+
+        Ok(drive_operations)
+    }
+}
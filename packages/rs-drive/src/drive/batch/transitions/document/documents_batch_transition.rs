@@ -1,10 +1,17 @@
 use crate::drive::batch::transitions::document::DriveHighLevelDocumentOperationConverter;
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
-use crate::drive::batch::DriveOperation;
+use crate::drive::batch::DriveOperation::IdentityOperation;
+use crate::drive::batch::{DriveOperation, IdentityOperationType};
+use crate::error::identity::IdentityError;
 use crate::error::Error;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
 use crate::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use dpp::block::epoch::Epoch;
+use dpp::fee::Credits;
+use dpp::prelude::Identifier;
 use dpp::version::PlatformVersion;
+use std::collections::BTreeMap;
 
 impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
     fn into_high_level_drive_operations<'b>(
@@ -14,7 +21,39 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
         let owner_id = self.owner_id();
         let transitions = self.transitions_owned();
-        Ok(transitions
+
+        // Balance operations set the resulting balance of an identity, so several of them for
+        // the same identity in one batch would overwrite each other. Payments of all purchases
+        // are summed up to a single operation per identity instead.
+        let mut paid_by_owner: Credits = 0;
+        let mut paid_to_sellers: BTreeMap<Identifier, Credits> = BTreeMap::new();
+
+        for transition in transitions.iter() {
+            let DocumentTransitionAction::PurchaseAction(purchase) = transition else {
+                continue;
+            };
+
+            let seller_id = purchase.original_owner_id();
+            if seller_id == owner_id {
+                continue;
+            }
+
+            paid_by_owner = paid_by_owner
+                .checked_add(purchase.price())
+                .ok_or(Error::Identity(IdentityError::CriticalBalanceOverflow(
+                    "sum of purchase prices overflows",
+                )))?;
+
+            let paid_to_seller = paid_to_sellers.entry(seller_id).or_default();
+            *paid_to_seller =
+                paid_to_seller
+                    .checked_add(purchase.price())
+                    .ok_or(Error::Identity(IdentityError::CriticalBalanceOverflow(
+                        "sum of purchase prices overflows",
+                    )))?;
+        }
+
+        let mut drive_operations = transitions
             .into_iter()
             .map(|transition| {
                 transition.into_high_level_document_drive_operations(
@@ -26,6 +65,26 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
             .collect::<Result<Vec<Vec<DriveOperation>>, Error>>()?
             .into_iter()
             .flatten()
-            .collect())
+            .collect::<Vec<_>>();
+
+        if paid_by_owner > 0 {
+            drive_operations.push(IdentityOperation(
+                IdentityOperationType::RemoveFromIdentityBalance {
+                    identity_id: owner_id.to_buffer(),
+                    balance_to_remove: paid_by_owner,
+                },
+            ));
+        }
+
+        for (seller_id, added_balance) in paid_to_sellers {
+            drive_operations.push(IdentityOperation(
+                IdentityOperationType::AddToIdentityBalance {
+                    identity_id: seller_id.to_buffer(),
+                    added_balance,
+                },
+            ));
+        }
+
+        Ok(drive_operations)
     }
 }
@@ -6,7 +6,10 @@ use dpp::version::PlatformVersion;
 
 mod document_create_transition;
 mod document_delete_transition;
+mod document_purchase_transition;
+mod document_transfer_transition;
 mod document_transition;
+mod document_update_price_transition;
 mod document_update_transition;
 mod documents_batch_transition;
 
@@ -16,6 +16,7 @@ impl DriveHighLevelOperationConverter for IdentityCreditTransferTransitionAction
         let recipient_id = self.recipient_id();
         let identity_id = self.identity_id();
         let transfer_amount = self.transfer_amount();
+        let nonce = self.nonce();
 
         let drive_operations = vec![
             IdentityOperation(IdentityOperationType::RemoveFromIdentityBalance {
@@ -26,6 +27,10 @@ impl DriveHighLevelOperationConverter for IdentityCreditTransferTransitionAction
                 identity_id: recipient_id.to_buffer(),
                 added_balance: transfer_amount,
             }),
+            IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
+                identity_id: identity_id.to_buffer(),
+                nonce,
+            }),
         ];
         Ok(drive_operations)
     }
@@ -16,6 +16,7 @@ impl DriveHighLevelOperationConverter for IdentityCreditWithdrawalTransitionActi
     ) -> Result<Vec<DriveOperation<'a>>, Error> {
         let identity_id = self.identity_id();
         let revision = self.revision();
+        let nonce = self.nonce();
         let prepared_withdrawal_document = self.prepared_withdrawal_document_owned();
 
         let drive_operations = vec![
@@ -23,6 +24,10 @@ impl DriveHighLevelOperationConverter for IdentityCreditWithdrawalTransitionActi
                 identity_id: identity_id.into_buffer(),
                 revision,
             }),
+            IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
+                identity_id: identity_id.into_buffer(),
+                nonce,
+            }),
             DocumentOperation(DocumentOperationType::AddWithdrawalDocument {
                 owned_document_info: OwnedDocumentInfo {
                     document_info: DocumentInfo::DocumentOwnedInfo((
@@ -58,6 +58,10 @@ impl<'s> DriveHighLevelOperationConverter for StateTransitionAction {
                 identity_credit_transfer_transition,
             ) => identity_credit_transfer_transition
                 .into_high_level_drive_operations(epoch, platform_version),
+            StateTransitionAction::DataContractDeleteAction(data_contract_delete_transition) => {
+                data_contract_delete_transition
+                    .into_high_level_drive_operations(epoch, platform_version)
+            }
         }
     }
 }
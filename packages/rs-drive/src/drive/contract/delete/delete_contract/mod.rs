@@ -0,0 +1,130 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Deletes a data contract together with all its documents and indexes.
+    ///
+    /// The version of the contract delete method is determined by the platform version.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The identifier of the contract to delete.
+    /// * `block_info` - A `BlockInfo` object containing information about the block where
+    ///   the contract is being deleted.
+    /// * `apply` - A boolean indicating whether the deletion should be applied (`true`) or
+    ///   only its fees should be calculated (`false`).
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for deleting the contract.
+    /// * `platform_version` - The `PlatformVersion` to determine which version of contract deletion to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - If successful, returns a `FeeResult` with the processing fee
+    ///   of the deletion and the storage fees refunded to the owners of the removed data.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the contract does not exist, can not be deleted,
+    /// has more than [MAX_DELETED_CONTRACT_ELEMENTS](crate::drive::defaults::MAX_DELETED_CONTRACT_ELEMENTS)
+    /// elements to delete, or if the provided platform version does not match any known versions.
+    pub fn delete_contract(
+        &self,
+        contract_id: [u8; 32],
+        block_info: BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .delete
+            .delete_contract
+        {
+            0 => self.delete_contract_v0(
+                contract_id,
+                block_info,
+                apply,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_contract".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Gets the operations to delete a data contract together with all its documents and indexes.
+    ///
+    /// The contract subtree is walked so every element under it is deleted before the tree
+    /// holding it, the storage of removed elements is refunded to their owners through the
+    /// storage flags when the operations are applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The identifier of the contract to delete.
+    /// * `block_info` - A `BlockInfo` object containing information about the block where
+    ///   the contract is being deleted.
+    /// * `estimated_costs_only_with_layer_info` - A mutable reference to an `Option` of a `HashMap`
+    ///   containing estimated layer information. If provided (`Some`), layer information for
+    ///   the deleted subtrees is added to it.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for deleting the contract.
+    /// * `platform_version` - The `PlatformVersion` to determine which version of contract deletion to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<LowLevelDriveOperation>, Error>` - If successful, returns the operations
+    ///   deleting the contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DriveError::DeletingTooLargeContract` if the contract has more than
+    /// [MAX_DELETED_CONTRACT_ELEMENTS](crate::drive::defaults::MAX_DELETED_CONTRACT_ELEMENTS)
+    /// elements to delete, it can be used to check a contract can be deleted before deleting it.
+    pub fn delete_contract_operations(
+        &self,
+        contract_id: [u8; 32],
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .delete
+            .delete_contract
+        {
+            0 => self.delete_contract_operations_v0(
+                contract_id,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_contract_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
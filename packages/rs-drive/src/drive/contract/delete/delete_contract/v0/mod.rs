@@ -0,0 +1,495 @@
+use crate::drive::contract::paths::{all_contracts_global_root_path, contract_root_path_vec};
+use crate::drive::defaults::{MAX_DELETED_CONTRACT_ELEMENTS, MAX_ELEMENT_SIZE};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchDeleteApplyType;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::fee::fee_result::FeeResult;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel};
+use grovedb::EstimatedLayerSizes::{AllSubtrees, Mix};
+use grovedb::EstimatedSumTrees::{NoSumTrees, SomeSumTrees};
+use grovedb::{Element, EstimatedLayerInformation, PathQuery, Query, SizedQuery, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Deletes a data contract and returns the fee of the deletion.
+    pub(super) fn delete_contract_v0(
+        &self,
+        contract_id: [u8; 32],
+        block_info: BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.delete_contract_operations_v0(
+            contract_id,
+            &block_info,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+            platform_version,
+        )?;
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        if apply {
+            let mut drive_cache = self.cache.write().unwrap();
+
+            drive_cache.cached_contracts.remove(contract_id);
+        }
+
+        Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+        )
+    }
+
+    /// Gets the operations to delete a data contract.
+    pub(super) fn delete_contract_operations_v0(
+        &self,
+        contract_id: [u8; 32],
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info_and_add_to_operations(
+                contract_id,
+                Some(&block_info.epoch),
+                false,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?
+            .ok_or(Error::Drive(DriveError::DeletingContractThatDoesNotExist(
+                "contract to delete does not exist",
+            )))?;
+
+        if !contract_fetch_info.contract.config().can_be_deleted() {
+            return Err(Error::Drive(DriveError::DeletingUndeletableContract(
+                "contract config does not allow it to be deleted",
+            )));
+        }
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_levels_up_to_contract(
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        let mut remaining_elements = MAX_DELETED_CONTRACT_ELEMENTS;
+
+        self.add_delete_tree_contents_operations_v0(
+            contract_root_path_vec(contract_fetch_info.contract.id_ref().as_bytes()),
+            false,
+            &mut remaining_elements,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+
+        // index backfills of the document types would otherwise outlive the contract
+        for document_type_name in contract_fetch_info.contract.document_types().keys() {
+            self.delete_index_backfill_operations(
+                &contract_id,
+                document_type_name,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?;
+        }
+
+        let global_root_path = all_contracts_global_root_path();
+
+        self.batch_delete(
+            (&global_root_path).into(),
+            contract_id.as_slice(),
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((true, false)),
+            },
+            transaction,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+
+    /// Adds the operations deleting every element of the tree at `path`.
+    ///
+    /// Subtrees are emptied before they are deleted themselves, so the tree at `path` is
+    /// empty once the operations are applied. At most `remaining_elements` elements are
+    /// deleted, so deleting a contract is bounded to what fits in a single block.
+    fn add_delete_tree_contents_operations_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        is_sum_tree: bool,
+        remaining_elements: &mut u16,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut query = Query::new();
+        query.insert_all();
+
+        // query one more element than allowed to find out if there are too many
+        let path_query = PathQuery::new(
+            path.clone(),
+            SizedQuery::new(query, Some(remaining_elements.saturating_add(1)), None),
+        );
+
+        let (query_result, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let key_elements = query_result.to_key_elements();
+
+        if key_elements.len() > *remaining_elements as usize {
+            return Err(Error::Drive(DriveError::DeletingTooLargeContract(
+                "contract has more elements than can be deleted in a single block",
+            )));
+        }
+
+        *remaining_elements -= key_elements.len() as u16;
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(path.clone()),
+                Self::estimated_layer_information_for_deletion(is_sum_tree, &key_elements),
+            );
+        }
+
+        for (key, element) in key_elements {
+            let is_known_to_be_subtree_with_sum = match element {
+                Element::Tree(..) => (true, false),
+                Element::SumTree(..) => (true, true),
+                _ => (false, false),
+            };
+
+            if let (true, is_sum_subtree) = is_known_to_be_subtree_with_sum {
+                let mut subtree_path = path.clone();
+                subtree_path.push(key.clone());
+
+                self.add_delete_tree_contents_operations_v0(
+                    subtree_path,
+                    is_sum_subtree,
+                    remaining_elements,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    drive_operations,
+                    platform_version,
+                )?;
+            }
+
+            self.batch_delete(
+                path.as_slice().into(),
+                key.as_slice(),
+                BatchDeleteApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some(is_known_to_be_subtree_with_sum),
+                },
+                transaction,
+                drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// The layer information of a tree whose elements are all deleted.
+    fn estimated_layer_information_for_deletion(
+        is_sum_tree: bool,
+        key_elements: &[(Vec<u8>, Element)],
+    ) -> EstimatedLayerInformation {
+        if key_elements.is_empty() {
+            return EstimatedLayerInformation {
+                is_sum_tree,
+                estimated_layer_count: EstimatedLevel(0, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            };
+        }
+
+        let element_count = key_elements.len() as u32;
+        let average_key_size = (key_elements.iter().map(|(key, _)| key.len()).sum::<usize>()
+            / key_elements.len())
+        .clamp(1, u8::MAX as usize) as u8;
+
+        let sum_tree_count = key_elements
+            .iter()
+            .filter(|(_, element)| matches!(element, Element::SumTree(..)))
+            .count() as u32;
+        let tree_count = key_elements
+            .iter()
+            .filter(|(_, element)| matches!(element, Element::Tree(..)))
+            .count() as u32;
+        let item_count = element_count - sum_tree_count - tree_count;
+
+        let storage_flags = Some(StorageFlags::approximate_size(true, None));
+
+        // weights are relative to each other and must fit a byte
+        let weight = |count: u32| (count * u8::MAX as u32 / element_count).max(1) as u8;
+
+        let subtrees_size = (sum_tree_count + tree_count > 0).then(|| {
+            let estimated_sum_trees = if sum_tree_count > 0 {
+                SomeSumTrees {
+                    sum_trees_weight: weight(sum_tree_count),
+                    non_sum_trees_weight: weight(tree_count),
+                }
+            } else {
+                NoSumTrees
+            };
+            (
+                average_key_size,
+                estimated_sum_trees,
+                storage_flags,
+                weight(sum_tree_count + tree_count),
+            )
+        });
+
+        let items_size = (item_count > 0).then_some((
+            average_key_size,
+            MAX_ELEMENT_SIZE,
+            storage_flags,
+            weight(item_count),
+        ));
+
+        EstimatedLayerInformation {
+            is_sum_tree,
+            estimated_layer_count: ApproximateElements(element_count),
+            estimated_layer_sizes: Mix {
+                subtrees_size,
+                items_size,
+                references_size: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use crate::drive::contract::paths::contract_root_path_vec;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::drive::Drive;
+    use crate::error::drive::DriveError;
+    use crate::error::Error;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::epoch::Epoch;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::config::v0::DataContractConfigSettersV0;
+    use dpp::data_contract::DataContract;
+    use dpp::tests::json_document::{json_document_to_contract, json_document_to_document};
+    use dpp::version::PlatformVersion;
+    use rand::Rng;
+    use std::borrow::Cow;
+
+    fn setup_dashpay_with_profile(can_be_deleted: bool) -> (Drive, DataContract, [u8; 32]) {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let mut contract = json_document_to_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract.json",
+            false,
+            platform_version,
+        )
+        .expect("expected to get a contract");
+        contract.config_mut().set_can_be_deleted(can_be_deleted);
+
+        drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract successfully");
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+
+        let owner_id = rand::thread_rng().gen::<[u8; 32]>();
+
+        let document = json_document_to_document(
+            "tests/supporting_files/contract/dashpay/profile0.json",
+            Some(owner_id.into()),
+            document_type,
+            platform_version,
+        )
+        .expect("expected to get document");
+
+        let storage_flags = Some(Cow::Owned(StorageFlags::SingleEpochOwned(0, owner_id)));
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&document, storage_flags)),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to insert a document successfully");
+
+        (drive, contract, owner_id)
+    }
+
+    #[test]
+    fn should_delete_contract_and_refund_document_storage() {
+        let (drive, contract, owner_id) = setup_dashpay_with_profile(true);
+        let platform_version = PlatformVersion::latest();
+
+        let fee_result = drive
+            .delete_contract(
+                contract.id().to_buffer(),
+                BlockInfo::default_with_epoch(Epoch::new(3).unwrap()),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to delete contract");
+
+        assert!(fee_result.processing_fee > 0);
+
+        let refunds = fee_result
+            .fee_refunds
+            .get(&owner_id)
+            .expect("expected document owner to be refunded");
+        assert!(refunds.get(&0).is_some_and(|credits| *credits > 0));
+
+        let fetched_contract = drive
+            .get_contract_with_fetch_info(contract.id().to_buffer(), false, None, platform_version)
+            .expect("expected to fetch contract");
+        assert!(fetched_contract.is_none());
+    }
+
+    #[test]
+    fn should_not_delete_contract_without_apply() {
+        let (drive, contract, _) = setup_dashpay_with_profile(true);
+        let platform_version = PlatformVersion::latest();
+
+        let fee_result = drive
+            .delete_contract(
+                contract.id().to_buffer(),
+                BlockInfo::default(),
+                false,
+                None,
+                platform_version,
+            )
+            .expect("expected to estimate contract deletion");
+
+        assert!(fee_result.processing_fee > 0);
+
+        let fetched_contract = drive
+            .get_contract_with_fetch_info(contract.id().to_buffer(), false, None, platform_version)
+            .expect("expected to fetch contract");
+        assert!(fetched_contract.is_some());
+    }
+
+    #[test]
+    fn should_fail_to_delete_undeletable_contract() {
+        let (drive, contract, _) = setup_dashpay_with_profile(false);
+        let platform_version = PlatformVersion::latest();
+
+        let result = drive.delete_contract(
+            contract.id().to_buffer(),
+            BlockInfo::default(),
+            true,
+            None,
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::DeletingUndeletableContract(_)))
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_delete_contract_with_too_many_elements() {
+        let (drive, contract, _) = setup_dashpay_with_profile(true);
+        let platform_version = PlatformVersion::latest();
+
+        let result = drive.add_delete_tree_contents_operations_v0(
+            contract_root_path_vec(contract.id_ref().as_bytes()),
+            false,
+            &mut 3,
+            &mut None,
+            None,
+            &mut vec![],
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::DeletingTooLargeContract(_)))
+        ));
+
+        let mut remaining_elements = u16::MAX - 1;
+
+        drive
+            .add_delete_tree_contents_operations_v0(
+                contract_root_path_vec(contract.id_ref().as_bytes()),
+                false,
+                &mut remaining_elements,
+                &mut None,
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to get operations");
+
+        assert!(u16::MAX - 1 - remaining_elements > 3);
+    }
+}
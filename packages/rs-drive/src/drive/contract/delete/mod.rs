@@ -0,0 +1 @@
+mod delete_contract;
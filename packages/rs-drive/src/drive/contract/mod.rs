@@ -37,6 +37,8 @@ mod apply;
 #[cfg(any(feature = "full", feature = "verify"))]
 mod contract_fetch_info;
 #[cfg(feature = "full")]
+mod delete;
+#[cfg(feature = "full")]
 mod estimation_costs;
 #[cfg(feature = "full")]
 mod get_fetch;
@@ -1,7 +1,12 @@
+use crate::drive::document::index_backfill::{
+    added_index_names, index_backfill_storage_key, IndexBackfill, ESTIMATED_INDEX_BACKFILL_SIZE,
+};
 use crate::drive::flags::StorageFlags;
-use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::grove_operations::{BatchInsertTreeApplyType, DirectQueryType};
 use crate::drive::object_size_info::DriveKeyInfo::KeyRef;
+use crate::drive::object_size_info::PathKeyElementInfo;
 use crate::drive::object_size_info::PathKeyInfo::PathFixedSizeKeyRef;
+use crate::drive::system::{misc_path, misc_path_vec};
 use crate::drive::{contract_documents_path, Drive};
 use crate::error::drive::DriveError;
 use crate::error::Error;
@@ -14,12 +19,19 @@ use dpp::data_contract::DataContract;
 use dpp::fee::fee_result::FeeResult;
 
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::document_type::DocumentTypeRef;
 use dpp::serialization::PlatformSerializableWithPlatformVersion;
 
+use crate::drive::document::contract_document_type_path_vec;
+use crate::query::QueryItem;
 use dpp::version::PlatformVersion;
 use grovedb::batch::KeyInfoPath;
-use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use grovedb::query_result_type::QueryResultType;
+use grovedb::EstimatedLayerCount::ApproximateElements;
+use grovedb::EstimatedLayerSizes::AllItems;
+use grovedb::{Element, EstimatedLayerInformation, PathQuery, Query, SizedQuery, TransactionArg};
 use std::collections::{HashMap, HashSet};
+use std::ops::RangeFull;
 
 impl Drive {
     /// Updates a data contract.
@@ -324,6 +336,17 @@ impl Drive {
                         index_cache.insert(index_bytes);
                     }
                 }
+
+                // existing documents are added to new indices over the next blocks
+                self.add_index_backfill_operations_v0(
+                    contract,
+                    document_type.as_ref(),
+                    added_index_names(document_type.as_ref(), original_document_type.as_ref()),
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    &mut batch_operations,
+                    platform_version,
+                )?;
             } else {
                 // We can just insert this directly because the original document type already exists
                 self.batch_insert_empty_tree(
@@ -370,4 +393,164 @@ impl Drive {
         }
         Ok(batch_operations)
     }
+
+    /// Registers the backfill of indices added to a document type that already holds documents.
+    ///
+    /// Indices whose previous backfill failed are backfilled again if the update keeps them,
+    /// and are forgotten otherwise.
+    #[allow(clippy::too_many_arguments)]
+    fn add_index_backfill_operations_v0(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        added_index_names: Vec<String>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let document_type_name = document_type.name().as_str();
+        let misc_path = misc_path();
+        let key = index_backfill_storage_key(contract.id_ref().as_bytes(), document_type_name);
+
+        // when estimating costs we consider that the document type holds documents
+        if let Some(estimated_costs_only_with_layer_info) =
+            estimated_costs_only_with_layer_info.as_mut()
+        {
+            if added_index_names.is_empty() {
+                return Ok(());
+            }
+
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(misc_path_vec()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(16),
+                    estimated_layer_sizes: AllItems(
+                        key.len().min(u8::MAX as usize) as u8,
+                        ESTIMATED_INDEX_BACKFILL_SIZE,
+                        None,
+                    ),
+                },
+            );
+
+            let index_backfill = IndexBackfill::new(
+                contract.id(),
+                document_type_name.to_string(),
+                added_index_names,
+                vec![],
+            );
+
+            return self.batch_insert(
+                PathKeyElementInfo::PathFixedSizeKeyRefElement((
+                    misc_path,
+                    key.as_slice(),
+                    index_backfill.serialize_to_element()?,
+                )),
+                batch_operations,
+                drive_version,
+            );
+        }
+
+        let previous_index_backfill = self
+            .grove_get_raw_optional(
+                (&misc_path).into(),
+                key.as_slice(),
+                DirectQueryType::StatefulDirectQuery,
+                transaction,
+                batch_operations,
+                drive_version,
+            )?
+            .map(|element| IndexBackfill::deserialize_from_element(&element))
+            .transpose()?;
+
+        let (previous_conflicts, previous_failed_index_names) = match previous_index_backfill {
+            Some(previous_index_backfill) if previous_index_backfill.is_pending() => {
+                if added_index_names.is_empty() {
+                    return Ok(());
+                }
+
+                return Err(Error::Drive(DriveError::IndexBackfillInProgress(
+                    "can not add indices to a document type whose indices are being backfilled",
+                )));
+            }
+            Some(previous_index_backfill) => (
+                previous_index_backfill.conflicts,
+                previous_index_backfill.failed_index_names,
+            ),
+            None => (vec![], vec![]),
+        };
+
+        let mut index_names = added_index_names;
+        for index in document_type.indices() {
+            if previous_failed_index_names.contains(&index.name)
+                && !index_names.contains(&index.name)
+            {
+                index_names.push(index.name.clone());
+            }
+        }
+
+        if index_names.is_empty() && previous_failed_index_names.is_empty() {
+            return Ok(());
+        }
+
+        if !index_names.is_empty() {
+            let mut primary_key_path =
+                contract_document_type_path_vec(contract.id_ref().as_bytes(), document_type_name);
+            primary_key_path.push(vec![0]);
+
+            let path_query = PathQuery::new(
+                primary_key_path,
+                SizedQuery::new(
+                    Query::new_single_query_item(QueryItem::RangeFull(RangeFull)),
+                    Some(1),
+                    None,
+                ),
+            );
+
+            let (documents, _) = self.grove_get_raw_path_query(
+                &path_query,
+                transaction,
+                QueryResultType::QueryKeyElementPairResultType,
+                batch_operations,
+                drive_version,
+            )?;
+
+            if documents.elements.is_empty() {
+                index_names.clear();
+            }
+        }
+
+        let index_backfill = IndexBackfill::new(
+            contract.id(),
+            document_type_name.to_string(),
+            index_names,
+            previous_conflicts,
+        );
+
+        if index_backfill.should_be_kept() {
+            self.batch_insert(
+                PathKeyElementInfo::PathFixedSizeKeyRefElement((
+                    misc_path,
+                    key.as_slice(),
+                    index_backfill.serialize_to_element()?,
+                )),
+                batch_operations,
+                drive_version,
+            )
+        } else {
+            // the failed indices were removed and no document was left out of an index
+            self.delete_index_backfill_operations(
+                contract.id_ref().as_bytes(),
+                document_type_name,
+                &mut None,
+                transaction,
+                batch_operations,
+                platform_version,
+            )
+        }
+    }
 }
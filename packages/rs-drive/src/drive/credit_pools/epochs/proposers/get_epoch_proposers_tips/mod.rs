@@ -0,0 +1,48 @@
+mod v0;
+
+use grovedb::TransactionArg;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::block::epoch::Epoch;
+use dpp::fee::Credits;
+
+use dpp::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Returns the tips each of the Epoch's block proposers earned
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch_tree` - An Epoch instance.
+    /// * `transaction` - A TransactionArg instance.
+    /// * `platform_version` - A PlatformVersion instance representing the version of the drive.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing a map of proposers' transaction hashes to their tips or an Error.
+    pub fn get_epoch_proposers_tips(
+        &self,
+        epoch_tree: &Epoch,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Vec<u8>, Credits>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .credit_pools
+            .epochs
+            .get_epoch_proposers_tips
+        {
+            0 => self.get_epoch_proposers_tips_v0(epoch_tree, transaction),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "get_epoch_proposers_tips".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use grovedb::query_result_type::QueryResultType::QueryKeyElementPairResultType;
+use grovedb::{Element, PathQuery, Query, SizedQuery, TransactionArg};
+use std::collections::BTreeMap;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee_pools::epochs::epoch_key_constants::KEY_PROPOSER_TIPS_PREFIX;
+use crate::fee_pools::epochs::paths::EpochProposers;
+use dpp::balances::credits::Creditable;
+use dpp::block::epoch::Epoch;
+use dpp::fee::Credits;
+
+impl Drive {
+    /// Returns the tips each of the Epoch's block proposers earned
+    pub(super) fn get_epoch_proposers_tips_v0(
+        &self,
+        epoch_tree: &Epoch,
+        transaction: TransactionArg,
+    ) -> Result<BTreeMap<Vec<u8>, Credits>, Error> {
+        let path_as_vec = epoch_tree.get_path_vec();
+
+        let prefix = KEY_PROPOSER_TIPS_PREFIX[0];
+
+        let mut query = Query::new();
+        query.insert_range(vec![prefix]..vec![prefix + 1]);
+
+        let path_query = PathQuery::new(path_as_vec, SizedQuery::new(query, None, None));
+
+        let key_elements = self
+            .grove
+            .query_raw(
+                &path_query,
+                transaction.is_some(),
+                true,
+                QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?
+            .0
+            .to_key_elements();
+
+        key_elements
+            .into_iter()
+            .map(|(key, element)| {
+                let Element::SumItem(tips, _) = element else {
+                    return Err(Error::Drive(DriveError::UnexpectedElementType(
+                        "epochs proposer tips must be a sum item",
+                    )));
+                };
+
+                Ok((key[1..].to_vec(), tips.to_unsigned()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+    use crate::drive::batch::GroveDbOpBatch;
+    use crate::fee_pools::epochs::operations_factory::EpochOperations;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::epoch::Epoch;
+    use std::collections::BTreeMap;
+
+    use dpp::version::PlatformVersion;
+
+    #[test]
+    fn test_value() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+
+        let first_pro_tx_hash: [u8; 32] = rand::random();
+        let second_pro_tx_hash: [u8; 32] = rand::random();
+
+        let epoch = Epoch::new(0).unwrap();
+
+        let mut batch = GroveDbOpBatch::new();
+
+        batch.push(
+            epoch
+                .update_proposer_tips_operation(&first_pro_tx_hash, 10)
+                .expect("should create operation"),
+        );
+        batch.push(
+            epoch
+                .update_proposer_tips_operation(&second_pro_tx_hash, 20)
+                .expect("should create operation"),
+        );
+
+        drive
+            .grove_apply_batch(batch, false, Some(&transaction), &platform_version.drive)
+            .expect("should apply batch");
+
+        let tips = drive
+            .get_epoch_proposers_tips(&epoch, Some(&transaction), platform_version)
+            .expect("should get proposers tips");
+
+        assert_eq!(
+            tips,
+            BTreeMap::from([
+                (first_pro_tx_hash.to_vec(), 10),
+                (second_pro_tx_hash.to_vec(), 20)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_no_tips() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+
+        let epoch = Epoch::new(0).unwrap();
+
+        let tips = drive
+            .get_epoch_proposers_tips(&epoch, Some(&transaction), platform_version)
+            .expect("should get proposers tips");
+
+        assert!(tips.is_empty());
+    }
+}
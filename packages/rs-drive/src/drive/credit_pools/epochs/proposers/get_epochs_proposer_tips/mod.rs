@@ -0,0 +1,49 @@
+mod v0;
+
+use grovedb::TransactionArg;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::block::epoch::Epoch;
+use dpp::fee::Credits;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Returns the tips the given proposer earned in the epoch
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - An Epoch instance.
+    /// * `proposer_tx_hash` - An array of bytes containing the transaction hash of the proposer.
+    /// * `transaction` - A TransactionArg instance.
+    /// * `platform_version` - A PlatformVersion instance representing the version of the drive.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the tips or an Error.
+    pub fn get_epochs_proposer_tips(
+        &self,
+        epoch: &Epoch,
+        proposer_tx_hash: &[u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Credits, Error> {
+        match platform_version
+            .drive
+            .methods
+            .credit_pools
+            .epochs
+            .get_epochs_proposer_tips
+        {
+            0 => self.get_epochs_proposer_tips_v0(epoch, proposer_tx_hash, transaction),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "get_epochs_proposer_tips".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
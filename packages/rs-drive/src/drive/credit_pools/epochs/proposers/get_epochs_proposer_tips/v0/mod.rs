@@ -0,0 +1,116 @@
+use grovedb::{Element, TransactionArg};
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee_pools::epochs::paths::{proposer_tips_key, EpochProposers};
+use dpp::balances::credits::Creditable;
+use dpp::block::epoch::Epoch;
+use dpp::fee::Credits;
+
+impl Drive {
+    /// Returns the tips the given proposer earned in the epoch
+    pub(super) fn get_epochs_proposer_tips_v0(
+        &self,
+        epoch: &Epoch,
+        proposer_tx_hash: &[u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Credits, Error> {
+        let element = self
+            .grove
+            .get(
+                &epoch.get_path(),
+                &proposer_tips_key(proposer_tx_hash),
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        let Element::SumItem(tips, _) = element else {
+            return Err(Error::Drive(DriveError::UnexpectedElementType(
+                "epochs proposer tips must be a sum item",
+            )));
+        };
+
+        Ok(tips.to_unsigned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+    use crate::drive::batch::GroveDbOpBatch;
+    use crate::error::drive::DriveError;
+    use crate::error::Error;
+    use crate::fee_pools::epochs::operations_factory::EpochOperations;
+    use crate::fee_pools::epochs::paths::{proposer_tips_key, EpochProposers};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::epoch::Epoch;
+    use grovedb::Element;
+
+    use dpp::version::PlatformVersion;
+
+    #[test]
+    fn test_value() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+
+        let pro_tx_hash: [u8; 32] = rand::random();
+
+        let epoch = Epoch::new(0).unwrap();
+
+        let mut batch = GroveDbOpBatch::new();
+
+        batch.push(
+            epoch
+                .update_proposer_tips_operation(&pro_tx_hash, 42)
+                .expect("should create operation"),
+        );
+
+        drive
+            .grove_apply_batch(batch, false, Some(&transaction), &platform_version.drive)
+            .expect("should apply batch");
+
+        let tips = drive
+            .get_epochs_proposer_tips(&epoch, &pro_tx_hash, Some(&transaction), platform_version)
+            .expect("should get proposer tips");
+
+        assert_eq!(tips, 42);
+    }
+
+    #[test]
+    fn test_error_if_value_has_wrong_element_type() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+
+        let pro_tx_hash: [u8; 32] = rand::random();
+
+        let epoch = Epoch::new(0).unwrap();
+
+        let mut batch = GroveDbOpBatch::new();
+
+        batch.add_insert(
+            epoch.get_path_vec(),
+            proposer_tips_key(&pro_tx_hash),
+            Element::Item(u64::MAX.to_be_bytes().to_vec(), None),
+        );
+
+        drive
+            .grove_apply_batch(batch, false, Some(&transaction), &platform_version.drive)
+            .expect("should apply batch");
+
+        let result = drive.get_epochs_proposer_tips(
+            &epoch,
+            &pro_tx_hash,
+            Some(&transaction),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::UnexpectedElementType(_)))
+        ));
+    }
+}
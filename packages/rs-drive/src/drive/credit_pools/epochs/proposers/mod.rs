@@ -33,5 +33,7 @@
 //!
 
 mod get_epoch_proposers;
+mod get_epoch_proposers_tips;
 mod get_epochs_proposer_block_count;
+mod get_epochs_proposer_tips;
 mod is_epochs_proposers_tree_empty;
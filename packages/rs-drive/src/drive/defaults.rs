@@ -79,10 +79,16 @@ pub const CONTRACT_MAX_SERIALIZED_SIZE: u16 = 16384;
 /// Max element size
 pub const MAX_ELEMENT_SIZE: u32 = 5000;
 
+/// Max number of elements removed when deleting a data contract, including its documents and indexes
+pub const MAX_DELETED_CONTRACT_ELEMENTS: u16 = 10000;
+
 /// Default required bytes to hold a user balance
 /// TODO We probably don't need it anymore since we always pay for 9 bytes
 pub const AVERAGE_BALANCE_SIZE: u32 = 6;
 
+/// Default required bytes to hold a value of an index sum tree
+pub const AVERAGE_INDEX_SUM_ITEM_SIZE: u32 = 9;
+
 /// Default required bytes to hold a public key
 pub const AVERAGE_KEY_SIZE: u32 = 50;
 
@@ -149,6 +149,14 @@ impl Drive {
             &mut batch_operations,
             platform_version,
         )?;
+
+        self.remove_document_expiry_operations(
+            &document_and_contract_info,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
         Ok(batch_operations)
     }
 }
@@ -57,6 +57,9 @@ pub use remove_document_from_primary_storage::*;
 mod remove_reference_for_index_level_for_contract_operations;
 pub use remove_reference_for_index_level_for_contract_operations::*;
 
+mod remove_aggregates_for_index_level_for_contract_operations;
+pub use remove_aggregates_for_index_level_for_contract_operations::*;
+
 // Module: remove_indices_for_index_level_for_contract_operations
 // This module contains functionality to remove indices for an index level for contract operations
 mod remove_indices_for_index_level_for_contract_operations;
@@ -0,0 +1,81 @@
+mod v0;
+
+use crate::drive::flags::StorageFlags;
+use crate::drive::object_size_info::{DocumentAndContractInfo, PathInfo};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::batch::KeyInfoPath;
+
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Removes the document from the count and sum trees of the index terminating at an index level.
+    ///
+    /// The aggregate trees themselves are kept, even once they no longer hold any documents.
+    ///
+    /// # Parameters
+    /// * `document_and_contract_info`: The document and contract info.
+    /// * `index_path_info`: Path info for the index.
+    /// * `countable`: Whether the index keeps a count of its documents.
+    /// * `summable_properties`: The properties the index keeps sums of.
+    /// * `storage_flags`: Optional storage flags.
+    /// * `previous_batch_operations`: Previous batch operations to include.
+    /// * `estimated_costs_only_with_layer_info`: Estimated costs with layer info.
+    /// * `event_id`: The event ID.
+    /// * `transaction`: The transaction argument.
+    /// * `batch_operations`: The batch operations to include.
+    /// * `platform_version`: The platform version to select the correct function version to run.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the operation was successful.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub(crate) fn remove_aggregates_for_index_level_for_contract_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        index_path_info: PathInfo<0>,
+        countable: bool,
+        summable_properties: &[String],
+        storage_flags: &Option<&StorageFlags>,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        event_id: [u8; 32],
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .delete
+            .remove_aggregates_for_index_level_for_contract_operations
+        {
+            0 => self.remove_aggregates_for_index_level_for_contract_operations_v0(
+                document_and_contract_info,
+                index_path_info,
+                countable,
+                summable_properties,
+                storage_flags,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                event_id,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_aggregates_for_index_level_for_contract_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
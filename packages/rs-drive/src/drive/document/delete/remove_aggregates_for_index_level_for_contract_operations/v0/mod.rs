@@ -0,0 +1,133 @@
+use grovedb::batch::key_info::KeyInfo::KnownKey;
+use grovedb::batch::KeyInfoPath;
+
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+
+use grovedb::EstimatedSumTrees::AllSumTrees;
+use std::collections::HashMap;
+
+use crate::drive::defaults::{AVERAGE_INDEX_SUM_ITEM_SIZE, DEFAULT_HASH_SIZE_U8};
+use crate::drive::document::{INDEX_COUNT_TREE_KEY, INDEX_SUM_TREES_KEY};
+use crate::drive::flags::StorageFlags;
+
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods, PathInfo};
+use crate::drive::Drive;
+
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Removes the document from the count and sum trees of the index terminating at an index level.
+    pub(super) fn remove_aggregates_for_index_level_for_contract_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        index_path_info: PathInfo<0>,
+        countable: bool,
+        summable_properties: &[String],
+        storage_flags: &Option<&StorageFlags>,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        event_id: [u8; 32],
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let index_key_info_path = index_path_info.convert_to_key_info_path();
+
+        let document_id = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_document_id_as_slice()
+            .unwrap_or(event_id.as_slice());
+
+        let mut aggregate_tree_paths = vec![];
+
+        if countable {
+            let mut key_info_path = index_key_info_path.clone();
+            key_info_path.push(KnownKey(vec![INDEX_COUNT_TREE_KEY]));
+            aggregate_tree_paths.push(key_info_path);
+        }
+
+        if !summable_properties.is_empty() {
+            let mut sum_trees_key_info_path = index_key_info_path;
+            sum_trees_key_info_path.push(KnownKey(vec![INDEX_SUM_TREES_KEY]));
+
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                estimated_costs_only_with_layer_info.insert(
+                    sum_trees_key_info_path.clone(),
+                    EstimatedLayerInformation {
+                        is_sum_tree: false,
+                        estimated_layer_count: ApproximateElements(
+                            summable_properties.len() as u32
+                        ),
+                        estimated_layer_sizes: AllSubtrees(
+                            DEFAULT_HASH_SIZE_U8,
+                            AllSumTrees,
+                            storage_flags.map(|s| s.serialized_size()),
+                        ),
+                    },
+                );
+            }
+
+            for property_name in summable_properties {
+                let mut key_info_path = sum_trees_key_info_path.clone();
+                key_info_path.push(KnownKey(property_name.as_bytes().to_vec()));
+                aggregate_tree_paths.push(key_info_path);
+            }
+        }
+
+        for key_info_path in aggregate_tree_paths {
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                estimated_costs_only_with_layer_info.insert(
+                    key_info_path.clone(),
+                    EstimatedLayerInformation {
+                        is_sum_tree: true,
+                        estimated_layer_count: PotentiallyAtMaxElements,
+                        estimated_layer_sizes: AllItems(
+                            DEFAULT_HASH_SIZE_U8,
+                            AVERAGE_INDEX_SUM_ITEM_SIZE,
+                            storage_flags.map(|s| s.serialized_size()),
+                        ),
+                    },
+                );
+            }
+
+            let delete_apply_type = Self::stateless_delete_of_non_tree_for_costs(
+                AllItems(
+                    DEFAULT_HASH_SIZE_U8,
+                    AVERAGE_INDEX_SUM_ITEM_SIZE,
+                    storage_flags.map(|s| s.serialized_size()),
+                ),
+                &key_info_path,
+                // we know we are not deleting a tree
+                Some((false, false)),
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+
+            // stopping at the aggregate tree itself keeps it around even once it is empty
+            let stop_path_height = key_info_path.len() as u16;
+
+            self.batch_delete_up_tree_while_empty(
+                key_info_path,
+                document_id,
+                Some(stop_path_height),
+                delete_apply_type,
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(())
+    }
+}
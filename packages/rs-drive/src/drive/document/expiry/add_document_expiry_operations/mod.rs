@@ -0,0 +1,58 @@
+mod v0;
+
+use crate::drive::object_size_info::DocumentAndContractInfo;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the expiry entry of a document whose document type has documents that expire.
+    ///
+    /// Does nothing for document types whose documents don't expire.
+    ///
+    /// # Parameters
+    /// * `document_and_contract_info`: The document being inserted and its contract.
+    /// * `estimated_costs_only_with_layer_info`: If given, only the costs are estimated.
+    /// * `batch_operations`: The operations the insertion is added to.
+    /// * `platform_version`: The platform version.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the operations were added.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub(crate) fn add_document_expiry_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiry
+            .add_document_expiry_operations
+        {
+            0 => self.add_document_expiry_operations_v0(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_document_expiry_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
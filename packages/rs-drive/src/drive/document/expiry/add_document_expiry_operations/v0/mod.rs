@@ -0,0 +1,100 @@
+use crate::drive::defaults::STORAGE_FLAGS_SIZE;
+use crate::drive::document::expiry::{
+    document_expires_at, document_expiry_storage_key, DOCUMENT_EXPIRY_STORAGE_KEY_SIZE,
+};
+use crate::drive::flags::StorageFlags;
+use crate::drive::object_size_info::{
+    DocumentAndContractInfo, DocumentInfoV0Methods, PathKeyElementInfo,
+};
+use crate::drive::system::misc_path_vec;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::document::DocumentV0Getters;
+use dpp::version::PlatformVersion;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::PotentiallyAtMaxElements;
+use grovedb::EstimatedLayerSizes::AllItems;
+use grovedb::{Element, EstimatedLayerInformation};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the expiry entry of a document whose document type has documents that expire.
+    pub(super) fn add_document_expiry_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let document_type = document_and_contract_info.document_type;
+
+        if document_type.documents_expire_after().is_none() {
+            return Ok(());
+        }
+
+        // the entry holds the name of the document type
+        let document_type_name = document_type.name().as_bytes();
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(misc_path_vec()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllItems(
+                        DOCUMENT_EXPIRY_STORAGE_KEY_SIZE as u8,
+                        document_type_name.len() as u32,
+                        Some(STORAGE_FLAGS_SIZE),
+                    ),
+                },
+            );
+        }
+
+        let document_info = &document_and_contract_info.owned_document_info.document_info;
+
+        let path_key_element_info = match document_info.get_borrowed_document_and_storage_flags() {
+            Some((document, storage_flags)) => {
+                let expires_at = document_expires_at(document_type, document).ok_or(
+                    Error::Drive(DriveError::CorruptedCodeExecution(
+                        "documents that expire must have a creation time",
+                    )),
+                )?;
+
+                let key = document_expiry_storage_key(
+                    expires_at,
+                    document_and_contract_info.contract.id_ref().as_bytes(),
+                    document.id_ref().as_bytes(),
+                );
+
+                // The owner of the document pays for the entry and gets it refunded on deletion
+                PathKeyElementInfo::<0>::PathKeyElement((
+                    misc_path_vec(),
+                    key,
+                    Element::new_item_with_flags(
+                        document_type_name.to_vec(),
+                        StorageFlags::map_to_some_element_flags(storage_flags),
+                    ),
+                ))
+            }
+            None => PathKeyElementInfo::<0>::PathKeyUnknownElementSize((
+                KeyInfoPath::from_known_owned_path(misc_path_vec()),
+                KeyInfo::MaxKeySize {
+                    unique_id: document_type.unique_id_for_storage().to_vec(),
+                    max_size: DOCUMENT_EXPIRY_STORAGE_KEY_SIZE as u8,
+                },
+                Element::required_item_space(document_type_name.len() as u32, STORAGE_FLAGS_SIZE),
+            )),
+        };
+
+        self.batch_insert(path_key_element_info, batch_operations, drive_version)
+    }
+}
@@ -0,0 +1,51 @@
+mod v0;
+
+use crate::drive::document::expiry::ExpiredDocumentsBatchOutcome;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Deletes the documents that expired at or before the time of the block.
+    ///
+    /// Documents are deleted in order of their expiry. Unlike documents deleted by their owner,
+    /// expired documents are deleted even if their document type is not mutable. The storage
+    /// of the deleted documents and of their expiry entries is refunded to their owners.
+    ///
+    /// # Parameters
+    /// * `limit`: The maximum number of documents to delete.
+    /// * `block_info`: The block info.
+    /// * `transaction`: The transaction argument.
+    /// * `platform_version`: The platform version.
+    ///
+    /// # Returns
+    /// * `Ok(ExpiredDocumentsBatchOutcome)` with the fees and the number of deleted documents.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn delete_expired_documents(
+        &self,
+        limit: u16,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ExpiredDocumentsBatchOutcome, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiry
+            .delete_expired_documents
+        {
+            0 => self.delete_expired_documents_v0(limit, block_info, transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_expired_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::drive::document::contract_documents_primary_key_path;
+use crate::drive::document::expiry::{
+    document_expiry_storage_key, DocumentExpiryEntry, ExpiredDocumentsBatchOutcome,
+};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchDeleteApplyType::StatefulBatchDelete;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
+use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+use crate::drive::system::misc_tree_constants::DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX;
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::query::QueryItem;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, PathQuery, Query, SizedQuery, TransactionArg};
+
+impl Drive {
+    /// Deletes the documents that expired at or before the time of the block.
+    pub(super) fn delete_expired_documents_v0(
+        &self,
+        limit: u16,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ExpiredDocumentsBatchOutcome, Error> {
+        let drive_version = &platform_version.drive;
+
+        let start = DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX.to_vec();
+        let mut end = DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX.to_vec();
+        end.extend_from_slice(&block_info.time_ms.saturating_add(1).to_be_bytes());
+
+        let path_query = PathQuery::new(
+            misc_path_vec(),
+            SizedQuery::new(
+                Query::new_single_query_item(QueryItem::Range(start..end)),
+                Some(limit),
+                None,
+            ),
+        );
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let entries = results
+            .to_key_elements()
+            .iter()
+            .map(|(key, element)| DocumentExpiryEntry::from_key_and_element(key, element))
+            .collect::<Result<Vec<DocumentExpiryEntry>, Error>>()?;
+
+        let mut documents_deleted = 0;
+
+        // Each document is deleted in its own batch, as deletions of documents sharing
+        // index values can't be combined
+        for entry in entries {
+            let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+
+            let contract_fetch_info = self
+                .get_contract_with_fetch_info_and_fee(
+                    entry.contract_id.to_buffer(),
+                    None,
+                    false,
+                    transaction,
+                    platform_version,
+                )?
+                .1;
+
+            let document_type = contract_fetch_info
+                .as_ref()
+                .and_then(|contract_fetch_info| {
+                    contract_fetch_info
+                        .contract
+                        .document_type_optional_for_name(entry.document_type_name.as_str())
+                });
+
+            let document_element = match document_type {
+                Some(_) => self.grove_get_raw_optional(
+                    (&contract_documents_primary_key_path(
+                        entry.contract_id.as_bytes(),
+                        entry.document_type_name.as_str(),
+                    ))
+                        .into(),
+                    entry.document_id.as_bytes(),
+                    StatefulDirectQuery,
+                    transaction,
+                    &mut drive_operations,
+                    drive_version,
+                )?,
+                None => None,
+            };
+
+            match (
+                contract_fetch_info.as_ref(),
+                document_type,
+                document_element,
+            ) {
+                (
+                    Some(contract_fetch_info),
+                    Some(document_type),
+                    Some(Element::Item(data, element_flags)),
+                ) => {
+                    let contract = &contract_fetch_info.contract;
+                    let document =
+                        Document::from_bytes(data.as_slice(), document_type, platform_version)?;
+                    let storage_flags =
+                        StorageFlags::map_cow_some_element_flags_ref(&element_flags)?;
+
+                    self.remove_document_from_primary_storage(
+                        entry.document_id.to_buffer(),
+                        document_type,
+                        contract_documents_primary_key_path(
+                            contract.id_ref().as_bytes(),
+                            entry.document_type_name.as_str(),
+                        ),
+                        &mut None,
+                        transaction,
+                        &mut batch_operations,
+                        platform_version,
+                    )?;
+
+                    let document_and_contract_info = DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentOwnedInfo((document, storage_flags)),
+                            owner_id: None,
+                        },
+                        contract,
+                        document_type,
+                    };
+
+                    self.remove_indices_for_top_index_level_for_contract_operations(
+                        &document_and_contract_info,
+                        &None,
+                        &mut None,
+                        transaction,
+                        &mut batch_operations,
+                        platform_version,
+                    )?;
+
+                    self.remove_document_expiry_operations(
+                        &document_and_contract_info,
+                        &mut None,
+                        transaction,
+                        &mut batch_operations,
+                        platform_version,
+                    )?;
+
+                    documents_deleted += 1;
+                }
+                (_, _, Some(_)) => {
+                    return Err(Error::Drive(DriveError::CorruptedDocumentNotItem(
+                        "expired document is not an item",
+                    )));
+                }
+                _ => {
+                    // The document is already gone with its contract, only the entry is left
+                    self.batch_delete(
+                        (&misc_path()).into(),
+                        document_expiry_storage_key(
+                            entry.expires_at,
+                            entry.contract_id.as_bytes(),
+                            entry.document_id.as_bytes(),
+                        )
+                        .as_slice(),
+                        StatefulBatchDelete {
+                            is_known_to_be_subtree_with_sum: Some((false, false)),
+                        },
+                        transaction,
+                        &mut batch_operations,
+                        drive_version,
+                    )?;
+                }
+            }
+
+            self.apply_batch_low_level_drive_operations(
+                None,
+                transaction,
+                batch_operations,
+                &mut drive_operations,
+                drive_version,
+            )?;
+        }
+
+        let fee_result = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+        )?;
+
+        Ok(ExpiredDocumentsBatchOutcome {
+            fee_result,
+            documents_deleted,
+        })
+    }
+}
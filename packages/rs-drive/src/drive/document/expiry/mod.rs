@@ -0,0 +1,188 @@
+// MIT LICENSE
+//
+// Copyright (c) 2023 Dash Core Group
+//
+// Permission is hereby granted, free of charge, to any
+// person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the
+// Software without restriction, including without
+// limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software
+// is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice
+// shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF
+// ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A
+// PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR
+// IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+//
+
+//! Expiry of documents.
+//!
+//! Documents of a document type with `documentsExpireAfter` expire that many milliseconds after
+//! their `$createdAt`. When such a document is inserted an entry is added to the misc tree under
+//! a key ordered by the expiry time, so expired documents can be found with a range query.
+//! The entry is paid by the identity creating the document, like the document itself.
+//!
+//! Expired documents are deleted in bounded batches at the end of blocks, and the storage of
+//! the deleted documents is refunded to their owners.
+//!
+
+mod add_document_expiry_operations;
+pub use add_document_expiry_operations::*;
+mod delete_expired_documents;
+pub use delete_expired_documents::*;
+mod remove_document_expiry_operations;
+pub use remove_document_expiry_operations::*;
+
+use crate::drive::system::misc_tree_constants::DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::prelude::TimestampMillis;
+use grovedb::Element;
+
+/// The size of the key of a document expiry entry: prefix, expiry time, contract id and document id
+pub(crate) const DOCUMENT_EXPIRY_STORAGE_KEY_SIZE: usize = 1 + 8 + 32 + 32;
+
+/// A document waiting for its expiry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentExpiryEntry {
+    /// When the document expires
+    pub expires_at: TimestampMillis,
+    /// The contract of the document
+    pub contract_id: Identifier,
+    /// The document type of the document
+    pub document_type_name: String,
+    /// The document
+    pub document_id: Identifier,
+}
+
+impl DocumentExpiryEntry {
+    /// Parses an entry stored in the misc tree, the document type name is the value of the item
+    pub(crate) fn from_key_and_element(key: &[u8], element: &Element) -> Result<Self, Error> {
+        if key.len() != DOCUMENT_EXPIRY_STORAGE_KEY_SIZE
+            || key[0] != DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX[0]
+        {
+            return Err(Error::Drive(DriveError::CorruptedSerialization(
+                "document expiry key has an unexpected format".to_string(),
+            )));
+        }
+
+        let mut expires_at = [0u8; 8];
+        expires_at.copy_from_slice(&key[1..9]);
+        let mut contract_id = [0u8; 32];
+        contract_id.copy_from_slice(&key[9..41]);
+        let mut document_id = [0u8; 32];
+        document_id.copy_from_slice(&key[41..73]);
+
+        let document_type_name =
+            String::from_utf8(element.as_item_bytes()?.to_vec()).map_err(|_| {
+                Error::Drive(DriveError::CorruptedSerialization(
+                    "document expiry document type name is not valid utf8".to_string(),
+                ))
+            })?;
+
+        Ok(DocumentExpiryEntry {
+            expires_at: TimestampMillis::from_be_bytes(expires_at),
+            contract_id: Identifier::new(contract_id),
+            document_type_name,
+            document_id: Identifier::new(document_id),
+        })
+    }
+}
+
+/// The outcome of deleting a batch of expired documents
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpiredDocumentsBatchOutcome {
+    /// The fees of the deletions, the storage refunds are attributed to the owners of the documents
+    pub fee_result: FeeResult,
+    /// The number of expired documents that were deleted
+    pub documents_deleted: u16,
+}
+
+/// The key of the expiry entry of a document in the misc tree.
+///
+/// The expiry time is big endian encoded so entries are ordered by it.
+pub(crate) fn document_expiry_storage_key(
+    expires_at: TimestampMillis,
+    contract_id: &[u8; 32],
+    document_id: &[u8; 32],
+) -> Vec<u8> {
+    let mut key = Vec::with_capacity(DOCUMENT_EXPIRY_STORAGE_KEY_SIZE);
+    key.extend_from_slice(DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX);
+    key.extend_from_slice(&expires_at.to_be_bytes());
+    key.extend_from_slice(contract_id);
+    key.extend_from_slice(document_id);
+    key
+}
+
+/// Returns when the document expires, if its document type has documents that expire
+pub fn document_expires_at(
+    document_type: DocumentTypeRef,
+    document: &Document,
+) -> Option<TimestampMillis> {
+    let documents_expire_after = document_type.documents_expire_after()?;
+    document
+        .created_at()
+        .map(|created_at| created_at.saturating_add(documents_expire_after))
+}
+
+/// Returns true if the document is expired at the given block time.
+///
+/// Expired documents are only deleted at the end of a block, so they should be filtered out of
+/// query results until then.
+pub fn document_is_expired(
+    document_type: DocumentTypeRef,
+    document: &Document,
+    block_time_ms: TimestampMillis,
+) -> bool {
+    document_expires_at(document_type, document)
+        .is_some_and(|expires_at| expires_at <= block_time_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_order_expiry_keys_by_expiry_time() {
+        let earlier = document_expiry_storage_key(255, &[9; 32], &[9; 32]);
+        let later = document_expiry_storage_key(256, &[1; 32], &[1; 32]);
+
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn should_parse_expiry_entry_from_key_and_element() {
+        let key = document_expiry_storage_key(1_000, &[1; 32], &[2; 32]);
+        let element = Element::new_item(b"contactRequest".to_vec());
+
+        let entry =
+            DocumentExpiryEntry::from_key_and_element(&key, &element).expect("expected an entry");
+
+        assert_eq!(
+            entry,
+            DocumentExpiryEntry {
+                expires_at: 1_000,
+                contract_id: Identifier::new([1; 32]),
+                document_type_name: "contactRequest".to_string(),
+                document_id: Identifier::new([2; 32]),
+            }
+        );
+    }
+}
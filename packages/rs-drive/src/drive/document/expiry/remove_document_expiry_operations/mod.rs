@@ -0,0 +1,61 @@
+mod v0;
+
+use crate::drive::object_size_info::DocumentAndContractInfo;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Removes the expiry entry of a document whose document type has documents that expire.
+    ///
+    /// Does nothing for document types whose documents don't expire.
+    ///
+    /// # Parameters
+    /// * `document_and_contract_info`: The document being deleted and its contract.
+    /// * `estimated_costs_only_with_layer_info`: If given, only the costs are estimated.
+    /// * `transaction`: The transaction argument.
+    /// * `batch_operations`: The operations the removal is added to.
+    /// * `platform_version`: The platform version.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the operations were added.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub(crate) fn remove_document_expiry_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiry
+            .remove_document_expiry_operations
+        {
+            0 => self.remove_document_expiry_operations_v0(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_document_expiry_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
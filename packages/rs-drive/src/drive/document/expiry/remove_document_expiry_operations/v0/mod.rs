@@ -0,0 +1,103 @@
+use crate::drive::document::expiry::{
+    document_expires_at, document_expiry_storage_key, DOCUMENT_EXPIRY_STORAGE_KEY_SIZE,
+};
+use crate::drive::grove_operations::BatchDeleteApplyType::{
+    StatefulBatchDelete, StatelessBatchDelete,
+};
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::DocumentV0Getters;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::PotentiallyAtMaxElements;
+use grovedb::EstimatedLayerSizes::AllItems;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Removes the expiry entry of a document whose document type has documents that expire.
+    pub(super) fn remove_document_expiry_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let document_type = document_and_contract_info.document_type;
+
+        if document_type.documents_expire_after().is_none() {
+            return Ok(());
+        }
+
+        let document_type_name_size = document_type.name().len() as u32;
+
+        let (key, apply_type) = if let Some(estimated_costs_only_with_layer_info) =
+            estimated_costs_only_with_layer_info
+        {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(misc_path_vec()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllItems(
+                        DOCUMENT_EXPIRY_STORAGE_KEY_SIZE as u8,
+                        document_type_name_size,
+                        None,
+                    ),
+                },
+            );
+
+            // the key doesn't matter when the costs are estimated
+            (
+                vec![0; DOCUMENT_EXPIRY_STORAGE_KEY_SIZE],
+                StatelessBatchDelete {
+                    is_sum_tree: false,
+                    estimated_value_size: document_type_name_size,
+                },
+            )
+        } else {
+            let document = document_and_contract_info
+                .owned_document_info
+                .document_info
+                .get_borrowed_document()
+                .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                    "the document must be known to remove its expiry entry",
+                )))?;
+
+            let expires_at = document_expires_at(document_type, document).ok_or(Error::Drive(
+                DriveError::CorruptedCodeExecution(
+                    "documents that expire must have a creation time",
+                ),
+            ))?;
+
+            (
+                document_expiry_storage_key(
+                    expires_at,
+                    document_and_contract_info.contract.id_ref().as_bytes(),
+                    document.id_ref().as_bytes(),
+                ),
+                StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                },
+            )
+        };
+
+        self.batch_delete(
+            (&misc_path()).into(),
+            key.as_slice(),
+            apply_type,
+            transaction,
+            batch_operations,
+            &platform_version.drive,
+        )
+    }
+}
@@ -0,0 +1,72 @@
+mod v0;
+
+use crate::drive::document::index_backfill::{IndexBackfill, IndexBackfillBatchOutcome};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::DataContract;
+use dpp::version::PlatformVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Backfills the pending indices of a document type for the next batch of its documents.
+    ///
+    /// Documents are backfilled in order of their id, starting after the last document of the
+    /// previous batch. The storage of the new index entries is attributed to the contract owner.
+    /// The backfill is updated with the progress and the conflicts of the batch, and is removed
+    /// once complete unless it left documents out of a unique index. A batch that would leave
+    /// more than [`MAX_INDEX_BACKFILL_CONFLICTS`] documents out of unique indices doesn't add
+    /// any entry and fails the backfill instead.
+    ///
+    /// Batches of a failed backfill remove the entries added by the previous batches, in the
+    /// same order. Once they are all removed, the indices are recorded as failed.
+    ///
+    /// # Parameters
+    /// * `contract`: The contract of the document type, as it is after the update.
+    /// * `index_backfill`: The pending backfill.
+    /// * `limit`: The maximum number of documents to backfill.
+    /// * `block_info`: The block info.
+    /// * `apply`: Whether to apply the operations, if false only the fees are estimated.
+    /// * `transaction`: The transaction argument.
+    /// * `platform_version`: The platform version.
+    ///
+    /// # Returns
+    /// * `Ok(IndexBackfillBatchOutcome)` with the fees and the progress of the batch.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn backfill_document_type_indices(
+        &self,
+        contract: &DataContract,
+        index_backfill: &IndexBackfill,
+        limit: u16,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<IndexBackfillBatchOutcome, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_backfill
+            .backfill_document_type_indices
+        {
+            0 => self.backfill_document_type_indices_v0(
+                contract,
+                index_backfill,
+                limit,
+                block_info,
+                apply,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "backfill_document_type_indices".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,750 @@
+use crate::drive::defaults::DEFAULT_HASH_SIZE_U8;
+use crate::drive::document::index_backfill::{
+    document_type_for_index_backfill, index_backfill_storage_key, IndexBackfill,
+    IndexBackfillBatchOutcome, IndexBackfillConflict, ESTIMATED_INDEX_BACKFILL_SIZE,
+    MAX_INDEX_BACKFILL_CONFLICTS,
+};
+use crate::drive::document::{contract_document_type_path_vec, unique_event_id};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::{
+    BatchDeleteApplyType, BatchInsertTreeApplyType, DirectQueryType,
+};
+use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+use crate::drive::object_size_info::{
+    DocumentAndContractInfo, DocumentInfoV0Methods, OwnedDocumentInfo, PathInfo, PathKeyElementInfo,
+};
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::fee::FeeError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::query::DriveQuery;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::{DocumentTypeRef, Index, IndexLevel};
+use dpp::data_contract::DataContract;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+impl Drive {
+    /// Backfills the pending indices of a document type for the next batch of its documents.
+    pub(super) fn backfill_document_type_indices_v0(
+        &self,
+        contract: &DataContract,
+        index_backfill: &IndexBackfill,
+        limit: u16,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<IndexBackfillBatchOutcome, Error> {
+        let drive_version = &platform_version.drive;
+        let document_type = document_type_for_index_backfill(contract, index_backfill)?;
+
+        let pending_indices = document_type
+            .indices()
+            .iter()
+            .filter(|index| index_backfill.is_index_pending(&index.name))
+            .cloned()
+            .collect::<Vec<Index>>();
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        // Documents are frozen while their indices are backfilled,
+        // so the last backfilled document still exists
+        let query = DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: Default::default(),
+            offset: None,
+            limit: Some(limit),
+            order_by: Default::default(),
+            start_at: index_backfill
+                .last_document_id
+                .map(|document_id| document_id.to_buffer()),
+            start_at_included: false,
+            block_time_ms: None,
+        };
+
+        let (items, _) = query.execute_raw_results_no_proof_internal(
+            self,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+
+        let documents = items
+            .iter()
+            .map(|serialized| {
+                Document::from_bytes(serialized.as_slice(), document_type, platform_version)
+            })
+            .collect::<Result<Vec<Document>, ProtocolError>>()?;
+
+        // The contract owner pays for the new index entries, so they get the refunds
+        let storage_flags = if document_type.documents_mutable()
+            || contract.config().can_be_deleted()
+            || document_type.documents_expire_after().is_some()
+        {
+            Some(StorageFlags::new_single_epoch(
+                block_info.epoch.index,
+                Some(contract.owner_id().to_buffer()),
+            ))
+        } else {
+            None
+        };
+
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        if let Some(estimated_costs_only_with_layer_info) =
+            estimated_costs_only_with_layer_info.as_mut()
+        {
+            Self::add_estimation_costs_for_levels_up_to_contract_document_type_excluded(
+                contract,
+                estimated_costs_only_with_layer_info,
+                drive_version,
+            )?;
+
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(misc_path_vec()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(16),
+                    estimated_layer_sizes: AllItems(
+                        (1 + 32 + document_type.name().len()).min(u8::MAX as usize) as u8,
+                        ESTIMATED_INDEX_BACKFILL_SIZE,
+                        None,
+                    ),
+                },
+            );
+        }
+
+        let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        if index_backfill.is_rolling_back() {
+            return self.roll_back_document_type_indices_batch_v0(
+                contract,
+                document_type,
+                index_backfill,
+                &pending_indices,
+                &documents,
+                limit,
+                &storage_flags,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                batch_operations,
+                drive_operations,
+                platform_version,
+            );
+        }
+
+        let mut unique_index_paths_in_batch: HashSet<Vec<Vec<u8>>> = HashSet::new();
+        let mut conflicts = vec![];
+        let mut documents_indices_to_add = Vec::with_capacity(documents.len());
+
+        for document in &documents {
+            let owner_id = document.owner_id().to_buffer();
+
+            let mut indices_to_add = Vec::with_capacity(pending_indices.len());
+            for index in &pending_indices {
+                if index.unique {
+                    if let Some(index_path) = Self::unique_index_path_for_document_v0(
+                        contract,
+                        document_type,
+                        index,
+                        document,
+                        owner_id,
+                        platform_version,
+                    )? {
+                        let conflicts_in_batch =
+                            !unique_index_paths_in_batch.insert(index_path.clone());
+                        if conflicts_in_batch
+                            || self
+                                .grove_get_raw_optional(
+                                    index_path.as_slice().into(),
+                                    &[0],
+                                    DirectQueryType::StatefulDirectQuery,
+                                    transaction,
+                                    &mut drive_operations,
+                                    drive_version,
+                                )?
+                                .is_some()
+                        {
+                            conflicts.push(IndexBackfillConflict {
+                                index_name: index.name.clone(),
+                                document_id: document.id(),
+                            });
+                            continue;
+                        }
+                    }
+                }
+                indices_to_add.push(index.clone());
+            }
+
+            if !indices_to_add.is_empty() {
+                documents_indices_to_add.push((document, indices_to_add));
+            }
+        }
+
+        let documents_processed = documents.len() as u16;
+
+        // Conflicting documents can't be changed anymore, so their amount is bounded
+        let failed =
+            index_backfill.conflicts.len() + conflicts.len() > MAX_INDEX_BACKFILL_CONFLICTS;
+        let completed = !failed && documents_processed < limit;
+
+        let mut updated_index_backfill = index_backfill.clone();
+
+        if failed {
+            updated_index_backfill.fail();
+        } else {
+            for (document, indices_to_add) in documents_indices_to_add {
+                let index_level = IndexLevel::try_from_indices(
+                    indices_to_add.as_slice(),
+                    document_type.name(),
+                    platform_version,
+                )?;
+
+                let document_and_contract_info = DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            document,
+                            storage_flags.as_ref().map(Cow::Borrowed),
+                        )),
+                        owner_id: Some(document.owner_id().to_buffer()),
+                    },
+                    contract,
+                    document_type,
+                };
+
+                let mut document_operations = vec![];
+                self.add_backfilled_indices_for_document_operations_v0(
+                    &document_and_contract_info,
+                    &index_level,
+                    &mut Some(&mut batch_operations),
+                    &mut estimated_costs_only_with_layer_info,
+                    transaction,
+                    &mut document_operations,
+                    platform_version,
+                )?;
+                batch_operations.extend(document_operations);
+            }
+
+            updated_index_backfill
+                .conflicts
+                .extend(conflicts.iter().cloned());
+
+            if completed {
+                updated_index_backfill.index_names.clear();
+                updated_index_backfill.last_document_id = None;
+            } else if let Some(last_document) = documents.last() {
+                updated_index_backfill.last_document_id = Some(last_document.id());
+            }
+        }
+
+        self.add_update_index_backfill_operations_v0(
+            contract,
+            document_type,
+            &updated_index_backfill,
+            apply,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let fee_result = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+        )?;
+
+        Ok(IndexBackfillBatchOutcome {
+            fee_result,
+            documents_processed,
+            conflicts,
+            completed,
+            failed,
+            rolled_back: failed && !updated_index_backfill.is_pending(),
+        })
+    }
+
+    /// Removes the entries a failed backfill added for the next batch of documents, up to the
+    /// last document it backfilled.
+    #[allow(clippy::too_many_arguments)]
+    fn roll_back_document_type_indices_batch_v0(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        index_backfill: &IndexBackfill,
+        pending_indices: &[Index],
+        documents: &[Document],
+        limit: u16,
+        storage_flags: &Option<StorageFlags>,
+        block_info: &BlockInfo,
+        mut estimated_costs_only_with_layer_info: Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        mut batch_operations: Vec<LowLevelDriveOperation>,
+        mut drive_operations: Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<IndexBackfillBatchOutcome, Error> {
+        let drive_version = &platform_version.drive;
+        let apply = estimated_costs_only_with_layer_info.is_none();
+
+        let Some(rollback_until_document_id) = index_backfill.rollback_until_document_id else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "failed index backfill must know the last document to roll back",
+            )));
+        };
+
+        // Documents are frozen until the rollback completes, so the ones after the last
+        // backfilled document never had entries in the failed indices
+        let documents_to_roll_back = documents
+            .iter()
+            .take_while(|document| document.id() <= rollback_until_document_id)
+            .collect::<Vec<_>>();
+
+        for document in &documents_to_roll_back {
+            // Conflicting documents were left out of the index
+            let indices_to_remove = pending_indices
+                .iter()
+                .filter(|index| {
+                    !index_backfill.conflicts.iter().any(|conflict| {
+                        conflict.document_id == document.id() && conflict.index_name == index.name
+                    })
+                })
+                .cloned()
+                .collect::<Vec<Index>>();
+
+            if indices_to_remove.is_empty() {
+                continue;
+            }
+
+            let index_level = IndexLevel::try_from_indices(
+                indices_to_remove.as_slice(),
+                document_type.name(),
+                platform_version,
+            )?;
+
+            let document_and_contract_info = DocumentAndContractInfo {
+                owned_document_info: OwnedDocumentInfo {
+                    document_info: DocumentRefInfo((
+                        document,
+                        storage_flags.as_ref().map(Cow::Borrowed),
+                    )),
+                    owner_id: Some(document.owner_id().to_buffer()),
+                },
+                contract,
+                document_type,
+            };
+
+            let mut document_operations = vec![];
+            self.remove_backfilled_indices_for_document_operations_v0(
+                &document_and_contract_info,
+                &index_level,
+                &Some(&mut batch_operations),
+                &mut estimated_costs_only_with_layer_info,
+                transaction,
+                &mut document_operations,
+                platform_version,
+            )?;
+            batch_operations.extend(document_operations);
+        }
+
+        let documents_processed = documents_to_roll_back.len() as u16;
+
+        let rolled_back = (documents.len() as u16) < limit
+            || documents_to_roll_back.len() < documents.len()
+            || documents_to_roll_back
+                .last()
+                .is_some_and(|document| document.id() == rollback_until_document_id);
+
+        let mut updated_index_backfill = index_backfill.clone();
+
+        if rolled_back {
+            updated_index_backfill.complete_rollback();
+        } else if let Some(last_document) = documents_to_roll_back.last() {
+            updated_index_backfill.last_document_id = Some(last_document.id());
+        }
+
+        self.add_update_index_backfill_operations_v0(
+            contract,
+            document_type,
+            &updated_index_backfill,
+            apply,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let fee_result = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+        )?;
+
+        Ok(IndexBackfillBatchOutcome {
+            fee_result,
+            documents_processed,
+            conflicts: vec![],
+            completed: false,
+            failed: false,
+            rolled_back,
+        })
+    }
+
+    /// Stores the updated backfill, or deletes it if it doesn't need to be kept anymore.
+    #[allow(clippy::too_many_arguments)]
+    fn add_update_index_backfill_operations_v0(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        updated_index_backfill: &IndexBackfill,
+        apply: bool,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let misc_path = misc_path();
+        let key = index_backfill_storage_key(contract.id_ref().as_bytes(), document_type.name());
+
+        if updated_index_backfill.should_be_kept() {
+            self.batch_insert(
+                PathKeyElementInfo::PathFixedSizeKeyRefElement((
+                    misc_path,
+                    key.as_slice(),
+                    updated_index_backfill.serialize_to_element()?,
+                )),
+                batch_operations,
+                drive_version,
+            )
+        } else {
+            let apply_type = if apply {
+                BatchDeleteApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                }
+            } else {
+                BatchDeleteApplyType::StatelessBatchDelete {
+                    is_sum_tree: false,
+                    estimated_value_size: ESTIMATED_INDEX_BACKFILL_SIZE,
+                }
+            };
+            self.batch_delete(
+                (&misc_path).into(),
+                key.as_slice(),
+                apply_type,
+                transaction,
+                batch_operations,
+                drive_version,
+            )
+        }
+    }
+
+    /// The path under which the reference of a document is stored at key 0 for a unique index.
+    /// Returns `None` if one of the indexed values is null, as uniqueness isn't enforced then.
+    fn unique_index_path_for_document_v0(
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        index: &Index,
+        document: &Document,
+        owner_id: [u8; 32],
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Vec<Vec<u8>>>, Error> {
+        let mut index_path =
+            contract_document_type_path_vec(contract.id_ref().as_bytes(), document_type.name());
+
+        for property in &index.properties {
+            let value = document
+                .get_raw_for_document_type(
+                    &property.name,
+                    document_type,
+                    Some(owner_id),
+                    platform_version,
+                )?
+                .unwrap_or_default();
+
+            if value.is_empty() {
+                return Ok(None);
+            }
+
+            index_path.push(property.name.as_bytes().to_vec());
+            index_path.push(value);
+        }
+
+        Ok(Some(index_path))
+    }
+
+    /// Adds the entries of a document for the given index levels, which only hold the indices
+    /// being backfilled. This mirrors adding the indices of the top index level of a new document.
+    fn add_backfilled_indices_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        index_level: &IndexLevel,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let document_type = document_and_contract_info.document_type;
+        let document_info = &document_and_contract_info.owned_document_info.document_info;
+        let owner_id = document_and_contract_info.owned_document_info.owner_id;
+        let storage_flags = document_info.get_storage_flags_ref();
+        let event_id = unique_event_id();
+
+        let contract_document_type_path = contract_document_type_path_vec(
+            document_and_contract_info.contract.id_ref().as_bytes(),
+            document_type.name(),
+        );
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(contract_document_type_path.clone()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(
+                        document_type.index_structure().sub_levels().len() as u32 + 1,
+                    ),
+                    estimated_layer_sizes: AllSubtrees(
+                        DEFAULT_HASH_SIZE_U8,
+                        NoSumTrees,
+                        storage_flags.map(|s| s.serialized_size()),
+                    ),
+                },
+            );
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_using_sums: false,
+                is_sum_tree: false,
+                flags_len: storage_flags
+                    .map(|s| s.serialized_size())
+                    .unwrap_or_default(),
+            }
+        };
+
+        for (name, sub_level) in index_level.sub_levels() {
+            // the top index component was added by the contract update
+            let mut index_path: Vec<Vec<u8>> = contract_document_type_path.clone();
+            index_path.push(Vec::from(name.as_bytes()));
+
+            let document_top_field = document_info
+                .get_raw_for_document_type(
+                    name,
+                    document_type,
+                    owner_id,
+                    Some((sub_level, event_id)),
+                    platform_version,
+                )?
+                .unwrap_or_default();
+
+            let path_key_info = document_top_field.clone().add_path::<0>(index_path.clone());
+            // the value tree can be shared with indices the document is already in
+            self.batch_insert_empty_tree_if_not_exists(
+                path_key_info,
+                storage_flags,
+                apply_type,
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                drive_version,
+            )?;
+
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                let document_top_field_estimated_size =
+                    document_info.get_estimated_size_for_document_type(name, document_type)?;
+
+                if document_top_field_estimated_size > u8::MAX as u16 {
+                    return Err(Error::Fee(FeeError::Overflow(
+                        "document field is too big for being an index on backfill",
+                    )));
+                }
+
+                estimated_costs_only_with_layer_info.insert(
+                    KeyInfoPath::from_known_owned_path(index_path.clone()),
+                    EstimatedLayerInformation {
+                        is_sum_tree: false,
+                        estimated_layer_count: PotentiallyAtMaxElements,
+                        estimated_layer_sizes: AllSubtrees(
+                            document_top_field_estimated_size as u8,
+                            NoSumTrees,
+                            storage_flags.map(|s| s.serialized_size()),
+                        ),
+                    },
+                );
+            }
+
+            let any_fields_null = document_top_field.is_empty();
+
+            let mut index_path_info = PathInfo::PathIterator::<0>(index_path);
+            index_path_info.push(document_top_field)?;
+
+            self.add_indices_for_index_level_for_contract_operations(
+                document_and_contract_info,
+                index_path_info,
+                sub_level,
+                any_fields_null,
+                previous_batch_operations,
+                &storage_flags,
+                estimated_costs_only_with_layer_info,
+                event_id,
+                transaction,
+                batch_operations,
+                platform_version,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the entries of a document for the given index levels, which only hold the indices
+    /// of a failed backfill. This mirrors removing the indices of the top index level of a
+    /// deleted document, but keeps the top index trees as they can be shared with other indices.
+    fn remove_backfilled_indices_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        index_level: &IndexLevel,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let document_type = document_and_contract_info.document_type;
+        let document_info = &document_and_contract_info.owned_document_info.document_info;
+        let owner_id = document_and_contract_info.owned_document_info.owner_id;
+        let storage_flags = document_info.get_storage_flags_ref();
+        let event_id = unique_event_id();
+
+        let contract_document_type_path = contract_document_type_path_vec(
+            document_and_contract_info.contract.id_ref().as_bytes(),
+            document_type.name(),
+        );
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(contract_document_type_path.clone()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(
+                        document_type.index_structure().sub_levels().len() as u32 + 1,
+                    ),
+                    estimated_layer_sizes: AllSubtrees(
+                        DEFAULT_HASH_SIZE_U8,
+                        NoSumTrees,
+                        storage_flags.map(|s| s.serialized_size()),
+                    ),
+                },
+            );
+        }
+
+        for (name, sub_level) in index_level.sub_levels() {
+            let mut index_path: Vec<Vec<u8>> = contract_document_type_path.clone();
+            index_path.push(Vec::from(name.as_bytes()));
+
+            let document_top_field = document_info
+                .get_raw_for_document_type(
+                    name,
+                    document_type,
+                    owner_id,
+                    Some((sub_level, event_id)),
+                    platform_version,
+                )?
+                .unwrap_or_default();
+
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                let document_top_field_estimated_size =
+                    document_info.get_estimated_size_for_document_type(name, document_type)?;
+
+                if document_top_field_estimated_size > u8::MAX as u16 {
+                    return Err(Error::Fee(FeeError::Overflow(
+                        "document field is too big for being an index on backfill rollback",
+                    )));
+                }
+
+                estimated_costs_only_with_layer_info.insert(
+                    KeyInfoPath::from_known_owned_path(index_path.clone()),
+                    EstimatedLayerInformation {
+                        is_sum_tree: false,
+                        estimated_layer_count: PotentiallyAtMaxElements,
+                        estimated_layer_sizes: AllSubtrees(
+                            document_top_field_estimated_size as u8,
+                            NoSumTrees,
+                            storage_flags.map(|s| s.serialized_size()),
+                        ),
+                    },
+                );
+            }
+
+            let any_fields_null = document_top_field.is_empty();
+
+            let mut index_path_info = PathInfo::PathIterator::<0>(index_path);
+            index_path_info.push(document_top_field)?;
+
+            self.remove_indices_for_index_level_for_contract_operations(
+                document_and_contract_info,
+                index_path_info,
+                sub_level,
+                any_fields_null,
+                &storage_flags,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                event_id,
+                transaction,
+                batch_operations,
+                platform_version,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the operation deleting the index backfill of a document type, if there is one
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The id of the contract of the document type.
+    /// * `document_type_name` - The name of the document type.
+    /// * `estimated_costs_only_with_layer_info` - Layer information used to estimate costs, `None` to apply.
+    /// * `transaction` - A `TransactionArg` object representing the transaction.
+    /// * `batch_operations` - The operations the delete operation is added to.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns `Ok(())` if the operation is added or there is no backfill to delete.
+    pub fn delete_index_backfill_operations(
+        &self,
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_backfill
+            .delete_index_backfill_operations
+        {
+            0 => self.delete_index_backfill_operations_v0(
+                contract_id,
+                document_type_name,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_index_backfill_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
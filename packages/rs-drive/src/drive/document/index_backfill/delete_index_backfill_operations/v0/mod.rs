@@ -0,0 +1,84 @@
+use crate::drive::document::index_backfill::{
+    index_backfill_storage_key, ESTIMATED_INDEX_BACKFILL_SIZE,
+};
+use crate::drive::grove_operations::{BatchDeleteApplyType, DirectQueryType};
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::ApproximateElements;
+use grovedb::EstimatedLayerSizes::AllItems;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the operation deleting the index backfill of a document type, if there is one
+    pub(super) fn delete_index_backfill_operations_v0(
+        &self,
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let misc_path = misc_path();
+        let key = index_backfill_storage_key(contract_id, document_type_name);
+
+        let apply_type = if let Some(estimated_costs_only_with_layer_info) =
+            estimated_costs_only_with_layer_info.as_mut()
+        {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(misc_path_vec()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(16),
+                    estimated_layer_sizes: AllItems(
+                        key.len().min(u8::MAX as usize) as u8,
+                        ESTIMATED_INDEX_BACKFILL_SIZE,
+                        None,
+                    ),
+                },
+            );
+
+            BatchDeleteApplyType::StatelessBatchDelete {
+                is_sum_tree: false,
+                estimated_value_size: ESTIMATED_INDEX_BACKFILL_SIZE,
+            }
+        } else {
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            }
+        };
+
+        // most document types never had indices backfilled
+        let index_backfill = self.grove_get_raw_optional(
+            (&misc_path).into(),
+            key.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            batch_operations,
+            drive_version,
+        )?;
+
+        if index_backfill.is_none() {
+            return Ok(());
+        }
+
+        self.batch_delete(
+            (&misc_path).into(),
+            key.as_slice(),
+            apply_type,
+            transaction,
+            batch_operations,
+            drive_version,
+        )
+    }
+}
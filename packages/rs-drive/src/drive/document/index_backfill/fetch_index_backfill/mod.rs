@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::document::index_backfill::IndexBackfill;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the index backfill of a document type
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The id of the contract of the document type.
+    /// * `document_type_name` - The name of the document type.
+    /// * `transaction` - A `TransactionArg` object representing the transaction.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<IndexBackfill>, Error>` - The backfill if one is pending for the document type,
+    ///   or if a completed one left documents out of a unique index.
+    pub fn fetch_index_backfill(
+        &self,
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IndexBackfill>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_backfill
+            .fetch_index_backfill
+        {
+            0 => self.fetch_index_backfill_v0(
+                contract_id,
+                document_type_name,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_index_backfill".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::drive::document::index_backfill::{index_backfill_storage_key, IndexBackfill};
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::system::misc_path;
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the index backfill of a document type
+    pub(super) fn fetch_index_backfill_v0(
+        &self,
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IndexBackfill>, Error> {
+        let misc_path = misc_path();
+        let key = index_backfill_storage_key(contract_id, document_type_name);
+
+        self.grove_get_raw_optional(
+            (&misc_path).into(),
+            key.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?
+        .map(|element| IndexBackfill::deserialize_from_element(&element))
+        .transpose()
+    }
+}
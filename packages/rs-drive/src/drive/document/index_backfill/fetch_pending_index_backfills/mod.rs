@@ -0,0 +1,44 @@
+mod v0;
+
+use crate::drive::document::index_backfill::IndexBackfill;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the index backfills that are still pending
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - A `TransactionArg` object representing the transaction.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<IndexBackfill>, Error>` - The pending backfills, ordered by contract id and
+    ///   document type name.
+    pub fn fetch_pending_index_backfills(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<IndexBackfill>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_backfill
+            .fetch_pending_index_backfills
+        {
+            0 => self.fetch_pending_index_backfills_v0(transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_pending_index_backfills".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::drive::document::index_backfill::IndexBackfill;
+use crate::drive::system::misc_path_vec;
+use crate::drive::system::misc_tree_constants::INDEX_BACKFILL_STORAGE_KEY_PREFIX;
+use crate::drive::Drive;
+use crate::error::Error;
+
+use crate::query::QueryItem;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{PathQuery, Query, TransactionArg};
+
+impl Drive {
+    /// Fetches the index backfills that are still pending
+    pub(super) fn fetch_pending_index_backfills_v0(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<IndexBackfill>, Error> {
+        let start = INDEX_BACKFILL_STORAGE_KEY_PREFIX.to_vec();
+        let end = vec![INDEX_BACKFILL_STORAGE_KEY_PREFIX[0] + 1];
+
+        let path_query = PathQuery::new_unsized(
+            misc_path_vec(),
+            Query::new_single_query_item(QueryItem::Range(start..end)),
+        );
+
+        let (results, _) = self.grove_get_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        // Completed and failed backfills are only kept for the documents they left out
+        // of unique indices and for their failed indices
+        results
+            .to_elements()
+            .iter()
+            .map(IndexBackfill::deserialize_from_element)
+            .filter(|index_backfill| {
+                index_backfill
+                    .as_ref()
+                    .map_or(true, |index_backfill| index_backfill.is_pending())
+            })
+            .collect()
+    }
+}
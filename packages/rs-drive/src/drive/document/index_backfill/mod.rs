@@ -0,0 +1,685 @@
+// MIT LICENSE
+//
+// Copyright (c) 2023 Dash Core Group
+//
+// Permission is hereby granted, free of charge, to any
+// person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the
+// Software without restriction, including without
+// limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software
+// is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice
+// shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF
+// ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A
+// PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR
+// IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+//
+
+//! Backfill of document type indices added by a contract update.
+//!
+//! When a contract update adds indices to a document type that already holds documents, the
+//! update registers an index backfill in the misc tree. The backfill is then processed in bounded
+//! batches at the end of later blocks. While it is pending, the document type is frozen and the
+//! new indices can't be queried.
+//!
+//! Documents that collide with an already indexed document on a new unique index are left out
+//! of that index and are recorded as conflicts on the backfill, which is kept once complete.
+//! Conflicting documents can't be changed anymore, so a backfill fails once it would record more
+//! than [`MAX_INDEX_BACKFILL_CONFLICTS`] of them. The entries a failed backfill already added
+//! are then removed over the next blocks, and the indices are recorded as failed. Failed indices
+//! can't be queried and keep documents of the type frozen, until the contract owner removes or
+//! changes them with a contract update. Changed indices are backfilled again.
+//!
+
+mod backfill_document_type_indices;
+pub use backfill_document_type_indices::*;
+mod delete_index_backfill_operations;
+pub use delete_index_backfill_operations::*;
+mod fetch_index_backfill;
+pub use fetch_index_backfill::*;
+mod fetch_pending_index_backfills;
+pub use fetch_pending_index_backfills::*;
+
+use crate::drive::system::misc_tree_constants::INDEX_BACKFILL_STORAGE_KEY_PREFIX;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::data_contract::DataContract;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use grovedb::Element;
+use serde::{Deserialize, Serialize};
+
+/// The estimated size of a serialized index backfill
+pub(crate) const ESTIMATED_INDEX_BACKFILL_SIZE: u32 = 256;
+
+/// The maximum amount of documents a document type can have left out of its unique indices
+pub const MAX_INDEX_BACKFILL_CONFLICTS: usize = 16;
+
+/// A document left out of a unique index because another document already held its values
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexBackfillConflict {
+    /// The name of the unique index
+    pub index_name: String,
+    /// The document that was left out of the index
+    pub document_id: Identifier,
+}
+
+/// The state of the backfill of the indices of a document type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexBackfill {
+    /// The contract of the document type
+    pub contract_id: Identifier,
+    /// The document type whose indices are backfilled
+    pub document_type_name: String,
+    /// The indices that are still being backfilled, empty once the backfill is complete
+    pub index_names: Vec<String>,
+    /// The last document that was backfilled, documents are backfilled in order of their id
+    pub last_document_id: Option<Identifier>,
+    /// Documents that conflicted with a unique index
+    pub conflicts: Vec<IndexBackfillConflict>,
+    /// True if the backfill stopped as it found too many conflicts
+    #[serde(default)]
+    pub failed: bool,
+    /// The last document whose entries a failed backfill has to remove
+    #[serde(default)]
+    pub rollback_until_document_id: Option<Identifier>,
+    /// The indices whose backfill failed, until they are removed or changed by a contract update
+    #[serde(default)]
+    pub failed_index_names: Vec<String>,
+}
+
+impl IndexBackfill {
+    /// Creates a backfill of the given indices, keeping the conflicts of a previous backfill
+    pub fn new(
+        contract_id: Identifier,
+        document_type_name: String,
+        index_names: Vec<String>,
+        previous_conflicts: Vec<IndexBackfillConflict>,
+    ) -> Self {
+        IndexBackfill {
+            contract_id,
+            document_type_name,
+            index_names,
+            last_document_id: None,
+            conflicts: previous_conflicts,
+            failed: false,
+            rollback_until_document_id: None,
+            failed_index_names: vec![],
+        }
+    }
+
+    /// Returns true if the indices are still being backfilled, or their entries are being
+    /// removed after the backfill failed
+    pub fn is_pending(&self) -> bool {
+        !self.index_names.is_empty()
+    }
+
+    /// Returns true if the entries added by the failed backfill are being removed
+    pub fn is_rolling_back(&self) -> bool {
+        self.is_pending() && self.failed
+    }
+
+    /// Returns true if documents of the document type can't be changed, as their indices are
+    /// pending or failed
+    pub fn is_document_type_frozen(&self) -> bool {
+        self.is_pending() || !self.failed_index_names.is_empty()
+    }
+
+    /// Returns true if the index with the given name is still being backfilled
+    pub fn is_index_pending(&self, index_name: &str) -> bool {
+        self.index_names.iter().any(|name| name == index_name)
+    }
+
+    /// Returns true if the backfill of the index with the given name failed
+    pub fn is_index_failed(&self, index_name: &str) -> bool {
+        self.failed_index_names
+            .iter()
+            .any(|name| name == index_name)
+    }
+
+    /// Marks the backfill as failed. Entries added by previous batches are removed by the next
+    /// ones, up to the last backfilled document.
+    pub(crate) fn fail(&mut self) {
+        self.failed = true;
+        self.rollback_until_document_id = self.last_document_id.take();
+
+        if self.rollback_until_document_id.is_none() {
+            self.complete_rollback();
+        }
+    }
+
+    /// Records the pending indices as failed once their entries are removed, and forgets
+    /// the conflicts they had
+    pub(crate) fn complete_rollback(&mut self) {
+        let index_names = std::mem::take(&mut self.index_names);
+
+        self.conflicts
+            .retain(|conflict| !index_names.contains(&conflict.index_name));
+        self.failed_index_names.extend(index_names);
+        self.last_document_id = None;
+        self.rollback_until_document_id = None;
+    }
+
+    /// Returns true if the backfill has to be kept in state
+    pub(crate) fn should_be_kept(&self) -> bool {
+        self.is_document_type_frozen() || !self.conflicts.is_empty()
+    }
+
+    /// Returns the conflict the document had during the backfill, if any
+    pub fn conflict_for_document(
+        &self,
+        document_id: &Identifier,
+    ) -> Option<&IndexBackfillConflict> {
+        self.conflicts
+            .iter()
+            .find(|conflict| &conflict.document_id == document_id)
+    }
+
+    /// Serializes the backfill to be stored as an item
+    pub(crate) fn serialize_to_element(&self) -> Result<Element, Error> {
+        let mut bytes = vec![];
+        ciborium::ser::into_writer(self, &mut bytes).map_err(|e| {
+            Error::Drive(DriveError::CorruptedSerialization(format!(
+                "unable to serialize index backfill: {}",
+                e
+            )))
+        })?;
+        Ok(Element::new_item(bytes))
+    }
+
+    /// Deserializes a backfill stored as an item
+    pub(crate) fn deserialize_from_element(element: &Element) -> Result<Self, Error> {
+        let bytes = element.as_item_bytes()?;
+        ciborium::de::from_reader(bytes).map_err(|e| {
+            Error::Drive(DriveError::CorruptedSerialization(format!(
+                "unable to deserialize index backfill: {}",
+                e
+            )))
+        })
+    }
+}
+
+/// The outcome of backfilling a batch of documents
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexBackfillBatchOutcome {
+    /// The fees of the batch
+    pub fee_result: FeeResult,
+    /// The number of documents that were backfilled
+    pub documents_processed: u16,
+    /// Documents that conflicted with a unique index in this batch
+    pub conflicts: Vec<IndexBackfillConflict>,
+    /// True if all documents of the document type are now backfilled
+    pub completed: bool,
+    /// True if the batch found too many conflicts and the backfill failed
+    pub failed: bool,
+    /// True if all entries added by the failed backfill are now removed
+    pub rolled_back: bool,
+}
+
+/// The key of the backfill of a document type in the misc tree
+pub(crate) fn index_backfill_storage_key(
+    contract_id: &[u8; 32],
+    document_type_name: &str,
+) -> Vec<u8> {
+    let mut key = Vec::with_capacity(1 + 32 + document_type_name.len());
+    key.extend_from_slice(INDEX_BACKFILL_STORAGE_KEY_PREFIX);
+    key.extend_from_slice(contract_id);
+    key.extend_from_slice(document_type_name.as_bytes());
+    key
+}
+
+/// The names of the indices of `document_type` that `original_document_type` didn't have
+pub fn added_index_names(
+    document_type: DocumentTypeRef,
+    original_document_type: DocumentTypeRef,
+) -> Vec<String> {
+    document_type
+        .indices()
+        .iter()
+        .filter(|index| {
+            !original_document_type
+                .indices()
+                .iter()
+                .any(|original_index| original_index.name == index.name)
+        })
+        .map(|index| index.name.clone())
+        .collect()
+}
+
+/// Returns the contract's document type for the backfill
+pub(crate) fn document_type_for_index_backfill<'a>(
+    contract: &'a DataContract,
+    index_backfill: &IndexBackfill,
+) -> Result<DocumentTypeRef<'a>, Error> {
+    if contract.id() != index_backfill.contract_id {
+        return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+            "index backfill is for another contract",
+        )));
+    }
+    Ok(contract.document_type_for_name(index_backfill.document_type_name.as_str())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::contract_document_type_path_vec;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::grove_operations::DirectQueryType;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::drive::Drive;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::config::v0::DataContractConfigSettersV0;
+    use dpp::data_contract::conversion::json::DataContractJsonConversionMethodsV0;
+    use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+    use dpp::document::{Document, DocumentV0};
+    use dpp::platform_value::Value;
+    use dpp::tests::json_document::json_document_to_json_value;
+    use dpp::version::PlatformVersion;
+    use std::collections::BTreeMap;
+
+    /// The family contract with an index on age, and an index on last name if given whether it
+    /// is unique
+    fn family_contract(version: u32, last_name_index_unique: Option<bool>) -> DataContract {
+        let mut value = json_document_to_json_value(
+            "tests/supporting_files/contract/family/family-contract-only-age-index.json",
+        )
+        .expect("expected to read contract");
+
+        value["version"] = serde_json::json!(version);
+
+        let indices = value["documentSchemas"]["person"]["indices"]
+            .as_array_mut()
+            .expect("expected indices");
+        indices[0]["name"] = serde_json::json!("byAge");
+
+        if let Some(unique) = last_name_index_unique {
+            indices.push(serde_json::json!({
+                "name": "byLastName",
+                "unique": unique,
+                "properties": [{ "lastName": "asc" }]
+            }));
+        }
+
+        let mut contract = DataContract::from_json(value, false, PlatformVersion::latest())
+            .expect("expected to create contract");
+        contract.config_mut().set_can_be_deleted(true);
+
+        contract
+    }
+
+    fn apply_family_contract(drive: &Drive, contract: &DataContract) {
+        drive
+            .apply_contract(
+                contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                PlatformVersion::latest(),
+            )
+            .expect("expected to apply contract");
+    }
+
+    /// Stores people with the given last names, then adds the unique last name index
+    fn setup_people_with_last_names(last_names: &[&str]) -> (Drive, DataContract) {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let contract = family_contract(1, None);
+
+        apply_family_contract(&drive, &contract);
+
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get person document type");
+
+        for (i, last_name) in last_names.iter().enumerate() {
+            let document: Document = DocumentV0 {
+                id: Identifier::new([i as u8 + 1; 32]),
+                owner_id: Identifier::new([0; 32]),
+                properties: BTreeMap::from([
+                    ("firstName".to_string(), Value::Text("Sam".to_string())),
+                    ("lastName".to_string(), Value::Text(last_name.to_string())),
+                    ("age".to_string(), Value::U64(30)),
+                ]),
+                revision: Some(1),
+                created_at: None,
+                updated_at: None,
+            }
+            .into();
+
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add document");
+        }
+
+        let updated_contract = family_contract(2, Some(true));
+
+        apply_family_contract(&drive, &updated_contract);
+
+        (drive, updated_contract)
+    }
+
+    fn backfill_person_indices(
+        drive: &Drive,
+        contract: &DataContract,
+    ) -> IndexBackfillBatchOutcome {
+        backfill_person_indices_with_limit(drive, contract, 100)
+    }
+
+    fn backfill_person_indices_with_limit(
+        drive: &Drive,
+        contract: &DataContract,
+        limit: u16,
+    ) -> IndexBackfillBatchOutcome {
+        let platform_version = PlatformVersion::latest();
+
+        let index_backfill = drive
+            .fetch_index_backfill(
+                contract.id_ref().as_bytes(),
+                "person",
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch index backfill")
+            .expect("expected an index backfill");
+
+        drive
+            .backfill_document_type_indices(
+                contract,
+                &index_backfill,
+                limit,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to backfill indices")
+    }
+
+    #[test]
+    fn should_complete_index_backfill_and_keep_conflicts() {
+        let (drive, contract) = setup_people_with_last_names(&["Smith", "Jones", "Smith"]);
+        let platform_version = PlatformVersion::latest();
+
+        let outcome = backfill_person_indices(&drive, &contract);
+
+        assert!(outcome.completed);
+        assert!(!outcome.failed);
+        assert_eq!(outcome.documents_processed, 3);
+        assert_eq!(outcome.conflicts.len(), 1);
+
+        let index_backfill = drive
+            .fetch_index_backfill(
+                contract.id_ref().as_bytes(),
+                "person",
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch index backfill")
+            .expect("expected the backfill to be kept for its conflict");
+
+        assert!(!index_backfill.is_pending());
+        assert_eq!(index_backfill.conflicts, outcome.conflicts);
+    }
+
+    fn fetch_person_index_backfill(
+        drive: &Drive,
+        contract: &DataContract,
+    ) -> Option<IndexBackfill> {
+        drive
+            .fetch_index_backfill(
+                contract.id_ref().as_bytes(),
+                "person",
+                None,
+                PlatformVersion::latest(),
+            )
+            .expect("expected to fetch index backfill")
+    }
+
+    /// Returns true if the last name index references a person with the given last name
+    fn has_last_name_index_entry(drive: &Drive, contract: &DataContract, last_name: &str) -> bool {
+        let platform_version = PlatformVersion::latest();
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get person document type");
+
+        let last_name = document_type
+            .serialize_value_for_key(
+                "lastName",
+                &Value::Text(last_name.to_string()),
+                platform_version,
+            )
+            .expect("expected to serialize last name");
+
+        let mut index_path =
+            contract_document_type_path_vec(contract.id_ref().as_bytes(), "person");
+        index_path.push(b"lastName".to_vec());
+        index_path.push(last_name);
+
+        drive
+            .grove_get_raw_optional(
+                index_path.as_slice().into(),
+                &[0],
+                DirectQueryType::StatefulDirectQuery,
+                None,
+                &mut vec![],
+                &platform_version.drive,
+            )
+            .map(|element| element.is_some())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn should_fail_index_backfill_with_too_many_conflicts() {
+        let last_names = vec!["Smith"; MAX_INDEX_BACKFILL_CONFLICTS + 2];
+        let (drive, contract) = setup_people_with_last_names(&last_names);
+        let platform_version = PlatformVersion::latest();
+
+        let outcome = backfill_person_indices(&drive, &contract);
+
+        assert!(outcome.failed);
+        assert!(!outcome.completed);
+        // nothing was backfilled before, so there is nothing to roll back
+        assert!(outcome.rolled_back);
+
+        let index_backfill = fetch_person_index_backfill(&drive, &contract)
+            .expect("expected the failed backfill to be kept");
+
+        assert!(index_backfill.failed);
+        assert!(!index_backfill.is_pending());
+        assert!(index_backfill.is_document_type_frozen());
+        assert!(index_backfill.is_index_failed("byLastName"));
+        assert!(index_backfill.conflicts.is_empty());
+        assert!(!has_last_name_index_entry(&drive, &contract, "Smith"));
+
+        let pending_index_backfills = drive
+            .fetch_pending_index_backfills(None, platform_version)
+            .expect("expected to fetch pending index backfills");
+
+        assert!(pending_index_backfills.is_empty());
+    }
+
+    /// Stores people with unique last names followed by too many Smiths, and backfills the
+    /// unique people before the backfill fails on the Smiths and is rolled back
+    fn setup_failed_and_rolled_back_backfill() -> (Drive, DataContract) {
+        let mut last_names = vec!["Adams", "Brown", "Clark", "Davis", "Evans"];
+        last_names.extend(vec!["Smith"; MAX_INDEX_BACKFILL_CONFLICTS + 2]);
+        let (drive, contract) = setup_people_with_last_names(&last_names);
+        let platform_version = PlatformVersion::latest();
+
+        let outcome = backfill_person_indices_with_limit(&drive, &contract, 5);
+
+        assert!(!outcome.failed);
+        assert!(!outcome.completed);
+        assert!(has_last_name_index_entry(&drive, &contract, "Adams"));
+        assert!(has_last_name_index_entry(&drive, &contract, "Evans"));
+
+        let outcome = backfill_person_indices(&drive, &contract);
+
+        assert!(outcome.failed);
+        assert!(!outcome.rolled_back);
+
+        let index_backfill = fetch_person_index_backfill(&drive, &contract)
+            .expect("expected the failed backfill to be kept");
+
+        assert!(index_backfill.is_rolling_back());
+        assert_eq!(
+            index_backfill.rollback_until_document_id,
+            Some(Identifier::new([5; 32]))
+        );
+
+        let pending_index_backfills = drive
+            .fetch_pending_index_backfills(None, platform_version)
+            .expect("expected to fetch pending index backfills");
+
+        assert_eq!(pending_index_backfills.len(), 1);
+
+        let outcome = backfill_person_indices(&drive, &contract);
+
+        assert!(outcome.rolled_back);
+        assert_eq!(outcome.documents_processed, 5);
+
+        for last_name in &last_names[..5] {
+            assert!(!has_last_name_index_entry(&drive, &contract, last_name));
+        }
+        assert!(!has_last_name_index_entry(&drive, &contract, "Smith"));
+
+        let index_backfill = fetch_person_index_backfill(&drive, &contract)
+            .expect("expected the failed backfill to be kept");
+
+        assert!(!index_backfill.is_pending());
+        assert!(index_backfill.is_document_type_frozen());
+        assert_eq!(index_backfill.failed_index_names, vec!["byLastName"]);
+
+        let pending_index_backfills = drive
+            .fetch_pending_index_backfills(None, platform_version)
+            .expect("expected to fetch pending index backfills");
+
+        assert!(pending_index_backfills.is_empty());
+
+        (drive, contract)
+    }
+
+    #[test]
+    fn should_recover_from_failed_index_backfill_by_removing_the_index() {
+        let (drive, _) = setup_failed_and_rolled_back_backfill();
+
+        let contract = family_contract(3, None);
+        apply_family_contract(&drive, &contract);
+
+        assert!(fetch_person_index_backfill(&drive, &contract).is_none());
+    }
+
+    #[test]
+    fn should_recover_from_failed_index_backfill_by_changing_the_index() {
+        let (drive, _) = setup_failed_and_rolled_back_backfill();
+
+        let contract = family_contract(3, Some(false));
+        apply_family_contract(&drive, &contract);
+
+        let index_backfill = fetch_person_index_backfill(&drive, &contract)
+            .expect("expected the changed index to be backfilled");
+
+        assert!(index_backfill.is_index_pending("byLastName"));
+        assert!(!index_backfill.is_index_failed("byLastName"));
+
+        let outcome = backfill_person_indices(&drive, &contract);
+
+        assert!(outcome.completed);
+        assert!(outcome.conflicts.is_empty());
+        assert!(fetch_person_index_backfill(&drive, &contract).is_none());
+    }
+
+    #[test]
+    fn should_delete_index_backfill_with_its_contract() {
+        let (drive, contract) = setup_people_with_last_names(&["Smith"]);
+        let platform_version = PlatformVersion::latest();
+
+        drive
+            .delete_contract(
+                contract.id().to_buffer(),
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to delete contract");
+
+        let index_backfill = drive
+            .fetch_index_backfill(
+                contract.id_ref().as_bytes(),
+                "person",
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch index backfill");
+
+        assert!(index_backfill.is_none());
+    }
+
+    #[test]
+    fn should_round_trip_index_backfill_through_element() {
+        let mut index_backfill = IndexBackfill::new(
+            Identifier::new([1; 32]),
+            "person".to_string(),
+            vec!["byName".to_string()],
+            vec![IndexBackfillConflict {
+                index_name: "bySsn".to_string(),
+                document_id: Identifier::new([2; 32]),
+            }],
+        );
+        index_backfill.last_document_id = Some(Identifier::new([3; 32]));
+
+        let element = index_backfill
+            .serialize_to_element()
+            .expect("expected to serialize");
+        let deserialized =
+            IndexBackfill::deserialize_from_element(&element).expect("expected to deserialize");
+
+        assert_eq!(deserialized, index_backfill);
+        assert!(deserialized.is_pending());
+        assert!(deserialized.is_index_pending("byName"));
+        assert!(!deserialized.is_index_pending("bySsn"));
+        assert!(deserialized
+            .conflict_for_document(&Identifier::new([2; 32]))
+            .is_some());
+        assert!(deserialized
+            .conflict_for_document(&Identifier::new([3; 32]))
+            .is_none());
+    }
+}
@@ -114,6 +114,8 @@ impl Drive {
                                 in_clause: None,
                                 range_clause: None,
                                 equal_clauses: where_queries,
+                                other_in_clauses: vec![],
+                                or_clauses: vec![],
                             },
                             offset: None,
                             limit: Some(1),
@@ -0,0 +1,206 @@
+use crate::drive::defaults::{AVERAGE_INDEX_SUM_ITEM_SIZE, DEFAULT_HASH_SIZE_U8};
+use crate::drive::document::{INDEX_COUNT_TREE_KEY, INDEX_SUM_TREES_KEY};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::object_size_info::DriveKeyInfo::{Key, KeyRef};
+use crate::drive::object_size_info::KeyElementInfo::{KeyElement, KeyElementSize};
+use crate::drive::object_size_info::{
+    DocumentAndContractInfo, DocumentInfoV0Methods, PathInfo, PathKeyElementInfo,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::document::DocumentV0Getters;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapPathHelper;
+use dpp::version::drive_versions::DriveVersion;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::EstimatedSumTrees::AllSumTrees;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the document to the count and sum trees of the index terminating at an index level.
+    pub(super) fn add_aggregates_for_index_level_for_contract_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        index_path_info: PathInfo<0>,
+        countable: bool,
+        summable_properties: &[String],
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        storage_flags: &Option<&StorageFlags>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        drive_version: &DriveVersion,
+    ) -> Result<(), Error> {
+        let document_info = &document_and_contract_info.owned_document_info.document_info;
+
+        let estimating_costs = estimated_costs_only_with_layer_info.is_some();
+
+        let apply_type = |is_sum_tree: bool| {
+            if !estimating_costs {
+                BatchInsertTreeApplyType::StatefulBatchInsertTree
+            } else {
+                BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                    in_tree_using_sums: false,
+                    is_sum_tree,
+                    flags_len: storage_flags
+                        .map(|s| s.serialized_size())
+                        .unwrap_or_default(),
+                }
+            }
+        };
+
+        // Each document has its own sum item in the aggregate trees, keyed by the document id
+        let sum_item_key_element = |value: i64| {
+            let element = Element::SumItem(
+                value,
+                StorageFlags::map_to_some_element_flags(*storage_flags),
+            );
+            match document_info.get_document_id_as_slice() {
+                Some(document_id) => KeyElement((document_id, element)),
+                None => KeyElementSize((
+                    KeyInfo::MaxKeySize {
+                        unique_id: document_and_contract_info
+                            .document_type
+                            .unique_id_for_storage()
+                            .to_vec(),
+                        max_size: DEFAULT_HASH_SIZE_U8,
+                    },
+                    element,
+                )),
+            }
+        };
+
+        let sum_tree_layer_information = || EstimatedLayerInformation {
+            is_sum_tree: true,
+            estimated_layer_count: PotentiallyAtMaxElements,
+            estimated_layer_sizes: AllItems(
+                DEFAULT_HASH_SIZE_U8,
+                AVERAGE_INDEX_SUM_ITEM_SIZE,
+                storage_flags.map(|s| s.serialized_size()),
+            ),
+        };
+
+        if countable {
+            // the count tree is a sum tree where each document adds 1
+            let path_key_info =
+                KeyRef(&[INDEX_COUNT_TREE_KEY]).add_path_info(index_path_info.clone());
+
+            self.batch_insert_empty_sum_tree_if_not_exists(
+                path_key_info,
+                *storage_flags,
+                apply_type(true),
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                drive_version,
+            )?;
+
+            let mut count_tree_path_info = index_path_info.clone();
+            count_tree_path_info.push(Key(vec![INDEX_COUNT_TREE_KEY]))?;
+
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                estimated_costs_only_with_layer_info.insert(
+                    count_tree_path_info.clone().convert_to_key_info_path(),
+                    sum_tree_layer_information(),
+                );
+            }
+
+            let path_key_element_info = PathKeyElementInfo::from_path_info_and_key_element(
+                count_tree_path_info,
+                sum_item_key_element(1),
+            )?;
+
+            self.batch_insert(path_key_element_info, batch_operations, drive_version)?;
+        }
+
+        if summable_properties.is_empty() {
+            return Ok(());
+        }
+
+        // the sums are kept in one sum tree per property, under a shared tree
+        let path_key_info = KeyRef(&[INDEX_SUM_TREES_KEY]).add_path_info(index_path_info.clone());
+
+        self.batch_insert_empty_tree_if_not_exists(
+            path_key_info,
+            *storage_flags,
+            apply_type(false),
+            transaction,
+            previous_batch_operations,
+            batch_operations,
+            drive_version,
+        )?;
+
+        let mut sum_trees_path_info = index_path_info;
+        sum_trees_path_info.push(Key(vec![INDEX_SUM_TREES_KEY]))?;
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                sum_trees_path_info.clone().convert_to_key_info_path(),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(summable_properties.len() as u32),
+                    estimated_layer_sizes: AllSubtrees(
+                        DEFAULT_HASH_SIZE_U8,
+                        AllSumTrees,
+                        storage_flags.map(|s| s.serialized_size()),
+                    ),
+                },
+            );
+        }
+
+        for property_name in summable_properties {
+            let path_key_info =
+                Key(property_name.as_bytes().to_vec()).add_path_info(sum_trees_path_info.clone());
+
+            self.batch_insert_empty_sum_tree_if_not_exists(
+                path_key_info,
+                *storage_flags,
+                apply_type(true),
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                drive_version,
+            )?;
+
+            let mut sum_tree_path_info = sum_trees_path_info.clone();
+            sum_tree_path_info.push(Key(property_name.as_bytes().to_vec()))?;
+
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                estimated_costs_only_with_layer_info.insert(
+                    sum_tree_path_info.clone().convert_to_key_info_path(),
+                    sum_tree_layer_information(),
+                );
+            }
+
+            // documents without the property do not add to the sum
+            let value = match document_info.get_borrowed_document() {
+                Some(document) => document
+                    .properties()
+                    .get_optional_at_path(property_name)?
+                    .map(|value| value.to_integer::<i64>())
+                    .transpose()?
+                    .unwrap_or_default(),
+                None => 0,
+            };
+
+            let path_key_element_info = PathKeyElementInfo::from_path_info_and_key_element(
+                sum_tree_path_info,
+                sum_item_key_element(value),
+            )?;
+
+            self.batch_insert(path_key_element_info, batch_operations, drive_version)?;
+        }
+
+        Ok(())
+    }
+}
@@ -120,6 +120,13 @@ impl Drive {
             platform_version,
         )?;
 
+        self.add_document_expiry_operations(
+            &document_and_contract_info,
+            estimated_costs_only_with_layer_info,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
         Ok(batch_operations)
     }
 }
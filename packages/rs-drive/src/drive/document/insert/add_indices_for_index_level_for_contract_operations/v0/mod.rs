@@ -13,7 +13,7 @@ use dpp::version::PlatformVersion;
 use grovedb::batch::KeyInfoPath;
 use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
 use grovedb::EstimatedLayerSizes::AllSubtrees;
-use grovedb::EstimatedSumTrees::NoSumTrees;
+use grovedb::EstimatedSumTrees::{NoSumTrees, SomeSumTrees};
 use grovedb::{EstimatedLayerInformation, TransactionArg};
 use std::collections::HashMap;
 
@@ -48,6 +48,21 @@ impl Drive {
                 batch_operations,
                 &platform_version.drive,
             )?;
+
+            if index_level.has_aggregates() {
+                self.add_aggregates_for_index_level_for_contract_operations(
+                    document_and_contract_info,
+                    index_path_info.clone(),
+                    index_level.is_countable(),
+                    index_level.summable_properties(),
+                    previous_batch_operations,
+                    storage_flags,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
         }
 
         let document_type = document_and_contract_info.document_type;
@@ -55,15 +70,28 @@ impl Drive {
         let sub_level_index_count = index_level.sub_levels().len() as u32;
 
         if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
-            // On this level we will have a 0 and all the top index paths
+            // On this level we will have a 0, the aggregate trees and all the top index paths
+            let count_tree_count = index_level.is_countable() as u32;
+            let non_sum_tree_count =
+                sub_level_index_count + 1 + !index_level.summable_properties().is_empty() as u32;
+            let estimated_sum_trees = if count_tree_count > 0 {
+                SomeSumTrees {
+                    sum_trees_weight: count_tree_count as u8,
+                    non_sum_trees_weight: non_sum_tree_count.min(u8::MAX as u32) as u8,
+                }
+            } else {
+                NoSumTrees
+            };
             estimated_costs_only_with_layer_info.insert(
                 index_path_info.clone().convert_to_key_info_path(),
                 EstimatedLayerInformation {
                     is_sum_tree: false,
-                    estimated_layer_count: ApproximateElements(sub_level_index_count + 1),
+                    estimated_layer_count: ApproximateElements(
+                        non_sum_tree_count + count_tree_count,
+                    ),
                     estimated_layer_sizes: AllSubtrees(
                         DEFAULT_HASH_SIZE_U8,
-                        NoSumTrees,
+                        estimated_sum_trees,
                         storage_flags.map(|s| s.serialized_size()),
                     ),
                 },
@@ -40,15 +40,17 @@ impl Drive {
         let contract = document_and_contract_info.contract;
         let event_id = unique_event_id();
         let document_type = document_and_contract_info.document_type;
-        let storage_flags =
-            if document_type.documents_mutable() || contract.config().can_be_deleted() {
-                document_and_contract_info
-                    .owned_document_info
-                    .document_info
-                    .get_storage_flags_ref()
-            } else {
-                None //there are no need for storage flags if documents are not mutable and contract can not be deleted
-            };
+        let storage_flags = if document_type.documents_mutable()
+            || contract.config().can_be_deleted()
+            || document_type.documents_expire_after().is_some()
+        {
+            document_and_contract_info
+                .owned_document_info
+                .document_info
+                .get_storage_flags_ref()
+        } else {
+            None //there are no need for storage flags if documents can never be deleted
+        };
 
         // dbg!(&estimated_costs_only_with_layer_info);
 
@@ -72,6 +72,9 @@ pub use add_indices_for_top_index_level_for_contract_operations::*;
 mod add_reference_for_index_level_for_contract_operations;
 pub use add_reference_for_index_level_for_contract_operations::*;
 
+mod add_aggregates_for_index_level_for_contract_operations;
+pub use add_aggregates_for_index_level_for_contract_operations::*;
+
 #[cfg(all(
     feature = "fixtures-and-mocks",
     feature = "data-contract-cbor-conversion"
@@ -60,6 +60,10 @@ mod delete;
 #[cfg(feature = "full")]
 mod estimation_costs;
 #[cfg(feature = "full")]
+pub mod expiry;
+#[cfg(feature = "full")]
+pub mod index_backfill;
+#[cfg(feature = "full")]
 mod index_uniqueness;
 #[cfg(any(feature = "full", feature = "fixtures-and-mocks"))]
 mod insert;
@@ -68,6 +72,15 @@ pub mod query;
 #[cfg(any(feature = "full", feature = "fixtures-and-mocks"))]
 mod update;
 
+#[cfg(any(feature = "full", feature = "verify"))]
+/// Key of the sum tree counting the documents of a countable index.
+/// It is stored next to the references of the index, which are under key 0.
+pub(crate) const INDEX_COUNT_TREE_KEY: u8 = 1;
+
+#[cfg(any(feature = "full", feature = "verify"))]
+/// Key of the tree holding a sum tree for each summable property of an index.
+pub(crate) const INDEX_SUM_TREES_KEY: u8 = 2;
+
 #[cfg(any(feature = "full", feature = "verify"))]
 /// Returns the path to a contract document type.
 pub(crate) fn contract_document_type_path<'a>(
@@ -14,7 +14,7 @@ use crate::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
 use crate::drive::object_size_info::DriveKeyInfo::{Key, KeyRef, KeySize};
 use crate::drive::object_size_info::PathKeyElementInfo::PathKeyRefElement;
 use crate::drive::object_size_info::{
-    DocumentAndContractInfo, DocumentInfoV0Methods, DriveKeyInfo, PathKeyInfo,
+    DocumentAndContractInfo, DocumentInfoV0Methods, DriveKeyInfo, PathInfo, PathKeyInfo,
 };
 use crate::drive::Drive;
 use crate::error::drive::DriveError;
@@ -27,6 +27,7 @@ use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::document::document_methods::DocumentMethodsV0;
 use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
 use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapPathHelper;
 
 use dpp::version::PlatformVersion;
 use grovedb::batch::key_info::KeyInfo;
@@ -195,7 +196,9 @@ impl Drive {
                 .get_raw_for_document_type(
                     &top_index_property.name,
                     document_type,
-                    owner_id,
+                    // the stored document keeps its own owner, which may differ from
+                    // the new owner after a transfer
+                    None,
                     None,
                     platform_version,
                 )?
@@ -266,7 +269,9 @@ impl Drive {
                     .get_raw_for_document_type(
                         &index_property.name,
                         document_type,
-                        owner_id,
+                        // the stored document keeps its own owner, which may differ from
+                        // the new owner after a transfer
+                        None,
                         None,
                         platform_version,
                     )?
@@ -348,6 +353,9 @@ impl Drive {
                 // Iteration 2. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
             }
 
+            // the count and sum trees of the index sit next to the references
+            let aggregates_index_path = index.has_aggregates().then(|| index_path.clone());
+
             if change_occurred_on_index {
                 // we first need to delete the old values
                 // unique indexes will be stored under key "0"
@@ -364,6 +372,22 @@ impl Drive {
                         .collect::<Vec<KeyInfo>>(),
                 );
 
+                if index.has_aggregates() {
+                    self.remove_aggregates_for_index_level_for_contract_operations(
+                        &document_and_contract_info,
+                        PathInfo::PathWithSizes(key_info_path.clone()),
+                        index.countable,
+                        &index.summable,
+                        &storage_flags,
+                        previous_batch_operations,
+                        estimated_costs_only_with_layer_info,
+                        document.id().to_buffer(),
+                        transaction,
+                        &mut batch_operations,
+                        platform_version,
+                    )?;
+                }
+
                 if !index.unique {
                     key_info_path.push(KnownKey(vec![0]));
 
@@ -437,6 +461,21 @@ impl Drive {
                         )));
                     }
                 }
+
+                if let Some(aggregates_index_path) = aggregates_index_path {
+                    self.add_aggregates_for_index_level_for_contract_operations(
+                        &document_and_contract_info,
+                        PathInfo::PathIterator(aggregates_index_path),
+                        index.countable,
+                        &index.summable,
+                        previous_batch_operations,
+                        &storage_flags,
+                        estimated_costs_only_with_layer_info,
+                        transaction,
+                        &mut batch_operations,
+                        drive_version,
+                    )?;
+                }
             } else {
                 // no change occurred on index, we need to refresh the references
 
@@ -467,6 +506,40 @@ impl Drive {
                         drive_version,
                     )?;
                 }
+
+                if let Some(aggregates_index_path) = aggregates_index_path {
+                    // the document is still counted, only sums of changed values need updating
+                    let mut changed_summable_properties = vec![];
+                    for property_name in &index.summable {
+                        let old_value = old_document_info
+                            .get_borrowed_document()
+                            .map(|old_document| {
+                                old_document
+                                    .properties()
+                                    .get_optional_at_path(property_name)
+                            })
+                            .transpose()?
+                            .flatten();
+                        if document.properties().get_optional_at_path(property_name)? != old_value {
+                            changed_summable_properties.push(property_name.clone());
+                        }
+                    }
+
+                    if !changed_summable_properties.is_empty() {
+                        self.add_aggregates_for_index_level_for_contract_operations(
+                            &document_and_contract_info,
+                            PathInfo::PathIterator(aggregates_index_path),
+                            false,
+                            &changed_summable_properties,
+                            previous_batch_operations,
+                            &storage_flags,
+                            estimated_costs_only_with_layer_info,
+                            transaction,
+                            &mut batch_operations,
+                            drive_version,
+                        )?;
+                    }
+                }
             }
         }
         Ok(batch_operations)
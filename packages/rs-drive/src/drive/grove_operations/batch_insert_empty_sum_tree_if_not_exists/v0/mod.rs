@@ -0,0 +1,87 @@
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::object_size_info::PathKeyInfo;
+use crate::drive::object_size_info::PathKeyInfo::{
+    PathFixedSizeKey, PathFixedSizeKeyRef, PathKey, PathKeyRef, PathKeySize,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::op::LowLevelDriveOperation::GroveOperation;
+use dpp::version::drive_versions::DriveVersion;
+use grovedb::batch::Op;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Pushes an "insert empty sum tree where path key does not yet exist" operation to `drive_operations`.
+    /// Will also check the current drive operations
+    pub(super) fn batch_insert_empty_sum_tree_if_not_exists_v0<const N: usize>(
+        &self,
+        path_key_info: PathKeyInfo<N>,
+        storage_flags: Option<&StorageFlags>,
+        apply_type: BatchInsertTreeApplyType,
+        transaction: TransactionArg,
+        check_existing_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        drive_version: &DriveVersion,
+    ) -> Result<bool, Error> {
+        let (path, key): (Vec<Vec<u8>>, Vec<u8>) = match path_key_info {
+            PathKeyRef((path, key)) => (path, key.to_vec()),
+            PathKey((path, key)) => (path, key),
+            PathFixedSizeKey((path, key)) => (path.into_iter().map(Vec::from).collect(), key),
+            PathFixedSizeKeyRef((path, key)) => {
+                (path.into_iter().map(Vec::from).collect(), key.to_vec())
+            }
+            PathKeySize(_key_path_info, _key_info) => {
+                return Err(Error::Drive(DriveError::NotSupportedPrivate(
+                    "document sizes in batch operations not supported",
+                )))
+            }
+        };
+
+        let drive_operation = LowLevelDriveOperation::for_known_path_key_empty_sum_tree(
+            path.clone(),
+            key.clone(),
+            storage_flags,
+        );
+
+        // we only add the operation if it doesn't already exist in the current batch
+        if let Some(existing_operations) = check_existing_operations {
+            let mut i = 0;
+            while i < existing_operations.len() {
+                // if it already exists then just ignore things
+                // if we had a delete then we need to remove the delete
+                let previous_drive_operation = &existing_operations[i];
+                if previous_drive_operation == &drive_operation {
+                    return Ok(false);
+                } else if let GroveOperation(grove_op) = previous_drive_operation {
+                    if grove_op.key == key
+                        && grove_op.path == path
+                        && matches!(
+                            grove_op.op,
+                            Op::DeleteTree { .. } | Op::DeleteSumTree { .. }
+                        )
+                    {
+                        existing_operations.remove(i);
+                        return Ok(false);
+                    }
+                }
+                i += 1;
+            }
+        }
+
+        let has_raw = self.grove_has_raw(
+            path.as_slice().into(),
+            key.as_slice(),
+            apply_type.to_direct_query_type(),
+            transaction,
+            drive_operations,
+            drive_version,
+        )?;
+        if !has_raw {
+            drive_operations.push(drive_operation);
+        }
+        Ok(!has_raw)
+    }
+}
@@ -89,6 +89,9 @@ pub mod batch_insert_empty_tree;
 /// Batch insert operation into empty tree, but only if it doesn't already exist
 pub mod batch_insert_empty_tree_if_not_exists;
 
+/// Batch insert operation into empty sum tree, but only if it doesn't already exist
+pub mod batch_insert_empty_sum_tree_if_not_exists;
+
 /// Batch insert operation into empty tree, but only if it doesn't exist and check existing operations
 pub mod batch_insert_empty_tree_if_not_exists_check_existing_operations;
 
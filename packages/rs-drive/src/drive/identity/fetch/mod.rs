@@ -1,7 +1,7 @@
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::identity::identity_path_vec;
 #[cfg(any(feature = "full", feature = "verify"))]
-use crate::drive::identity::IdentityRootStructure::IdentityTreeRevision;
+use crate::drive::identity::IdentityRootStructure::{IdentityTreeNonce, IdentityTreeRevision};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::Drive;
@@ -39,6 +39,8 @@ mod fetch_by_public_key_hashes;
 #[cfg(feature = "full")]
 mod full_identity;
 #[cfg(feature = "full")]
+mod nonce;
+#[cfg(feature = "full")]
 mod partial_identity;
 #[cfg(feature = "full")]
 mod prove;
@@ -64,6 +66,22 @@ impl Drive {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// The query for the identity nonce
+    pub fn identity_nonce_query(identity_id: &[u8; 32]) -> PathQuery {
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        let mut query = Query::new();
+        query.insert_key(vec![IdentityTreeNonce as u8]);
+        PathQuery {
+            path: identity_path,
+            query: SizedQuery {
+                query,
+                limit: None,
+                offset: None,
+            },
+        }
+    }
+
     #[cfg(feature = "full")]
     /// Given a vector of identities, fetches the identities from storage.
     pub fn verify_all_identities_exist(
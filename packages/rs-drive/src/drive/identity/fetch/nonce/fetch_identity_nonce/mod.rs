@@ -0,0 +1,97 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::prelude::IdentityNonce;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the Identity's nonce from the backing store
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - Identity Id to fetch.
+    /// * `apply` - If `true`, the changes are applied, otherwise only the cost is estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option` for the Identity's nonce, `None` if the identity
+    /// never used a nonce-protected state transition, otherwise an `Error` if the operation fails
+    /// or the version is not supported.
+    pub fn fetch_identity_nonce(
+        &self,
+        identity_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .fetch
+            .attributes
+            .nonce
+        {
+            0 => self.fetch_identity_nonce_v0(identity_id, apply, transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_identity_nonce".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the operations to get Identity's nonce from the backing store.
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - Identity Id to fetch.
+    /// * `apply` - If `true`, the changes are applied, otherwise only the cost is estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `drive_operations` - A mutable reference to a vector of low-level drive operations.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option` for the Identity's nonce, otherwise an `Error` if the operation fails or the version is not supported.
+    pub(crate) fn fetch_identity_nonce_operations(
+        &self,
+        identity_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .fetch
+            .attributes
+            .nonce
+        {
+            0 => self.fetch_identity_nonce_operations_v0(
+                identity_id,
+                apply,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_identity_nonce_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
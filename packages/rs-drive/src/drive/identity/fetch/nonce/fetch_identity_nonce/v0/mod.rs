@@ -0,0 +1,82 @@
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::grove_operations::QueryTarget::QueryTargetValue;
+use crate::drive::identity::identity_path;
+use crate::drive::identity::IdentityRootStructure::IdentityTreeNonce;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::prelude::IdentityNonce;
+
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the Identity's nonce from the backing store
+    /// Passing apply as false get the estimated cost instead
+    pub(super) fn fetch_identity_nonce_v0(
+        &self,
+        identity_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.fetch_identity_nonce_operations_v0(
+            identity_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )
+    }
+
+    /// Creates the operations to get Identity's nonce from the backing store
+    /// This gets operations based on apply flag (stateful vs stateless)
+    pub(super) fn fetch_identity_nonce_operations_v0(
+        &self,
+        identity_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_using_sums: false,
+                query_target: QueryTargetValue(8),
+            }
+        };
+        let identity_path = identity_path(identity_id.as_slice());
+        match self.grove_get_raw_optional(
+            (&identity_path).into(),
+            &[IdentityTreeNonce as u8],
+            direct_query_type,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(Some(Item(encoded_nonce, _))) => {
+                let nonce =
+                    IdentityNonce::from_be_bytes(encoded_nonce.try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedElementType(
+                            "identity nonce was not 8 bytes as expected",
+                        ))
+                    })?);
+
+                Ok(Some(nonce))
+            }
+
+            Ok(None) => Ok(None),
+
+            Ok(Some(..)) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity nonce was present but was not identified as an item",
+            ))),
+
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod fetch_identity_nonce;
+mod prove;
@@ -0,0 +1,106 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::drive_versions::DriveVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves an Identity's nonce from the backing store
+    pub fn prove_identity_nonce(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let nonce_query = Self::identity_nonce_query(&identity_id);
+        self.grove_get_proved_path_query(
+            &nonce_query,
+            false,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::Identity;
+    use dpp::version::PlatformVersion;
+
+    #[test]
+    fn should_prove_identity_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let identity = Identity::random_identity(3, Some(14), platform_version)
+            .expect("expected a platform identity");
+
+        let identity_id = identity.id().to_buffer();
+        drive
+            .add_new_identity(
+                identity,
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        let proof = drive
+            .prove_identity_nonce(identity_id, None, &platform_version.drive)
+            .expect("should not error when proving an identity nonce");
+
+        let (_, proved_nonce) = Drive::verify_identity_nonce_for_identity_id(
+            proof.as_slice(),
+            identity_id,
+            false,
+            platform_version,
+        )
+        .expect("expect that this be verified");
+
+        assert_eq!(proved_nonce, None);
+
+        let operation = drive
+            .update_identity_nonce_operation(identity_id, 1, &mut None, platform_version)
+            .expect("expected to create the nonce operation");
+
+        drive
+            .apply_batch_low_level_drive_operations(
+                None,
+                None,
+                vec![operation],
+                &mut vec![],
+                &platform_version.drive,
+            )
+            .expect("expected to update the nonce");
+
+        assert_eq!(
+            drive
+                .fetch_identity_nonce(identity_id, true, None, platform_version)
+                .expect("expected to fetch the nonce"),
+            Some(1)
+        );
+
+        let proof = drive
+            .prove_identity_nonce(identity_id, None, &platform_version.drive)
+            .expect("should not error when proving an identity nonce");
+
+        let (_, proved_nonce) = Drive::verify_identity_nonce_for_identity_id(
+            proof.as_slice(),
+            identity_id,
+            false,
+            platform_version,
+        )
+        .expect("expect that this be verified");
+
+        assert_eq!(proved_nonce, Some(1));
+    }
+}
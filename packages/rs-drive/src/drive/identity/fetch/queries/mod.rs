@@ -16,6 +16,7 @@ use grovedb::{PathQuery, Query, SizedQuery};
 /// * `FullIdentity`: Represents a request to prove the full identity (0).
 /// * `Balance`: Represents a request to prove the account balance (1).
 /// * `Keys`: Represents a request to prove the public keys (2).
+/// * `Nonce`: Represents a request to prove the identity nonce (3).
 #[repr(u8)]
 pub enum IdentityProveRequestType {
     /// FullIdentity: A variant representing full identity access, assigned the value 0.
@@ -24,6 +25,8 @@ pub enum IdentityProveRequestType {
     Balance = 1,
     /// Keys: A variant representing keys access only, assigned the value 2.
     Keys = 2,
+    /// Nonce: A variant representing nonce access only, assigned the value 3.
+    Nonce = 3,
 }
 
 impl TryFrom<u8> for IdentityProveRequestType {
@@ -34,6 +37,7 @@ impl TryFrom<u8> for IdentityProveRequestType {
             0 => Ok(IdentityProveRequestType::FullIdentity),
             1 => Ok(IdentityProveRequestType::Balance),
             2 => Ok(IdentityProveRequestType::Keys),
+            3 => Ok(IdentityProveRequestType::Nonce),
             _ => Err(Error::Query(QuerySyntaxError::InvalidIdentityProveRequest(
                 "unknown prove request type",
             ))),
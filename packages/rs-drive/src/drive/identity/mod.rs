@@ -303,6 +303,8 @@ pub enum IdentityRootStructure {
     IdentityTreeNegativeCredit = 3,
     /// Identity contract information
     IdentityContractInfo = 4,
+    /// The nonce of the last nonce-protected state transition
+    IdentityTreeNonce = 5,
 }
 
 #[cfg(feature = "full")]
@@ -335,6 +337,7 @@ impl From<IdentityRootStructure> for &'static [u8; 1] {
             IdentityRootStructure::IdentityTreeKeyReferences => &[2],
             IdentityRootStructure::IdentityTreeNegativeCredit => &[3],
             IdentityRootStructure::IdentityContractInfo => &[4],
+            IdentityRootStructure::IdentityTreeNonce => &[5],
         }
     }
 }
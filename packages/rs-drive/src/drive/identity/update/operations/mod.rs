@@ -3,4 +3,5 @@ mod initialize_negative_identity_balance_operation;
 mod insert_identity_balance_operation;
 mod update_identity_balance_operation;
 mod update_identity_negative_credit_operation;
+mod update_identity_nonce_operation;
 mod update_identity_revision_operation;
@@ -0,0 +1,55 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::prelude::IdentityNonce;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Creates the operation setting the nonce of the identity. This function is version controlled.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The ID of the Identity whose nonce is to be set.
+    /// * `nonce` - The nonce of the last executed nonce-protected state transition.
+    /// * `estimated_costs_only_with_layer_info` - The estimated costs with layer information.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `LowLevelDriveOperation` - The resulting low level drive operation.
+    pub fn update_identity_nonce_operation(
+        &self,
+        identity_id: [u8; 32],
+        nonce: IdentityNonce,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<LowLevelDriveOperation, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .update
+            .update_identity_nonce
+        {
+            0 => self.update_identity_nonce_operation_v0(
+                identity_id,
+                nonce,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_identity_nonce_operation".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::drive::identity::{identity_path_vec, IdentityRootStructure};
+use crate::drive::Drive;
+
+use crate::error::Error;
+
+use crate::fee::op::LowLevelDriveOperation;
+use grovedb::batch::KeyInfoPath;
+
+use dpp::prelude::IdentityNonce;
+
+use dpp::version::PlatformVersion;
+use grovedb::{Element, EstimatedLayerInformation};
+
+use std::collections::HashMap;
+
+impl Drive {
+    /// Set the nonce of the identity
+    /// The nonce item is created by the first nonce-protected state transition of the identity,
+    /// so it is inserted rather than replaced
+    pub(super) fn update_identity_nonce_operation_v0(
+        &self,
+        identity_id: [u8; 32],
+        nonce: IdentityNonce,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<LowLevelDriveOperation, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            // the nonce is an item of the identity tree, just like the revision
+            Self::add_estimation_costs_for_update_revision(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        let nonce_bytes = nonce.to_be_bytes().to_vec();
+
+        Ok(LowLevelDriveOperation::insert_for_known_path_key_element(
+            identity_path,
+            Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityTreeNonce).to_vec(),
+            Element::new_item(nonce_bytes),
+        ))
+    }
+}
@@ -77,6 +77,8 @@ impl Drive {
                 in_clause: None,
                 range_clause: None,
                 equal_clauses: where_clauses,
+                other_in_clauses: vec![],
+                or_clauses: vec![],
             },
             offset: None,
             limit: Some(100),
@@ -154,6 +156,8 @@ impl Drive {
                 in_clause: None,
                 range_clause: None,
                 equal_clauses: where_clauses,
+                other_in_clauses: vec![],
+                or_clauses: vec![],
             },
             offset: None,
             limit: Some(1),
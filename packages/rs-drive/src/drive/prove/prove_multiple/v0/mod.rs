@@ -49,6 +49,9 @@ impl Drive {
                         path_queries
                             .push(Self::identity_all_keys_query(&identity_query.identity_id)?);
                     }
+                    IdentityProveRequestType::Nonce => {
+                        path_queries.push(Self::identity_nonce_query(&identity_query.identity_id));
+                    }
                 }
             }
             count += identity_queries.len();
@@ -3,3 +3,9 @@ pub const PROTOCOL_VERSION_STORAGE_KEY: &[u8; 1] = b"v";
 
 /// Next Epoch Protocol Version Storage key
 pub const NEXT_PROTOCOL_VERSION_STORAGE_KEY: &[u8; 1] = b"n";
+
+/// Prefix of the keys of document type index backfills
+pub const INDEX_BACKFILL_STORAGE_KEY_PREFIX: &[u8; 1] = b"b";
+
+/// Prefix of the keys of document expiry entries, which are followed by the expiry time
+pub const DOCUMENT_EXPIRY_STORAGE_KEY_PREFIX: &[u8; 1] = b"e";
@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+use crate::query::DocumentAggregateDriveQuery;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies a proof for the count or sum of the documents matching an aggregate query.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    /// * `aggregate_query` - The aggregate query the proof was made for.
+    /// * `platform_version` - The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing:
+    /// * A tuple with the root hash and the aggregate if the proof is valid.
+    /// * An `Error` variant, in case the proof verification fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. No index of the document type can answer the aggregate query.
+    /// 2. The proof verification fails.
+    /// 3. A proved element is not an aggregate tree.
+    pub fn verify_documents_aggregate(
+        proof: &[u8],
+        aggregate_query: &DocumentAggregateDriveQuery,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, i64), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_documents_aggregate
+        {
+            0 => Self::verify_documents_aggregate_v0(proof, aggregate_query, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_documents_aggregate".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+
+use crate::error::Error;
+use crate::query::DocumentAggregateDriveQuery;
+
+use dpp::version::PlatformVersion;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the given proof and returns the root hash of the GroveDB tree and the count or
+    /// sum of the documents matching the aggregate query.
+    ///
+    /// Index values without aggregate trees are absent from the proof and do not add to the
+    /// aggregate.
+    pub(super) fn verify_documents_aggregate_v0(
+        proof: &[u8],
+        aggregate_query: &DocumentAggregateDriveQuery,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, i64), Error> {
+        let path_query = aggregate_query.construct_path_query(platform_version)?;
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let value = DocumentAggregateDriveQuery::total_of_aggregate_elements(
+            proved_key_values
+                .into_iter()
+                .filter_map(|(_path, _key, element)| element),
+        )?;
+        Ok((root_hash, value))
+    }
+}
@@ -28,6 +28,9 @@ impl<'a> DriveQuery<'a> {
         proof: &[u8],
         platform_version: &PlatformVersion,
     ) -> Result<(RootHash, Vec<Vec<u8>>), Error> {
+        if self.internal_clauses.is_disjunctive() {
+            return self.verify_branch_proofs(proof, platform_version);
+        }
         let path_query = if let Some(start_at) = &self.start_at {
             let (_, start_document) =
                 self.verify_start_at_document_in_proof(proof, true, *start_at, platform_version)?;
@@ -51,4 +54,41 @@ impl<'a> DriveQuery<'a> {
             .collect::<Result<Vec<Vec<u8>>, Error>>()?;
         Ok((root_hash, documents))
     }
+
+    /// Verifies the proofs of every branch of a disjunctive query and merges their documents.
+    fn verify_branch_proofs(
+        &self,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<Vec<u8>>), Error> {
+        let branch_queries = self.branch_queries(platform_version)?;
+        let branch_proofs = Self::split_branch_proofs(proof)?;
+        if branch_proofs.len() != branch_queries.len() {
+            return Err(Error::Proof(ProofError::CorruptedProof(format!(
+                "expected proofs of {} query branches, got {}",
+                branch_queries.len(),
+                branch_proofs.len()
+            ))));
+        }
+
+        let mut root_hash = None;
+        let mut branch_documents = vec![];
+        for (branch_query, branch_proof) in branch_queries.iter().zip(branch_proofs) {
+            let (branch_root_hash, documents) =
+                branch_query.verify_proof_keep_serialized_v0(branch_proof, platform_version)?;
+            if root_hash.is_some_and(|root_hash| root_hash != branch_root_hash) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "query branches are proven with different root hashes".to_string(),
+                )));
+            }
+            root_hash = Some(branch_root_hash);
+            branch_documents.push(documents);
+        }
+
+        let root_hash = root_hash.ok_or(Error::Proof(ProofError::IncompleteProof(
+            "query has no branches",
+        )))?;
+        let (documents, _) = self.merge_branch_documents(branch_documents, platform_version)?;
+        Ok((root_hash, documents))
+    }
 }
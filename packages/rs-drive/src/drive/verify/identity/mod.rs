@@ -7,4 +7,5 @@ mod verify_identity_balances_for_identity_ids;
 mod verify_identity_id_by_public_key_hash;
 mod verify_identity_ids_by_public_key_hashes;
 mod verify_identity_keys_by_identity_id;
+mod verify_identity_nonce_for_identity_id;
 mod verify_identity_revision_for_identity_id;
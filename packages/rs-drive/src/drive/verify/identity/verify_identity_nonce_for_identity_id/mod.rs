@@ -0,0 +1,64 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::prelude::IdentityNonce;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the nonce of an identity by their identity ID.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the identity nonce.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityNonce>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<IdentityNonce>` represents the nonce of the user's identity if it was ever set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - The proved key value is not for the correct path or key.
+    /// - An unknown or unsupported platform version is provided.
+    ///
+    pub fn verify_identity_nonce_for_identity_id(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<IdentityNonce>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_identity_nonce_for_identity_id
+        {
+            0 => Self::verify_identity_nonce_for_identity_id_v0(
+                proof,
+                identity_id,
+                verify_subset_of_proof,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_identity_nonce_for_identity_id".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::drive::identity::IdentityRootStructure::IdentityTreeNonce;
+use crate::{
+    drive::{identity::identity_path_vec, verify::RootHash, Drive},
+    error::{proof::ProofError, Error},
+};
+use dpp::prelude::IdentityNonce;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the nonce of an identity by their identity ID.
+    ///
+    /// `verify_subset_of_proof` is used to indicate if we want to verify a subset of a bigger proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityNonce>`. The nonce is absent if the identity does not exist or has not
+    /// executed any nonce-protected state transition yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - The proved key value is not for the correct path or key in the identity tree.
+    /// - More than one nonce is found.
+    ///
+    pub(super) fn verify_identity_nonce_for_identity_id_v0(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<IdentityNonce>), Error> {
+        let mut path_query = Self::identity_nonce_query(&identity_id);
+        path_query.query.limit = Some(1);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = &proved_key_values.remove(0);
+            if path != &identity_path_vec(&identity_id) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in nonce".to_string(),
+                )));
+            }
+            if key != &vec![IdentityTreeNonce as u8] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key in nonce".to_string(),
+                )));
+            }
+
+            let nonce = maybe_element
+                .as_ref()
+                .map(|element| {
+                    let encoded_nonce = element.as_item_bytes()?;
+                    Ok::<IdentityNonce, Error>(IdentityNonce::from_be_bytes(
+                        encoded_nonce.try_into().map_err(|_| {
+                            Error::Proof(ProofError::CorruptedProof(
+                                "identity nonce was not 8 bytes as expected".to_string(),
+                            ))
+                        })?,
+                    ))
+                })
+                .transpose()?;
+            Ok((root_hash, nonce))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity nonce",
+            )))
+        }
+    }
+}
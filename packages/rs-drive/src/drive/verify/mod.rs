@@ -7,6 +7,9 @@ pub mod identity;
 /// Single Document verification methods on proofs
 pub mod single_document;
 
+/// State transition execution result verification methods on proofs
+pub mod state_transition;
+
 /// System components (Epoch info etc...) verification methods on proofs
 pub mod system;
 
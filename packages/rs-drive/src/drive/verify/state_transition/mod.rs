@@ -0,0 +1 @@
+mod verify_state_transition_was_executed_with_proof;
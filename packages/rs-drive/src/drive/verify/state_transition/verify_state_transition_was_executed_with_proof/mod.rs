@@ -0,0 +1,70 @@
+mod v0;
+
+use std::sync::Arc;
+
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::DataContract;
+use dpp::prelude::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies that a state transition was executed, by checking that the state proven by
+    /// `proof` is the state the state transition should have produced.
+    ///
+    /// The proof is expected to be created the way DAPI creates proofs for
+    /// `waitForStateTransitionResult`, that is by proving all identities, data contracts or
+    /// documents modified by the state transition. Credit transfers and withdrawals also need
+    /// the nonce of the sender to be proven.
+    ///
+    /// # Parameters
+    ///
+    /// - `state_transition`: The state transition that was executed.
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `known_contracts_provider_fn`: Returns data contracts used by documents batch transitions;
+    ///   documents can not be verified without the data contract they belong to.
+    /// - `platform_version`: The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and [StateTransitionProofResult] holding
+    /// the verified objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - An unknown or unsupported platform version is provided.
+    /// - The proof is corrupted or does not match the state transition.
+    /// - A data contract required to verify documents is not known.
+    pub fn verify_state_transition_was_executed_with_proof(
+        state_transition: &StateTransition,
+        proof: &[u8],
+        known_contracts_provider_fn: &impl Fn(&Identifier) -> Result<Option<Arc<DataContract>>, Error>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, StateTransitionProofResult), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .state_transition
+            .verify_state_transition_was_executed_with_proof
+        {
+            0 => Drive::verify_state_transition_was_executed_with_proof_v0(
+                state_transition,
+                proof,
+                known_contracts_provider_fn,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_state_transition_was_executed_with_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,1067 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::query::SingleDocumentDriveQuery;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::data_contract::DataContract;
+use dpp::document::property_names::PRICE;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::{IdentityPublicKey, PartialIdentity};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::prelude::{Identifier, IdentityNonce};
+use dpp::state_transition::data_contract_create_transition::accessors::DataContractCreateTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::{
+    DocumentTransition, DocumentTransitionV0Methods,
+};
+use dpp::state_transition::identity_create_transition::accessors::IdentityCreateTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dpp::state_transition::identity_topup_transition::accessors::IdentityTopUpTransitionAccessorsV0;
+use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::public_key_in_creation::accessors::IdentityPublicKeyInCreationV0Getters;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies that a state transition was executed, by checking that the state proven by
+    /// `proof` is the state the state transition should have produced.
+    ///
+    /// # Parameters
+    ///
+    /// - `state_transition`: The state transition that was executed.
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `known_contracts_provider_fn`: Returns data contracts used by documents batch transitions.
+    /// - `platform_version`: The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and [StateTransitionProofResult] holding
+    /// the verified objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The proven state does not match the state transition.
+    /// - A data contract required to verify documents is not known.
+    pub(super) fn verify_state_transition_was_executed_with_proof_v0(
+        state_transition: &StateTransition,
+        proof: &[u8],
+        known_contracts_provider_fn: &impl Fn(&Identifier) -> Result<Option<Arc<DataContract>>, Error>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, StateTransitionProofResult), Error> {
+        match state_transition {
+            StateTransition::DataContractCreate(data_contract_create) => {
+                Self::verify_data_contract_was_stored(
+                    proof,
+                    DataContract::try_from_platform_versioned(
+                        data_contract_create.data_contract().clone(),
+                        false,
+                        platform_version,
+                    )?,
+                    platform_version,
+                )
+            }
+            StateTransition::DataContractUpdate(data_contract_update) => {
+                Self::verify_data_contract_was_stored(
+                    proof,
+                    DataContract::try_from_platform_versioned(
+                        data_contract_update.data_contract().clone(),
+                        false,
+                        platform_version,
+                    )?,
+                    platform_version,
+                )
+            }
+            StateTransition::DocumentsBatch(documents_batch) => {
+                let owner_id = state_transition.owner_id();
+                let transitions = documents_batch.transitions();
+                // all documents are proven together, so each of them is a subset of the proof
+                let is_subset = transitions.len() > 1;
+
+                let mut root_hash = None;
+                let mut documents = BTreeMap::new();
+
+                for transition in transitions {
+                    let data_contract_id = transition.data_contract_id();
+                    let data_contract = known_contracts_provider_fn(&data_contract_id)?
+                        .ok_or_else(|| {
+                            Error::Proof(ProofError::UnknownContract(format!(
+                                "data contract {} is required to verify documents",
+                                data_contract_id
+                            )))
+                        })?;
+                    let document_type =
+                        data_contract.document_type_for_name(transition.document_type_name())?;
+
+                    let query = SingleDocumentDriveQuery {
+                        contract_id: data_contract_id.into_buffer(),
+                        document_type_name: transition.document_type_name().clone(),
+                        document_type_keeps_history: document_type.documents_keep_history(),
+                        document_id: transition.get_id().into_buffer(),
+                        block_time_ms: None,
+                    };
+
+                    let (document_root_hash, document) =
+                        query.verify_proof(is_subset, proof, document_type, platform_version)?;
+
+                    Self::check_document_matches_transition(
+                        document.as_ref(),
+                        transition,
+                        document_type,
+                        owner_id,
+                    )?;
+
+                    if root_hash.is_some_and(|root_hash| root_hash != document_root_hash) {
+                        return Err(Error::Proof(ProofError::CorruptedProof(
+                            "documents in the proof have different root hashes".to_string(),
+                        )));
+                    }
+                    root_hash = Some(document_root_hash);
+                    documents.insert(transition.get_id(), document);
+                }
+
+                let root_hash = root_hash.ok_or(Error::Proof(ProofError::IncompleteProof(
+                    "documents batch transition has no transitions",
+                )))?;
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedDocuments(documents),
+                ))
+            }
+            StateTransition::IdentityCreate(identity_create) => {
+                let (root_hash, identity) = Self::verify_full_identity_by_identity_id(
+                    proof,
+                    false,
+                    identity_create.identity_id().into_buffer(),
+                    platform_version,
+                )?;
+                let identity = identity.ok_or(Error::Proof(ProofError::IncompleteProof(
+                    "proof did not contain the created identity",
+                )))?;
+
+                for key in identity_create.public_keys() {
+                    let expected_key = IdentityPublicKey::from(key);
+                    // the key could be disabled after the identity was created
+                    let matches =
+                        identity
+                            .public_keys()
+                            .get(&expected_key.id())
+                            .is_some_and(|stored_key| {
+                                stored_key.data() == expected_key.data()
+                                    && stored_key.key_type() == expected_key.key_type()
+                                    && stored_key.purpose() == expected_key.purpose()
+                                    && stored_key.security_level() == expected_key.security_level()
+                                    && stored_key.read_only() == expected_key.read_only()
+                                    && stored_key.contract_bounds()
+                                        == expected_key.contract_bounds()
+                            });
+                    if !matches {
+                        return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                            "identity {} does not contain created key {}",
+                            identity.id(),
+                            expected_key.id()
+                        ))));
+                    }
+                }
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedIdentity(identity),
+                ))
+            }
+            StateTransition::IdentityTopUp(identity_top_up) => {
+                let (root_hash, identity) = Self::verify_full_identity_by_identity_id(
+                    proof,
+                    false,
+                    identity_top_up.identity_id().into_buffer(),
+                    platform_version,
+                )?;
+                let identity = identity.ok_or(Error::Proof(ProofError::IncompleteProof(
+                    "proof did not contain the topped up identity",
+                )))?;
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedIdentity(identity),
+                ))
+            }
+            StateTransition::IdentityCreditWithdrawal(identity_credit_withdrawal) => {
+                let identity_id = identity_credit_withdrawal.identity_id();
+
+                let (root_hash, identity) = Self::verify_full_identity_by_identity_id(
+                    proof,
+                    true,
+                    identity_id.into_buffer(),
+                    platform_version,
+                )?;
+                let identity = identity.ok_or(Error::Proof(ProofError::IncompleteProof(
+                    "proof did not contain the withdrawing identity",
+                )))?;
+
+                Self::verify_identity_nonce_was_used(
+                    proof,
+                    identity_id,
+                    identity_credit_withdrawal.nonce(),
+                    root_hash,
+                    platform_version,
+                )?;
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedIdentity(identity),
+                ))
+            }
+            StateTransition::IdentityUpdate(identity_update) => {
+                let (root_hash, identity) = Self::verify_full_identity_by_identity_id(
+                    proof,
+                    false,
+                    identity_update.identity_id().into_buffer(),
+                    platform_version,
+                )?;
+                let identity = identity.ok_or(Error::Proof(ProofError::IncompleteProof(
+                    "proof did not contain the updated identity",
+                )))?;
+
+                if identity.revision() != identity_update.revision() {
+                    return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                        "identity {} has revision {}, expected {}",
+                        identity.id(),
+                        identity.revision(),
+                        identity_update.revision()
+                    ))));
+                }
+
+                if let Some(missing_key) = identity_update
+                    .public_keys_to_add()
+                    .iter()
+                    .find(|key| !identity.public_keys().contains_key(&key.id()))
+                {
+                    return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                        "identity {} does not contain added key {}",
+                        identity.id(),
+                        missing_key.id()
+                    ))));
+                }
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedIdentity(identity),
+                ))
+            }
+            StateTransition::IdentityCreditTransfer(identity_credit_transfer) => {
+                let sender_id = identity_credit_transfer.identity_id();
+                let recipient_id = identity_credit_transfer.recipient_id();
+
+                let (root_hash, balances): (RootHash, BTreeMap<[u8; 32], Option<u64>>) =
+                    Self::verify_identity_balances_for_identity_ids(
+                        proof,
+                        true,
+                        &[sender_id.into_buffer(), recipient_id.into_buffer()],
+                        platform_version,
+                    )?;
+
+                Self::verify_identity_nonce_was_used(
+                    proof,
+                    sender_id,
+                    identity_credit_transfer.nonce(),
+                    root_hash,
+                    platform_version,
+                )?;
+
+                let partial_identity_with_balance = |identity_id: Identifier| {
+                    let balance = balances
+                        .get(identity_id.as_bytes())
+                        .copied()
+                        .flatten()
+                        .ok_or(Error::Proof(ProofError::IncompleteProof(
+                            "proof did not contain balances of both identities",
+                        )))?;
+
+                    Ok::<_, Error>(PartialIdentity {
+                        id: identity_id,
+                        loaded_public_keys: Default::default(),
+                        balance: Some(balance),
+                        revision: None,
+                        not_found_public_keys: Default::default(),
+                    })
+                };
+
+                let recipient = partial_identity_with_balance(recipient_id)?;
+                if recipient.balance < Some(identity_credit_transfer.amount()) {
+                    return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                        "recipient {} has balance {:?}, lower than transferred amount {}",
+                        recipient_id,
+                        recipient.balance,
+                        identity_credit_transfer.amount()
+                    ))));
+                }
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedBalanceTransfer(
+                        partial_identity_with_balance(sender_id)?,
+                        recipient,
+                    ),
+                ))
+            }
+            StateTransition::DataContractDelete(data_contract_delete) => {
+                let data_contract_id = data_contract_delete.data_contract_id();
+
+                let (root_hash, data_contract) = Self::verify_contract(
+                    proof,
+                    None,
+                    false,
+                    true,
+                    data_contract_id.into_buffer(),
+                    platform_version,
+                )?;
+
+                if data_contract.is_some() {
+                    return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                        "data contract {} still exists after it was deleted",
+                        data_contract_id
+                    ))));
+                }
+
+                Ok((
+                    root_hash,
+                    StateTransitionProofResult::VerifiedDataContractDeleted(data_contract_id),
+                ))
+            }
+        }
+    }
+
+    /// Verifies that `expected_data_contract` is stored, as proven by `proof`.
+    fn verify_data_contract_was_stored(
+        proof: &[u8],
+        expected_data_contract: DataContract,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, StateTransitionProofResult), Error> {
+        // DAPI proves data contracts the way multiple contracts are proven
+        let (root_hash, data_contract) = Self::verify_contract(
+            proof,
+            None,
+            false,
+            true,
+            expected_data_contract.id().into_buffer(),
+            platform_version,
+        )?;
+        let data_contract = data_contract.ok_or(Error::Proof(ProofError::IncompleteProof(
+            "proof did not contain the data contract",
+        )))?;
+
+        if data_contract != expected_data_contract {
+            return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                "data contract {} in proof does not match the state transition",
+                data_contract.id()
+            ))));
+        }
+
+        Ok((
+            root_hash,
+            StateTransitionProofResult::VerifiedDataContract(data_contract),
+        ))
+    }
+
+    /// Verifies that the nonce of `identity_id` proven by `proof` shows that the state transition
+    /// with `nonce` was executed.
+    ///
+    /// Nonces are only bumped by executed state transitions, which also debit the identity,
+    /// so balances in the proof alone can't show that credits left the identity.
+    fn verify_identity_nonce_was_used(
+        proof: &[u8],
+        identity_id: Identifier,
+        nonce: IdentityNonce,
+        expected_root_hash: RootHash,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let (root_hash, identity_nonce) = Self::verify_identity_nonce_for_identity_id(
+            proof,
+            identity_id.into_buffer(),
+            true,
+            platform_version,
+        )?;
+
+        if root_hash != expected_root_hash {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "identity nonce and identity in the proof have different root hashes".to_string(),
+            )));
+        }
+
+        let identity_nonce = identity_nonce.ok_or(Error::Proof(ProofError::IncompleteProof(
+            "proof did not contain the identity nonce",
+        )))?;
+
+        if identity_nonce < nonce {
+            return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                "identity {} has nonce {}, state transition with nonce {} was not executed",
+                identity_id, identity_nonce, nonce
+            ))));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the proven `document` is the result of applying `transition`.
+    fn check_document_matches_transition(
+        document: Option<&Document>,
+        transition: &DocumentTransition,
+        document_type: DocumentTypeRef,
+        owner_id: Identifier,
+    ) -> Result<(), Error> {
+        let document_id = transition.get_id();
+        // a transfer hands the document over to the recipient
+        let expected_owner_id = match transition {
+            DocumentTransition::Transfer(transfer_transition) => {
+                transfer_transition.recipient_owner_id()
+            }
+            _ => owner_id,
+        };
+        match (transition, document) {
+            (DocumentTransition::Delete(_), None) => Ok(()),
+            (DocumentTransition::Delete(_), Some(_)) => {
+                Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                    "document {} still exists after it was deleted",
+                    document_id
+                ))))
+            }
+            (_, None) => Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                "document {} was not found",
+                document_id
+            )))),
+            (_, Some(document)) if document.owner_id() != expected_owner_id => {
+                Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                    "document {} belongs to {}, expected {}",
+                    document_id,
+                    document.owner_id(),
+                    expected_owner_id
+                ))))
+            }
+            (DocumentTransition::Create(_), Some(document))
+                if document.revision() != document_type.initial_revision() =>
+            {
+                Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                    "document {} has revision {:?}, expected {:?}",
+                    document_id,
+                    document.revision(),
+                    document_type.initial_revision()
+                ))))
+            }
+            (DocumentTransition::Create(_), Some(_)) => {
+                Self::check_document_has_transition_data(document, transition)
+            }
+            (_, Some(document)) if document.revision() != transition.revision() => {
+                Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                    "document {} has revision {:?}, expected {:?}",
+                    document_id,
+                    document.revision(),
+                    transition.revision()
+                ))))
+            }
+            (DocumentTransition::UpdatePrice(update_price_transition), Some(document))
+                if document
+                    .properties()
+                    .get_optional_integer::<Credits>(PRICE)?
+                    != Some(update_price_transition.price()) =>
+            {
+                Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                    "document {} is not listed at price {}",
+                    document_id,
+                    update_price_transition.price()
+                ))))
+            }
+            (DocumentTransition::Replace(_) | DocumentTransition::Patch(_), Some(_)) => {
+                Self::check_document_has_transition_data(document, transition)
+            }
+            (_, Some(_)) => Ok(()),
+        }
+    }
+
+    /// Checks that the proven `document` has all properties set by a create, replace or patch `transition`.
+    fn check_document_has_transition_data(
+        document: Option<&Document>,
+        transition: &DocumentTransition,
+    ) -> Result<(), Error> {
+        let (Some(document), Some(data)) = (document, transition.data()) else {
+            return Ok(());
+        };
+
+        if let Some((name, _)) = data
+            .iter()
+            .find(|(name, value)| document.properties().get(*name) != Some(*value))
+        {
+            return Err(Error::Proof(ProofError::UnexpectedResultProof(format!(
+                "document {} does not have property {} set by the state transition",
+                transition.get_id(),
+                name
+            ))));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "full"))]
+mod tests {
+    use super::*;
+    use crate::common::setup_contract;
+    use crate::drive::identity::{IdentityDriveQuery, IdentityProveRequestType};
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::serialized_version::DataContractInSerializationFormat;
+    use dpp::document::DocumentV0Setters;
+    use dpp::identity::accessors::IdentitySettersV0;
+    use dpp::identity::Identity;
+    use dpp::platform_value::{BinaryData, Bytes32, Value};
+    use dpp::state_transition::data_contract_create_transition::v0::DataContractCreateTransitionV0;
+    use dpp::state_transition::data_contract_create_transition::DataContractCreateTransition;
+    use dpp::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+    use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+    use dpp::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
+    use dpp::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+    use dpp::state_transition::documents_batch_transition::document_create_transition::v0::DocumentCreateTransitionV0;
+    use dpp::state_transition::documents_batch_transition::document_create_transition::DocumentCreateTransition;
+    use dpp::state_transition::documents_batch_transition::{
+        DocumentsBatchTransition, DocumentsBatchTransitionV0,
+    };
+    use dpp::state_transition::identity_create_transition::v0::IdentityCreateTransitionV0;
+    use dpp::state_transition::identity_create_transition::IdentityCreateTransition;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use dpp::state_transition::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
+    use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+    use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+    use dpp::tests::json_document::json_document_to_document;
+    use dpp::version::TryIntoPlatformVersioned;
+
+    const FAMILY_CONTRACT_PATH: &str =
+        "tests/supporting_files/contract/family/family-contract-reduced.json";
+
+    fn no_contracts(_id: &Identifier) -> Result<Option<Arc<DataContract>>, Error> {
+        Ok(None)
+    }
+
+    fn add_identity(drive: &Drive, seed: u64, balance: Credits) -> Identity {
+        let platform_version = PlatformVersion::latest();
+
+        let mut identity = Identity::random_identity(3, Some(seed), platform_version)
+            .expect("expected a random identity");
+        identity.set_balance(balance);
+
+        drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        identity
+    }
+
+    fn set_identity_nonce(drive: &Drive, identity_id: Identifier, nonce: IdentityNonce) {
+        let platform_version = PlatformVersion::latest();
+
+        let operation = drive
+            .update_identity_nonce_operation(
+                identity_id.into_buffer(),
+                nonce,
+                &mut None,
+                platform_version,
+            )
+            .expect("expected to create the nonce operation");
+
+        drive
+            .apply_batch_low_level_drive_operations(
+                None,
+                None,
+                vec![operation],
+                &mut vec![],
+                &platform_version.drive,
+            )
+            .expect("expected to update the nonce");
+    }
+
+    fn prove_identities(
+        drive: &Drive,
+        queries: Vec<([u8; 32], IdentityProveRequestType)>,
+    ) -> Vec<u8> {
+        let identity_queries = queries
+            .into_iter()
+            .map(|(identity_id, prove_request_type)| IdentityDriveQuery {
+                identity_id,
+                prove_request_type,
+            })
+            .collect();
+
+        drive
+            .prove_multiple(
+                &identity_queries,
+                &[],
+                &vec![],
+                None,
+                PlatformVersion::latest(),
+            )
+            .expect("expected to prove identities")
+    }
+
+    fn prove_contract(drive: &Drive, contract_id: Identifier) -> Vec<u8> {
+        drive
+            .prove_multiple(
+                &vec![],
+                &[(contract_id.into_buffer(), None)],
+                &vec![],
+                None,
+                PlatformVersion::latest(),
+            )
+            .expect("expected to prove the data contract")
+    }
+
+    fn credit_transfer(
+        sender_id: Identifier,
+        recipient_id: Identifier,
+        amount: Credits,
+        nonce: IdentityNonce,
+    ) -> StateTransition {
+        StateTransition::IdentityCreditTransfer(IdentityCreditTransferTransition::V0(
+            IdentityCreditTransferTransitionV0 {
+                identity_id: sender_id,
+                recipient_id,
+                amount,
+                nonce,
+                ..Default::default()
+            },
+        ))
+    }
+
+    fn credit_withdrawal(identity_id: Identifier, nonce: IdentityNonce) -> StateTransition {
+        StateTransition::IdentityCreditWithdrawal(IdentityCreditWithdrawalTransition::V0(
+            IdentityCreditWithdrawalTransitionV0 {
+                identity_id,
+                amount: 1000,
+                nonce,
+                ..Default::default()
+            },
+        ))
+    }
+
+    fn document_create(document: &Document, data_contract_id: Identifier) -> StateTransition {
+        let create_transition = DocumentCreateTransition::V0(DocumentCreateTransitionV0 {
+            base: DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+                id: document.id(),
+                document_type_name: "person".to_string(),
+                data_contract_id,
+            }),
+            entropy: [0; 32],
+            created_at: None,
+            updated_at: None,
+            data: document.properties().clone(),
+        });
+
+        StateTransition::DocumentsBatch(DocumentsBatchTransition::V0(DocumentsBatchTransitionV0 {
+            owner_id: document.owner_id(),
+            transitions: vec![DocumentTransition::Create(create_transition)],
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn should_verify_data_contract_create() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let contract = setup_contract(&drive, FAMILY_CONTRACT_PATH, None, None);
+        let proof = prove_contract(&drive, contract.id());
+
+        let serialized_contract: DataContractInSerializationFormat = contract
+            .clone()
+            .try_into_platform_versioned(platform_version)
+            .expect("expected to serialize the contract");
+        let state_transition = StateTransition::DataContractCreate(
+            DataContractCreateTransition::V0(DataContractCreateTransitionV0 {
+                data_contract: serialized_contract,
+                entropy: Bytes32::default(),
+                user_fee_increase: 0,
+                signature_public_key_id: 0,
+                signature: BinaryData::default(),
+            }),
+        );
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect("expected to verify the data contract");
+
+        assert_eq!(
+            result,
+            StateTransitionProofResult::VerifiedDataContract(contract)
+        );
+    }
+
+    #[test]
+    fn should_verify_data_contract_delete_only_when_contract_is_gone() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let contract = setup_contract(&drive, FAMILY_CONTRACT_PATH, None, None);
+        let deleted_contract_id = Identifier::new([7; 32]);
+
+        let delete = |data_contract_id| {
+            StateTransition::DataContractDelete(DataContractDeleteTransition::V0(
+                DataContractDeleteTransitionV0 {
+                    data_contract_id,
+                    owner_id: contract.owner_id(),
+                    ..Default::default()
+                },
+            ))
+        };
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &delete(deleted_contract_id),
+            &prove_contract(&drive, deleted_contract_id),
+            &no_contracts,
+            platform_version,
+        )
+        .expect("expected to verify the deletion");
+
+        assert_eq!(
+            result,
+            StateTransitionProofResult::VerifiedDataContractDeleted(deleted_contract_id)
+        );
+
+        Drive::verify_state_transition_was_executed_with_proof(
+            &delete(contract.id()),
+            &prove_contract(&drive, contract.id()),
+            &no_contracts,
+            platform_version,
+        )
+        .expect_err("existing contract must not be verified as deleted");
+    }
+
+    #[test]
+    fn should_verify_identity_create_keys() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let identity = add_identity(&drive, 1, 1000);
+        let proof = prove_identities(
+            &drive,
+            vec![(
+                identity.id().into_buffer(),
+                IdentityProveRequestType::FullIdentity,
+            )],
+        );
+
+        let mut public_keys: Vec<IdentityPublicKeyInCreation> =
+            identity.public_keys().values().map(Into::into).collect();
+        let create = |public_keys| {
+            StateTransition::IdentityCreate(IdentityCreateTransition::V0(
+                IdentityCreateTransitionV0 {
+                    public_keys,
+                    identity_id: identity.id(),
+                    ..Default::default()
+                },
+            ))
+        };
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &create(public_keys.clone()),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect("expected to verify the identity");
+
+        assert_eq!(
+            result,
+            StateTransitionProofResult::VerifiedIdentity(identity)
+        );
+
+        // the proven identity doesn't have a key with different data
+        let other_identity = Identity::random_identity(1, Some(2), platform_version)
+            .expect("expected a random identity");
+        let other_key = other_identity
+            .public_keys()
+            .values()
+            .next()
+            .expect("expected a key");
+        public_keys[0] = other_key.into();
+
+        Drive::verify_state_transition_was_executed_with_proof(
+            &create(public_keys),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect_err("identity without the created key must not be verified");
+    }
+
+    #[test]
+    fn should_verify_credit_transfer_with_sender_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let sender = add_identity(&drive, 1, 500);
+        let recipient = add_identity(&drive, 2, 1500);
+        set_identity_nonce(&drive, sender.id(), 1);
+
+        let proof = prove_identities(
+            &drive,
+            vec![
+                (sender.id().into_buffer(), IdentityProveRequestType::Balance),
+                (
+                    recipient.id().into_buffer(),
+                    IdentityProveRequestType::Balance,
+                ),
+                (sender.id().into_buffer(), IdentityProveRequestType::Nonce),
+            ],
+        );
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &credit_transfer(sender.id(), recipient.id(), 1000, 1),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect("expected to verify the transfer");
+
+        let StateTransitionProofResult::VerifiedBalanceTransfer(proven_sender, proven_recipient) =
+            result
+        else {
+            panic!("expected balance transfer, got {:?}", result);
+        };
+        assert_eq!(proven_sender.balance, Some(500));
+        assert_eq!(proven_recipient.balance, Some(1500));
+
+        // the transfer with the next nonce was not executed yet
+        Drive::verify_state_transition_was_executed_with_proof(
+            &credit_transfer(sender.id(), recipient.id(), 1000, 2),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect_err("transfer with unused nonce must not be verified");
+
+        // the recipient can't have received more than its balance
+        Drive::verify_state_transition_was_executed_with_proof(
+            &credit_transfer(sender.id(), recipient.id(), 2000, 1),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect_err("transfer larger than recipient balance must not be verified");
+
+        // balances alone don't show that the sender was debited
+        let proof_without_nonce = prove_identities(
+            &drive,
+            vec![
+                (sender.id().into_buffer(), IdentityProveRequestType::Balance),
+                (
+                    recipient.id().into_buffer(),
+                    IdentityProveRequestType::Balance,
+                ),
+            ],
+        );
+        Drive::verify_state_transition_was_executed_with_proof(
+            &credit_transfer(sender.id(), recipient.id(), 1000, 1),
+            &proof_without_nonce,
+            &no_contracts,
+            platform_version,
+        )
+        .expect_err("transfer without sender nonce must not be verified");
+    }
+
+    #[test]
+    fn should_verify_credit_withdrawal_with_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let identity = add_identity(&drive, 1, 500);
+        set_identity_nonce(&drive, identity.id(), 3);
+
+        let proof = prove_identities(
+            &drive,
+            vec![
+                (
+                    identity.id().into_buffer(),
+                    IdentityProveRequestType::FullIdentity,
+                ),
+                (identity.id().into_buffer(), IdentityProveRequestType::Nonce),
+            ],
+        );
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &credit_withdrawal(identity.id(), 3),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect("expected to verify the withdrawal");
+
+        assert_eq!(
+            result,
+            StateTransitionProofResult::VerifiedIdentity(identity.clone())
+        );
+
+        Drive::verify_state_transition_was_executed_with_proof(
+            &credit_withdrawal(identity.id(), 4),
+            &proof,
+            &no_contracts,
+            platform_version,
+        )
+        .expect_err("withdrawal with unused nonce must not be verified");
+    }
+
+    #[test]
+    fn should_verify_document_create_data_and_revision() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let contract = Arc::new(setup_contract(&drive, FAMILY_CONTRACT_PATH, None, None));
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+        let document = json_document_to_document(
+            "tests/supporting_files/contract/family/person0.json",
+            Some(Identifier::new([3; 32])),
+            document_type,
+            platform_version,
+        )
+        .expect("expected to get document");
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&document, None)),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to insert a document");
+
+        let proof = drive
+            .prove_multiple(
+                &vec![],
+                &[],
+                &vec![SingleDocumentDriveQuery {
+                    contract_id: contract.id().into_buffer(),
+                    document_type_name: "person".to_string(),
+                    document_type_keeps_history: false,
+                    document_id: document.id().into_buffer(),
+                    block_time_ms: None,
+                }],
+                None,
+                platform_version,
+            )
+            .expect("expected to prove the document");
+
+        let contracts = |_id: &Identifier| -> Result<Option<Arc<DataContract>>, Error> {
+            Ok(Some(contract.clone()))
+        };
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &document_create(&document, contract.id()),
+            &proof,
+            &contracts,
+            platform_version,
+        )
+        .expect("expected to verify the document");
+
+        assert_eq!(
+            result,
+            StateTransitionProofResult::VerifiedDocuments(BTreeMap::from([(
+                document.id(),
+                Some(document.clone())
+            )]))
+        );
+
+        // the stored document doesn't have the data of the transition
+        let mut other_document = document.clone();
+        other_document.set("firstName", Value::Text("Other".to_string()));
+
+        Drive::verify_state_transition_was_executed_with_proof(
+            &document_create(&other_document, contract.id()),
+            &proof,
+            &contracts,
+            platform_version,
+        )
+        .expect_err("document with other data must not be verified");
+
+        // a created document has the initial revision
+        let mut replaced_document = document.clone();
+        replaced_document.set_revision(Some(2));
+        drive
+            .update_document_for_contract(
+                &replaced_document,
+                &contract,
+                document_type,
+                None,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to update the document");
+
+        let proof = drive
+            .prove_multiple(
+                &vec![],
+                &[],
+                &vec![SingleDocumentDriveQuery {
+                    contract_id: contract.id().into_buffer(),
+                    document_type_name: "person".to_string(),
+                    document_type_keeps_history: false,
+                    document_id: document.id().into_buffer(),
+                    block_time_ms: None,
+                }],
+                None,
+                platform_version,
+            )
+            .expect("expected to prove the document");
+
+        Drive::verify_state_transition_was_executed_with_proof(
+            &document_create(&document, contract.id()),
+            &proof,
+            &contracts,
+            platform_version,
+        )
+        .expect_err("replaced document must not be verified as created");
+    }
+}
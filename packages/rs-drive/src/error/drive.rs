@@ -54,6 +54,16 @@ pub enum DriveError {
     #[error("updating readonly immutable document error: {0}")]
     UpdatingReadOnlyImmutableDocument(&'static str),
 
+    /// Error
+    #[error("deleting contract that can not be deleted error: {0}")]
+    DeletingUndeletableContract(&'static str),
+    /// Error
+    #[error("deleting contract that does not exist error: {0}")]
+    DeletingContractThatDoesNotExist(&'static str),
+    /// Error
+    #[error("deleting contract that has too many elements error: {0}")]
+    DeletingTooLargeContract(&'static str),
+
     /// Error
     #[error("invalid deletion of document that keeps history error: {0}")]
     InvalidDeletionOfDocumentThatKeepsHistory(&'static str),
@@ -163,4 +173,8 @@ pub enum DriveError {
     /// Error
     #[error("invalid contract history fetch limit: {0}. The limit must be between 1 and {MAX_CONTRACT_HISTORY_FETCH_LIMIT}")]
     InvalidContractHistoryFetchLimit(u16),
+
+    /// Error
+    #[error("index backfill in progress error: {0}")]
+    IndexBackfillInProgress(&'static str),
 }
@@ -41,6 +41,15 @@ pub enum ProofError {
         /// The actual path
         actual: Path,
     },
+
+    /// The proof is valid, however the proven state is not what the state transition should
+    /// have produced.
+    #[error("unexpected result in proof error: {0}")]
+    UnexpectedResultProof(String),
+
+    /// The proof can not be verified because a data contract it refers to is not known
+    #[error("unknown contract in proof error: {0}")]
+    UnknownContract(String),
 }
 #[allow(dead_code)]
 fn get_error_code(error: &ProofError) -> u32 {
@@ -52,5 +61,7 @@ fn get_error_code(error: &ProofError) -> u32 {
         ProofError::IncompleteProof(_) => 6004,
         ProofError::IncorrectValueSize(_) => 6005,
         ProofError::IncorrectElementPath { .. } => 6006,
+        ProofError::UnexpectedResultProof(_) => 6007,
+        ProofError::UnknownContract(_) => 6008,
     }
 }
@@ -27,6 +27,12 @@ pub enum QuerySyntaxError {
     /// Multiple in clauses error
     #[error("multiple in clauses error: {0}")]
     MultipleInClauses(&'static str),
+    /// Too many query branches error
+    #[error("too many query branches error: {0}")]
+    TooManyQueryBranches(&'static str),
+    /// Too many documents in query branch error
+    #[error("too many documents in query branch error: {0}")]
+    TooManyDocumentsInQueryBranch(&'static str),
     /// Multiple range clauses error
     #[error("multiple range clauses error: {0}")]
     MultipleRangeClauses(&'static str),
@@ -127,4 +133,19 @@ pub enum QuerySyntaxError {
     /// Invalid identity prove request error
     #[error("invalid identity prove request error: {0}")]
     InvalidIdentityProveRequest(&'static str),
+
+    /// Invalid aggregate query error
+    #[error("invalid aggregate query error: {0}")]
+    InvalidAggregateQuery(&'static str),
+
+    /// Invalid SQL error at a position of the SQL string
+    #[error("invalid sql error at line {line}, column {column}: {message}")]
+    InvalidSQLAtPosition {
+        /// The error message
+        message: String,
+        /// The line of the error, starting at 1
+        line: u64,
+        /// The column of the error, starting at 1
+        column: u64,
+    },
 }
@@ -12,3 +12,5 @@ pub const KEY_START_BLOCK_CORE_HEIGHT: &[u8; 1] = b"c";
 pub const KEY_PROPOSERS: &[u8; 1] = b"m";
 /// Fee multiplier key
 pub const KEY_FEE_MULTIPLIER: &[u8; 1] = b"x";
+/// Proposer tips key prefix, followed by the proposer's pro_tx_hash
+pub const KEY_PROPOSER_TIPS_PREFIX: &[u8; 1] = b"u";
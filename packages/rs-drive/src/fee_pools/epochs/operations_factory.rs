@@ -35,6 +35,7 @@
 use crate::drive::batch::GroveDbOpBatch;
 use crate::drive::credit_pools::paths::pools_vec_path;
 use crate::drive::Drive;
+use crate::error::fee::FeeError;
 use crate::error::Error;
 
 use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
@@ -42,7 +43,7 @@ use crate::fee_pools::epochs::epoch_key_constants::{
     KEY_FEE_MULTIPLIER, KEY_POOL_PROCESSING_FEES, KEY_POOL_STORAGE_FEES, KEY_PROPOSERS,
     KEY_START_BLOCK_CORE_HEIGHT, KEY_START_BLOCK_HEIGHT, KEY_START_TIME,
 };
-use crate::fee_pools::epochs::paths::EpochProposers;
+use crate::fee_pools::epochs::paths::{proposer_tips_key, EpochProposers};
 use dpp::balances::credits::Creditable;
 use dpp::block::epoch::Epoch;
 use dpp::fee::Credits;
@@ -61,6 +62,16 @@ pub trait EpochOperations {
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<GroveDbOp, Error>;
+    /// Adds the given tips to the proposer's tips to be paid out with the epoch
+    fn increment_proposer_tips_operation(
+        &self,
+        drive: &Drive,
+        proposer_pro_tx_hash: &[u8; 32],
+        tips: Credits,
+        cached_previous_tips: Option<Credits>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<GroveDbOp, Error>;
     /// Adds to the groveDB op batch operations to insert an empty tree into the epoch
     fn add_init_empty_without_storage_operations(&self, batch: &mut GroveDbOpBatch);
     /// Adds to the groveDB op batch operations to insert an empty tree into the epoch
@@ -112,6 +123,18 @@ pub trait EpochOperations {
         pro_tx_hashes: Vec<Vec<u8>>,
         batch: &mut GroveDbOpBatch,
     );
+    /// Returns a groveDB op which updates the given epoch proposer's tips.
+    fn update_proposer_tips_operation(
+        &self,
+        proposer_pro_tx_hash: &[u8],
+        tips: Credits,
+    ) -> Result<GroveDbOp, Error>;
+    /// Adds groveDB ops to the batch which delete tips of the given epoch proposers.
+    fn add_delete_proposers_tips_operations(
+        &self,
+        pro_tx_hashes: Vec<Vec<u8>>,
+        batch: &mut GroveDbOpBatch,
+    );
 }
 
 impl EpochOperations for Epoch {
@@ -147,6 +170,36 @@ impl EpochOperations for Epoch {
         Ok(operation)
     }
 
+    /// Adds the given tips to the proposer's tips to be paid out with the epoch
+    fn increment_proposer_tips_operation(
+        &self,
+        drive: &Drive,
+        proposer_pro_tx_hash: &[u8; 32],
+        tips: Credits,
+        cached_previous_tips: Option<Credits>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<GroveDbOp, Error> {
+        let previous_tips = if let Some(previous_tips) = cached_previous_tips {
+            previous_tips
+        } else {
+            drive
+                .get_epochs_proposer_tips(self, proposer_pro_tx_hash, transaction, platform_version)
+                .or_else(|e| match e {
+                    Error::GroveDB(grovedb::Error::PathKeyNotFound(_)) => Ok(0),
+                    _ => Err(e),
+                })?
+        };
+
+        let total_tips = previous_tips
+            .checked_add(tips)
+            .ok_or(Error::Fee(FeeError::Overflow(
+                "overflow when adding proposer tips",
+            )))?;
+
+        self.update_proposer_tips_operation(proposer_pro_tx_hash, total_tips)
+    }
+
     /// Adds to the groveDB op batch operations to insert an empty tree into the epoch
     fn add_init_empty_without_storage_operations(&self, batch: &mut GroveDbOpBatch) {
         batch.add_insert_empty_sum_tree(pools_vec_path(), self.key.to_vec());
@@ -296,6 +349,30 @@ impl EpochOperations for Epoch {
             batch.add_delete(self.get_proposers_path_vec(), pro_tx_hash);
         }
     }
+
+    /// Returns a groveDB op which updates the given epoch proposer's tips.
+    fn update_proposer_tips_operation(
+        &self,
+        proposer_pro_tx_hash: &[u8],
+        tips: Credits,
+    ) -> Result<GroveDbOp, Error> {
+        Ok(GroveDbOp::insert_op(
+            self.get_path_vec(),
+            proposer_tips_key(proposer_pro_tx_hash),
+            Element::new_sum_item(tips.to_signed()?),
+        ))
+    }
+
+    /// Adds groveDB ops to the batch which delete tips of the given epoch proposers.
+    fn add_delete_proposers_tips_operations(
+        &self,
+        pro_tx_hashes: Vec<Vec<u8>>,
+        batch: &mut GroveDbOpBatch,
+    ) {
+        for pro_tx_hash in pro_tx_hashes.into_iter() {
+            batch.add_delete(self.get_path_vec(), proposer_tips_key(&pro_tx_hash));
+        }
+    }
 }
 
 #[cfg(test)]
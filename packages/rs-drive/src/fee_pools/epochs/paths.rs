@@ -81,6 +81,14 @@ impl EpochProposers for Epoch {
     }
 }
 
+/// Returns the key of the given proposer's tips in the epoch tree
+pub fn proposer_tips_key(proposer_pro_tx_hash: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(1 + proposer_pro_tx_hash.len());
+    key.extend_from_slice(epoch_key_constants::KEY_PROPOSER_TIPS_PREFIX);
+    key.extend_from_slice(proposer_pro_tx_hash);
+    key
+}
+
 /// Encodes an epoch index key with storage offset
 pub fn encode_epoch_index_key(index: u16) -> Result<[u8; 2], Error> {
     let index_with_offset =
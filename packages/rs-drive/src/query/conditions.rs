@@ -42,6 +42,8 @@ use WhereOperator::{
 
 use crate::error::query::QuerySyntaxError;
 use crate::error::Error;
+use crate::query::defaults::MAX_QUERY_BRANCHES;
+use crate::query::sql::{sql_identifier, sql_text, sql_value};
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
 use dpp::data_contract::document_type::DocumentTypeRef;
 use dpp::document::document_methods::DocumentMethodsV0;
@@ -69,10 +71,46 @@ fn sql_value_to_platform_value(sql_value: ast::Value) -> Option<Value> {
         ast::Value::SingleQuotedString(s) => Some(Value::Text(s)),
         ast::Value::HexStringLiteral(s) => Some(Value::Text(s)),
         ast::Value::NationalStringLiteral(s) => Some(Value::Text(s)),
+        ast::Value::Null => Some(Value::Null),
         _ => None,
     }
 }
 
+/// Converts SQL value expressions, which are values optionally preceded by a sign, to CBOR.
+fn sql_expr_to_platform_value(sql_expr: &ast::Expr) -> Option<Value> {
+    match sql_expr {
+        ast::Expr::Value(sql_value) => sql_value_to_platform_value(sql_value.clone()),
+        ast::Expr::UnaryOp {
+            op: ast::UnaryOperator::Plus,
+            expr,
+        } => match sql_expr_to_platform_value(expr)? {
+            value @ (Value::I64(_) | Value::Float(_)) => Some(value),
+            _ => None,
+        },
+        ast::Expr::UnaryOp {
+            op: ast::UnaryOperator::Minus,
+            expr,
+        } => match sql_expr_to_platform_value(expr)? {
+            Value::I64(int) => int.checked_neg().map(Value::I64),
+            Value::Float(float) => Some(Value::Float(-float)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns true if the SQL expression is a value, optionally preceded by a sign.
+fn is_sql_value_expr(sql_expr: &ast::Expr) -> bool {
+    match sql_expr {
+        ast::Expr::Value(_) => true,
+        ast::Expr::UnaryOp {
+            op: ast::UnaryOperator::Plus | ast::UnaryOperator::Minus,
+            expr,
+        } => matches!(&**expr, ast::Expr::Value(_)),
+        _ => false,
+    }
+}
+
 /// Where operator arguments
 #[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum WhereOperator {
@@ -227,6 +265,9 @@ impl From<WhereOperator> for Value {
     }
 }
 
+/// First component of the `where` array element holding alternative groups of where clauses
+pub const OR_CLAUSE: &str = "or";
+
 /// Where clause struct
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct WhereClause {
@@ -429,6 +470,82 @@ impl<'a> WhereClause {
         })
     }
 
+    /// Returns the groups of where clauses given the elements of a `where` array.
+    /// A document matches the query if it matches all the clauses of at least one group.
+    ///
+    /// Elements are either where clause components or `["or", [[clause, ...], ...]]` alternatives;
+    /// alternatives are distributed over all other elements.
+    pub fn groups_from_components(where_components: &'a [Value]) -> Result<Vec<Vec<Self>>, Error> {
+        let mut groups: Vec<Vec<Self>> = vec![vec![]];
+        for where_component in where_components {
+            let Value::Array(clause_components) = where_component else {
+                return Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                    "where clause must be an array",
+                )));
+            };
+            if clause_components.len() == 2
+                && clause_components.get(0).and_then(|value| value.as_text()) == Some(OR_CLAUSE)
+            {
+                let alternatives = clause_components
+                    .get(1)
+                    .and_then(|value| value.as_array())
+                    .ok_or(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                        "or clause must have an array of alternatives",
+                    )))?
+                    .iter()
+                    .map(|alternative| {
+                        alternative
+                            .as_array()
+                            .ok_or(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                                "or clause alternative must be an array of where clauses",
+                            )))?
+                            .iter()
+                            .map(|where_clause| match where_clause {
+                                Value::Array(components) => Self::from_components(components),
+                                _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                                    "where clause must be an array",
+                                ))),
+                            })
+                            .collect::<Result<Vec<Self>, Error>>()
+                    })
+                    .collect::<Result<Vec<Vec<Self>>, Error>>()?;
+                if alternatives.is_empty() {
+                    return Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                        "or clause must have at least one alternative",
+                    )));
+                }
+                groups = Self::and_groups(groups, alternatives)?;
+            } else {
+                let where_clause = Self::from_components(clause_components)?;
+                groups
+                    .iter_mut()
+                    .for_each(|group| group.push(where_clause.clone()));
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Returns groups of where clauses matching both the left and the right groups
+    fn and_groups(left: Vec<Vec<Self>>, right: Vec<Vec<Self>>) -> Result<Vec<Vec<Self>>, Error> {
+        if left.len() * right.len() > MAX_QUERY_BRANCHES {
+            return Err(Error::Query(QuerySyntaxError::TooManyQueryBranches(
+                "or clauses can not be split into more than 100 groups",
+            )));
+        }
+        Ok(left
+            .iter()
+            .flat_map(|left_group| {
+                right.iter().map(move |right_group| {
+                    left_group
+                        .iter()
+                        .chain(right_group.iter())
+                        .cloned()
+                        .collect()
+                })
+            })
+            .collect())
+    }
+
     fn lower_bound_clause(where_clauses: &'a [&WhereClause]) -> Result<Option<&'a Self>, Error> {
         let lower_range_clauses: Vec<&&WhereClause> = where_clauses
             .iter()
@@ -460,11 +577,20 @@ impl<'a> WhereClause {
     }
 
     /// Given a list of where clauses, returns them in groups of equal, range, and in clauses
+    /// The first in clause is returned on its own, other in clauses are returned in the last group
     pub(crate) fn group_clauses(
         where_clauses: &'a [WhereClause],
-    ) -> Result<(BTreeMap<String, Self>, Option<Self>, Option<Self>), Error> {
+    ) -> Result<
+        (
+            BTreeMap<String, Self>,
+            Option<Self>,
+            Option<Self>,
+            Vec<Self>,
+        ),
+        Error,
+    > {
         if where_clauses.is_empty() {
-            return Ok((BTreeMap::new(), None, None));
+            return Ok((BTreeMap::new(), None, None, vec![]));
         }
         let equal_clauses_array =
             where_clauses
@@ -504,25 +630,27 @@ impl<'a> WhereClause {
             })
             .collect::<Vec<WhereClause>>();
 
-        let in_clause = match in_clauses_array.len() {
-            0 => Ok(None),
-            1 => {
-                let clause = in_clauses_array.get(0).expect("there must be a value");
+        let mut in_clauses = in_clauses_array
+            .into_iter()
+            .map(|clause| {
                 if known_fields.contains(&clause.field) {
                     Err(Error::Query(
                         QuerySyntaxError::DuplicateNonGroupableClauseSameField(
-                            "in clause has same field as an equality clause",
+                            "in clause has same field as an equality clause or another in clause",
                         ),
                     ))
                 } else {
                     known_fields.insert(clause.field.clone());
-                    Ok(Some(clause.clone()))
+                    Ok(clause)
                 }
-            }
-            _ => Err(Error::Query(QuerySyntaxError::MultipleInClauses(
-                "There should only be one in clause",
-            ))),
-        }?;
+            })
+            .collect::<Result<Vec<WhereClause>, Error>>()?;
+
+        let in_clause = if in_clauses.is_empty() {
+            None
+        } else {
+            Some(in_clauses.remove(0))
+        };
 
         // In order to group range clauses
         let groupable_range_clauses: Vec<&WhereClause> = where_clauses
@@ -665,7 +793,7 @@ impl<'a> WhereClause {
                 )))
             }?;
 
-        Ok((equal_clauses, range_clause, in_clause))
+        Ok((equal_clauses, range_clause, in_clause, in_clauses))
     }
 
     fn split_value_for_between(
@@ -1075,6 +1203,43 @@ impl<'a> WhereClause {
         Ok(query)
     }
 
+    /// Build groups of where clauses from operations, a document matches if it matches
+    /// all where clauses of at least one group
+    pub(crate) fn build_where_clause_groups_from_operations(
+        binary_operation: &ast::Expr,
+    ) -> Result<Vec<Vec<WhereClause>>, Error> {
+        match &binary_operation {
+            ast::Expr::Nested(expr) => Self::build_where_clause_groups_from_operations(expr),
+            ast::Expr::BinaryOp {
+                left,
+                op: ast::BinaryOperator::And,
+                right,
+            } => Self::and_groups(
+                Self::build_where_clause_groups_from_operations(left)?,
+                Self::build_where_clause_groups_from_operations(right)?,
+            ),
+            ast::Expr::BinaryOp {
+                left,
+                op: ast::BinaryOperator::Or,
+                right,
+            } => {
+                let mut groups = Self::build_where_clause_groups_from_operations(left)?;
+                groups.extend(Self::build_where_clause_groups_from_operations(right)?);
+                if groups.len() > MAX_QUERY_BRANCHES {
+                    return Err(Error::Query(QuerySyntaxError::TooManyQueryBranches(
+                        "or clauses can not be split into more than 100 groups",
+                    )));
+                }
+                Ok(groups)
+            }
+            _ => {
+                let mut where_clauses = vec![];
+                Self::build_where_clauses_from_operations(binary_operation, &mut where_clauses)?;
+                Ok(vec![where_clauses])
+            }
+        }
+    }
+
     /// Build where clauses from operations
     pub(crate) fn build_where_clauses_from_operations(
         binary_operation: &ast::Expr,
@@ -1102,8 +1267,8 @@ impl<'a> WhereClause {
 
                 let mut in_values: Vec<Value> = Vec::new();
                 for value in list {
-                    if let ast::Expr::Value(sql_value) = value {
-                        let cbor_val = sql_value_to_platform_value(sql_value.clone()).ok_or({
+                    if is_sql_value_expr(value) {
+                        let cbor_val = sql_expr_to_platform_value(value).ok_or({
                             Error::Query(QuerySyntaxError::InvalidSQL(
                                 "Invalid query: unexpected value type",
                             ))
@@ -1124,6 +1289,45 @@ impl<'a> WhereClause {
 
                 Ok(())
             }
+            ast::Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                if *negated {
+                    return Err(Error::Query(QuerySyntaxError::Unsupported(
+                        "Invalid query: negated between clause not supported".to_string(),
+                    )));
+                }
+
+                let field_name = if let ast::Expr::Identifier(ident) = &**expr {
+                    ident.value.clone()
+                } else {
+                    return Err(Error::Query(QuerySyntaxError::InvalidBetweenClause(
+                        "Invalid query: between clause should start with an identifier",
+                    )));
+                };
+
+                let bounds = [low, high]
+                    .into_iter()
+                    .map(|bound| {
+                        sql_expr_to_platform_value(bound).ok_or(Error::Query(
+                            QuerySyntaxError::InvalidSQL(
+                                "Invalid query: between bounds should be values",
+                            ),
+                        ))
+                    })
+                    .collect::<Result<Vec<Value>, Error>>()?;
+
+                where_clauses.push(WhereClause {
+                    field: field_name,
+                    operator: WhereOperator::Between,
+                    value: Value::Array(bounds),
+                });
+
+                Ok(())
+            }
             ast::Expr::BinaryOp { left, op, right } => {
                 if *op == ast::BinaryOperator::And {
                     Self::build_where_clauses_from_operations(left, where_clauses)?;
@@ -1137,13 +1341,11 @@ impl<'a> WhereClause {
                     let identifier;
                     let value_expr;
 
-                    if matches!(&**left, ast::Expr::Identifier(_))
-                        && matches!(&**right, ast::Expr::Value(_))
-                    {
+                    if matches!(&**left, ast::Expr::Identifier(_)) && is_sql_value_expr(right) {
                         identifier = &**left;
                         value_expr = &**right;
                     } else if matches!(&**right, ast::Expr::Identifier(_))
-                        && matches!(&**left, ast::Expr::Value(_))
+                        && is_sql_value_expr(left)
                     {
                         identifier = &**right;
                         value_expr = &**left;
@@ -1160,8 +1362,8 @@ impl<'a> WhereClause {
                         panic!("unreachable: confirmed it's identifier variant");
                     };
 
-                    let value = if let ast::Expr::Value(value) = value_expr {
-                        let cbor_val = sql_value_to_platform_value(value.clone()).ok_or({
+                    let value = if is_sql_value_expr(value_expr) {
+                        let cbor_val = sql_expr_to_platform_value(value_expr).ok_or({
                             Error::Query(QuerySyntaxError::InvalidSQL(
                                 "Invalid query: unexpected value type",
                             ))
@@ -1204,6 +1406,66 @@ impl<'a> WhereClause {
             ))),
         }
     }
+
+    /// Converts the where clause to a SQL condition that parses back to the same clause,
+    /// between clauses excluding bounds are written as two range conditions.
+    pub fn to_sql(&self) -> Result<String, Error> {
+        let field = sql_identifier(&self.field);
+        let sql = match self.operator {
+            Equal | GreaterThan | GreaterThanOrEquals | LessThan | LessThanOrEquals => format!(
+                "{} {} {}",
+                field,
+                self.operator.to_string(),
+                sql_value(&self.value)?
+            ),
+            Between | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight => {
+                let (low, high) = match &self.value {
+                    Value::Array(bounds) if bounds.len() == 2 => {
+                        (sql_value(&bounds[0])?, sql_value(&bounds[1])?)
+                    }
+                    _ => {
+                        return Err(Error::Query(QuerySyntaxError::InvalidBetweenClause(
+                            "when using between operator you must provide an array of exactly two values",
+                        )))
+                    }
+                };
+                match self.operator {
+                    Between => format!("{} BETWEEN {} AND {}", field, low, high),
+                    BetweenExcludeBounds => {
+                        format!("{} > {} AND {} < {}", field, low, field, high)
+                    }
+                    BetweenExcludeLeft => {
+                        format!("{} > {} AND {} <= {}", field, low, field, high)
+                    }
+                    _ => format!("{} >= {} AND {} < {}", field, low, field, high),
+                }
+            }
+            In => format!(
+                "{} IN ({})",
+                field,
+                self.in_values()?
+                    .iter()
+                    .map(sql_value)
+                    .collect::<Result<Vec<String>, Error>>()?
+                    .join(", ")
+            ),
+            StartsWith => {
+                let prefix = self.value.as_text().ok_or(Error::Query(
+                    QuerySyntaxError::InvalidStartsWithClause(
+                        "Invalid query: startsWith takes text",
+                    ),
+                ))?;
+                if prefix.contains('%') {
+                    return Err(Error::Query(QuerySyntaxError::Unsupported(
+                        "a startsWith prefix containing % can not be represented in sql"
+                            .to_string(),
+                    )));
+                }
+                format!("{} LIKE {}", field, sql_text(&format!("{}%", prefix)))
+            }
+        };
+        Ok(sql)
+    }
 }
 
 impl<'a> From<WhereClause> for Value {
@@ -1241,7 +1503,7 @@ mod tests {
                     value: Value::Float(1.0),
                 },
             ];
-            let (_, range_clause, _) = WhereClause::group_clauses(&where_clauses)
+            let (_, range_clause, _, _) = WhereClause::group_clauses(&where_clauses)
                 .expect("expected to have groupable pair");
             range_clause.expect("expected to have range clause returned");
         }
@@ -1267,7 +1529,7 @@ mod tests {
                     value: Value::Float(0.0),
                 },
             ];
-            let (_, range_clause, _) = WhereClause::group_clauses(&where_clauses)
+            let (_, range_clause, _, _) = WhereClause::group_clauses(&where_clauses)
                 .expect("expected to have groupable pair");
             range_clause.expect("expected to have range clause returned");
         }
@@ -1,2 +1,9 @@
 /// Max index difference constant
 pub(crate) const MAX_INDEX_DIFFERENCE: u16 = 2;
+
+/// Max number of branches a query with or groups and multiple in clauses can be split into
+pub(crate) const MAX_QUERY_BRANCHES: usize = 100;
+
+/// Max number of documents a branch of a query with or groups and multiple in clauses can match,
+/// all of them are needed to sort documents of all branches together
+pub(crate) const MAX_QUERY_BRANCH_DOCUMENTS: u16 = 100;
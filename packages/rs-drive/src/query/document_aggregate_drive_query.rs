@@ -0,0 +1,314 @@
+use crate::drive::config::DriveConfig;
+use crate::drive::document::{
+    contract_document_type_path, INDEX_COUNT_TREE_KEY, INDEX_SUM_TREES_KEY,
+};
+#[cfg(feature = "full")]
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::sql::sql_identifier;
+use crate::query::{DriveQuery, Query, WhereClause};
+#[cfg(feature = "full")]
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::document_type::Index;
+use dpp::data_contract::DataContract;
+use dpp::version::PlatformVersion;
+#[cfg(feature = "full")]
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, PathQuery, SizedQuery};
+#[cfg(feature = "full")]
+use grovedb::{Error as GroveError, TransactionArg};
+
+/// The aggregate computed by a document aggregate query
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DocumentAggregate {
+    /// The number of documents matching the query
+    Count,
+    /// The sum of an integer property over the documents matching the query
+    Sum(String),
+}
+
+/// Drive query computing an aggregate over the documents matching the where clauses of a query
+///
+/// Aggregates are read from the count and sum trees of a countable or summable index, so the
+/// query can only be made of equal clauses on the first properties of the index, optionally
+/// followed by one in or range clause on the next property.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DocumentAggregateDriveQuery<'a> {
+    /// The query selecting the documents, only its where clauses are used
+    pub query: DriveQuery<'a>,
+    /// The aggregate
+    pub aggregate: DocumentAggregate,
+}
+
+impl<'a> DocumentAggregateDriveQuery<'a> {
+    /// Converts a SQL expression selecting `COUNT(*)` or `SUM(<property>)` to a
+    /// `DocumentAggregateDriveQuery`.
+    pub fn from_sql_expr(
+        sql_string: &str,
+        contract: &'a DataContract,
+        config: &DriveConfig,
+    ) -> Result<Self, Error> {
+        let (query, aggregate) =
+            DriveQuery::from_sql_expr_with_aggregate(sql_string, contract, config)?;
+        let aggregate = aggregate.ok_or(Error::Query(QuerySyntaxError::InvalidAggregateQuery(
+            "aggregate queries must select COUNT(*) or SUM(<property>)",
+        )))?;
+        Ok(DocumentAggregateDriveQuery { query, aggregate })
+    }
+
+    /// Converts the query to a SQL statement that `from_sql_expr` parses back to the same query.
+    pub fn to_sql(&self) -> Result<String, Error> {
+        let projection = match &self.aggregate {
+            DocumentAggregate::Count => "COUNT(*)".to_string(),
+            DocumentAggregate::Sum(property_name) => {
+                format!("SUM({})", sql_identifier(property_name))
+            }
+        };
+        self.query.to_sql_selecting(&projection)
+    }
+
+    /// Returns the in or range clause of the query if there is one.
+    fn non_equal_clause(&self) -> Result<Option<&WhereClause>, Error> {
+        let internal_clauses = &self.query.internal_clauses;
+        if !internal_clauses.or_clauses.is_empty()
+            || !internal_clauses.other_in_clauses.is_empty()
+            || internal_clauses.is_for_primary_key()
+            || self.query.start_at.is_some()
+        {
+            return Err(Error::Query(QuerySyntaxError::InvalidAggregateQuery(
+                "aggregate queries only support equal clauses and one in or range clause on indexed properties",
+            )));
+        }
+        match (&internal_clauses.in_clause, &internal_clauses.range_clause) {
+            (Some(_), Some(_)) => Err(Error::Query(QuerySyntaxError::InvalidAggregateQuery(
+                "aggregate queries can not have both an in and a range clause",
+            ))),
+            (Some(where_clause), None) | (None, Some(where_clause)) => Ok(Some(where_clause)),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Finds an index keeping the aggregate whose first properties are the ones of the equal
+    /// clauses, followed by the property of the in or range clause if there is one.
+    pub fn find_aggregate_index(&self) -> Result<&Index, Error> {
+        let non_equal_clause = self.non_equal_clause()?;
+        let equal_clauses = &self.query.internal_clauses.equal_clauses;
+        let equal_clause_count = equal_clauses.len();
+
+        self.query
+            .document_type
+            .indices()
+            .iter()
+            .find(|index| {
+                let keeps_aggregate = match &self.aggregate {
+                    DocumentAggregate::Count => index.countable,
+                    DocumentAggregate::Sum(property_name) => index.summable.contains(property_name),
+                };
+                keeps_aggregate
+                    && index.properties.len()
+                        >= equal_clause_count + non_equal_clause.is_some() as usize
+                    && index.properties[..equal_clause_count]
+                        .iter()
+                        .all(|property| equal_clauses.contains_key(&property.name))
+                    && non_equal_clause.map_or(true, |where_clause| {
+                        index.properties[equal_clause_count].name == where_clause.field
+                    })
+            })
+            .ok_or(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(
+                    "aggregate query must be on a countable or summable index",
+                ),
+            ))
+    }
+
+    /// Operations to construct a path query.
+    ///
+    /// The path query returns one sum tree per distinct combination of index values matching
+    /// the query, the aggregate is the total of their sums.
+    pub fn construct_path_query(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<PathQuery, Error> {
+        let index = self.find_aggregate_index()?;
+        let non_equal_clause = self.non_equal_clause()?;
+        let equal_clauses = &self.query.internal_clauses.equal_clauses;
+        let document_type = self.query.document_type;
+
+        let mut path = contract_document_type_path(
+            self.query.contract.id_ref().as_bytes(),
+            document_type.name().as_str(),
+        )
+        .into_iter()
+        .map(|a| a.to_vec())
+        .collect::<Vec<Vec<u8>>>();
+
+        let (equal_properties, remaining_properties) =
+            index.properties.split_at(equal_clauses.len());
+
+        for property in equal_properties {
+            let where_clause = equal_clauses.get(&property.name).ok_or(Error::Drive(
+                DriveError::CorruptedCodeExecution("index property must have an equal clause"),
+            ))?;
+            path.push(property.name.as_bytes().to_vec());
+            path.push(document_type.serialize_value_for_key(
+                property.name.as_str(),
+                &where_clause.value,
+                platform_version,
+            )?);
+        }
+
+        let mut aggregate_path = match &self.aggregate {
+            DocumentAggregate::Count => vec![vec![INDEX_COUNT_TREE_KEY]],
+            DocumentAggregate::Sum(property_name) => {
+                vec![vec![INDEX_SUM_TREES_KEY], property_name.as_bytes().to_vec()]
+            }
+        };
+
+        let Some((next_property, last_properties)) = remaining_properties.split_first() else {
+            // all the index values are known, there is a single aggregate tree to get
+            let aggregate_key =
+                aggregate_path
+                    .pop()
+                    .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                        "aggregate path can not be empty",
+                    )))?;
+            path.extend(aggregate_path);
+            let mut query = Query::new();
+            query.insert_key(aggregate_key);
+            return Ok(PathQuery::new(path, SizedQuery::new(query, None, None)));
+        };
+
+        path.push(next_property.name.as_bytes().to_vec());
+
+        let mut query = match non_equal_clause {
+            Some(where_clause) => {
+                where_clause.to_path_query(document_type, &None, true, platform_version)?
+            }
+            None => {
+                let mut query = Query::new();
+                query.insert_all();
+                query
+            }
+        };
+
+        // every value of the last properties leads to its own aggregate trees, we go through
+        // all of them starting from the deepest level
+        let mut subquery_path = aggregate_path;
+        let mut subquery: Option<Query> = None;
+        for property in last_properties.iter().rev() {
+            let mut level_query = Query::new();
+            level_query.insert_all();
+            level_query.set_subquery_path(subquery_path);
+            if let Some(subquery) = subquery {
+                level_query.set_subquery(subquery);
+            }
+            subquery = Some(level_query);
+            subquery_path = vec![property.name.as_bytes().to_vec()];
+        }
+
+        query.set_subquery_path(subquery_path);
+        if let Some(subquery) = subquery {
+            query.set_subquery(subquery);
+        }
+
+        Ok(PathQuery::new(path, SizedQuery::new(query, None, None)))
+    }
+
+    /// Adds up the sums of the aggregate trees returned by the path query.
+    pub(crate) fn total_of_aggregate_elements<I: IntoIterator<Item = Element>>(
+        elements: I,
+    ) -> Result<i64, Error> {
+        elements.into_iter().try_fold(0i64, |total, element| {
+            let Element::SumTree(_, sum, _) = element else {
+                return Err(Error::Drive(DriveError::CorruptedElementType(
+                    "index aggregates must be sum trees",
+                )));
+            };
+            total
+                .checked_add(sum)
+                .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                    "index aggregate overflow",
+                )))
+        })
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes the query with no proof and returns the aggregate and fee.
+    pub fn execute_no_proof(
+        &self,
+        drive: &Drive,
+        block_info: Option<BlockInfo>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(i64, u64), Error> {
+        let mut drive_operations = vec![];
+        let path_query = self.construct_path_query(platform_version)?;
+        let query_result = drive.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            &mut drive_operations,
+            &platform_version.drive,
+        );
+        let value = match query_result {
+            Err(Error::GroveDB(GroveError::PathKeyNotFound(_)))
+            | Err(Error::GroveDB(GroveError::PathNotFound(_)))
+            | Err(Error::GroveDB(GroveError::PathParentLayerNotFound(_))) => 0,
+            _ => {
+                let (elements, _) = query_result?;
+                Self::total_of_aggregate_elements(elements.to_elements())?
+            }
+        };
+        let cost = if let Some(block_info) = block_info {
+            let fee_result = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                &block_info.epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+            )?;
+            fee_result.processing_fee
+        } else {
+            0
+        };
+        Ok((value, cost))
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes the query with proof and returns the proof and fee.
+    pub fn execute_with_proof(
+        &self,
+        drive: &Drive,
+        block_info: Option<BlockInfo>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<u8>, u64), Error> {
+        let mut drive_operations = vec![];
+        let path_query = self.construct_path_query(platform_version)?;
+        let proof = drive.grove_get_proved_path_query(
+            &path_query,
+            false,
+            transaction,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+        let cost = if let Some(block_info) = block_info {
+            let fee_result = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                &block_info.epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+            )?;
+            fee_result.processing_fee
+        } else {
+            0
+        };
+        Ok((proof, cost))
+    }
+}
@@ -28,7 +28,7 @@
 //
 
 #[cfg(any(feature = "full", feature = "verify"))]
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 #[cfg(any(feature = "full", feature = "verify"))]
 use std::ops::BitXor;
 
@@ -42,6 +42,8 @@ pub use grovedb::{PathQuery, Query, QueryItem, SizedQuery};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use indexmap::IndexMap;
+#[cfg(any(feature = "full", feature = "verify"))]
+use integer_encoding::VarInt;
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use sqlparser::ast;
@@ -50,11 +52,7 @@ use sqlparser::ast::TableFactor::Table;
 #[cfg(any(feature = "full", feature = "verify"))]
 use sqlparser::ast::Value::Number;
 #[cfg(any(feature = "full", feature = "verify"))]
-use sqlparser::ast::{OrderByExpr, Select, Statement};
-#[cfg(any(feature = "full", feature = "verify"))]
-use sqlparser::dialect::GenericDialect;
-#[cfg(any(feature = "full", feature = "verify"))]
-use sqlparser::parser::Parser;
+use sqlparser::ast::{OrderByExpr, Select};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 pub use conditions::WhereClause;
@@ -83,6 +81,8 @@ use crate::drive::Drive;
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::error::drive::DriveError;
 #[cfg(any(feature = "full", feature = "verify"))]
+use crate::error::proof::ProofError;
+#[cfg(any(feature = "full", feature = "verify"))]
 use crate::error::query::QuerySyntaxError;
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::error::Error;
@@ -118,14 +118,22 @@ pub mod conditions;
 #[cfg(any(feature = "full", feature = "verify"))]
 mod defaults;
 #[cfg(any(feature = "full", feature = "verify"))]
+mod document_aggregate_drive_query;
+#[cfg(any(feature = "full", feature = "verify"))]
 pub mod ordering;
 #[cfg(any(feature = "full", feature = "verify"))]
 mod single_document_drive_query;
+#[cfg(any(feature = "full", feature = "verify"))]
+mod sql;
 #[cfg(feature = "full")]
 mod test_index;
 
+#[cfg(any(feature = "full", feature = "verify"))]
+pub use document_aggregate_drive_query::{DocumentAggregate, DocumentAggregateDriveQuery};
 #[cfg(any(feature = "full", feature = "verify"))]
 pub use single_document_drive_query::SingleDocumentDriveQuery;
+#[cfg(any(feature = "full", feature = "verify"))]
+use sql::{parse_sql_statement, sql_identifier};
 
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
@@ -145,12 +153,35 @@ pub struct InternalClauses {
     pub range_clause: Option<WhereClause>,
     /// Equal clause
     pub equal_clauses: BTreeMap<String, WhereClause>,
+    /// In clauses on other fields than the in clause, the query is split in a branch for every
+    /// combination of their values
+    pub other_in_clauses: Vec<WhereClause>,
+    /// Alternative groups of clauses, a document matches if it matches at least one of them.
+    /// When set all other clauses are empty
+    pub or_clauses: Vec<InternalClauses>,
 }
 
 impl InternalClauses {
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns true if the clause is a valid format.
     pub fn verify(&self) -> bool {
+        if !self.or_clauses.is_empty() {
+            // Alternatives replace all other clauses and can not be nested
+            return self.or_clauses.len() > 1
+                && self.primary_key_in_clause.is_none()
+                && self.primary_key_equal_clause.is_none()
+                && self.in_clause.is_none()
+                && self.other_in_clauses.is_empty()
+                && self.range_clause.is_none()
+                && self.equal_clauses.is_empty()
+                && self
+                    .or_clauses
+                    .iter()
+                    .all(|or_clause| or_clause.or_clauses.is_empty() && or_clause.verify());
+        }
+        if self.in_clause.is_none() && !self.other_in_clauses.is_empty() {
+            return false;
+        }
         // There can only be 1 primary key clause, or many other clauses
         if self
             .primary_key_in_clause
@@ -180,6 +211,134 @@ impl InternalClauses {
             && self.equal_clauses.is_empty()
             && self.primary_key_in_clause.is_none()
             && self.primary_key_equal_clause.is_none()
+            && self.or_clauses.is_empty()
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Converts the clauses to a SQL condition, alternative groups of clauses are written
+    /// in parentheses joined by `OR`.
+    pub fn to_sql(&self) -> Result<String, Error> {
+        if !self.or_clauses.is_empty() {
+            return Ok(self
+                .or_clauses
+                .iter()
+                .map(|or_clause| Ok(format!("({})", or_clause.to_sql()?)))
+                .collect::<Result<Vec<String>, Error>>()?
+                .join(" OR "));
+        }
+        let conditions = self
+            .primary_key_equal_clause
+            .iter()
+            .chain(self.primary_key_in_clause.iter())
+            .chain(self.equal_clauses.values())
+            .chain(self.in_clause.iter())
+            .chain(self.other_in_clauses.iter())
+            .chain(self.range_clause.iter())
+            .map(WhereClause::to_sql)
+            .collect::<Result<Vec<String>, Error>>()?;
+        Ok(conditions.join(" AND "))
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns true if the query must be split into several branches, because it has
+    /// alternative groups of clauses or more than one in clause.
+    pub fn is_disjunctive(&self) -> bool {
+        !self.or_clauses.is_empty() || !self.other_in_clauses.is_empty()
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Extracts groups of `WhereClause`s, any of which a document must match, and returns
+    /// them as type `InternalClauses`.
+    pub fn extract_from_clause_groups(
+        mut where_clause_groups: Vec<Vec<WhereClause>>,
+    ) -> Result<Self, Error> {
+        if where_clause_groups.len() > defaults::MAX_QUERY_BRANCHES {
+            return Err(Error::Query(QuerySyntaxError::TooManyQueryBranches(
+                "or clauses can not be split into more than 100 groups",
+            )));
+        }
+        if where_clause_groups.len() <= 1 {
+            return Self::extract_from_clauses(where_clause_groups.pop().unwrap_or_default());
+        }
+        let or_clauses = where_clause_groups
+            .into_iter()
+            .map(Self::extract_from_clauses)
+            .collect::<Result<Vec<Self>, Error>>()?;
+
+        Ok(InternalClauses {
+            or_clauses,
+            ..Default::default()
+        })
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the branches of the query, each of them with at most one in clause and no
+    /// alternatives. `in_field_for_branch` picks which in clause is kept in a branch, other
+    /// in clauses are split into equality clauses.
+    fn branches(
+        &self,
+        in_field_for_branch: &dyn Fn(&InternalClauses) -> Option<String>,
+    ) -> Result<Vec<InternalClauses>, Error> {
+        if !self.or_clauses.is_empty() {
+            let mut branches = vec![];
+            for or_clause in &self.or_clauses {
+                branches.extend(or_clause.branches(in_field_for_branch)?);
+                if branches.len() > defaults::MAX_QUERY_BRANCHES {
+                    return Err(Error::Query(QuerySyntaxError::TooManyQueryBranches(
+                        "query can not be split into more than 100 branches",
+                    )));
+                }
+            }
+            return Ok(branches);
+        }
+
+        let Some(in_clause) = &self.in_clause else {
+            return Ok(vec![self.clone()]);
+        };
+        if self.other_in_clauses.is_empty() {
+            return Ok(vec![self.clone()]);
+        }
+
+        let mut in_clauses = vec![in_clause.clone()];
+        in_clauses.extend(self.other_in_clauses.iter().cloned());
+
+        let kept_in_field = in_field_for_branch(self);
+        let (kept_in_clauses, split_in_clauses): (Vec<WhereClause>, Vec<WhereClause>) = in_clauses
+            .into_iter()
+            .partition(|in_clause| Some(&in_clause.field) == kept_in_field.as_ref());
+
+        let mut branches = vec![InternalClauses {
+            in_clause: kept_in_clauses.into_iter().next(),
+            other_in_clauses: vec![],
+            ..self.clone()
+        }];
+        for split_in_clause in split_in_clauses {
+            let in_values = split_in_clause.in_values()?;
+            if branches.len() * in_values.len() > defaults::MAX_QUERY_BRANCHES {
+                return Err(Error::Query(QuerySyntaxError::TooManyQueryBranches(
+                    "in clauses can not be split into more than 100 branches",
+                )));
+            }
+            let field = &split_in_clause.field;
+            branches = branches
+                .iter()
+                .flat_map(|branch| {
+                    in_values.iter().map(move |in_value| {
+                        let mut branch = branch.clone();
+                        branch.equal_clauses.insert(
+                            field.clone(),
+                            WhereClause {
+                                field: field.clone(),
+                                operator: WhereOperator::Equal,
+                                value: in_value.clone(),
+                            },
+                        );
+                        branch
+                    })
+                })
+                .collect();
+        }
+        Ok(branches)
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -207,7 +366,7 @@ impl InternalClauses {
             })
             .collect::<Vec<WhereClause>>();
 
-        let (equal_clauses, range_clause, in_clause) =
+        let (equal_clauses, range_clause, in_clause, other_in_clauses) =
             WhereClause::group_clauses(&all_where_clauses)?;
 
         let primary_key_equal_clause = match primary_key_equal_clauses_array.len() {
@@ -246,6 +405,8 @@ impl InternalClauses {
             in_clause,
             range_clause,
             equal_clauses,
+            other_in_clauses,
+            or_clauses: vec![],
         };
 
         match internal_clauses.verify() {
@@ -257,6 +418,34 @@ impl InternalClauses {
     }
 }
 
+impl InternalClauses {
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the elements of the `where` array of the query, alternative groups of clauses
+    /// are returned as a single `["or", [[clause, ...], ...]]` element.
+    pub fn into_where_values(self) -> Vec<Value> {
+        if self.or_clauses.is_empty() {
+            let where_clauses: Vec<WhereClause> = self.into();
+            return where_clauses.into_iter().map(Value::from).collect();
+        }
+
+        let alternatives = self
+            .or_clauses
+            .into_iter()
+            .map(|or_clause| {
+                let where_clauses: Vec<WhereClause> = or_clause.into();
+                Value::Array(where_clauses.into_iter().map(Value::from).collect())
+            })
+            .collect();
+
+        vec![Value::Array(vec![
+            Value::Text(conditions::OR_CLAUSE.to_string()),
+            Value::Array(alternatives),
+        ])]
+    }
+}
+
+/// Alternative groups of clauses can not be represented as a list of where clauses and are
+/// not included, use [InternalClauses::into_where_values] to keep them.
 impl From<InternalClauses> for Vec<WhereClause> {
     fn from(clauses: InternalClauses) -> Self {
         let mut result: Self = clauses.equal_clauses.into_values().collect();
@@ -264,6 +453,7 @@ impl From<InternalClauses> for Vec<WhereClause> {
         if let Some(clause) = clauses.in_clause {
             result.push(clause);
         };
+        result.extend(clauses.other_in_clauses);
         if let Some(clause) = clauses.primary_key_equal_clause {
             result.push(clause);
         };
@@ -453,23 +643,12 @@ impl<'a> DriveQuery<'a> {
             .remove_optional_integer("blockTime")
             .map_err(|e| Error::Protocol(ProtocolError::ValueError(e)))?;
 
-        let all_where_clauses: Vec<WhereClause> =
+        let where_clause_groups: Vec<Vec<WhereClause>> =
             query_document
                 .remove("where")
                 .map_or(Ok(vec![]), |id_cbor| {
                     if let Value::Array(clauses) = id_cbor {
-                        clauses
-                            .iter()
-                            .map(|where_clause| {
-                                if let Value::Array(clauses_components) = where_clause {
-                                    WhereClause::from_components(clauses_components)
-                                } else {
-                                    Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
-                                        "where clause must be an array",
-                                    )))
-                                }
-                            })
-                            .collect::<Result<Vec<WhereClause>, Error>>()
+                        WhereClause::groups_from_components(&clauses)
                     } else {
                         Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
                             "where clause must be an array",
@@ -477,7 +656,7 @@ impl<'a> DriveQuery<'a> {
                     }
                 })?;
 
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let internal_clauses = InternalClauses::extract_from_clause_groups(where_clause_groups)?;
 
         let start_at_option = query_document.remove("startAt");
         let start_after_option = query_document.remove("startAfter");
@@ -583,26 +762,15 @@ impl<'a> DriveQuery<'a> {
                 config.max_query_limit
             ))))?;
 
-        let all_where_clauses: Vec<WhereClause> = match where_clause {
+        let where_clause_groups: Vec<Vec<WhereClause>> = match where_clause {
             Value::Null => Ok(vec![]),
-            Value::Array(clauses) => clauses
-                .iter()
-                .map(|where_clause| {
-                    if let Value::Array(clauses_components) = where_clause {
-                        WhereClause::from_components(clauses_components)
-                    } else {
-                        Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
-                            "where clause must be an array",
-                        )))
-                    }
-                })
-                .collect::<Result<Vec<WhereClause>, Error>>(),
+            Value::Array(clauses) => WhereClause::groups_from_components(&clauses),
             _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
                 "where clause must be an array",
             ))),
         }?;
 
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let internal_clauses = InternalClauses::extract_from_clause_groups(where_clause_groups)?;
 
         let order_by: IndexMap<String, OrderClause> = order_by
             .map_or(vec![], |id_cbor| {
@@ -640,37 +808,50 @@ impl<'a> DriveQuery<'a> {
 
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Converts a SQL expression to a `DriveQuery`.
+    ///
+    /// Supported statements are of the form
+    /// `SELECT * FROM <document type> [WHERE ...] [ORDER BY ...] [LIMIT n] [OFFSET n]`,
+    /// conditions can use `=`, `<`, `<=`, `>`, `>=`, `IN (...)`, `BETWEEN ... AND ...` and
+    /// `LIKE 'prefix%'`, combined with `AND`, `OR` and parentheses. Parse errors give the line
+    /// and column of the problem.
     pub fn from_sql_expr(
         sql_string: &str,
         contract: &'a DataContract,
         config: &DriveConfig,
     ) -> Result<Self, Error> {
-        let dialect: GenericDialect = sqlparser::dialect::GenericDialect {};
-        let statements: Vec<Statement> = Parser::parse_sql(&dialect, sql_string)
-            .map_err(|_| Error::Query(QuerySyntaxError::InvalidSQL("Issue parsing sql")))?;
-
-        // Should ideally iterate over each statement
-        let first_statement =
-            statements
-                .get(0)
-                .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-                    "Issue parsing sql",
-                )))?;
+        let (query, aggregate) = Self::from_sql_expr_with_aggregate(sql_string, contract, config)?;
+        if aggregate.is_some() {
+            return Err(Error::Query(QuerySyntaxError::InvalidSQL(
+                "Invalid query: aggregates can only be used in aggregate queries",
+            )));
+        }
+        Ok(query)
+    }
 
-        let query: &ast::Query = match first_statement {
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Converts a SQL expression to a `DriveQuery` and the aggregate it selects, if it selects
+    /// `COUNT(*)` or `SUM(<property>)` instead of `*`.
+    pub(crate) fn from_sql_expr_with_aggregate(
+        sql_string: &str,
+        contract: &'a DataContract,
+        config: &DriveConfig,
+    ) -> Result<(Self, Option<DocumentAggregate>), Error> {
+        let statement = parse_sql_statement(sql_string)?;
+
+        let query: &ast::Query = match &statement {
             ast::Statement::Query(query_struct) => Some(query_struct),
             _ => None,
         }
         .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-            "Issue parsing sql",
+            "Invalid query: only select statements are supported",
         )))?;
 
         let limit: u16 = if let Some(limit_expr) = &query.limit {
             match limit_expr {
-                ast::Expr::Value(Number(num_string, _)) => {
-                    let cast_num_string: &String = num_string;
-                    cast_num_string.parse::<u16>().ok()
-                }
+                ast::Expr::Value(Number(num_string, _)) => num_string
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|limit| *limit > 0 && *limit <= config.max_query_limit),
                 _ => None,
             }
             .ok_or(Error::Query(QuerySyntaxError::InvalidLimit(format!(
@@ -681,12 +862,29 @@ impl<'a> DriveQuery<'a> {
             config.default_query_limit
         };
 
+        let offset: Option<u16> = query
+            .offset
+            .as_ref()
+            .map(|offset| {
+                match &offset.value {
+                    ast::Expr::Value(Number(num_string, _)) => num_string.parse::<u16>().ok(),
+                    _ => None,
+                }
+                .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
+                    "Invalid query: offset must be a number lower than 65536",
+                )))
+            })
+            .transpose()?;
+
         let order_by: IndexMap<String, OrderClause> = query
             .order_by
             .iter()
             .map(|order_exp: &OrderByExpr| {
                 let ascending = order_exp.asc.is_none() || order_exp.asc.unwrap();
-                let field = order_exp.expr.to_string();
+                let field = match &order_exp.expr {
+                    ast::Expr::Identifier(ident) => ident.value.clone(),
+                    expr => expr.to_string(),
+                };
                 (field.clone(), OrderClause { field, ascending })
             })
             .collect::<IndexMap<String, OrderClause>>();
@@ -700,6 +898,38 @@ impl<'a> DriveQuery<'a> {
             "Issue parsing sql",
         )))?;
 
+        // Only everything or an aggregate can be selected
+        let aggregate = match select.projection.as_slice() {
+            [ast::SelectItem::Wildcard] => None,
+            [ast::SelectItem::UnnamedExpr(ast::Expr::Function(function))] => {
+                let function_name = function.name.to_string().to_lowercase();
+                let arguments = function
+                    .args
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect::<Vec<String>>();
+                match (function_name.as_str(), arguments.as_slice()) {
+                    ("count", [argument]) if argument == "*" => Some(DocumentAggregate::Count),
+                    ("sum", [property_name]) => Some(DocumentAggregate::Sum(
+                        property_name
+                            .trim_matches('"')
+                            .replace("\"\"", "\""),
+                    )),
+                    _ => {
+                        return Err(Error::Query(QuerySyntaxError::Unsupported(format!(
+                            "Invalid query: unsupported aggregate {}, only COUNT(*) and SUM(<property>) are supported",
+                            function
+                        ))))
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::Query(QuerySyntaxError::InvalidSQL(
+                    "Invalid query: only * or an aggregate can be selected",
+                )))
+            }
+        };
+
         // Get the document type from the 'from' section
         let document_type_name = match &select
             .from
@@ -732,57 +962,101 @@ impl<'a> DriveQuery<'a> {
         // Restrictions
         // only binary where clauses are supported
         // i.e. [<fieldname>, <operator>, <value>]
-        // [and] is used to separate where clauses
-        // currently where clauses are either binary operations or list descriptions (in clauses)
-        // hence once [and] is encountered [left] and [right] must be only one of the above
-        // i.e other where clauses
-        // e.g. firstname = wisdom and lastname = ogwu
-        // if op is not [and] then [left] or [right] must not be a binary operation or list description
-        let mut all_where_clauses: Vec<WhereClause> = Vec::new();
+        // [and] and [or] are used to combine where clauses, parentheses can be used to group them
+        // currently where clauses are either binary operations, list descriptions (in clauses)
+        // or between clauses
+        // e.g. firstname = wisdom and (lastname = ogwu or age between 20 and 30)
+        // the selection is expanded into groups of where clauses joined by [and],
+        // a document matches if it matches any of the groups
         let selection_tree = select.selection.as_ref();
 
         // Where clauses are optional
-        if let Some(selection_tree) = selection_tree {
-            WhereClause::build_where_clauses_from_operations(
-                selection_tree,
-                &mut all_where_clauses,
-            )?;
+        let where_clause_groups = if let Some(selection_tree) = selection_tree {
+            WhereClause::build_where_clause_groups_from_operations(selection_tree)?
+        } else {
+            vec![]
+        };
+
+        let internal_clauses = InternalClauses::extract_from_clause_groups(where_clause_groups)?;
+
+        Ok((
+            DriveQuery {
+                contract,
+                document_type: document_type.as_ref(),
+                internal_clauses,
+                offset,
+                limit: Some(limit),
+                order_by,
+                start_at: None,
+                start_at_included: true,
+                block_time_ms: None,
+            },
+            aggregate,
+        ))
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Converts the query to a SQL statement that `from_sql_expr` parses back to the same query.
+    ///
+    /// Queries starting at a document or at a block time can not be represented in SQL.
+    pub fn to_sql(&self) -> Result<String, Error> {
+        self.to_sql_selecting("*")
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Converts the query to a SQL statement with the given projection.
+    pub(crate) fn to_sql_selecting(&self, projection: &str) -> Result<String, Error> {
+        if self.start_at.is_some() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with start at or start after can not be represented in sql".to_string(),
+            )));
+        }
+        if self.block_time_ms.is_some() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with a block time can not be represented in sql".to_string(),
+            )));
         }
 
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let mut sql = format!(
+            "SELECT {} FROM {}",
+            projection,
+            sql_identifier(self.document_type.name())
+        );
 
-        let start_at_option = None; //todo
-        let start_after_option = None; //todo
-        let mut start_at_included = true;
-        let mut start_option: Option<Value> = None;
+        if !self.internal_clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&self.internal_clauses.to_sql()?);
+        }
 
-        if start_after_option.is_some() {
-            start_option = start_after_option;
-            start_at_included = false;
-        } else if start_at_option.is_some() {
-            start_option = start_at_option;
-            start_at_included = true;
+        if !self.order_by.is_empty() {
+            let order_by = self
+                .order_by
+                .values()
+                .map(|order_clause| {
+                    format!(
+                        "{} {}",
+                        sql_identifier(&order_clause.field),
+                        if order_clause.ascending {
+                            "ASC"
+                        } else {
+                            "DESC"
+                        }
+                    )
+                })
+                .collect::<Vec<String>>();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order_by.join(", "));
         }
 
-        let start_at: Option<[u8; 32]> = start_option
-            .map(|v| {
-                v.into_identifier()
-                    .map_err(|e| Error::Protocol(ProtocolError::ValueError(e)))
-                    .map(|identifier| identifier.into_buffer())
-            })
-            .transpose()?;
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
 
-        Ok(DriveQuery {
-            contract,
-            document_type: document_type.as_ref(),
-            internal_clauses,
-            offset: None,
-            limit: Some(limit),
-            order_by,
-            start_at,
-            start_at_included,
-            block_time_ms: None,
-        })
+        if let Some(offset) = self.offset {
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        Ok(sql)
     }
 
     /// Serialize drive query to CBOR format.
@@ -848,6 +1122,13 @@ impl<'a> DriveQuery<'a> {
             .map(|a| a.to_vec())
             .collect::<Vec<Vec<u8>>>();
 
+        if self.internal_clauses.is_disjunctive() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with or clauses or several in clauses have a path query per branch"
+                    .to_string(),
+            )));
+        }
+
         let (starts_at_document, start_at_path_query) = match &self.start_at {
             None => Ok((None, None)),
             Some(starts_at) => {
@@ -942,6 +1223,12 @@ impl<'a> DriveQuery<'a> {
             .into_iter()
             .map(|a| a.to_vec())
             .collect::<Vec<Vec<u8>>>();
+        if self.internal_clauses.is_disjunctive() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with or clauses or several in clauses have a path query per branch"
+                    .to_string(),
+            )));
+        }
         let starts_at_document = starts_at_document
             .map(|starts_at_document| (starts_at_document, self.start_at_included));
         if self.is_for_primary_key() {
@@ -1127,6 +1414,317 @@ impl<'a> DriveQuery<'a> {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the queries for the branches of a query with alternative groups of clauses or
+    /// several in clauses.
+    ///
+    /// Every branch is queried for all the documents it matches, so that documents of all
+    /// branches can be sorted by the order by clauses before the offset and limit are applied,
+    /// see `merge_branch_documents`. A branch can match at most
+    /// `MAX_QUERY_BRANCH_DOCUMENTS` documents, one more is queried to find out if there are more.
+    pub fn branch_queries(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveQuery<'a>>, Error> {
+        if self.start_at.is_some() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "start at is not supported for queries with or clauses or several in clauses"
+                    .to_string(),
+            )));
+        }
+
+        let all_where_fields = Self::where_clause_fields(&self.internal_clauses);
+
+        Ok(self
+            .internal_clauses
+            .branches(&|internal_clauses| {
+                self.in_field_for_branch(internal_clauses, &all_where_fields, platform_version)
+            })?
+            .into_iter()
+            .map(|internal_clauses| {
+                let mut branch_query = self.branch_query(internal_clauses, &all_where_fields);
+                branch_query.limit = Some(defaults::MAX_QUERY_BRANCH_DOCUMENTS + 1);
+                branch_query
+            })
+            .collect())
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Merges documents found by the branches of a disjunctive query.
+    ///
+    /// Documents found by several branches are kept once, all documents are sorted by the order
+    /// by clauses, ties being broken by document id, then the offset and limit of the query are
+    /// applied. Returns the serialized documents and the number of skipped documents.
+    pub(crate) fn merge_branch_documents(
+        &self,
+        branch_documents: Vec<Vec<Vec<u8>>>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        let mut documents = BTreeMap::new();
+        for serialized_documents in branch_documents {
+            if serialized_documents.len() > defaults::MAX_QUERY_BRANCH_DOCUMENTS as usize {
+                return Err(Error::Query(QuerySyntaxError::TooManyDocumentsInQueryBranch(
+                    "a branch of a query with or clauses or several in clauses can not match more than 100 documents",
+                )));
+            }
+            for serialized_document in serialized_documents {
+                let document = Document::from_bytes(
+                    serialized_document.as_slice(),
+                    self.document_type,
+                    platform_version,
+                )?;
+                if documents.contains_key(&document.id()) {
+                    continue;
+                }
+                // Values are serialized the same way as in indexes, so they sort the same way
+                let order_values = self
+                    .order_by
+                    .keys()
+                    .map(|field| {
+                        document.get_raw_for_document_type(
+                            field,
+                            self.document_type,
+                            None,
+                            platform_version,
+                        )
+                    })
+                    .collect::<Result<Vec<Option<Vec<u8>>>, ProtocolError>>()?;
+                documents.insert(document.id(), (order_values, serialized_document));
+            }
+        }
+
+        // Documents are sorted by id, the stable sort keeps them that way for equal values
+        let mut documents: Vec<(Vec<Option<Vec<u8>>>, Vec<u8>)> = documents.into_values().collect();
+        documents.sort_by(|(left_values, _), (right_values, _)| {
+            self.order_by
+                .values()
+                .zip(left_values.iter().zip(right_values.iter()))
+                .map(|(order_clause, (left_value, right_value))| {
+                    if order_clause.ascending {
+                        left_value.cmp(right_value)
+                    } else {
+                        right_value.cmp(left_value)
+                    }
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let offset = self.offset.unwrap_or_default() as usize;
+        let skipped = offset.min(documents.len()) as u16;
+        let documents = documents
+            .into_iter()
+            .skip(offset)
+            .take(self.limit.map_or(usize::MAX, usize::from))
+            .map(|(_, serialized_document)| serialized_document)
+            .collect();
+
+        Ok((documents, skipped))
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Splits the proof of a disjunctive query into the proofs of its branches, each of them
+    /// is prefixed with its length.
+    pub(crate) fn split_branch_proofs(proof: &[u8]) -> Result<Vec<&[u8]>, Error> {
+        let mut branch_proofs = vec![];
+        let mut remaining = proof;
+        while !remaining.is_empty() {
+            let (length, length_size) = usize::decode_var(remaining).ok_or(Error::Proof(
+                ProofError::CorruptedProof("invalid length of a query branch proof".to_string()),
+            ))?;
+            let rest = &remaining[length_size..];
+            if rest.len() < length {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "query branch proof is truncated".to_string(),
+                )));
+            }
+            let (branch_proof, rest) = rest.split_at(length);
+            branch_proofs.push(branch_proof);
+            remaining = rest;
+        }
+        Ok(branch_proofs)
+    }
+
+    #[cfg(feature = "full")]
+    /// Proves the documents of every branch of a disjunctive query, proofs of the branches are
+    /// concatenated, each of them prefixed with its length.
+    fn prove_branches(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let mut proof = vec![];
+        for branch_query in self.branch_queries(platform_version)? {
+            let path_query = branch_query.construct_path_query_operations(
+                drive,
+                true,
+                transaction,
+                drive_operations,
+                platform_version,
+            )?;
+            let branch_proof = drive.grove_get_proved_path_query(
+                &path_query,
+                false,
+                transaction,
+                drive_operations,
+                &platform_version.drive,
+            )?;
+            proof.extend(branch_proof.len().encode_var_vec());
+            proof.extend(branch_proof);
+        }
+        Ok(proof)
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes every branch of a disjunctive query and merges their documents.
+    fn execute_branches_no_proof(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        let branch_documents = self
+            .branch_queries(platform_version)?
+            .iter()
+            .map(|branch_query| {
+                branch_query
+                    .execute_raw_results_no_proof_internal(
+                        drive,
+                        transaction,
+                        drive_operations,
+                        platform_version,
+                    )
+                    .map(|(documents, _)| documents)
+            })
+            .collect::<Result<Vec<Vec<Vec<u8>>>, Error>>()?;
+        self.merge_branch_documents(branch_documents, platform_version)
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the query for a single branch of a disjunctive query.
+    ///
+    /// Order by clauses on fields used only by other branches are left out, so that they do not
+    /// get in the way of finding an index for the branch.
+    fn branch_query(
+        &self,
+        internal_clauses: InternalClauses,
+        all_where_fields: &BTreeSet<String>,
+    ) -> DriveQuery<'a> {
+        let branch_where_fields = Self::where_clause_fields(&internal_clauses);
+        let order_by = self
+            .order_by
+            .iter()
+            .filter(|(field, _)| {
+                branch_where_fields.contains(*field) || !all_where_fields.contains(*field)
+            })
+            .map(|(field, order_clause)| (field.clone(), order_clause.clone()))
+            .collect();
+
+        DriveQuery {
+            contract: self.contract,
+            document_type: self.document_type,
+            internal_clauses,
+            offset: None,
+            limit: None,
+            order_by,
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: self.block_time_ms,
+        }
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the field of the in clause to keep when splitting clauses with several in clauses,
+    /// that is the first one for which an index can be found. Returns `None` if all in clauses
+    /// must be split into equality clauses.
+    fn in_field_for_branch(
+        &self,
+        internal_clauses: &InternalClauses,
+        all_where_fields: &BTreeSet<String>,
+        platform_version: &PlatformVersion,
+    ) -> Option<String> {
+        let in_clauses: Vec<&WhereClause> = internal_clauses
+            .in_clause
+            .iter()
+            .chain(internal_clauses.other_in_clauses.iter())
+            .collect();
+
+        in_clauses
+            .iter()
+            .find(|kept_in_clause| {
+                let mut equal_clauses = internal_clauses.equal_clauses.clone();
+                for in_clause in &in_clauses {
+                    if in_clause.field == kept_in_clause.field {
+                        continue;
+                    }
+                    let Some(value) = in_clause
+                        .in_values()
+                        .ok()
+                        .and_then(|in_values| in_values.first().cloned())
+                    else {
+                        return false;
+                    };
+                    equal_clauses.insert(
+                        in_clause.field.clone(),
+                        WhereClause {
+                            field: in_clause.field.clone(),
+                            operator: WhereOperator::Equal,
+                            value,
+                        },
+                    );
+                }
+                let equal_clauses_count = equal_clauses.len();
+                let trial_clauses = InternalClauses {
+                    in_clause: Some(WhereClause::clone(kept_in_clause)),
+                    other_in_clauses: vec![],
+                    equal_clauses,
+                    ..internal_clauses.clone()
+                };
+                let trial_query = self.branch_query(trial_clauses, all_where_fields);
+                let Ok(index) = trial_query.find_best_index(platform_version) else {
+                    return false;
+                };
+                // The in clause must come right after the equality clauses in the index
+                let equal_prefix_count = index
+                    .properties
+                    .iter()
+                    .take_while(|property| {
+                        trial_query
+                            .internal_clauses
+                            .equal_clauses
+                            .contains_key(property.name.as_str())
+                    })
+                    .count();
+                equal_prefix_count == equal_clauses_count
+                    && index
+                        .properties
+                        .get(equal_prefix_count)
+                        .map(|property| &property.name)
+                        == Some(&kept_in_clause.field)
+            })
+            .map(|in_clause| in_clause.field.clone())
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the fields used by where clauses, including all alternative groups of clauses.
+    fn where_clause_fields(internal_clauses: &InternalClauses) -> BTreeSet<String> {
+        let mut fields: BTreeSet<String> = internal_clauses
+            .or_clauses
+            .iter()
+            .flat_map(Self::where_clause_fields)
+            .collect();
+        let where_clauses: Vec<WhereClause> = internal_clauses.clone().into();
+        fields.extend(
+            where_clauses
+                .into_iter()
+                .map(|where_clause| where_clause.field),
+        );
+        fields
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Finds the best index for the query.
     pub fn find_best_index(&self, platform_version: &PlatformVersion) -> Result<&Index, Error> {
@@ -1676,6 +2274,9 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<u8>, Error> {
+        if self.internal_clauses.is_disjunctive() {
+            return self.prove_branches(drive, transaction, drive_operations, platform_version);
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             true,
@@ -1732,6 +2333,11 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<([u8; 32], Vec<Vec<u8>>), Error> {
+        if self.internal_clauses.is_disjunctive() {
+            let proof =
+                self.prove_branches(drive, transaction, drive_operations, platform_version)?;
+            return self.verify_proof_keep_serialized(proof.as_slice(), platform_version);
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             true,
@@ -1815,6 +2421,14 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        if self.internal_clauses.is_disjunctive() {
+            return self.execute_branches_no_proof(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             false,
@@ -1901,10 +2515,9 @@ impl<'a> From<&DriveQuery<'a>> for BTreeMap<String, Value> {
         );
 
         // Internal clauses
-        let all_where_clauses: Vec<WhereClause> = query.internal_clauses.clone().into();
         response.insert(
             "where".to_string(),
-            Value::Array(all_where_clauses.into_iter().map(|v| v.into()).collect()),
+            Value::Array(query.internal_clauses.clone().into_where_values()),
         );
 
         // Offset
@@ -2073,6 +2686,139 @@ mod tests {
         assert_eq!(deserialized.block_time_ms, Some(13453432u64));
     }
 
+    #[test]
+    fn test_drive_query_with_or_clauses_from_to_cbor() {
+        let config = DriveConfig::default();
+        let contract = get_data_contract_fixture(None, 1).data_contract_owned();
+        let document_type = contract
+            .document_type_for_name("niceDocument")
+            .expect("expected to get nice document");
+
+        let query_value = json!({
+            "where": [
+                ["lastName", "=", "Doe"],
+                ["or", [
+                    [["firstName", "<", "Gilligan"]],
+                    [["firstName", ">", "Skipper"]],
+                ]],
+            ],
+            "limit": 100u16,
+            "orderBy": [
+                ["firstName", "asc"],
+            ],
+        });
+
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(where_cbor.as_slice(), &contract, document_type, &config)
+            .expect("deserialize cbor shouldn't fail");
+
+        assert!(query.internal_clauses.is_disjunctive());
+        assert_eq!(query.internal_clauses.or_clauses.len(), 2);
+        assert!(query
+            .internal_clauses
+            .or_clauses
+            .iter()
+            .all(|or_clause| or_clause.equal_clauses.contains_key("lastName")
+                && or_clause.range_clause.is_some()));
+
+        let cbor = query.to_cbor().expect("should serialize cbor");
+
+        let deserialized = DriveQuery::from_cbor(&cbor, &contract, document_type, &config)
+            .expect("should deserialize cbor");
+
+        assert_eq!(query, deserialized);
+    }
+
+    #[test]
+    fn test_valid_query_multiple_in_clauses() {
+        let query_value = json!({
+            "where": [
+                ["firstName", "in", ["Gilligan", "Skipper"]],
+                ["lastName", "in", ["Doe", "Smith"]],
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"],
+                ["lastName", "asc"],
+            ]
+        });
+        let contract = get_data_contract_fixture(None, 1).data_contract_owned();
+        let document_type = contract
+            .document_type_for_name("niceDocument")
+            .expect("expected to get nice document");
+
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect("multiple in clauses on different fields should be allowed");
+
+        assert!(query.internal_clauses.is_disjunctive());
+        assert_eq!(query.internal_clauses.other_in_clauses.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_query_multiple_in_clauses_same_field() {
+        let query_value = json!({
+            "where": [
+                ["firstName", "in", ["Gilligan", "Skipper"]],
+                ["firstName", "in", ["Doe", "Smith"]],
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"],
+            ]
+        });
+        let contract = get_data_contract_fixture(None, 1).data_contract_owned();
+        let document_type = contract
+            .document_type_for_name("niceDocument")
+            .expect("expected to get nice document");
+
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect_err("in clauses on the same field should not be allowed");
+    }
+
+    #[test]
+    fn test_invalid_query_nested_or_clauses() {
+        let query_value = json!({
+            "where": [
+                ["or", [
+                    [["firstName", "==", "Gilligan"]],
+                    [["or", [
+                        [["lastName", "==", "Doe"]],
+                        [["lastName", "==", "Smith"]],
+                    ]]],
+                ]],
+            ],
+        });
+        let contract = get_data_contract_fixture(None, 1).data_contract_owned();
+        let document_type = contract
+            .document_type_for_name("niceDocument")
+            .expect("expected to get nice document");
+
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect_err("or clauses can not be nested");
+    }
+
     #[test]
     fn test_invalid_query_ranges_different_fields() {
         let query_value = json!({
@@ -0,0 +1,179 @@
+//! SQL helpers
+//!
+//! Parsing of SQL strings into statements with positioned errors and formatting of
+//! identifiers and values back to SQL.
+
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use dpp::platform_value::string_encoding::Encoding;
+use dpp::platform_value::{Identifier, Value};
+use sqlparser::ast::Statement;
+use sqlparser::dialect::{Dialect, GenericDialect};
+use sqlparser::parser::Parser;
+use sqlparser::tokenizer::{Token, Tokenizer};
+
+/// The SQL dialect of drive queries, it is the generic dialect where identifiers can also
+/// start with `$` so system fields like `$ownerId` or `$createdAt` can be used unquoted.
+#[derive(Debug, Default)]
+struct DriveSqlDialect {
+    generic_dialect: GenericDialect,
+}
+
+impl Dialect for DriveSqlDialect {
+    fn is_identifier_start(&self, ch: char) -> bool {
+        ch == '$' || self.generic_dialect.is_identifier_start(ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        ch == '$' || self.generic_dialect.is_identifier_part(ch)
+    }
+}
+
+/// Builds the error of a problem found at a position of the SQL string.
+fn error_at_position(message: String, (line, column): (u64, u64)) -> Error {
+    Error::Query(QuerySyntaxError::InvalidSQLAtPosition {
+        message,
+        line,
+        column,
+    })
+}
+
+/// Returns the line and column, both starting at 1, where the token at `token_index` starts.
+fn token_position(tokens: &[Token], token_index: usize) -> (u64, u64) {
+    let mut line = 1;
+    let mut column = 1;
+    for token in tokens.iter().take(token_index) {
+        for ch in token.to_string().chars() {
+            if ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+    }
+    (line, column)
+}
+
+/// Returns the position of the token the parser stopped at.
+///
+/// The parser doesn't keep track of positions, so the position is found by counting the
+/// tokens that were not consumed yet. Some errors are raised after the offending token was
+/// consumed, in which case it is the one the error message says was found.
+fn parser_position(tokens: &[Token], parser: &mut Parser, message: &str) -> (u64, u64) {
+    let mut remaining_tokens = 0;
+    while parser.next_token() != Token::EOF {
+        remaining_tokens += 1;
+    }
+
+    let significant_token_indexes = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token, Token::Whitespace(_)))
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+
+    let mut significant_index = significant_token_indexes
+        .len()
+        .saturating_sub(remaining_tokens);
+
+    if let Some((_, found)) = message.rsplit_once("found: ") {
+        let found_at = |significant_index: usize| {
+            significant_token_indexes
+                .get(significant_index)
+                .map_or(false, |index| tokens[*index].to_string() == found)
+        };
+        if !found_at(significant_index) && significant_index > 0 && found_at(significant_index - 1)
+        {
+            significant_index -= 1;
+        }
+    }
+
+    let token_index = significant_token_indexes
+        .get(significant_index)
+        .copied()
+        .unwrap_or(tokens.len());
+
+    token_position(tokens, token_index)
+}
+
+/// Parses a SQL string holding a single statement.
+///
+/// Errors give the line and column of the problem.
+pub(crate) fn parse_sql_statement(sql_string: &str) -> Result<Statement, Error> {
+    let dialect = DriveSqlDialect::default();
+
+    let tokens = Tokenizer::new(&dialect, sql_string)
+        .tokenize()
+        .map_err(|e| error_at_position(e.message, (e.line, e.col)))?;
+
+    let mut parser = Parser::new(tokens.clone(), &dialect);
+
+    while parser.consume_token(&Token::SemiColon) {}
+
+    if parser.peek_token() == Token::EOF {
+        return Err(Error::Query(QuerySyntaxError::InvalidSQL(
+            "Invalid query: no statement",
+        )));
+    }
+
+    let statement = match parser.parse_statement() {
+        Ok(statement) => statement,
+        Err(error) => {
+            let message = error.to_string();
+            let position = parser_position(&tokens, &mut parser, &message);
+            return Err(error_at_position(message, position));
+        }
+    };
+
+    while parser.consume_token(&Token::SemiColon) {}
+
+    if parser.peek_token() != Token::EOF {
+        let message = format!("Expected end of statement, found: {}", parser.peek_token());
+        let position = parser_position(&tokens, &mut parser, &message);
+        return Err(error_at_position(message, position));
+    }
+
+    Ok(statement)
+}
+
+/// Formats a field or document type name as a quoted SQL identifier.
+pub(crate) fn sql_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Formats a value as a SQL value, identifiers and 32 byte arrays are written as base58 text.
+pub(crate) fn sql_value(value: &Value) -> Result<String, Error> {
+    let sql = match value {
+        Value::Null => "NULL".to_string(),
+        Value::Bool(bool) => bool.to_string(),
+        Value::U128(int) => int.to_string(),
+        Value::I128(int) => int.to_string(),
+        Value::U64(int) => int.to_string(),
+        Value::I64(int) => int.to_string(),
+        Value::U32(int) => int.to_string(),
+        Value::I32(int) => int.to_string(),
+        Value::U16(int) => int.to_string(),
+        Value::I16(int) => int.to_string(),
+        Value::U8(int) => int.to_string(),
+        Value::I8(int) => int.to_string(),
+        // the debug format always has a decimal point, so the value is read back as a float
+        Value::Float(float) if float.is_finite() => format!("{:?}", float),
+        Value::Text(text) => sql_text(text),
+        Value::Identifier(bytes) | Value::Bytes32(bytes) => {
+            sql_text(&Identifier::new(*bytes).to_string(Encoding::Base58))
+        }
+        _ => {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(format!(
+                "value {} can not be represented in sql",
+                value
+            ))))
+        }
+    };
+    Ok(sql)
+}
+
+/// Formats text as a single quoted SQL string.
+pub(crate) fn sql_text(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
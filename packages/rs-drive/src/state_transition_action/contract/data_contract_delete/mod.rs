@@ -0,0 +1,39 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use derive_more::From;
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
+
+/// data contract delete transition action
+#[derive(Debug, Clone, From)]
+pub enum DataContractDeleteTransitionAction {
+    /// v0
+    V0(DataContractDeleteTransitionActionV0),
+}
+
+impl DataContractDeleteTransitionAction {
+    /// Data contract id
+    pub fn data_contract_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.data_contract_id,
+        }
+    }
+
+    /// Owner id
+    pub fn owner_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.owner_id,
+        }
+    }
+
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+}
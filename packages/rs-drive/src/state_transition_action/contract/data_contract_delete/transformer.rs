@@ -0,0 +1,23 @@
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+
+impl From<DataContractDeleteTransition> for DataContractDeleteTransitionAction {
+    fn from(value: DataContractDeleteTransition) -> Self {
+        match value {
+            DataContractDeleteTransition::V0(v0) => {
+                DataContractDeleteTransitionActionV0::from(v0).into()
+            }
+        }
+    }
+}
+
+impl From<&DataContractDeleteTransition> for DataContractDeleteTransitionAction {
+    fn from(value: &DataContractDeleteTransition) -> Self {
+        match value {
+            DataContractDeleteTransition::V0(v0) => {
+                DataContractDeleteTransitionActionV0::from(v0).into()
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+mod transformer;
+
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
+use serde::{Deserialize, Serialize};
+
+/// data contract delete transition action v0
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataContractDeleteTransitionActionV0 {
+    /// data contract id
+    pub data_contract_id: Identifier,
+    /// owner id
+    pub owner_id: Identifier,
+    /// nonce
+    pub nonce: IdentityNonce,
+}
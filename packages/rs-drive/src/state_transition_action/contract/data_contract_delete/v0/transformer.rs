@@ -0,0 +1,34 @@
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use dpp::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0;
+
+impl From<DataContractDeleteTransitionV0> for DataContractDeleteTransitionActionV0 {
+    fn from(value: DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            data_contract_id,
+            owner_id,
+            nonce,
+            ..
+        } = value;
+        DataContractDeleteTransitionActionV0 {
+            data_contract_id,
+            owner_id,
+            nonce,
+        }
+    }
+}
+
+impl From<&DataContractDeleteTransitionV0> for DataContractDeleteTransitionActionV0 {
+    fn from(value: &DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            data_contract_id,
+            owner_id,
+            nonce,
+            ..
+        } = value;
+        DataContractDeleteTransitionActionV0 {
+            data_contract_id: *data_contract_id,
+            owner_id: *owner_id,
+            nonce: *nonce,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+mod v0;
+
+use derive_more::From;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+use dpp::document::Document;
+pub use v0::*;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// tranformer
+pub mod transformer;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum DocumentPurchaseTransitionAction {
+    /// v0
+    V0(DocumentPurchaseTransitionActionV0),
+}
+
+impl DocumentPurchaseTransitionActionAccessorsV0 for DocumentPurchaseTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn document(&self) -> &Document {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => &v0.document,
+        }
+    }
+
+    fn document_owned(self) -> Document {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.document,
+        }
+    }
+
+    fn original_owner_id(&self) -> Identifier {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.original_owner_id,
+        }
+    }
+
+    fn price(&self) -> Credits {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.price,
+        }
+    }
+}
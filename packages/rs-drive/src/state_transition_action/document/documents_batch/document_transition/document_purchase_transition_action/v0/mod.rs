@@ -0,0 +1,36 @@
+pub mod transformer;
+
+use dpp::document::Document;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// document purchase transition action v0
+#[derive(Debug, Clone)]
+pub struct DocumentPurchaseTransitionActionV0 {
+    /// Document Base Transition
+    pub base: DocumentBaseTransitionAction,
+    /// The document as it will be stored once the transition is applied
+    pub document: Document,
+    /// The identity that owned the document before the purchase
+    pub original_owner_id: Identifier,
+    /// The price paid by the purchaser
+    pub price: Credits,
+}
+
+/// document purchase transition action accessors v0
+pub trait DocumentPurchaseTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the document to store
+    fn document(&self) -> &Document;
+    /// the document to store owned
+    fn document_owned(self) -> Document;
+    /// original owner id
+    fn original_owner_id(&self) -> Identifier;
+    /// price
+    fn price(&self) -> Credits;
+}
@@ -0,0 +1,53 @@
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::identity::TimestampMillis;
+use dpp::document::property_names::PRICE;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::DocumentPurchaseTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::DocumentPurchaseTransitionActionV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+impl DocumentPurchaseTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_purchase_transition(
+        document_purchase_transition: &DocumentPurchaseTransitionV0,
+        original_document: Document,
+        purchaser_id: Identifier,
+        block_time_ms: TimestampMillis,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentPurchaseTransitionV0 {
+            base,
+            revision,
+            price,
+        } = document_purchase_transition;
+
+        let original_owner_id = original_document.owner_id();
+
+        let mut modified_document = original_document;
+
+        modified_document.set_owner_id(purchaser_id);
+
+        // the new owner has to list the document again to sell it
+        modified_document.properties_mut().remove(PRICE);
+
+        modified_document.set_revision(Some(*revision));
+
+        if modified_document.updated_at().is_some() {
+            modified_document.set_updated_at(Some(block_time_ms));
+        }
+
+        Ok(DocumentPurchaseTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            document: modified_document,
+            original_owner_id,
+            price: *price,
+        })
+    }
+}
@@ -1,9 +1,10 @@
 use dpp::platform_value::Identifier;
 use std::sync::Arc;
 
+use dpp::document::Document;
 use dpp::identity::TimestampMillis;
 use dpp::ProtocolError;
-use dpp::state_transition::documents_batch_transition::document_transition::DocumentReplaceTransition;
+use dpp::state_transition::documents_batch_transition::document_transition::{DocumentPatchTransition, DocumentReplaceTransition};
 use crate::drive::contract::DataContractFetchInfo;
 use crate::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::{DocumentReplaceTransitionAction, DocumentReplaceTransitionActionV0};
 
@@ -25,4 +26,22 @@ impl DocumentReplaceTransitionAction {
             ),
         }
     }
+
+    /// try from borrowed patch, merging the patched properties into the original document
+    pub fn try_from_borrowed_document_patch_transition(
+        document_patch_transition: &DocumentPatchTransition,
+        original_document: &Document,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        match document_patch_transition {
+            DocumentPatchTransition::V0(v0) => Ok(
+                DocumentReplaceTransitionActionV0::try_from_borrowed_document_patch_transition(
+                    v0,
+                    original_document,
+                    get_data_contract,
+                )?
+                .into(),
+            ),
+        }
+    }
 }
@@ -1,8 +1,10 @@
 use dpp::platform_value::Identifier;
 use std::sync::Arc;
 
+use dpp::document::{Document, DocumentV0Getters};
 use dpp::identity::TimestampMillis;
 use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_patch_transition::DocumentPatchTransitionV0;
 use dpp::state_transition::documents_batch_transition::document_transition::document_replace_transition::DocumentReplaceTransitionV0;
 use crate::drive::contract::DataContractFetchInfo;
 use crate::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::v0::DocumentReplaceTransitionActionV0;
@@ -34,4 +36,102 @@ impl DocumentReplaceTransitionActionV0 {
             data: data.clone(),
         })
     }
+
+    /// try from borrowed patch, merging the patched properties into the original document
+    ///
+    /// Patched properties only overwrite the properties of the original document, so a patch
+    /// can't remove an optional property. A replace transition is needed for that.
+    pub fn try_from_borrowed_document_patch_transition(
+        document_patch_transition: &DocumentPatchTransitionV0,
+        original_document: &Document,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentPatchTransitionV0 {
+            base,
+            revision,
+            updated_at,
+            data,
+        } = document_patch_transition;
+
+        let mut patched_data = original_document.properties().clone();
+        patched_data.extend(data.clone());
+
+        Ok(DocumentReplaceTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            revision: *revision,
+            created_at: original_document.created_at(),
+            updated_at: *updated_at,
+            data: patched_data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::document::DocumentV0;
+    use dpp::platform_value::Value;
+    use dpp::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
+    use dpp::version::PlatformVersion;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_merge_patched_properties_into_original_document() {
+        let platform_version = PlatformVersion::latest();
+        let data_contract_fetch_info = Arc::new(DataContractFetchInfo::dpns_contract_fixture(
+            platform_version.protocol_version,
+        ));
+
+        let document_id = Identifier::new([1; 32]);
+
+        let original_document: Document = DocumentV0 {
+            id: document_id,
+            owner_id: Identifier::new([2; 32]),
+            properties: BTreeMap::from([
+                ("label".to_string(), Value::Text("old".to_string())),
+                ("records".to_string(), Value::Map(vec![])),
+            ]),
+            revision: Some(1),
+            created_at: Some(1000),
+            updated_at: Some(1000),
+        }
+        .into();
+
+        let patch_transition = DocumentPatchTransitionV0 {
+            base: DocumentBaseTransitionV0 {
+                id: document_id,
+                document_type_name: "domain".to_string(),
+                data_contract_id: data_contract_fetch_info.contract.id(),
+            }
+            .into(),
+            revision: 2,
+            updated_at: Some(2000),
+            data: BTreeMap::from([("label".to_string(), Value::Text("new".to_string()))]),
+        };
+
+        let action =
+            DocumentReplaceTransitionActionV0::try_from_borrowed_document_patch_transition(
+                &patch_transition,
+                &original_document,
+                |_| Ok(data_contract_fetch_info.clone()),
+            )
+            .expect("expected to create replace action");
+
+        assert_eq!(action.base.id(), document_id);
+        assert_eq!(action.revision, 2);
+        assert_eq!(action.created_at, Some(1000));
+        assert_eq!(action.updated_at, Some(2000));
+        assert_eq!(
+            action.data,
+            BTreeMap::from([
+                ("label".to_string(), Value::Text("new".to_string())),
+                ("records".to_string(), Value::Map(vec![])),
+            ])
+        );
+    }
 }
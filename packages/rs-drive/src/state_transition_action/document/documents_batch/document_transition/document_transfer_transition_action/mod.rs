@@ -0,0 +1,42 @@
+mod v0;
+
+use derive_more::From;
+use dpp::document::Document;
+pub use v0::*;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// tranformer
+pub mod transformer;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum DocumentTransferTransitionAction {
+    /// v0
+    V0(DocumentTransferTransitionActionV0),
+}
+
+impl DocumentTransferTransitionActionAccessorsV0 for DocumentTransferTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentTransferTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentTransferTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn document(&self) -> &Document {
+        match self {
+            DocumentTransferTransitionAction::V0(v0) => &v0.document,
+        }
+    }
+
+    fn document_owned(self) -> Document {
+        match self {
+            DocumentTransferTransitionAction::V0(v0) => v0.document,
+        }
+    }
+}
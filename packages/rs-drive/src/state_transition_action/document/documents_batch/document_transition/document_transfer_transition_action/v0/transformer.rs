@@ -0,0 +1,48 @@
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::identity::TimestampMillis;
+use dpp::document::property_names::PRICE;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::DocumentTransferTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+impl DocumentTransferTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_transfer_transition(
+        document_transfer_transition: &DocumentTransferTransitionV0,
+        original_document: Document,
+        block_time_ms: TimestampMillis,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentTransferTransitionV0 {
+            base,
+            revision,
+            recipient_owner_id,
+        } = document_transfer_transition;
+
+        let mut modified_document = original_document;
+
+        modified_document.set_owner_id(*recipient_owner_id);
+
+        // a transferred document is no longer for sale
+        modified_document.properties_mut().remove(PRICE);
+
+        modified_document.set_revision(Some(*revision));
+
+        if modified_document.updated_at().is_some() {
+            modified_document.set_updated_at(Some(block_time_ms));
+        }
+
+        Ok(DocumentTransferTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            document: modified_document,
+        })
+    }
+}
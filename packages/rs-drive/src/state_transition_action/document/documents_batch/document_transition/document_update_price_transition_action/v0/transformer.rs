@@ -0,0 +1,45 @@
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::identity::TimestampMillis;
+use dpp::document::property_names::PRICE;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_update_price_transition::DocumentUpdatePriceTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::v0::DocumentUpdatePriceTransitionActionV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+impl DocumentUpdatePriceTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_update_price_transition(
+        document_update_price_transition: &DocumentUpdatePriceTransitionV0,
+        original_document: Document,
+        block_time_ms: TimestampMillis,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentUpdatePriceTransitionV0 {
+            base,
+            revision,
+            price,
+        } = document_update_price_transition;
+
+        let mut modified_document = original_document;
+
+        modified_document.set_u64(PRICE, *price);
+
+        modified_document.set_revision(Some(*revision));
+
+        if modified_document.updated_at().is_some() {
+            modified_document.set_updated_at(Some(block_time_ms));
+        }
+
+        Ok(DocumentUpdatePriceTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            document: modified_document,
+        })
+    }
+}
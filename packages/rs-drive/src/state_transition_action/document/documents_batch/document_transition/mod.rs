@@ -5,8 +5,14 @@ pub mod document_base_transition_action;
 pub mod document_create_transition_action;
 /// document_delete_transition_action
 pub mod document_delete_transition_action;
+/// document_purchase_transition_action
+pub mod document_purchase_transition_action;
 /// document_replace_transition_action
 pub mod document_replace_transition_action;
+/// document_transfer_transition_action
+pub mod document_transfer_transition_action;
+/// document_update_price_transition_action
+pub mod document_update_price_transition_action;
 
 pub use dpp::state_transition::documents_batch_transition::document_transition::action_type::DocumentTransitionActionType;
 
@@ -17,6 +23,9 @@ use crate::state_transition_action::document::documents_batch::document_transiti
 use crate::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::DocumentDeleteTransitionAction;
 use crate::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::{DocumentReplaceTransitionAction, DocumentReplaceTransitionActionAccessorsV0};
 use crate::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::v0::DocumentDeleteTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::{DocumentPurchaseTransitionAction, DocumentPurchaseTransitionActionAccessorsV0};
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::{DocumentTransferTransitionAction, DocumentTransferTransitionActionAccessorsV0};
+use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::{DocumentUpdatePriceTransitionAction, DocumentUpdatePriceTransitionActionAccessorsV0};
 /// version
 pub const DOCUMENT_TRANSITION_ACTION_VERSION: u32 = 0;
 
@@ -29,6 +38,12 @@ pub enum DocumentTransitionAction {
     ReplaceAction(DocumentReplaceTransitionAction),
     /// delete
     DeleteAction(DocumentDeleteTransitionAction),
+    /// transfer
+    TransferAction(DocumentTransferTransitionAction),
+    /// update price
+    UpdatePriceAction(DocumentUpdatePriceTransitionAction),
+    /// purchase
+    PurchaseAction(DocumentPurchaseTransitionAction),
 }
 
 impl DocumentTransitionAction {
@@ -38,6 +53,9 @@ impl DocumentTransitionAction {
             DocumentTransitionAction::CreateAction(d) => d.base(),
             DocumentTransitionAction::DeleteAction(d) => d.base(),
             DocumentTransitionAction::ReplaceAction(d) => d.base(),
+            DocumentTransitionAction::TransferAction(d) => d.base(),
+            DocumentTransitionAction::UpdatePriceAction(d) => d.base(),
+            DocumentTransitionAction::PurchaseAction(d) => d.base(),
         }
     }
 }
@@ -7,6 +7,7 @@ use crate::state_transition_action::identity::identity_credit_transfer::v0::Iden
 use derive_more::From;
 use dpp::fee::Credits;
 use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
 
 /// action
 #[derive(Debug, Clone, From)]
@@ -36,4 +37,11 @@ impl IdentityCreditTransferTransitionAction {
             IdentityCreditTransferTransitionAction::V0(transition) => transition.recipient_id,
         }
     }
+
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityCreditTransferTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
 }
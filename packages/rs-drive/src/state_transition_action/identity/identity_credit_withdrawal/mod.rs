@@ -8,7 +8,7 @@ use derive_more::From;
 use dpp::document::Document;
 
 use dpp::platform_value::Identifier;
-use dpp::prelude::Revision;
+use dpp::prelude::{IdentityNonce, Revision};
 
 /// action
 #[derive(Debug, Clone, From)]
@@ -25,6 +25,13 @@ impl IdentityCreditWithdrawalTransitionAction {
         }
     }
 
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityCreditWithdrawalTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+
     /// Identity Id
     pub fn identity_id(&self) -> Identifier {
         match self {
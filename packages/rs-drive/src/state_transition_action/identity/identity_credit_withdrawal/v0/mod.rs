@@ -2,7 +2,7 @@ mod transformer;
 
 use dpp::document::Document;
 use dpp::identifier::Identifier;
-use dpp::prelude::Revision;
+use dpp::prelude::{IdentityNonce, Revision};
 
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +14,8 @@ pub struct IdentityCreditWithdrawalTransitionActionV0 {
     pub identity_id: Identifier,
     /// revision
     pub revision: Revision,
+    /// nonce
+    pub nonce: IdentityNonce,
     /// prepared withdrawal document
     pub prepared_withdrawal_document: Document,
 }
@@ -40,6 +40,7 @@ impl IdentityCreditWithdrawalTransitionActionV0 {
         IdentityCreditWithdrawalTransitionActionV0 {
             identity_id: identity_credit_withdrawal.identity_id,
             revision: identity_credit_withdrawal.revision,
+            nonce: identity_credit_withdrawal.nonce,
             prepared_withdrawal_document: withdrawal_document,
         }
     }
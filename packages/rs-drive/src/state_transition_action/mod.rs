@@ -6,6 +6,7 @@ pub mod document;
 pub mod identity;
 
 use crate::state_transition_action::contract::data_contract_create::DataContractCreateTransitionAction;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
 use crate::state_transition_action::contract::data_contract_update::DataContractUpdateTransitionAction;
 use crate::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use crate::state_transition_action::identity::identity_create::IdentityCreateTransitionAction;
@@ -34,4 +35,6 @@ pub enum StateTransitionAction {
     IdentityUpdateAction(IdentityUpdateTransitionAction),
     /// identity credit transfer
     IdentityCreditTransferAction(IdentityCreditTransferTransitionAction),
+    /// data contract delete
+    DataContractDeleteAction(DataContractDeleteTransitionAction),
 }
@@ -65,7 +65,7 @@ use drive::drive::Drive;
 #[cfg(feature = "full")]
 use drive::error::{query::QuerySyntaxError, Error};
 #[cfg(feature = "full")]
-use drive::query::DriveQuery;
+use drive::query::{DocumentAggregate, DocumentAggregateDriveQuery, DriveQuery};
 #[cfg(feature = "full")]
 #[cfg(test)]
 use drive::tests::helpers::setup::setup_drive;
@@ -82,6 +82,8 @@ use serde_json::json;
 #[cfg(feature = "full")]
 use dpp::document::Document;
 #[cfg(feature = "full")]
+use dpp::platform_value::string_encoding::Encoding;
+#[cfg(feature = "full")]
 use dpp::platform_value::Value;
 use dpp::platform_value::{platform_value, Bytes32, Identifier};
 
@@ -2875,6 +2877,776 @@ fn test_family_sql_query() {
         .expect("should build query");
 
     assert_eq!(query1, query2);
+
+    // Or clauses
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({
+            "where": [
+                ["or", [
+                    [["firstName", "<", "Chris"]],
+                    [["firstName", ">", "Wisdom"]]
+                ]]
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"]
+            ]
+        }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query1 = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("should build query");
+
+    let sql_string = "select * from person where firstName < 'Chris' or firstName > 'Wisdom' order by firstName asc limit 100";
+    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+        .expect("should build query");
+
+    assert_eq!(query1, query2);
+
+    // Or clauses distributed over other clauses
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({
+            "where": [
+                ["firstName", "==", "Chris"],
+                ["or", [
+                    [["age", "<", 10]],
+                    [["age", ">", 50]]
+                ]]
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["age", "asc"]
+            ]
+        }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query1 = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("should build query");
+
+    let sql_string = "select * from person where firstName = 'Chris' and (age < 10 or age > 50) order by age asc limit 100";
+    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+        .expect("should build query");
+
+    assert_eq!(query1, query2);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_sql_query_dialect() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    // Between clause
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({
+            "where": [
+                ["firstName", "between", ["Chris", "Noellyn"]]
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"]
+            ]
+        }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query1 = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("should build query");
+
+    let sql_string = "select * from person where firstName between 'Chris' and 'Noellyn' order by firstName asc limit 100";
+    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+        .expect("should build query");
+
+    assert_eq!(query1, query2);
+
+    // Several order by columns with limit and offset
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({
+            "where": [
+                ["firstName", "==", "Chris"]
+            ],
+            "limit": 10,
+            "offset": 5,
+            "orderBy": [
+                ["firstName", "asc"],
+                ["lastName", "desc"]
+            ]
+        }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query1 = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("should build query");
+
+    let sql_string = "select * from person where firstName = 'Chris' order by firstName asc, lastName desc limit 10 offset 5";
+    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+        .expect("should build query");
+
+    assert_eq!(query1, query2);
+
+    // System fields
+    let owner_id = Identifier::new([3u8; 32]).to_string(Encoding::Base58);
+    let sql_string = format!(
+        "select * from person where $ownerId = '{}' and $createdAt > 1000 order by $createdAt desc",
+        owner_id
+    );
+    let query = DriveQuery::from_sql_expr(&sql_string, &contract, &DriveConfig::default())
+        .expect("should build query");
+
+    assert_eq!(
+        query.internal_clauses.equal_clauses.get("$ownerId"),
+        Some(&WhereClause {
+            field: "$ownerId".to_string(),
+            operator: WhereOperator::Equal,
+            value: Value::Text(owner_id),
+        })
+    );
+    assert_eq!(
+        query.internal_clauses.range_clause,
+        Some(WhereClause {
+            field: "$createdAt".to_string(),
+            operator: WhereOperator::GreaterThan,
+            value: Value::I64(1000),
+        })
+    );
+    assert!(query.order_by.contains_key("$createdAt"));
+
+    // Aggregates
+    let sql_string = "select count(*) from person where lastName = 'Smith'";
+    let aggregate_query =
+        DocumentAggregateDriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+            .expect("should build aggregate query");
+    assert_eq!(aggregate_query.aggregate, DocumentAggregate::Count);
+    assert!(matches!(
+        DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default()),
+        Err(Error::Query(QuerySyntaxError::InvalidSQL(_)))
+    ));
+
+    let sql_string = "select sum(age) from person where lastName = 'Smith'";
+    let aggregate_query =
+        DocumentAggregateDriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+            .expect("should build aggregate query");
+    assert_eq!(
+        aggregate_query.aggregate,
+        DocumentAggregate::Sum("age".to_string())
+    );
+    assert_eq!(
+        aggregate_query.to_sql().expect("should convert to sql"),
+        "SELECT SUM(\"age\") FROM \"person\" WHERE \"lastName\" = 'Smith' LIMIT 100"
+    );
+
+    // Errors give the position of the problem
+    let error =
+        DriveQuery::from_sql_expr("select * form person", &contract, &DriveConfig::default())
+            .expect_err("should not parse");
+    assert!(matches!(
+        error,
+        Error::Query(QuerySyntaxError::InvalidSQLAtPosition {
+            line: 1,
+            column: 10,
+            ..
+        })
+    ));
+
+    let error = DriveQuery::from_sql_expr(
+        "select * from person\nwhere firstName = 'Chris' limi 10",
+        &contract,
+        &DriveConfig::default(),
+    )
+    .expect_err("should not parse");
+    assert!(matches!(
+        error,
+        Error::Query(QuerySyntaxError::InvalidSQLAtPosition {
+            line: 2,
+            column: 27,
+            ..
+        })
+    ));
+
+    let error = DriveQuery::from_sql_expr(
+        "select * from person limit 1000",
+        &contract,
+        &DriveConfig::default(),
+    )
+    .expect_err("limit should be too high");
+    assert!(matches!(
+        error,
+        Error::Query(QuerySyntaxError::InvalidLimit(_))
+    ));
+
+    // Queries convert back to sql
+    let sql_string = "select * from person where firstName = 'Chris' and age between 10 and 50 order by age asc limit 100";
+    let query = DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+        .expect("should build query");
+    assert_eq!(
+        query.to_sql().expect("should convert to sql"),
+        "SELECT * FROM \"person\" WHERE \"firstName\" = 'Chris' AND \"age\" BETWEEN 10 AND 50 ORDER BY \"age\" ASC LIMIT 100"
+    );
+
+    for sql_string in [
+        "select * from person order by firstName asc limit 100",
+        "select * from person where firstName > 'Chris' and firstName <= 'Noellyn' order by firstName asc limit 100",
+        "select * from person where firstName like 'C%' order by firstName asc limit 100",
+        "select * from person where firstName in ('a', 'b') and lastName in ('c', 'd') order by firstName limit 100",
+        "select * from person where firstName = 'O''Neil' and (age < 10 or age > 50) order by age asc limit 100",
+        "select * from person where age > -5 and age < 50 order by age desc, firstName asc limit 10 offset 20",
+    ] {
+        let query = DriveQuery::from_sql_expr(sql_string, &contract, &DriveConfig::default())
+            .expect("should build query");
+        let round_trip_sql = query.to_sql().expect("should convert to sql");
+        let round_trip_query =
+            DriveQuery::from_sql_expr(&round_trip_sql, &contract, &DriveConfig::default())
+                .expect("should build query from converted sql");
+        assert_eq!(query, round_trip_query);
+        assert_eq!(
+            round_trip_query.to_sql().expect("should convert to sql"),
+            round_trip_sql
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_or_and_multiple_in_queries() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let root_hash = drive
+        .grove
+        .root_hash(None)
+        .unwrap()
+        .expect("there is always a root hash");
+
+    let first_name_and_age = |document: &Document| {
+        let first_name = document
+            .get("firstName")
+            .and_then(|value| value.as_text())
+            .expect("the first name should be a string")
+            .to_string();
+        let age = document
+            .get("age")
+            .expect("we should be able to get the age")
+            .to_integer_broad_conversion::<u64>()
+            .expect("the age should be an integer");
+        (first_name, age)
+    };
+
+    // Runs the query with and without proof and returns the first names and ages of the people found,
+    // in the order they are returned
+    let execute_query = |query_value: serde_json::Value| {
+        let query_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(
+            query_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+        )
+        .expect("query should be built");
+
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+
+        let (proof, _) = query
+            .clone()
+            .execute_with_proof(&drive, None, None, platform_version)
+            .expect("expected proof to be generated");
+        let (proof_root_hash, documents) = query
+            .verify_proof(&proof, platform_version)
+            .expect("expected to verify proof");
+        assert_eq!(root_hash, proof_root_hash);
+
+        let people: Vec<(String, u64)> = results
+            .iter()
+            .map(|result| {
+                let document =
+                    Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                        .expect("we should be able to deserialize the document");
+                first_name_and_age(&document)
+            })
+            .collect();
+
+        let proven_people: Vec<(String, u64)> = documents.iter().map(first_name_and_age).collect();
+
+        assert_eq!(people, proven_people);
+
+        people
+    };
+
+    let all_people = execute_query(json!({
+        "where": [],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    assert_eq!(all_people.len(), 10);
+
+    // Equalities on the same field, without order by documents are sorted by id
+    let mut people = execute_query(json!({
+        "where": [
+            ["or", [
+                [["firstName", "==", "Adey"]],
+                [["firstName", "==", "Briney"]]
+            ]]
+        ]
+    }));
+    people.sort();
+    let names: Vec<&str> = people.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["Adey", "Briney"]);
+
+    // Ranges on the same field
+    let people = execute_query(json!({
+        "where": [
+            ["or", [
+                [["firstName", "<", "Briney"]],
+                [["firstName", ">", "Noellyn"]]
+            ]]
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    let names: Vec<&str> = people.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["Adey", "Prissie"]);
+
+    // Ranges on different fields, people matching both ranges are returned once
+    let (_, dalia_age) = all_people
+        .iter()
+        .find(|(name, _)| name == "Dalia")
+        .expect("expected Dalia")
+        .clone();
+    let people = execute_query(json!({
+        "where": [
+            ["or", [
+                [["firstName", ">=", "Dalia"]],
+                [["age", ">=", dalia_age]]
+            ]]
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"],
+            ["age", "asc"]
+        ]
+    }));
+    let mut expected_people: Vec<(String, u64)> = all_people
+        .iter()
+        .filter(|(name, age)| name.as_str() >= "Dalia" || *age >= dalia_age)
+        .cloned()
+        .collect();
+    expected_people.sort();
+    assert_eq!(people, expected_people);
+
+    // Multiple in clauses
+    let mut ages: Vec<u64> = all_people
+        .iter()
+        .filter(|(name, _)| name == "Cammi" || name == "Kevina")
+        .map(|(_, age)| *age)
+        .collect();
+    ages.dedup();
+    let people = execute_query(json!({
+        "where": [
+            ["firstName", "in", ["Adey", "Cammi", "Kevina"]],
+            ["age", "in", ages]
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"],
+            ["age", "asc"]
+        ]
+    }));
+    let mut expected_people: Vec<(String, u64)> = all_people
+        .iter()
+        .filter(|(name, age)| {
+            ["Adey", "Cammi", "Kevina"].contains(&name.as_str()) && ages.contains(age)
+        })
+        .cloned()
+        .collect();
+    expected_people.sort();
+    assert_eq!(people, expected_people);
+
+    // Documents of all branches are sorted together before the offset and limit are applied
+    let people = execute_query(json!({
+        "where": [
+            ["or", [
+                [["firstName", ">=", "Dalia"]],
+                [["age", ">=", dalia_age]]
+            ]]
+        ],
+        "offset": 1,
+        "limit": 3,
+        "orderBy": [
+            ["firstName", "desc"],
+            ["age", "desc"]
+        ]
+    }));
+    let expected_people: Vec<(String, u64)> = all_people
+        .iter()
+        .filter(|(name, age)| name.as_str() >= "Dalia" || *age >= dalia_age)
+        .rev()
+        .skip(1)
+        .take(3)
+        .cloned()
+        .collect();
+    assert_eq!(people, expected_people);
+
+    // Start at is not supported for disjunctive queries
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({
+            "where": [
+                ["or", [
+                    [["firstName", "==", "Adey"]],
+                    [["firstName", "==", "Briney"]]
+                ]]
+            ],
+            "startAt": String::from("6A8SGgdmj2NtWCYoYDPDpbsYkq2MCbgi6Lx4ALLfF178"),
+        }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("query should be built");
+    let result = query.execute_raw_results_no_proof(&drive, None, None, platform_version);
+    assert!(matches!(
+        result,
+        Err(Error::Query(QuerySyntaxError::Unsupported(_)))
+    ));
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_or_query_with_too_many_documents_in_branch() {
+    let (drive, contract) = setup_family_tests(150, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    // All documents of every branch are needed to sort them, so branches are limited
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({
+            "where": [
+                ["or", [
+                    [["firstName", ">", "A"]],
+                    [["age", ">=", 0]]
+                ]]
+            ],
+            "limit": 1,
+            "orderBy": [
+                ["firstName", "asc"],
+                ["age", "asc"]
+            ]
+        }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("query should be built");
+
+    let result = query.execute_raw_results_no_proof(&drive, None, None, platform_version);
+    assert!(matches!(
+        result,
+        Err(Error::Query(
+            QuerySyntaxError::TooManyDocumentsInQueryBranch(_)
+        ))
+    ));
+
+    let (proof, _) = query
+        .clone()
+        .execute_with_proof(&drive, None, None, platform_version)
+        .expect("expected proof to be generated");
+    let result = query.verify_proof(&proof, platform_version);
+    assert!(matches!(
+        result,
+        Err(Error::Query(
+            QuerySyntaxError::TooManyDocumentsInQueryBranch(_)
+        ))
+    ));
+}
+#[cfg(feature = "full")]
+#[test]
+fn test_family_count_and_sum_queries() {
+    let drive = setup_drive_with_initial_state_structure();
+    let platform_version = PlatformVersion::latest();
+
+    let contract = common::setup_contract(
+        &drive,
+        "tests/supporting_files/contract/family/family-contract-with-aggregates.json",
+        None,
+        None,
+    );
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let mut people = Person::random_people(30, 84594);
+    for person in &people {
+        let value = serde_json::to_value(person).expect("serialized person");
+        let document_cbor = cbor_serializer::serializable_value_to_cbor(&value, Some(0))
+            .expect("expected to serialize to cbor");
+        let document = Document::from_cbor(document_cbor.as_slice(), None, None, platform_version)
+            .expect("document should be properly deserialized");
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            Some(Cow::Owned(StorageFlags::SingleEpoch(0))),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type: person_document_type,
+                },
+                true,
+                BlockInfo::genesis(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("document should be inserted");
+    }
+
+    // Runs the aggregate query with and without proof, making sure both agree
+    let execute_aggregate_query = |where_clauses: serde_json::Value,
+                                   aggregate: DocumentAggregate| {
+        let query_cbor =
+            cbor_serializer::serializable_value_to_cbor(&json!({ "where": where_clauses }), None)
+                .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(
+            query_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+        )
+        .expect("query should be built");
+        let aggregate_query = DocumentAggregateDriveQuery { query, aggregate };
+
+        let (value, _) = aggregate_query
+            .execute_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+
+        let (proof, _) = aggregate_query
+            .execute_with_proof(&drive, None, None, platform_version)
+            .expect("expected proof to be generated");
+        let (proof_root_hash, proved_value) =
+            Drive::verify_documents_aggregate(&proof, &aggregate_query, platform_version)
+                .expect("expected to verify proof");
+
+        let root_hash = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("there is always a root hash");
+        assert_eq!(root_hash, proof_root_hash);
+        assert_eq!(value, proved_value);
+        value
+    };
+
+    let expected = |people: &[Person], filter: &dyn Fn(&Person) -> bool| {
+        let matching = people.iter().filter(|person| filter(person));
+        let count = matching.clone().count() as i64;
+        let age_sum = matching.map(|person| person.age as i64).sum::<i64>();
+        (count, age_sum)
+    };
+
+    let last_name = people[0].last_name.clone();
+    let first_name = people[0].first_name.clone();
+    let other_last_name = people
+        .iter()
+        .map(|person| person.last_name.clone())
+        .find(|other_last_name| other_last_name != &last_name)
+        .expect("expected people with different last names");
+
+    let check_aggregates = |people: &[Person]| {
+        let (count, age_sum) = expected(people, &|_| true);
+        assert_eq!(
+            execute_aggregate_query(json!([]), DocumentAggregate::Count),
+            count
+        );
+        assert_eq!(
+            execute_aggregate_query(json!([]), DocumentAggregate::Sum("age".to_string())),
+            age_sum
+        );
+
+        let (count, age_sum) = expected(people, &|person| person.last_name == last_name);
+        let where_clauses = json!([["lastName", "==", last_name]]);
+        assert_eq!(
+            execute_aggregate_query(where_clauses.clone(), DocumentAggregate::Count),
+            count
+        );
+        assert_eq!(
+            execute_aggregate_query(where_clauses, DocumentAggregate::Sum("age".to_string())),
+            age_sum
+        );
+
+        let (count, _) = expected(people, &|person| {
+            person.last_name == last_name && person.first_name == first_name
+        });
+        assert_eq!(
+            execute_aggregate_query(
+                json!([
+                    ["lastName", "==", last_name],
+                    ["firstName", "==", first_name]
+                ]),
+                DocumentAggregate::Count
+            ),
+            count
+        );
+
+        let (count, age_sum) = expected(people, &|person| {
+            person.last_name == last_name || person.last_name == other_last_name
+        });
+        let where_clauses = json!([["lastName", "in", [last_name, other_last_name]]]);
+        assert_eq!(
+            execute_aggregate_query(where_clauses.clone(), DocumentAggregate::Count),
+            count
+        );
+        assert_eq!(
+            execute_aggregate_query(where_clauses, DocumentAggregate::Sum("age".to_string())),
+            age_sum
+        );
+    };
+
+    check_aggregates(&people);
+
+    // an update changing the age only changes the sum
+    let mut updated_person = people.remove(0);
+    updated_person.age = updated_person.age.wrapping_add(7) % 85;
+    let value = serde_json::to_value(&updated_person).expect("serialized person");
+    let document_cbor = cbor_serializer::serializable_value_to_cbor(&value, Some(0))
+        .expect("expected to serialize to cbor");
+    let document = Document::from_cbor(document_cbor.as_slice(), None, None, platform_version)
+        .expect("document should be properly deserialized");
+    drive
+        .update_document_for_contract(
+            &document,
+            &contract,
+            person_document_type,
+            None,
+            BlockInfo::genesis(),
+            true,
+            Some(Cow::Owned(StorageFlags::SingleEpoch(0))),
+            None,
+            platform_version,
+        )
+        .expect("expected to update document");
+    people.push(updated_person);
+
+    check_aggregates(&people);
+
+    // an update changing the last name moves the document to other aggregates
+    let mut moved_person = people.remove(0);
+    moved_person.last_name = other_last_name.clone();
+    let value = serde_json::to_value(&moved_person).expect("serialized person");
+    let document_cbor = cbor_serializer::serializable_value_to_cbor(&value, Some(0))
+        .expect("expected to serialize to cbor");
+    let document = Document::from_cbor(document_cbor.as_slice(), None, None, platform_version)
+        .expect("document should be properly deserialized");
+    drive
+        .update_document_for_contract(
+            &document,
+            &contract,
+            person_document_type,
+            None,
+            BlockInfo::genesis(),
+            true,
+            Some(Cow::Owned(StorageFlags::SingleEpoch(0))),
+            None,
+            platform_version,
+        )
+        .expect("expected to update document");
+    people.push(moved_person);
+
+    check_aggregates(&people);
+
+    // deleted documents are no longer counted
+    let deleted_person = people.remove(0);
+    drive
+        .delete_document_for_contract(
+            deleted_person
+                .id
+                .clone()
+                .try_into()
+                .expect("expected a 32 byte id"),
+            &contract,
+            "person",
+            BlockInfo::genesis(),
+            true,
+            None,
+            platform_version,
+        )
+        .expect("expected to delete document");
+
+    check_aggregates(&people);
+
+    let query_cbor = cbor_serializer::serializable_value_to_cbor(
+        &json!({ "where": [["firstName", "==", first_name]] }),
+        None,
+    )
+    .expect("expected to serialize to cbor");
+    let query = DriveQuery::from_cbor(
+        query_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("query should be built");
+    let result = DocumentAggregateDriveQuery {
+        query,
+        aggregate: DocumentAggregate::Count,
+    }
+    .execute_no_proof(&drive, None, None, platform_version);
+    assert!(matches!(
+        result,
+        Err(Error::Query(
+            QuerySyntaxError::WhereClauseOnNonIndexedProperty(_)
+        ))
+    ));
 }
 
 #[cfg(feature = "full")]
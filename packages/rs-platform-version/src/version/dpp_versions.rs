@@ -107,11 +107,16 @@ pub struct StateTransitionSerializationVersions {
     pub identity_credit_transfer_state_transition: FeatureVersionBounds,
     pub contract_create_state_transition: FeatureVersionBounds,
     pub contract_update_state_transition: FeatureVersionBounds,
+    pub contract_delete_state_transition: FeatureVersionBounds,
     pub documents_batch_state_transition: FeatureVersionBounds,
     pub document_base_state_transition: FeatureVersionBounds,
     pub document_create_state_transition: DocumentFeatureVersionBounds,
     pub document_replace_state_transition: DocumentFeatureVersionBounds,
     pub document_delete_state_transition: DocumentFeatureVersionBounds,
+    pub document_transfer_state_transition: DocumentFeatureVersionBounds,
+    pub document_update_price_state_transition: DocumentFeatureVersionBounds,
+    pub document_purchase_state_transition: DocumentFeatureVersionBounds,
+    pub document_patch_state_transition: DocumentFeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -14,6 +14,8 @@ pub struct DriveAbciQueryVersions {
     pub base_query_structure: FeatureVersion,
     pub proofs_query: FeatureVersionBounds,
     pub document_query: FeatureVersionBounds,
+    pub document_count_query: FeatureVersionBounds,
+    pub state_transition_fee_estimation_query: FeatureVersionBounds,
     pub identity_based_queries: DriveAbciQueryIdentityVersions,
     pub data_contract_based_queries: DriveAbciQueryDataContractVersions,
     pub system: DriveAbciQuerySystemVersions,
@@ -26,6 +28,7 @@ pub struct DriveAbciQueryIdentityVersions {
     pub keys: FeatureVersionBounds,
     pub balance: FeatureVersionBounds,
     pub balance_and_revision: FeatureVersionBounds,
+    pub nonce: FeatureVersionBounds,
     pub identity_by_public_key_hash: FeatureVersionBounds,
     pub identities_by_public_key_hashes: FeatureVersionBounds,
 }
@@ -69,6 +72,8 @@ pub struct DriveAbciMethodVersions {
     pub epoch: DriveAbciEpochMethodVersions,
     pub block_start: DriveAbciBlockStartMethodVersions,
     pub block_end: DriveAbciBlockEndMethodVersions,
+    pub index_backfill: DriveAbciIndexBackfillMethodVersions,
+    pub document_expiry: DriveAbciDocumentExpiryMethodVersions,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -86,9 +91,15 @@ pub struct DriveAbciDocumentsStateTransitionValidationVersions {
     pub document_create_transition_structure_validation: FeatureVersion,
     pub document_delete_transition_structure_validation: FeatureVersion,
     pub document_replace_transition_structure_validation: FeatureVersion,
+    pub document_transfer_transition_structure_validation: FeatureVersion,
+    pub document_update_price_transition_structure_validation: FeatureVersion,
+    pub document_purchase_transition_structure_validation: FeatureVersion,
     pub document_create_transition_state_validation: FeatureVersion,
     pub document_delete_transition_state_validation: FeatureVersion,
     pub document_replace_transition_state_validation: FeatureVersion,
+    pub document_transfer_transition_state_validation: FeatureVersion,
+    pub document_update_price_transition_state_validation: FeatureVersion,
+    pub document_purchase_transition_state_validation: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -126,6 +137,7 @@ pub struct DriveAbciStateTransitionValidationVersions {
     pub identity_credit_transfer_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_create_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_update_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub contract_delete_state_transition: DriveAbciStateTransitionValidationVersion,
     pub documents_batch_state_transition: DriveAbciDocumentsStateTransitionValidationVersions,
 }
 
@@ -148,6 +160,7 @@ pub struct DriveAbciAssetLockValidationVersions {
 pub struct DriveAbciEngineMethodVersions {
     pub init_chain: FeatureVersion,
     pub check_tx: FeatureVersion,
+    pub estimate_state_transition_fee: FeatureVersion,
     pub run_block_proposal: FeatureVersion,
     pub finalize_block_proposal: FeatureVersion,
 }
@@ -226,6 +239,19 @@ pub struct DriveAbciBlockEndMethodVersions {
     pub update_state_cache: FeatureVersion,
     pub update_drive_cache: FeatureVersion,
     pub validator_set_update: FeatureVersion,
+    pub store_historical_state: FeatureVersion,
+    pub store_snapshot: FeatureVersion,
+    pub update_consensus_params: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveAbciIndexBackfillMethodVersions {
+    pub process_index_backfills: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveAbciDocumentExpiryMethodVersions {
+    pub remove_expired_documents: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -247,6 +273,7 @@ pub struct DriveAbciStateTransitionProcessingMethodVersions {
     pub execute_event: FeatureVersion,
     pub process_raw_state_transitions: FeatureVersion,
     pub validate_fees_of_event: FeatureVersion,
+    pub collect_change_feed_events: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
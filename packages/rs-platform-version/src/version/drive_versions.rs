@@ -61,6 +61,7 @@ pub struct DriveVerifyMethodVersions {
     pub identity: DriveVerifyIdentityMethodVersions,
     pub single_document: DriveVerifySingleDocumentMethodVersions,
     pub system: DriveVerifySystemMethodVersions,
+    pub state_transition: DriveVerifyStateTransitionMethodVersions,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -74,6 +75,7 @@ pub struct DriveVerifyDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
     pub verify_proof_keep_serialized: FeatureVersion,
     pub verify_start_at_document_in_proof: FeatureVersion,
+    pub verify_documents_aggregate: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -86,6 +88,7 @@ pub struct DriveVerifyIdentityMethodVersions {
     pub verify_identity_id_by_public_key_hash: FeatureVersion,
     pub verify_identity_ids_by_public_key_hashes: FeatureVersion,
     pub verify_identity_keys_by_identity_id: FeatureVersion,
+    pub verify_identity_nonce_for_identity_id: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -95,6 +98,11 @@ pub struct DriveVerifySystemMethodVersions {
     pub verify_upgrade_vote_status: FeatureVersion,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveVerifyStateTransitionMethodVersions {
+    pub verify_state_transition_was_executed_with_proof: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveVerifySingleDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
@@ -136,6 +144,7 @@ pub struct DriveContractMethodVersions {
     pub apply: DriveContractApplyMethodVersions,
     pub insert: DriveContractInsertMethodVersions,
     pub update: DriveContractUpdateMethodVersions,
+    pub delete: DriveContractDeleteMethodVersions,
     pub costs: DriveContractCostsMethodVersions,
     pub get: DriveContractGetMethodVersions,
 }
@@ -164,6 +173,11 @@ pub struct DriveContractUpdateMethodVersions {
     pub update_contract: FeatureVersion,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveContractDeleteMethodVersions {
+    pub delete_contract: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveContractGetMethodVersions {
     pub fetch_contract: FeatureVersion,
@@ -230,6 +244,23 @@ pub struct DriveDocumentMethodVersions {
     pub update: DriveDocumentUpdateMethodVersions,
     pub estimation_costs: DriveDocumentEstimationCostsMethodVersions,
     pub index_uniqueness: DriveDocumentIndexUniquenessMethodVersions,
+    pub index_backfill: DriveDocumentIndexBackfillMethodVersions,
+    pub expiry: DriveDocumentExpiryMethodVersions,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentIndexBackfillMethodVersions {
+    pub fetch_index_backfill: FeatureVersion,
+    pub fetch_pending_index_backfills: FeatureVersion,
+    pub backfill_document_type_indices: FeatureVersion,
+    pub delete_index_backfill_operations: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentExpiryMethodVersions {
+    pub add_document_expiry_operations: FeatureVersion,
+    pub remove_document_expiry_operations: FeatureVersion,
+    pub delete_expired_documents: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -253,6 +284,7 @@ pub struct DriveDocumentInsertMethodVersions {
     pub add_indices_for_index_level_for_contract_operations: FeatureVersion,
     pub add_indices_for_top_index_level_for_contract_operations: FeatureVersion,
     pub add_reference_for_index_level_for_contract_operations: FeatureVersion,
+    pub add_aggregates_for_index_level_for_contract_operations: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -274,6 +306,7 @@ pub struct DriveDocumentDeleteMethodVersions {
     pub delete_document_for_contract_apply_and_add_to_operations: FeatureVersion,
     pub remove_document_from_primary_storage: FeatureVersion,
     pub remove_reference_for_index_level_for_contract_operations: FeatureVersion,
+    pub remove_aggregates_for_index_level_for_contract_operations: FeatureVersion,
     pub remove_indices_for_index_level_for_contract_operations: FeatureVersion,
     pub remove_indices_for_top_index_level_for_contract_operations: FeatureVersion,
     pub delete_document_for_contract_id_with_named_type_operations: FeatureVersion,
@@ -316,6 +349,7 @@ pub struct DriveGroveBatchMethodVersions {
     pub batch_insert_empty_tree: FeatureVersion,
     pub batch_insert_empty_tree_if_not_exists: FeatureVersion,
     pub batch_insert_empty_tree_if_not_exists_check_existing_operations: FeatureVersion,
+    pub batch_insert_empty_sum_tree_if_not_exists: FeatureVersion,
     pub batch_insert: FeatureVersion,
     pub batch_insert_if_not_exists: FeatureVersion,
     pub batch_insert_if_changed_value: FeatureVersion,
@@ -368,6 +402,8 @@ pub struct DriveCreditPoolEpochsMethodVersions {
     pub get_epochs_proposer_block_count: FeatureVersion,
     pub add_update_pending_epoch_refunds_operations: FeatureVersion,
     pub is_epochs_proposers_tree_empty: FeatureVersion,
+    pub get_epochs_proposer_tips: FeatureVersion,
+    pub get_epoch_proposers_tips: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -460,6 +496,7 @@ pub struct DriveIdentityFetchAttributesMethodVersions {
     pub balance: FeatureVersion,
     pub balance_include_debt: FeatureVersion,
     pub negative_balance: FeatureVersion,
+    pub nonce: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -536,6 +573,7 @@ pub struct DriveIdentityInsertMethodVersions {
 #[derive(Clone, Debug, Default)]
 pub struct DriveIdentityUpdateMethodVersions {
     pub update_identity_revision: FeatureVersion,
+    pub update_identity_nonce: FeatureVersion,
     pub update_identity_negative_credit_operation: FeatureVersion,
     pub initialize_identity_revision: FeatureVersion,
     pub disable_identity_keys: FeatureVersion,
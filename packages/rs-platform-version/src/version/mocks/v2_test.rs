@@ -13,13 +13,15 @@ use crate::version::drive_abci_versions::{
     DriveAbciAssetLockValidationVersions, DriveAbciBlockEndMethodVersions,
     DriveAbciBlockFeeProcessingMethodVersions, DriveAbciBlockStartMethodVersions,
     DriveAbciCoreBasedUpdatesMethodVersions, DriveAbciCoreSubsidyMethodVersions,
-    DriveAbciDocumentsStateTransitionValidationVersions, DriveAbciEngineMethodVersions,
-    DriveAbciEpochMethodVersions, DriveAbciFeePoolInwardsDistributionMethodVersions,
+    DriveAbciDocumentExpiryMethodVersions, DriveAbciDocumentsStateTransitionValidationVersions,
+    DriveAbciEngineMethodVersions, DriveAbciEpochMethodVersions,
+    DriveAbciFeePoolInwardsDistributionMethodVersions,
     DriveAbciFeePoolOutwardsDistributionMethodVersions,
-    DriveAbciIdentityCreditWithdrawalMethodVersions, DriveAbciInitializationMethodVersions,
-    DriveAbciMasternodeIdentitiesUpdatesMethodVersions, DriveAbciMethodVersions,
-    DriveAbciProtocolUpgradeMethodVersions, DriveAbciQueryDataContractVersions,
-    DriveAbciQueryIdentityVersions, DriveAbciQuerySystemVersions, DriveAbciQueryVersions,
+    DriveAbciIdentityCreditWithdrawalMethodVersions, DriveAbciIndexBackfillMethodVersions,
+    DriveAbciInitializationMethodVersions, DriveAbciMasternodeIdentitiesUpdatesMethodVersions,
+    DriveAbciMethodVersions, DriveAbciProtocolUpgradeMethodVersions,
+    DriveAbciQueryDataContractVersions, DriveAbciQueryIdentityVersions,
+    DriveAbciQuerySystemVersions, DriveAbciQueryVersions,
     DriveAbciStateTransitionCommonValidationVersions,
     DriveAbciStateTransitionProcessingMethodVersions, DriveAbciStateTransitionValidationVersion,
     DriveAbciStateTransitionValidationVersions, DriveAbciStructureVersions,
@@ -29,13 +31,15 @@ use crate::version::drive_abci_versions::{
 use crate::version::drive_versions::{
     DriveAssetLockMethodVersions, DriveBalancesMethodVersions, DriveBatchOperationsMethodVersion,
     DriveContractApplyMethodVersions, DriveContractCostsMethodVersions,
-    DriveContractGetMethodVersions, DriveContractInsertMethodVersions, DriveContractMethodVersions,
+    DriveContractDeleteMethodVersions, DriveContractGetMethodVersions,
+    DriveContractInsertMethodVersions, DriveContractMethodVersions,
     DriveContractProveMethodVersions, DriveContractUpdateMethodVersions,
     DriveCreditPoolEpochsMethodVersions, DriveCreditPoolMethodVersions,
     DriveCreditPoolPendingEpochRefundsMethodVersions,
     DriveCreditPoolStorageFeeDistributionPoolMethodVersions,
     DriveDataContractOperationMethodVersions, DriveDocumentDeleteMethodVersions,
-    DriveDocumentEstimationCostsMethodVersions, DriveDocumentIndexUniquenessMethodVersions,
+    DriveDocumentEstimationCostsMethodVersions, DriveDocumentExpiryMethodVersions,
+    DriveDocumentIndexBackfillMethodVersions, DriveDocumentIndexUniquenessMethodVersions,
     DriveDocumentInsertMethodVersions, DriveDocumentMethodVersions,
     DriveDocumentQueryMethodVersions, DriveDocumentUpdateMethodVersions,
     DriveEstimatedCostsMethodVersions, DriveFeesMethodVersions, DriveGroveApplyMethodVersions,
@@ -56,7 +60,7 @@ use crate::version::drive_versions::{
     DriveSystemProtocolVersionMethodVersions, DriveVerifyContractMethodVersions,
     DriveVerifyDocumentMethodVersions, DriveVerifyIdentityMethodVersions,
     DriveVerifyMethodVersions, DriveVerifySingleDocumentMethodVersions,
-    DriveVerifySystemMethodVersions, DriveVersion,
+    DriveVerifyStateTransitionMethodVersions, DriveVerifySystemMethodVersions, DriveVersion,
 };
 use crate::version::mocks::TEST_BYTES;
 use crate::version::protocol_version::{FeatureVersionBounds, PlatformVersion};
@@ -115,6 +119,8 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     get_epochs_proposer_block_count: 0,
                     add_update_pending_epoch_refunds_operations: 0,
                     is_epochs_proposers_tree_empty: 0,
+                    get_epochs_proposer_tips: 0,
+                    get_epoch_proposers_tips: 0,
                 },
                 pending_epoch_refunds: DriveCreditPoolPendingEpochRefundsMethodVersions {
                     add_delete_pending_epoch_refunds_except_specified: 0,
@@ -154,6 +160,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     delete_document_for_contract_apply_and_add_to_operations: 0,
                     remove_document_from_primary_storage: 0,
                     remove_reference_for_index_level_for_contract_operations: 0,
+                    remove_aggregates_for_index_level_for_contract_operations: 0,
                     remove_indices_for_index_level_for_contract_operations: 0,
                     remove_indices_for_top_index_level_for_contract_operations: 0,
                     delete_document_for_contract_id_with_named_type_operations: 0,
@@ -169,6 +176,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     add_indices_for_index_level_for_contract_operations: 0,
                     add_indices_for_top_index_level_for_contract_operations: 0,
                     add_reference_for_index_level_for_contract_operations: 0,
+                    add_aggregates_for_index_level_for_contract_operations: 0,
                 },
                 update: DriveDocumentUpdateMethodVersions {
                     add_update_multiple_documents_operations: 0,
@@ -189,6 +197,17 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     validate_document_replace_transition_action_uniqueness: 0,
                     validate_uniqueness_of_data: 0,
                 },
+                index_backfill: DriveDocumentIndexBackfillMethodVersions {
+                    fetch_index_backfill: 0,
+                    fetch_pending_index_backfills: 0,
+                    backfill_document_type_indices: 0,
+                    delete_index_backfill_operations: 0,
+                },
+                expiry: DriveDocumentExpiryMethodVersions {
+                    add_document_expiry_operations: 0,
+                    remove_document_expiry_operations: 0,
+                    delete_expired_documents: 0,
+                },
             },
             contract: DriveContractMethodVersions {
                 prove: DriveContractProveMethodVersions {
@@ -205,6 +224,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     insert_contract: 0,
                 },
                 update: DriveContractUpdateMethodVersions { update_contract: 0 },
+                delete: DriveContractDeleteMethodVersions { delete_contract: 0 },
                 costs: DriveContractCostsMethodVersions {
                     add_estimation_costs_for_contract_insertion: 0,
                 },
@@ -235,6 +255,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_proof: 0,
                     verify_proof_keep_serialized: 0,
                     verify_start_at_document_in_proof: 0,
+                    verify_documents_aggregate: 0,
                 },
                 identity: DriveVerifyIdentityMethodVersions {
                     verify_full_identities_by_public_key_hashes: 0,
@@ -245,6 +266,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_identity_id_by_public_key_hash: 0,
                     verify_identity_ids_by_public_key_hashes: 0,
                     verify_identity_keys_by_identity_id: 0,
+                    verify_identity_nonce_for_identity_id: 0,
                 },
                 single_document: DriveVerifySingleDocumentMethodVersions {
                     verify_proof: 0,
@@ -255,6 +277,9 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_upgrade_state: 0,
                     verify_upgrade_vote_status: 0,
                 },
+                state_transition: DriveVerifyStateTransitionMethodVersions {
+                    verify_state_transition_was_executed_with_proof: 0,
+                },
             },
             identity: DriveIdentityMethodVersions {
                 fetch: DriveIdentityFetchMethodVersions {
@@ -275,6 +300,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                         balance: 0,
                         balance_include_debt: 0,
                         negative_balance: 0,
+                        nonce: 0,
                     },
                     partial_identity: DriveIdentityFetchPartialIdentityMethodVersions {
                         fetch_identity_balance_with_keys: 0,
@@ -326,6 +352,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 },
                 update: DriveIdentityUpdateMethodVersions {
                     update_identity_revision: 0,
+                    update_identity_nonce: 0,
                     update_identity_negative_credit_operation: 0,
                     initialize_identity_revision: 0,
                     disable_identity_keys: 0,
@@ -417,6 +444,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 batch_insert_empty_tree: 0,
                 batch_insert_empty_tree_if_not_exists: 0,
                 batch_insert_empty_tree_if_not_exists_check_existing_operations: 0,
+                batch_insert_empty_sum_tree_if_not_exists: 0,
                 batch_insert: 0,
                 batch_insert_if_not_exists: 0,
                 batch_insert_if_changed_value: 0,
@@ -451,7 +479,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
     drive_abci: DriveAbciVersion {
         structs: DriveAbciStructureVersions {
             platform_state_structure: 0,
-            platform_state_for_saving_structure: 0,
+            platform_state_for_saving_structure: 1,
             state_transition_execution_context: 0,
             commit: 0,
             masternode: 0,
@@ -460,6 +488,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
             engine: DriveAbciEngineMethodVersions {
                 init_chain: 0,
                 check_tx: 0,
+                estimate_state_transition_fee: 0,
                 run_block_proposal: 0,
                 finalize_block_proposal: 0,
             },
@@ -519,6 +548,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 execute_event: 0,
                 process_raw_state_transitions: 0,
                 validate_fees_of_event: 0,
+                collect_change_feed_events: 0,
             },
             withdrawals: DriveAbciWithdrawalsMethodVersions {
                 check_withdrawals: 0,
@@ -535,6 +565,15 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 update_state_cache: 0,
                 update_drive_cache: 0,
                 validator_set_update: 0,
+                store_historical_state: 0,
+                store_snapshot: 0,
+                update_consensus_params: 0,
+            },
+            index_backfill: DriveAbciIndexBackfillMethodVersions {
+                process_index_backfills: 0,
+            },
+            document_expiry: DriveAbciDocumentExpiryMethodVersions {
+                remove_expired_documents: 0,
             },
         },
         validation_and_processing: DriveAbciValidationVersions {
@@ -593,6 +632,12 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     state: 0,
                     transform_into_action: 0,
                 },
+                contract_delete_state_transition: DriveAbciStateTransitionValidationVersion {
+                    structure: 0,
+                    identity_signatures: None,
+                    state: 0,
+                    transform_into_action: 0,
+                },
                 documents_batch_state_transition:
                     DriveAbciDocumentsStateTransitionValidationVersions {
                         structure: 0,
@@ -613,9 +658,15 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                         document_create_transition_structure_validation: 0,
                         document_delete_transition_structure_validation: 0,
                         document_replace_transition_structure_validation: 0,
+                        document_transfer_transition_structure_validation: 0,
+                        document_update_price_transition_structure_validation: 0,
+                        document_purchase_transition_structure_validation: 0,
                         document_create_transition_state_validation: 0,
                         document_delete_transition_state_validation: 0,
                         document_replace_transition_state_validation: 0,
+                        document_transfer_transition_state_validation: 0,
+                        document_update_price_transition_state_validation: 0,
+                        document_purchase_transition_state_validation: 0,
                     },
             },
             process_state_transition: 0,
@@ -633,6 +684,16 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            document_count_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            state_transition_fee_estimation_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             identity_based_queries: DriveAbciQueryIdentityVersions {
                 identity: FeatureVersionBounds {
                     min_version: 0,
@@ -659,6 +720,11 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     max_version: 0,
                     default_current_version: 0,
                 },
+                nonce: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
                 identity_by_public_key_hash: FeatureVersionBounds {
                     min_version: 0,
                     max_version: 0,
@@ -767,6 +833,11 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            contract_delete_state_transition: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             documents_batch_state_transition: FeatureVersionBounds {
                 min_version: 0,
                 max_version: 0,
@@ -798,6 +869,34 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     default_current_version: 0,
                 },
             },
+            document_transfer_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_update_price_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_purchase_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_patch_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
         },
         state_transition_conversion_versions: StateTransitionConversionVersions {
             identity_to_identity_create_transition: 0,
@@ -0,0 +1,278 @@
+//! Caching of data retrieved from the Platform.
+//!
+//! [Sdk](crate::Sdk) keeps recently retrieved data contracts, quorum public keys and, optionally, identities in
+//! memory, so that they don't need to be downloaded and verified again. See [CacheSettings] for configuration.
+
+use std::{ops::RangeInclusive, sync::Arc, time::Duration};
+
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::prelude::{DataContract, Identifier, Identity};
+use moka::sync::Cache;
+
+/// Default maximum number of cached data contracts.
+const DEFAULT_DATA_CONTRACTS_CAPACITY: u64 = 100;
+/// Default number of blocks after which a mutable data contract is retrieved again.
+const DEFAULT_DATA_CONTRACT_MAX_AGE: u64 = 100;
+/// Default maximum number of cached quorum public keys.
+const DEFAULT_QUORUM_PUBLIC_KEYS_CAPACITY: u64 = 100;
+/// Default maximum number of cached identities.
+const DEFAULT_IDENTITIES_CAPACITY: u64 = 1000;
+
+/// Settings of the [Sdk](crate::Sdk) cache.
+///
+/// Capacity is the maximum number of objects given cache holds; when it's full, some objects are evicted
+/// to make room for new ones. Capacity of `0` disables given cache.
+///
+/// By default, data contracts and quorum public keys are cached, and identities are not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheSettings {
+    /// Maximum number of cached data contracts.
+    pub data_contracts: u64,
+    /// Number of blocks after which a mutable data contract is retrieved again.
+    ///
+    /// Read-only data contracts can't change, so they are kept until evicted.
+    pub data_contract_max_age: u64,
+    /// Maximum number of cached quorum public keys.
+    pub quorum_public_keys: u64,
+    /// Maximum number of cached identities.
+    pub identities: u64,
+    /// Time after which a cached identity is retrieved again; `None` disables caching of identities.
+    ///
+    /// Balance, revision and keys of an identity can change with any block, so cached identities
+    /// can be outdated for up to this time.
+    pub identity_ttl: Option<Duration>,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self {
+            data_contracts: DEFAULT_DATA_CONTRACTS_CAPACITY,
+            data_contract_max_age: DEFAULT_DATA_CONTRACT_MAX_AGE,
+            quorum_public_keys: DEFAULT_QUORUM_PUBLIC_KEYS_CAPACITY,
+            identities: DEFAULT_IDENTITIES_CAPACITY,
+            identity_ttl: None,
+        }
+    }
+}
+
+/// Latest known version of a data contract.
+#[derive(Clone, Copy)]
+struct LatestDataContractVersion {
+    version: u32,
+    /// Platform height at which the version was retrieved.
+    height: u64,
+}
+
+/// Quorum public key stored in the cache.
+#[derive(Clone)]
+struct CachedQuorumPublicKey {
+    public_key: [u8; 48],
+    /// Core chain locked heights at which the quorum provider accepted the key.
+    ///
+    /// A quorum is active over a continuous range of heights, so it's active at any height
+    /// between two heights it was accepted at.
+    active_heights: RangeInclusive<u32>,
+}
+
+/// In-memory cache of data retrieved from the Platform.
+///
+/// Used internally by [Sdk](crate::Sdk); available with [Sdk::cache()](crate::Sdk::cache()) to inspect
+/// or invalidate cached objects.
+pub struct SdkCache {
+    settings: CacheSettings,
+    /// Data contracts, by data contract ID and version
+    data_contracts: Option<Cache<(Identifier, u32), Arc<DataContract>>>,
+    /// Latest known version of each cached data contract, by data contract ID
+    latest_data_contract_versions: Option<Cache<Identifier, LatestDataContractVersion>>,
+    /// Quorum public keys, by quorum type and quorum hash
+    quorum_public_keys: Option<Cache<(u32, [u8; 32]), CachedQuorumPublicKey>>,
+    identities: Option<Cache<Identifier, Identity>>,
+}
+
+impl SdkCache {
+    /// Create new cache with provided settings.
+    pub fn new(settings: CacheSettings) -> Self {
+        let data_contracts =
+            (settings.data_contracts > 0).then(|| Cache::new(settings.data_contracts));
+        let latest_data_contract_versions =
+            (settings.data_contracts > 0).then(|| Cache::new(settings.data_contracts));
+
+        let quorum_public_keys =
+            (settings.quorum_public_keys > 0).then(|| Cache::new(settings.quorum_public_keys));
+
+        let identities = match settings.identity_ttl {
+            Some(ttl) if settings.identities > 0 => Some(
+                Cache::builder()
+                    .max_capacity(settings.identities)
+                    .time_to_live(ttl)
+                    .build(),
+            ),
+            _ => None,
+        };
+
+        Self {
+            settings,
+            data_contracts,
+            latest_data_contract_versions,
+            quorum_public_keys,
+            identities,
+        }
+    }
+
+    /// Settings of this cache.
+    pub fn settings(&self) -> CacheSettings {
+        self.settings
+    }
+
+    /// Get the latest version of data contract with provided `id`, if it's up to date at Platform `height`.
+    ///
+    /// Mutable data contracts retrieved more than [CacheSettings::data_contract_max_age] blocks before
+    /// `height` are no longer returned, until a newer retrieval is stored.
+    pub fn data_contract(&self, id: &Identifier, height: u64) -> Option<Arc<DataContract>> {
+        let latest_versions = self.latest_data_contract_versions.as_ref()?;
+        let latest = latest_versions.get(id)?;
+
+        let contract = self.data_contract_version(id, latest.version)?;
+
+        if !contract.config().readonly()
+            && height.saturating_sub(latest.height) > self.settings.data_contract_max_age
+        {
+            latest_versions.invalidate(id);
+            return None;
+        }
+
+        Some(contract)
+    }
+
+    /// Get data contract with provided `id` and `version`.
+    ///
+    /// A version of a data contract never changes, so it's returned regardless of its age.
+    pub fn data_contract_version(
+        &self,
+        id: &Identifier,
+        version: u32,
+    ) -> Option<Arc<DataContract>> {
+        self.data_contracts.as_ref()?.get(&(*id, version))
+    }
+
+    /// Store data contract retrieved at Platform `height`.
+    ///
+    /// Data contract versions only increase, so a contract older than the latest cached one is
+    /// stored only under its own version.
+    pub fn insert_data_contract(&self, contract: Arc<DataContract>, height: u64) {
+        let (Some(cache), Some(latest_versions)) =
+            (&self.data_contracts, &self.latest_data_contract_versions)
+        else {
+            return;
+        };
+
+        let id = contract.id();
+        let version = contract.version();
+
+        cache.insert((id, version), contract);
+
+        if let Some(latest) = latest_versions.get(&id) {
+            if latest.version > version {
+                return;
+            }
+        }
+
+        latest_versions.insert(id, LatestDataContractVersion { version, height });
+    }
+
+    /// Stop returning cached versions of data contract with provided `id` from [SdkCache::data_contract()].
+    ///
+    /// Versions requested explicitly with [SdkCache::data_contract_version()] are kept.
+    pub fn invalidate_data_contract(&self, id: &Identifier) {
+        if let Some(latest_versions) = &self.latest_data_contract_versions {
+            latest_versions.invalidate(id);
+        }
+    }
+
+    /// Get public key of a quorum, if it's known to be active at `core_chain_locked_height`.
+    ///
+    /// Keys are returned only for heights between the lowest and the highest core chain locked
+    /// height they were stored at; for other heights the quorum provider must check them again.
+    pub fn quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+    ) -> Option<[u8; 48]> {
+        let cached = self
+            .quorum_public_keys
+            .as_ref()?
+            .get(&(quorum_type, quorum_hash))?;
+
+        cached
+            .active_heights
+            .contains(&core_chain_locked_height)
+            .then_some(cached.public_key)
+    }
+
+    /// Store public key of a quorum, accepted by the quorum provider at `core_chain_locked_height`.
+    pub fn insert_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+        public_key: [u8; 48],
+    ) {
+        let Some(cache) = &self.quorum_public_keys else {
+            return;
+        };
+
+        let active_heights = match cache.get(&(quorum_type, quorum_hash)) {
+            Some(cached) if cached.public_key == public_key => {
+                (*cached.active_heights.start()).min(core_chain_locked_height)
+                    ..=(*cached.active_heights.end()).max(core_chain_locked_height)
+            }
+            _ => core_chain_locked_height..=core_chain_locked_height,
+        };
+
+        cache.insert(
+            (quorum_type, quorum_hash),
+            CachedQuorumPublicKey {
+                public_key,
+                active_heights,
+            },
+        );
+    }
+
+    /// Get identity with provided `id`, if it was retrieved less than [CacheSettings::identity_ttl] ago.
+    pub fn identity(&self, id: &Identifier) -> Option<Identity> {
+        self.identities.as_ref()?.get(id)
+    }
+
+    /// Store identity.
+    pub fn insert_identity(&self, identity: Identity) {
+        if let Some(cache) = &self.identities {
+            cache.insert(identity.id(), identity);
+        }
+    }
+
+    /// Remove identity with provided `id` from the cache.
+    pub fn invalidate_identity(&self, id: &Identifier) {
+        if let Some(cache) = &self.identities {
+            cache.invalidate(id);
+        }
+    }
+
+    /// Remove all objects from the cache.
+    pub fn clear(&self) {
+        if let Some(cache) = &self.data_contracts {
+            cache.invalidate_all();
+        }
+        if let Some(cache) = &self.latest_data_contract_versions {
+            cache.invalidate_all();
+        }
+        if let Some(cache) = &self.quorum_public_keys {
+            cache.invalidate_all();
+        }
+        if let Some(cache) = &self.identities {
+            cache.invalidate_all();
+        }
+    }
+}
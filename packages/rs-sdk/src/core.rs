@@ -1,14 +1,24 @@
-//! Core RPC client used to retrieve quorum keys from core.
+//! Sources of Dash Core data, like quorum keys.
 //!
-//! TODO: This is a temporary implementation, effective until we integrate SPV
+//! * [CoreClient] retrieves them from a Core node over JSON-RPC.
+//! * [DapiQuorumProvider] retrieves and verifies them through DAPI, without a Core node.
+//!
+//! TODO: [CoreClient] is a temporary implementation, effective until we integrate SPV
 //! into rs-sdk.
 
+mod dapi_provider;
+mod difficulty;
+
+pub use dapi_provider::{Checkpoint, DapiQuorumProvider};
+
 use dashcore_rpc::{
     dashcore::{hashes::Hash, QuorumHash},
-    dashcore_rpc_json::QuorumType,
+    dashcore_rpc_json::{MasternodeListDiff, MasternodeType, QuorumType},
 };
 use drive_abci::rpc::core::{CoreRPCLike, DefaultCoreRPC};
 use drive_proof_verifier::QuorumInfoProvider;
+use http::Uri;
+use std::net::SocketAddr;
 use std::sync::Mutex;
 
 use crate::error::Error;
@@ -45,6 +55,38 @@ impl CoreClient {
             core: Mutex::new(Box::new(core)),
         })
     }
+
+    /// Retrieve DAPI addresses of evonodes from the masternode list at the best chain locked height.
+    ///
+    /// Evonodes that are PoSe-banned or don't announce a Platform HTTP port are skipped.
+    pub fn evonode_addresses(&self) -> Result<Vec<Uri>, Error> {
+        let core = self.core.lock().expect("Core lock poisoned");
+
+        let height = core.get_best_chain_lock()?.core_block_height;
+        let MasternodeListDiff { added_mns, .. } =
+            core.get_protx_diff_with_masternodes(None, height)?;
+
+        let addresses = added_mns
+            .into_iter()
+            .filter(|masternode| {
+                masternode.node_type == MasternodeType::Evo
+                    && masternode.state.pose_ban_height.is_none()
+            })
+            .filter_map(|masternode| {
+                let port = masternode.state.platform_http_port?;
+                let address = SocketAddr::new(masternode.state.service.ip(), port as u16);
+
+                format!("https://{}", address)
+                    .parse::<Uri>()
+                    .map_err(|e| {
+                        tracing::warn!(pro_tx_hash = %masternode.pro_tx_hash, error = %e, "invalid evonode address")
+                    })
+                    .ok()
+            })
+            .collect();
+
+        Ok(addresses)
+    }
 }
 
 impl QuorumInfoProvider for CoreClient {
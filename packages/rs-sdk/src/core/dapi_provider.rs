@@ -0,0 +1,817 @@
+//! Quorum public key provider that retrieves quorums through DAPI instead of a Core node.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use dapi_grpc::core::v0::{
+    block_headers_with_chain_locks_request::FromBlock,
+    block_headers_with_chain_locks_response::Responses, get_block_request,
+    BlockHeadersWithChainLocksRequest, GetBlockRequest,
+};
+use dashcore_rpc::dashcore::{
+    block::Header, blockdata::transaction::special_transaction::TransactionPayload,
+    consensus::deserialize, ephemerealdata::chain_lock::ChainLock, hashes::Hash, Block, BlockHash,
+    Network,
+};
+use drive_proof_verifier::QuorumInfoProvider;
+use rs_dapi_client::{DapiClient, RequestSettings};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::difficulty::{required_bits, HeaderDifficulty, DGW_PAST_BLOCKS};
+use crate::error::Error;
+
+/// Number of blocks below the tip that are considered final if the tip isn't chain locked.
+const UNCONFIRMED_BLOCKS: u32 = 6;
+
+/// Quorums mined more blocks ago than this are removed from the cache.
+///
+/// This is about a week of blocks, much longer than any quorum stays active.
+const QUORUM_RETENTION_BLOCKS: u32 = 4032;
+
+/// A Core block trusted by the user, used as the root of verification of block headers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Height of the block
+    pub height: u32,
+    /// Hash of the block
+    pub block_hash: BlockHash,
+}
+
+/// [QuorumInfoProvider] that retrieves quorum public keys from Core blocks served by DAPI.
+///
+/// Block headers are downloaded from the [Checkpoint] up to the chain locked tip, and each of
+/// them must link to the previous one and carry valid proof of work at the difficulty required
+/// by Dark Gravity Wave, starting from the headers preceding the checkpoint. Blocks are then
+/// verified against their headers, and public keys are taken from quorum commitments mined in
+/// them. Chain lock signatures are not verified; the checkpoint is the root of trust.
+///
+/// Anyone can mine blocks on devnets and regtest, so their difficulty is not verified.
+///
+/// The checkpoint must be older than the quorums used to sign Platform blocks, so it should be
+/// at least a day (576 blocks) behind the tip.
+///
+/// Quorums are kept in memory and, if configured with [DapiQuorumProvider::with_cache()], on
+/// disk. [DapiQuorumProvider::sync()] should be called before the first use, and it is called
+/// in the background when a quorum that's not known yet is requested, to follow quorum rotation.
+#[derive(Clone)]
+pub struct DapiQuorumProvider {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    dapi: Mutex<DapiClient>,
+    network: Network,
+    checkpoint: Checkpoint,
+    cache_path: Option<PathBuf>,
+    state: RwLock<QuorumState>,
+    syncing: AtomicBool,
+}
+
+/// Verified Core chain and quorums mined in it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct QuorumState {
+    /// Height of the checkpoint the state was verified from
+    checkpoint_height: u32,
+    /// Hash of the checkpoint the state was verified from
+    checkpoint_hash: [u8; 32],
+    /// Height of the last verified block
+    height: u32,
+    /// Hash of the last verified block
+    block_hash: [u8; 32],
+    /// Quorums mined in verified blocks
+    quorums: Vec<CachedQuorum>,
+    /// Difficulty of the last verified blocks, oldest first
+    #[serde(default)]
+    recent_headers: Vec<HeaderDifficulty>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CachedQuorum {
+    quorum_type: u32,
+    quorum_hash: [u8; 32],
+    public_key: Vec<u8>,
+    mined_at_height: u32,
+}
+
+impl QuorumState {
+    fn new(checkpoint: &Checkpoint) -> Self {
+        Self {
+            checkpoint_height: checkpoint.height,
+            checkpoint_hash: checkpoint.block_hash.to_byte_array(),
+            height: checkpoint.height,
+            block_hash: checkpoint.block_hash.to_byte_array(),
+            quorums: Vec::new(),
+            recent_headers: Vec::new(),
+        }
+    }
+
+    fn is_verified_from(&self, checkpoint: &Checkpoint) -> bool {
+        self.checkpoint_height == checkpoint.height
+            && self.checkpoint_hash == checkpoint.block_hash.to_byte_array()
+    }
+
+    /// Public key of a quorum that could sign a Platform block at `core_chain_locked_height`.
+    ///
+    /// Returns `None` if the quorum is not known, but blocks up to `core_chain_locked_height`
+    /// are not verified yet.
+    fn quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+    ) -> Result<Option<Vec<u8>>, drive_proof_verifier::Error> {
+        let quorum = self
+            .quorums
+            .iter()
+            .find(|quorum| quorum.quorum_type == quorum_type && quorum.quorum_hash == quorum_hash);
+
+        match quorum {
+            Some(quorum) if quorum.mined_at_height > core_chain_locked_height => {
+                Err(drive_proof_verifier::Error::InvalidQuorum {
+                    error: format!(
+                        "quorum {} was mined at height {}, after core chain locked height {}",
+                        hex::encode(quorum_hash),
+                        quorum.mined_at_height,
+                        core_chain_locked_height
+                    ),
+                })
+            }
+            Some(quorum)
+                if core_chain_locked_height - quorum.mined_at_height > QUORUM_RETENTION_BLOCKS =>
+            {
+                Err(drive_proof_verifier::Error::InvalidQuorum {
+                    error: format!(
+                        "quorum {} mined at height {} is no longer active at core chain locked height {}",
+                        hex::encode(quorum_hash),
+                        quorum.mined_at_height,
+                        core_chain_locked_height
+                    ),
+                })
+            }
+            Some(quorum) => Ok(Some(quorum.public_key.clone())),
+            None if core_chain_locked_height > self.height => Ok(None),
+            None => Err(drive_proof_verifier::Error::InvalidQuorum {
+                error: format!(
+                    "quorum {} of type {} was not mined in verified blocks up to height {}",
+                    hex::encode(quorum_hash),
+                    quorum_type,
+                    self.height
+                ),
+            }),
+        }
+    }
+}
+
+impl DapiQuorumProvider {
+    /// Create new provider that verifies Core blocks of the `network` starting from the
+    /// `checkpoint`.
+    ///
+    /// Requests are sent with `dapi`, which should use different nodes than the [Sdk](crate::Sdk)
+    /// if possible.
+    pub fn new(dapi: DapiClient, network: Network, checkpoint: Checkpoint) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                dapi: Mutex::new(dapi),
+                network,
+                checkpoint,
+                cache_path: None,
+                state: RwLock::new(QuorumState::new(&checkpoint)),
+                syncing: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Keep verified quorums in a file, so they don't have to be downloaded again.
+    ///
+    /// The file is loaded if it exists and was verified from the same checkpoint, otherwise it
+    /// will be created by [DapiQuorumProvider::sync()].
+    pub fn with_cache(mut self, path: &Path) -> Self {
+        let inner = Arc::get_mut(&mut self.inner).expect("provider should not be shared yet");
+
+        match load_cache(path) {
+            Some(cached) if cached.is_verified_from(&inner.checkpoint) => {
+                inner.state = RwLock::new(cached);
+            }
+            Some(_) => {
+                tracing::info!(
+                    ?path,
+                    "quorum cache was verified from another checkpoint, ignoring"
+                )
+            }
+            None => {}
+        }
+
+        inner.cache_path = Some(path.to_path_buf());
+
+        self
+    }
+
+    /// Height of the last verified Core block.
+    pub fn verified_height(&self) -> u32 {
+        self.inner
+            .state
+            .read()
+            .expect("quorum state lock poisoned")
+            .height
+    }
+
+    /// Download and verify Core blocks mined since the last verified block, and store quorums
+    /// committed in them.
+    ///
+    /// Returns height of the last verified block.
+    pub async fn sync(&self) -> Result<u32, Error> {
+        let mut dapi = self.inner.dapi.lock().await;
+
+        let (height, block_hash, mut recent_headers) = {
+            let state = self.inner.state.read().expect("quorum state lock poisoned");
+            (
+                state.height,
+                BlockHash::from_byte_array(state.block_hash),
+                state.recent_headers.clone(),
+            )
+        };
+
+        let tip = dapi
+            .get_core_status(RequestSettings::default())
+            .await?
+            .chain
+            .map(|chain| chain.blocks_count)
+            .unwrap_or_default();
+
+        if tip <= height {
+            return Ok(height);
+        }
+
+        // Headers preceding the checkpoint, or the cache created before difficulty was verified
+        let needed_headers = DGW_PAST_BLOCKS.min(height as usize + 1);
+        if recent_headers.len() < needed_headers {
+            recent_headers =
+                download_recent_headers(&mut dapi, height, block_hash, needed_headers as u32)
+                    .await?;
+
+            self.inner
+                .state
+                .write()
+                .expect("quorum state lock poisoned")
+                .recent_headers
+                .clone_from(&recent_headers);
+        }
+
+        let headers = download_headers(
+            &mut dapi,
+            self.inner.network,
+            height,
+            block_hash,
+            recent_headers,
+            tip - height,
+        )
+        .await?;
+
+        for (index, header) in headers.iter().enumerate() {
+            let block_height = height + index as u32 + 1;
+            let block = download_block(&mut dapi, header).await?;
+
+            let mut state = self
+                .inner
+                .state
+                .write()
+                .expect("quorum state lock poisoned");
+
+            state
+                .quorums
+                .extend(quorum_commitments(&block, block_height));
+            state.height = block_height;
+            state.block_hash = header.block_hash().to_byte_array();
+            push_recent_header(&mut state.recent_headers, header);
+        }
+
+        let mut state = self
+            .inner
+            .state
+            .write()
+            .expect("quorum state lock poisoned");
+
+        let min_height = state.height.saturating_sub(QUORUM_RETENTION_BLOCKS);
+        state
+            .quorums
+            .retain(|quorum| quorum.mined_at_height >= min_height);
+
+        if let Some(path) = &self.inner.cache_path {
+            save_cache(path, &state);
+        }
+
+        tracing::debug!(
+            height = state.height,
+            quorums = state.quorums.len(),
+            "quorums synced"
+        );
+
+        Ok(state.height)
+    }
+
+    /// Start [DapiQuorumProvider::sync()] in the background, unless it's already running or
+    /// there is no Tokio runtime.
+    fn sync_in_background(&self) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        if self.inner.syncing.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let provider = self.clone();
+        runtime.spawn(async move {
+            if let Err(error) = provider.sync().await {
+                tracing::warn!(%error, "unable to sync quorums");
+            }
+
+            provider.inner.syncing.store(false, Ordering::Release);
+        });
+    }
+}
+
+impl QuorumInfoProvider for DapiQuorumProvider {
+    fn get_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+    ) -> Result<[u8; 48], drive_proof_verifier::Error> {
+        let public_key = self
+            .inner
+            .state
+            .read()
+            .expect("quorum state lock poisoned")
+            .quorum_public_key(quorum_type, quorum_hash, core_chain_locked_height)?;
+
+        let Some(public_key) = public_key else {
+            // Most likely a new quorum that was mined after the last sync
+            self.sync_in_background();
+
+            return Err(drive_proof_verifier::Error::InvalidQuorum {
+                error: format!(
+                    "quorum {} of type {} is not known yet, quorums are being synced",
+                    hex::encode(quorum_hash),
+                    quorum_type
+                ),
+            });
+        };
+
+        public_key
+            .try_into()
+            .map_err(|_| drive_proof_verifier::Error::InvalidQuorum {
+                error: "quorum public key is not 48 bytes long".to_string(),
+            })
+    }
+}
+
+/// Download `count` headers ending with the verified block at `height`, to verify difficulty of
+/// the blocks following it.
+///
+/// The headers are trusted because they form a chain that leads to the verified block.
+async fn download_recent_headers(
+    dapi: &mut DapiClient,
+    height: u32,
+    block_hash: BlockHash,
+    count: u32,
+) -> Result<Vec<HeaderDifficulty>, Error> {
+    let mut stream = dapi
+        .subscribe_core_block_headers(
+            BlockHeadersWithChainLocksRequest {
+                from_block: Some(FromBlock::FromBlockHeight(height + 1 - count)),
+                count,
+            },
+            RequestSettings::default(),
+        )
+        .await?;
+
+    let mut headers: Vec<Header> = Vec::with_capacity(count as usize);
+
+    while (headers.len() as u32) < count {
+        let Some(message) = stream
+            .message()
+            .await
+            .map_err(|e| Error::DapiClientError(format!("{:?}", e)))?
+        else {
+            break;
+        };
+
+        let Some(Responses::BlockHeaders(block_headers)) = message.responses else {
+            continue;
+        };
+
+        for raw_header in block_headers.headers {
+            let header = decode_header(&raw_header)?;
+
+            if let Some(previous) = headers.last() {
+                if header.prev_blockhash != previous.block_hash() {
+                    return Err(Error::InvalidCoreData(format!(
+                        "block {} doesn't follow block {}",
+                        header.block_hash(),
+                        previous.block_hash()
+                    )));
+                }
+            }
+
+            headers.push(header);
+        }
+    }
+
+    if headers.len() as u32 != count
+        || headers.last().map(|header| header.block_hash()) != Some(block_hash)
+    {
+        return Err(Error::InvalidCoreData(format!(
+            "headers preceding block {} don't lead to it",
+            block_hash
+        )));
+    }
+
+    Ok(headers.iter().map(HeaderDifficulty::from).collect())
+}
+
+/// Download `count` headers following the block at `height` and verify that they form a chain.
+///
+/// Difficulty of the first header is verified against `recent_headers`, which end with the block
+/// at `height`. Only headers that are chain locked or deep enough in the chain are returned.
+async fn download_headers(
+    dapi: &mut DapiClient,
+    network: Network,
+    height: u32,
+    block_hash: BlockHash,
+    mut recent_headers: Vec<HeaderDifficulty>,
+    count: u32,
+) -> Result<Vec<Header>, Error> {
+    let mut stream = dapi
+        .subscribe_core_block_headers(
+            BlockHeadersWithChainLocksRequest {
+                from_block: Some(FromBlock::FromBlockHeight(height + 1)),
+                count,
+            },
+            RequestSettings::default(),
+        )
+        .await?;
+
+    let mut headers: Vec<Header> = Vec::with_capacity(count as usize);
+    let mut chain_lock: Option<ChainLock> = None;
+
+    while (headers.len() as u32) < count {
+        let Some(message) = stream
+            .message()
+            .await
+            .map_err(|e| Error::DapiClientError(format!("{:?}", e)))?
+        else {
+            break;
+        };
+
+        match message.responses {
+            Some(Responses::BlockHeaders(block_headers)) => {
+                for raw_header in block_headers.headers {
+                    let header = decode_header(&raw_header)?;
+
+                    let previous_hash = headers
+                        .last()
+                        .map(|previous| previous.block_hash())
+                        .unwrap_or(block_hash);
+                    let previous_height = height + headers.len() as u32;
+
+                    verify_header(
+                        &header,
+                        previous_hash,
+                        network,
+                        previous_height,
+                        &recent_headers,
+                    )?;
+
+                    push_recent_header(&mut recent_headers, &header);
+                    headers.push(header);
+                }
+            }
+            Some(Responses::ChainLock(raw_chain_lock)) => {
+                let received: ChainLock = deserialize(&raw_chain_lock).map_err(|e| {
+                    Error::InvalidCoreData(format!("unable to decode chain lock: {}", e))
+                })?;
+
+                if chain_lock
+                    .as_ref()
+                    .map(|chain_lock| chain_lock.block_height < received.block_height)
+                    .unwrap_or(true)
+                {
+                    chain_lock = Some(received);
+                }
+            }
+            None => {}
+        }
+    }
+
+    let last_height = height + headers.len() as u32;
+
+    let chain_locked_height = chain_lock.and_then(|chain_lock| {
+        let index = chain_lock.block_height.checked_sub(height + 1)? as usize;
+
+        (headers.get(index)?.block_hash() == chain_lock.block_hash)
+            .then_some(chain_lock.block_height)
+    });
+
+    let final_height = chain_locked_height
+        .unwrap_or_else(|| last_height.saturating_sub(UNCONFIRMED_BLOCKS))
+        .max(height);
+
+    headers.truncate((final_height - height) as usize);
+
+    Ok(headers)
+}
+
+fn decode_header(raw_header: &[u8]) -> Result<Header, Error> {
+    deserialize(raw_header)
+        .map_err(|e| Error::InvalidCoreData(format!("unable to decode block header: {}", e)))
+}
+
+/// Append the header to difficulty of the last verified blocks, dropping ones no longer needed.
+fn push_recent_header(recent_headers: &mut Vec<HeaderDifficulty>, header: &Header) {
+    recent_headers.push(HeaderDifficulty::from(header));
+
+    if recent_headers.len() > DGW_PAST_BLOCKS {
+        recent_headers.drain(..recent_headers.len() - DGW_PAST_BLOCKS);
+    }
+}
+
+/// Check that the header follows the block with `previous_hash` at `previous_height`, and has
+/// valid proof of work at the difficulty required after `recent_headers`.
+fn verify_header(
+    header: &Header,
+    previous_hash: BlockHash,
+    network: Network,
+    previous_height: u32,
+    recent_headers: &[HeaderDifficulty],
+) -> Result<(), Error> {
+    if header.prev_blockhash != previous_hash {
+        return Err(Error::InvalidCoreData(format!(
+            "block {} doesn't follow block {}",
+            header.block_hash(),
+            previous_hash
+        )));
+    }
+
+    if let Some(bits) = required_bits(network, previous_height, recent_headers, header.time)? {
+        if header.bits.to_consensus() != bits {
+            return Err(Error::InvalidCoreData(format!(
+                "block {} has difficulty bits {:#010x}, but {:#010x} is required",
+                header.block_hash(),
+                header.bits.to_consensus(),
+                bits
+            )));
+        }
+    }
+
+    header.validate_pow(header.target()).map_err(|e| {
+        Error::InvalidCoreData(format!(
+            "block {} has invalid proof of work: {}",
+            header.block_hash(),
+            e
+        ))
+    })?;
+
+    Ok(())
+}
+
+/// Download the block of a verified header and check that it matches the header.
+async fn download_block(dapi: &mut DapiClient, header: &Header) -> Result<Block, Error> {
+    let block_hash = header.block_hash();
+
+    let response = dapi
+        .get_core_block(
+            GetBlockRequest {
+                block: Some(get_block_request::Block::Hash(block_hash.to_string())),
+            },
+            RequestSettings::default(),
+        )
+        .await?;
+
+    let block: Block = deserialize(&response.block).map_err(|e| {
+        Error::InvalidCoreData(format!("unable to decode block {}: {}", block_hash, e))
+    })?;
+
+    if block.block_hash() != block_hash || !block.check_merkle_root() {
+        return Err(Error::InvalidCoreData(format!(
+            "block {} doesn't match its header",
+            block_hash
+        )));
+    }
+
+    Ok(block)
+}
+
+/// Quorums committed in the block, excluding null commitments.
+fn quorum_commitments(block: &Block, height: u32) -> impl Iterator<Item = CachedQuorum> + '_ {
+    block.txdata.iter().filter_map(move |transaction| {
+        let Some(TransactionPayload::QuorumCommitmentPayloadType(payload)) =
+            &transaction.special_transaction_payload
+        else {
+            return None;
+        };
+
+        let commitment = &payload.finalization_commitment;
+
+        if !commitment.signers.iter().any(|signer| *signer) {
+            return None;
+        }
+
+        Some(CachedQuorum {
+            quorum_type: commitment.llmq_type as u32,
+            quorum_hash: commitment.quorum_hash.to_byte_array(),
+            public_key: commitment.quorum_public_key.to_bytes().to_vec(),
+            mined_at_height: height,
+        })
+    })
+}
+
+fn load_cache(path: &Path) -> Option<QuorumState> {
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            tracing::warn!(?path, error = %e, "unable to read quorum cache");
+            return None;
+        }
+    };
+
+    serde_json::from_slice(&data)
+        .map_err(|e| tracing::warn!(?path, error = %e, "unable to decode quorum cache"))
+        .ok()
+}
+
+fn save_cache(path: &Path, state: &QuorumState) {
+    let data = serde_json::to_vec(state).expect("encode quorum cache to json");
+
+    if let Err(e) = std::fs::write(path, data) {
+        tracing::warn!(?path, error = %e, "unable to write quorum cache");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dashcore_rpc::dashcore::{block::Version, CompactTarget, TxMerkleNode};
+
+    const BITS: u32 = 0x1a0a8a8b;
+    const TIME: u32 = 1700000000;
+
+    fn recent_headers() -> Vec<HeaderDifficulty> {
+        (0..DGW_PAST_BLOCKS as u32)
+            .map(|index| HeaderDifficulty {
+                time: TIME + index * 150,
+                bits: BITS,
+            })
+            .collect()
+    }
+
+    fn header(prev_blockhash: BlockHash, bits: u32) -> Header {
+        Header {
+            version: Version::from_consensus(0x20000000),
+            prev_blockhash,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: TIME + DGW_PAST_BLOCKS as u32 * 150,
+            bits: CompactTarget::from_consensus(bits),
+            nonce: 0,
+        }
+    }
+
+    fn quorum_state() -> QuorumState {
+        let mut state = QuorumState::new(&Checkpoint {
+            height: 1000,
+            block_hash: BlockHash::all_zeros(),
+        });
+        state.height = 2000;
+        state.quorums.push(CachedQuorum {
+            quorum_type: 4,
+            quorum_hash: [1; 32],
+            public_key: vec![2; 48],
+            mined_at_height: 1500,
+        });
+
+        state
+    }
+
+    #[test]
+    fn should_verify_header_following_previous_block() {
+        let previous_hash = BlockHash::from_byte_array([1; 32]);
+
+        // Difficulty of regtest is not verified, so it's easy to mine a valid block
+        let mut header = header(previous_hash, 0x207fffff);
+        while header.validate_pow(header.target()).is_err() {
+            header.nonce += 1;
+        }
+
+        verify_header(&header, previous_hash, Network::Regtest, 2000, &[])
+            .expect("expected valid header");
+
+        assert!(matches!(
+            verify_header(
+                &header,
+                BlockHash::from_byte_array([2; 32]),
+                Network::Regtest,
+                2000,
+                &[]
+            ),
+            Err(Error::InvalidCoreData(message)) if message.contains("doesn't follow")
+        ));
+    }
+
+    #[test]
+    fn should_reject_header_with_invalid_difficulty() {
+        let previous_hash = BlockHash::from_byte_array([1; 32]);
+
+        // Blocks were mined on time, so the difficulty must be retargeted
+        let header = header(previous_hash, BITS);
+
+        assert!(matches!(
+            verify_header(
+                &header,
+                previous_hash,
+                Network::Dash,
+                2000000,
+                &recent_headers()
+            ),
+            Err(Error::InvalidCoreData(message)) if message.contains("difficulty")
+        ));
+    }
+
+    #[test]
+    fn should_reject_header_without_proof_of_work() {
+        let previous_hash = BlockHash::from_byte_array([1; 32]);
+
+        let header = header(previous_hash, 0x1a0a1a1a);
+
+        assert!(matches!(
+            verify_header(
+                &header,
+                previous_hash,
+                Network::Dash,
+                2000000,
+                &recent_headers()
+            ),
+            Err(Error::InvalidCoreData(message)) if message.contains("proof of work")
+        ));
+    }
+
+    #[test]
+    fn should_keep_difficulty_of_last_blocks() {
+        let mut recent_headers = recent_headers();
+
+        push_recent_header(
+            &mut recent_headers,
+            &header(BlockHash::all_zeros(), 0x1a0a1a1a),
+        );
+
+        assert_eq!(recent_headers.len(), DGW_PAST_BLOCKS);
+        assert_eq!(recent_headers[0].time, TIME + 150);
+        assert_eq!(
+            recent_headers.last().map(|header| header.bits),
+            Some(0x1a0a1a1a)
+        );
+    }
+
+    #[test]
+    fn should_provide_public_key_of_quorum_active_at_core_chain_locked_height() {
+        let state = quorum_state();
+
+        assert_eq!(
+            state
+                .quorum_public_key(4, [1; 32], 1600)
+                .expect("expected quorum"),
+            Some(vec![2; 48])
+        );
+
+        // Mined after the Platform block was signed
+        assert!(matches!(
+            state.quorum_public_key(4, [1; 32], 1400),
+            Err(drive_proof_verifier::Error::InvalidQuorum { .. })
+        ));
+
+        // Too old to sign the Platform block
+        assert!(matches!(
+            state.quorum_public_key(4, [1; 32], 1500 + QUORUM_RETENTION_BLOCKS + 1),
+            Err(drive_proof_verifier::Error::InvalidQuorum { .. })
+        ));
+    }
+
+    #[test]
+    fn should_wait_for_sync_only_for_quorums_above_verified_height() {
+        let state = quorum_state();
+
+        assert!(matches!(
+            state.quorum_public_key(4, [3; 32], 1600),
+            Err(drive_proof_verifier::Error::InvalidQuorum { .. })
+        ));
+
+        assert_eq!(
+            state
+                .quorum_public_key(4, [3; 32], 2100)
+                .expect("expected quorum to be synced"),
+            None
+        );
+    }
+}
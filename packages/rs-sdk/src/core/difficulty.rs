@@ -0,0 +1,383 @@
+//! Difficulty of Core blocks, as retargeted by Dark Gravity Wave.
+
+use dashcore_rpc::dashcore::{block::Header, Network};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Number of past blocks Dark Gravity Wave averages difficulty over.
+pub(super) const DGW_PAST_BLOCKS: usize = 24;
+
+/// Expected time between blocks, in seconds.
+const TARGET_SPACING: i64 = 150;
+
+/// Time and difficulty of a verified block header, needed to verify difficulty of the next ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct HeaderDifficulty {
+    /// Block time, in seconds
+    pub time: u32,
+    /// Compact target
+    pub bits: u32,
+}
+
+impl From<&Header> for HeaderDifficulty {
+    fn from(header: &Header) -> Self {
+        Self {
+            time: header.time,
+            bits: header.bits.to_consensus(),
+        }
+    }
+}
+
+/// Difficulty rules of a network.
+struct PowParams {
+    /// Easiest target allowed
+    pow_limit: U256,
+    /// Height Dark Gravity Wave was activated at
+    dgw_height: u32,
+    /// Allow easier blocks if the previous one was mined long ago
+    allow_min_difficulty_blocks: bool,
+}
+
+impl PowParams {
+    /// Difficulty rules of the `network`, `None` if its difficulty can't be verified.
+    fn for_network(network: Network) -> Option<Self> {
+        let mut pow_limit = [0xff; 32];
+        pow_limit[..2].fill(0);
+        pow_limit[2] = 0x0f;
+
+        match network {
+            Network::Dash => Some(PowParams {
+                pow_limit: U256::from_be_bytes(pow_limit),
+                dgw_height: 34140,
+                allow_min_difficulty_blocks: false,
+            }),
+            Network::Testnet => Some(PowParams {
+                pow_limit: U256::from_be_bytes(pow_limit),
+                dgw_height: 4002,
+                allow_min_difficulty_blocks: true,
+            }),
+            // Anyone can mine on devnets and regtest, so their difficulty doesn't protect anything
+            _ => None,
+        }
+    }
+}
+
+/// Compact target required for the block mined at `time` after `recent_headers`.
+///
+/// `recent_headers` are the headers preceding the block, oldest first, and the last of them is at
+/// `height`. Returns `None` if difficulty is not verified on the `network`.
+pub(super) fn required_bits(
+    network: Network,
+    height: u32,
+    recent_headers: &[HeaderDifficulty],
+    time: u32,
+) -> Result<Option<u32>, Error> {
+    let Some(params) = PowParams::for_network(network) else {
+        return Ok(None);
+    };
+
+    if height + 1 < params.dgw_height {
+        return Err(Error::InvalidCoreData(format!(
+            "difficulty of blocks below height {} can't be verified",
+            params.dgw_height
+        )));
+    }
+
+    let Some(last) = recent_headers.last() else {
+        return Err(Error::InvalidCoreData(
+            "no headers to verify difficulty from".to_string(),
+        ));
+    };
+
+    if params.allow_min_difficulty_blocks {
+        let time_since_last = i64::from(time) - i64::from(last.time);
+
+        // The last block was mined more than 2 hours ago
+        if time_since_last > 2 * 60 * 60 {
+            return Ok(Some(params.pow_limit.to_compact()));
+        }
+
+        // The last block was mined more than 10 minutes ago
+        if time_since_last > 4 * TARGET_SPACING {
+            let target = U256::from_compact(last.bits).mul_u64(10);
+
+            return Ok(Some(target.min(params.pow_limit).to_compact()));
+        }
+    }
+
+    dark_gravity_wave(&params, height, recent_headers).map(Some)
+}
+
+/// Dark Gravity Wave v3, as implemented in Dash Core.
+fn dark_gravity_wave(
+    params: &PowParams,
+    height: u32,
+    recent_headers: &[HeaderDifficulty],
+) -> Result<u32, Error> {
+    if (height as usize) < DGW_PAST_BLOCKS {
+        return Ok(params.pow_limit.to_compact());
+    }
+
+    let Some(past_headers) = recent_headers
+        .len()
+        .checked_sub(DGW_PAST_BLOCKS)
+        .map(|start| &recent_headers[start..])
+    else {
+        return Err(Error::InvalidCoreData(format!(
+            "{} headers are needed to verify difficulty, got {}",
+            DGW_PAST_BLOCKS,
+            recent_headers.len()
+        )));
+    };
+
+    // Not really an average, but it's what Core does
+    let mut past_target_average = U256::ZERO;
+    for (count, header) in (1u64..).zip(past_headers.iter().rev()) {
+        let target = U256::from_compact(header.bits);
+
+        past_target_average = if count == 1 {
+            target
+        } else {
+            past_target_average
+                .mul_u64(count)
+                .wrapping_add(target)
+                .div_u64(count + 1)
+        };
+    }
+
+    let target_timespan = DGW_PAST_BLOCKS as i64 * TARGET_SPACING;
+    let actual_timespan = (i64::from(past_headers[DGW_PAST_BLOCKS - 1].time)
+        - i64::from(past_headers[0].time))
+    .clamp(target_timespan / 3, target_timespan * 3);
+
+    let target = past_target_average
+        .mul_u64(actual_timespan as u64)
+        .div_u64(target_timespan as u64);
+
+    Ok(target.min(params.pow_limit).to_compact())
+}
+
+/// Unsigned 256-bit integer with wrapping arithmetic, like `arith_uint256` of Dash Core.
+///
+/// Limbs are stored most significant first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct U256([u64; 4]);
+
+impl U256 {
+    const ZERO: U256 = U256([0; 4]);
+
+    fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_be_bytes(chunk.try_into().expect("chunk of 8 bytes"));
+        }
+
+        U256(limbs)
+    }
+
+    fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(self.0) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decode compact target, ignoring the sign bit and bytes that don't fit.
+    fn from_compact(compact: u32) -> Self {
+        let size = (compact >> 24) as usize;
+        let mantissa = (compact & 0x007fffff).to_be_bytes();
+
+        let mut bytes = [0u8; 32];
+        for (offset, byte) in mantissa[1..].iter().enumerate() {
+            if let Some(index) = (32 + offset).checked_sub(size).filter(|index| *index < 32) {
+                bytes[index] = *byte;
+            }
+        }
+
+        Self::from_be_bytes(bytes)
+    }
+
+    fn to_compact(self) -> u32 {
+        let bytes = self.to_be_bytes();
+
+        let Some(first) = bytes.iter().position(|byte| *byte != 0) else {
+            return 0;
+        };
+
+        let mut size = (32 - first) as u32;
+        let mut mantissa = (first..first + 3).fold(0u32, |mantissa, index| {
+            (mantissa << 8) | u32::from(bytes.get(index).copied().unwrap_or_default())
+        });
+
+        // The highest bit of mantissa is the sign
+        if mantissa & 0x00800000 != 0 {
+            mantissa >>= 8;
+            size += 1;
+        }
+
+        mantissa | (size << 24)
+    }
+
+    fn wrapping_add(self, other: Self) -> Self {
+        let mut result = self.0;
+        let mut carry = false;
+        for (limb, other) in result.iter_mut().zip(other.0).rev() {
+            let (sum, overflow) = limb.overflowing_add(other);
+            let (sum, carry_overflow) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = overflow || carry_overflow;
+        }
+
+        U256(result)
+    }
+
+    fn mul_u64(self, multiplier: u64) -> Self {
+        let mut result = self.0;
+        let mut carry = 0u128;
+        for limb in result.iter_mut().rev() {
+            let product = u128::from(*limb) * u128::from(multiplier) + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+
+        U256(result)
+    }
+
+    fn div_u64(self, divisor: u64) -> Self {
+        let mut result = self.0;
+        let mut remainder = 0u128;
+        for limb in result.iter_mut() {
+            let dividend = (remainder << 64) | u128::from(*limb);
+            *limb = (dividend / u128::from(divisor)) as u64;
+            remainder = dividend % u128::from(divisor);
+        }
+
+        U256(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BITS: u32 = 0x1a0a8a8b;
+    const TIME: u32 = 1700000000;
+
+    fn recent_headers(spacing: u32) -> Vec<HeaderDifficulty> {
+        (0..DGW_PAST_BLOCKS as u32)
+            .map(|index| HeaderDifficulty {
+                time: TIME + index * spacing,
+                bits: BITS,
+            })
+            .collect()
+    }
+
+    fn next_time(recent_headers: &[HeaderDifficulty], spacing: u32) -> u32 {
+        recent_headers.last().expect("headers").time + spacing
+    }
+
+    #[test]
+    fn should_encode_compact_target() {
+        let target = U256::from_compact(0x1d00ffff);
+
+        let mut expected = [0u8; 32];
+        expected[4] = 0xff;
+        expected[5] = 0xff;
+        assert_eq!(target.to_be_bytes(), expected);
+
+        for compact in [0x1d00ffff, 0x1e0ffff0, 0x1a0a8a8b, 0x207fffff, 0x03123456] {
+            assert_eq!(U256::from_compact(compact).to_compact(), compact);
+        }
+    }
+
+    #[test]
+    fn should_keep_difficulty_when_blocks_are_on_time() {
+        let headers = recent_headers(150);
+
+        let bits = required_bits(Network::Dash, 2000000, &headers, next_time(&headers, 150))
+            .expect("expected to calculate difficulty");
+
+        // Timespan of 24 blocks covers only 23 intervals, so difficulty slightly increases
+        assert_eq!(bits, Some(0x1a0a1a1a));
+    }
+
+    #[test]
+    fn should_limit_difficulty_adjustment() {
+        let headers = recent_headers(1);
+        let bits = required_bits(Network::Dash, 2000000, &headers, next_time(&headers, 150))
+            .expect("expected to calculate difficulty");
+        assert_eq!(bits, Some(0x1a038383));
+
+        let headers = recent_headers(1000);
+        let bits = required_bits(Network::Dash, 2000000, &headers, next_time(&headers, 150))
+            .expect("expected to calculate difficulty");
+        assert_eq!(bits, Some(0x1a1f9fa1));
+    }
+
+    #[test]
+    fn should_average_difficulty_of_past_blocks() {
+        let headers: Vec<HeaderDifficulty> = recent_headers(150)
+            .into_iter()
+            .enumerate()
+            .map(|(index, header)| HeaderDifficulty {
+                bits: header.bits + (index as u32 % 3) * 0x100,
+                ..header
+            })
+            .collect();
+
+        let bits = required_bits(Network::Dash, 2000000, &headers, next_time(&headers, 150))
+            .expect("expected to calculate difficulty");
+
+        assert_eq!(bits, Some(0x1a0a1b19));
+    }
+
+    #[test]
+    fn should_allow_min_difficulty_blocks_on_testnet() {
+        let headers = recent_headers(150);
+
+        let bits = required_bits(Network::Testnet, 900000, &headers, next_time(&headers, 601))
+            .expect("expected to calculate difficulty");
+        assert_eq!(bits, Some(0x1a69696e));
+
+        let bits = required_bits(
+            Network::Testnet,
+            900000,
+            &headers,
+            next_time(&headers, 7201),
+        )
+        .expect("expected to calculate difficulty");
+        assert_eq!(bits, Some(0x1e0fffff));
+
+        let bits = required_bits(Network::Dash, 2000000, &headers, next_time(&headers, 7201))
+            .expect("expected to calculate difficulty");
+        assert_eq!(bits, Some(0x1a0a1a1a));
+    }
+
+    #[test]
+    fn should_require_past_headers() {
+        let headers = recent_headers(150);
+
+        assert!(matches!(
+            required_bits(
+                Network::Dash,
+                2000000,
+                &headers[1..],
+                next_time(&headers, 150)
+            ),
+            Err(Error::InvalidCoreData(_))
+        ));
+
+        assert!(matches!(
+            required_bits(Network::Dash, 1000, &headers, next_time(&headers, 150)),
+            Err(Error::InvalidCoreData(_))
+        ));
+
+        assert_eq!(
+            required_bits(Network::Regtest, 1000, &[], TIME).expect("expected no difficulty"),
+            None
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! Definitions of errors
 use std::fmt::Debug;
 
+use dapi_grpc::platform::v0::StateTransitionBroadcastError as StateTransitionBroadcastErrorProto;
 use dpp::ProtocolError;
 use rs_dapi_client::DapiClientError;
 
@@ -25,12 +26,60 @@ pub enum Error {
     /// Core client error, for example, connection error
     #[error("Core client error: {0}")]
     CoreClientError(#[from] dashcore_rpc::Error),
+    /// Core data received from DAPI is invalid or can't be verified
+    #[error("Invalid Core data: {0}")]
+    InvalidCoreData(String),
     /// Dependency not found, for example data contract for a document not found
     #[error("Required {0} not found: {1}")]
     MissingDependency(String, String),
     /// Epoch not found; we must have at least one epoch
     #[error("No epoch found on the Platform; it should never happen")]
     EpochNotFound,
+    /// Not enough DAPI nodes returned the same response to a request without proofs
+    #[error("Nodes disagree: {agreeing} of {responses} nodes returned the same response, {threshold} required")]
+    NodesDisagree {
+        /// Number of nodes that returned the most common response
+        agreeing: usize,
+        /// Number of nodes that responded
+        responses: usize,
+        /// Number of nodes required to agree
+        threshold: usize,
+    },
+    /// Not enough DAPI nodes are up to date to accept a response to a request without proofs
+    #[error("Stale nodes: {stale} nodes are behind height {height}, {threshold} nodes must agree")]
+    StaleNodes {
+        /// Number of nodes that responded with an older state
+        stale: usize,
+        /// Highest height seen
+        height: u64,
+        /// Number of nodes required to agree
+        threshold: usize,
+    },
+    /// State transition was rejected by the Platform
+    #[error("State transition broadcast error: {0}")]
+    StateTransitionBroadcastError(#[from] StateTransitionBroadcastError),
+}
+
+/// Error returned by the Platform when a broadcast state transition could not be executed.
+#[derive(Debug, thiserror::Error)]
+#[error("code {code}: {message}")]
+pub struct StateTransitionBroadcastError {
+    /// Error code
+    pub code: u32,
+    /// Human readable error message
+    pub message: String,
+    /// Serialized details of the error, if provided by the Platform
+    pub data: Vec<u8>,
+}
+
+impl From<StateTransitionBroadcastErrorProto> for StateTransitionBroadcastError {
+    fn from(value: StateTransitionBroadcastErrorProto) -> Self {
+        Self {
+            code: value.code,
+            message: value.message,
+            data: value.data,
+        }
+    }
 }
 
 impl<T: Debug> From<DapiClientError<T>> for Error {
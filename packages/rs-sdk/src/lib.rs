@@ -36,6 +36,15 @@
 //! for more details.
 //! 3. [`DriveQuery`](crate::platform::DriveQuery) - can be used to build more complex queries
 //!
+//! Objects are created and modified on the Platform by broadcasting signed state transitions. The following
+//! traits sign, broadcast and wait for the state transition to be executed:
+//!
+//! 1. [`PutIdentity`](crate::platform::PutIdentity) and [`TopUpIdentity`](crate::platform::TopUpIdentity)
+//! 2. [`PutContract`](crate::platform::PutContract)
+//! 3. [`PutDocument`](crate::platform::PutDocument)
+//! 4. [`TransferToIdentity`](crate::platform::TransferToIdentity) and
+//! [`WithdrawFromIdentity`](crate::platform::WithdrawFromIdentity)
+//!
 //! ## Testability
 //!
 //! SDK operations can be mocked using [Sdk::new_mock()].
@@ -60,6 +69,7 @@
 #![warn(missing_docs)]
 #![allow(rustdoc::private_intra_doc_links)]
 
+pub mod cache;
 pub mod core;
 pub mod error;
 pub mod mock;
@@ -1,20 +1,22 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use dpp::{
     block::extended_epoch_info::ExtendedEpochInfo,
     document::serialization_traits::DocumentCborMethodsV0,
     document::Document,
+    identity::{KeyID, PartialIdentity},
     platform_serialization::{
         platform_encode_to_vec, platform_versioned_decode_from_slice, PlatformVersionEncode,
         PlatformVersionedDecode,
     },
-    prelude::{DataContract, Identity},
+    prelude::{DataContract, Identifier, Identity, IdentityPublicKey, Revision},
     serialization::{
         PlatformDeserializableWithPotentialValidationFromVersionedStructure,
         PlatformSerializableWithPlatformVersion,
     },
 };
 
+use drive_proof_verifier::types::StateTransitionProofResult;
 use rs_dapi_client::mock::Key;
 use serde::{Deserialize, Serialize};
 
@@ -196,6 +198,21 @@ impl MockResponse for drive_proof_verifier::types::IdentityBalanceAndRevision {
     }
 }
 
+impl MockResponse for drive_proof_verifier::types::IdentityNonceFetcher {
+    fn mock_serialize(&self, _sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        self.0.to_le_bytes().to_vec()
+    }
+
+    fn mock_deserialize(_sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        Self(u64::from_le_bytes(
+            buf.try_into().expect("identity nonce should be 8 bytes"),
+        ))
+    }
+}
+
 impl MockResponse for ExtendedEpochInfo {
     fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
         platform_encode_to_vec(self, bincode::config::standard(), sdk.version())
@@ -209,3 +226,121 @@ impl MockResponse for ExtendedEpochInfo {
             .expect("decode ExtendedEpochInfo")
     }
 }
+
+/// Fields of [PartialIdentity] in a form that can be encoded in mock expectations.
+type MockPartialIdentity = (
+    Identifier,
+    BTreeMap<KeyID, IdentityPublicKey>,
+    Option<u64>,
+    Option<Revision>,
+    BTreeSet<KeyID>,
+);
+
+/// [StateTransitionProofResult] with objects serialized the same way as in other mock expectations.
+#[derive(bincode::Encode, bincode::Decode)]
+enum MockStateTransitionProofResult {
+    DataContract(Vec<u8>),
+    Identity(Vec<u8>),
+    PartialIdentity(MockPartialIdentity),
+    BalanceTransfer(MockPartialIdentity, MockPartialIdentity),
+    Documents(Vec<(Identifier, Option<Vec<u8>>)>),
+    DataContractDeleted(Identifier),
+}
+
+fn partial_identity_to_mock(identity: &PartialIdentity) -> MockPartialIdentity {
+    (
+        identity.id,
+        identity.loaded_public_keys.clone(),
+        identity.balance,
+        identity.revision,
+        identity.not_found_public_keys.clone(),
+    )
+}
+
+fn partial_identity_from_mock(identity: MockPartialIdentity) -> PartialIdentity {
+    let (id, loaded_public_keys, balance, revision, not_found_public_keys) = identity;
+
+    PartialIdentity {
+        id,
+        loaded_public_keys,
+        balance,
+        revision,
+        not_found_public_keys,
+    }
+}
+
+impl MockResponse for StateTransitionProofResult {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        let result = match self {
+            StateTransitionProofResult::VerifiedDataContract(data_contract) => {
+                MockStateTransitionProofResult::DataContract(data_contract.mock_serialize(sdk))
+            }
+            StateTransitionProofResult::VerifiedIdentity(identity) => {
+                MockStateTransitionProofResult::Identity(identity.mock_serialize(sdk))
+            }
+            StateTransitionProofResult::VerifiedPartialIdentity(identity) => {
+                MockStateTransitionProofResult::PartialIdentity(partial_identity_to_mock(identity))
+            }
+            StateTransitionProofResult::VerifiedBalanceTransfer(sender, recipient) => {
+                MockStateTransitionProofResult::BalanceTransfer(
+                    partial_identity_to_mock(sender),
+                    partial_identity_to_mock(recipient),
+                )
+            }
+            StateTransitionProofResult::VerifiedDocuments(documents) => {
+                MockStateTransitionProofResult::Documents(
+                    documents
+                        .iter()
+                        .map(|(id, document)| {
+                            (*id, document.as_ref().map(|d| d.mock_serialize(sdk)))
+                        })
+                        .collect(),
+                )
+            }
+            StateTransitionProofResult::VerifiedDataContractDeleted(id) => {
+                MockStateTransitionProofResult::DataContractDeleted(*id)
+            }
+        };
+
+        bincode::encode_to_vec(result, bincode::config::standard())
+            .expect("encode StateTransitionProofResult")
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        let (result, _): (MockStateTransitionProofResult, _) =
+            bincode::decode_from_slice(buf, bincode::config::standard())
+                .expect("decode StateTransitionProofResult");
+
+        match result {
+            MockStateTransitionProofResult::DataContract(data_contract) => {
+                Self::VerifiedDataContract(DataContract::mock_deserialize(sdk, &data_contract))
+            }
+            MockStateTransitionProofResult::Identity(identity) => {
+                Self::VerifiedIdentity(Identity::mock_deserialize(sdk, &identity))
+            }
+            MockStateTransitionProofResult::PartialIdentity(identity) => {
+                Self::VerifiedPartialIdentity(partial_identity_from_mock(identity))
+            }
+            MockStateTransitionProofResult::BalanceTransfer(sender, recipient) => {
+                Self::VerifiedBalanceTransfer(
+                    partial_identity_from_mock(sender),
+                    partial_identity_from_mock(recipient),
+                )
+            }
+            MockStateTransitionProofResult::Documents(documents) => Self::VerifiedDocuments(
+                documents
+                    .into_iter()
+                    .map(|(id, document)| {
+                        (id, document.map(|d| Document::mock_deserialize(sdk, &d)))
+                    })
+                    .collect(),
+            ),
+            MockStateTransitionProofResult::DataContractDeleted(id) => {
+                Self::VerifiedDataContractDeleted(id)
+            }
+        }
+    }
+}
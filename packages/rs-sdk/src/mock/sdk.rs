@@ -1,9 +1,14 @@
 //! Mocking mechanisms for Dash Platform SDK.
 //!
 //! See [MockDashPlatformSdk] for more details.
+use dapi_grpc::platform::v0::wait_for_state_transition_result_response::{
+    self, wait_for_state_transition_result_response_v0, WaitForStateTransitionResultResponseV0,
+};
 use dapi_grpc::platform::v0::{self as proto};
+use dpp::state_transition::StateTransition;
 use dpp::version::PlatformVersion;
-use drive_proof_verifier::{FromProof, MockQuorumInfoProvider};
+use drive_proof_verifier::types::{StateTransitionProofResult, StateTransitionQuery};
+use drive_proof_verifier::{FromProof, FromUnproved, MockQuorumInfoProvider};
 use rs_dapi_client::{
     mock::{Key, MockDapiClient},
     transport::TransportRequest,
@@ -14,7 +19,11 @@ use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
 use tokio::sync::Mutex;
 
 use crate::{
-    platform::{types::identity::IdentityRequest, DocumentQuery, Fetch, FetchMany, Query},
+    error::StateTransitionBroadcastError,
+    platform::{
+        transition::broadcast_request::BroadcastRequestForStateTransition,
+        types::identity::IdentityRequest, DocumentQuery, Fetch, FetchMany, Query,
+    },
     Error,
 };
 
@@ -24,8 +33,9 @@ use super::{MockRequest, MockResponse};
 ///
 /// This object is returned by [Sdk::mock()](crate::Sdk::mock()) and is used to define mock expectations.
 ///
-/// Use [MockDashPlatformSdk::expect_fetch_many()] to define expectations for [FetchMany] requests
-/// and [MockDashPlatformSdk::expect_fetch()] for [Fetch] requests.
+/// Use [MockDashPlatformSdk::expect_fetch_many()] to define expectations for [FetchMany] requests,
+/// [MockDashPlatformSdk::expect_fetch()] for [Fetch] requests and [MockDashPlatformSdk::expect_broadcast()]
+/// for broadcast state transitions.
 ///
 /// ## Panics
 ///
@@ -133,6 +143,10 @@ impl MockDashPlatformSdk {
                     self.load_expectation::<proto::GetIdentityKeysRequest>(filename)
                         .await?
                 }
+                "GetIdentityNonceRequest" => {
+                    self.load_expectation::<proto::GetIdentityNonceRequest>(filename)
+                        .await?
+                }
                 "GetProtocolVersionUpgradeStateRequest" => {
                     self.load_expectation::<proto::GetProtocolVersionUpgradeStateRequest>(filename)
                         .await?
@@ -286,7 +300,12 @@ impl MockDashPlatformSdk {
                 <O as FetchMany<K>>::Request,
                 Request = <O as FetchMany<K>>::Request,
                 Response = <<O as FetchMany<K>>::Request as TransportRequest>::Response,
-            > + Sync,
+            > + FromUnproved<
+                <O as FetchMany<K>>::Request,
+                Request = <O as FetchMany<K>>::Request,
+                Response = <<O as FetchMany<K>>::Request as TransportRequest>::Response,
+            > + PartialEq
+            + Sync,
     {
         let grpc_request = query.query(self.prove).expect("query must be correct");
         self.expect(grpc_request, objects).await;
@@ -294,6 +313,76 @@ impl MockDashPlatformSdk {
         self
     }
 
+    /// Expect a state transition to be broadcast and executed.
+    ///
+    /// Broadcasting the state transition will succeed, and waiting for its result will return `result`,
+    /// as if it was verified against the proof returned by the Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// - `query`: The state transition to broadcast; use [StateTransitionQuery] with data contracts added
+    /// for documents batch transitions.
+    /// - `result`: Result of the state transition execution, or None if the proof is expected to be empty.
+    pub async fn expect_broadcast<Q: Into<StateTransitionQuery>>(
+        &mut self,
+        query: Q,
+        result: Option<StateTransitionProofResult>,
+    ) -> &mut Self {
+        let query: StateTransitionQuery = query.into();
+
+        self.from_proof_expectations
+            .insert(query.mock_key(), result.mock_serialize(self));
+        self.expect_broadcast_response(&query.state_transition, Default::default())
+            .await;
+
+        self
+    }
+
+    /// Expect a state transition to be broadcast and then rejected by the Platform with `error`.
+    pub async fn expect_broadcast_error(
+        &mut self,
+        state_transition: &StateTransition,
+        error: StateTransitionBroadcastError,
+    ) -> &mut Self {
+        let response = proto::WaitForStateTransitionResultResponse {
+            version: Some(wait_for_state_transition_result_response::Version::V0(
+                WaitForStateTransitionResultResponseV0 {
+                    result: Some(wait_for_state_transition_result_response_v0::Result::Error(
+                        proto::StateTransitionBroadcastError {
+                            code: error.code,
+                            message: error.message,
+                            data: error.data,
+                        },
+                    )),
+                    metadata: None,
+                },
+            )),
+        };
+
+        self.expect_broadcast_response(state_transition, response)
+            .await;
+
+        self
+    }
+
+    /// Save expectations for broadcast of `state_transition` and for waiting for its result.
+    async fn expect_broadcast_response(
+        &mut self,
+        state_transition: &StateTransition,
+        response: proto::WaitForStateTransitionResultResponse,
+    ) {
+        let broadcast_request = state_transition
+            .broadcast_request_for_state_transition()
+            .expect("state transition must be correct");
+        let wait_request = state_transition
+            .wait_for_state_transition_result_request()
+            .expect("state transition must be correct");
+
+        let mut dapi_guard = self.dapi.lock().await;
+        dapi_guard.expect(&broadcast_request, &Default::default());
+        dapi_guard.expect(&wait_request, &response);
+    }
+
     /// Save expectations for a request.
     async fn expect<I: TransportRequest + MockRequest, O: MockResponse>(
         &mut self,
@@ -338,4 +427,22 @@ impl MockDashPlatformSdk {
 
         Ok(data)
     }
+
+    /// Wrapper around [FromUnproved] that uses mock expectations instead of executing [FromUnproved] trait.
+    pub(crate) fn parse_unproved<I, O: FromUnproved<I>>(
+        &self,
+        request: O::Request,
+        response: O::Response,
+    ) -> Result<Option<O>, drive_proof_verifier::Error>
+    where
+        O::Request: MockRequest,
+        Option<O>: MockResponse,
+    {
+        let data = match self.from_proof_expectations.get(&request.mock_key()) {
+            Some(d) => Option::<O>::mock_deserialize(self, d),
+            None => O::maybe_from_unproved(request, response, self.version())?,
+        };
+
+        Ok(data)
+    }
 }
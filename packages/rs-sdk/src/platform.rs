@@ -5,11 +5,14 @@
 // generated types. Later these re-exports could be swapped with actual rs-sdk's requests
 // and while it will change the substance, the API structure will remain the same.
 
+pub(crate) mod consistency;
 mod delegate;
 mod document_query;
+pub mod events;
 mod fetch;
 mod fetch_many;
 mod query;
+pub mod transition;
 pub mod types;
 
 pub use dapi_grpc::platform::v0::{self as proto};
@@ -22,10 +25,15 @@ pub use drive::{
     query::DriveQuery,
 };
 pub use {
+    consistency::ConsistencySettings,
     document_query::DocumentQuery,
     fetch::Fetch,
     fetch_many::FetchMany,
     query::{LimitQuery, Query, DEFAULT_EPOCH_QUERY_LIMIT},
+    transition::{
+        BroadcastStateTransition, PutContract, PutDocument, PutIdentity, TopUpIdentity,
+        TransferToIdentity, WithdrawFromIdentity,
+    },
 };
 
 pub use rs_dapi_client as dapi;
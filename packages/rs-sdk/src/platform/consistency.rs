@@ -0,0 +1,208 @@
+//! Fetching data without proofs.
+//!
+//! Responses received without proofs can't be verified, so the same request is sent to multiple DAPI nodes and
+//! the result is accepted only when enough of them agree on it. See [ConsistencySettings] for more details.
+
+use dpp::version::PlatformVersion;
+use drive_proof_verifier::FromUnproved;
+use http::Uri;
+use rs_dapi_client::{
+    transport::{PlatformGrpcClient, TransportRequest, TransportResponse},
+    DapiClient, DapiClientError, RequestSettings,
+};
+
+use crate::error::Error;
+
+/// Default number of nodes each request is sent to when proofs are disabled.
+const DEFAULT_CONSISTENCY_NODES: usize = 3;
+/// Default number of nodes that must return the same response when proofs are disabled.
+const DEFAULT_CONSISTENCY_THRESHOLD: usize = 2;
+
+/// Settings of requests executed without proofs.
+///
+/// When the [Sdk](crate::Sdk) is configured without proofs, each request is sent to `nodes` distinct DAPI nodes.
+/// The result is accepted only when at least `threshold` nodes return the same data, created from the same
+/// Platform state: height, core chain locked height and block time in response metadata must match.
+///
+/// Threshold must be a majority of `nodes`, so that two different results can't be accepted at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsistencySettings {
+    /// Number of distinct DAPI nodes each request is sent to.
+    pub nodes: usize,
+    /// Minimum number of nodes that must return the same response.
+    pub threshold: usize,
+}
+
+impl Default for ConsistencySettings {
+    fn default() -> Self {
+        Self {
+            nodes: DEFAULT_CONSISTENCY_NODES,
+            threshold: DEFAULT_CONSISTENCY_THRESHOLD,
+        }
+    }
+}
+
+impl ConsistencySettings {
+    /// Check that the threshold is a majority of nodes.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.threshold > self.nodes || self.threshold * 2 <= self.nodes {
+            return Err(Error::Config(format!(
+                "consistency threshold {} must be a majority of {} nodes",
+                self.threshold, self.nodes
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Platform state a response was created from: height, core chain locked height and block time.
+type PlatformState = (u64, u32, u64);
+
+/// Nodes that returned the same object from the same Platform state.
+struct Vote<O> {
+    /// Platform state of the responses; `None` for "not found" errors, which carry no metadata.
+    state: Option<PlatformState>,
+    object: Option<O>,
+    uris: Vec<Uri>,
+}
+
+impl<O> Vote<O> {
+    fn height(&self) -> u64 {
+        self.state.map(|(height, _, _)| height).unwrap_or_default()
+    }
+}
+
+/// Send `request` without proofs to multiple nodes and retrieve object `O` that enough nodes agree on.
+///
+/// Returns the object and height of the Platform state it was retrieved from, if known.
+///
+/// Nodes that returned an invalid response, or different data than the accepted one at the same height, are banned.
+pub(crate) async fn fetch_unproved<R, O>(
+    dapi: &DapiClient,
+    request: R,
+    settings: ConsistencySettings,
+    version: &PlatformVersion,
+) -> Result<(Option<O>, Option<u64>), Error>
+where
+    R: TransportRequest<Client = PlatformGrpcClient>,
+    O: FromUnproved<R, Request = R, Response = R::Response> + PartialEq,
+{
+    let responses = dapi
+        .execute_on_nodes(request.clone(), settings.nodes, RequestSettings::default())
+        .await?;
+
+    let mut votes: Vec<Vote<O>> = Vec::new();
+    let mut stale = 0;
+    let mut last_error: Option<Error> = None;
+
+    for (uri, result) in responses {
+        let (state, object) = match result {
+            Ok(response) => {
+                let state = response.response_metadata().map(|metadata| {
+                    (
+                        metadata.height,
+                        metadata.core_chain_locked_height,
+                        metadata.time_ms,
+                    )
+                });
+
+                match O::maybe_from_unproved(request.clone(), response, version) {
+                    Ok(object) => (state, object),
+                    Err(error) => {
+                        tracing::warn!(%uri, %error, "banning address that returned an invalid response");
+                        dapi.ban_address(&uri);
+                        last_error = Some(error.into());
+                        continue;
+                    }
+                }
+            }
+            Err(DapiClientError::Transport(status)) if status.code() == tonic::Code::NotFound => {
+                (None, None)
+            }
+            Err(DapiClientError::StaleNode { .. }) => {
+                stale += 1;
+                continue;
+            }
+            Err(error) => {
+                last_error = Some(error.into());
+                continue;
+            }
+        };
+
+        match votes
+            .iter_mut()
+            .find(|vote| vote.state == state && vote.object == object)
+        {
+            Some(vote) => vote.uris.push(uri),
+            None => votes.push(Vote {
+                state,
+                object,
+                uris: vec![uri],
+            }),
+        }
+    }
+
+    let responses: usize = votes.iter().map(|vote| vote.uris.len()).sum();
+    let highest_height = votes.iter().map(Vote::height).max().unwrap_or_default();
+
+    // On ties, prefer the most recent state
+    let Some(best) = votes
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, vote)| (vote.uris.len(), vote.height()))
+        .map(|(index, _)| index)
+    else {
+        return Err(last_error.unwrap_or(Error::StaleNodes {
+            stale,
+            height: dapi.highest_seen_height(),
+            threshold: settings.threshold,
+        }));
+    };
+
+    let agreeing = votes[best].uris.len();
+
+    if agreeing < settings.threshold {
+        let behind = stale
+            + votes
+                .iter()
+                .filter(|vote| vote.state.is_some() && vote.height() < highest_height)
+                .map(|vote| vote.uris.len())
+                .sum::<usize>();
+
+        if behind > 0 {
+            return Err(Error::StaleNodes {
+                stale: behind,
+                height: highest_height,
+                threshold: settings.threshold,
+            });
+        }
+
+        // Too few nodes responded to compare their responses; report why
+        return Err(match last_error {
+            Some(error) if responses < settings.threshold => error,
+            _ => Error::NodesDisagree {
+                agreeing,
+                responses,
+                threshold: settings.threshold,
+            },
+        });
+    }
+
+    let accepted = votes.swap_remove(best);
+
+    // Nodes that returned different data from the same state can't be trusted
+    for vote in votes
+        .iter()
+        .filter(|vote| vote.state.is_some() && vote.state == accepted.state)
+    {
+        for uri in &vote.uris {
+            tracing::warn!(%uri, "banning address that returned data different from other nodes");
+            dapi.ban_address(uri);
+        }
+    }
+
+    let height = accepted.state.map(|(height, _, _)| height);
+
+    Ok((accepted.object, height))
+}
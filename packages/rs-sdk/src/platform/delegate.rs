@@ -1,4 +1,4 @@
-//! Macros to delegate TransportRequest and FromProof to an enum wrapper.
+//! Macros to delegate TransportRequest, FromProof and FromUnproved to an enum wrapper.
 //!
 //! Two enum wrappers are created using [delegate_enum!](crate::delegate_enum!):
 //!
@@ -110,21 +110,71 @@ macro_rules! delegate_from_proof_variant {
         }
     };
 }
+/// Delegate the execution of a [FromUnproved](drive_proof_verifier::FromUnproved) trait to an enum supporting
+/// multiple variants.
+///
+/// Works like [delegate_from_proof_variant!](crate::delegate_from_proof_variant!), for responses received
+/// without proofs.
+///
+/// See [delegate_enum!](crate::delegate_enum!) for more details.
+#[macro_export]
+macro_rules! delegate_from_unproved_variant {
+    ($request:ty, $response:ty, $object:ty, $(($variant:ident, $req: ty, $resp: ty)),+) => {
+        impl drive_proof_verifier::FromUnproved<$request> for $object {
+            type Request = $request;
+            type Response = $response;
+
+            fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+                request: I,
+                response: O,
+                version: &dpp::version::PlatformVersion,
+            ) -> Result<Option<Self>, drive_proof_verifier::Error> {
+                use $request as req;
+                use $response as resp;
+
+                let request: Self::Request = request.into();
+                let response: Self::Response = response.into();
+
+                match request {$(
+                    req::$variant(request) => {
+                        if let resp::$variant(response) = response {
+                            <Self as drive_proof_verifier::FromUnproved<$req>>::maybe_from_unproved(
+                                request, response, version,
+                            )
+                        } else {
+                            Err(drive_proof_verifier::Error::ResponseDecodeError {
+                                error: format!(
+                                    "expected {}, got {}",
+                                    stringify!($resp),
+                                    $crate::platform::delegate::type_name(&response),
+                                ),
+                            })
+                        }
+                    },
+                )*
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 /// Define enums that will wrap multiple requests/responses for one object.
 ///
 /// In order to support multiple request/response types for one object (like, GetIdentityRequest and
 /// GetIdentityByPublicKeyHashRequest for Identity), we need to wrap them in an enum and
-/// delegate [TransportRequest](crate::platform::dapi::transport::TransportRequest)
-/// and [FromProof](drive_proof_verifier::FromProof) to the appropriate variant.
+/// delegate [TransportRequest](crate::platform::dapi::transport::TransportRequest),
+/// [FromProof](drive_proof_verifier::FromProof) and [FromUnproved](drive_proof_verifier::FromUnproved)
+/// to the appropriate variant.
 ///
 /// This macro creates enums for requests (`$request`) and responses (`$response`) and variants  (`$variant`) for
 /// each request (`$req`) /response (`$req`) pair. Variant name in request and response enums are the same.
 ///
-/// It also calls [delegate_transport_request_variant!](crate::delegate_transport_request_variant!) and
-/// [delegate_from_proof_variant!](crate::delegate_from_proof_variant!) to delegate
-/// [TransportRequest](crate::platform::dapi::transport::TransportRequest)
-/// and [FromProof](drive_proof_verifier::FromProof)
+/// It also calls [delegate_transport_request_variant!](crate::delegate_transport_request_variant!),
+/// [delegate_from_proof_variant!](crate::delegate_from_proof_variant!) and
+/// [delegate_from_unproved_variant!](crate::delegate_from_unproved_variant!) to delegate
+/// [TransportRequest](crate::platform::dapi::transport::TransportRequest),
+/// [FromProof](drive_proof_verifier::FromProof) and [FromUnproved](drive_proof_verifier::FromUnproved)
 /// traits to the appropriate variant.
 macro_rules! delegate_enum {
     ($request:ident, $response:ident, $object:ty, $(($variant:ident, $req: ty, $resp: ty)),+) => {
@@ -153,7 +203,16 @@ macro_rules! delegate_enum {
             )+
         }
 
-        impl $crate::platform::dapi::transport::TransportResponse for $response {}
+        impl $crate::platform::dapi::transport::TransportResponse for $response {
+            fn response_metadata(&self) -> Option<&$crate::platform::proto::ResponseMetadata> {
+                match self {
+                    $response::Unknown => None,
+                    $(
+                        $response::$variant(response) => $crate::platform::dapi::transport::TransportResponse::response_metadata(response),
+                    )+
+                }
+            }
+        }
 
 
         $crate::delegate_transport_request_variant! {
@@ -168,6 +227,13 @@ macro_rules! delegate_enum {
             $object,
             $(($variant,$req,$resp)),+
         }
+
+        $crate::delegate_from_unproved_variant! {
+            $request,
+            $response,
+            $object,
+            $(($variant,$req,$resp)),+
+        }
     };
 }
 /// Return type name of a variable as a String
@@ -8,18 +8,22 @@ use dapi_grpc::platform::v0::get_documents_request::Version::V0;
 use dapi_grpc::platform::v0::{
     self as platform_proto,
     get_documents_request::{get_documents_request_v0::Start, GetDocumentsRequestV0},
+    get_documents_response::{self, get_documents_response_v0},
     GetDocumentsRequest,
 };
 use dpp::{
     data_contract::{
         accessors::v0::DataContractV0Getters, document_type::accessors::DocumentTypeV0Getters,
     },
-    document::Document,
+    document::{
+        serialization_traits::DocumentPlatformConversionMethodsV0, Document, DocumentV0Getters,
+    },
     platform_value::{platform_value, Value},
     prelude::{DataContract, Identifier},
 };
+use drive::query::conditions::OR_CLAUSE;
 use drive::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
-use drive_proof_verifier::{types::Documents, FromProof};
+use drive_proof_verifier::{types::Documents, FromProof, FromUnproved};
 use rs_dapi_client::transport::{
     AppliedRequestSettings, BoxFuture, TransportClient, TransportRequest,
 };
@@ -42,12 +46,17 @@ pub struct DocumentQuery {
     pub document_type_name: String,
     /// `where` clauses for the query
     pub where_clauses: Vec<WhereClause>,
+    /// Alternative groups of `where` clauses; when not empty, documents must match all clauses
+    /// of at least one group, in addition to `where_clauses`
+    pub or_where_clauses: Vec<Vec<WhereClause>>,
     /// `order_by` clauses for the query
     pub order_by_clauses: Vec<OrderClause>,
     /// queryset limit
     pub limit: u32,
     /// first object to start with
     pub start: Option<Start>,
+    /// Request proofs of returned documents; use `false` only with [Sdk] configured without proofs
+    pub prove: bool,
 }
 
 impl DocumentQuery {
@@ -64,9 +73,11 @@ impl DocumentQuery {
             data_contract: Arc::clone(&contract),
             document_type_name: document_type_name.to_string(),
             where_clauses: vec![],
+            or_where_clauses: vec![],
             order_by_clauses: vec![],
             limit: 0,
             start: None,
+            prove: true,
         })
     }
 
@@ -77,7 +88,7 @@ impl DocumentQuery {
 
     /// Create new document query for provided document type name and data contract ID.
     ///
-    /// Note that this method will fetch data contract first.
+    /// Note that this method will fetch data contract first, unless it's available in [Sdk::cache()].
     pub async fn new_with_data_contract_id(
         api: &mut Sdk,
         data_contract_id: Identifier,
@@ -114,6 +125,16 @@ impl DocumentQuery {
         self
     }
 
+    /// Add alternative groups of where clauses to the query; documents must match all clauses
+    /// of at least one group.
+    ///
+    /// Existing alternative groups will be replaced.
+    pub fn with_where_any(mut self, clause_groups: Vec<Vec<WhereClause>>) -> Self {
+        self.or_where_clauses = clause_groups;
+
+        self
+    }
+
     /// Add order by clause to the query.
     ///
     /// Existing order by clauses will be preserved.
@@ -122,6 +143,15 @@ impl DocumentQuery {
 
         self
     }
+
+    /// Request documents with or without proofs.
+    ///
+    /// Must match [Sdk::prove()] of the [Sdk] used to fetch documents. Defaults to `true`.
+    pub fn with_proofs(mut self, prove: bool) -> Self {
+        self.prove = prove;
+
+        self
+    }
 }
 
 impl TransportRequest for DocumentQuery {
@@ -202,12 +232,109 @@ impl FromProof<DocumentQuery> for drive_proof_verifier::types::Documents {
     }
 }
 
+impl FromUnproved<DocumentQuery> for Document {
+    type Request = DocumentQuery;
+    type Response = platform_proto::GetDocumentsResponse;
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        version: &dpp::version::PlatformVersion,
+    ) -> Result<Option<Self>, drive_proof_verifier::Error> {
+        let documents: Option<Documents> =
+            <Documents as FromUnproved<Self::Request>>::maybe_from_unproved(
+                request, response, version,
+            )?;
+
+        match documents {
+            None => Ok(None),
+            Some(docs) => match docs.len() {
+                0 | 1 => Ok(docs.into_iter().next().and_then(|(_, v)| v)),
+                n => Err(drive_proof_verifier::Error::ResponseDecodeError {
+                    error: format!("expected 1 element, got {}", n),
+                }),
+            },
+        }
+    }
+}
+
+impl FromUnproved<DocumentQuery> for drive_proof_verifier::types::Documents {
+    type Request = DocumentQuery;
+    type Response = platform_proto::GetDocumentsResponse;
+    fn maybe_from_unproved<I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        version: &dpp::version::PlatformVersion,
+    ) -> Result<Option<Self>, drive_proof_verifier::Error> {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let document_type = request
+            .data_contract
+            .document_type_for_name(&request.document_type_name)
+            .map_err(|e| drive_proof_verifier::Error::RequestDecodeError {
+                error: e.to_string(),
+            })?;
+
+        let result = match response
+            .version
+            .ok_or(drive_proof_verifier::Error::EmptyVersion)?
+        {
+            get_documents_response::Version::V0(v0) => v0.result,
+        };
+
+        let documents = match result {
+            Some(get_documents_response_v0::Result::Documents(documents)) => documents.documents,
+            Some(get_documents_response_v0::Result::Proof(_)) => {
+                return Err(drive_proof_verifier::Error::ResponseDecodeError {
+                    error: "expected documents, got proof".to_string(),
+                })
+            }
+            None => {
+                return Err(drive_proof_verifier::Error::ResponseDecodeError {
+                    error: "empty result".to_string(),
+                })
+            }
+        };
+
+        if documents.is_empty() {
+            return Ok(None);
+        }
+
+        let documents = documents
+            .iter()
+            .map(|bytes| {
+                Document::from_bytes(bytes, document_type, version)
+                    .map(|document| (document.id(), Some(document)))
+            })
+            .collect::<Result<Documents, _>>()?;
+
+        Ok(Some(documents))
+    }
+}
+
 impl TryFrom<DocumentQuery> for platform_proto::GetDocumentsRequest {
     type Error = Error;
     fn try_from(dapi_request: DocumentQuery) -> Result<Self, Self::Error> {
         // TODO implement where and order_by clause
 
-        let where_clauses = serialize_vec_to_cbor(dapi_request.where_clauses.clone())
+        let mut where_values: Vec<Value> = dapi_request
+            .where_clauses
+            .iter()
+            .cloned()
+            .map(Value::from)
+            .collect();
+        if !dapi_request.or_where_clauses.is_empty() {
+            let alternatives = dapi_request
+                .or_where_clauses
+                .iter()
+                .map(|group| Value::Array(group.iter().cloned().map(Value::from).collect()))
+                .collect();
+            where_values.push(Value::Array(vec![
+                Value::Text(OR_CLAUSE.to_string()),
+                Value::Array(alternatives),
+            ]));
+        }
+        let where_clauses = serialize_vec_to_cbor(where_values)
             .expect("where clauses serialization should never fail");
         let order_by = serialize_vec_to_cbor(dapi_request.order_by_clauses.clone())?;
         // Order clause
@@ -220,8 +347,9 @@ impl TryFrom<DocumentQuery> for platform_proto::GetDocumentsRequest {
                 r#where: where_clauses,
                 order_by,
                 limit: dapi_request.limit,
-                prove: true,
+                prove: dapi_request.prove,
                 start: dapi_request.start.clone(),
+                height: None,
             })),
         })
     }
@@ -231,6 +359,12 @@ impl<'a> From<&'a DriveQuery<'a>> for DocumentQuery {
     fn from(value: &'a DriveQuery<'a>) -> Self {
         let data_contract = value.contract.clone();
         let document_type_name = value.document_type.name();
+        let or_where_clauses = value
+            .internal_clauses
+            .or_clauses
+            .iter()
+            .map(|or_clause| or_clause.clone().into())
+            .collect();
         let where_clauses = value.internal_clauses.clone().into();
         let order_by_clauses = value.order_by.iter().map(|(_, v)| v.clone()).collect();
         let limit = value.limit.unwrap_or(0) as u32;
@@ -248,9 +382,11 @@ impl<'a> From<&'a DriveQuery<'a>> for DocumentQuery {
             data_contract: Arc::new(data_contract),
             document_type_name: document_type_name.to_string(),
             where_clauses,
+            or_where_clauses,
             order_by_clauses,
             limit,
             start,
+            prove: true,
         }
     }
 }
@@ -264,8 +400,25 @@ impl<'a> TryFrom<&'a DocumentQuery> for DriveQuery<'a> {
             .data_contract
             .document_type_for_name(&request.document_type_name)?;
 
-        let internal_clauses = InternalClauses::extract_from_clauses(request.where_clauses.clone())
-            .map_err(Error::Drive)?;
+        let internal_clauses = if request.or_where_clauses.is_empty() {
+            InternalClauses::extract_from_clauses(request.where_clauses.clone())
+        } else {
+            InternalClauses::extract_from_clause_groups(
+                request
+                    .or_where_clauses
+                    .iter()
+                    .map(|group| {
+                        request
+                            .where_clauses
+                            .iter()
+                            .chain(group.iter())
+                            .cloned()
+                            .collect()
+                    })
+                    .collect(),
+            )
+        }
+        .map_err(Error::Drive)?;
 
         let limit = if request.limit != 0 {
             Some(request.limit as u16)
@@ -0,0 +1,105 @@
+//! Subscription to changes of platform state.
+
+use dapi_grpc::platform::v0::{
+    subscribe_platform_events_request::{self, SubscribePlatformEventsRequestV0},
+    subscribe_platform_events_response::{self, SubscribePlatformEventsResponseV0},
+    SubscribePlatformEventsRequest, SubscribePlatformEventsResponse,
+};
+use dpp::prelude::Identifier;
+use futures::stream::BoxStream;
+use rs_dapi_client::DapiClientError;
+
+use crate::error::Error;
+
+/// Stream of changes made by each committed block, as returned by [Sdk::subscribe](crate::Sdk::subscribe).
+///
+/// Blocks without changes matching the filter are skipped.
+pub type PlatformEventStream = BoxStream<'static, Result<SubscribePlatformEventsResponseV0, Error>>;
+
+/// Selects events streamed by [Sdk::subscribe](crate::Sdk::subscribe).
+///
+/// An empty filter selects all events. Otherwise document events are selected by data contract
+/// and document type, and identity events by identity.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_sdk::platform::{events::PlatformEventsFilter, Identifier};
+///
+/// let filter = PlatformEventsFilter::new()
+///     .with_data_contract(Identifier::new([1; 32]))
+///     .with_document_type("note")
+///     .with_identity(Identifier::new([2; 32]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlatformEventsFilter {
+    /// Data contracts of document events
+    pub data_contract_ids: Vec<Identifier>,
+    /// Document types of document events
+    pub document_type_names: Vec<String>,
+    /// Identities of identity events
+    pub identity_ids: Vec<Identifier>,
+}
+
+impl PlatformEventsFilter {
+    /// Create a filter that selects all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select document events of the data contract.
+    pub fn with_data_contract(mut self, data_contract_id: Identifier) -> Self {
+        self.data_contract_ids.push(data_contract_id);
+        self
+    }
+
+    /// Select document events of the document type.
+    pub fn with_document_type(mut self, document_type_name: &str) -> Self {
+        self.document_type_names
+            .push(document_type_name.to_string());
+        self
+    }
+
+    /// Select balance and key events of the identity.
+    pub fn with_identity(mut self, identity_id: Identifier) -> Self {
+        self.identity_ids.push(identity_id);
+        self
+    }
+}
+
+impl From<PlatformEventsFilter> for SubscribePlatformEventsRequest {
+    fn from(filter: PlatformEventsFilter) -> Self {
+        SubscribePlatformEventsRequest {
+            version: Some(subscribe_platform_events_request::Version::V0(
+                SubscribePlatformEventsRequestV0 {
+                    data_contract_ids: filter
+                        .data_contract_ids
+                        .into_iter()
+                        .map(|id| id.to_vec())
+                        .collect(),
+                    document_types: filter.document_type_names,
+                    identity_ids: filter
+                        .identity_ids
+                        .into_iter()
+                        .map(|id| id.to_vec())
+                        .collect(),
+                },
+            )),
+        }
+    }
+}
+
+/// Unwrap a versioned response received from the stream.
+pub(crate) fn parse_response<E: std::fmt::Debug>(
+    response: Result<SubscribePlatformEventsResponse, E>,
+) -> Result<SubscribePlatformEventsResponseV0, Error> {
+    match response
+        .map_err(|e| Error::from(DapiClientError::Transport(e)))?
+        .version
+    {
+        Some(subscribe_platform_events_response::Version::V0(response)) => Ok(response),
+        None => Err(Error::DapiClientError(
+            "empty version in platform events response".to_string(),
+        )),
+    }
+}
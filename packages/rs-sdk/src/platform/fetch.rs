@@ -5,18 +5,27 @@
 //!
 //! ## Traits
 //! - [Fetch]: An asynchronous trait that defines how to fetch data from the platform.
-//!   It requires the implementing type to also implement [Debug] and [FromProof]
+//!   It requires the implementing type to also implement [Debug], [FromProof] and [FromUnproved]
 //!   traits. The associated [Fetch::Request]` type needs to implement [TransportRequest].
 
 use crate::mock::{MockRequest, MockResponse};
 use crate::{error::Error, platform::query::Query, Sdk};
-use dapi_grpc::platform::v0::{self as platform_proto};
+use dapi_grpc::platform::v0::{
+    self as platform_proto, get_data_contract_request, get_identity_request,
+};
 use dpp::block::extended_epoch_info::ExtendedEpochInfo;
 use dpp::platform_value::Identifier;
-use dpp::{document::Document, prelude::Identity};
-use drive_proof_verifier::FromProof;
-use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
+use dpp::{
+    document::Document,
+    prelude::{DataContract, Identity},
+};
+use drive_proof_verifier::{FromProof, FromUnproved};
+use rs_dapi_client::{
+    transport::{PlatformGrpcClient, TransportRequest, TransportResponse},
+    DapiRequest, RequestSettings,
+};
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use super::types::identity::IdentityRequest;
 use super::DocumentQuery;
@@ -48,10 +57,15 @@ where
     Self: Sized
         + Debug
         + MockResponse
+        + PartialEq
         + FromProof<
             <Self as Fetch>::Request,
             Request = <Self as Fetch>::Request,
             Response = <<Self as Fetch>::Request as DapiRequest>::Response,
+        > + FromUnproved<
+            <Self as Fetch>::Request,
+            Request = <Self as Fetch>::Request,
+            Response = <<Self as Fetch>::Request as DapiRequest>::Response,
         >,
 {
     /// Type of request used to fetch data from the platform.
@@ -59,7 +73,7 @@ where
     /// Most likely, one of the types defined in [`dapi_grpc::platform::v0`].
     ///
     /// This type must implement [`TransportRequest`] and [`MockRequest`].
-    type Request: TransportRequest
+    type Request: TransportRequest<Client = PlatformGrpcClient>
         + MockRequest
         + Into<<Self as FromProof<<Self as Fetch>::Request>>::Request>;
 
@@ -68,6 +82,9 @@ where
     /// Fetch object from the platform that satisfies provided [Query].
     /// Most often, the Query is an [Identifier] of the object to be fetched.
     ///
+    /// When the [Sdk] is configured without proofs, the request is sent to multiple nodes and the object is
+    /// returned only when enough of them agree on it; see [ConsistencySettings](crate::platform::ConsistencySettings).
+    ///
     /// ## Parameters
     ///
     /// - `sdk`: An instance of [Sdk].
@@ -88,21 +105,9 @@ where
         query: Q,
     ) -> Result<Option<Self>, Error> {
         let request = query.query(sdk.prove())?;
+        let (object, _) = fetch_request::<Self>(sdk, request).await?;
 
-        let response = request
-            .clone()
-            .execute(sdk, RequestSettings::default())
-            .await?;
-
-        let object_type = std::any::type_name::<Self>().to_string();
-        tracing::trace!(request = ?request, response = ?response, object_type, "fetched object from platform");
-
-        let object: Option<Self> = sdk.parse_proof(request, response)?;
-
-        match object {
-            Some(item) => Ok(item.into()),
-            None => Ok(None),
-        }
+        Ok(object)
     }
 
     /// Fetch single object from the Platfom by identifier.
@@ -123,12 +128,120 @@ where
     }
 }
 
+/// Execute `request` and retrieve object `O` from the response, verifying its proof if [Sdk::prove()] is `true`.
+///
+/// Returns the object and Platform height at which it was retrieved.
+async fn fetch_request<O: Fetch>(
+    sdk: &mut Sdk,
+    request: O::Request,
+) -> Result<(Option<O>, u64), Error> {
+    if !sdk.prove() {
+        let (object, height) = sdk.fetch_unproved(request).await?;
+        return Ok((object, height.unwrap_or_else(|| sdk.platform_height())));
+    }
+
+    let response = request
+        .clone()
+        .execute(sdk, RequestSettings::default())
+        .await?;
+
+    let object_type = std::any::type_name::<O>().to_string();
+    tracing::trace!(request = ?request, response = ?response, object_type, "fetched object from platform");
+
+    let height = response
+        .inner
+        .metadata_height()
+        .unwrap_or_else(|| sdk.platform_height());
+    let object: Option<O> = sdk.parse_proof(request, response)?;
+
+    Ok((object, height))
+}
+
+/// Fetch identities.
+///
+/// When [CacheSettings::identity_ttl](crate::cache::CacheSettings::identity_ttl) is set, identities are stored in
+/// [Sdk::cache()] and identities requested by [Identifier] are returned from it until they expire.
+#[async_trait::async_trait]
 impl Fetch for Identity {
     type Request = IdentityRequest;
+
+    async fn fetch<Q: Query<<Self as Fetch>::Request>>(
+        sdk: &mut Sdk,
+        query: Q,
+    ) -> Result<Option<Self>, Error> {
+        let request = query.query(sdk.prove())?;
+
+        // Identities at past heights are not cached
+        let (cacheable, id) = match request {
+            IdentityRequest::GetIdentity(platform_proto::GetIdentityRequest {
+                version:
+                    Some(get_identity_request::Version::V0(
+                        get_identity_request::GetIdentityRequestV0 {
+                            ref id,
+                            height: None,
+                            ..
+                        },
+                    )),
+            }) => (true, Identifier::from_bytes(id).ok()),
+            IdentityRequest::GetIdentity(_) => (false, None),
+            IdentityRequest::GetIdentityByPublicKeyHash(_) => (true, None),
+        };
+
+        if let Some(identity) = id.and_then(|id| sdk.cache().identity(&id)) {
+            return Ok(Some(identity));
+        }
+
+        let (identity, _) = fetch_request::<Self>(sdk, request).await?;
+
+        if let (true, Some(identity)) = (cacheable, &identity) {
+            sdk.cache().insert_identity(identity.clone());
+        }
+
+        Ok(identity)
+    }
 }
 
-impl Fetch for dpp::prelude::DataContract {
+/// Fetch data contracts.
+///
+/// Data contracts are stored in [Sdk::cache()] and returned from it while they are up to date;
+/// see [CacheSettings](crate::cache::CacheSettings).
+#[async_trait::async_trait]
+impl Fetch for DataContract {
     type Request = platform_proto::GetDataContractRequest;
+
+    async fn fetch<Q: Query<<Self as Fetch>::Request>>(
+        sdk: &mut Sdk,
+        query: Q,
+    ) -> Result<Option<Self>, Error> {
+        let request = query.query(sdk.prove())?;
+
+        // Data contracts at past heights are not cached
+        let id = match request.version {
+            Some(get_data_contract_request::Version::V0(
+                get_data_contract_request::GetDataContractRequestV0 {
+                    ref id,
+                    height: None,
+                    ..
+                },
+            )) => Identifier::from_bytes(id).ok(),
+            _ => None,
+        };
+
+        if let Some(id) = id {
+            if let Some(data_contract) = sdk.cache().data_contract(&id, sdk.platform_height()) {
+                return Ok(Some(data_contract.as_ref().clone()));
+            }
+        }
+
+        let (data_contract, height) = fetch_request::<Self>(sdk, request).await?;
+
+        if let (Some(_), Some(data_contract)) = (id, &data_contract) {
+            sdk.cache()
+                .insert_data_contract(Arc::new(data_contract.clone()), height);
+        }
+
+        Ok(data_contract)
+    }
 }
 
 impl Fetch for Document {
@@ -143,6 +256,10 @@ impl Fetch for drive_proof_verifier::types::IdentityBalanceAndRevision {
     type Request = platform_proto::GetIdentityBalanceAndRevisionRequest;
 }
 
+impl Fetch for drive_proof_verifier::types::IdentityNonceFetcher {
+    type Request = platform_proto::GetIdentityNonceRequest;
+}
+
 impl Fetch for ExtendedEpochInfo {
     type Request = platform_proto::GetEpochsInfoRequest;
 }
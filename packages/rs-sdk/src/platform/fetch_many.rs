@@ -18,6 +18,7 @@ use dapi_grpc::platform::v0::{
 use dashcore_rpc::dashcore::ProTxHash;
 use dpp::block::epoch::EpochIndex;
 use dpp::block::extended_epoch_info::ExtendedEpochInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::DataContract;
 use dpp::document::Document;
 use dpp::identity::KeyID;
@@ -26,8 +27,11 @@ use dpp::util::deserializer::ProtocolVersion;
 use drive_proof_verifier::types::{
     MasternodeProtocolVote, ProtocolVersionVoteCount, RetrievedObjects,
 };
-use drive_proof_verifier::{types::Documents, FromProof};
-use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
+use drive_proof_verifier::{types::Documents, FromProof, FromUnproved};
+use rs_dapi_client::{
+    transport::{PlatformGrpcClient, TransportRequest},
+    DapiRequest, ExecutionResponse, RequestSettings,
+};
 use std::collections::BTreeMap;
 
 use super::LimitQuery;
@@ -73,10 +77,15 @@ pub trait FetchMany<K: Ord>
 where
     Self: Sized,
     BTreeMap<K, Option<Self>>: MockResponse
+        + PartialEq
         + FromProof<
             Self::Request,
             Request = Self::Request,
             Response = <<Self as FetchMany<K>>::Request as TransportRequest>::Response,
+        > + FromUnproved<
+            Self::Request,
+            Request = Self::Request,
+            Response = <<Self as FetchMany<K>>::Request as TransportRequest>::Response,
         > + Sync,
 {
     /// Type of request used to fetch multiple objects from the platform.
@@ -84,7 +93,7 @@ where
     /// Most likely, one of the types defined in [`dapi_grpc::platform::v0`].
     ///
     /// This type must implement [`TransportRequest`] and [`MockRequest`](crate::mock::MockRequest).
-    type Request: TransportRequest
+    type Request: TransportRequest<Client = PlatformGrpcClient>
         + Into<<BTreeMap<K, Option<Self>> as FromProof<<Self as FetchMany<K>>::Request>>::Request>;
 
     /// Fetch (or search) multiple objects on the Dash Platform
@@ -94,6 +103,10 @@ where
     /// Note that this method might introduce some predefined limit on the number of objects returned.
     /// If you need to specify the limit yourself, use [FetchMany::fetch_many_with_limit()] or [LimitQuery] instead.
     ///
+    /// When the [Sdk] is configured without proofs, the request is sent to multiple nodes and objects are
+    /// returned only when enough of them agree on the result; see
+    /// [ConsistencySettings](crate::platform::ConsistencySettings).
+    ///
     /// ## Per-object type documentation
     ///
     /// See documentation of [FetchMany trait implementations](FetchMany#foreign-impls) for each object type
@@ -131,6 +144,14 @@ where
     ) -> Result<RetrievedObjects<K, Self>, Error> {
         let request = query.query(sdk.prove())?;
 
+        if !sdk.prove() {
+            return Ok(sdk
+                .fetch_unproved::<_, BTreeMap<K, Option<Self>>>(request)
+                .await?
+                .0
+                .unwrap_or_default());
+        }
+
         let response = request
             .clone()
             .execute(sdk, RequestSettings::default())
@@ -219,15 +240,30 @@ impl FetchMany<Identifier> for Document {
     ) -> Result<BTreeMap<Identifier, Option<Self>>, Error> {
         let document_query: DocumentQuery = query.query(sdk.prove())?;
 
+        if !sdk.prove() {
+            return Ok(sdk
+                .fetch_unproved::<DocumentQuery, Documents>(document_query)
+                .await?
+                .0
+                .unwrap_or_default());
+        }
+
         let request = document_query.clone();
-        let response: GetDocumentsResponse =
+        let response: ExecutionResponse<GetDocumentsResponse> =
             request.execute(sdk, RequestSettings::default()).await?;
 
         tracing::trace!(request=?document_query, response=?response, "fetch multiple documents");
 
+        let data_contract_id = document_query.data_contract.id();
+
         // let object: Option<BTreeMap<K,Document>> = sdk
         let documents: BTreeMap<Identifier, Option<Document>> = sdk
-            .parse_proof::<DocumentQuery, Documents>(document_query, response)?
+            .parse_proof::<DocumentQuery, Documents>(document_query, response)
+            .map_err(|e| {
+                // Cached data contract can be outdated; retrieve it again next time
+                sdk.cache().invalidate_data_contract(&data_contract_id);
+                e
+            })?
             .unwrap_or_default();
 
         Ok(documents)
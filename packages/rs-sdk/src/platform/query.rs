@@ -60,7 +60,7 @@ pub trait Query<T: TransportRequest>: Send + Debug + Clone {
     ///
     /// # Arguments
     ///
-    /// * `prove` - Whether to include proofs in the response. Only `true` is supported at the moment.
+    /// * `prove` - Whether to include proofs in the response.
     ///
     /// # Returns
     /// On success, this method yields an instance of the `TransportRequest` type (`T`).
@@ -72,28 +72,27 @@ pub trait Query<T: TransportRequest>: Send + Debug + Clone {
     fn query(self, prove: bool) -> Result<T, Error>;
 }
 
+/// Requests are sent as they are, so their own `prove` flag must match [Sdk::prove()](crate::Sdk::prove()).
 impl<T> Query<T> for T
 where
     T: TransportRequest + Sized + Send + Sync + Clone + Debug,
     T::Response: Send + Sync + Debug,
 {
-    fn query(self, prove: bool) -> Result<T, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
+    fn query(self, _prove: bool) -> Result<T, Error> {
         Ok(self)
     }
 }
 
 impl Query<proto::GetDataContractRequest> for Identifier {
     fn query(self, prove: bool) -> Result<proto::GetDataContractRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let id = self.to_vec();
         Ok(proto::GetDataContractRequest {
             version: Some(proto::get_data_contract_request::Version::V0(
-                proto::get_data_contract_request::GetDataContractRequestV0 { id, prove },
+                proto::get_data_contract_request::GetDataContractRequestV0 {
+                    id,
+                    prove,
+                    height: None,
+                },
             )),
         })
     }
@@ -101,9 +100,6 @@ impl Query<proto::GetDataContractRequest> for Identifier {
 
 impl Query<proto::GetDataContractsRequest> for Vec<Identifier> {
     fn query(self, prove: bool) -> Result<proto::GetDataContractsRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let ids = self.into_iter().map(|id| id.to_vec()).collect();
         Ok(proto::GetDataContractsRequest {
             version: Some(proto::get_data_contracts_request::Version::V0(
@@ -116,9 +112,6 @@ impl Query<proto::GetDataContractsRequest> for Vec<Identifier> {
 impl Query<proto::GetIdentityKeysRequest> for Identifier {
     /// Get all keys for an identity with provided identifier.
     fn query(self, prove: bool) -> Result<proto::GetIdentityKeysRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let identity_id = self.to_vec();
         Ok(GetIdentityKeysRequest {
             version: Some(get_identity_keys_request::Version::V0(
@@ -138,11 +131,8 @@ impl Query<proto::GetIdentityKeysRequest> for Identifier {
 
 impl<'a> Query<DocumentQuery> for DriveQuery<'a> {
     fn query(self, prove: bool) -> Result<DocumentQuery, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let q: DocumentQuery = (&self).into();
-        Ok(q)
+        Ok(q.with_proofs(prove))
     }
 }
 
@@ -182,9 +172,6 @@ impl<Q> From<Q> for LimitQuery<Q> {
 
 impl<E: Into<EpochQuery> + Clone + Debug + Send> Query<GetEpochsInfoRequest> for LimitQuery<E> {
     fn query(self, prove: bool) -> Result<GetEpochsInfoRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let inner: EpochQuery = self.query.into();
         Ok(GetEpochsInfoRequest {
             version: Some(proto::get_epochs_info_request::Version::V0(
@@ -207,20 +194,12 @@ impl Query<GetEpochsInfoRequest> for EpochIndex {
 
 impl Query<GetProtocolVersionUpgradeStateRequest> for () {
     fn query(self, prove: bool) -> Result<GetProtocolVersionUpgradeStateRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
-
         Ok(proto::get_protocol_version_upgrade_state_request::GetProtocolVersionUpgradeStateRequestV0 {prove}.into())
     }
 }
 
 impl Query<GetProtocolVersionUpgradeVoteStatusRequest> for LimitQuery<Option<ProTxHash>> {
     fn query(self, prove: bool) -> Result<GetProtocolVersionUpgradeVoteStatusRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
-
         Ok(proto::get_protocol_version_upgrade_vote_status_request::GetProtocolVersionUpgradeVoteStatusRequestV0 {
             prove,
             // start_pro_tx_hash == [] means "start from beginning"
@@ -0,0 +1,23 @@
+//! State transitions used to put changed objects to the Dash Platform.
+//!
+//! Each trait in this module builds a [StateTransition](dpp::state_transition::StateTransition) for one kind of
+//! operation, signs it using provided [Signer](dpp::identity::signer::Signer), broadcasts it to the Platform and,
+//! optionally, waits until it is executed and returns the resulting object.
+pub mod broadcast;
+pub(crate) mod broadcast_request;
+pub mod estimate_fee;
+pub mod put_contract;
+pub mod put_document;
+pub mod put_identity;
+pub mod top_up_identity;
+pub mod transfer;
+pub mod withdraw_from_identity;
+
+pub use broadcast::BroadcastStateTransition;
+pub use estimate_fee::FeeEstimate;
+pub use put_contract::PutContract;
+pub use put_document::PutDocument;
+pub use put_identity::PutIdentity;
+pub use top_up_identity::TopUpIdentity;
+pub use transfer::TransferToIdentity;
+pub use withdraw_from_identity::WithdrawFromIdentity;
@@ -0,0 +1,129 @@
+//! Broadcasting of state transitions to the Dash Platform.
+use dapi_grpc::platform::v0::wait_for_state_transition_result_response::{
+    self, wait_for_state_transition_result_response_v0,
+};
+use dpp::state_transition::StateTransition;
+use drive_proof_verifier::types::{StateTransitionProofResult, StateTransitionQuery};
+use rs_dapi_client::{transport::TransportResponse, DapiRequest, RequestSettings};
+use std::sync::Arc;
+
+use super::broadcast_request::BroadcastRequestForStateTransition;
+use crate::error::StateTransitionBroadcastError;
+use crate::{Error, Sdk};
+
+/// Broadcast a signed [StateTransition] to the Platform and wait until it is executed.
+///
+/// Implemented for [StateTransition] and for [StateTransitionQuery]. Documents batch transitions can only be
+/// verified when data contracts of the documents are known, so they should be wrapped in a [StateTransitionQuery]
+/// with the data contracts added.
+#[async_trait::async_trait]
+pub trait BroadcastStateTransition {
+    /// Submit the state transition to the Platform.
+    ///
+    /// Returns as soon as the state transition was accepted by the DAPI node; it does not mean it was executed.
+    async fn broadcast(&self, sdk: &mut Sdk) -> Result<(), Error>;
+
+    /// Wait until the state transition, broadcast earlier, is executed by the Platform.
+    ///
+    /// Returns the state of the Platform after execution, verified against the returned proof.
+    /// Returns [Error::StateTransitionBroadcastError] if the Platform rejected the state transition.
+    async fn wait_for_response(&self, sdk: &mut Sdk) -> Result<StateTransitionProofResult, Error>;
+
+    /// Broadcast the state transition and wait until it is executed.
+    ///
+    /// See [BroadcastStateTransition::broadcast()] and [BroadcastStateTransition::wait_for_response()].
+    async fn broadcast_and_wait(&self, sdk: &mut Sdk) -> Result<StateTransitionProofResult, Error> {
+        self.broadcast(sdk).await?;
+        self.wait_for_response(sdk).await
+    }
+}
+
+#[async_trait::async_trait]
+impl BroadcastStateTransition for StateTransitionQuery {
+    async fn broadcast(&self, sdk: &mut Sdk) -> Result<(), Error> {
+        let request = self
+            .state_transition
+            .broadcast_request_for_state_transition()?;
+
+        request.execute(sdk, RequestSettings::default()).await?;
+
+        tracing::debug!(
+            state_transition = self.state_transition.name(),
+            "state transition broadcast"
+        );
+
+        Ok(())
+    }
+
+    async fn wait_for_response(&self, sdk: &mut Sdk) -> Result<StateTransitionProofResult, Error> {
+        let request = self
+            .state_transition
+            .wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        if let Some(wait_for_state_transition_result_response::Version::V0(ref v0)) =
+            response.inner.version
+        {
+            if let Some(wait_for_state_transition_result_response_v0::Result::Error(ref e)) =
+                v0.result
+            {
+                return Err(StateTransitionBroadcastError::from(e.clone()).into());
+            }
+        }
+
+        let height = response
+            .inner
+            .metadata_height()
+            .unwrap_or_else(|| sdk.platform_height());
+        // The proof is verified against the state transition, so a node can't forge the result
+        let result = sdk
+            .parse_proof::<StateTransitionQuery, StateTransitionProofResult>(
+                self.clone(),
+                response,
+            )?
+            .ok_or(drive_proof_verifier::Error::NotFound)?;
+
+        update_cache(sdk, &result, height);
+
+        Ok(result)
+    }
+}
+
+/// Replace or invalidate objects in [Sdk::cache()] that were changed by an executed state transition.
+fn update_cache(sdk: &Sdk, result: &StateTransitionProofResult, height: u64) {
+    let cache = sdk.cache();
+
+    match result {
+        StateTransitionProofResult::VerifiedDataContract(data_contract) => {
+            cache.insert_data_contract(Arc::new(data_contract.clone()), height)
+        }
+        StateTransitionProofResult::VerifiedDataContractDeleted(id) => {
+            cache.invalidate_data_contract(id)
+        }
+        StateTransitionProofResult::VerifiedIdentity(identity) => {
+            cache.insert_identity(identity.clone())
+        }
+        StateTransitionProofResult::VerifiedPartialIdentity(identity) => {
+            cache.invalidate_identity(&identity.id)
+        }
+        StateTransitionProofResult::VerifiedBalanceTransfer(sender, recipient) => {
+            cache.invalidate_identity(&sender.id);
+            cache.invalidate_identity(&recipient.id);
+        }
+        StateTransitionProofResult::VerifiedDocuments(_) => {}
+    }
+}
+
+#[async_trait::async_trait]
+impl BroadcastStateTransition for StateTransition {
+    async fn broadcast(&self, sdk: &mut Sdk) -> Result<(), Error> {
+        StateTransitionQuery::new(self.clone()).broadcast(sdk).await
+    }
+
+    async fn wait_for_response(&self, sdk: &mut Sdk) -> Result<StateTransitionProofResult, Error> {
+        StateTransitionQuery::new(self.clone())
+            .wait_for_response(sdk)
+            .await
+    }
+}
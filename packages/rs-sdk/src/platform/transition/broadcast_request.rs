@@ -0,0 +1,47 @@
+//! Conversion of state transitions to DAPI broadcast requests.
+use dapi_grpc::platform::v0::wait_for_state_transition_result_request::{
+    self, WaitForStateTransitionResultRequestV0,
+};
+use dapi_grpc::platform::v0::{
+    BroadcastStateTransitionRequest, WaitForStateTransitionResultRequest,
+};
+use dpp::serialization::PlatformSerializable;
+use dpp::state_transition::StateTransition;
+
+use crate::Error;
+
+/// Create DAPI requests used to broadcast a state transition and wait for its result.
+pub(crate) trait BroadcastRequestForStateTransition {
+    /// Request that submits the state transition to the Platform.
+    fn broadcast_request_for_state_transition(
+        &self,
+    ) -> Result<BroadcastStateTransitionRequest, Error>;
+
+    /// Request that waits until the state transition is executed and returns a proof of its result.
+    fn wait_for_state_transition_result_request(
+        &self,
+    ) -> Result<WaitForStateTransitionResultRequest, Error>;
+}
+
+impl BroadcastRequestForStateTransition for StateTransition {
+    fn broadcast_request_for_state_transition(
+        &self,
+    ) -> Result<BroadcastStateTransitionRequest, Error> {
+        Ok(BroadcastStateTransitionRequest {
+            state_transition: self.serialize_to_bytes()?,
+        })
+    }
+
+    fn wait_for_state_transition_result_request(
+        &self,
+    ) -> Result<WaitForStateTransitionResultRequest, Error> {
+        Ok(WaitForStateTransitionResultRequest {
+            version: Some(wait_for_state_transition_result_request::Version::V0(
+                WaitForStateTransitionResultRequestV0 {
+                    state_transition_hash: self.transaction_id()?.to_vec(),
+                    prove: true,
+                },
+            )),
+        })
+    }
+}
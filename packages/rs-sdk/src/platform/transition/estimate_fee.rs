@@ -0,0 +1,99 @@
+//! Estimation of fees of state transitions before they are broadcast.
+use std::collections::BTreeMap;
+
+use dapi_grpc::platform::v0::estimate_state_transition_fee_request::{
+    self, EstimateStateTransitionFeeRequestV0,
+};
+use dapi_grpc::platform::v0::estimate_state_transition_fee_response::{
+    self, EstimateStateTransitionFeeResponseV0,
+};
+use dapi_grpc::platform::v0::{
+    EstimateStateTransitionFeeRequest, EstimateStateTransitionFeeResponse,
+};
+use dpp::fee::Credits;
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::state_transition::StateTransition;
+
+use crate::error::StateTransitionBroadcastError;
+use crate::Error;
+
+/// Fees of a state transition, as estimated by the Platform against its latest committed state.
+///
+/// Returned by [Sdk::estimate_fee](crate::Sdk::estimate_fee).
+#[derive(Debug, Default)]
+pub struct FeeEstimate {
+    /// Fee paid for the storage of added data
+    pub storage_fee: Credits,
+    /// Fee paid for processing the state transition
+    pub processing_fee: Credits,
+    /// Credits refunded to identities for the data removed by the state transition
+    pub fee_refunds: BTreeMap<Identifier, Credits>,
+    /// Errors the state transition would be rejected with if it was broadcast
+    pub errors: Vec<StateTransitionBroadcastError>,
+}
+
+impl FeeEstimate {
+    /// Storage and processing fees, without refunds.
+    pub fn total_base_fee(&self) -> Credits {
+        self.storage_fee.saturating_add(self.processing_fee)
+    }
+
+    /// Whether the state transition would be accepted by the Platform.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl TryFrom<EstimateStateTransitionFeeResponse> for FeeEstimate {
+    type Error = Error;
+
+    fn try_from(response: EstimateStateTransitionFeeResponse) -> Result<Self, Self::Error> {
+        let Some(estimate_state_transition_fee_response::Version::V0(
+            EstimateStateTransitionFeeResponseV0 {
+                storage_fee,
+                processing_fee,
+                fee_refunds,
+                errors,
+                ..
+            },
+        )) = response.version
+        else {
+            return Err(Error::DapiClientError(
+                "empty fee estimation response".to_string(),
+            ));
+        };
+
+        let fee_refunds = fee_refunds
+            .into_iter()
+            .map(|fee_refund| {
+                Identifier::from_bytes(&fee_refund.identity_id)
+                    .map(|identity_id| (identity_id, fee_refund.credits))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| Error::Protocol(e.into()))?;
+
+        Ok(Self {
+            storage_fee,
+            processing_fee,
+            fee_refunds,
+            errors: errors
+                .into_iter()
+                .map(StateTransitionBroadcastError::from)
+                .collect(),
+        })
+    }
+}
+
+/// Create the DAPI request used to estimate fees of a state transition.
+pub(crate) fn estimate_fee_request(
+    state_transition: &StateTransition,
+) -> Result<EstimateStateTransitionFeeRequest, Error> {
+    Ok(EstimateStateTransitionFeeRequest {
+        version: Some(estimate_state_transition_fee_request::Version::V0(
+            EstimateStateTransitionFeeRequestV0 {
+                state_transition: state_transition.serialize_to_bytes()?,
+            },
+        )),
+    })
+}
@@ -0,0 +1,102 @@
+//! Publish a new [DataContract] on the Platform.
+use std::collections::{BTreeMap, BTreeSet};
+
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::signer::Signer;
+use dpp::identity::{IdentityPublicKey, PartialIdentity};
+use dpp::platform_value::Bytes32;
+use dpp::state_transition::data_contract_create_transition::methods::DataContractCreateTransitionMethodsV0;
+use dpp::state_transition::data_contract_create_transition::DataContractCreateTransition;
+use dpp::state_transition::StateTransition;
+use dpp::ProtocolError;
+use drive_proof_verifier::types::StateTransitionProofResult;
+
+use super::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+
+/// Publish a [DataContract] on the Platform.
+///
+/// The data contract is owned by its current owner, who must own `identity_public_key`.
+/// ID of the published data contract is derived from the owner ID and provided entropy, so it can differ
+/// from the ID of `self`.
+#[async_trait::async_trait]
+pub trait PutContract<S: Signer> {
+    /// Build, sign and broadcast a data contract create state transition.
+    async fn put_to_platform(
+        &self,
+        sdk: &mut Sdk,
+        entropy: Bytes32,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<StateTransition, Error>;
+
+    /// Publish the data contract and wait until it is stored on the Platform.
+    ///
+    /// Returns the data contract as stored on the Platform.
+    async fn put_to_platform_and_wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        entropy: Bytes32,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<DataContract, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer + Sync> PutContract<S> for DataContract {
+    async fn put_to_platform(
+        &self,
+        sdk: &mut Sdk,
+        entropy: Bytes32,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<StateTransition, Error> {
+        let partial_identity = PartialIdentity {
+            id: self.owner_id(),
+            loaded_public_keys: BTreeMap::from([(
+                identity_public_key.id(),
+                identity_public_key.clone(),
+            )]),
+            balance: None,
+            revision: None,
+            not_found_public_keys: BTreeSet::new(),
+        };
+
+        let transition = DataContractCreateTransition::new_from_data_contract(
+            self.clone(),
+            entropy,
+            &partial_identity,
+            identity_public_key.id(),
+            signer,
+            sdk.version(),
+            None,
+        )?;
+
+        transition.broadcast(sdk).await?;
+
+        Ok(transition)
+    }
+
+    async fn put_to_platform_and_wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        entropy: Bytes32,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<DataContract, Error> {
+        let state_transition = self
+            .put_to_platform(sdk, entropy, identity_public_key, signer)
+            .await?;
+
+        let result = state_transition.wait_for_response(sdk).await?;
+
+        match result {
+            StateTransitionProofResult::VerifiedDataContract(data_contract) => Ok(data_contract),
+            _ => Err(Error::Protocol(ProtocolError::CorruptedCodeExecution(
+                "expected data contract in the state transition proof result".to_string(),
+            ))),
+        }
+    }
+}
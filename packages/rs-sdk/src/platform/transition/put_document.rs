@@ -0,0 +1,168 @@
+//! Create or replace a [Document] on the Platform.
+use std::sync::Arc;
+
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters, INITIAL_REVISION};
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dpp::state_transition::StateTransition;
+use dpp::ProtocolError;
+use drive_proof_verifier::types::{StateTransitionProofResult, StateTransitionQuery};
+
+use super::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+
+/// Put a [Document] to the Platform.
+///
+/// Documents with no revision or with the [initial revision](INITIAL_REVISION) are created;
+/// documents with a higher revision replace the existing document. When replacing a document,
+/// its revision must already be incremented.
+#[async_trait::async_trait]
+pub trait PutDocument<S: Signer> {
+    /// Build, sign and broadcast a documents batch state transition that puts this document.
+    ///
+    /// Returns the broadcast state transition; it can be used to wait for the result later.
+    ///
+    /// ## Parameters
+    ///
+    /// - `sdk`: An instance of [Sdk].
+    /// - `data_contract`: Data contract the document belongs to.
+    /// - `document_type_name`: Name of the document type in `data_contract`.
+    /// - `document_state_transition_entropy`: Entropy used to generate the document ID; ignored on replace.
+    /// - `identity_public_key`: Key of the document owner used to sign the state transition.
+    /// - `signer`: [Signer] holding the private key of `identity_public_key`.
+    async fn put_to_platform(
+        &self,
+        sdk: &mut Sdk,
+        data_contract: &DataContract,
+        document_type_name: &str,
+        document_state_transition_entropy: [u8; 32],
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<StateTransition, Error>;
+
+    /// Wait until a state transition returned by [PutDocument::put_to_platform()] is executed
+    /// and return the document as stored on the Platform.
+    ///
+    /// `data_contract` is needed to verify the document in the proof of the state transition result.
+    async fn wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        state_transition: &StateTransition,
+        data_contract: Arc<DataContract>,
+    ) -> Result<Document, Error>;
+
+    /// Put the document to the Platform and wait until it is stored.
+    ///
+    /// See [PutDocument::put_to_platform()] for description of the parameters.
+    async fn put_to_platform_and_wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        data_contract: Arc<DataContract>,
+        document_type_name: &str,
+        document_state_transition_entropy: [u8; 32],
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<Document, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer + Sync> PutDocument<S> for Document {
+    async fn put_to_platform(
+        &self,
+        sdk: &mut Sdk,
+        data_contract: &DataContract,
+        document_type_name: &str,
+        document_state_transition_entropy: [u8; 32],
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<StateTransition, Error> {
+        let document_type = data_contract.document_type_for_name(document_type_name)?;
+
+        let transition = match self.revision() {
+            Some(revision) if revision > INITIAL_REVISION => {
+                DocumentsBatchTransition::new_document_replacement_transition_from_document(
+                    self.clone(),
+                    document_type,
+                    identity_public_key,
+                    signer,
+                    sdk.version(),
+                    None,
+                    None,
+                    None,
+                )?
+            }
+            _ => DocumentsBatchTransition::new_document_creation_transition_from_document(
+                self.clone(),
+                document_type,
+                document_state_transition_entropy,
+                identity_public_key,
+                signer,
+                sdk.version(),
+                None,
+                None,
+                None,
+            )?,
+        };
+
+        transition.broadcast(sdk).await?;
+
+        Ok(transition)
+    }
+
+    async fn wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        state_transition: &StateTransition,
+        data_contract: Arc<DataContract>,
+    ) -> Result<Document, Error> {
+        let result = StateTransitionQuery::new(state_transition.clone())
+            .with_data_contract(data_contract)
+            .wait_for_response(sdk)
+            .await?;
+
+        let StateTransitionProofResult::VerifiedDocuments(mut documents) = result else {
+            return Err(Error::Protocol(ProtocolError::CorruptedCodeExecution(
+                "expected documents in the state transition proof result".to_string(),
+            )));
+        };
+
+        documents
+            .remove(&self.id())
+            .flatten()
+            .ok_or(Error::MissingDependency(
+                "Document".to_string(),
+                format!(
+                    "document {} not found after state transition was executed",
+                    self.id()
+                ),
+            ))
+    }
+
+    async fn put_to_platform_and_wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        data_contract: Arc<DataContract>,
+        document_type_name: &str,
+        document_state_transition_entropy: [u8; 32],
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<Document, Error> {
+        let state_transition = self
+            .put_to_platform(
+                sdk,
+                &data_contract,
+                document_type_name,
+                document_state_transition_entropy,
+                identity_public_key,
+                signer,
+            )
+            .await?;
+
+        <Self as PutDocument<S>>::wait_for_response(self, sdk, &state_transition, data_contract)
+            .await
+    }
+}
@@ -0,0 +1,84 @@
+//! Register a new [Identity] on the Platform.
+use dpp::dashcore::PrivateKey;
+use dpp::identity::signer::Signer;
+use dpp::prelude::{AssetLockProof, Identity};
+use dpp::state_transition::identity_create_transition::methods::IdentityCreateTransitionMethodsV0;
+use dpp::state_transition::identity_create_transition::IdentityCreateTransition;
+use dpp::state_transition::StateTransition;
+use dpp::{NativeBlsModule, ProtocolError};
+use drive_proof_verifier::types::StateTransitionProofResult;
+
+use super::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+
+/// Register an [Identity] on the Platform, funding it with an asset lock.
+#[async_trait::async_trait]
+pub trait PutIdentity<S: Signer> {
+    /// Build, sign and broadcast an identity create state transition.
+    ///
+    /// Public keys of the identity are signed with `signer`; the state transition itself is signed
+    /// with the one-time private key of the asset lock.
+    async fn put_to_platform(
+        &self,
+        sdk: &mut Sdk,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_proof_private_key: &PrivateKey,
+        signer: &S,
+    ) -> Result<StateTransition, Error>;
+
+    /// Register the identity and wait until it is stored on the Platform.
+    ///
+    /// Returns the identity as stored on the Platform.
+    async fn put_to_platform_and_wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_proof_private_key: &PrivateKey,
+        signer: &S,
+    ) -> Result<Identity, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer + Sync> PutIdentity<S> for Identity {
+    async fn put_to_platform(
+        &self,
+        sdk: &mut Sdk,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_proof_private_key: &PrivateKey,
+        signer: &S,
+    ) -> Result<StateTransition, Error> {
+        let transition = IdentityCreateTransition::try_from_identity_with_signer(
+            self.clone(),
+            asset_lock_proof,
+            &asset_lock_proof_private_key.to_bytes(),
+            signer,
+            &NativeBlsModule,
+            sdk.version(),
+        )?;
+
+        transition.broadcast(sdk).await?;
+
+        Ok(transition)
+    }
+
+    async fn put_to_platform_and_wait_for_response(
+        &self,
+        sdk: &mut Sdk,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_proof_private_key: &PrivateKey,
+        signer: &S,
+    ) -> Result<Identity, Error> {
+        let state_transition = self
+            .put_to_platform(sdk, asset_lock_proof, asset_lock_proof_private_key, signer)
+            .await?;
+
+        let result = state_transition.wait_for_response(sdk).await?;
+
+        match result {
+            StateTransitionProofResult::VerifiedIdentity(identity) => Ok(identity),
+            _ => Err(Error::Protocol(ProtocolError::CorruptedCodeExecution(
+                "expected identity in the state transition proof result".to_string(),
+            ))),
+        }
+    }
+}
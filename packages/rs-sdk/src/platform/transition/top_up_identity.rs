@@ -0,0 +1,56 @@
+//! Top up the balance of an [Identity].
+use dpp::dashcore::PrivateKey;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::Identity;
+use dpp::prelude::AssetLockProof;
+use dpp::state_transition::identity_topup_transition::methods::IdentityTopUpTransitionMethodsV0;
+use dpp::state_transition::identity_topup_transition::IdentityTopUpTransition;
+use dpp::{NativeBlsModule, ProtocolError};
+use drive_proof_verifier::types::{IdentityBalance, StateTransitionProofResult};
+
+use super::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+
+/// Add credits from an asset lock to the balance of an [Identity].
+#[async_trait::async_trait]
+pub trait TopUpIdentity {
+    /// Top up the identity and wait until the state transition is executed.
+    ///
+    /// The state transition is signed with the one-time private key of the asset lock.
+    ///
+    /// Returns the new balance of the identity.
+    async fn top_up_identity(
+        &self,
+        sdk: &mut Sdk,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_proof_private_key: &PrivateKey,
+    ) -> Result<IdentityBalance, Error>;
+}
+
+#[async_trait::async_trait]
+impl TopUpIdentity for Identity {
+    async fn top_up_identity(
+        &self,
+        sdk: &mut Sdk,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_proof_private_key: &PrivateKey,
+    ) -> Result<IdentityBalance, Error> {
+        let state_transition = IdentityTopUpTransition::try_from_identity(
+            self.clone(),
+            asset_lock_proof,
+            &asset_lock_proof_private_key.to_bytes(),
+            &NativeBlsModule,
+            sdk.version(),
+            None,
+        )?;
+
+        let result = state_transition.broadcast_and_wait(sdk).await?;
+
+        match result {
+            StateTransitionProofResult::VerifiedIdentity(identity) => Ok(identity.balance()),
+            _ => Err(Error::Protocol(ProtocolError::CorruptedCodeExecution(
+                "expected identity in the state transition proof result".to_string(),
+            ))),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Transfer credits between identities.
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::signer::Signer;
+use dpp::identity::Identity;
+use dpp::prelude::Identifier;
+use dpp::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::ProtocolError;
+use drive_proof_verifier::types::{
+    IdentityBalance, IdentityNonceFetcher, StateTransitionProofResult,
+};
+
+use super::broadcast::BroadcastStateTransition;
+use crate::platform::Fetch;
+use crate::{Error, Sdk};
+
+/// Transfer credits from an [Identity] to another identity.
+#[async_trait::async_trait]
+pub trait TransferToIdentity<S: Signer> {
+    /// Transfer `amount` credits to identity `to_identity_id` and wait until the state transition is executed.
+    ///
+    /// The state transition is signed with the first critical authentication key of the identity,
+    /// which must be available in `signer`. The nonce of the transition is fetched from the platform.
+    ///
+    /// Returns the new balance of the sending identity.
+    async fn transfer_credits(
+        &self,
+        sdk: &mut Sdk,
+        to_identity_id: Identifier,
+        amount: u64,
+        signer: &S,
+    ) -> Result<IdentityBalance, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer + Sync> TransferToIdentity<S> for Identity {
+    async fn transfer_credits(
+        &self,
+        sdk: &mut Sdk,
+        to_identity_id: Identifier,
+        amount: u64,
+        signer: &S,
+    ) -> Result<IdentityBalance, Error> {
+        // Identities without any nonce-protected state transition start from 0
+        let nonce = IdentityNonceFetcher::fetch(sdk, self.id())
+            .await?
+            .map(|IdentityNonceFetcher(nonce)| nonce)
+            .unwrap_or_default();
+
+        let state_transition = IdentityCreditTransferTransition::try_from_identity(
+            self,
+            to_identity_id,
+            amount,
+            nonce + 1,
+            signer,
+            sdk.version(),
+            None,
+        )?;
+
+        let result = state_transition.broadcast_and_wait(sdk).await?;
+
+        match result {
+            StateTransitionProofResult::VerifiedBalanceTransfer(sender, _recipient) => {
+                sender.balance.ok_or(Error::MissingDependency(
+                    "IdentityBalance".to_string(),
+                    format!("balance of identity {} not found", self.id()),
+                ))
+            }
+            _ => Err(Error::Protocol(ProtocolError::CorruptedCodeExecution(
+                "expected balance transfer in the state transition proof result".to_string(),
+            ))),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Withdraw credits of an [Identity] to a Dash Core address.
+use dpp::dashcore::Address;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::core_script::CoreScript;
+use dpp::identity::signer::Signer;
+use dpp::identity::Identity;
+use dpp::state_transition::identity_credit_withdrawal_transition::methods::IdentityCreditWithdrawalTransitionMethodsV0;
+use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dpp::withdrawal::Pooling;
+use dpp::ProtocolError;
+use drive_proof_verifier::types::{
+    IdentityBalance, IdentityNonceFetcher, StateTransitionProofResult,
+};
+
+use super::broadcast::BroadcastStateTransition;
+use crate::platform::Fetch;
+use crate::{Error, Sdk};
+
+/// Default fee per byte paid for the withdrawal transaction on Dash Core.
+pub const DEFAULT_CORE_FEE_PER_BYTE: u32 = 1;
+
+/// Withdraw credits from an [Identity] to a Dash Core address.
+#[async_trait::async_trait]
+pub trait WithdrawFromIdentity<S: Signer> {
+    /// Withdraw `amount` credits to `address` and wait until the state transition is executed.
+    ///
+    /// `core_fee_per_byte` defaults to [DEFAULT_CORE_FEE_PER_BYTE].
+    /// The state transition is signed with the first critical authentication key of the identity,
+    /// which must be available in `signer`. The nonce of the transition is fetched from the platform.
+    ///
+    /// Returns the new balance of the identity.
+    async fn withdraw(
+        &self,
+        sdk: &mut Sdk,
+        address: Address,
+        amount: u64,
+        core_fee_per_byte: Option<u32>,
+        signer: &S,
+    ) -> Result<IdentityBalance, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer + Sync> WithdrawFromIdentity<S> for Identity {
+    async fn withdraw(
+        &self,
+        sdk: &mut Sdk,
+        address: Address,
+        amount: u64,
+        core_fee_per_byte: Option<u32>,
+        signer: &S,
+    ) -> Result<IdentityBalance, Error> {
+        // Identities without any nonce-protected state transition start from 0
+        let nonce = IdentityNonceFetcher::fetch(sdk, self.id())
+            .await?
+            .map(|IdentityNonceFetcher(nonce)| nonce)
+            .unwrap_or_default();
+
+        let state_transition = IdentityCreditWithdrawalTransition::try_from_identity(
+            self,
+            CoreScript::new(address.script_pubkey()),
+            amount,
+            Pooling::Never,
+            core_fee_per_byte.unwrap_or(DEFAULT_CORE_FEE_PER_BYTE),
+            nonce + 1,
+            signer,
+            sdk.version(),
+            None,
+        )?;
+
+        let result = state_transition.broadcast_and_wait(sdk).await?;
+
+        match result {
+            StateTransitionProofResult::VerifiedIdentity(identity) => Ok(identity.balance()),
+            _ => Err(Error::Protocol(ProtocolError::CorruptedCodeExecution(
+                "expected identity in the state transition proof result".to_string(),
+            ))),
+        }
+    }
+}
@@ -3,12 +3,13 @@
 use dapi_grpc::platform::v0::get_identity_balance_and_revision_request::GetIdentityBalanceAndRevisionRequestV0;
 use dapi_grpc::platform::v0::get_identity_balance_request::GetIdentityBalanceRequestV0;
 use dapi_grpc::platform::v0::get_identity_by_public_key_hash_request::GetIdentityByPublicKeyHashRequestV0;
+use dapi_grpc::platform::v0::get_identity_nonce_request::GetIdentityNonceRequestV0;
 use dapi_grpc::platform::v0::get_identity_request::GetIdentityRequestV0;
 use dapi_grpc::platform::v0::{
     get_identity_balance_and_revision_request, get_identity_balance_request,
-    get_identity_by_public_key_hash_request, get_identity_request,
+    get_identity_by_public_key_hash_request, get_identity_nonce_request, get_identity_request,
     GetIdentityBalanceAndRevisionRequest, GetIdentityBalanceRequest,
-    GetIdentityByPublicKeyHashRequest, GetIdentityRequest,
+    GetIdentityByPublicKeyHashRequest, GetIdentityNonceRequest, GetIdentityRequest,
 };
 use dpp::prelude::Identity;
 
@@ -30,14 +31,12 @@ delegate_enum! {
 
 impl Query<IdentityRequest> for dpp::prelude::Identifier {
     fn query(self, prove: bool) -> Result<IdentityRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let id = self.to_vec();
         Ok(IdentityRequest::GetIdentity(GetIdentityRequest {
             version: Some(get_identity_request::Version::V0(GetIdentityRequestV0 {
                 id,
-                prove: true,
+                prove,
+                height: None,
             })),
         }))
     }
@@ -52,9 +51,6 @@ pub struct PublicKeyHash(pub [u8; 20]);
 
 impl Query<IdentityRequest> for PublicKeyHash {
     fn query(self, prove: bool) -> Result<IdentityRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let request: GetIdentityByPublicKeyHashRequest = GetIdentityByPublicKeyHashRequest {
             version: Some(get_identity_by_public_key_hash_request::Version::V0(
                 GetIdentityByPublicKeyHashRequestV0 {
@@ -70,9 +66,6 @@ impl Query<IdentityRequest> for PublicKeyHash {
 
 impl Query<GetIdentityBalanceRequest> for dpp::prelude::Identifier {
     fn query(self, prove: bool) -> Result<GetIdentityBalanceRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let id = self.to_vec();
 
         let request: GetIdentityBalanceRequest = GetIdentityBalanceRequest {
@@ -87,9 +80,6 @@ impl Query<GetIdentityBalanceRequest> for dpp::prelude::Identifier {
 
 impl Query<GetIdentityBalanceAndRevisionRequest> for dpp::prelude::Identifier {
     fn query(self, prove: bool) -> Result<GetIdentityBalanceAndRevisionRequest, Error> {
-        if !prove {
-            unimplemented!("queries without proofs are not supported yet");
-        }
         let id = self.to_vec();
         let request: GetIdentityBalanceAndRevisionRequest = GetIdentityBalanceAndRevisionRequest {
             version: Some(get_identity_balance_and_revision_request::Version::V0(
@@ -100,3 +90,16 @@ impl Query<GetIdentityBalanceAndRevisionRequest> for dpp::prelude::Identifier {
         Ok(request)
     }
 }
+
+impl Query<GetIdentityNonceRequest> for dpp::prelude::Identifier {
+    fn query(self, prove: bool) -> Result<GetIdentityNonceRequest, Error> {
+        let id = self.to_vec();
+        let request: GetIdentityNonceRequest = GetIdentityNonceRequest {
+            version: Some(get_identity_nonce_request::Version::V0(
+                GetIdentityNonceRequestV0 { id, prove },
+            )),
+        };
+
+        Ok(request)
+    }
+}
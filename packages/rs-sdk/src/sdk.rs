@@ -1,29 +1,33 @@
 //! [Sdk] entrypoint to Dash Platform.
 
 #[cfg(feature = "mocks")]
-use std::{
-    path::{Path, PathBuf},
-    sync::Arc,
-};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+use crate::cache::{CacheSettings, SdkCache};
 #[cfg(feature = "mocks")]
 use crate::mock::MockDashPlatformSdk;
 use crate::mock::{MockRequest, MockResponse};
+use crate::platform::consistency::{self, ConsistencySettings};
+use crate::platform::events::{self, PlatformEventStream, PlatformEventsFilter};
+use crate::platform::transition::estimate_fee::{estimate_fee_request, FeeEstimate};
 use crate::{core::CoreClient, error::Error};
+use dpp::state_transition::StateTransition;
 use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
 #[cfg(feature = "mocks")]
 use drive_proof_verifier::MockQuorumInfoProvider;
-use drive_proof_verifier::{FromProof, QuorumInfoProvider};
+use drive_proof_verifier::{FromProof, FromUnproved, QuorumInfoProvider};
+use futures::StreamExt;
 #[cfg(feature = "mocks")]
 use hex::ToHex;
 pub use http::Uri;
 #[cfg(feature = "mocks")]
 use rs_dapi_client::mock::MockDapiClient;
-pub use rs_dapi_client::AddressList;
 use rs_dapi_client::{
-    transport::{TransportClient, TransportRequest},
-    Dapi, DapiClient, DapiClientError, RequestSettings,
+    transport::{PlatformGrpcClient, TransportClient, TransportRequest},
+    Dapi, DapiClient, DapiClientError, DapiRequest, ExecutionResponse, RequestSettings,
 };
+pub use rs_dapi_client::{AddressHealth, AddressList};
 #[cfg(feature = "mocks")]
 use tokio::sync::Mutex;
 
@@ -49,8 +53,13 @@ pub struct Sdk {
     inner: SdkInstance,
     /// Use proofs when retrieving data from the platform.
     ///
-    /// This is set to `true` by default. `false` is not implemented yet.
+    /// This is set to `true` by default. When `false`, responses of multiple nodes are compared instead,
+    /// as configured in [Sdk::consistency].
     proofs: bool,
+    /// Settings of requests executed without proofs.
+    consistency: ConsistencySettings,
+    /// Data contracts, quorum public keys and identities retrieved from the Platform.
+    cache: SdkCache,
     #[cfg(feature = "mocks")]
     dump_dir: Option<PathBuf>,
 }
@@ -64,8 +73,10 @@ enum SdkInstance {
     Dapi {
         /// DAPI client used to communicate with Dash Platform.
         dapi: DapiClient,
-        /// Core client used to retrieve quorum keys from core.
-        core: CoreClient,
+        /// Core client, if configured with [SdkBuilder::with_core].
+        core: Option<Arc<CoreClient>>,
+        /// Provider of quorum keys used to verify proofs.
+        quorum_provider: Arc<dyn QuorumInfoProvider>,
         /// Platform version configured for this Sdk
         version: &'static PlatformVersion,
     },
@@ -95,6 +106,7 @@ impl Sdk {
     /// Retrieve object `O` from proof contained in `request` (of type `R`) and `response`.
     ///
     /// This method is used to retrieve objects from proofs returned by Dash Platform.
+    /// The address that served the `response` is banned if its proof is invalid.
     ///
     /// ## Generic Parameters
     ///
@@ -103,20 +115,75 @@ impl Sdk {
     pub(crate) fn parse_proof<R, O: FromProof<R> + MockResponse>(
         &self,
         request: O::Request,
-        response: O::Response,
+        response: ExecutionResponse<O::Response>,
     ) -> Result<Option<O>, drive_proof_verifier::Error>
     where
         O::Request: MockRequest,
     {
+        let ExecutionResponse {
+            inner: response,
+            address,
+        } = response;
+
         match self.inner {
-            SdkInstance::Dapi { .. } => {
-                O::maybe_from_proof(request, response, self.version(), self)
+            SdkInstance::Dapi { ref dapi, .. } => {
+                let result = O::maybe_from_proof(request, response, self.version(), self);
+
+                // Don't ask the same node again if its response failed verification
+                if let (Err(error), Some(uri)) = (&result, address) {
+                    if is_node_fault(error) {
+                        tracing::warn!(%uri, %error, "banning address that returned an invalid proof");
+                        dapi.ban_address(&uri);
+                    }
+                }
+
+                result
             }
             #[cfg(feature = "mocks")]
             SdkInstance::Mock { ref mock, .. } => mock.parse_proof(request, response),
         }
     }
 
+    /// Retrieve object `O` from responses of multiple nodes to `request` sent without proofs.
+    ///
+    /// The object is accepted only if enough nodes agree on it, as configured with
+    /// [SdkBuilder::with_consistency()]. Returns the object and Platform height it was retrieved at, if known.
+    ///
+    /// ## Generic Parameters
+    ///
+    /// - `R`: Type of the request used to fetch the object.
+    /// - `O`: Type of the object to be retrieved from the responses.
+    pub(crate) async fn fetch_unproved<R, O>(
+        &mut self,
+        request: R,
+    ) -> Result<(Option<O>, Option<u64>), Error>
+    where
+        R: TransportRequest<Client = PlatformGrpcClient> + MockRequest,
+        O: FromUnproved<R, Request = R, Response = R::Response> + PartialEq + MockResponse,
+    {
+        match self.inner {
+            SdkInstance::Dapi { ref dapi, .. } => {
+                consistency::fetch_unproved(dapi, request, self.consistency, self.version()).await
+            }
+            #[cfg(feature = "mocks")]
+            SdkInstance::Mock {
+                ref dapi, ref mock, ..
+            } => {
+                use rs_dapi_client::transport::TransportResponse;
+
+                let response = dapi
+                    .lock()
+                    .await
+                    .execute(request.clone(), RequestSettings::default())
+                    .await?
+                    .inner;
+                let height = response.metadata_height();
+
+                Ok((mock.parse_unproved(request, response)?, height))
+            }
+        }
+    }
+
     /// Returns a mutable reference to the `MockDashPlatformSdk` instance.
     ///
     /// Use returned object to configure mock responses with methods like `expect_fetch`.
@@ -156,6 +223,110 @@ impl Sdk {
         self.proofs
     }
 
+    /// Settings of requests executed without proofs, used when [Sdk::prove()] is `false`.
+    pub fn consistency(&self) -> ConsistencySettings {
+        self.consistency
+    }
+
+    /// Cache of data retrieved from the Platform.
+    ///
+    /// Use it to invalidate cached objects that are known to be outdated.
+    pub fn cache(&self) -> &SdkCache {
+        &self.cache
+    }
+
+    /// Highest Platform height seen in responses received by this SDK.
+    ///
+    /// Returns `0` in mock mode.
+    pub(crate) fn platform_height(&self) -> u64 {
+        match self.inner {
+            SdkInstance::Dapi { ref dapi, .. } => dapi.highest_seen_height(),
+            #[cfg(feature = "mocks")]
+            SdkInstance::Mock { .. } => 0,
+        }
+    }
+
+    /// Health statistics of DAPI addresses used by this SDK.
+    ///
+    /// Returns an empty list in mock mode.
+    pub fn address_health(&self) -> Vec<(Uri, AddressHealth)> {
+        match &self.inner {
+            SdkInstance::Dapi { dapi, .. } => dapi.address_health(),
+            #[cfg(feature = "mocks")]
+            SdkInstance::Mock { .. } => Vec::new(),
+        }
+    }
+
+    /// Add evonodes from the masternode list of Dash Core to DAPI addresses used by this SDK.
+    ///
+    /// Returns number of new addresses. Can be called periodically to follow changes of the
+    /// masternode list; addresses that are already known keep their health statistics.
+    pub fn discover_evonodes(&self) -> Result<usize, Error> {
+        match &self.inner {
+            SdkInstance::Dapi {
+                dapi,
+                core: Some(core),
+                ..
+            } => Ok(core
+                .evonode_addresses()?
+                .into_iter()
+                .filter(|uri| dapi.add_uri(uri.clone()))
+                .count()),
+            SdkInstance::Dapi { core: None, .. } => Err(Error::Config(
+                "evonode discovery requires Core configured with SdkBuilder::with_core".to_string(),
+            )),
+            #[cfg(feature = "mocks")]
+            SdkInstance::Mock { .. } => Err(Error::Config(
+                "evonode discovery is not supported in mock mode".to_string(),
+            )),
+        }
+    }
+
+    /// Subscribe to changes of platform state selected by the `filter`.
+    ///
+    /// Each item of the returned stream contains changes made by one committed block.
+    /// Events are not proved; use [Fetch](crate::platform::Fetch) to retrieve a proved state
+    /// of changed objects when needed.
+    ///
+    /// Subscriptions are not supported in mock mode.
+    pub async fn subscribe(
+        &mut self,
+        filter: PlatformEventsFilter,
+    ) -> Result<PlatformEventStream, Error> {
+        match self.inner {
+            SdkInstance::Dapi { ref mut dapi, .. } => {
+                let stream = dapi
+                    .subscribe_platform_events(filter.into(), RequestSettings::default())
+                    .await?;
+
+                Ok(stream.map(events::parse_response).boxed())
+            }
+            #[cfg(feature = "mocks")]
+            SdkInstance::Mock { .. } => Err(Error::Config(
+                "subscriptions are not supported in mock mode".to_string(),
+            )),
+        }
+    }
+
+    /// Estimate fees of a state transition without broadcasting it.
+    ///
+    /// The state transition is validated and its fees are estimated by the Platform against its
+    /// latest committed state; nothing is changed. It doesn't need to be signed, so the fees can be
+    /// shown to the user before asking them to sign.
+    ///
+    /// Consensus errors the state transition would be rejected with are returned in
+    /// [FeeEstimate::errors]. Estimations are not proved.
+    pub async fn estimate_fee(
+        &mut self,
+        state_transition: &StateTransition,
+    ) -> Result<FeeEstimate, Error> {
+        let request = estimate_fee_request(state_transition)?;
+
+        let response = request.execute(self, RequestSettings::default()).await?;
+
+        FeeEstimate::try_from(response.inner)
+    }
+
     /// Save quorum public key to disk.
     ///
     /// Files are named: `quorum_pubkey-<int_quorum_type>-<hex_quorum_hash>.json`
@@ -190,6 +361,24 @@ impl Sdk {
     }
 }
 
+/// Check if proof verification failed because of the response, as opposed to the request
+/// or the quorum information provided by Core.
+fn is_node_fault(error: &drive_proof_verifier::Error) -> bool {
+    use drive_proof_verifier::Error::*;
+
+    matches!(
+        error,
+        DriveError { .. }
+            | EmptyResponseMetadata
+            | EmptyVersion
+            | NoProofInResult
+            | ResponseDecodeError { .. }
+            | SignatureVerificationError { .. }
+            | InvalidSignatureFormat { .. }
+            | InvalidSignature { .. }
+    )
+}
+
 impl QuorumInfoProvider for Sdk {
     fn get_quorum_public_key(
         &self,
@@ -197,8 +386,18 @@ impl QuorumInfoProvider for Sdk {
         quorum_hash: [u8; 32],
         core_chain_locked_height: u32,
     ) -> Result<[u8; 48], drive_proof_verifier::Error> {
+        if let Some(key) =
+            self.cache
+                .quorum_public_key(quorum_type, quorum_hash, core_chain_locked_height)
+        {
+            return Ok(key);
+        }
+
         let provider: &dyn QuorumInfoProvider = match self.inner {
-            SdkInstance::Dapi { ref core, .. } => core,
+            SdkInstance::Dapi {
+                ref quorum_provider,
+                ..
+            } => quorum_provider.as_ref(),
             #[cfg(feature = "mocks")]
             SdkInstance::Mock {
                 ref quorum_provider,
@@ -208,6 +407,12 @@ impl QuorumInfoProvider for Sdk {
 
         let key =
             provider.get_quorum_public_key(quorum_type, quorum_hash, core_chain_locked_height)?;
+        self.cache.insert_quorum_public_key(
+            quorum_type,
+            quorum_hash,
+            core_chain_locked_height,
+            key,
+        );
 
         #[cfg(feature = "mocks")]
         self.dump_quorum_public_key(quorum_type, quorum_hash, core_chain_locked_height, &key);
@@ -222,7 +427,10 @@ impl Dapi for Sdk {
         &mut self,
         request: R,
         settings: RequestSettings,
-    ) -> Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>> {
+    ) -> Result<
+        ExecutionResponse<R::Response>,
+        DapiClientError<<R::Client as TransportClient>::Error>,
+    > {
         match self.inner {
             SdkInstance::Dapi { ref mut dapi, .. } => dapi.execute(request, settings).await,
             #[cfg(feature = "mocks")]
@@ -244,7 +452,7 @@ impl Dapi for Sdk {
 /// Mandatory steps of initialization in normal mode are:
 ///
 /// 1. Create an instance of [SdkBuilder] with [`SdkBuilder::new()`]
-/// 2. Configure the builder with [`SdkBuilder::with_core()`]
+/// 2. Configure the builder with [`SdkBuilder::with_core()`] or [`SdkBuilder::with_quorum_provider()`]
 /// 3. Call [`SdkBuilder::build()`] to create the [Sdk] instance.
 pub struct SdkBuilder {
     /// List of addressses to connect to.
@@ -258,8 +466,15 @@ pub struct SdkBuilder {
     core_user: String,
     core_password: String,
 
+    /// Provider of quorum keys; if `None`, Core is used.
+    quorum_provider: Option<Arc<dyn QuorumInfoProvider>>,
+
     /// If true, request and verify proofs of the responses.
     proofs: bool,
+    /// Settings of requests executed without proofs.
+    consistency: ConsistencySettings,
+    /// Settings of the cache of data retrieved from the Platform.
+    cache: CacheSettings,
 
     version: &'static PlatformVersion,
 
@@ -278,8 +493,11 @@ impl Default for SdkBuilder {
             core_port: 0,
             core_password: "".to_string(),
             core_user: "".to_string(),
+            quorum_provider: None,
 
             proofs: true,
+            consistency: ConsistencySettings::default(),
+            cache: CacheSettings::default(),
 
             version: PlatformVersion::latest(),
             #[cfg(feature = "mocks")]
@@ -290,6 +508,9 @@ impl Default for SdkBuilder {
 
 impl SdkBuilder {
     /// Create a new SdkBuilder with provided address list.
+    ///
+    /// If the address list is empty, evonodes are discovered from the masternode list of Dash Core
+    /// configured with [`SdkBuilder::with_core()`].
     pub fn new(addresses: AddressList) -> Self {
         Self {
             addresses: Some(addresses),
@@ -345,6 +566,34 @@ impl SdkBuilder {
         self
     }
 
+    /// Configure whether to request and verify proofs of the responses.
+    ///
+    /// Without proofs, each request is sent to multiple nodes and their responses are compared,
+    /// as configured with [`SdkBuilder::with_consistency()`]. This is cheaper for the Platform, but only
+    /// as trustworthy as the nodes that agree on the response.
+    ///
+    /// Defaults to `true`.
+    pub fn with_proofs(mut self, proofs: bool) -> Self {
+        self.proofs = proofs;
+        self
+    }
+
+    /// Configure requests executed without proofs.
+    ///
+    /// Defaults to [ConsistencySettings::default()]. See [ConsistencySettings] for more information.
+    pub fn with_consistency(mut self, consistency: ConsistencySettings) -> Self {
+        self.consistency = consistency;
+        self
+    }
+
+    /// Configure caching of data contracts, quorum public keys and identities.
+    ///
+    /// Defaults to [CacheSettings::default()]. See [CacheSettings] for more information.
+    pub fn with_cache(mut self, cache: CacheSettings) -> Self {
+        self.cache = cache;
+        self
+    }
+
     /// Configure connection to Dash Core
     ///
     /// TODO: This is temporary implementation, effective until we integrate SPV into rs-sdk.
@@ -357,6 +606,16 @@ impl SdkBuilder {
         self
     }
 
+    /// Configure provider of quorum keys used to verify proofs.
+    ///
+    /// Use it to verify proofs without a Core node, for example with
+    /// [DapiQuorumProvider](crate::core::DapiQuorumProvider). Defaults to Core configured with
+    /// [`SdkBuilder::with_core()`].
+    pub fn with_quorum_provider<P: QuorumInfoProvider + 'static>(mut self, provider: P) -> Self {
+        self.quorum_provider = Some(Arc::new(provider));
+        self
+    }
+
     /// Configure directory where dumps of all requests and responses will be saved.
     /// Useful for debugging.
     ///
@@ -386,30 +645,52 @@ impl SdkBuilder {
     pub fn build(self) -> Result<Sdk, Error> {
         PlatformVersion::set_current(self.version);
 
+        if !self.proofs {
+            self.consistency.validate()?;
+        }
+
         match self.addresses {
             Some(addresses) => {
-                if self.core_ip.is_empty() || self.core_port == 0 {
-                    return Err(Error::Config(
-                        "Core must be configured with SdkBuilder::with_core".to_string(),
-                    ));
-                }
+                let core = if self.core_ip.is_empty() || self.core_port == 0 {
+                    None
+                } else {
+                    Some(Arc::new(CoreClient::new(
+                        &self.core_ip,
+                        self.core_port,
+                        &self.core_user,
+                        &self.core_password,
+                    )?))
+                };
+
+                let quorum_provider: Arc<dyn QuorumInfoProvider> = match (self.quorum_provider, &core) {
+                    (Some(provider), _) => provider,
+                    (None, Some(core)) => core.clone(),
+                    (None, None) => return Err(Error::Config(
+                        "Core must be configured with SdkBuilder::with_core, or quorum provider with SdkBuilder::with_quorum_provider".to_string(),
+                    )),
+                };
+
+                let discover_evonodes = addresses.is_empty();
                 let dapi = DapiClient::new(addresses, self.settings);
                 #[cfg(feature = "mocks")]
                 let dapi = dapi.dump_dir(self.dump_dir.clone());
 
-                let core = CoreClient::new(
-                    &self.core_ip,
-                    self.core_port,
-                    &self.core_user,
-                    &self.core_password,
-                )?;
-
-                Ok(Sdk{
-                    inner:SdkInstance::Dapi { dapi, core, version:self.version },
+                let sdk = Sdk{
+                    inner:SdkInstance::Dapi { dapi, core, quorum_provider, version:self.version },
                     proofs:self.proofs,
+                    consistency: self.consistency,
+                    cache: SdkCache::new(self.cache),
                     #[cfg(feature = "mocks")]
                     dump_dir: self.dump_dir,
-                })
+                };
+
+                if discover_evonodes && sdk.discover_evonodes()? == 0 {
+                    return Err(Error::Config(
+                        "no evonodes found in the masternode list".to_string(),
+                    ));
+                }
+
+                Ok(sdk)
             },
             #[cfg(feature = "mocks")]
             None =>{ let dapi =Arc::new(Mutex::new(  MockDapiClient::new()));
@@ -421,6 +702,8 @@ impl SdkBuilder {
                     },
                     dump_dir: self.dump_dir,
                     proofs:self.proofs,
+                    consistency: self.consistency,
+                    cache: SdkCache::new(self.cache),
             })},
             #[cfg(not(feature = "mocks"))]
             None => Err(Error::Config(
@@ -22,13 +22,15 @@ async fn get_current_epoch(sdk: &mut Sdk, cfg: &Config) -> EpochIndex {
     let identity_request: GetIdentityRequest = GetIdentityRequestV0 {
         id: cfg.existing_identity_id.to_vec(),
         prove: true,
+        height: None,
     }
     .into();
 
     let response = sdk
         .execute(identity_request, RequestSettings::default())
         .await
-        .expect("get identity");
+        .expect("get identity")
+        .inner;
 
     response.metadata().expect("metadata").epoch as EpochIndex
 }
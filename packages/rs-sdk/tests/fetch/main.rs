@@ -10,6 +10,7 @@ mod data_contract;
 mod document;
 mod epoch;
 mod identity;
+mod mock_broadcast;
 mod mock_fetch;
 mod mock_fetch_many;
 mod protocol_version_vote_count;
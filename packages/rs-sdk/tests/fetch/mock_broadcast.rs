@@ -0,0 +1,274 @@
+//! Tests of broadcasting state transitions using mock API.
+//!
+
+use super::common::{mock_data_contract, mock_document_type};
+use dpp::{
+    data_contract::{
+        accessors::v0::DataContractV0Getters,
+        document_type::{
+            accessors::DocumentTypeV0Getters, random_document::CreateRandomDocument, DocumentType,
+        },
+    },
+    document::DocumentV0Getters,
+    identity::{
+        identity_public_key::v0::IdentityPublicKeyV0, signer::Signer, IdentityPublicKey, KeyType,
+        PartialIdentity, Purpose, SecurityLevel,
+    },
+    platform_value::{BinaryData, Bytes32},
+    prelude::Identifier,
+    state_transition::{
+        data_contract_create_transition::{
+            methods::DataContractCreateTransitionMethodsV0, DataContractCreateTransition,
+        },
+        documents_batch_transition::{
+            methods::v0::DocumentsBatchTransitionMethodsV0, DocumentsBatchTransition,
+        },
+        identity_credit_transfer_transition::{
+            v0::IdentityCreditTransferTransitionV0, IdentityCreditTransferTransition,
+        },
+        StateTransition,
+    },
+    ProtocolError,
+};
+use drive_proof_verifier::types::{StateTransitionProofResult, StateTransitionQuery};
+use rs_sdk::{
+    error::StateTransitionBroadcastError,
+    platform::transition::{
+        broadcast::BroadcastStateTransition, put_contract::PutContract, put_document::PutDocument,
+    },
+    Error, Sdk,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+/// Signer that returns the same signature for any data, so that signed state transitions are deterministic
+struct MockSigner;
+
+impl Signer for MockSigner {
+    fn sign(
+        &self,
+        _identity_public_key: &IdentityPublicKey,
+        _data: &[u8],
+    ) -> Result<BinaryData, ProtocolError> {
+        Ok(BinaryData::new(vec![1; 65]))
+    }
+}
+
+/// Create an authentication key that can sign data contract and documents batch transitions
+fn mock_identity_public_key() -> IdentityPublicKey {
+    IdentityPublicKey::V0(IdentityPublicKeyV0 {
+        id: 1,
+        purpose: Purpose::AUTHENTICATION,
+        security_level: SecurityLevel::CRITICAL,
+        contract_bounds: None,
+        key_type: KeyType::ECDSA_SECP256K1,
+        read_only: false,
+        data: BinaryData::new(vec![2; 33]),
+        disabled_at: None,
+    })
+}
+
+/// Create a partial identity with no balance and revision
+fn mock_partial_identity(id: Identifier) -> PartialIdentity {
+    PartialIdentity {
+        id,
+        loaded_public_keys: BTreeMap::new(),
+        balance: None,
+        revision: None,
+        not_found_public_keys: BTreeSet::new(),
+    }
+}
+
+/// Create a signed credit transfer state transition
+fn mock_credit_transfer(sender: Identifier, recipient: Identifier) -> StateTransition {
+    StateTransition::IdentityCreditTransfer(IdentityCreditTransferTransition::V0(
+        IdentityCreditTransferTransitionV0 {
+            identity_id: sender,
+            recipient_id: recipient,
+            amount: 1000,
+            nonce: 1,
+            signature_public_key_id: 1,
+            signature: BinaryData::new(vec![1; 65]),
+            ..Default::default()
+        },
+    ))
+}
+
+#[tokio::test]
+/// Given some state transition, when I broadcast it using mock API, then I get the expected proof result
+async fn test_mock_broadcast_and_wait() {
+    let mut sdk = Sdk::new_mock();
+
+    let sender = Identifier::random();
+    let recipient = Identifier::random();
+    let state_transition = mock_credit_transfer(sender, recipient);
+
+    let mut expected_sender = mock_partial_identity(sender);
+    expected_sender.balance = Some(0);
+    let mut expected_recipient = mock_partial_identity(recipient);
+    expected_recipient.balance = Some(1000);
+    let expected =
+        StateTransitionProofResult::VerifiedBalanceTransfer(expected_sender, expected_recipient);
+
+    sdk.mock()
+        .expect_broadcast(state_transition.clone(), Some(expected.clone()))
+        .await;
+
+    let retrieved = state_transition
+        .broadcast_and_wait(&mut sdk)
+        .await
+        .expect("broadcast should succeed");
+
+    assert_eq!(retrieved, expected);
+}
+
+#[tokio::test]
+/// Given some state transition rejected by the Platform, when I broadcast it using mock API,
+/// then I get the error returned by the Platform
+async fn test_mock_broadcast_rejected() {
+    let mut sdk = Sdk::new_mock();
+
+    let state_transition = mock_credit_transfer(Identifier::random(), Identifier::random());
+
+    sdk.mock()
+        .expect_broadcast_error(
+            &state_transition,
+            StateTransitionBroadcastError {
+                code: 40100,
+                message: "identity not found".to_string(),
+                data: vec![],
+            },
+        )
+        .await;
+
+    let error = state_transition
+        .broadcast_and_wait(&mut sdk)
+        .await
+        .expect_err("broadcast should be rejected");
+
+    match error {
+        Error::StateTransitionBroadcastError(e) => {
+            assert_eq!(e.code, 40100);
+            assert_eq!(e.message, "identity not found");
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+}
+
+#[tokio::test]
+/// Given a state transition with empty proof, when I wait for its result using mock API, then I get an error
+async fn test_mock_broadcast_proof_not_found() {
+    let mut sdk = Sdk::new_mock();
+
+    let state_transition = mock_credit_transfer(Identifier::random(), Identifier::random());
+
+    sdk.mock()
+        .expect_broadcast(state_transition.clone(), None)
+        .await;
+
+    state_transition
+        .broadcast_and_wait(&mut sdk)
+        .await
+        .expect_err("missing proof result should fail");
+}
+
+#[tokio::test]
+/// Given some data contract, when I put it using mock API, then I get the data contract stored on the Platform
+async fn test_mock_put_contract() {
+    let mut sdk = Sdk::new_mock();
+
+    let data_contract = mock_data_contract(None);
+    let entropy = Bytes32::new([4; 32]);
+    let key = mock_identity_public_key();
+
+    // Build the same state transition that put_to_platform() broadcasts
+    let partial_identity = PartialIdentity {
+        loaded_public_keys: BTreeMap::from([(1, key.clone())]),
+        ..mock_partial_identity(data_contract.owner_id())
+    };
+    let state_transition = DataContractCreateTransition::new_from_data_contract(
+        data_contract.clone(),
+        entropy,
+        &partial_identity,
+        1,
+        &MockSigner,
+        sdk.version(),
+        None,
+    )
+    .expect("create data contract transition");
+
+    let expected = data_contract.clone();
+    sdk.mock()
+        .expect_broadcast(
+            state_transition,
+            Some(StateTransitionProofResult::VerifiedDataContract(
+                expected.clone(),
+            )),
+        )
+        .await;
+
+    let retrieved = data_contract
+        .put_to_platform_and_wait_for_response(&mut sdk, entropy, &key, &MockSigner)
+        .await
+        .expect("put data contract");
+
+    assert_eq!(retrieved, expected);
+    // Published data contract is cached; mock API doesn't return metadata, so it's stored at height 0
+    assert!(sdk.cache().data_contract(&expected.id(), 0).is_some());
+}
+
+#[tokio::test]
+/// Given some document, when I put it using mock API, then I get the document stored on the Platform
+async fn test_mock_put_document() {
+    let mut sdk = Sdk::new_mock();
+
+    let document_type: DocumentType = mock_document_type();
+    let data_contract = Arc::new(mock_data_contract(Some(&document_type)));
+    let document = document_type
+        .random_document(None, sdk.version())
+        .expect("document should be created");
+    let entropy = [3; 32];
+    let key = mock_identity_public_key();
+
+    // Build the same state transition that put_to_platform() broadcasts
+    let state_transition =
+        DocumentsBatchTransition::new_document_creation_transition_from_document(
+            document.clone(),
+            data_contract
+                .document_type_for_name(document_type.name())
+                .expect("document type"),
+            entropy,
+            &key,
+            &MockSigner,
+            sdk.version(),
+            None,
+            None,
+            None,
+        )
+        .expect("create documents batch transition");
+
+    sdk.mock()
+        .expect_broadcast(
+            StateTransitionQuery::new(state_transition).with_data_contract(data_contract.clone()),
+            Some(StateTransitionProofResult::VerifiedDocuments(
+                BTreeMap::from([(document.id(), Some(document.clone()))]),
+            )),
+        )
+        .await;
+
+    let retrieved = document
+        .put_to_platform_and_wait_for_response(
+            &mut sdk,
+            data_contract,
+            document_type.name(),
+            entropy,
+            &key,
+            &MockSigner,
+        )
+        .await
+        .expect("put document");
+
+    assert_eq!(retrieved, document);
+}
@@ -4,7 +4,7 @@
 use super::common::{mock_data_contract, mock_document_type};
 use dpp::{
     data_contract::{
-        accessors::v0::DataContractV0Getters,
+        accessors::v0::{DataContractV0Getters, DataContractV0Setters},
         document_type::{
             accessors::DocumentTypeV0Getters, random_document::CreateRandomDocument, DocumentType,
         },
@@ -14,9 +14,11 @@ use dpp::{
     prelude::{DataContract, Identifier, Identity},
 };
 use rs_sdk::{
+    cache::{CacheSettings, SdkCache},
     platform::{DocumentQuery, Fetch},
     Sdk,
 };
+use std::sync::Arc;
 
 #[tokio::test]
 /// Given some identity, when I fetch it using mock API, then I get the same identity
@@ -69,6 +71,75 @@ async fn test_mock_fetch_data_contract() {
     assert_eq!(retrieved, expected);
 }
 
+/// Given some data contract, when I fetch it using mock API, then it is stored in the cache.
+#[tokio::test]
+async fn test_mock_fetch_data_contract_cached() {
+    let mut sdk = Sdk::new_mock();
+
+    let expected = mock_data_contract(None);
+    let id = expected.id();
+
+    sdk.mock().expect_fetch(id, Some(expected.clone())).await;
+
+    DataContract::fetch(&mut sdk, id)
+        .await
+        .unwrap()
+        .expect("object should exist");
+
+    let cached = sdk
+        .cache()
+        .data_contract(&id, 0)
+        .expect("data contract should be cached");
+    assert_eq!(cached.as_ref(), &expected);
+}
+
+/// Given a cached data contract, when an older version is stored, then the newer one is kept until it's outdated.
+#[test]
+fn test_cache_keeps_newer_data_contract() {
+    let settings = CacheSettings::default();
+    let cache = SdkCache::new(settings);
+
+    let mut newer = mock_data_contract(None);
+    newer.set_version(2);
+    let mut older = newer.clone();
+    older.set_version(1);
+
+    cache.insert_data_contract(Arc::new(newer.clone()), 10);
+    cache.insert_data_contract(Arc::new(older), 20);
+
+    let cached = cache
+        .data_contract(&newer.id(), 20)
+        .expect("data contract should be cached");
+    assert_eq!(cached.version(), 2);
+
+    let cached_older = cache
+        .data_contract_version(&newer.id(), 1)
+        .expect("older version should be cached");
+    assert_eq!(cached_older.version(), 1);
+
+    let outdated_height = 10 + settings.data_contract_max_age + 1;
+    assert!(cache.data_contract(&newer.id(), outdated_height).is_none());
+}
+
+/// Given a cached quorum public key, when it's requested at a height it wasn't accepted at, then it's not returned.
+#[test]
+fn test_cache_quorum_public_key_active_heights() {
+    let cache = SdkCache::new(CacheSettings::default());
+    let quorum_hash = [1u8; 32];
+    let public_key = [2u8; 48];
+
+    cache.insert_quorum_public_key(4, quorum_hash, 100, public_key);
+    cache.insert_quorum_public_key(4, quorum_hash, 200, public_key);
+
+    assert_eq!(
+        cache.quorum_public_key(4, quorum_hash, 150),
+        Some(public_key)
+    );
+    assert!(cache.quorum_public_key(4, quorum_hash, 99).is_none());
+    assert!(cache.quorum_public_key(4, quorum_hash, 201).is_none());
+    assert!(cache.quorum_public_key(5, quorum_hash, 150).is_none());
+}
+
 /// Given some data contract, document type name and document, when I fetch expected document using mock Sdk, I get it.
 #[tokio::test]
 async fn test_mock_fetch_document() {
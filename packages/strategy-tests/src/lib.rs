@@ -611,6 +611,7 @@ impl Strategy {
                                     DocumentsBatchTransitionV0 {
                                         owner_id: identity.id(),
                                         transitions: vec![document_create_transition.into()],
+                                        user_fee_increase: 0,
                                         signature_public_key_id: 0,
                                         signature: BinaryData::default(),
                                     }
@@ -720,6 +721,7 @@ impl Strategy {
                                     DocumentsBatchTransitionV0 {
                                         owner_id: identity.id(),
                                         transitions: vec![document_create_transition.into()],
+                                        user_fee_increase: 0,
                                         signature_public_key_id: 0,
                                         signature: BinaryData::default(),
                                     }
@@ -811,6 +813,7 @@ impl Strategy {
                                 DocumentsBatchTransitionV0 {
                                     owner_id: identity.id,
                                     transitions: vec![document_delete_transition.into()],
+                                    user_fee_increase: 0,
                                     signature_public_key_id: 0,
                                     signature: BinaryData::default(),
                                 }
@@ -904,6 +907,7 @@ impl Strategy {
                                 DocumentsBatchTransitionV0 {
                                     owner_id: identity.id,
                                     transitions: vec![document_replace_transition.into()],
+                                    user_fee_increase: 0,
                                     signature_public_key_id: 0,
                                     signature: BinaryData::default(),
                                 }
@@ -996,11 +1000,21 @@ impl Strategy {
                             (0..current_identities.len()).choose_multiple(rng, count as usize);
                         for index in indices {
                             let random_identity = current_identities.get_mut(index).unwrap();
+                            let nonce = drive
+                                .fetch_identity_nonce(
+                                    random_identity.id().to_buffer(),
+                                    true,
+                                    None,
+                                    platform_version,
+                                )
+                                .expect("expected to be able to get identity nonce")
+                                .unwrap_or_default();
                             let state_transition =
                                 crate::transitions::create_identity_withdrawal_transition(
                                     random_identity,
                                     signer,
                                     rng,
+                                    nonce + 1,
                                 );
                             operations.push(state_transition);
                         }
@@ -1020,12 +1034,23 @@ impl Strategy {
                             .expect("expected to be able to get identity")
                             .expect("expected to get an identity");
 
+                        let fetched_owner_nonce = drive
+                            .fetch_identity_nonce(
+                                owner.id().to_buffer(),
+                                true,
+                                None,
+                                platform_version,
+                            )
+                            .expect("expected to be able to get identity nonce")
+                            .unwrap_or_default();
+
                         let state_transition =
                             crate::transitions::create_identity_credit_transfer_transition(
                                 owner,
                                 recipient,
                                 signer,
                                 fetched_owner_balance - 100,
+                                fetched_owner_nonce + 1,
                             );
                         operations.push(state_transition);
                     }
@@ -16,7 +16,7 @@ use dpp::identity::KeyType::ECDSA_SECP256K1;
 use dpp::identity::Purpose::AUTHENTICATION;
 use dpp::identity::SecurityLevel::{CRITICAL, MASTER};
 use dpp::identity::{Identity, IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
-use dpp::prelude::AssetLockProof;
+use dpp::prelude::{AssetLockProof, IdentityNonce};
 use dpp::state_transition::identity_create_transition::methods::IdentityCreateTransitionMethodsV0;
 use dpp::state_transition::identity_create_transition::IdentityCreateTransition;
 use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
@@ -433,6 +433,7 @@ pub fn create_identity_update_transition_disable_keys(
 /// - `signer`: A mutable reference to the signer used to create the cryptographic signature for
 ///   the state transition.
 /// - `rng`: A mutable reference to a random number generator, used for generating the random Pay-To-Script-Hash (P2SH).
+/// - `nonce`: The identity nonce of the withdrawal, one more than the nonce stored for the identity.
 ///
 /// # Returns
 /// - `StateTransition`: The constructed and signed state transition representing the identity's credit withdrawal.
@@ -443,6 +444,7 @@ pub fn create_identity_update_transition_disable_keys(
 ///     &mut identity,
 ///     &mut signer,
 ///     &mut rng,
+///     1,
 /// );
 /// ```
 ///
@@ -454,6 +456,7 @@ pub fn create_identity_withdrawal_transition(
     identity: &mut Identity,
     signer: &mut SimpleSigner,
     rng: &mut StdRng,
+    nonce: IdentityNonce,
 ) -> StateTransition {
     identity.bump_revision();
     let mut withdrawal: StateTransition = IdentityCreditWithdrawalTransitionV0 {
@@ -463,6 +466,8 @@ pub fn create_identity_withdrawal_transition(
         pooling: Pooling::Never,
         output_script: CoreScript::random_p2sh(rng),
         revision: identity.revision(),
+        nonce,
+        user_fee_increase: 0,
         signature_public_key_id: 0,
         signature: Default::default(),
     }
@@ -500,6 +505,7 @@ pub fn create_identity_withdrawal_transition(
 /// - `signer`: A mutable reference to a signer, used for creating the cryptographic signature
 ///   for the state transition.
 /// - `amount`: The number of credits to be transferred from the sender to the recipient.
+/// - `nonce`: The identity nonce of the transfer, one more than the nonce stored for the sender.
 ///
 /// # Returns
 /// - `StateTransition`: The constructed and signed state transition representing the credit transfer
@@ -512,6 +518,7 @@ pub fn create_identity_withdrawal_transition(
 ///     &recipient_identity,
 ///     &mut signer,
 ///     1000,
+///     1,
 /// );
 /// ```
 ///
@@ -524,11 +531,14 @@ pub fn create_identity_credit_transfer_transition(
     recipient: &Identity,
     signer: &mut SimpleSigner,
     amount: u64,
+    nonce: IdentityNonce,
 ) -> StateTransition {
     let mut transition: StateTransition = IdentityCreditTransferTransitionV0 {
         identity_id: identity.id(),
         recipient_id: recipient.id(),
         amount,
+        nonce,
+        user_fee_increase: 0,
         signature_public_key_id: 0,
         signature: Default::default(),
     }
@@ -99,6 +99,16 @@ impl IdentityCreditTransferTransitionWasm {
         self.0.set_amount(amount as u64);
     }
 
+    #[wasm_bindgen(js_name=getNonce)]
+    pub fn get_nonce(&self) -> f64 {
+        self.0.nonce() as f64
+    }
+
+    #[wasm_bindgen(js_name=setNonce)]
+    pub fn set_nonce(&mut self, nonce: f64) {
+        self.0.set_nonce(nonce as u64);
+    }
+
     #[wasm_bindgen(js_name=toObject)]
     pub fn to_object(&self, options: JsValue) -> Result<JsValue, JsValue> {
         let opts: super::to_object::ToObjectOptions = if options.is_object() {
@@ -104,5 +104,10 @@ pub async fn validate_state_transition_identity_signature_wasm(
                 .validate(&mut state_transition, &execution_context)
                 .await
         }
+        StateTransition::DataContractDelete(mut state_transition) => {
+            validator
+                .validate(&mut state_transition, &execution_context)
+                .await
+        }
     }
 }
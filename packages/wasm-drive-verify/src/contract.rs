@@ -0,0 +1,89 @@
+use crate::errors::to_js_error;
+use crate::proof::verify_proof;
+use crate::utils::{to_fixed_bytes, to_js_object};
+use dpp::data_contract::conversion::value::v0::DataContractValueConversionMethodsV0;
+use dpp::prelude::DataContract;
+use dpp::serialization::PlatformSerializableWithPlatformVersion;
+use dpp::version::PlatformVersion;
+use drive::drive::verify::RootHash;
+use drive::drive::Drive;
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+/// A data contract verified from a proof
+#[wasm_bindgen(js_name=VerifiedDataContract)]
+pub struct VerifiedDataContractWasm {
+    root_hash: RootHash,
+    data_contract: Option<DataContract>,
+    platform_version: &'static PlatformVersion,
+}
+
+#[wasm_bindgen(js_class=VerifiedDataContract)]
+impl VerifiedDataContractWasm {
+    #[wasm_bindgen(js_name=getRootHash)]
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.root_hash.to_vec()
+    }
+
+    /// Returns the data contract as a plain object, or undefined if the proof proves its absence
+    #[wasm_bindgen(js_name=getDataContract)]
+    pub fn data_contract(&self) -> Result<JsValue, JsValue> {
+        match &self.data_contract {
+            Some(data_contract) => to_js_object(
+                &data_contract
+                    .to_value(self.platform_version)
+                    .map_err(to_js_error)?,
+            ),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// Returns the serialized data contract, as expected by `verifyDocumentsProof`
+    #[wasm_bindgen(js_name=getSerializedDataContract)]
+    pub fn serialized_data_contract(&self) -> Result<Option<Vec<u8>>, JsValue> {
+        self.data_contract
+            .as_ref()
+            .map(|data_contract| {
+                data_contract
+                    .serialize_to_bytes_with_platform_version(self.platform_version)
+                    .map_err(to_js_error)
+            })
+            .transpose()
+    }
+}
+
+#[wasm_bindgen(js_name=verifyContract)]
+pub fn verify_contract(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    contract_known_keeps_history: Option<bool>,
+    is_proof_subset: bool,
+    in_multiple_contract_proof_form: bool,
+    contract_id: &[u8],
+) -> Result<VerifiedDataContractWasm, JsValue> {
+    let contract_id = to_fixed_bytes(contract_id, "contract id")?;
+
+    let (root_hash, (data_contract, platform_version)) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            Drive::verify_contract(
+                grovedb_proof,
+                contract_known_keeps_history,
+                is_proof_subset,
+                in_multiple_contract_proof_form,
+                contract_id,
+                platform_version,
+            )
+            .map(|(root_hash, data_contract)| (root_hash, (data_contract, platform_version)))
+        },
+    )?;
+
+    Ok(VerifiedDataContractWasm {
+        root_hash,
+        data_contract,
+        platform_version,
+    })
+}
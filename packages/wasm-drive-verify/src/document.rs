@@ -0,0 +1,77 @@
+use crate::errors::to_js_error;
+use crate::proof::verify_proof;
+use crate::utils::to_js_object;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformValueMethodsV0;
+use dpp::document::Document;
+use dpp::prelude::DataContract;
+use dpp::serialization::PlatformDeserializableWithPotentialValidationFromVersionedStructure;
+use drive::drive::config::DriveConfig;
+use drive::drive::verify::RootHash;
+use drive::query::DriveQuery;
+use js_sys::{Array, Function};
+use wasm_bindgen::prelude::*;
+
+/// Documents verified from a proof
+#[wasm_bindgen(js_name=VerifiedDocuments)]
+pub struct VerifiedDocumentsWasm {
+    root_hash: RootHash,
+    documents: Vec<Document>,
+}
+
+#[wasm_bindgen(js_class=VerifiedDocuments)]
+impl VerifiedDocumentsWasm {
+    #[wasm_bindgen(js_name=getRootHash)]
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.root_hash.to_vec()
+    }
+
+    /// Returns the documents matching the query as plain objects
+    #[wasm_bindgen(js_name=getDocuments)]
+    pub fn documents(&self) -> Result<Array, JsValue> {
+        self.documents
+            .iter()
+            .map(|document| to_js_object(&document.to_object().map_err(to_js_error)?))
+            .collect()
+    }
+}
+
+/// Verifies a proof of the documents matching a query.
+///
+/// `data_contract` is the serialized data contract of the documents and `query` is the CBOR
+/// encoded query, with the `where`, `orderBy`, `limit`, `startAt` and `startAfter` fields of a
+/// documents request.
+#[wasm_bindgen(js_name=verifyDocumentsProof)]
+pub fn verify_documents_proof(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    data_contract: &[u8],
+    document_type_name: &str,
+    query: &[u8],
+) -> Result<VerifiedDocumentsWasm, JsValue> {
+    let (root_hash, documents) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            let data_contract =
+                DataContract::versioned_deserialize(data_contract, false, platform_version)?;
+            let document_type = data_contract.document_type_for_name(document_type_name)?;
+
+            let query = DriveQuery::from_cbor(
+                query,
+                &data_contract,
+                document_type,
+                &DriveConfig::default(),
+            )?;
+
+            query.verify_proof(grovedb_proof, platform_version)
+        },
+    )?;
+
+    Ok(VerifiedDocumentsWasm {
+        root_hash,
+        documents,
+    })
+}
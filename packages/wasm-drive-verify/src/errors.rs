@@ -0,0 +1,7 @@
+use std::fmt::Display;
+use wasm_bindgen::JsValue;
+
+/// Converts an error into a JS `Error` with the error message
+pub(crate) fn to_js_error(error: impl Display) -> JsValue {
+    js_sys::Error::new(&error.to_string()).into()
+}
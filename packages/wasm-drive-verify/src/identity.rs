@@ -0,0 +1,198 @@
+use crate::errors::to_js_error;
+use crate::proof::verify_proof;
+use crate::utils::{to_fixed_bytes, to_js_object};
+use dpp::identity::Identity;
+use dpp::serialization::{PlatformSerializable, ValueConvertible};
+use drive::drive::verify::RootHash;
+use drive::drive::Drive;
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+/// An identity verified from a proof
+#[wasm_bindgen(js_name=VerifiedIdentity)]
+pub struct VerifiedIdentityWasm {
+    root_hash: RootHash,
+    identity: Option<Identity>,
+}
+
+#[wasm_bindgen(js_class=VerifiedIdentity)]
+impl VerifiedIdentityWasm {
+    #[wasm_bindgen(js_name=getRootHash)]
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.root_hash.to_vec()
+    }
+
+    /// Returns the identity as a plain object, or undefined if the proof proves its absence
+    #[wasm_bindgen(js_name=getIdentity)]
+    pub fn identity(&self) -> Result<JsValue, JsValue> {
+        match &self.identity {
+            Some(identity) => to_js_object(&identity.to_object().map_err(to_js_error)?),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// Returns the serialized identity, which can be loaded with `Identity.fromBuffer` of wasm-dpp
+    #[wasm_bindgen(js_name=getSerializedIdentity)]
+    pub fn serialized_identity(&self) -> Result<Option<Vec<u8>>, JsValue> {
+        self.identity
+            .as_ref()
+            .map(|identity| identity.serialize_to_bytes().map_err(to_js_error))
+            .transpose()
+    }
+}
+
+/// An identity id verified from a proof
+#[wasm_bindgen(js_name=VerifiedIdentityId)]
+pub struct VerifiedIdentityIdWasm {
+    root_hash: RootHash,
+    identity_id: Option<[u8; 32]>,
+}
+
+#[wasm_bindgen(js_class=VerifiedIdentityId)]
+impl VerifiedIdentityIdWasm {
+    #[wasm_bindgen(js_name=getRootHash)]
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.root_hash.to_vec()
+    }
+
+    #[wasm_bindgen(js_name=getIdentityId)]
+    pub fn identity_id(&self) -> Option<Vec<u8>> {
+        self.identity_id.map(|identity_id| identity_id.to_vec())
+    }
+}
+
+/// An identity balance verified from a proof
+#[wasm_bindgen(js_name=VerifiedIdentityBalance)]
+pub struct VerifiedIdentityBalanceWasm {
+    root_hash: RootHash,
+    balance: Option<u64>,
+}
+
+#[wasm_bindgen(js_class=VerifiedIdentityBalance)]
+impl VerifiedIdentityBalanceWasm {
+    #[wasm_bindgen(js_name=getRootHash)]
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.root_hash.to_vec()
+    }
+
+    /// Returns the balance in credits as a bigint, or undefined if the identity doesn't exist
+    #[wasm_bindgen(js_name=getBalance)]
+    pub fn balance(&self) -> Option<u64> {
+        self.balance
+    }
+}
+
+#[wasm_bindgen(js_name=verifyFullIdentityByIdentityId)]
+pub fn verify_full_identity_by_identity_id(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    is_proof_subset: bool,
+    identity_id: &[u8],
+) -> Result<VerifiedIdentityWasm, JsValue> {
+    let identity_id = to_fixed_bytes(identity_id, "identity id")?;
+
+    let (root_hash, identity) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            Drive::verify_full_identity_by_identity_id(
+                grovedb_proof,
+                is_proof_subset,
+                identity_id,
+                platform_version,
+            )
+        },
+    )?;
+
+    Ok(VerifiedIdentityWasm {
+        root_hash,
+        identity,
+    })
+}
+
+#[wasm_bindgen(js_name=verifyFullIdentityByPublicKeyHash)]
+pub fn verify_full_identity_by_public_key_hash(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    public_key_hash: &[u8],
+) -> Result<VerifiedIdentityWasm, JsValue> {
+    let public_key_hash = to_fixed_bytes(public_key_hash, "public key hash")?;
+
+    let (root_hash, identity) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            Drive::verify_full_identity_by_public_key_hash(
+                grovedb_proof,
+                public_key_hash,
+                platform_version,
+            )
+        },
+    )?;
+
+    Ok(VerifiedIdentityWasm {
+        root_hash,
+        identity,
+    })
+}
+
+#[wasm_bindgen(js_name=verifyIdentityIdByPublicKeyHash)]
+pub fn verify_identity_id_by_public_key_hash(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    is_proof_subset: bool,
+    public_key_hash: &[u8],
+) -> Result<VerifiedIdentityIdWasm, JsValue> {
+    let public_key_hash = to_fixed_bytes(public_key_hash, "public key hash")?;
+
+    let (root_hash, identity_id) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            Drive::verify_identity_id_by_public_key_hash(
+                grovedb_proof,
+                is_proof_subset,
+                public_key_hash,
+                platform_version,
+            )
+        },
+    )?;
+
+    Ok(VerifiedIdentityIdWasm {
+        root_hash,
+        identity_id,
+    })
+}
+
+#[wasm_bindgen(js_name=verifyIdentityBalanceForIdentityId)]
+pub fn verify_identity_balance_for_identity_id(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    identity_id: &[u8],
+    is_proof_subset: bool,
+) -> Result<VerifiedIdentityBalanceWasm, JsValue> {
+    let identity_id = to_fixed_bytes(identity_id, "identity id")?;
+
+    let (root_hash, balance) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            Drive::verify_identity_balance_for_identity_id(
+                grovedb_proof,
+                identity_id,
+                is_proof_subset,
+                platform_version,
+            )
+        },
+    )?;
+
+    Ok(VerifiedIdentityBalanceWasm { root_hash, balance })
+}
@@ -0,0 +1,19 @@
+//! WebAssembly bindings for Drive proof verification.
+//!
+//! Lets JavaScript clients verify GroveDB proofs returned by DAPI with the `Drive::verify_*`
+//! functions, along with the Tenderdash quorum signature of the proved root hash, instead of
+//! trusting the node they are connected to.
+
+pub use contract::*;
+pub use document::*;
+pub use identity::*;
+pub use system::*;
+
+mod contract;
+mod document;
+mod errors;
+mod identity;
+mod proof;
+mod quorum_provider;
+mod system;
+mod utils;
@@ -0,0 +1,173 @@
+use crate::errors::to_js_error;
+use crate::quorum_provider::QuorumPublicKey;
+use dapi_grpc::platform::v0::{Proof, ResponseMetadata};
+use dapi_grpc::Message;
+use dpp::version::PlatformVersion;
+use drive::drive::verify::RootHash;
+use drive_proof_verifier::{verify_tenderdash_proof, Error, QuorumInfoProvider};
+use js_sys::Function;
+use wasm_bindgen::JsValue;
+
+/// Verifies the proof of a DAPI response.
+///
+/// `proof` and `metadata` are the protobuf encoded `Proof` and `ResponseMetadata` of the
+/// response. The GroveDB proof is verified with `verify_grovedb_proof`, then the proved root hash
+/// is checked against the signature of the quorum returned by `quorum_public_key_provider`.
+pub(crate) fn verify_proof<T>(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: &Function,
+    verify_grovedb_proof: impl FnOnce(
+        &[u8],
+        &'static PlatformVersion,
+    ) -> Result<(RootHash, T), drive::error::Error>,
+) -> Result<(RootHash, T), JsValue> {
+    let proof = Proof::decode(proof).map_err(to_js_error)?;
+    let metadata = ResponseMetadata::decode(metadata).map_err(to_js_error)?;
+
+    let quorum_public_key = QuorumPublicKey::fetch(quorum_public_key_provider, &proof, &metadata)?;
+
+    verify_signed_proof(&proof, &metadata, &quorum_public_key, verify_grovedb_proof)
+        .map_err(to_js_error)
+}
+
+/// Verifies the GroveDB proof of a response and that its root hash was signed by the quorum.
+fn verify_signed_proof<T>(
+    proof: &Proof,
+    metadata: &ResponseMetadata,
+    provider: &dyn QuorumInfoProvider,
+    verify_grovedb_proof: impl FnOnce(
+        &[u8],
+        &'static PlatformVersion,
+    ) -> Result<(RootHash, T), drive::error::Error>,
+) -> Result<(RootHash, T), Error> {
+    let platform_version = PlatformVersion::get(metadata.protocol_version)?;
+
+    let (root_hash, value) = verify_grovedb_proof(&proof.grovedb_proof, platform_version)?;
+
+    verify_tenderdash_proof(proof, metadata, &root_hash, provider)?;
+
+    Ok((root_hash, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dapi_grpc::platform::v0::get_identity_balance_response::get_identity_balance_response_v0;
+    use dapi_grpc::platform::v0::{get_identity_balance_response, GetIdentityBalanceResponse};
+    use drive::drive::Drive;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Vector {
+        response: GetIdentityBalanceResponse,
+    }
+
+    const IDENTITY_ID: [u8; 32] = [
+        48, 18, 193, 155, 152, 236, 0, 51, 173, 219, 54, 205, 100, 183, 245, 16, 103, 15, 42, 53,
+        26, 67, 4, 181, 246, 153, 65, 68, 40, 110, 253, 172,
+    ];
+
+    fn identity_balance_response() -> (Proof, ResponseMetadata) {
+        let vector: Vector =
+            serde_json::from_str(include_str!("../tests/vectors/get_identity_balance.json"))
+                .expect("expected a valid test vector");
+
+        let Some(get_identity_balance_response::Version::V0(response)) = vector.response.version
+        else {
+            panic!("expected a v0 response");
+        };
+
+        let Some(get_identity_balance_response_v0::Result::Proof(proof)) = response.result else {
+            panic!("expected a proof");
+        };
+
+        (proof, response.metadata.expect("expected metadata"))
+    }
+
+    fn quorum_public_key(proof: &Proof, metadata: &ResponseMetadata) -> QuorumPublicKey {
+        let public_key: Vec<u8> = serde_json::from_str(include_str!(
+            "../tests/vectors/quorum_pubkey-106-229983404293f0205c1bbd8df060c5983a47736b8d541f748d2d6c2848828110.json"
+        ))
+        .expect("expected a valid quorum public key");
+
+        QuorumPublicKey::new(
+            proof.quorum_type,
+            proof.quorum_hash.as_slice().try_into().unwrap(),
+            metadata.core_chain_locked_height,
+            public_key.try_into().unwrap(),
+        )
+    }
+
+    fn verify_identity_balance(
+        proof: &Proof,
+        metadata: &ResponseMetadata,
+        provider: &dyn QuorumInfoProvider,
+    ) -> Result<Option<u64>, Error> {
+        verify_signed_proof(
+            proof,
+            metadata,
+            provider,
+            |grovedb_proof, platform_version| {
+                Drive::verify_identity_balance_for_identity_id(
+                    grovedb_proof,
+                    IDENTITY_ID,
+                    false,
+                    platform_version,
+                )
+            },
+        )
+        .map(|(_, balance)| balance)
+    }
+
+    #[test]
+    fn should_verify_proof_signed_by_quorum() {
+        let (proof, metadata) = identity_balance_response();
+        let provider = quorum_public_key(&proof, &metadata);
+
+        let balance = verify_identity_balance(&proof, &metadata, &provider)
+            .expect("expected to verify proof");
+
+        assert!(balance.is_some());
+    }
+
+    #[test]
+    fn should_not_verify_proof_with_tampered_metadata() {
+        let (proof, mut metadata) = identity_balance_response();
+        let provider = quorum_public_key(&proof, &metadata);
+
+        metadata.time_ms += 1;
+
+        assert!(matches!(
+            verify_identity_balance(&proof, &metadata, &provider),
+            Err(Error::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn should_not_verify_proof_with_tampered_grovedb_proof() {
+        let (mut proof, metadata) = identity_balance_response();
+        let provider = quorum_public_key(&proof, &metadata);
+
+        let last = proof.grovedb_proof.len() - 1;
+        proof.grovedb_proof[last] ^= 1;
+
+        assert!(verify_identity_balance(&proof, &metadata, &provider).is_err());
+    }
+
+    #[test]
+    fn should_not_verify_proof_signed_by_another_quorum() {
+        let (proof, metadata) = identity_balance_response();
+        let provider = QuorumPublicKey::new(
+            proof.quorum_type,
+            [0; 32],
+            metadata.core_chain_locked_height,
+            [0; 48],
+        );
+
+        assert!(matches!(
+            verify_identity_balance(&proof, &metadata, &provider),
+            Err(Error::InvalidQuorum { .. })
+        ));
+    }
+}
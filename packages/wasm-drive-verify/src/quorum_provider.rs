@@ -0,0 +1,140 @@
+use crate::errors::to_js_error;
+use dapi_grpc::platform::v0::{Proof, ResponseMetadata};
+use drive_proof_verifier::{Error, QuorumInfoProvider};
+use js_sys::{Function, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen(typescript_custom_section)]
+const QUORUM_PUBLIC_KEY_PROVIDER: &'static str = r#"
+/**
+ * Returns the 48 bytes BLS public key of a quorum valid at the given core chain locked height
+ */
+export type QuorumPublicKeyProvider = (
+  quorumType: number,
+  quorumHash: Uint8Array,
+  coreChainLockedHeight: number,
+) => Uint8Array;
+"#;
+
+/// The public key of the quorum that signed a response.
+///
+/// The key is requested from the JavaScript `QuorumPublicKeyProvider` callback before the proof
+/// is verified, so only plain bytes are handed to the verifier.
+#[derive(Debug, Clone)]
+pub(crate) struct QuorumPublicKey {
+    quorum_type: u32,
+    quorum_hash: [u8; 32],
+    core_chain_locked_height: u32,
+    public_key: [u8; 48],
+}
+
+impl QuorumPublicKey {
+    /// Requests the public key of the quorum that signed `proof` from the JavaScript callback.
+    ///
+    /// Proof verification is synchronous, so the callback must return the public key directly,
+    /// keys fetched asynchronously have to be cached by the caller beforehand.
+    pub(crate) fn fetch(
+        quorum_public_key_provider: &Function,
+        proof: &Proof,
+        metadata: &ResponseMetadata,
+    ) -> Result<Self, JsValue> {
+        let quorum_hash: [u8; 32] = proof.quorum_hash.as_slice().try_into().map_err(|_| {
+            to_js_error(format!(
+                "quorum hash must be 32 bytes long, got {} bytes",
+                proof.quorum_hash.len()
+            ))
+        })?;
+
+        let public_key = quorum_public_key_provider
+            .call3(
+                &JsValue::NULL,
+                &JsValue::from(proof.quorum_type),
+                &Uint8Array::from(quorum_hash.as_slice()).into(),
+                &JsValue::from(metadata.core_chain_locked_height),
+            )?
+            .dyn_into::<Uint8Array>()
+            .map_err(|_| to_js_error("quorum public key provider must return a Uint8Array"))?
+            .to_vec();
+
+        let public_key = public_key.as_slice().try_into().map_err(|_| {
+            to_js_error(format!(
+                "quorum public key must be 48 bytes long, got {} bytes",
+                public_key.len()
+            ))
+        })?;
+
+        Ok(Self::new(
+            proof.quorum_type,
+            quorum_hash,
+            metadata.core_chain_locked_height,
+            public_key,
+        ))
+    }
+
+    pub(crate) fn new(
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+        public_key: [u8; 48],
+    ) -> Self {
+        QuorumPublicKey {
+            quorum_type,
+            quorum_hash,
+            core_chain_locked_height,
+            public_key,
+        }
+    }
+}
+
+impl QuorumInfoProvider for QuorumPublicKey {
+    fn get_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+    ) -> Result<[u8; 48], Error> {
+        if quorum_type != self.quorum_type
+            || quorum_hash != self.quorum_hash
+            || core_chain_locked_height != self.core_chain_locked_height
+        {
+            return Err(Error::InvalidQuorum {
+                error: format!(
+                    "public key was provided for quorum {} of type {}, not for quorum {} of type {}",
+                    hex::encode(self.quorum_hash),
+                    self.quorum_type,
+                    hex::encode(quorum_hash),
+                    quorum_type
+                ),
+            });
+        }
+
+        Ok(self.public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_provide_public_key_of_another_quorum() {
+        let quorum_public_key = QuorumPublicKey::new(106, [1; 32], 1626, [2; 48]);
+
+        assert_eq!(
+            quorum_public_key
+                .get_quorum_public_key(106, [1; 32], 1626)
+                .expect("expected public key"),
+            [2; 48]
+        );
+
+        assert!(matches!(
+            quorum_public_key.get_quorum_public_key(106, [3; 32], 1626),
+            Err(Error::InvalidQuorum { .. })
+        ));
+        assert!(matches!(
+            quorum_public_key.get_quorum_public_key(4, [1; 32], 1626),
+            Err(Error::InvalidQuorum { .. })
+        ));
+    }
+}
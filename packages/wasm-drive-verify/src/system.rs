@@ -0,0 +1,103 @@
+use crate::proof::verify_proof;
+use dpp::block::epoch::EpochIndex;
+use dpp::block::extended_epoch_info::v0::ExtendedEpochInfoV0Getters;
+use dpp::block::extended_epoch_info::ExtendedEpochInfo;
+use drive::drive::verify::RootHash;
+use drive::drive::Drive;
+use js_sys::{Array, Function};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=EpochInfo)]
+#[derive(Clone, Debug)]
+pub struct EpochInfoWasm(ExtendedEpochInfo);
+
+impl From<ExtendedEpochInfo> for EpochInfoWasm {
+    fn from(v: ExtendedEpochInfo) -> Self {
+        EpochInfoWasm(v)
+    }
+}
+
+#[wasm_bindgen(js_class=EpochInfo)]
+impl EpochInfoWasm {
+    #[wasm_bindgen(js_name=getIndex)]
+    pub fn index(&self) -> EpochIndex {
+        self.0.index()
+    }
+
+    #[wasm_bindgen(js_name=getFirstBlockTime)]
+    pub fn first_block_time(&self) -> f64 {
+        self.0.first_block_time() as f64
+    }
+
+    #[wasm_bindgen(js_name=getFirstBlockHeight)]
+    pub fn first_block_height(&self) -> f64 {
+        self.0.first_block_height() as f64
+    }
+
+    #[wasm_bindgen(js_name=getFirstCoreBlockHeight)]
+    pub fn first_core_block_height(&self) -> u32 {
+        self.0.first_core_block_height()
+    }
+
+    #[wasm_bindgen(js_name=getFeeMultiplier)]
+    pub fn fee_multiplier(&self) -> f64 {
+        self.0.fee_multiplier()
+    }
+}
+
+/// Epoch infos verified from a proof
+#[wasm_bindgen(js_name=VerifiedEpochInfos)]
+pub struct VerifiedEpochInfosWasm {
+    root_hash: RootHash,
+    epoch_infos: Vec<ExtendedEpochInfo>,
+}
+
+#[wasm_bindgen(js_class=VerifiedEpochInfos)]
+impl VerifiedEpochInfosWasm {
+    #[wasm_bindgen(js_name=getRootHash)]
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.root_hash.to_vec()
+    }
+
+    /// Returns the verified epochs as `EpochInfo` instances
+    #[wasm_bindgen(js_name=getEpochInfos)]
+    pub fn epoch_infos(&self) -> Array {
+        self.epoch_infos
+            .iter()
+            .cloned()
+            .map(|epoch_info| JsValue::from(EpochInfoWasm::from(epoch_info)))
+            .collect()
+    }
+}
+
+#[wasm_bindgen(js_name=verifyEpochInfos)]
+pub fn verify_epoch_infos(
+    proof: &[u8],
+    metadata: &[u8],
+    quorum_public_key_provider: Function,
+    current_epoch: EpochIndex,
+    start_epoch: Option<EpochIndex>,
+    count: u16,
+    ascending: bool,
+) -> Result<VerifiedEpochInfosWasm, JsValue> {
+    let (root_hash, epoch_infos) = verify_proof(
+        proof,
+        metadata,
+        &quorum_public_key_provider,
+        |grovedb_proof, platform_version| {
+            Drive::verify_epoch_infos(
+                grovedb_proof,
+                current_epoch,
+                start_epoch,
+                count,
+                ascending,
+                platform_version,
+            )
+        },
+    )?;
+
+    Ok(VerifiedEpochInfosWasm {
+        root_hash,
+        epoch_infos,
+    })
+}
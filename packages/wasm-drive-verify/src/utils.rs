@@ -0,0 +1,20 @@
+use crate::errors::to_js_error;
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+pub(crate) fn to_fixed_bytes<const N: usize>(bytes: &[u8], name: &str) -> Result<[u8; N], JsValue> {
+    bytes.try_into().map_err(|_| {
+        to_js_error(format!(
+            "{} must be {} bytes long, got {} bytes",
+            name,
+            N,
+            bytes.len()
+        ))
+    })
+}
+
+pub(crate) fn to_js_object(value: &impl Serialize) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(to_js_error)
+}